@@ -0,0 +1,100 @@
+//! Critical path benchmarks on a 10k-task layered dependency graph.
+//!
+//! Run with `cargo bench --bench critical_path`.
+
+use chrono::NaiveDate;
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use mouc_rust::critical_path::{
+    calculate_critical_path_interned, CriticalPathCache, CriticalPathConfig, InternedContext,
+};
+use mouc_rust::{Dependency, Task};
+use rustc_hash::{FxHashMap, FxHashSet};
+
+const TASKS: usize = 10_000;
+const WIDTH: usize = 100;
+/// Every task is a target of the cache, so it is built on a smaller graph.
+const CACHE_TASKS: usize = 1_000;
+
+/// Layers of `WIDTH` tasks, each depending on three tasks of the layer before.
+fn layered_graph(tasks: usize) -> FxHashMap<String, Task> {
+    (0..tasks)
+        .map(|i| {
+            let layer = i / WIDTH;
+            let dependencies = if layer == 0 {
+                Vec::new()
+            } else {
+                (0..3)
+                    .map(|k| Dependency {
+                        entity_id: format!("t{}", (layer - 1) * WIDTH + (i * 7 + k * 31) % WIDTH),
+                        lag_days: (k % 2) as f64,
+                        ..Default::default()
+                    })
+                    .collect()
+            };
+            let task = Task {
+                id: format!("t{i}"),
+                duration_days: 1.0 + (i % 5) as f64,
+                dependencies,
+                ..Default::default()
+            };
+            (task.id.clone(), task)
+        })
+        .collect()
+}
+
+fn bench_critical_path(c: &mut Criterion) {
+    let tasks = layered_graph(TASKS);
+    c.bench_function("build_context_10k", |b| {
+        b.iter(|| InternedContext::new(black_box(&tasks), 50))
+    });
+
+    let ctx = InternedContext::new(&tasks, 50);
+    let scheduled = vec![f64::MAX; ctx.len()];
+    let completed = vec![false; ctx.len()];
+    let target = format!("t{}", TASKS - 1);
+    c.bench_function("critical_path_10k", |b| {
+        b.iter(|| {
+            calculate_critical_path_interned(black_box(&target), &ctx, &scheduled, &completed)
+                .unwrap()
+        })
+    });
+
+    let days = [
+        NaiveDate::from_ymd_opt(2025, 1, 1).unwrap(),
+        NaiveDate::from_ymd_opt(2025, 1, 2).unwrap(),
+    ];
+    let mut tasks = layered_graph(CACHE_TASKS);
+    for (i, task) in tasks.values_mut().enumerate() {
+        if i % 4 == 0 {
+            task.end_before = days[0].checked_add_days(chrono::Days::new(i as u64 % 90));
+        }
+    }
+    let ctx = InternedContext::new(&tasks, 50);
+    let unscheduled: FxHashSet<String> = tasks.keys().cloned().collect();
+    let scheduled = vec![f64::MAX; ctx.len()];
+    let completed = vec![false; ctx.len()];
+    // Compare with `--features parallel`
+    c.bench_function("build_cache_1k", |b| {
+        b.iter(|| {
+            CriticalPathCache::new(&unscheduled, &tasks, &ctx, &scheduled, &completed, 50).unwrap()
+        })
+    });
+    let mut cache =
+        CriticalPathCache::new(&unscheduled, &tasks, &ctx, &scheduled, &completed, 50).unwrap();
+    let config = CriticalPathConfig::default();
+    c.bench_function("rank_targets_1k", |b| {
+        b.iter(|| cache.get_ranked_targets(&config, black_box(days[0])).len())
+    });
+    let mut day = 0;
+    c.bench_function("rank_targets_new_day_1k", |b| {
+        b.iter(|| {
+            day ^= 1;
+            cache
+                .get_ranked_targets(&config, black_box(days[day]))
+                .len()
+        })
+    });
+}
+
+criterion_group!(benches, bench_critical_path);
+criterion_main!(benches);
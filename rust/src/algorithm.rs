@@ -0,0 +1,185 @@
+//! Common interface over the scheduling algorithms.
+//!
+//! `Scheduler` is implemented by `ParallelScheduler` and
+//! `CriticalPathScheduler`; `build_scheduler` picks one from
+//! `SchedulingConfig::algorithm`, so callers can switch algorithms through
+//! configuration alone.
+
+use chrono::NaiveDate;
+use rustc_hash::FxHashSet;
+
+use crate::cancellation::CancellationToken;
+use crate::config::{RolloutConfig, SchedulingAlgorithm, SchedulingConfig};
+use crate::critical_path::{CriticalPathConfig, CriticalPathScheduler};
+use crate::exceptions::ScheduleFailure;
+use crate::models::{AlgorithmMetadata, AlgorithmResult, Task};
+use crate::progress::ProgressReporter;
+use crate::scheduler::{ParallelScheduler, ResourceConfig};
+
+/// A scheduling algorithm that can be run, observed and cancelled.
+pub trait Scheduler: Send {
+    /// Run the algorithm.
+    fn schedule(&mut self) -> Result<AlgorithmResult, ScheduleFailure>;
+
+    /// Metadata of the last completed `schedule()` run.
+    fn get_metadata(&self) -> Option<&AlgorithmMetadata>;
+
+    /// Set a token that can be used to cancel the next run.
+    fn set_cancellation_token(&mut self, token: CancellationToken);
+
+    /// Set a reporter invoked periodically during the next run.
+    fn set_progress_reporter(&mut self, reporter: ProgressReporter);
+}
+
+impl Scheduler for ParallelScheduler {
+    fn schedule(&mut self) -> Result<AlgorithmResult, ScheduleFailure> {
+        ParallelScheduler::schedule(self).map_err(|e| ScheduleFailure::from(&e))
+    }
+
+    fn get_metadata(&self) -> Option<&AlgorithmMetadata> {
+        ParallelScheduler::get_metadata(self)
+    }
+
+    fn set_cancellation_token(&mut self, token: CancellationToken) {
+        ParallelScheduler::set_cancellation_token(self, token);
+    }
+
+    fn set_progress_reporter(&mut self, reporter: ProgressReporter) {
+        ParallelScheduler::set_progress_reporter(self, reporter);
+    }
+}
+
+impl Scheduler for CriticalPathScheduler {
+    fn schedule(&mut self) -> Result<AlgorithmResult, ScheduleFailure> {
+        CriticalPathScheduler::schedule(self).map_err(|e| ScheduleFailure::from(&e))
+    }
+
+    fn get_metadata(&self) -> Option<&AlgorithmMetadata> {
+        CriticalPathScheduler::get_metadata(self)
+    }
+
+    fn set_cancellation_token(&mut self, token: CancellationToken) {
+        CriticalPathScheduler::set_cancellation_token(self, token);
+    }
+
+    fn set_progress_reporter(&mut self, reporter: ProgressReporter) {
+        CriticalPathScheduler::set_progress_reporter(self, reporter);
+    }
+}
+
+/// Create the scheduler selected by `config.algorithm`.
+///
+/// `rollout_config` applies to `BoundedRollout` (defaulted when `None`) and
+/// `critical_path_config` to `CriticalPath` and `Hybrid`; each is ignored by
+/// the other algorithms. The critical path scheduler takes its default
+/// priority from `config.default_priority`, and in `Hybrid` its task ordering
+/// from `config.strategy`.
+#[allow(clippy::too_many_arguments)]
+pub fn build_scheduler(
+    tasks: Vec<Task>,
+    current_date: NaiveDate,
+    completed_task_ids: FxHashSet<String>,
+    config: SchedulingConfig,
+    rollout_config: Option<RolloutConfig>,
+    critical_path_config: Option<CriticalPathConfig>,
+    resource_config: Option<ResourceConfig>,
+    global_dns_periods: Vec<(NaiveDate, NaiveDate)>,
+) -> Result<Box<dyn Scheduler>, ScheduleFailure> {
+    let rollout_config = match config.algorithm {
+        SchedulingAlgorithm::ParallelSgs => None,
+        SchedulingAlgorithm::BoundedRollout => Some(rollout_config.unwrap_or_default()),
+        SchedulingAlgorithm::CriticalPath | SchedulingAlgorithm::Hybrid => {
+            let mut scheduler = CriticalPathScheduler::new(
+                tasks,
+                current_date,
+                completed_task_ids,
+                config.default_priority,
+                critical_path_config.unwrap_or_default(),
+                resource_config,
+                global_dns_periods,
+            )
+            .map_err(|e| ScheduleFailure::from(&e))?;
+            if config.algorithm == SchedulingAlgorithm::Hybrid {
+                scheduler
+                    .set_task_ordering(config)
+                    .map_err(|e| ScheduleFailure::from(&e))?;
+            }
+            return Ok(Box::new(scheduler));
+        }
+    };
+    let scheduler = ParallelScheduler::new(
+        tasks,
+        current_date,
+        completed_task_ids,
+        config,
+        rollout_config,
+        resource_config,
+        global_dns_periods,
+        None,
+        None,
+    )
+    .map_err(|e| ScheduleFailure::from(&e))?;
+    Ok(Box::new(scheduler))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::Dependency;
+
+    fn tasks() -> Vec<Task> {
+        vec![
+            Task {
+                id: "a".to_string(),
+                duration_days: 2.0,
+                resources: vec![("r1".to_string(), 1.0)],
+                ..Default::default()
+            },
+            Task {
+                id: "b".to_string(),
+                duration_days: 1.0,
+                resources: vec![("r1".to_string(), 1.0)],
+                dependencies: vec![Dependency {
+                    entity_id: "a".to_string(),
+                    ..Default::default()
+                }],
+                ..Default::default()
+            },
+        ]
+    }
+
+    #[test]
+    fn test_build_scheduler_selects_algorithm() {
+        let start = NaiveDate::from_ymd_opt(2025, 1, 1).unwrap();
+        for algorithm in [
+            SchedulingAlgorithm::ParallelSgs,
+            SchedulingAlgorithm::BoundedRollout,
+            SchedulingAlgorithm::CriticalPath,
+            SchedulingAlgorithm::Hybrid,
+        ] {
+            let config = SchedulingConfig {
+                algorithm,
+                ..Default::default()
+            };
+            let mut scheduler = build_scheduler(
+                tasks(),
+                start,
+                FxHashSet::default(),
+                config,
+                None,
+                None,
+                None,
+                Vec::new(),
+            )
+            .unwrap();
+            assert!(scheduler.get_metadata().is_none());
+
+            let result = scheduler.schedule().unwrap();
+            assert_eq!(result.scheduled_tasks.len(), 2);
+            assert_eq!(
+                scheduler.get_metadata().map(|m| m.algorithm.as_str()),
+                Some(algorithm.as_str())
+            );
+        }
+    }
+}
@@ -1,24 +1,92 @@
 //! Backward pass algorithm for deadline and priority propagation.
 
-use chrono::{Duration, NaiveDate};
+use chrono::NaiveDate;
 use rustc_hash::{FxHashMap, FxHashSet};
 use std::collections::VecDeque;
+use std::hash::Hash;
 
-use crate::models::Task;
+use crate::models::{Dependency, LagUnit, Task};
+use crate::validation::{offset_date, DateOverflow};
 
 /// Error types for backward pass processing.
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum BackwardPassError {
-    /// Circular dependency detected in task graph.
-    CircularDependency,
+    /// Circular dependency detected in task graph. Holds the task IDs on the
+    /// cycle, each depending on the next and the last depending on the first.
+    CircularDependency(Vec<String>),
+    /// A propagated date fell outside the supported date range.
+    DateOverflow(String),
+}
+
+impl BackwardPassError {
+    /// The offending dependency cycle, if this error describes one.
+    pub fn cycle(&self) -> Option<&[String]> {
+        match self {
+            BackwardPassError::CircularDependency(cycle) => Some(cycle),
+            BackwardPassError::DateOverflow(_) => None,
+        }
+    }
+}
+
+impl From<DateOverflow> for BackwardPassError {
+    fn from(err: DateOverflow) -> Self {
+        BackwardPassError::DateOverflow(err.to_string())
+    }
 }
 
 impl std::fmt::Display for BackwardPassError {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
-            BackwardPassError::CircularDependency => {
-                write!(f, "Circular dependency detected in task graph")
+            BackwardPassError::CircularDependency(cycle) => {
+                write!(
+                    f,
+                    "Circular dependency detected in task graph: {}",
+                    format_cycle(cycle)
+                )
             }
+            BackwardPassError::DateOverflow(message) => write!(f, "{}", message),
+        }
+    }
+}
+
+/// Render a dependency cycle as `a -> b -> c -> a`.
+pub fn format_cycle(cycle: &[String]) -> String {
+    let mut parts: Vec<&str> = cycle.iter().map(|s| s.as_str()).collect();
+    if let Some(&first) = parts.first() {
+        parts.push(first);
+    }
+    parts.join(" -> ")
+}
+
+/// Rotate a cycle so it starts at its smallest ID, for stable reporting.
+pub(crate) fn canonical_cycle(mut cycle: Vec<String>) -> Vec<String> {
+    if let Some(pos) = (0..cycle.len()).min_by_key(|&i| &cycle[i]) {
+        cycle.rotate_left(pos);
+    }
+    cycle
+}
+
+/// Follow `next` from `start` until a node repeats and return the loop.
+///
+/// `next` must always lead to another node that is itself on or leading to a
+/// cycle (e.g. a node left over after Kahn's algorithm), otherwise the walk
+/// ends early and the path walked so far is returned.
+pub(crate) fn trace_cycle<N: Copy + Eq + Hash>(
+    start: N,
+    mut next: impl FnMut(N) -> Option<N>,
+) -> Vec<N> {
+    let mut path: Vec<N> = Vec::new();
+    let mut position: FxHashMap<N, usize> = FxHashMap::default();
+    let mut current = start;
+    loop {
+        if let Some(&pos) = position.get(&current) {
+            return path.split_off(pos);
+        }
+        position.insert(current, path.len());
+        path.push(current);
+        match next(current) {
+            Some(node) => current = node,
+            None => return path,
         }
     }
 }
@@ -30,13 +98,57 @@ impl std::error::Error for BackwardPassError {}
 pub struct BackwardPassConfig {
     /// Default priority for tasks without explicit priority (0-100).
     pub default_priority: i32,
+    /// Multiplier applied to a priority each time it propagates one level upstream
+    /// (1.0 = no decay). Propagation never lowers a task's own priority.
+    pub priority_decay: f64,
+    /// Tighten deadlines of tasks that share a resource so their combined
+    /// workload fits before the deadlines, instead of assuming each runs alone.
+    pub contention_aware: bool,
+    /// Count durations in working days, skipping non-working periods, when
+    /// passing deadlines upstream. `None` subtracts raw calendar days.
+    pub calendar: Option<DeadlineCalendar>,
+    /// Non-working periods that working-day lags skip (usually the global DNS periods).
+    pub lag_calendar: Vec<(NaiveDate, NaiveDate)>,
+    /// Candidate resources of auto-assigned tasks, keyed by task ID. Under
+    /// `contention_aware`, such a task's work is split evenly over its
+    /// candidates; auto-assigned tasks without an entry are not stacked.
+    pub candidate_resources: FxHashMap<String, Vec<String>>,
 }
 
 impl Default for BackwardPassConfig {
     fn default() -> Self {
         Self {
             default_priority: 50,
+            priority_decay: 1.0,
+            contention_aware: false,
+            calendar: None,
+            lag_calendar: Vec::new(),
+            candidate_resources: FxHashMap::default(),
+        }
+    }
+}
+
+/// Non-working periods used by calendar-aware deadline propagation.
+#[derive(Debug, Clone, Default)]
+pub struct DeadlineCalendar {
+    /// Periods when nobody works.
+    pub global_dns_periods: Vec<(NaiveDate, NaiveDate)>,
+    /// Periods each resource is away, by resource name.
+    pub dns_periods: FxHashMap<String, Vec<(NaiveDate, NaiveDate)>>,
+}
+
+impl DeadlineCalendar {
+    /// Days a task cannot be worked: global DNS, the DNS periods of its
+    /// assigned resources, and its own blocked periods.
+    fn non_working(&self, task: &Task) -> Vec<(NaiveDate, NaiveDate)> {
+        let mut periods = self.global_dns_periods.clone();
+        for (resource, _) in &task.resources {
+            if let Some(resource_periods) = self.dns_periods.get(resource) {
+                periods.extend_from_slice(resource_periods);
+            }
         }
+        periods.extend_from_slice(&task.blocked_periods);
+        periods
     }
 }
 
@@ -47,20 +159,153 @@ pub struct BackwardPassResult {
     pub computed_deadlines: FxHashMap<String, NaiveDate>,
     /// Computed priorities for each task (effective priority after propagation).
     pub computed_priorities: FxHashMap<String, i32>,
+    /// Direct dependent each inherited deadline came from; tasks keeping
+    /// their own deadline (or one set by resource contention) are absent.
+    pub deadline_sources: FxHashMap<String, String>,
+    /// Direct dependent each inherited priority came from.
+    pub priority_sources: FxHashMap<String, String>,
+    /// Earliest feasible start of each task constrained by a `start_after`,
+    /// `start_on` or external date, its own or one of its dependencies'.
+    pub computed_earliest_starts: FxHashMap<String, NaiveDate>,
+}
+
+impl BackwardPassResult {
+    /// Tasks a deadline was passed down through, from the direct dependent to
+    /// the task that set it. Empty when the task's deadline is its own.
+    pub fn deadline_path(&self, task_id: &str) -> Vec<String> {
+        inheritance_path(&self.deadline_sources, task_id)
+    }
+
+    /// Tasks a priority was passed down through, like `deadline_path`.
+    pub fn priority_path(&self, task_id: &str) -> Vec<String> {
+        inheritance_path(&self.priority_sources, task_id)
+    }
+}
+
+fn inheritance_path(sources: &FxHashMap<String, String>, task_id: &str) -> Vec<String> {
+    let mut path = Vec::new();
+    let mut current = task_id;
+    while let Some(source) = sources.get(current) {
+        path.push(source.clone());
+        current = source;
+    }
+    path
+}
+
+/// Record `source` as where `task_id` inherited a value, replacing an earlier
+/// source when the new value is better or ties with a smaller ID.
+fn record_source(
+    sources: &mut FxHashMap<String, String>,
+    task_id: &str,
+    source: &str,
+    improved: bool,
+    tied: bool,
+) {
+    match sources.get_mut(task_id) {
+        Some(current) if improved || (tied && source < current.as_str()) => {
+            *current = source.to_string()
+        }
+        None if improved => {
+            sources.insert(task_id.to_string(), source.to_string());
+        }
+        _ => {}
+    }
 }
 
 /// Compute when a dependency must finish for its dependent to meet its deadline.
 ///
 /// If task B depends on task A (A blocks B), this computes A's deadline given B's.
 /// The dependency (A) must finish before the dependent (B) can start, accounting for lag.
-fn compute_dependency_deadline(
+/// With `non_working` periods, B's duration is counted in days outside them;
+/// a working-day lag skips the `lag_calendar` periods.
+pub(crate) fn compute_dependency_deadline(
     dependent_deadline: NaiveDate,
     dependent_duration_days: f64,
-    lag_days: f64,
-) -> NaiveDate {
+    dep: &Dependency,
+    non_working: Option<&[(NaiveDate, NaiveDate)]>,
+    lag_calendar: &[(NaiveDate, NaiveDate)],
+) -> Result<NaiveDate, DateOverflow> {
     // Ceiling ensures fractional days round up to whole days for scheduling
-    let total_days = (dependent_duration_days + lag_days).ceil() as i64;
-    dependent_deadline - Duration::days(total_days)
+    let total_days = (dependent_duration_days + dep.lag_days).ceil();
+    let work_days = dependent_duration_days.ceil();
+    // Checked first even with a calendar, which only moves the start earlier
+    let mut start = offset_date(dependent_deadline, -work_days)?;
+    if let Some(periods) = non_working {
+        start = dependent_deadline;
+        let mut remaining = work_days as i64;
+        while remaining > 0 {
+            start = start.pred_opt().ok_or(DateOverflow {
+                date: dependent_deadline,
+                days: -work_days,
+            })?;
+            if !periods.iter().any(|&(s, e)| s <= start && start <= e) {
+                remaining -= 1;
+            }
+        }
+    }
+    let lag_days = total_days - work_days;
+    match (dep.lag_unit, start.succ_opt()) {
+        (LagUnit::Working, Some(next)) if lag_days > 0.0 => {
+            Ok(LagUnit::Working.latest_end(next, lag_days, lag_calendar))
+        }
+        _ => offset_date(start, -lag_days),
+    }
+}
+
+/// Compute the earliest start forward through the dependency graph.
+///
+/// A task can start no earlier than its own `start_after`/`start_on` and the
+/// day after each constrained dependency could finish plus lag, assuming
+/// unlimited resources. External milestones finish on their date.
+fn propagate_earliest_starts(
+    tasks: &FxHashMap<&str, &Task>,
+    topo_order: &[String],
+    completed_task_ids: &FxHashSet<String>,
+    lag_calendar: &[(NaiveDate, NaiveDate)],
+) -> Result<FxHashMap<String, NaiveDate>, DateOverflow> {
+    let mut earliest: FxHashMap<String, NaiveDate> = FxHashMap::default();
+    // Dependencies come last in topological order, so walk it backwards
+    for task_id in topo_order.iter().rev() {
+        let Some(task) = tasks.get(task_id.as_str()) else {
+            continue;
+        };
+        if completed_task_ids.contains(task_id) {
+            continue;
+        }
+        let mut start = task.start_on.or(task.start_after);
+        for dep in &task.dependencies {
+            let Some(dep_task) = tasks.get(dep.entity_id.as_str()) else {
+                continue;
+            };
+            let dep_finish = match dep_task.external_date {
+                Some(external) => Some(external),
+                None => earliest
+                    .get(&dep.entity_id)
+                    .map(|&s| offset_date(s, dep_task.duration_days))
+                    .transpose()?,
+            };
+            let Some(dep_finish) = dep_finish else {
+                continue;
+            };
+            start = start.max(Some(dep.ready_date(dep_finish, lag_calendar)));
+        }
+        if task.start_on.is_some() {
+            start = task.start_on;
+        }
+        if let Some(start) = start {
+            earliest.insert(task_id.clone(), start);
+        }
+    }
+    Ok(earliest)
+}
+
+/// Attenuate a priority inherited across one dependency level.
+fn decay_priority(priority: i32, decay: f64) -> i32 {
+    if decay == 1.0 {
+        priority
+    } else {
+        (f64::from(priority) * decay).round() as i32
+    }
 }
 
 /// Perform topological sort of tasks using Kahn's algorithm.
@@ -105,7 +350,28 @@ fn topological_sort(tasks: &FxHashMap<&str, &Task>) -> Result<Vec<String>, Backw
     }
 
     if result.len() != tasks.len() {
-        return Err(BackwardPassError::CircularDependency);
+        // Every leftover task still has a leftover dependent; walking those
+        // edges must loop. Reverse so each task depends on the next.
+        let leftover = |id: &str| in_degree.get(id).is_some_and(|&d| d > 0);
+        let start = in_degree
+            .iter()
+            .filter(|(_, &d)| d > 0)
+            .map(|(&id, _)| id)
+            .min()
+            .unwrap_or_default();
+        let mut cycle = trace_cycle(start, |id| {
+            tasks
+                .values()
+                .filter(|t| {
+                    leftover(t.id.as_str()) && t.dependencies.iter().any(|dep| dep.entity_id == id)
+                })
+                .map(|t| t.id.as_str())
+                .min()
+        });
+        cycle.reverse();
+        return Err(BackwardPassError::CircularDependency(canonical_cycle(
+            cycle.into_iter().map(str::to_string).collect(),
+        )));
     }
 
     Ok(result)
@@ -117,14 +383,20 @@ fn calculate_deadlines_and_priorities(
     topo_order: &[String],
     completed_task_ids: &FxHashSet<String>,
     config: &BackwardPassConfig,
-) -> BackwardPassResult {
+) -> Result<BackwardPassResult, DateOverflow> {
     let mut deadlines: FxHashMap<String, NaiveDate> = FxHashMap::default();
     let mut priorities: FxHashMap<String, i32> = FxHashMap::default();
+    let mut deadline_sources: FxHashMap<String, String> = FxHashMap::default();
+    let mut priority_sources: FxHashMap<String, String> = FxHashMap::default();
 
-    // Initialize with explicit deadlines
+    // Initialize with explicit deadlines; external milestones must be met on their date
     for (&task_id, task) in tasks {
-        if let Some(end_before) = task.end_before {
-            deadlines.insert(task_id.to_string(), end_before);
+        let deadline = match (task.tightest_deadline(), task.external_date) {
+            (Some(end_before), Some(external)) => Some(end_before.min(external)),
+            (end_before, external) => end_before.or(external),
+        };
+        if let Some(deadline) = deadline {
+            deadlines.insert(task_id.to_string(), deadline);
         }
     }
 
@@ -134,17 +406,53 @@ fn calculate_deadlines_and_priorities(
         priorities.insert(task_id.to_string(), priority);
     }
 
-    // Propagate deadlines backwards and priorities forwards through dependency graph
+    propagate_deadlines(
+        tasks,
+        topo_order,
+        completed_task_ids,
+        config,
+        &mut deadlines,
+        &mut deadline_sources,
+    )?;
+    // Tightened deadlines flow upstream and can reorder other resources'
+    // stacks, so stack again until nothing moves. Deadlines only move
+    // earlier; the bound guards against creeping forever.
+    let max_rounds = if config.contention_aware {
+        tasks.len() + 1
+    } else {
+        0
+    };
+    for _ in 0..max_rounds {
+        if !apply_resource_contention(
+            tasks,
+            completed_task_ids,
+            &config.candidate_resources,
+            &mut deadlines,
+            &mut deadline_sources,
+        )? {
+            break;
+        }
+        propagate_deadlines(
+            tasks,
+            topo_order,
+            completed_task_ids,
+            config,
+            &mut deadlines,
+            &mut deadline_sources,
+        )?;
+    }
+
+    // Propagate priorities forwards to upstream dependencies
     for task_id in topo_order {
         let Some(task) = tasks.get(task_id.as_str()) else {
             continue;
         };
 
-        let task_deadline = deadlines.get(task_id).copied();
         let task_priority = priorities
             .get(task_id)
             .copied()
             .unwrap_or(config.default_priority);
+        let inherited = decay_priority(task_priority, config.priority_decay);
 
         for dep in &task.dependencies {
             let dep_id = &dep.entity_id;
@@ -154,29 +462,153 @@ fn calculate_deadlines_and_priorities(
                 continue;
             }
 
-            // Propagate priority (max of current and dependent's priority)
-            priorities
-                .entry(dep_id.clone())
-                .and_modify(|p| *p = (*p).max(task_priority))
-                .or_insert(task_priority);
-
-            // Propagate deadline if this task has one
-            if let Some(deadline) = task_deadline {
-                let dep_deadline =
-                    compute_dependency_deadline(deadline, task.duration_days, dep.lag_days);
-
-                deadlines
-                    .entry(dep_id.clone())
-                    .and_modify(|d| *d = (*d).min(dep_deadline))
-                    .or_insert(dep_deadline);
-            }
+            // Max of current and dependent's decayed priority
+            let current = priorities.entry(dep_id.clone()).or_insert(inherited);
+            record_source(
+                &mut priority_sources,
+                dep_id,
+                task_id,
+                inherited > *current,
+                inherited == *current,
+            );
+            *current = (*current).max(inherited);
         }
     }
 
-    BackwardPassResult {
+    Ok(BackwardPassResult {
         computed_deadlines: deadlines,
         computed_priorities: priorities,
+        deadline_sources,
+        priority_sources,
+        computed_earliest_starts: propagate_earliest_starts(
+            tasks,
+            topo_order,
+            completed_task_ids,
+            &config.lag_calendar,
+        )?,
+    })
+}
+
+/// Propagate deadlines backwards through the dependency graph, keeping the
+/// tightest deadline seen for each task.
+fn propagate_deadlines(
+    tasks: &FxHashMap<&str, &Task>,
+    topo_order: &[String],
+    completed_task_ids: &FxHashSet<String>,
+    config: &BackwardPassConfig,
+    deadlines: &mut FxHashMap<String, NaiveDate>,
+    sources: &mut FxHashMap<String, String>,
+) -> Result<(), DateOverflow> {
+    for task_id in topo_order {
+        let Some(task) = tasks.get(task_id.as_str()) else {
+            continue;
+        };
+        let Some(deadline) = deadlines.get(task_id).copied() else {
+            continue;
+        };
+        let duration = if task.external_date.is_some() {
+            0.0
+        } else {
+            task.duration_days
+        };
+        let non_working = config.calendar.as_ref().map(|c| c.non_working(task));
+
+        for dep in &task.dependencies {
+            let dep_id = &dep.entity_id;
+
+            // Skip dependencies not in our task list or already completed
+            if !tasks.contains_key(dep_id.as_str()) || completed_task_ids.contains(dep_id) {
+                continue;
+            }
+
+            let dep_deadline = compute_dependency_deadline(
+                deadline,
+                duration,
+                dep,
+                non_working.as_deref(),
+                &config.lag_calendar,
+            )?;
+            let current = deadlines.get(dep_id).copied();
+            record_source(
+                sources,
+                dep_id,
+                task_id,
+                current.is_none_or(|d| dep_deadline < d),
+                current == Some(dep_deadline),
+            );
+            deadlines
+                .entry(dep_id.clone())
+                .and_modify(|d| *d = (*d).min(dep_deadline))
+                .or_insert(dep_deadline);
+        }
+    }
+    Ok(())
+}
+
+/// Tighten deadlines of tasks that compete for the same resource.
+///
+/// For each resource, its outstanding deadline-bearing work is stacked
+/// backwards from the latest deadline (allocation-weighted), so a task
+/// finishing earlier in that stack must be done before the work queued after
+/// it starts. Auto-assigned tasks add an even share of their work to each of
+/// their `candidate_resources`. Returns true if any deadline moved.
+fn apply_resource_contention(
+    tasks: &FxHashMap<&str, &Task>,
+    completed_task_ids: &FxHashSet<String>,
+    candidate_resources: &FxHashMap<String, Vec<String>>,
+    deadlines: &mut FxHashMap<String, NaiveDate>,
+    sources: &mut FxHashMap<String, String>,
+) -> Result<bool, DateOverflow> {
+    let mut workload: FxHashMap<&str, Vec<(NaiveDate, f64, &str)>> = FxHashMap::default();
+    for (&task_id, task) in tasks {
+        if completed_task_ids.contains(task_id) || task.external_date.is_some() {
+            continue;
+        }
+        let Some(&deadline) = deadlines.get(task_id) else {
+            continue;
+        };
+        for (resource, allocation) in &task.resources {
+            workload.entry(resource.as_str()).or_default().push((
+                deadline,
+                task.duration_days * allocation,
+                task_id,
+            ));
+        }
+        if !task.resources.is_empty() {
+            continue;
+        }
+        let candidates = candidate_resources
+            .get(task_id)
+            .map_or(&[][..], Vec::as_slice);
+        for resource in candidates {
+            workload.entry(resource.as_str()).or_default().push((
+                deadline,
+                task.duration_days / candidates.len() as f64,
+                task_id,
+            ));
+        }
     }
+
+    let mut changed = false;
+    for queue in workload.values_mut() {
+        // Latest deadline first; ties broken by ID for determinism
+        queue.sort_by(|a, b| b.0.cmp(&a.0).then_with(|| b.2.cmp(a.2)));
+
+        let mut available_until: Option<NaiveDate> = None;
+        for &(deadline, work, task_id) in queue.iter() {
+            let finish = available_until.map_or(deadline, |until| until.min(deadline));
+            available_until = Some(offset_date(finish, -work)?);
+
+            if let Some(current) = deadlines.get_mut(task_id) {
+                if finish < *current {
+                    *current = finish;
+                    sources.remove(task_id);
+                    changed = true;
+                }
+            }
+        }
+    }
+    Ok(changed)
 }
 
 /// Run the backward pass algorithm to compute deadlines and priorities.
@@ -184,6 +616,7 @@ fn calculate_deadlines_and_priorities(
 /// This algorithm:
 /// 1. Propagates deadlines backward through dependencies
 /// 2. Propagates priorities forward to upstream dependencies
+/// 3. Propagates earliest starts forward to downstream dependents
 ///
 /// # Arguments
 /// * `tasks` - Slice of tasks to process
@@ -191,8 +624,10 @@ fn calculate_deadlines_and_priorities(
 /// * `config` - Algorithm configuration
 ///
 /// # Returns
-/// * `Ok(BackwardPassResult)` with computed deadlines and priorities
-/// * `Err(BackwardPassError::CircularDependency)` if the task graph has cycles
+/// * `Ok(BackwardPassResult)` with computed deadlines, priorities and earliest starts
+/// * `Err(BackwardPassError::CircularDependency)` with the cycle if the task graph has one
+/// * `Err(BackwardPassError::DateOverflow)` if a propagated date would leave the
+///   supported date range
 pub fn backward_pass(
     tasks: &[Task],
     completed_task_ids: &FxHashSet<String>,
@@ -205,7 +640,7 @@ pub fn backward_pass(
         &topo_order,
         completed_task_ids,
         config,
-    ))
+    )?)
 }
 
 #[cfg(test)]
@@ -223,20 +658,17 @@ mod tests {
         Task {
             id: id.to_string(),
             duration_days: duration,
-            resources: vec![],
             dependencies: deps
                 .into_iter()
                 .map(|(dep_id, lag)| Dependency {
                     entity_id: dep_id.to_string(),
                     lag_days: lag,
+                    ..Default::default()
                 })
                 .collect(),
-            start_after: None,
             end_before,
-            start_on: None,
-            end_on: None,
-            resource_spec: None,
             priority,
+            ..Default::default()
         }
     }
 
@@ -315,6 +747,44 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_external_date_is_deadline_source() {
+        let mut milestone = make_task("m", 10.0, vec![("a", 2.0)], None, Some(50));
+        milestone.external_date = NaiveDate::from_ymd_opt(2025, 1, 20);
+        let tasks = vec![make_task("a", 5.0, vec![], None, Some(50)), milestone];
+        let result = backward_pass(
+            &tasks,
+            &FxHashSet::default(),
+            &BackwardPassConfig::default(),
+        )
+        .unwrap();
+
+        // Milestones take no time, so a's deadline = Jan 20 - lag = Jan 18
+        assert_eq!(
+            result.computed_deadlines.get("a"),
+            NaiveDate::from_ymd_opt(2025, 1, 18).as_ref()
+        );
+    }
+
+    #[test]
+    fn test_calendar_aware_deadlines_skip_dns() {
+        let d = |day| NaiveDate::from_ymd_opt(2025, 1, day).unwrap();
+        let mut b = make_task("b", 3.0, vec![("a", 1.0)], Some(d(20)), None);
+        b.resources = vec![("alice".to_string(), 1.0)];
+        let tasks = vec![make_task("a", 2.0, vec![], None, None), b];
+        let config = BackwardPassConfig {
+            calendar: Some(DeadlineCalendar {
+                global_dns_periods: vec![(d(18), d(18))],
+                dns_periods: FxHashMap::from_iter([("alice".to_string(), vec![(d(15), d(16))])]),
+            }),
+            ..Default::default()
+        };
+        let result = backward_pass(&tasks, &FxHashSet::default(), &config).unwrap();
+
+        // b works Jan 19, 17 and 14 around the DNS days, then one day of lag
+        assert_eq!(result.computed_deadlines.get("a"), Some(&d(13)));
+    }
+
     #[test]
     fn test_priority_propagation() {
         // b (priority 80) depends on a (priority 50) -> a should get priority 80
@@ -333,6 +803,35 @@ mod tests {
         assert_eq!(result.computed_priorities.get("b"), Some(&80));
     }
 
+    #[test]
+    fn test_provenance_traces_inherited_values() {
+        let deadline = NaiveDate::from_ymd_opt(2025, 1, 30).unwrap();
+        let tasks = vec![
+            make_task("trivial", 1.0, vec![], None, Some(10)),
+            make_task("build", 3.0, vec![("trivial", 0.0)], None, Some(50)),
+            make_task(
+                "release",
+                1.0,
+                vec![("build", 0.0)],
+                Some(deadline),
+                Some(90),
+            ),
+            make_task("docs", 1.0, vec![("trivial", 0.0)], None, Some(95)),
+        ];
+        let result = backward_pass(
+            &tasks,
+            &FxHashSet::default(),
+            &BackwardPassConfig::default(),
+        )
+        .unwrap();
+
+        assert_eq!(result.deadline_path("trivial"), ["build", "release"]);
+        assert_eq!(result.priority_path("trivial"), ["docs"]);
+        assert_eq!(result.priority_path("build"), ["release"]);
+        assert!(result.deadline_path("release").is_empty());
+        assert!(result.priority_path("docs").is_empty());
+    }
+
     #[test]
     fn test_diamond_dependency() {
         // d depends on b and c, which both depend on a
@@ -382,7 +881,12 @@ mod tests {
             &BackwardPassConfig::default(),
         );
 
-        assert_eq!(result, Err(BackwardPassError::CircularDependency));
+        let err = result.unwrap_err();
+        assert_eq!(err.cycle(), Some(&["a".to_string(), "b".to_string()][..]));
+        assert_eq!(
+            err.to_string(),
+            "Circular dependency detected in task graph: a -> b -> a"
+        );
     }
 
     #[test]
@@ -398,7 +902,191 @@ mod tests {
 
         // a should not inherit b's priority or get a propagated deadline
         assert_eq!(result.computed_priorities.get("a"), Some(&50)); // Original, not 80
-        assert!(result.computed_deadlines.get("a").is_none()); // No propagated deadline
+        assert!(!result.computed_deadlines.contains_key("a")); // No propagated deadline
+    }
+
+    #[test]
+    fn test_earliest_starts_propagate_downstream() {
+        let d = |day| NaiveDate::from_ymd_opt(2025, 6, day).unwrap();
+        let mut a = make_task("a", 4.0, vec![], None, None);
+        a.start_after = Some(d(1));
+        let mut c = make_task("c", 1.0, vec![("b", 0.0)], None, None);
+        c.start_after = Some(d(20));
+        let tasks = vec![
+            a,
+            make_task("b", 2.5, vec![("a", 1.0)], None, None),
+            c,
+            make_task("free", 1.0, vec![], None, None),
+        ];
+        let result = backward_pass(
+            &tasks,
+            &FxHashSet::default(),
+            &BackwardPassConfig::default(),
+        )
+        .unwrap();
+
+        let starts = &result.computed_earliest_starts;
+        assert_eq!(starts.get("a"), Some(&d(1)));
+        // a ends June 5; b may start the day after plus one day of lag
+        assert_eq!(starts.get("b"), Some(&d(7)));
+        // b could end June 10, but c's own start_after is later
+        assert_eq!(starts.get("c"), Some(&d(20)));
+        assert!(!starts.contains_key("free"));
+    }
+
+    #[test]
+    fn test_priority_decay() {
+        // c (P90) <- b <- a: each level upstream loses 10%
+        let tasks = vec![
+            make_task("a", 1.0, vec![], None, Some(10)),
+            make_task("b", 1.0, vec![("a", 0.0)], None, Some(10)),
+            make_task("c", 1.0, vec![("b", 0.0)], None, Some(90)),
+        ];
+        let config = BackwardPassConfig {
+            priority_decay: 0.9,
+            ..Default::default()
+        };
+        let result = backward_pass(&tasks, &FxHashSet::default(), &config).unwrap();
+
+        assert_eq!(result.computed_priorities.get("c"), Some(&90));
+        assert_eq!(result.computed_priorities.get("b"), Some(&81));
+        assert_eq!(result.computed_priorities.get("a"), Some(&73));
+    }
+
+    #[test]
+    fn test_out_of_range_dates_are_errors() {
+        let near_min = NaiveDate::MIN
+            .checked_add_days(chrono::Days::new(5))
+            .unwrap();
+        let near_max = NaiveDate::MAX
+            .checked_sub_days(chrono::Days::new(5))
+            .unwrap();
+        let plain = BackwardPassConfig::default();
+        let calendar = BackwardPassConfig {
+            calendar: Some(DeadlineCalendar::default()),
+            ..Default::default()
+        };
+        let contention = BackwardPassConfig {
+            contention_aware: true,
+            ..Default::default()
+        };
+        let fails = |tasks: &[Task], config: &BackwardPassConfig| {
+            let result = backward_pass(tasks, &FxHashSet::default(), config);
+            matches!(result, Err(BackwardPassError::DateOverflow(_)))
+        };
+
+        for duration in [f64::INFINITY, 1e18, 10.0] {
+            // Deadline propagation, with and without a calendar
+            let chain = vec![
+                make_task("a", 1.0, vec![], None, None),
+                make_task("t", duration, vec![("a", 0.0)], Some(near_min), None),
+            ];
+            assert!(fails(&chain, &plain));
+            assert!(fails(&chain, &calendar));
+
+            // Resource stacking
+            let mut alone = make_task("b", duration, vec![], Some(near_min), None);
+            alone.resources = vec![("alice".to_string(), 1.0)];
+            assert!(!fails(&[alone.clone()], &plain));
+            assert!(fails(&[alone], &contention));
+
+            // Earliest starts
+            let mut late = make_task("a", duration, vec![], None, None);
+            late.start_after = Some(near_max);
+            let chain = vec![late, make_task("b", 1.0, vec![("a", 0.0)], None, None)];
+            assert!(fails(&chain, &plain));
+        }
+    }
+
+    #[test]
+    fn test_contention_aware_deadlines() {
+        // a and b both feed t and both need alice for 5 days
+        let deadline = NaiveDate::from_ymd_opt(2025, 1, 20).unwrap();
+        let mut a = make_task("a", 5.0, vec![("p", 0.0)], None, None);
+        a.resources = vec![("alice".to_string(), 1.0)];
+        let mut b = make_task("b", 5.0, vec![], None, None);
+        b.resources = vec![("alice".to_string(), 1.0)];
+        let tasks = vec![
+            make_task("p", 2.0, vec![], None, None),
+            a,
+            b,
+            make_task("t", 0.0, vec![("a", 0.0), ("b", 0.0)], Some(deadline), None),
+        ];
+
+        let plain = backward_pass(
+            &tasks,
+            &FxHashSet::default(),
+            &BackwardPassConfig::default(),
+        )
+        .unwrap();
+        assert_eq!(plain.computed_deadlines.get("a"), Some(&deadline));
+        assert_eq!(plain.computed_deadlines.get("b"), Some(&deadline));
+
+        let config = BackwardPassConfig {
+            contention_aware: true,
+            ..Default::default()
+        };
+        let result = backward_pass(&tasks, &FxHashSet::default(), &config).unwrap();
+        let date = |day| NaiveDate::from_ymd_opt(2025, 1, day);
+
+        // b keeps the slot ending on the deadline; a must be done 5 days earlier
+        assert_eq!(result.computed_deadlines.get("b"), Some(&deadline));
+        assert_eq!(result.computed_deadlines.get("a"), date(15).as_ref());
+        // The tightened deadline flows on to a's prerequisites
+        assert_eq!(result.computed_deadlines.get("p"), date(10).as_ref());
+    }
+
+    #[test]
+    fn test_contention_restacks_after_propagation() {
+        // t needs a and b from alice; a needs x, which shares bob with y
+        let date = |day| NaiveDate::from_ymd_opt(2025, 1, day).unwrap();
+        let on = |mut task: Task, resource: &str| {
+            task.resources = vec![(resource.to_string(), 1.0)];
+            task
+        };
+        let tasks = vec![
+            on(make_task("x", 2.0, vec![], None, None), "bob"),
+            on(make_task("y", 5.0, vec![], Some(date(12)), None), "bob"),
+            on(make_task("a", 5.0, vec![("x", 0.0)], None, None), "alice"),
+            on(make_task("b", 5.0, vec![], None, None), "alice"),
+            make_task("t", 0.0, vec![("a", 0.0), ("b", 0.0)], Some(date(20)), None),
+        ];
+        let config = BackwardPassConfig {
+            contention_aware: true,
+            ..Default::default()
+        };
+        let result = backward_pass(&tasks, &FxHashSet::default(), &config).unwrap();
+
+        // Alice pulls a to the 15th, so x is due the 10th and now queues
+        // behind y's five days on bob
+        assert_eq!(result.computed_deadlines.get("a"), Some(&date(15)));
+        assert_eq!(result.computed_deadlines.get("y"), Some(&date(12)));
+        assert_eq!(result.computed_deadlines.get("x"), Some(&date(7)));
+    }
+
+    #[test]
+    fn test_contention_spreads_auto_assigned_work() {
+        let deadline = NaiveDate::from_ymd_opt(2025, 1, 20).unwrap();
+        let mut a = make_task("a", 4.0, vec![], Some(deadline), None);
+        a.resources = vec![("alice".to_string(), 1.0)];
+        let mut b = make_task("b", 4.0, vec![], Some(deadline), None);
+        b.resource_spec = Some("alice|bob".to_string());
+        let config = BackwardPassConfig {
+            contention_aware: true,
+            candidate_resources: FxHashMap::from_iter([(
+                "b".to_string(),
+                vec!["alice".to_string(), "bob".to_string()],
+            )]),
+            ..Default::default()
+        };
+        let result = backward_pass(&[a, b], &FxHashSet::default(), &config).unwrap();
+
+        // b keeps the deadline; half its work on alice pushes a back 2 days
+        assert_eq!(result.computed_deadlines.get("b"), Some(&deadline));
+        assert_eq!(
+            result.computed_deadlines.get("a"),
+            NaiveDate::from_ymd_opt(2025, 1, 18).as_ref()
+        );
     }
 
     #[test]
@@ -406,6 +1094,7 @@ mod tests {
         let tasks = vec![make_task("a", 5.0, vec![], None, None)]; // No explicit priority
         let config = BackwardPassConfig {
             default_priority: 75,
+            ..Default::default()
         };
         let result = backward_pass(&tasks, &FxHashSet::default(), &config).unwrap();
 
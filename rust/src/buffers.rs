@@ -0,0 +1,410 @@
+//! Critical chain (CCPM) buffer calculation.
+//!
+//! Given a finished schedule, the critical chain leading to a target is traced
+//! back through both dependencies and resource hand-offs. A project buffer is
+//! placed after the target, and a feeding buffer is placed wherever a
+//! non-chain dependency merges into the chain. Buffers are sized as a fraction
+//! of the total duration of the chain they protect.
+
+use chrono::{Days, NaiveDate};
+use pyo3::prelude::*;
+use rustc_hash::{FxHashMap, FxHashSet};
+use thiserror::Error;
+
+use crate::models::{AlgorithmResult, ScheduledTask, Task};
+use crate::validation::MAX_SCHEDULE_DATE;
+
+/// Errors from buffer calculation.
+#[derive(Error, Debug, Clone, PartialEq)]
+pub enum BufferError {
+    #[error("buffer_ratio must be a finite number >= 0, got {0}")]
+    InvalidRatio(f64),
+    #[error("Buffer {0} falls outside the supported date range")]
+    DateOverflow(String),
+}
+
+/// A project or feeding buffer protecting the end of a chain of tasks.
+#[pyclass]
+#[derive(Clone, Debug, PartialEq)]
+pub struct Buffer {
+    /// Pseudo-task ID, e.g. "launch__project_buffer".
+    #[pyo3(get)]
+    pub id: String,
+    /// "project" or "feeding".
+    #[pyo3(get)]
+    pub kind: String,
+    /// Protected chain, in execution order.
+    #[pyo3(get)]
+    pub chain: Vec<String>,
+    /// Buffer length in days.
+    #[pyo3(get)]
+    pub size_days: f64,
+    #[pyo3(get)]
+    pub start_date: NaiveDate,
+    #[pyo3(get)]
+    pub end_date: NaiveDate,
+    /// End date of the chain's last task when the buffer was computed.
+    #[pyo3(get)]
+    pub baseline_end: NaiveDate,
+}
+
+#[pymethods]
+impl Buffer {
+    /// The task whose slip consumes this buffer (last task of the chain).
+    #[getter]
+    pub fn protected_task(&self) -> Option<String> {
+        self.chain.last().cloned()
+    }
+
+    fn __repr__(&self) -> String {
+        format!(
+            "Buffer(id={:?}, kind={:?}, size_days={}, chain={})",
+            self.id,
+            self.kind,
+            self.size_days,
+            self.chain.len()
+        )
+    }
+}
+
+/// How much of a buffer a rescheduled plan has used up.
+#[pyclass]
+#[derive(Clone, Debug, PartialEq)]
+pub struct BufferStatus {
+    #[pyo3(get)]
+    pub buffer_id: String,
+    #[pyo3(get)]
+    pub size_days: f64,
+    /// Days the protected task now finishes after its baseline end (never negative).
+    #[pyo3(get)]
+    pub consumed_days: f64,
+    /// `consumed_days / size_days`; above 1.0 the buffer is exhausted.
+    #[pyo3(get)]
+    pub consumed_fraction: f64,
+}
+
+#[pymethods]
+impl BufferStatus {
+    fn __repr__(&self) -> String {
+        format!(
+            "BufferStatus(buffer_id={:?}, consumed_days={}, consumed_fraction={:.2})",
+            self.buffer_id, self.consumed_days, self.consumed_fraction
+        )
+    }
+}
+
+/// Lookup tables shared by chain tracing.
+struct ChainContext<'a> {
+    scheduled: FxHashMap<&'a str, &'a ScheduledTask>,
+    tasks: FxHashMap<&'a str, &'a Task>,
+}
+
+impl<'a> ChainContext<'a> {
+    fn new(result: &'a AlgorithmResult, tasks: &'a [Task]) -> Self {
+        Self {
+            scheduled: result
+                .scheduled_tasks
+                .iter()
+                .map(|t| (t.task_id.as_str(), t))
+                .collect(),
+            tasks: tasks.iter().map(|t| (t.id.as_str(), t)).collect(),
+        }
+    }
+
+    /// Walk back from `end_id`, at each step taking the latest-finishing
+    /// predecessor: a dependency, or an earlier task on a shared resource.
+    /// Tasks in `exclude` are never entered. Returns the chain in execution order.
+    fn trace(&self, end_id: &'a str, exclude: &FxHashSet<&str>) -> Vec<String> {
+        let mut chain: Vec<&str> = Vec::new();
+        let mut visited: FxHashSet<&str> = FxHashSet::default();
+        let mut current = Some(end_id);
+
+        while let Some(id) = current {
+            let Some(&scheduled) = self.scheduled.get(id) else {
+                break;
+            };
+            chain.push(id);
+            visited.insert(id);
+
+            let dependencies = self
+                .tasks
+                .get(id)
+                .into_iter()
+                .flat_map(|t| t.dependencies.iter())
+                .filter_map(|dep| self.scheduled.get(dep.entity_id.as_str()).copied());
+            let resource_predecessors = self.scheduled.values().copied().filter(|other| {
+                other.end_date < scheduled.start_date
+                    && other
+                        .resources
+                        .iter()
+                        .any(|r| scheduled.resources.contains(r))
+            });
+
+            current = dependencies
+                .chain(resource_predecessors)
+                .filter(|t| {
+                    let other = t.task_id.as_str();
+                    !visited.contains(other) && !exclude.contains(other)
+                })
+                .max_by(|a, b| {
+                    a.end_date
+                        .cmp(&b.end_date)
+                        .then_with(|| b.task_id.cmp(&a.task_id))
+                })
+                .map(|t| t.task_id.as_str());
+        }
+
+        chain.reverse();
+        chain.into_iter().map(str::to_string).collect()
+    }
+
+    fn total_duration(&self, chain: &[String]) -> f64 {
+        chain
+            .iter()
+            .filter_map(|id| self.scheduled.get(id.as_str()))
+            .map(|t| t.duration_days)
+            .sum()
+    }
+}
+
+fn days(size: f64) -> Days {
+    Days::new(size.max(0.0).ceil() as u64)
+}
+
+/// `start` plus `size` days, or `None` past [`MAX_SCHEDULE_DATE`].
+fn buffer_end(start: NaiveDate, size: f64) -> Option<NaiveDate> {
+    start
+        .checked_add_days(days(size))
+        .filter(|&end| end <= MAX_SCHEDULE_DATE)
+}
+
+/// Compute the project buffer for `target_id` and feeding buffers for every
+/// non-chain dependency that merges into its critical chain.
+///
+/// Each buffer is `buffer_ratio` times the summed duration of its chain.
+/// Returns an empty list if `target_id` is not in the schedule. Fails if
+/// `buffer_ratio` is negative or not finite, or a buffer would fall outside
+/// the supported date range.
+pub fn compute_buffers(
+    result: &AlgorithmResult,
+    tasks: &[Task],
+    target_id: &str,
+    buffer_ratio: f64,
+) -> Result<Vec<Buffer>, BufferError> {
+    if !buffer_ratio.is_finite() || buffer_ratio < 0.0 {
+        return Err(BufferError::InvalidRatio(buffer_ratio));
+    }
+    let ctx = ChainContext::new(result, tasks);
+    let Some(&target) = ctx.scheduled.get(target_id) else {
+        return Ok(Vec::new());
+    };
+
+    let critical_chain = ctx.trace(target_id, &FxHashSet::default());
+    let on_chain: FxHashSet<&str> = critical_chain.iter().map(|s| s.as_str()).collect();
+
+    let size = ctx.total_duration(&critical_chain) * buffer_ratio;
+    let id = format!("{}__project_buffer", target_id);
+    let Some((start, end)) = target
+        .end_date
+        .succ_opt()
+        .and_then(|start| Some((start, buffer_end(start, size)?)))
+    else {
+        return Err(BufferError::DateOverflow(id));
+    };
+    let mut buffers = vec![Buffer {
+        id,
+        kind: "project".to_string(),
+        chain: critical_chain.clone(),
+        size_days: size,
+        start_date: start,
+        end_date: end,
+        baseline_end: target.end_date,
+    }];
+
+    let mut fed: FxHashSet<&str> = FxHashSet::default();
+    for chain_id in &critical_chain {
+        let merge_point = ctx.scheduled[chain_id.as_str()];
+        let Some(task) = ctx.tasks.get(chain_id.as_str()) else {
+            continue;
+        };
+        for dep in &task.dependencies {
+            let feeder = dep.entity_id.as_str();
+            let Some(&feeder_task) = ctx.scheduled.get(feeder) else {
+                continue;
+            };
+            if on_chain.contains(feeder) || !fed.insert(feeder) {
+                continue;
+            }
+
+            let chain = ctx.trace(feeder, &on_chain);
+            let size = ctx.total_duration(&chain) * buffer_ratio;
+            let id = format!("{}__feeding_buffer", feeder);
+            let Some((start, end)) = merge_point
+                .start_date
+                .pred_opt()
+                .and_then(|end| Some((end.checked_sub_days(days(size))?, end)))
+            else {
+                return Err(BufferError::DateOverflow(id));
+            };
+            buffers.push(Buffer {
+                id,
+                kind: "feeding".to_string(),
+                chain,
+                size_days: size,
+                start_date: start,
+                end_date: end,
+                baseline_end: feeder_task.end_date,
+            });
+        }
+    }
+
+    Ok(buffers)
+}
+
+/// Append a zero-resource pseudo-task for each buffer to the schedule.
+pub fn insert_buffer_tasks(result: &mut AlgorithmResult, buffers: &[Buffer]) {
+    result
+        .scheduled_tasks
+        .extend(buffers.iter().map(|buffer| ScheduledTask {
+            task_id: buffer.id.clone(),
+            start_date: buffer.start_date,
+            end_date: buffer.end_date,
+            duration_days: buffer.size_days,
+            ..Default::default()
+        }));
+}
+
+/// Measure how much of each buffer a rescheduled plan consumes.
+///
+/// Consumption is how far the buffer's protected task now finishes past its
+/// baseline end. Buffers whose protected task is missing from `result` are skipped.
+pub fn buffer_consumption(buffers: &[Buffer], result: &AlgorithmResult) -> Vec<BufferStatus> {
+    let ends: FxHashMap<&str, NaiveDate> = result
+        .scheduled_tasks
+        .iter()
+        .map(|t| (t.task_id.as_str(), t.end_date))
+        .collect();
+
+    buffers
+        .iter()
+        .filter_map(|buffer| {
+            let end = *ends.get(buffer.chain.last()?.as_str())?;
+            let consumed = (end - buffer.baseline_end).num_days().max(0) as f64;
+            let fraction = if buffer.size_days > 0.0 {
+                consumed / buffer.size_days
+            } else if consumed > 0.0 {
+                f64::INFINITY
+            } else {
+                0.0
+            };
+            Some(BufferStatus {
+                buffer_id: buffer.id.clone(),
+                size_days: buffer.size_days,
+                consumed_days: consumed,
+                consumed_fraction: fraction,
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::Dependency;
+
+    fn d(year: i32, month: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(year, month, day).unwrap()
+    }
+
+    fn task(id: &str, deps: &[&str]) -> Task {
+        Task {
+            id: id.to_string(),
+            dependencies: deps
+                .iter()
+                .map(|dep| Dependency {
+                    entity_id: dep.to_string(),
+                    ..Default::default()
+                })
+                .collect(),
+            ..Default::default()
+        }
+    }
+
+    fn scheduled(id: &str, start: NaiveDate, days: u64, resource: &str) -> ScheduledTask {
+        ScheduledTask {
+            task_id: id.to_string(),
+            start_date: start,
+            end_date: start + Days::new(days),
+            duration_days: days as f64,
+            resources: vec![resource.to_string()],
+            ..Default::default()
+        }
+    }
+
+    /// a (alice, 4d) -> c (alice, 2d); b (bob, 2d) feeds c
+    fn plan() -> (Vec<Task>, AlgorithmResult) {
+        let tasks = vec![task("a", &[]), task("b", &[]), task("c", &["a", "b"])];
+        let result = AlgorithmResult {
+            scheduled_tasks: vec![
+                scheduled("a", d(2025, 1, 1), 4, "alice"),
+                scheduled("b", d(2025, 1, 1), 2, "bob"),
+                scheduled("c", d(2025, 1, 6), 2, "alice"),
+            ],
+            ..Default::default()
+        };
+        (tasks, result)
+    }
+
+    #[test]
+    fn test_project_and_feeding_buffers() {
+        let (tasks, result) = plan();
+        let buffers = compute_buffers(&result, &tasks, "c", 0.5).unwrap();
+        assert_eq!(buffers.len(), 2);
+
+        let project = &buffers[0];
+        assert_eq!(project.kind, "project");
+        assert_eq!(project.chain, vec!["a", "c"]);
+        assert_eq!(project.size_days, 3.0);
+        assert_eq!(project.start_date, d(2025, 1, 9));
+        assert_eq!(project.end_date, d(2025, 1, 12));
+
+        let feeding = &buffers[1];
+        assert_eq!(feeding.id, "b__feeding_buffer");
+        assert_eq!(feeding.chain, vec!["b"]);
+        assert_eq!(feeding.size_days, 1.0);
+        assert_eq!(feeding.end_date, d(2025, 1, 5));
+
+        let mut with_buffers = result.clone();
+        insert_buffer_tasks(&mut with_buffers, &buffers);
+        assert_eq!(with_buffers.scheduled_tasks.len(), 5);
+    }
+
+    #[test]
+    fn test_buffer_consumption() {
+        let (tasks, result) = plan();
+        let buffers = compute_buffers(&result, &tasks, "c", 0.5).unwrap();
+
+        let mut slipped = result.clone();
+        slipped.scheduled_tasks[2].end_date = d(2025, 1, 10);
+        let status = buffer_consumption(&buffers, &slipped);
+
+        assert_eq!(status[0].consumed_days, 2.0);
+        assert!((status[0].consumed_fraction - 2.0 / 3.0).abs() < 1e-9);
+        assert_eq!(status[1].consumed_days, 0.0);
+    }
+
+    #[test]
+    fn test_rejects_bad_ratios_and_overflowing_buffers() {
+        let (tasks, result) = plan();
+        for ratio in [f64::NAN, -0.5, f64::INFINITY] {
+            assert!(matches!(
+                compute_buffers(&result, &tasks, "c", ratio),
+                Err(BufferError::InvalidRatio(_))
+            ));
+        }
+        assert_eq!(
+            compute_buffers(&result, &tasks, "c", 1e300),
+            Err(BufferError::DateOverflow("c__project_buffer".to_string()))
+        );
+    }
+}
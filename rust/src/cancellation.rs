@@ -0,0 +1,111 @@
+//! Cooperative cancellation and wall-clock timeouts for long-running schedules.
+//!
+//! The schedulers poll a `StopCondition` once per main-loop iteration. A stop is
+//! requested either explicitly through a shared `CancellationToken` or implicitly
+//! when the configured timeout elapses.
+
+use pyo3::prelude::*;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+/// Shared flag used to request cancellation of a running schedule.
+///
+/// Clones share the same underlying flag, so a token can be handed to the
+/// scheduler while the caller keeps a handle to cancel it from another thread.
+#[pyclass]
+#[derive(Clone, Debug, Default)]
+pub struct CancellationToken {
+    flag: Arc<AtomicBool>,
+}
+
+impl CancellationToken {
+    /// Create a new, non-cancelled token.
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+#[pymethods]
+impl CancellationToken {
+    #[new]
+    fn py_new() -> Self {
+        Self::new()
+    }
+
+    /// Request cancellation.
+    pub fn cancel(&self) {
+        self.flag.store(true, Ordering::Relaxed);
+    }
+
+    /// Check whether cancellation has been requested.
+    pub fn is_cancelled(&self) -> bool {
+        self.flag.load(Ordering::Relaxed)
+    }
+
+    fn __repr__(&self) -> String {
+        format!("CancellationToken(cancelled={})", self.is_cancelled())
+    }
+}
+
+/// Combined cancellation token and timeout deadline for a single scheduling run.
+#[derive(Clone, Debug, Default)]
+pub struct StopCondition {
+    token: Option<CancellationToken>,
+    deadline: Option<Instant>,
+}
+
+impl StopCondition {
+    /// Build a stop condition starting now, with an optional timeout in seconds.
+    ///
+    /// A negative, non-finite, or too large timeout sets no deadline.
+    pub fn new(token: Option<CancellationToken>, timeout_secs: Option<f64>) -> Self {
+        let deadline = timeout_secs
+            .and_then(|secs| Duration::try_from_secs_f64(secs).ok())
+            .and_then(|timeout| Instant::now().checked_add(timeout));
+        Self { token, deadline }
+    }
+
+    /// Returns true if the run should stop (cancelled or timed out).
+    #[inline]
+    pub fn should_stop(&self) -> bool {
+        if let Some(token) = &self.token {
+            if token.is_cancelled() {
+                return true;
+            }
+        }
+        match self.deadline {
+            Some(deadline) => Instant::now() >= deadline,
+            None => false,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_token_shared_between_clones() {
+        let token = CancellationToken::new();
+        let clone = token.clone();
+        assert!(!clone.is_cancelled());
+        token.cancel();
+        assert!(clone.is_cancelled());
+    }
+
+    #[test]
+    fn test_stop_condition() {
+        assert!(!StopCondition::default().should_stop());
+        assert!(StopCondition::new(None, Some(0.0)).should_stop());
+        assert!(!StopCondition::new(None, Some(3600.0)).should_stop());
+        assert!(!StopCondition::new(None, Some(1e20)).should_stop());
+        assert!(!StopCondition::new(None, Some(f64::INFINITY)).should_stop());
+
+        let token = CancellationToken::new();
+        let stop = StopCondition::new(Some(token.clone()), None);
+        assert!(!stop.should_stop());
+        token.cancel();
+        assert!(stop.should_stop());
+    }
+}
@@ -1,6 +1,690 @@
 //! Configuration types for the scheduling system.
 
+use std::collections::HashMap;
+
+use chrono::NaiveDate;
 use pyo3::prelude::*;
+use rustc_hash::FxHashMap;
+use thiserror::Error;
+
+use crate::exceptions::InvalidConfigError;
+use crate::models::{ScheduledTask, Task};
+
+/// Every problem found when validating a configuration.
+#[derive(Error, Debug, Clone, PartialEq)]
+#[error("invalid configuration: {}", .problems.join("; "))]
+pub struct ConfigError {
+    pub problems: Vec<String>,
+}
+
+/// Collects validation problems so `validate()` can report all of them at once.
+#[derive(Default)]
+pub(crate) struct Problems(Vec<String>);
+
+impl Problems {
+    pub(crate) fn require(&mut self, ok: bool, message: impl FnOnce() -> String) {
+        if !ok {
+            self.0.push(message());
+        }
+    }
+
+    pub(crate) fn positive(&mut self, name: &str, value: f64) {
+        self.require(value.is_finite() && value > 0.0, || {
+            format!("{} must be positive, got {}", name, value)
+        });
+    }
+
+    pub(crate) fn non_negative(&mut self, name: &str, value: f64) {
+        self.require(value.is_finite() && value >= 0.0, || {
+            format!("{} must be non-negative, got {}", name, value)
+        });
+    }
+
+    pub(crate) fn fraction(&mut self, name: &str, value: f64) {
+        self.require((0.0..=1.0).contains(&value), || {
+            format!("{} must be in [0, 1], got {}", name, value)
+        });
+    }
+
+    pub(crate) fn finish(self) -> Result<(), ConfigError> {
+        if self.0.is_empty() {
+            Ok(())
+        } else {
+            Err(ConfigError { problems: self.0 })
+        }
+    }
+}
+
+/// Names accepted by `SchedulingConfig::preset` and `CriticalPathConfig::preset`.
+pub const PRESETS: &[&str] = &["deadline_focused", "throughput", "stable_plan", "fast"];
+
+pub(crate) fn unknown_preset(name: &str) -> ConfigError {
+    ConfigError {
+        problems: vec![format!(
+            "unknown preset {:?}; expected one of {}",
+            name,
+            PRESETS.join(", ")
+        )],
+    }
+}
+
+/// A value that can be read from a profile field.
+pub(crate) trait ProfileValue: Sized {
+    const KIND: &'static str;
+    fn from_json(value: &serde_json::Value) -> Option<Self>;
+}
+
+macro_rules! profile_ints {
+    ($($ty:ty),*) => {
+        $(
+            impl ProfileValue for $ty {
+                const KIND: &'static str = "an integer";
+                fn from_json(value: &serde_json::Value) -> Option<Self> {
+                    value.as_i64().and_then(|v| v.try_into().ok())
+                }
+            }
+        )*
+    };
+}
+profile_ints!(u8, i32, u32, usize);
+
+impl ProfileValue for f64 {
+    const KIND: &'static str = "a number";
+    fn from_json(value: &serde_json::Value) -> Option<Self> {
+        value.as_f64()
+    }
+}
+
+impl ProfileValue for bool {
+    const KIND: &'static str = "a boolean";
+    fn from_json(value: &serde_json::Value) -> Option<Self> {
+        value.as_bool()
+    }
+}
+
+impl ProfileValue for String {
+    const KIND: &'static str = "a string";
+    fn from_json(value: &serde_json::Value) -> Option<Self> {
+        value.as_str().map(str::to_string)
+    }
+}
+
+impl<T: ProfileValue> ProfileValue for Option<T> {
+    const KIND: &'static str = T::KIND;
+    fn from_json(value: &serde_json::Value) -> Option<Self> {
+        if value.is_null() {
+            Some(None)
+        } else {
+            T::from_json(value).map(Some)
+        }
+    }
+}
+
+/// Field overrides read from a JSON or TOML profile.
+///
+/// Each `apply` consumes one field; fields left over at `finish` are reported
+/// as unknown settings alongside any type errors.
+pub(crate) struct Overrides {
+    prefix: String,
+    fields: serde_json::Map<String, serde_json::Value>,
+    problems: Problems,
+}
+
+impl Overrides {
+    /// Parse a profile: JSON if it starts with `{`, TOML otherwise.
+    pub(crate) fn parse(text: &str) -> Result<Self, ConfigError> {
+        let parsed = if text.trim_start().starts_with('{') {
+            serde_json::from_str::<serde_json::Value>(text).map_err(|e| e.to_string())
+        } else {
+            toml::from_str::<serde_json::Value>(text).map_err(|e| e.to_string())
+        };
+        match parsed {
+            Ok(serde_json::Value::Object(fields)) => Ok(Self {
+                prefix: String::new(),
+                fields,
+                problems: Problems::default(),
+            }),
+            Ok(_) => Err(ConfigError {
+                problems: vec!["profile must be a table of settings".to_string()],
+            }),
+            Err(e) => Err(ConfigError {
+                problems: vec![format!("cannot parse profile: {}", e)],
+            }),
+        }
+    }
+
+    /// Remove the optional `preset` field naming the base configuration.
+    pub(crate) fn take_preset(&mut self) -> Option<String> {
+        let mut preset = None;
+        self.apply("preset", &mut preset);
+        preset
+    }
+
+    /// Overwrite `target` with the field `key`, if present.
+    pub(crate) fn apply<T: ProfileValue>(&mut self, key: &str, target: &mut T) {
+        self.apply_with(key, target, |name, value| {
+            T::from_json(value)
+                .ok_or_else(|| format!("{} must be {}, got {}", name, T::KIND, value))
+        });
+    }
+
+    /// Overwrite an enum `target` from the string field `key`, if present.
+    pub(crate) fn apply_str<T>(
+        &mut self,
+        key: &str,
+        target: &mut T,
+        parse: impl FnOnce(&str) -> Result<T, String>,
+    ) {
+        self.apply_with(key, target, |name, value| {
+            value
+                .as_str()
+                .ok_or_else(|| format!("{} must be a string, got {}", name, value))
+                .and_then(parse)
+        });
+    }
+
+    /// Overrides for the nested table `key` (empty if absent).
+    pub(crate) fn section(&mut self, key: &str) -> Self {
+        let mut fields = serde_json::Map::new();
+        self.apply_with(key, &mut fields, |name, value| {
+            value
+                .as_object()
+                .cloned()
+                .ok_or_else(|| format!("{} must be a table, got {}", name, value))
+        });
+        Self {
+            prefix: format!("{}{}.", self.prefix, key),
+            fields,
+            problems: Problems::default(),
+        }
+    }
+
+    /// Fold a finished section's problems into this one.
+    pub(crate) fn merge(&mut self, section: Self) {
+        if let Err(e) = section.finish() {
+            self.problems.0.extend(e.problems);
+        }
+    }
+
+    fn apply_with<T>(
+        &mut self,
+        key: &str,
+        target: &mut T,
+        parse: impl FnOnce(&str, &serde_json::Value) -> Result<T, String>,
+    ) {
+        if let Some(value) = self.fields.remove(key) {
+            match parse(&format!("{}{}", self.prefix, key), &value) {
+                Ok(parsed) => *target = parsed,
+                Err(e) => self.problems.0.push(e),
+            }
+        }
+    }
+
+    /// Report type errors and unknown settings.
+    pub(crate) fn finish(self) -> Result<(), ConfigError> {
+        self.finish_with(Ok(()))
+    }
+
+    /// Report type errors and unknown settings, then the problems from `validation`.
+    pub(crate) fn finish_with(
+        mut self,
+        validation: Result<(), ConfigError>,
+    ) -> Result<(), ConfigError> {
+        for key in self.fields.keys() {
+            self.problems
+                .0
+                .push(format!("unknown setting {}{}", self.prefix, key));
+        }
+        if let Err(e) = validation {
+            self.problems.0.extend(e.problems);
+        }
+        self.problems.finish()
+    }
+}
+
+/// Define builder setters that each assign the config field of the same name.
+macro_rules! builder_setters {
+    ($($field:ident: $ty:ty),* $(,)?) => {
+        $(
+            pub fn $field(mut self, $field: $ty) -> Self {
+                self.config.$field = $field;
+                self
+            }
+        )*
+    };
+}
+pub(crate) use builder_setters;
+
+/// Prioritization strategies accepted by `SchedulingConfig.strategy`.
+const STRATEGIES: &[&str] = &["priority_first", "cr_first", "weighted", "atc"];
+
+/// How schedulers treat dependencies on task IDs that are neither in the task
+/// list nor completed.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum UnknownDependencyPolicy {
+    /// Leave the dependency unsatisfiable; the dependent is never scheduled.
+    #[default]
+    Block,
+    /// Fail before scheduling, naming the missing IDs.
+    Error,
+    /// Treat missing IDs as completed and report them in result metadata.
+    Ignore,
+}
+
+impl UnknownDependencyPolicy {
+    /// Parse from string (for Python interop).
+    #[allow(clippy::should_implement_trait)]
+    pub fn from_str(s: &str) -> Result<Self, String> {
+        match s.to_lowercase().as_str() {
+            "block" => Ok(Self::Block),
+            "error" => Ok(Self::Error),
+            "ignore" => Ok(Self::Ignore),
+            _ => Err(format!(
+                "Invalid unknown_dependencies '{}', expected 'block', 'error', or 'ignore'",
+                s
+            )),
+        }
+    }
+
+    /// Convert to string (for Python interop).
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Block => "block",
+            Self::Error => "error",
+            Self::Ignore => "ignore",
+        }
+    }
+}
+
+/// What schedulers do when tasks are left unscheduled at the end of a run.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum UnschedulablePolicy {
+    /// Fail with `FailedToSchedule`.
+    #[default]
+    Error,
+    /// Return the partial schedule and list the unschedulable tasks.
+    Defer,
+    /// Like `Defer`, but first reschedule with dependencies on unschedulable
+    /// or unknown tasks dropped, so only tasks blocked on their own account remain.
+    BestEffort,
+}
+
+impl UnschedulablePolicy {
+    /// Parse from string (for Python interop).
+    #[allow(clippy::should_implement_trait)]
+    pub fn from_str(s: &str) -> Result<Self, String> {
+        match s.to_lowercase().as_str() {
+            "error" => Ok(Self::Error),
+            "defer" => Ok(Self::Defer),
+            "best_effort" => Ok(Self::BestEffort),
+            _ => Err(format!(
+                "Invalid on_unschedulable '{}', expected 'error', 'defer', or 'best_effort'",
+                s
+            )),
+        }
+    }
+
+    /// Convert to string (for Python interop).
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Error => "error",
+            Self::Defer => "defer",
+            Self::BestEffort => "best_effort",
+        }
+    }
+}
+
+/// Which scheduler `build_scheduler` creates.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum SchedulingAlgorithm {
+    /// `ParallelScheduler` without rollout.
+    #[default]
+    ParallelSgs,
+    /// `ParallelScheduler` with bounded rollout (default `RolloutConfig` if none is given).
+    BoundedRollout,
+    /// `CriticalPathScheduler`.
+    CriticalPath,
+    /// `CriticalPathScheduler` ranking targets, with eligible tasks ordered by `strategy`.
+    Hybrid,
+}
+
+impl SchedulingAlgorithm {
+    /// Parse from string (for Python interop).
+    #[allow(clippy::should_implement_trait)]
+    pub fn from_str(s: &str) -> Result<Self, String> {
+        match s.to_lowercase().as_str() {
+            "parallel_sgs" => Ok(Self::ParallelSgs),
+            "bounded_rollout" => Ok(Self::BoundedRollout),
+            "critical_path" => Ok(Self::CriticalPath),
+            "hybrid" => Ok(Self::Hybrid),
+            _ => Err(format!(
+                "Invalid algorithm '{}', expected 'parallel_sgs', 'bounded_rollout', 'critical_path', or 'hybrid'",
+                s
+            )),
+        }
+    }
+
+    /// Convert to string (for Python interop).
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::ParallelSgs => "parallel_sgs",
+            Self::BoundedRollout => "bounded_rollout",
+            Self::CriticalPath => "critical_path",
+            Self::Hybrid => "hybrid",
+        }
+    }
+}
+
+/// What schedulers do when fixed-date tasks overlap on a resource.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum FixedConflictPolicy {
+    /// Keep the overlap.
+    Ignore,
+    /// Keep the overlap and report it in the result's warnings.
+    #[default]
+    Warn,
+    /// Fail with `FixedTaskConflicts`.
+    Error,
+    /// Move the later task past the one it collides with, and report the move.
+    Shift,
+}
+
+impl FixedConflictPolicy {
+    /// Parse from string (for Python interop).
+    #[allow(clippy::should_implement_trait)]
+    pub fn from_str(s: &str) -> Result<Self, String> {
+        match s.to_lowercase().as_str() {
+            "ignore" => Ok(Self::Ignore),
+            "warn" => Ok(Self::Warn),
+            "error" => Ok(Self::Error),
+            "shift" => Ok(Self::Shift),
+            _ => Err(format!(
+                "Invalid on_fixed_conflict '{}', expected 'ignore', 'warn', 'error', or 'shift'",
+                s
+            )),
+        }
+    }
+
+    /// Convert to string (for Python interop).
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Ignore => "ignore",
+            Self::Warn => "warn",
+            Self::Error => "error",
+            Self::Shift => "shift",
+        }
+    }
+}
+
+/// How whole-day scheduling rounds fractional task durations.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum DurationRounding {
+    /// Every started day counts, so a 0.5-day task takes a full day.
+    #[default]
+    Up,
+    /// Round duration and remaining work to the nearest whole day before
+    /// scheduling; non-zero work still takes at least one day. Results keep
+    /// the planned duration and report the rounded work as effective.
+    Nearest,
+}
+
+impl DurationRounding {
+    /// Parse from string (for Python interop).
+    #[allow(clippy::should_implement_trait)]
+    pub fn from_str(s: &str) -> Result<Self, String> {
+        match s.to_lowercase().as_str() {
+            "up" => Ok(Self::Up),
+            "nearest" => Ok(Self::Nearest),
+            _ => Err(format!(
+                "Invalid duration_rounding '{}', expected 'up' or 'nearest'",
+                s
+            )),
+        }
+    }
+
+    /// Convert to string (for Python interop).
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Up => "up",
+            Self::Nearest => "nearest",
+        }
+    }
+
+    /// Apply the policy to a task's duration and remaining work.
+    pub fn apply(&self, task: Task) -> Task {
+        match self {
+            Self::Up => task,
+            Self::Nearest => task.round_to_nearest_day(),
+        }
+    }
+
+    /// Apply the policy to every task, also returning the unrounded duration
+    /// of each task it changed so results can report the planned work.
+    pub fn apply_all(
+        &self,
+        tasks: impl IntoIterator<Item = Task>,
+    ) -> (Vec<Task>, FxHashMap<String, f64>) {
+        let mut planned = FxHashMap::default();
+        let tasks = tasks
+            .into_iter()
+            .map(|task| {
+                let duration_days = task.duration_days;
+                let task = self.apply(task);
+                if task.duration_days != duration_days {
+                    planned.insert(task.id.clone(), duration_days);
+                }
+                task
+            })
+            .collect();
+        (tasks, planned)
+    }
+}
+
+/// How much of the scheduler rollout simulations replicate.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum RolloutFidelity {
+    /// Simulate every task exactly as the real scheduler would.
+    #[default]
+    Full,
+    /// Leave auto-assigned tasks out of simulations.
+    NoAutoAssignment,
+    /// Only simulate critical tasks (and milestones).
+    CriticalTasksOnly,
+}
+
+impl RolloutFidelity {
+    /// Parse from string (for Python interop).
+    #[allow(clippy::should_implement_trait)]
+    pub fn from_str(s: &str) -> Result<Self, String> {
+        match s.to_lowercase().replace('-', "_").as_str() {
+            "full" => Ok(Self::Full),
+            "no_auto_assignment" => Ok(Self::NoAutoAssignment),
+            "critical_tasks_only" => Ok(Self::CriticalTasksOnly),
+            _ => Err(format!(
+                "Invalid rollout_fidelity '{}', expected 'full', 'no_auto_assignment', or 'critical_tasks_only'",
+                s
+            )),
+        }
+    }
+
+    /// Convert to string (for Python interop).
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Full => "full",
+            Self::NoAutoAssignment => "no_auto_assignment",
+            Self::CriticalTasksOnly => "critical_tasks_only",
+        }
+    }
+}
+
+/// Weights of the objective rollout uses to compare candidate schedules.
+///
+/// Lower scores are better. Per-task terms are scaled by the task's priority
+/// divided by `priority_scale`. The defaults reproduce the original scoring.
+#[pyclass]
+#[derive(Clone, Debug, PartialEq)]
+pub struct ScheduleObjective {
+    /// Weight per priority-weighted day of each task's flow time
+    #[pyo3(get, set)]
+    pub flow_time_weight: f64,
+    /// Weight per priority-weighted day a task finishes past its deadline
+    #[pyo3(get, set)]
+    pub tardiness_weight: f64,
+    /// Weight per day from the schedule start to the last completion
+    #[pyo3(get, set)]
+    pub makespan_weight: f64,
+    /// Weight per resource-day left idle before the rollout horizon
+    #[pyo3(get, set)]
+    pub idle_time_weight: f64,
+    /// Priority that counts as a task weight of 1.0
+    #[pyo3(get, set)]
+    pub priority_scale: f64,
+    /// Penalty per task placed off the resource it had in the reference plan
+    /// (`ResourceConfig.prior_assignments`)
+    #[pyo3(get, set)]
+    pub reassignment_weight: f64,
+}
+
+impl Default for ScheduleObjective {
+    fn default() -> Self {
+        Self {
+            flow_time_weight: 1.0,
+            tardiness_weight: 1000.0,
+            makespan_weight: 0.0,
+            idle_time_weight: 0.0,
+            priority_scale: 100.0,
+            reassignment_weight: 0.0,
+        }
+    }
+}
+
+impl ScheduleObjective {
+    pub(crate) fn apply_overrides(&mut self, overrides: &mut Overrides) {
+        overrides.apply("flow_time_weight", &mut self.flow_time_weight);
+        overrides.apply("tardiness_weight", &mut self.tardiness_weight);
+        overrides.apply("makespan_weight", &mut self.makespan_weight);
+        overrides.apply("idle_time_weight", &mut self.idle_time_weight);
+        overrides.apply("priority_scale", &mut self.priority_scale);
+        overrides.apply("reassignment_weight", &mut self.reassignment_weight);
+    }
+
+    /// Weight of a task with the given priority.
+    pub fn task_weight(&self, priority: i32) -> f64 {
+        priority as f64 / self.priority_scale
+    }
+
+    /// Makespan and resource idle time terms for the window `[start, horizon)`.
+    pub fn schedule_terms(
+        &self,
+        scheduled: &[ScheduledTask],
+        start: NaiveDate,
+        horizon: NaiveDate,
+    ) -> f64 {
+        let mut score = 0.0;
+        if self.makespan_weight != 0.0 {
+            score += self.makespan_weight * makespan_days(scheduled, start) as f64;
+        }
+        if self.idle_time_weight != 0.0 {
+            score += self.idle_time_weight * resource_idle_days(scheduled, start, horizon) as f64;
+        }
+        score
+    }
+
+    /// Stability term: `reassignment_weight` per scheduled task whose resources
+    /// leave out its prior assignment. Tasks without one are not counted.
+    pub fn reassignment_term(
+        &self,
+        scheduled: &[ScheduledTask],
+        prior_assignments: &HashMap<String, String>,
+    ) -> f64 {
+        if self.reassignment_weight == 0.0 {
+            return 0.0;
+        }
+        let moved = scheduled
+            .iter()
+            .filter(|t| {
+                prior_assignments
+                    .get(&t.task_id)
+                    .is_some_and(|prior| !t.resources.contains(prior))
+            })
+            .count();
+        self.reassignment_weight * moved as f64
+    }
+}
+
+/// Days from `start` to the last completion (0 for an empty schedule).
+pub(crate) fn makespan_days(scheduled: &[ScheduledTask], start: NaiveDate) -> i64 {
+    scheduled
+        .iter()
+        .map(|t| (t.end_date - start).num_days().max(0))
+        .max()
+        .unwrap_or(0)
+}
+
+/// Resource-days within `[start, horizon)` that assigned resources spend idle.
+pub(crate) fn resource_idle_days(
+    scheduled: &[ScheduledTask],
+    start: NaiveDate,
+    horizon: NaiveDate,
+) -> i64 {
+    let window = (horizon - start).num_days().max(0);
+    let mut busy: FxHashMap<&str, i64> = FxHashMap::default();
+    for task in scheduled {
+        let overlap = (task.end_date.min(horizon) - task.start_date.max(start))
+            .num_days()
+            .max(0);
+        for resource in &task.resources {
+            *busy.entry(resource.as_str()).or_default() += overlap;
+        }
+    }
+    busy.values().map(|b| (window - b).max(0)).sum()
+}
+
+#[pymethods]
+impl ScheduleObjective {
+    #[new]
+    #[pyo3(signature = (
+        flow_time_weight=1.0,
+        tardiness_weight=1000.0,
+        makespan_weight=0.0,
+        idle_time_weight=0.0,
+        priority_scale=100.0,
+        reassignment_weight=0.0
+    ))]
+    fn new(
+        flow_time_weight: f64,
+        tardiness_weight: f64,
+        makespan_weight: f64,
+        idle_time_weight: f64,
+        priority_scale: f64,
+        reassignment_weight: f64,
+    ) -> PyResult<Self> {
+        if priority_scale <= 0.0 {
+            return Err(InvalidConfigError::new_err(
+                "priority_scale must be positive",
+            ));
+        }
+        Ok(Self {
+            flow_time_weight,
+            tardiness_weight,
+            makespan_weight,
+            idle_time_weight,
+            priority_scale,
+            reassignment_weight,
+        })
+    }
+
+    fn __repr__(&self) -> String {
+        format!(
+            "ScheduleObjective(flow_time_weight={}, tardiness_weight={}, makespan_weight={}, idle_time_weight={}, reassignment_weight={})",
+            self.flow_time_weight,
+            self.tardiness_weight,
+            self.makespan_weight,
+            self.idle_time_weight,
+            self.reassignment_weight
+        )
+    }
+}
 
 /// Configuration for task prioritization and algorithm selection.
 #[pyclass]
@@ -36,6 +720,57 @@ pub struct SchedulingConfig {
     /// Verbosity level: 0=silent, 1=changes, 2=checks, 3=debug
     #[pyo3(get, set)]
     pub verbosity: u8,
+    /// Wall-clock timeout in seconds; scheduling stops with a Cancelled error when exceeded
+    #[pyo3(get, set)]
+    pub timeout_secs: Option<f64>,
+    /// Pack fractional-day tasks within a day instead of rounding each up to whole days
+    #[pyo3(get, set)]
+    pub sub_day: bool,
+    /// Rounding of fractional durations when `sub_day` is off.
+    /// Not directly exposed to Python; use duration_rounding_str getter/setter.
+    pub duration_rounding: DurationRounding,
+    /// Multiplier applied to inherited priority per dependency level in the backward pass
+    #[pyo3(get, set)]
+    pub priority_decay: f64,
+    /// Tighten backward-pass deadlines for tasks competing for the same resource
+    #[pyo3(get, set)]
+    pub contention_aware_deadlines: bool,
+    /// Count durations in working days, skipping DNS and blocked periods,
+    /// when propagating backward-pass deadlines
+    #[pyo3(get, set)]
+    pub calendar_aware_deadlines: bool,
+    /// Days from the scheduling date after which work is placed in coarse
+    /// rolling-wave buckets instead of day by day (parallel scheduler; None = off)
+    #[pyo3(get, set)]
+    pub rolling_wave_horizon_days: Option<u32>,
+    /// Calendar days per rolling-wave bucket (7 = weekly, 30 = monthly)
+    #[pyo3(get, set)]
+    pub rolling_wave_bucket_days: u32,
+    /// Handling of dependencies on unknown task IDs.
+    /// Not directly exposed to Python; use unknown_dependencies_str getter/setter.
+    pub unknown_dependencies: UnknownDependencyPolicy,
+    /// Shift tasks as late as deadlines, successors, and resources allow (ALAP)
+    #[pyo3(get, set)]
+    pub alap: bool,
+    /// Handling of tasks left unscheduled at the end of a run.
+    /// Not directly exposed to Python; use on_unschedulable_str getter/setter.
+    pub on_unschedulable: UnschedulablePolicy,
+    /// Maximum time advances before failing with IterationLimit (None = 100 per task)
+    #[pyo3(get, set)]
+    pub max_iterations: Option<usize>,
+    /// Let milestones and start_on tasks fall inside global DNS periods; when
+    /// false they move to the day after the period
+    #[pyo3(get, set)]
+    pub allow_fixed_in_global_dns: bool,
+    /// Handling of fixed-date tasks that overlap on a resource.
+    /// Not directly exposed to Python; use on_fixed_conflict_str getter/setter.
+    pub on_fixed_conflict: FixedConflictPolicy,
+    /// Record per-phase timings in the result metadata
+    #[pyo3(get, set)]
+    pub profile: bool,
+    /// Scheduler created by `build_scheduler`.
+    /// Not directly exposed to Python; use algorithm_str getter/setter.
+    pub algorithm: SchedulingAlgorithm,
 }
 
 impl Default for SchedulingConfig {
@@ -51,8 +786,237 @@ impl Default for SchedulingConfig {
             atc_default_urgency_multiplier: 1.0,
             atc_default_urgency_floor: 0.3,
             verbosity: 0,
+            timeout_secs: None,
+            sub_day: false,
+            duration_rounding: DurationRounding::Up,
+            priority_decay: 1.0,
+            contention_aware_deadlines: false,
+            calendar_aware_deadlines: false,
+            rolling_wave_horizon_days: None,
+            rolling_wave_bucket_days: 7,
+            unknown_dependencies: UnknownDependencyPolicy::Block,
+            alap: false,
+            on_unschedulable: UnschedulablePolicy::Error,
+            max_iterations: None,
+            allow_fixed_in_global_dns: true,
+            on_fixed_conflict: FixedConflictPolicy::Warn,
+            profile: false,
+            algorithm: SchedulingAlgorithm::ParallelSgs,
+        }
+    }
+}
+
+impl SchedulingConfig {
+    /// Start building a configuration from the defaults.
+    pub fn builder() -> SchedulingConfigBuilder {
+        SchedulingConfigBuilder {
+            config: Self::default(),
+        }
+    }
+
+    /// A named starting point for common goals; see `PRESETS`.
+    ///
+    /// - `deadline_focused`: ATC with a short lookahead and contention-aware deadlines
+    /// - `throughput`: ATC with a long lookahead, closer to shortest-job-first
+    /// - `stable_plan`: priority first, so small date changes don't reorder work
+    /// - `fast`: priority first, and work past 90 days placed in monthly rolling-wave buckets
+    pub fn preset(name: &str) -> Result<Self, ConfigError> {
+        let defaults = Self::default();
+        match name {
+            "deadline_focused" => Ok(Self {
+                strategy: "atc".to_string(),
+                atc_k: 1.5,
+                atc_default_urgency_floor: 0.1,
+                contention_aware_deadlines: true,
+                ..defaults
+            }),
+            "throughput" => Ok(Self {
+                strategy: "atc".to_string(),
+                atc_k: 3.0,
+                atc_default_urgency_floor: 0.5,
+                ..defaults
+            }),
+            "stable_plan" => Ok(Self {
+                strategy: "priority_first".to_string(),
+                ..defaults
+            }),
+            "fast" => Ok(Self {
+                strategy: "priority_first".to_string(),
+                rolling_wave_horizon_days: Some(90),
+                rolling_wave_bucket_days: 30,
+                ..defaults
+            }),
+            _ => Err(unknown_preset(name)),
         }
     }
+
+    /// Load a JSON or TOML profile of field overrides.
+    ///
+    /// An optional `preset` field picks the base configuration (default
+    /// otherwise). Enum fields take the same strings as the Python setters.
+    /// Unknown fields, wrong types and out-of-range values are all reported.
+    pub fn from_profile(text: &str) -> Result<Self, ConfigError> {
+        let mut overrides = Overrides::parse(text)?;
+        let mut config = match overrides.take_preset() {
+            Some(name) => Self::preset(&name)?,
+            None => Self::default(),
+        };
+        overrides.apply("strategy", &mut config.strategy);
+        overrides.apply("cr_weight", &mut config.cr_weight);
+        overrides.apply("priority_weight", &mut config.priority_weight);
+        overrides.apply("default_priority", &mut config.default_priority);
+        overrides.apply("default_cr_multiplier", &mut config.default_cr_multiplier);
+        overrides.apply("default_cr_floor", &mut config.default_cr_floor);
+        overrides.apply("atc_k", &mut config.atc_k);
+        overrides.apply(
+            "atc_default_urgency_multiplier",
+            &mut config.atc_default_urgency_multiplier,
+        );
+        overrides.apply(
+            "atc_default_urgency_floor",
+            &mut config.atc_default_urgency_floor,
+        );
+        overrides.apply("verbosity", &mut config.verbosity);
+        overrides.apply("timeout_secs", &mut config.timeout_secs);
+        overrides.apply("sub_day", &mut config.sub_day);
+        overrides.apply_str(
+            "duration_rounding",
+            &mut config.duration_rounding,
+            DurationRounding::from_str,
+        );
+        overrides.apply("priority_decay", &mut config.priority_decay);
+        overrides.apply(
+            "contention_aware_deadlines",
+            &mut config.contention_aware_deadlines,
+        );
+        overrides.apply(
+            "calendar_aware_deadlines",
+            &mut config.calendar_aware_deadlines,
+        );
+        overrides.apply(
+            "rolling_wave_horizon_days",
+            &mut config.rolling_wave_horizon_days,
+        );
+        overrides.apply(
+            "rolling_wave_bucket_days",
+            &mut config.rolling_wave_bucket_days,
+        );
+        overrides.apply_str(
+            "unknown_dependencies",
+            &mut config.unknown_dependencies,
+            UnknownDependencyPolicy::from_str,
+        );
+        overrides.apply("alap", &mut config.alap);
+        overrides.apply_str(
+            "on_unschedulable",
+            &mut config.on_unschedulable,
+            UnschedulablePolicy::from_str,
+        );
+        overrides.apply("max_iterations", &mut config.max_iterations);
+        overrides.apply(
+            "allow_fixed_in_global_dns",
+            &mut config.allow_fixed_in_global_dns,
+        );
+        overrides.apply_str(
+            "on_fixed_conflict",
+            &mut config.on_fixed_conflict,
+            FixedConflictPolicy::from_str,
+        );
+        overrides.apply("profile", &mut config.profile);
+        overrides.apply_str(
+            "algorithm",
+            &mut config.algorithm,
+            SchedulingAlgorithm::from_str,
+        );
+        overrides.finish_with(config.validate())?;
+        Ok(config)
+    }
+
+    /// Check value ranges, reporting every problem found.
+    pub fn validate(&self) -> Result<(), ConfigError> {
+        let mut problems = Problems::default();
+        problems.require(STRATEGIES.contains(&self.strategy.as_str()), || {
+            format!(
+                "strategy must be one of {}, got {:?}",
+                STRATEGIES.join(", "),
+                self.strategy
+            )
+        });
+        problems.non_negative("cr_weight", self.cr_weight);
+        problems.non_negative("priority_weight", self.priority_weight);
+        problems.require((0..=100).contains(&self.default_priority), || {
+            format!(
+                "default_priority must be in [0, 100], got {}",
+                self.default_priority
+            )
+        });
+        problems.positive("default_cr_multiplier", self.default_cr_multiplier);
+        problems.non_negative("default_cr_floor", self.default_cr_floor);
+        problems.positive("atc_k", self.atc_k);
+        problems.non_negative(
+            "atc_default_urgency_multiplier",
+            self.atc_default_urgency_multiplier,
+        );
+        problems.fraction("atc_default_urgency_floor", self.atc_default_urgency_floor);
+        problems.require(self.verbosity <= 3, || {
+            format!("verbosity must be at most 3, got {}", self.verbosity)
+        });
+        if let Some(timeout) = self.timeout_secs {
+            problems.non_negative("timeout_secs", timeout);
+        }
+        problems.fraction("priority_decay", self.priority_decay);
+        problems.require(self.max_iterations != Some(0), || {
+            "max_iterations must be positive".to_string()
+        });
+        problems.finish()
+    }
+}
+
+/// Builder for `SchedulingConfig` with one named setter per field.
+#[derive(Clone, Debug)]
+pub struct SchedulingConfigBuilder {
+    config: SchedulingConfig,
+}
+
+impl SchedulingConfigBuilder {
+    pub fn strategy(mut self, strategy: impl Into<String>) -> Self {
+        self.config.strategy = strategy.into();
+        self
+    }
+
+    builder_setters! {
+        cr_weight: f64,
+        priority_weight: f64,
+        default_priority: i32,
+        default_cr_multiplier: f64,
+        default_cr_floor: f64,
+        atc_k: f64,
+        atc_default_urgency_multiplier: f64,
+        atc_default_urgency_floor: f64,
+        verbosity: u8,
+        timeout_secs: Option<f64>,
+        sub_day: bool,
+        duration_rounding: DurationRounding,
+        priority_decay: f64,
+        contention_aware_deadlines: bool,
+        calendar_aware_deadlines: bool,
+        rolling_wave_horizon_days: Option<u32>,
+        rolling_wave_bucket_days: u32,
+        unknown_dependencies: UnknownDependencyPolicy,
+        alap: bool,
+        on_unschedulable: UnschedulablePolicy,
+        max_iterations: Option<usize>,
+        allow_fixed_in_global_dns: bool,
+        on_fixed_conflict: FixedConflictPolicy,
+        profile: bool,
+        algorithm: SchedulingAlgorithm,
+    }
+
+    /// Validate and return the configuration.
+    pub fn build(self) -> Result<SchedulingConfig, ConfigError> {
+        self.config.validate()?;
+        Ok(self.config)
+    }
 }
 
 #[pymethods]
@@ -68,7 +1032,23 @@ impl SchedulingConfig {
         atc_k=None,
         atc_default_urgency_multiplier=None,
         atc_default_urgency_floor=None,
-        verbosity=None
+        verbosity=None,
+        timeout_secs=None,
+        sub_day=None,
+        duration_rounding=None,
+        priority_decay=None,
+        contention_aware_deadlines=None,
+        calendar_aware_deadlines=None,
+        rolling_wave_horizon_days=None,
+        rolling_wave_bucket_days=None,
+        unknown_dependencies=None,
+        alap=None,
+        on_unschedulable=None,
+        max_iterations=None,
+        allow_fixed_in_global_dns=None,
+        on_fixed_conflict=None,
+        profile=None,
+        algorithm=None
     ))]
     #[allow(clippy::too_many_arguments)]
     fn new(
@@ -82,9 +1062,45 @@ impl SchedulingConfig {
         atc_default_urgency_multiplier: Option<f64>,
         atc_default_urgency_floor: Option<f64>,
         verbosity: Option<u8>,
-    ) -> Self {
+        timeout_secs: Option<f64>,
+        sub_day: Option<bool>,
+        duration_rounding: Option<&str>,
+        priority_decay: Option<f64>,
+        contention_aware_deadlines: Option<bool>,
+        calendar_aware_deadlines: Option<bool>,
+        rolling_wave_horizon_days: Option<u32>,
+        rolling_wave_bucket_days: Option<u32>,
+        unknown_dependencies: Option<&str>,
+        alap: Option<bool>,
+        on_unschedulable: Option<&str>,
+        max_iterations: Option<usize>,
+        allow_fixed_in_global_dns: Option<bool>,
+        on_fixed_conflict: Option<&str>,
+        profile: Option<bool>,
+        algorithm: Option<&str>,
+    ) -> PyResult<Self> {
         let defaults = Self::default();
-        Self {
+        let unknown_dependencies = match unknown_dependencies {
+            Some(s) => UnknownDependencyPolicy::from_str(s).map_err(InvalidConfigError::new_err)?,
+            None => defaults.unknown_dependencies,
+        };
+        let on_unschedulable = match on_unschedulable {
+            Some(s) => UnschedulablePolicy::from_str(s).map_err(InvalidConfigError::new_err)?,
+            None => defaults.on_unschedulable,
+        };
+        let duration_rounding = match duration_rounding {
+            Some(s) => DurationRounding::from_str(s).map_err(InvalidConfigError::new_err)?,
+            None => defaults.duration_rounding,
+        };
+        let on_fixed_conflict = match on_fixed_conflict {
+            Some(s) => FixedConflictPolicy::from_str(s).map_err(InvalidConfigError::new_err)?,
+            None => defaults.on_fixed_conflict,
+        };
+        let algorithm = match algorithm {
+            Some(s) => SchedulingAlgorithm::from_str(s).map_err(InvalidConfigError::new_err)?,
+            None => defaults.algorithm,
+        };
+        Ok(Self {
             strategy: strategy.unwrap_or(defaults.strategy),
             cr_weight: cr_weight.unwrap_or(defaults.cr_weight),
             priority_weight: priority_weight.unwrap_or(defaults.priority_weight),
@@ -97,7 +1113,120 @@ impl SchedulingConfig {
             atc_default_urgency_floor: atc_default_urgency_floor
                 .unwrap_or(defaults.atc_default_urgency_floor),
             verbosity: verbosity.unwrap_or(defaults.verbosity),
-        }
+            timeout_secs,
+            sub_day: sub_day.unwrap_or(defaults.sub_day),
+            duration_rounding,
+            priority_decay: priority_decay.unwrap_or(defaults.priority_decay),
+            contention_aware_deadlines: contention_aware_deadlines
+                .unwrap_or(defaults.contention_aware_deadlines),
+            calendar_aware_deadlines: calendar_aware_deadlines
+                .unwrap_or(defaults.calendar_aware_deadlines),
+            rolling_wave_horizon_days,
+            rolling_wave_bucket_days: rolling_wave_bucket_days
+                .unwrap_or(defaults.rolling_wave_bucket_days),
+            unknown_dependencies,
+            alap: alap.unwrap_or(defaults.alap),
+            on_unschedulable,
+            max_iterations,
+            allow_fixed_in_global_dns: allow_fixed_in_global_dns
+                .unwrap_or(defaults.allow_fixed_in_global_dns),
+            on_fixed_conflict,
+            profile: profile.unwrap_or(defaults.profile),
+            algorithm,
+        })
+    }
+
+    /// Get the unknown dependency policy as a string.
+    #[getter]
+    fn unknown_dependencies_str(&self) -> &'static str {
+        self.unknown_dependencies.as_str()
+    }
+
+    /// Set the unknown dependency policy from a string.
+    #[setter]
+    fn set_unknown_dependencies_str(&mut self, value: &str) -> PyResult<()> {
+        self.unknown_dependencies =
+            UnknownDependencyPolicy::from_str(value).map_err(InvalidConfigError::new_err)?;
+        Ok(())
+    }
+
+    /// Get the unschedulable-task policy as a string.
+    #[getter]
+    fn on_unschedulable_str(&self) -> &'static str {
+        self.on_unschedulable.as_str()
+    }
+
+    /// Set the unschedulable-task policy from a string.
+    #[setter]
+    fn set_on_unschedulable_str(&mut self, value: &str) -> PyResult<()> {
+        self.on_unschedulable =
+            UnschedulablePolicy::from_str(value).map_err(InvalidConfigError::new_err)?;
+        Ok(())
+    }
+
+    /// Get the fixed-task conflict policy as a string.
+    #[getter]
+    fn on_fixed_conflict_str(&self) -> &'static str {
+        self.on_fixed_conflict.as_str()
+    }
+
+    /// Set the fixed-task conflict policy from a string.
+    #[setter]
+    fn set_on_fixed_conflict_str(&mut self, value: &str) -> PyResult<()> {
+        self.on_fixed_conflict =
+            FixedConflictPolicy::from_str(value).map_err(InvalidConfigError::new_err)?;
+        Ok(())
+    }
+
+    /// Get the duration rounding policy as a string.
+    #[getter]
+    fn duration_rounding_str(&self) -> &'static str {
+        self.duration_rounding.as_str()
+    }
+
+    /// Set the duration rounding policy from a string.
+    #[setter]
+    fn set_duration_rounding_str(&mut self, value: &str) -> PyResult<()> {
+        self.duration_rounding =
+            DurationRounding::from_str(value).map_err(InvalidConfigError::new_err)?;
+        Ok(())
+    }
+
+    /// Get the scheduling algorithm as a string.
+    #[getter]
+    fn algorithm_str(&self) -> &'static str {
+        self.algorithm.as_str()
+    }
+
+    /// Set the scheduling algorithm from a string.
+    #[setter]
+    fn set_algorithm_str(&mut self, value: &str) -> PyResult<()> {
+        self.algorithm =
+            SchedulingAlgorithm::from_str(value).map_err(InvalidConfigError::new_err)?;
+        Ok(())
+    }
+
+    /// A named preset: "deadline_focused", "throughput", "stable_plan", or "fast".
+    #[staticmethod]
+    #[pyo3(name = "preset")]
+    fn py_preset(name: &str) -> PyResult<Self> {
+        Self::preset(name).map_err(|e| InvalidConfigError::new_err(e.to_string()))
+    }
+
+    /// Load a JSON or TOML profile of overrides, optionally naming a `preset`.
+    #[staticmethod]
+    #[pyo3(name = "from_profile")]
+    fn py_from_profile(text: &str) -> PyResult<Self> {
+        Self::from_profile(text).map_err(|e| InvalidConfigError::new_err(e.to_string()))
+    }
+
+    /// Problems with this configuration's values (empty if valid).
+    #[pyo3(name = "validate")]
+    fn py_validate(&self) -> Vec<String> {
+        self.validate()
+            .err()
+            .map(|e| e.problems)
+            .unwrap_or_default()
     }
 
     fn __repr__(&self) -> String {
@@ -127,6 +1256,20 @@ pub struct RolloutConfig {
     /// Maximum rollout horizon in days (limits simulation depth for performance)
     #[pyo3(get, set)]
     pub max_horizon_days: Option<i32>,
+    /// How much detail rollout simulations replicate. Critical tasks are those
+    /// with a computed deadline or priority at or above `priority_threshold`.
+    /// Not directly exposed to Python; use rollout_fidelity_str getter/setter.
+    pub rollout_fidelity: RolloutFidelity,
+    /// Objective used to score rollout scenarios
+    #[pyo3(get, set)]
+    pub objective: ScheduleObjective,
+    /// Keep both simulated schedules of the last rollout decision for debugging
+    #[pyo3(get, set)]
+    pub trace_rollouts: bool,
+    /// Days a skip holds an idle resource for the competing task before it is
+    /// released (0 = released as soon as time advances)
+    #[pyo3(get, set)]
+    pub reservation_max_idle_days: u32,
 }
 
 impl Default for RolloutConfig {
@@ -137,6 +1280,10 @@ impl Default for RolloutConfig {
             cr_relaxed_threshold: 5.0,
             min_cr_urgency_gap: 3.0,
             max_horizon_days: Some(30),
+            rollout_fidelity: RolloutFidelity::Full,
+            objective: ScheduleObjective::default(),
+            trace_rollouts: false,
+            reservation_max_idle_days: 0,
         }
     }
 }
@@ -149,23 +1296,52 @@ impl RolloutConfig {
         min_priority_gap=None,
         cr_relaxed_threshold=None,
         min_cr_urgency_gap=None,
-        max_horizon_days=30
+        max_horizon_days=30,
+        rollout_fidelity="full",
+        objective=None,
+        trace_rollouts=false,
+        reservation_max_idle_days=0
     ))]
+    #[allow(clippy::too_many_arguments)]
     fn new(
         priority_threshold: Option<i32>,
         min_priority_gap: Option<i32>,
         cr_relaxed_threshold: Option<f64>,
         min_cr_urgency_gap: Option<f64>,
         max_horizon_days: Option<i32>,
-    ) -> Self {
+        rollout_fidelity: &str,
+        objective: Option<ScheduleObjective>,
+        trace_rollouts: bool,
+        reservation_max_idle_days: u32,
+    ) -> PyResult<Self> {
         let defaults = Self::default();
-        Self {
+        let rollout_fidelity =
+            RolloutFidelity::from_str(rollout_fidelity).map_err(InvalidConfigError::new_err)?;
+        Ok(Self {
             priority_threshold: priority_threshold.unwrap_or(defaults.priority_threshold),
             min_priority_gap: min_priority_gap.unwrap_or(defaults.min_priority_gap),
             cr_relaxed_threshold: cr_relaxed_threshold.unwrap_or(defaults.cr_relaxed_threshold),
             min_cr_urgency_gap: min_cr_urgency_gap.unwrap_or(defaults.min_cr_urgency_gap),
             max_horizon_days,
-        }
+            rollout_fidelity,
+            objective: objective.unwrap_or_default(),
+            trace_rollouts,
+            reservation_max_idle_days,
+        })
+    }
+
+    /// Get the rollout fidelity as a string.
+    #[getter]
+    fn rollout_fidelity_str(&self) -> &'static str {
+        self.rollout_fidelity.as_str()
+    }
+
+    /// Set the rollout fidelity from a string.
+    #[setter]
+    fn set_rollout_fidelity_str(&mut self, value: &str) -> PyResult<()> {
+        self.rollout_fidelity =
+            RolloutFidelity::from_str(value).map_err(InvalidConfigError::new_err)?;
+        Ok(())
     }
 
     fn __repr__(&self) -> String {
@@ -175,3 +1351,68 @@ impl RolloutConfig {
         )
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_scheduling_config_builder_validates() {
+        let config = SchedulingConfig::builder()
+            .strategy("atc")
+            .atc_k(2.5)
+            .alap(true)
+            .build()
+            .unwrap();
+        assert_eq!(config.strategy, "atc");
+        assert_eq!(config.atc_k, 2.5);
+        assert!(config.alap);
+
+        let err = SchedulingConfig::builder()
+            .strategy("fastest")
+            .default_priority(120)
+            .atc_default_urgency_floor(-0.1)
+            .build()
+            .unwrap_err();
+        assert_eq!(err.problems.len(), 3);
+        assert!(err.problems[0].contains("\"fastest\""));
+        assert!(SchedulingConfig::default().validate().is_ok());
+
+        let timeout = |secs| SchedulingConfig {
+            timeout_secs: Some(secs),
+            ..SchedulingConfig::default()
+        };
+        assert!(timeout(0.0).validate().is_ok());
+        assert!(timeout(-1.0).validate().is_err());
+    }
+
+    #[test]
+    fn test_presets_and_profiles() {
+        for name in PRESETS {
+            assert!(SchedulingConfig::preset(name).unwrap().validate().is_ok());
+        }
+        assert!(SchedulingConfig::preset("fastest").is_err());
+        let fast = SchedulingConfig::preset("fast").unwrap();
+        assert_eq!(fast.rolling_wave_horizon_days, Some(90));
+        assert_ne!(fast.strategy, SchedulingConfig::default().strategy);
+
+        let toml =
+            "preset = \"deadline_focused\"\natc_k = 2.5\nunknown_dependencies = \"ignore\"\n";
+        let config = SchedulingConfig::from_profile(toml).unwrap();
+        assert_eq!(config.strategy, "atc");
+        assert!(config.contention_aware_deadlines);
+        assert_eq!(config.atc_k, 2.5);
+        assert_eq!(config.unknown_dependencies, UnknownDependencyPolicy::Ignore);
+
+        let json = r#"{"cr_weight": "high", "colour": 1, "default_priority": 200}"#;
+        let err = SchedulingConfig::from_profile(json).unwrap_err();
+        assert_eq!(
+            err.problems,
+            vec![
+                "cr_weight must be a number, got \"high\"".to_string(),
+                "unknown setting colour".to_string(),
+                "default_priority must be in [0, 100], got 200".to_string(),
+            ]
+        );
+    }
+}
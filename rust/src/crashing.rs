@@ -0,0 +1,346 @@
+//! Crashing: shortening tasks with overtime or extra help to recover late targets.
+//!
+//! For each target that finishes after its `end_before`, the chain of binding
+//! predecessors is followed back through the schedule: a dependency whose end
+//! plus lag is exactly the task's start, or else a task on a shared resource
+//! that ends the day before. Shortening any task on that chain pulls the target
+//! in by the same amount, so the cheapest crash set takes days from the chain's
+//! crashable tasks in order of cost per day saved.
+
+use chrono::NaiveDate;
+use pyo3::prelude::*;
+use rustc_hash::{FxHashMap, FxHashSet};
+use thiserror::Error;
+
+use crate::config::{ConfigError, Problems};
+use crate::models::{AlgorithmResult, ScheduledTask, Task};
+
+/// Errors from crash suggestions.
+#[derive(Error, Debug)]
+pub enum CrashError {
+    #[error("Targets not in the schedule: {}", .0.join(", "))]
+    UnknownTargets(Vec<String>),
+    #[error(transparent)]
+    InvalidCrashSpec(#[from] ConfigError),
+}
+
+/// Days taken off one task.
+#[pyclass]
+#[derive(Clone, Debug, PartialEq)]
+pub struct CrashAction {
+    #[pyo3(get)]
+    pub task_id: String,
+    #[pyo3(get)]
+    pub days_saved: f64,
+    #[pyo3(get)]
+    pub cost: f64,
+}
+
+#[pymethods]
+impl CrashAction {
+    fn __repr__(&self) -> String {
+        format!(
+            "CrashAction(task_id={:?}, days_saved={}, cost={:.2})",
+            self.task_id, self.days_saved, self.cost
+        )
+    }
+}
+
+/// Cheapest crash set found for one late target.
+#[pyclass]
+#[derive(Clone, Debug, PartialEq)]
+pub struct CrashPlan {
+    #[pyo3(get)]
+    pub target_id: String,
+    #[pyo3(get)]
+    pub deadline: NaiveDate,
+    #[pyo3(get)]
+    pub scheduled_end: NaiveDate,
+    /// Days the target finishes past its deadline.
+    #[pyo3(get)]
+    pub late_days: f64,
+    /// Tasks to crash, cheapest per day first.
+    #[pyo3(get)]
+    pub crashes: Vec<CrashAction>,
+    #[pyo3(get)]
+    pub total_cost: f64,
+    /// Days recovered by `crashes`; less than `late_days` if crashing cannot
+    /// bring the target back on its own.
+    #[pyo3(get)]
+    pub recovered_days: f64,
+}
+
+impl CrashPlan {
+    /// Whether the crash set brings the target back before its deadline.
+    pub fn is_sufficient(&self) -> bool {
+        self.recovered_days >= self.late_days
+    }
+}
+
+#[pymethods]
+impl CrashPlan {
+    #[pyo3(name = "is_sufficient")]
+    fn py_is_sufficient(&self) -> bool {
+        self.is_sufficient()
+    }
+
+    fn __repr__(&self) -> String {
+        format!(
+            "CrashPlan(target={}, late_days={}, recovered_days={}, total_cost={:.2})",
+            self.target_id, self.late_days, self.recovered_days, self.total_cost
+        )
+    }
+}
+
+/// Suggest the cheapest set of tasks to crash for each late target.
+///
+/// With `target_ids` of `None`, every scheduled task that misses its
+/// `end_before` is a target. On-time targets and targets without a deadline
+/// are omitted. Working-day lags skip `global_dns_periods`. The schedule is
+/// not re-run, so the plan is an estimate: a crashed chain can hand the
+/// binding constraint to another path.
+pub fn suggest_crashes(
+    tasks: &[Task],
+    result: &AlgorithmResult,
+    target_ids: Option<&[String]>,
+    global_dns_periods: &[(NaiveDate, NaiveDate)],
+) -> Result<Vec<CrashPlan>, CrashError> {
+    let mut problems = Problems::default();
+    for task in tasks {
+        if let Some(spec) = &task.crashable {
+            spec.check(&mut problems, &format!("task '{}' ", task.id));
+            problems.require(spec.min_duration_days <= task.duration_days, || {
+                format!(
+                    "task '{}' min_duration_days {} exceeds duration_days {}",
+                    task.id, spec.min_duration_days, task.duration_days
+                )
+            });
+        }
+    }
+    problems.finish()?;
+
+    let by_id: FxHashMap<&str, &Task> = tasks.iter().map(|t| (t.id.as_str(), t)).collect();
+    let scheduled: FxHashMap<&str, &ScheduledTask> = result
+        .scheduled_tasks
+        .iter()
+        .map(|st| (st.task_id.as_str(), st))
+        .collect();
+
+    let targets: Vec<&str> = match target_ids {
+        Some(ids) => {
+            let unknown: Vec<String> = ids
+                .iter()
+                .filter(|id| !scheduled.contains_key(id.as_str()))
+                .cloned()
+                .collect();
+            if !unknown.is_empty() {
+                return Err(CrashError::UnknownTargets(unknown));
+            }
+            ids.iter().map(String::as_str).collect()
+        }
+        None => result
+            .scheduled_tasks
+            .iter()
+            .map(|st| st.task_id.as_str())
+            .collect(),
+    };
+
+    let mut plans = Vec::new();
+    for target_id in targets {
+        let st = scheduled[target_id];
+        let Some(deadline) = by_id.get(target_id).and_then(|t| t.end_before) else {
+            continue;
+        };
+        if st.end_date <= deadline {
+            continue;
+        }
+        let late_days = (st.end_date - deadline).num_days() as f64;
+
+        let mut options: Vec<(&Task, f64)> =
+            binding_chain(st, &by_id, &scheduled, result, global_dns_periods)
+                .into_iter()
+                .filter_map(|id| {
+                    let task = by_id.get(id)?;
+                    let spec = task.crashable.as_ref()?;
+                    let room = task.duration_days - spec.min_duration_days;
+                    (room > 0.0).then_some((*task, room))
+                })
+                .collect();
+        options.sort_by(|(a, _), (b, _)| {
+            let cost = |t: &Task| t.crashable.as_ref().map_or(0.0, |c| c.cost_per_day);
+            cost(a).total_cmp(&cost(b)).then_with(|| a.id.cmp(&b.id))
+        });
+
+        let mut crashes = Vec::new();
+        let mut recovered_days = 0.0;
+        for (task, room) in options {
+            if recovered_days >= late_days {
+                break;
+            }
+            let days_saved = room.min(late_days - recovered_days);
+            let cost_per_day = task.crashable.as_ref().map_or(0.0, |c| c.cost_per_day);
+            recovered_days += days_saved;
+            crashes.push(CrashAction {
+                task_id: task.id.clone(),
+                days_saved,
+                cost: days_saved * cost_per_day,
+            });
+        }
+        plans.push(CrashPlan {
+            target_id: target_id.to_string(),
+            deadline,
+            scheduled_end: st.end_date,
+            late_days,
+            total_cost: crashes.iter().map(|c| c.cost).sum(),
+            crashes,
+            recovered_days,
+        });
+    }
+    Ok(plans)
+}
+
+/// The target and its chain of binding predecessors in the schedule.
+fn binding_chain<'a>(
+    target: &'a ScheduledTask,
+    by_id: &FxHashMap<&str, &'a Task>,
+    scheduled: &FxHashMap<&str, &'a ScheduledTask>,
+    result: &'a AlgorithmResult,
+    lag_calendar: &[(NaiveDate, NaiveDate)],
+) -> Vec<&'a str> {
+    let mut chain = vec![target.task_id.as_str()];
+    let mut seen: FxHashSet<&str> = chain.iter().copied().collect();
+    let mut current = target;
+    loop {
+        let dependency = by_id
+            .get(current.task_id.as_str())
+            .into_iter()
+            .flat_map(|t| &t.dependencies)
+            .filter_map(|dep| {
+                let pred = scheduled.get(dep.entity_id.as_str())?;
+                (dep.ready_date(pred.end_date, lag_calendar) >= current.start_date).then_some(*pred)
+            })
+            .max_by(|a, b| (a.end_date, &b.task_id).cmp(&(b.end_date, &a.task_id)));
+        let resource = || {
+            result
+                .scheduled_tasks
+                .iter()
+                .filter(|other| {
+                    other.end_date.succ_opt() == Some(current.start_date)
+                        && other
+                            .resources
+                            .iter()
+                            .any(|r| current.resources.contains(r))
+                })
+                .min_by(|a, b| a.task_id.cmp(&b.task_id))
+        };
+        match dependency.or_else(resource) {
+            Some(pred) if seen.insert(pred.task_id.as_str()) => {
+                chain.push(pred.task_id.as_str());
+                current = pred;
+            }
+            _ => break,
+        }
+    }
+    chain
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{CrashSpec, Dependency};
+
+    fn d(day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(2025, 1, day).unwrap()
+    }
+
+    fn task(id: &str, duration_days: f64, deps: &[&str], crash: Option<(f64, f64)>) -> Task {
+        Task {
+            id: id.to_string(),
+            duration_days,
+            dependencies: deps
+                .iter()
+                .map(|dep| Dependency {
+                    entity_id: dep.to_string(),
+                    ..Default::default()
+                })
+                .collect(),
+            crashable: crash.map(|(min_duration_days, cost_per_day)| CrashSpec {
+                min_duration_days,
+                cost_per_day,
+            }),
+            ..Default::default()
+        }
+    }
+
+    fn placed(id: &str, start: u32, end: u32, resource: &str) -> ScheduledTask {
+        ScheduledTask {
+            task_id: id.to_string(),
+            start_date: d(start),
+            end_date: d(end),
+            resources: vec![resource.to_string()],
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_suggest_crashes_cheapest_first() {
+        let mut ship = task("ship", 2.0, &["build"], None);
+        ship.end_before = Some(d(10));
+        let tasks = vec![
+            task("design", 4.0, &[], Some((2.0, 100.0))),
+            task("build", 4.0, &["design"], Some((3.0, 50.0))),
+            // Off the binding chain: never suggested
+            task("docs", 3.0, &[], Some((1.0, 1.0))),
+            ship,
+        ];
+        let result = AlgorithmResult {
+            scheduled_tasks: vec![
+                placed("design", 1, 5, "alice"),
+                placed("docs", 1, 4, "bob"),
+                placed("build", 6, 10, "bob"),
+                placed("ship", 11, 13, "carol"),
+            ],
+            ..Default::default()
+        };
+
+        let plans = suggest_crashes(&tasks, &result, None, &[]).unwrap();
+        assert_eq!(plans.len(), 1);
+        let plan = &plans[0];
+        assert_eq!(plan.target_id, "ship");
+        assert_eq!(plan.late_days, 3.0);
+        // build saves its one spare day at 50, then design two days at 100
+        let crashed: Vec<(&str, f64)> = plan
+            .crashes
+            .iter()
+            .map(|c| (c.task_id.as_str(), c.days_saved))
+            .collect();
+        assert_eq!(crashed, vec![("build", 1.0), ("design", 2.0)]);
+        assert_eq!(plan.total_cost, 250.0);
+        assert!(plan.is_sufficient());
+
+        let err = suggest_crashes(&tasks, &result, Some(&["ghost".to_string()]), &[]).unwrap_err();
+        assert!(matches!(err, CrashError::UnknownTargets(ids) if ids == ["ghost"]));
+    }
+
+    #[test]
+    fn test_suggest_crashes_rejects_invalid_spec() {
+        let tasks = vec![
+            task("a", 2.0, &[], Some((3.0, 10.0))),
+            task("b", 2.0, &[], Some((1.0, f64::NAN))),
+            task("c", 2.0, &[], Some((-1.0, 10.0))),
+        ];
+        let err = suggest_crashes(&tasks, &AlgorithmResult::default(), None, &[]).unwrap_err();
+        let CrashError::InvalidCrashSpec(config) = err else {
+            panic!("expected InvalidCrashSpec, got {:?}", err);
+        };
+        assert_eq!(
+            config.problems,
+            [
+                "task 'a' min_duration_days 3 exceeds duration_days 2",
+                "task 'b' cost_per_day must be non-negative, got NaN",
+                "task 'c' min_duration_days must be non-negative, got -1",
+            ]
+        );
+        assert!(CrashSpec::new(1.0, f64::INFINITY).is_err());
+    }
+}
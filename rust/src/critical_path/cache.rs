@@ -9,12 +9,96 @@ use rustc_hash::{FxHashMap, FxHashSet};
 
 use crate::models::Task;
 
-use super::calculation::{calculate_critical_path_interned, TaskData as InternedContext};
+use super::calculation::{
+    calculate_critical_path_scratch, CriticalPathScratch, TaskData as InternedContext,
+};
 use super::scoring::{
     compute_deadline_urgency, compute_no_deadline_urgency, compute_task_urgency,
     get_urgency_denominator, score_task_unified, transform_work,
 };
-use super::types::{CriticalPathConfig, TargetInfo, TaskId};
+use super::types::{Adjacency, CriticalPathConfig, TargetInfo, TaskId};
+
+/// Timing of one task within a target's dependency subgraph.
+#[derive(Clone, Copy, Debug)]
+struct NodeTiming {
+    earliest_start: f64,
+    /// Longest path from this task's finish to the target's finish.
+    tail: f64,
+    /// Index of the target's entry in `task_to_targets_int[task]`.
+    slot: u32,
+}
+
+/// A target's subgraph tasks (including the target) and their timings.
+type TargetGraph = FxHashMap<TaskId, NodeTiming>;
+
+/// Drop the entry at `slot` from `task_int`'s extended index entries,
+/// repointing the entry swapped into its place.
+fn remove_entry(
+    task_to_targets_int: &mut [Vec<(TaskId, f64)>],
+    target_graphs: &mut [TargetGraph],
+    task_int: TaskId,
+    slot: u32,
+) {
+    let entries = &mut task_to_targets_int[task_int as usize];
+    entries.swap_remove(slot as usize);
+    if let Some(&(moved_target, _)) = entries.get(slot as usize) {
+        if let Some(node) = target_graphs[moved_target as usize].get_mut(&task_int) {
+            node.slot = slot;
+        }
+    }
+}
+
+/// Subgraph tasks reachable from `from` along `edges`, ordered so each comes
+/// after every one of them it is reachable through (`back` is the reverse of
+/// `edges`).
+fn subgraph_order(
+    graph: &TargetGraph,
+    from: TaskId,
+    edges: &Adjacency,
+    back: &Adjacency,
+) -> Vec<TaskId> {
+    let mut reached: FxHashSet<TaskId> = FxHashSet::default();
+    let mut stack = vec![from];
+    while let Some(task_int) = stack.pop() {
+        for &(next, _) in &edges[task_int as usize] {
+            if graph.contains_key(&next) && reached.insert(next) {
+                stack.push(next);
+            }
+        }
+    }
+
+    let mut waiting: FxHashMap<TaskId, usize> = reached
+        .iter()
+        .map(|&task_int| {
+            let count = back[task_int as usize]
+                .iter()
+                .filter(|(prev, _)| reached.contains(prev))
+                .count();
+            (task_int, count)
+        })
+        .collect();
+    let mut order: Vec<TaskId> = Vec::with_capacity(reached.len());
+    order.extend(
+        waiting
+            .iter()
+            .filter(|(_, &count)| count == 0)
+            .map(|(&id, _)| id),
+    );
+    let mut next = 0;
+    while next < order.len() {
+        let task_int = order[next];
+        next += 1;
+        for &(after, _) in &edges[task_int as usize] {
+            if let Some(count) = waiting.get_mut(&after) {
+                *count -= 1;
+                if *count == 0 {
+                    order.push(after);
+                }
+            }
+        }
+    }
+    order
+}
 
 /// Cache for critical path target information.
 ///
@@ -34,6 +118,10 @@ pub struct CriticalPathCache {
     /// Used for unified task scoring across all targets.
     task_to_targets_int: Vec<Vec<(TaskId, f64)>>,
 
+    /// Subgraph timings per target, indexed by target_int; empty for tasks
+    /// that are not targets.
+    target_graphs: Vec<TargetGraph>,
+
     /// Precomputed target scores indexed by target_int.
     /// Updated by get_ranked_targets().
     target_scores: Vec<f64>,
@@ -45,6 +133,16 @@ pub struct CriticalPathCache {
     /// Precomputed average work across all targets.
     /// Updated by get_ranked_targets().
     avg_work: f64,
+
+    /// Targets whose score must be recomputed, indexed by target_int.
+    /// Set when a scheduling event changes a target; cleared by get_ranked_targets().
+    score_dirty: Vec<bool>,
+
+    /// `(current_time, avg_work)` the deadline targets were last scored for.
+    scored_for: Option<(NaiveDate, u64)>,
+
+    /// Urgency given to targets without a deadline in the last scoring.
+    no_deadline_urgency: Option<f64>,
 }
 
 impl CriticalPathCache {
@@ -53,6 +151,8 @@ impl CriticalPathCache {
     /// Computes critical paths for all targets and builds the reverse indices.
     /// The extended index (`task_to_targets_int`) tracks ALL tasks in each target's
     /// dependency subgraph with their slack values.
+    /// With the `parallel` feature, the per-target critical paths are computed
+    /// on the rayon thread pool; the indices are built serially afterwards.
     pub fn new(
         unscheduled: &FxHashSet<String>,
         tasks: &FxHashMap<String, Task>,
@@ -69,24 +169,39 @@ impl CriticalPathCache {
         // Initialize Vec-based extended index
         let num_tasks = ctx.index.len();
         let mut task_to_targets_int: Vec<Vec<(TaskId, f64)>> = vec![Vec::new(); num_tasks];
+        let mut target_graphs: Vec<TargetGraph> = vec![TargetGraph::default(); num_tasks];
 
-        for task_id in unscheduled {
-            let task = match tasks.get(task_id) {
-                Some(t) => t,
-                None => continue,
-            };
-
-            // Get integer ID for this task
-            let target_int = match ctx.index.get_id(task_id) {
-                Some(id) => id,
-                None => continue,
-            };
+        let candidates: Vec<(&String, &Task, TaskId)> = unscheduled
+            .iter()
+            .filter_map(|task_id| Some((task_id, tasks.get(task_id)?, ctx.index.get(task_id)?)))
+            .collect();
+        let compute = |scratch: &mut CriticalPathScratch, task_id: &str| {
+            calculate_critical_path_scratch(task_id, ctx, scheduled_vec, completed_vec, scratch)
+        };
+        #[cfg(feature = "parallel")]
+        let results: Vec<_> = {
+            use rayon::prelude::*;
+            candidates
+                .par_iter()
+                .map_init(
+                    || CriticalPathScratch::new(ctx),
+                    |scratch, &(task_id, _, _)| compute(scratch, task_id),
+                )
+                .collect()
+        };
+        #[cfg(not(feature = "parallel"))]
+        let results: Vec<_> = {
+            let mut scratch = CriticalPathScratch::new(ctx);
+            candidates
+                .iter()
+                .map(|&(task_id, _, _)| compute(&mut scratch, task_id))
+                .collect()
+        };
 
+        for ((task_id, task, target_int), cp_result) in candidates.into_iter().zip(results) {
+            let cp_result = cp_result?;
             let priority = task.priority.unwrap_or(default_priority);
-            let deadline = task.end_before;
-
-            let cp_result =
-                calculate_critical_path_interned(task_id, ctx, scheduled_vec, completed_vec)?;
+            let deadline = task.tightest_deadline();
 
             let mut info = TargetInfo::new(task_id.clone(), target_int, priority, deadline);
             info.critical_path_tasks = cp_result.critical_path_tasks.clone();
@@ -97,7 +212,7 @@ impl CriticalPathCache {
             info.critical_path_ints = cp_result
                 .critical_path_tasks
                 .iter()
-                .filter_map(|id| ctx.index.get_id(id))
+                .filter_map(|id| ctx.index.get(id))
                 .collect();
 
             // Build reverse index: for each task on this target's critical path,
@@ -111,9 +226,19 @@ impl CriticalPathCache {
 
             // Build extended reverse index: for ALL tasks in subgraph,
             // add (target_int, slack) to that task's vector
+            let graph = &mut target_graphs[target_int as usize];
             for (subgraph_task_id, timing) in &cp_result.task_timings {
-                if let Some(task_int) = ctx.index.get_id(subgraph_task_id) {
-                    task_to_targets_int[task_int as usize].push((target_int, timing.slack));
+                if let Some(task_int) = ctx.index.get(subgraph_task_id) {
+                    let entries = &mut task_to_targets_int[task_int as usize];
+                    graph.insert(
+                        task_int,
+                        NodeTiming {
+                            earliest_start: timing.earliest_start,
+                            tail: cp_result.critical_path_length - timing.latest_finish,
+                            slot: entries.len() as u32,
+                        },
+                    );
+                    entries.push((target_int, timing.slack));
                 }
             }
 
@@ -124,140 +249,217 @@ impl CriticalPathCache {
             targets,
             task_to_targets,
             task_to_targets_int,
+            target_graphs,
             target_scores: vec![0.0; num_tasks],
             target_denominators: vec![1.0; num_tasks],
             avg_work: 1.0,
+            score_dirty: vec![true; num_tasks],
+            scored_for: None,
+            no_deadline_urgency: None,
         })
     }
 
-    /// Called when a task is scheduled. Removes it as a target and recomputes
-    /// only the affected targets (those that had this task in their subgraph).
+    /// Called when a task is scheduled. Removes it as a target and updates
+    /// the targets that had it in their subgraph.
+    ///
+    /// Only the scheduled task's neighbourhood in each affected subgraph is
+    /// revisited: earliest starts downstream of it, remaining path lengths
+    /// upstream of it, and the upstream tasks it was the last link for. Slack
+    /// is refreshed everywhere only when the target's critical path length
+    /// changes.
     ///
-    /// Returns the number of targets recomputed.
+    /// Returns the number of targets updated.
     pub fn on_task_scheduled(
         &mut self,
         scheduled_task_id: &str,
-        tasks: &FxHashMap<String, Task>,
         ctx: &InternedContext,
         scheduled_vec: &[f64],
         completed_vec: &[bool],
-        default_priority: i32,
-    ) -> Result<usize, super::calculation::CriticalPathError> {
+    ) -> usize {
         // Remove this task as a target (it's now scheduled)
-        self.targets.remove(scheduled_task_id);
-
-        // Get the scheduled task's integer ID for extended index updates
-        let scheduled_task_int = ctx.index.get_id(scheduled_task_id);
-
-        // Find all targets affected by this task being scheduled
-        // We use the extended index to find all targets that had this task in their subgraph
-        let affected_target_ints: Vec<TaskId> = scheduled_task_int
-            .map(|task_int| {
-                self.task_to_targets_int[task_int as usize]
-                    .iter()
-                    .map(|(target_int, _)| *target_int)
-                    .collect()
-            })
-            .unwrap_or_default();
-
-        // Remove this task from the string-based reverse index
+        if let Some(info) = self.targets.remove(scheduled_task_id) {
+            for cp_task_id in &info.critical_path_tasks {
+                if let Some(targets) = self.task_to_targets.get_mut(cp_task_id) {
+                    targets.remove(scheduled_task_id);
+                }
+            }
+        }
         self.task_to_targets.remove(scheduled_task_id);
 
-        // Also remove it from all other entries in the string reverse index
-        for targets in self.task_to_targets.values_mut() {
-            targets.remove(scheduled_task_id);
+        let Some(scheduled_int) = ctx.index.get(scheduled_task_id) else {
+            return 0;
+        };
+        let own_graph = std::mem::take(&mut self.target_graphs[scheduled_int as usize]);
+        for (&task_int, node) in &own_graph {
+            remove_entry(
+                &mut self.task_to_targets_int,
+                &mut self.target_graphs,
+                task_int,
+                node.slot,
+            );
         }
 
-        // Clear this task's entry in the extended index
-        if let Some(task_int) = scheduled_task_int {
-            self.task_to_targets_int[task_int as usize].clear();
-        }
+        // Every remaining target with this task in its subgraph drops it
+        let affected: Vec<TaskId> = self.task_to_targets_int[scheduled_int as usize]
+            .drain(..)
+            .map(|(target_int, _)| target_int)
+            .collect();
+        affected
+            .into_iter()
+            .filter(|&target_int| {
+                self.update_target(target_int, scheduled_int, ctx, scheduled_vec, completed_vec)
+            })
+            .count()
+    }
 
-        // Remove this target from all extended index entries
-        for target_int in &affected_target_ints {
-            // Remove entries pointing to this target from all tasks' entries
-            for task_entry in &mut self.task_to_targets_int {
-                task_entry.retain(|(t_int, _)| t_int != target_int);
+    /// Remove `scheduled_int` from `target_int`'s subgraph and update its timings.
+    ///
+    /// Returns false if the target is not cached.
+    fn update_target(
+        &mut self,
+        target_int: TaskId,
+        scheduled_int: TaskId,
+        ctx: &InternedContext,
+        scheduled_vec: &[f64],
+        completed_vec: &[bool],
+    ) -> bool {
+        let Some(target_id) = ctx.index.resolve(target_int) else {
+            return false;
+        };
+        if !self.targets.contains_key(target_id) {
+            return false;
+        }
+        let mut graph = std::mem::take(&mut self.target_graphs[target_int as usize]);
+        graph.remove(&scheduled_int);
+        let mut removed_work = ctx.durations[scheduled_int as usize];
+        let mut removed = vec![scheduled_int];
+        let mut changed = Vec::new();
+
+        // Upstream: tasks with no dependent left in the subgraph drop out, the
+        // rest get their remaining path to the target recomputed
+        let upstream = subgraph_order(&graph, scheduled_int, &ctx.deps, &ctx.dependents);
+        for task_int in upstream {
+            let tail = ctx.dependents[task_int as usize]
+                .iter()
+                .filter_map(|&(dependent, lag)| {
+                    graph
+                        .get(&dependent)
+                        .map(|node| node.tail + ctx.durations[dependent as usize] + lag)
+                })
+                .reduce(f64::max);
+            match tail {
+                Some(tail) => {
+                    graph
+                        .get_mut(&task_int)
+                        .expect("upstream task in subgraph")
+                        .tail = tail;
+                    changed.push(task_int);
+                }
+                None => {
+                    let node = graph.remove(&task_int).expect("upstream task in subgraph");
+                    remove_entry(
+                        &mut self.task_to_targets_int,
+                        &mut self.target_graphs,
+                        task_int,
+                        node.slot,
+                    );
+                    removed_work += ctx.durations[task_int as usize];
+                    removed.push(task_int);
+                }
             }
         }
 
-        let mut recomputed = 0;
-
-        // Recompute only affected targets (use integer IDs to include all subgraph tasks,
-        // not just critical path tasks)
-        for &target_int in &affected_target_ints {
-            // Skip if this was the scheduled task itself
-            if scheduled_task_int == Some(target_int) {
-                continue;
+        // Downstream: earliest starts now follow the scheduled finish
+        let downstream = subgraph_order(&graph, scheduled_int, &ctx.dependents, &ctx.deps);
+        for task_int in downstream {
+            let mut earliest_start: f64 = 0.0;
+            for &(dep_int, lag) in &ctx.deps[task_int as usize] {
+                let dep_idx = dep_int as usize;
+                if completed_vec[dep_idx] {
+                    continue;
+                }
+                if scheduled_vec[dep_idx] < f64::MAX {
+                    earliest_start = earliest_start.max(scheduled_vec[dep_idx] + lag);
+                } else if let Some(dep) = graph.get(&dep_int) {
+                    earliest_start =
+                        earliest_start.max(dep.earliest_start + ctx.durations[dep_idx] + lag);
+                }
+            }
+            graph
+                .get_mut(&task_int)
+                .expect("downstream task in subgraph")
+                .earliest_start = earliest_start;
+            changed.push(task_int);
+        }
+        // A lone target starts at 0, as in a full calculation with an empty subgraph
+        if graph.len() == 1 {
+            if let Some(node) = graph.get_mut(&target_int) {
+                node.earliest_start = 0.0;
             }
+        }
 
-            // Get the target ID from the integer
-            let target_id = match ctx.index.get_name(target_int) {
-                Some(name) => name.to_string(),
-                None => continue,
-            };
+        let critical_path_length = graph.get(&target_int).map_or(0.0, |node| {
+            node.earliest_start + ctx.durations[target_int as usize]
+        });
+        let info = self.targets.get_mut(target_id).expect("target is cached");
+        if critical_path_length != info.critical_path_length {
+            changed = graph.keys().copied().collect();
+        }
+        info.critical_path_length = critical_path_length;
+        info.total_work -= removed_work;
 
-            // Skip if this target is no longer in our cache (already scheduled)
-            if !self.targets.contains_key(&target_id) {
+        for task_int in removed {
+            let Some(name) = ctx.index.resolve(task_int) else {
                 continue;
-            }
-
-            let task = match tasks.get(&target_id) {
-                Some(t) => t,
-                None => continue,
             };
-
-            let priority = task.priority.unwrap_or(default_priority);
-            let deadline = task.end_before;
-
-            // Recompute critical path
-            let cp_result =
-                calculate_critical_path_interned(&target_id, ctx, scheduled_vec, completed_vec)?;
-
-            // Update the target info
-            let mut info = TargetInfo::new(target_id.clone(), target_int, priority, deadline);
-            info.critical_path_tasks = cp_result.critical_path_tasks.clone();
-            info.total_work = cp_result.total_work;
-            info.critical_path_length = cp_result.critical_path_length;
-
-            // Build critical_path_ints from critical_path_tasks
-            info.critical_path_ints = cp_result
-                .critical_path_tasks
-                .iter()
-                .filter_map(|id| ctx.index.get_id(id))
-                .collect();
-
-            // Update string-based reverse index: remove old entries, add new ones
-            // First, remove this target from all task entries (from the old critical path)
-            for targets in self.task_to_targets.values_mut() {
-                targets.remove(&target_id);
-            }
-
-            // Then add new entries based on the new critical path
-            for cp_task_id in &cp_result.critical_path_tasks {
-                self.task_to_targets
-                    .entry(cp_task_id.clone())
-                    .or_default()
-                    .insert(target_id.clone());
+            if info.critical_path_tasks.remove(name) {
+                if let Some(targets) = self.task_to_targets.get_mut(name) {
+                    targets.remove(target_id);
+                }
             }
-
-            // Update extended index: add new entries for all tasks in subgraph
-            for (subgraph_task_id, timing) in &cp_result.task_timings {
-                if let Some(task_int) = ctx.index.get_id(subgraph_task_id) {
-                    self.task_to_targets_int[task_int as usize].push((target_int, timing.slack));
+        }
+        for task_int in changed {
+            let node = graph[&task_int];
+            let slack = critical_path_length
+                - node.tail
+                - ctx.durations[task_int as usize]
+                - node.earliest_start;
+            self.task_to_targets_int[task_int as usize][node.slot as usize].1 = slack;
+            let Some(name) = ctx.index.resolve(task_int) else {
+                continue;
+            };
+            if slack.abs() < 1e-9 {
+                if info.critical_path_tasks.insert(name.to_string()) {
+                    self.task_to_targets
+                        .entry(name.to_string())
+                        .or_default()
+                        .insert(target_id.to_string());
+                }
+            } else if info.critical_path_tasks.remove(name) {
+                if let Some(targets) = self.task_to_targets.get_mut(name) {
+                    targets.remove(target_id);
                 }
             }
-
-            self.targets.insert(target_id.clone(), info);
-            recomputed += 1;
         }
+        info.critical_path_ints = info
+            .critical_path_tasks
+            .iter()
+            .filter_map(|id| ctx.index.get(id))
+            .collect();
 
-        Ok(recomputed)
+        self.target_graphs[target_int as usize] = graph;
+        self.score_dirty[target_int as usize] = true;
+        true
     }
 
     /// Get all targets as a slice, scored and ranked.
     ///
     /// Computes urgency and score for each target, then sorts by score descending.
+    /// Scores are recomputed lazily: deadline targets when `current_time` or the
+    /// average work changes, targets without a deadline when their shared
+    /// urgency changes, and any target changed by a scheduling event.
+    /// With `max_targets_per_iteration`, only the best targets are selected
+    /// (in linear time) and sorted.
     /// Also populates the precomputed arrays (target_scores, target_denominators)
     /// for use by score_eligible_task().
     /// Returns references to avoid expensive clones of FxHashSet<String>.
@@ -274,57 +476,67 @@ impl CriticalPathCache {
         let avg_work =
             self.targets.values().map(|t| t.total_work).sum::<f64>() / self.targets.len() as f64;
         self.avg_work = avg_work;
+        let key = (current_time, avg_work.to_bits());
+        let stale_deadlines = self.scored_for != Some(key);
+        self.scored_for = Some(key);
 
-        // Compute scores and update in place
-        // First pass: compute min urgency among deadline targets for context
-        let min_deadline_urgency = self
-            .targets
-            .values()
-            .filter_map(|t| {
-                t.deadline.map(|deadline| {
-                    compute_deadline_urgency(
-                        deadline,
-                        t.critical_path_length,
-                        current_time,
-                        config,
-                        avg_work,
-                    )
-                })
-            })
-            .reduce(f64::min);
-
+        // First pass: deadline urgencies, and their minimum for context
+        let mut min_deadline_urgency: Option<f64> = None;
         for target in self.targets.values_mut() {
-            let urgency = match target.deadline {
-                Some(deadline) => compute_deadline_urgency(
+            let Some(deadline) = target.deadline else {
+                continue;
+            };
+            let idx = target.target_int as usize;
+            if stale_deadlines || self.score_dirty[idx] {
+                target.urgency = compute_deadline_urgency(
                     deadline,
                     target.critical_path_length,
                     current_time,
                     config,
                     avg_work,
-                ),
-                None => compute_no_deadline_urgency(min_deadline_urgency, config),
-            };
-
-            let priority = target.priority as f64;
-            let transformed_work = transform_work(target.total_work, config);
-            target.urgency = urgency;
-            target.score = (priority / transformed_work) * urgency;
+                );
+                self.score_dirty[idx] = true;
+            }
+            min_deadline_urgency =
+                Some(min_deadline_urgency.map_or(target.urgency, |m| m.min(target.urgency)));
+        }
+        let no_deadline_urgency = compute_no_deadline_urgency(min_deadline_urgency, config);
+        let stale_no_deadline = self.no_deadline_urgency != Some(no_deadline_urgency);
+        self.no_deadline_urgency = Some(no_deadline_urgency);
 
-            // Populate precomputed arrays for unified task scoring
+        for target in self.targets.values_mut() {
             let idx = target.target_int as usize;
-            if idx < self.target_scores.len() {
+            if self.score_dirty[idx] || (target.deadline.is_none() && stale_no_deadline) {
+                if target.deadline.is_none() {
+                    target.urgency = no_deadline_urgency;
+                }
+                let priority = target.priority as f64;
+                let transformed_work = transform_work(target.total_work, config);
+                target.score = (priority / transformed_work) * target.urgency;
                 self.target_scores[idx] = target.score;
-                self.target_denominators[idx] = get_urgency_denominator(target, avg_work, config);
+                self.score_dirty[idx] = false;
             }
+            // Populate precomputed arrays for unified task scoring
+            self.target_denominators[idx] = get_urgency_denominator(target, avg_work, config);
         }
 
-        // Collect references and sort
+        // Collect references, keep the top K, and sort
         let mut scored: Vec<&TargetInfo> = self.targets.values().collect();
-        scored.sort_by(|a, b| {
+        let by_score = |a: &&TargetInfo, b: &&TargetInfo| {
             b.score
                 .partial_cmp(&a.score)
                 .unwrap_or(std::cmp::Ordering::Equal)
-        });
+        };
+        if let Some(k) = config
+            .max_targets_per_iteration
+            .filter(|&k| k < scored.len())
+        {
+            scored.select_nth_unstable_by(k, |a, b| {
+                by_score(a, b).then(a.target_int.cmp(&b.target_int))
+            });
+            scored.truncate(k);
+        }
+        scored.sort_by(by_score);
 
         scored
     }
@@ -400,26 +612,23 @@ impl CriticalPathCache {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::critical_path::types::{UrgencyDenominator, WorkTransform};
     use crate::models::Dependency;
 
     fn make_task(id: &str, duration: f64, deps: Vec<(&str, f64)>, priority: Option<i32>) -> Task {
         Task {
             id: id.to_string(),
             duration_days: duration,
-            resources: vec![],
             dependencies: deps
                 .into_iter()
                 .map(|(dep_id, lag)| Dependency {
                     entity_id: dep_id.to_string(),
                     lag_days: lag,
+                    ..Default::default()
                 })
                 .collect(),
-            start_after: None,
-            end_before: None,
-            start_on: None,
-            end_on: None,
-            resource_spec: None,
             priority,
+            ..Default::default()
         }
     }
 
@@ -458,6 +667,46 @@ mod tests {
         assert_eq!(targets.len(), 3);
     }
 
+    #[test]
+    fn test_max_targets_per_iteration_keeps_best() {
+        let tasks: FxHashMap<String, Task> = [
+            make_task("low", 1.0, vec![], Some(10)),
+            make_task("mid", 1.0, vec![], Some(50)),
+            make_task("high", 1.0, vec![], Some(90)),
+            make_task("top", 1.0, vec![], Some(100)),
+        ]
+        .into_iter()
+        .map(|t| (t.id.clone(), t))
+        .collect();
+        let unscheduled: FxHashSet<String> = tasks.keys().cloned().collect();
+        let ctx = InternedContext::new(&tasks, 50);
+        let completed_vec = vec![false; ctx.index.len()];
+        let scheduled_vec = vec![f64::MAX; ctx.index.len()];
+        let mut cache = CriticalPathCache::new(
+            &unscheduled,
+            &tasks,
+            &ctx,
+            &scheduled_vec,
+            &completed_vec,
+            50,
+        )
+        .unwrap();
+        let config = CriticalPathConfig {
+            max_targets_per_iteration: Some(2),
+            ..Default::default()
+        };
+
+        let targets = cache.get_ranked_targets(
+            &config,
+            chrono::NaiveDate::from_ymd_opt(2025, 1, 1).unwrap(),
+        );
+        let ids: Vec<&str> = targets.iter().map(|t| t.target_id.as_str()).collect();
+        assert_eq!(ids, ["top", "high"]);
+        // Targets left out of the ranking are still scored
+        let low = ctx.index.get("low").unwrap();
+        assert!(cache.score_eligible_task(low, &config) > 0.0);
+    }
+
     #[test]
     fn test_cache_incremental_update() {
         // Simple chain: a -> b -> c
@@ -486,12 +735,10 @@ mod tests {
         .unwrap();
 
         // Schedule task a
-        let a_id = ctx.index.get_id("a").unwrap() as usize;
+        let a_id = ctx.index.get("a").unwrap() as usize;
         scheduled_vec[a_id] = 0.0; // scheduled at time 0
 
-        let recomputed = cache
-            .on_task_scheduled("a", &tasks, &ctx, &scheduled_vec, &completed_vec, 50)
-            .unwrap();
+        let recomputed = cache.on_task_scheduled("a", &ctx, &scheduled_vec, &completed_vec);
 
         // a is removed, b and c are affected (a was on their critical path)
         let targets = cache.get_ranked_targets(
@@ -503,6 +750,139 @@ mod tests {
         assert_eq!(recomputed, 2);
     }
 
+    #[test]
+    fn test_incremental_update_matches_rebuild() {
+        //   f -> b -> d <- g
+        //   a -> b, a -> c -> d
+        //   e -[1d]-> c
+        let tasks: FxHashMap<String, Task> = [
+            make_task("a", 2.0, vec![], Some(50)),
+            make_task("b", 3.0, vec![("a", 0.0), ("f", 0.0)], Some(50)),
+            make_task("c", 5.0, vec![("a", 0.0), ("e", 1.0)], Some(50)),
+            make_task("d", 1.0, vec![("b", 0.0), ("c", 0.0), ("g", 0.0)], Some(50)),
+            make_task("e", 4.0, vec![], Some(50)),
+            make_task("f", 1.0, vec![], Some(50)),
+            make_task("g", 2.0, vec![], Some(50)),
+        ]
+        .into_iter()
+        .map(|t| (t.id.clone(), t))
+        .collect();
+
+        let mut unscheduled: FxHashSet<String> = tasks.keys().cloned().collect();
+        let ctx = InternedContext::new(&tasks, 50);
+        let completed_vec = vec![false; ctx.index.len()];
+        let mut scheduled_vec = vec![f64::MAX; ctx.index.len()];
+        let mut cache = CriticalPathCache::new(
+            &unscheduled,
+            &tasks,
+            &ctx,
+            &scheduled_vec,
+            &completed_vec,
+            50,
+        )
+        .unwrap();
+
+        let entries = |cache: &CriticalPathCache, task_int: usize| {
+            let mut entries = cache.task_to_targets_int[task_int].clone();
+            entries.sort_by_key(|&(target_int, _)| target_int);
+            entries
+        };
+        for (task_id, end) in [("a", 2.0), ("f", 1.0), ("c", 8.0), ("b", 6.0)] {
+            scheduled_vec[ctx.index.get(task_id).unwrap() as usize] = end;
+            unscheduled.remove(task_id);
+            cache.on_task_scheduled(task_id, &ctx, &scheduled_vec, &completed_vec);
+
+            let rebuilt = CriticalPathCache::new(
+                &unscheduled,
+                &tasks,
+                &ctx,
+                &scheduled_vec,
+                &completed_vec,
+                50,
+            )
+            .unwrap();
+            for (target_id, expected) in &rebuilt.targets {
+                let actual = &cache.targets[target_id];
+                assert_eq!(actual.critical_path_length, expected.critical_path_length);
+                assert!((actual.total_work - expected.total_work).abs() < 1e-9);
+                assert_eq!(actual.critical_path_tasks, expected.critical_path_tasks);
+            }
+            assert_eq!(cache.targets.len(), rebuilt.targets.len());
+            for task_int in 0..ctx.index.len() {
+                let actual = entries(&cache, task_int);
+                let expected = entries(&rebuilt, task_int);
+                assert_eq!(actual.len(), expected.len(), "after scheduling {task_id}");
+                for ((t1, s1), (t2, s2)) in actual.into_iter().zip(expected) {
+                    assert_eq!(t1, t2);
+                    assert!((s1 - s2).abs() < 1e-9);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_lazy_scores_match_full_rescoring() {
+        //   a -> b -> ship (deadline), c -> d
+        let date = |day| chrono::NaiveDate::from_ymd_opt(2025, 1, day).unwrap();
+        let tasks: FxHashMap<String, Task> = [
+            make_task("a", 2.0, vec![], Some(50)),
+            make_task("b", 3.0, vec![("a", 0.0)], Some(70)),
+            Task {
+                end_before: Some(date(12)),
+                ..make_task("ship", 1.0, vec![("b", 0.0)], Some(90))
+            },
+            make_task("c", 4.0, vec![], Some(30)),
+            make_task("d", 1.0, vec![("c", 0.0)], Some(60)),
+        ]
+        .into_iter()
+        .map(|t| (t.id.clone(), t))
+        .collect();
+
+        let mut unscheduled: FxHashSet<String> = tasks.keys().cloned().collect();
+        let ctx = InternedContext::new(&tasks, 50);
+        let completed_vec = vec![false; ctx.index.len()];
+        let mut scheduled_vec = vec![f64::MAX; ctx.index.len()];
+        let config = CriticalPathConfig::default();
+        let mut cache = CriticalPathCache::new(
+            &unscheduled,
+            &tasks,
+            &ctx,
+            &scheduled_vec,
+            &completed_vec,
+            50,
+        )
+        .unwrap();
+        cache.get_ranked_targets(&config, date(1));
+
+        for (task_id, end, day) in [("c", 4.0, 1), ("a", 2.0, 1), ("d", 5.0, 5)] {
+            scheduled_vec[ctx.index.get(task_id).unwrap() as usize] = end;
+            unscheduled.remove(task_id);
+            cache.on_task_scheduled(task_id, &ctx, &scheduled_vec, &completed_vec);
+
+            let mut rebuilt = CriticalPathCache::new(
+                &unscheduled,
+                &tasks,
+                &ctx,
+                &scheduled_vec,
+                &completed_vec,
+                50,
+            )
+            .unwrap();
+            let scores = |cache: &mut CriticalPathCache| -> Vec<(String, f64)> {
+                cache
+                    .get_ranked_targets(&config, date(day))
+                    .into_iter()
+                    .map(|t| (t.target_id.clone(), t.score))
+                    .collect()
+            };
+            assert_eq!(scores(&mut cache), scores(&mut rebuilt), "after {task_id}");
+            for target in rebuilt.targets.values() {
+                let idx = target.target_int as usize;
+                assert_eq!(cache.target_scores[idx], rebuilt.target_scores[idx]);
+            }
+        }
+    }
+
     #[test]
     fn test_urgency_uses_critical_path_length_not_total_work() {
         // Target with parallel work paths:
@@ -603,20 +983,20 @@ mod tests {
         .unwrap();
 
         // User's config values
-        let config = CriticalPathConfig::new(
-            1.5,   // k
-            0.9,   // no_deadline_urgency_multiplier
-            0.001, // urgency_floor (very small)
-            0,
-            true,
-            1.0,
-            Some(60),
-            "power",
-            1.0,
-            true,         // prefer_fungible_resources
-            "global_avg", // urgency_denominator
-        )
-        .unwrap();
+        let config = CriticalPathConfig::builder()
+            .k(1.5)
+            .no_deadline_urgency_multiplier(0.9)
+            .urgency_floor(0.001) // very small
+            .verbosity(0)
+            .rollout_enabled(true)
+            .rollout_score_ratio_threshold(1.0)
+            .rollout_max_horizon_days(Some(60))
+            .work_transform(WorkTransform::Power)
+            .work_exponent(1.0)
+            .prefer_fungible_resources(true)
+            .urgency_denominator(UrgencyDenominator::GlobalAvg)
+            .build()
+            .unwrap();
         let current_time = chrono::NaiveDate::from_ymd_opt(2025, 1, 1).unwrap();
 
         let targets = cache.get_ranked_targets(&config, current_time);
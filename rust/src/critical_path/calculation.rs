@@ -4,30 +4,11 @@ use chrono::NaiveDate;
 use rustc_hash::{FxHashMap, FxHashSet};
 use std::collections::VecDeque;
 
-use crate::models::Task;
+use crate::backward_pass::{canonical_cycle, format_cycle, trace_cycle};
+pub use crate::graph::{build_dependents_map, DependentsMap};
+use crate::models::{LagUnit, Task};
 
-use super::types::{TaskId, TaskIndex, TaskResourceReq, TaskTiming};
-
-/// Pre-computed reverse dependency map: task_id -> Vec<(dependent_id, lag)>
-/// This allows O(1) lookup of all tasks that depend on a given task.
-pub type DependentsMap<'a> = FxHashMap<&'a str, Vec<(&'a str, f64)>>;
-
-/// Build a global dependents map from all tasks.
-/// This should be computed once and reused across multiple critical path calculations.
-pub fn build_dependents_map(tasks: &FxHashMap<String, Task>) -> DependentsMap<'_> {
-    // Pre-size: most tasks have at least one dependency
-    let mut dependents: DependentsMap =
-        FxHashMap::with_capacity_and_hasher(tasks.len(), Default::default());
-    for (task_id, task) in tasks {
-        for dep in &task.dependencies {
-            dependents
-                .entry(&dep.entity_id)
-                .or_default()
-                .push((task_id.as_str(), dep.lag_days));
-        }
-    }
-    dependents
-}
+use super::types::{Adjacency, Bitset, TaskId, TaskIndex, TaskResourceReq, TaskTiming};
 
 /// Pre-computed task data for fast critical path calculations.
 /// Build this once and reuse for multiple target calculations.
@@ -41,16 +22,28 @@ pub struct TaskData {
     pub priorities: Vec<i32>,
     /// Task start_after constraints indexed by task ID.
     pub start_afters: Vec<Option<NaiveDate>>,
+    /// Inclusive periods each task cannot be worked on, indexed by task ID.
+    pub blocked_periods: Vec<Vec<(NaiveDate, NaiveDate)>>,
     /// Task dependencies as (dep_id, lag) pairs, indexed by task ID.
-    pub deps: Vec<Vec<(TaskId, f64)>>,
+    pub deps: Adjacency,
     /// Reverse dependencies (dependents) as (dependent_id, lag) pairs, indexed by task ID.
-    pub dependents: Vec<Vec<(TaskId, f64)>>,
+    pub dependents: Adjacency,
     /// Pre-computed resource requirements indexed by task ID.
     pub resource_reqs: Vec<Option<TaskResourceReq>>,
     /// Explicit resources assigned to each task: Vec<(resource_name, allocation)>
     pub explicit_resources: Vec<Vec<(String, f64)>>,
     /// Resource specs for auto-assignment.
     pub resource_specs: Vec<Option<String>>,
+    /// Skills required of an auto-assigned resource, indexed by task ID.
+    pub required_skills: Vec<Vec<String>>,
+    /// Resource an auto-assigned task is pinned to, indexed by task ID.
+    pub pinned_resources: Vec<Option<String>>,
+    /// Backlog rank of each task, indexed by task ID.
+    pub ranks: Vec<Option<i64>>,
+    /// (dependent, dependency) edges whose lag counts working days.
+    pub working_lags: FxHashSet<(TaskId, TaskId)>,
+    /// Non-working periods that working-day lags skip; see `set_lag_calendar()`.
+    pub lag_calendar: Vec<(NaiveDate, NaiveDate)>,
 }
 
 impl TaskData {
@@ -79,39 +72,61 @@ impl TaskData {
         let mut durations = vec![0.0; n];
         let mut priorities = vec![default_priority; n];
         let mut start_afters = vec![None; n];
-        let mut deps: Vec<Vec<(TaskId, f64)>> = vec![Vec::new(); n];
-        let mut dependents: Vec<Vec<(TaskId, f64)>> = vec![Vec::new(); n];
+        let mut blocked_periods = vec![Vec::new(); n];
+        let mut edges: Vec<(TaskId, TaskId, f64)> = Vec::new();
         let mut explicit_resources = vec![Vec::new(); n];
         let mut resource_specs = vec![None; n];
+        let mut required_skills = vec![Vec::new(); n];
+        let mut pinned_resources = vec![None; n];
+        let mut ranks = vec![None; n];
+        let mut working_lags = FxHashSet::default();
 
         for (task_id, task) in tasks {
-            if let Some(id) = index.get_id(task_id) {
+            if let Some(id) = index.get(task_id) {
                 let idx = id as usize;
                 durations[idx] = task.duration_days;
                 priorities[idx] = task.priority.unwrap_or(default_priority);
                 start_afters[idx] = task.start_after;
+                blocked_periods[idx] = task.blocked_periods.clone();
                 explicit_resources[idx] = task.resources.clone();
                 resource_specs[idx] = task.resource_spec.clone();
+                required_skills[idx] = task.required_skills.clone();
+                pinned_resources[idx] = task.pinned_resource.clone();
+                ranks[idx] = task.rank;
 
                 for dep in &task.dependencies {
-                    if let Some(dep_id) = index.get_id(&dep.entity_id) {
-                        deps[idx].push((dep_id, dep.lag_days));
-                        dependents[dep_id as usize].push((id, dep.lag_days));
+                    if let Some(dep_id) = index.get(&dep.entity_id) {
+                        edges.push((id, dep_id, dep.lag_days));
+                        if dep.lag_unit == LagUnit::Working {
+                            working_lags.insert((id, dep_id));
+                        }
                     }
                 }
             }
         }
 
+        let deps = Adjacency::from_edges(n, &edges);
+        for edge in &mut edges {
+            *edge = (edge.1, edge.0, edge.2);
+        }
+        let dependents = Adjacency::from_edges(n, &edges);
+
         Self {
             index,
             durations,
             priorities,
             start_afters,
+            blocked_periods,
             deps,
             dependents,
             resource_reqs: vec![None; n],
             explicit_resources,
             resource_specs,
+            required_skills,
+            pinned_resources,
+            ranks,
+            working_lags,
+            lag_calendar: Vec::new(),
         }
     }
 
@@ -120,6 +135,27 @@ impl TaskData {
         self.resource_reqs = reqs;
     }
 
+    /// Set the non-working periods that working-day lags skip.
+    pub fn set_lag_calendar(&mut self, periods: Vec<(NaiveDate, NaiveDate)>) {
+        self.lag_calendar = periods;
+    }
+
+    /// First date `task` may start once its dependency `dep` ends on `dep_end`.
+    pub fn dependency_ready(
+        &self,
+        task: TaskId,
+        dep: TaskId,
+        dep_end: NaiveDate,
+        lag: f64,
+    ) -> NaiveDate {
+        let unit = if self.working_lags.contains(&(task, dep)) {
+            LagUnit::Working
+        } else {
+            LagUnit::Calendar
+        };
+        unit.ready_date(dep_end, lag, &self.lag_calendar)
+    }
+
     /// Get number of tasks.
     pub fn len(&self) -> usize {
         self.index.len()
@@ -134,7 +170,7 @@ impl TaskData {
     pub fn to_bool_vec(&self, strings: &FxHashSet<String>) -> Vec<bool> {
         let mut result = vec![false; self.index.len()];
         for s in strings {
-            if let Some(id) = self.index.get_id(s) {
+            if let Some(id) = self.index.get(s) {
                 result[id as usize] = true;
             }
         }
@@ -150,7 +186,7 @@ impl TaskData {
     ) -> Vec<f64> {
         let mut result = vec![f64::MAX; self.index.len()];
         for (id, (_, end)) in scheduled {
-            if let Some(int_id) = self.index.get_id(id) {
+            if let Some(int_id) = self.index.get(id) {
                 let days = (*end - current_time).num_days() as f64;
                 result[int_id as usize] = days.max(0.0);
             }
@@ -167,7 +203,7 @@ impl TaskData {
     ) -> Vec<f64> {
         let mut result = vec![f64::MAX; self.index.len()];
         for (id, (start, _)) in scheduled {
-            if let Some(int_id) = self.index.get_id(id) {
+            if let Some(int_id) = self.index.get(id) {
                 let days = (*start - current_time).num_days() as f64;
                 result[int_id as usize] = days;
             }
@@ -179,7 +215,7 @@ impl TaskData {
     pub fn to_unscheduled_vec(&self, unscheduled: &FxHashSet<String>) -> Vec<bool> {
         let mut result = vec![false; self.index.len()];
         for id in unscheduled {
-            if let Some(int_id) = self.index.get_id(id) {
+            if let Some(int_id) = self.index.get(id) {
                 result[int_id as usize] = true;
             }
         }
@@ -195,7 +231,7 @@ impl TaskData {
     ) -> Vec<(f64, f64)> {
         let mut result = vec![(f64::MAX, f64::MAX); self.index.len()];
         for (id, (start, end)) in scheduled {
-            if let Some(int_id) = self.index.get_id(id) {
+            if let Some(int_id) = self.index.get(id) {
                 let start_offset = (*start - reference_time).num_days() as f64;
                 let end_offset = (*end - reference_time).num_days() as f64;
                 result[int_id as usize] = (start_offset, end_offset.max(0.0));
@@ -221,14 +257,28 @@ pub type InternedContext = TaskData;
 /// Error types for critical path calculation.
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum CriticalPathError {
-    CircularDependency,
+    /// Task IDs on the cycle, each depending on the next.
+    CircularDependency(Vec<String>),
+}
+
+impl CriticalPathError {
+    /// The offending dependency cycle, if this error describes one.
+    pub fn cycle(&self) -> Option<&[String]> {
+        match self {
+            CriticalPathError::CircularDependency(cycle) => Some(cycle),
+        }
+    }
 }
 
 impl std::fmt::Display for CriticalPathError {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
-            CriticalPathError::CircularDependency => {
-                write!(f, "Circular dependency detected in task graph")
+            CriticalPathError::CircularDependency(cycle) => {
+                write!(
+                    f,
+                    "Circular dependency detected in task graph: {}",
+                    format_cycle(cycle)
+                )
             }
         }
     }
@@ -455,6 +505,30 @@ pub fn calculate_critical_path_with_dependents<'a>(
     })
 }
 
+/// Buffers for `calculate_critical_path_scratch`, sized to one `TaskData`.
+///
+/// Reusing one across targets avoids allocating per-task arrays for every
+/// critical path. Every buffer is left cleared between calls.
+pub struct CriticalPathScratch {
+    nodes: Bitset,
+    in_degree: Vec<usize>,
+    timings: Vec<Option<TaskTiming>>,
+    queue: VecDeque<TaskId>,
+}
+
+impl CriticalPathScratch {
+    /// Create buffers for tasks in `ctx`.
+    pub fn new(ctx: &TaskData) -> Self {
+        let n = ctx.index.len();
+        Self {
+            nodes: Bitset::new(n),
+            in_degree: vec![0; n],
+            timings: vec![None; n],
+            queue: VecDeque::new(),
+        }
+    }
+}
+
 /// Calculate critical path using integer IDs and array indexing for maximum performance.
 /// All internal operations use integer IDs with direct array access; strings are only used at boundaries.
 pub fn calculate_critical_path_interned(
@@ -463,7 +537,24 @@ pub fn calculate_critical_path_interned(
     scheduled_vec: &[f64],  // indexed by TaskId, f64::MAX means not scheduled
     completed_vec: &[bool], // indexed by TaskId
 ) -> Result<CriticalPathResult, CriticalPathError> {
-    let target_int = match ctx.index.get_id(target_id) {
+    calculate_critical_path_scratch(
+        target_id,
+        ctx,
+        scheduled_vec,
+        completed_vec,
+        &mut CriticalPathScratch::new(ctx),
+    )
+}
+
+/// `calculate_critical_path_interned` using caller-owned buffers.
+pub fn calculate_critical_path_scratch(
+    target_id: &str,
+    ctx: &TaskData,
+    scheduled_vec: &[f64],
+    completed_vec: &[bool],
+    scratch: &mut CriticalPathScratch,
+) -> Result<CriticalPathResult, CriticalPathError> {
+    let target_int = match ctx.index.get(target_id) {
         Some(id) => id,
         None => {
             return Ok(CriticalPathResult {
@@ -475,11 +566,9 @@ pub fn calculate_critical_path_interned(
         }
     };
 
-    let n = ctx.index.len();
-
     // Find subgraph using array-based lookup
-    let (subgraph_vec, subgraph_ids) =
-        find_dependency_subgraph_vec(target_int, ctx, completed_vec, scheduled_vec);
+    let subgraph_ids =
+        find_dependency_subgraph_vec(target_int, ctx, completed_vec, scheduled_vec, scratch);
 
     if subgraph_ids.is_empty() {
         let duration = ctx.durations[target_int as usize];
@@ -505,11 +594,12 @@ pub fn calculate_critical_path_interned(
     }
 
     // Topological sort with array-based structures
-    let topo_order = topological_sort_vec(&subgraph_vec, &subgraph_ids, target_int, ctx)?;
+    let topo_order = topological_sort_vec(&subgraph_ids, target_int, ctx, scratch);
+    scratch.nodes.clear();
+    let topo_order = topo_order?;
 
-    // Forward pass with array indexing
-    // Use Vec<Option<TaskTiming>> for O(1) access
-    let mut timings: Vec<Option<TaskTiming>> = vec![None; n];
+    // Forward pass with array indexing; every entry set here is taken below
+    let timings = &mut scratch.timings;
     let mut total_work = 0.0;
 
     for &task_int in &topo_order {
@@ -565,9 +655,6 @@ pub fn calculate_critical_path_interned(
         // Use dependents for O(1) lookup
         for &(dependent_int, lag) in &ctx.dependents[idx] {
             let dep_idx = dependent_int as usize;
-            if !subgraph_vec[dep_idx] && dependent_int != target_int {
-                continue;
-            }
             if let Some(ref dep_timing) = timings[dep_idx] {
                 let required_finish = dep_timing.latest_start - lag;
                 if required_finish < latest_finish {
@@ -599,7 +686,7 @@ pub fn calculate_critical_path_interned(
     for &task_int in &topo_order {
         let idx = task_int as usize;
         if let Some(timing) = timings[idx].take() {
-            if let Some(name) = ctx.index.get_name(task_int) {
+            if let Some(name) = ctx.index.resolve(task_int) {
                 if timing.is_critical() {
                     critical_path_tasks.insert(name.to_string());
                 }
@@ -617,17 +704,18 @@ pub fn calculate_critical_path_interned(
 }
 
 /// Find dependency subgraph using array-based lookups.
-/// Returns (subgraph_vec, subgraph_ids) where subgraph_vec[i] is true if task i is in subgraph.
+/// Returns the subgraph's task IDs and leaves them marked in `scratch.nodes`.
 fn find_dependency_subgraph_vec(
     target_int: TaskId,
     ctx: &TaskData,
     completed_vec: &[bool],
     scheduled_vec: &[f64],
-) -> (Vec<bool>, Vec<TaskId>) {
+    scratch: &mut CriticalPathScratch,
+) -> Vec<TaskId> {
     let n = ctx.index.len();
-    let mut subgraph_vec = vec![false; n];
+    let nodes = &mut scratch.nodes;
+    let queue = &mut scratch.queue;
     let mut subgraph_ids = Vec::new();
-    let mut queue: VecDeque<TaskId> = VecDeque::new();
 
     // Start from target's dependencies
     for &(dep_int, _) in &ctx.deps[target_int as usize] {
@@ -639,10 +727,9 @@ fn find_dependency_subgraph_vec(
 
     while let Some(task_int) = queue.pop_front() {
         let idx = task_int as usize;
-        if subgraph_vec[idx] {
+        if !nodes.insert(task_int) {
             continue;
         }
-        subgraph_vec[idx] = true;
         subgraph_ids.push(task_int);
 
         for &(dep_int, _) in &ctx.deps[idx] {
@@ -650,50 +737,43 @@ fn find_dependency_subgraph_vec(
             if dep_idx < n
                 && !completed_vec[dep_idx]
                 && scheduled_vec[dep_idx] == f64::MAX
-                && !subgraph_vec[dep_idx]
+                && !nodes.contains(dep_int)
             {
                 queue.push_back(dep_int);
             }
         }
     }
 
-    (subgraph_vec, subgraph_ids)
+    subgraph_ids
 }
 
-/// Topological sort using array-based lookups.
+/// Topological sort of the subgraph marked in `scratch.nodes` plus the target.
 fn topological_sort_vec(
-    subgraph_vec: &[bool],
     subgraph_ids: &[TaskId],
     target_int: TaskId,
     ctx: &TaskData,
+    scratch: &mut CriticalPathScratch,
 ) -> Result<Vec<TaskId>, CriticalPathError> {
     let n = ctx.index.len();
     let node_count = subgraph_ids.len() + 1;
 
     // Build node set including target
-    let mut node_vec = subgraph_vec.to_vec();
-    node_vec[target_int as usize] = true;
+    let node_vec = &mut scratch.nodes;
+    node_vec.insert(target_int);
 
-    // Calculate in-degrees using array
-    let mut in_degree = vec![0usize; n];
-    for &task_int in subgraph_ids {
-        let idx = task_int as usize;
-        for &(dep_int, _) in &ctx.deps[idx] {
-            if node_vec[dep_int as usize] {
-                in_degree[idx] += 1;
-            }
-        }
-    }
-    // Also for target
+    // Calculate in-degrees; only entries for nodes are read, so reset just those
+    let in_degree = &mut scratch.in_degree;
     let target_idx = target_int as usize;
-    for &(dep_int, _) in &ctx.deps[target_idx] {
-        if node_vec[dep_int as usize] {
-            in_degree[target_idx] += 1;
-        }
+    for &task_int in subgraph_ids.iter().chain([&target_int]) {
+        let idx = task_int as usize;
+        in_degree[idx] = ctx.deps[idx]
+            .iter()
+            .filter(|&&(dep_int, _)| node_vec.contains(dep_int))
+            .count();
     }
 
     // Initialize queue with zero in-degree nodes
-    let mut queue: VecDeque<TaskId> = VecDeque::new();
+    let queue = &mut scratch.queue;
     for &task_int in subgraph_ids {
         if in_degree[task_int as usize] == 0 {
             queue.push_back(task_int);
@@ -711,7 +791,7 @@ fn topological_sort_vec(
         // Update dependents
         for &(dependent_int, _) in &ctx.dependents[task_int as usize] {
             let dep_idx = dependent_int as usize;
-            if node_vec[dep_idx] {
+            if node_vec.contains(dependent_int) {
                 in_degree[dep_idx] -= 1;
                 if in_degree[dep_idx] == 0 {
                     queue.push_back(dependent_int);
@@ -721,7 +801,24 @@ fn topological_sort_vec(
     }
 
     if result.len() != node_count {
-        return Err(CriticalPathError::CircularDependency);
+        // Every leftover node still waits on a leftover dependency, so following
+        // dependencies from any of them must loop.
+        let leftover = |id: TaskId| node_vec.contains(id) && in_degree[id as usize] > 0;
+        let start = (0..n as TaskId)
+            .find(|&id| leftover(id))
+            .unwrap_or(target_int);
+        let cycle = trace_cycle(start, |id| {
+            ctx.deps[id as usize]
+                .iter()
+                .map(|&(dep_int, _)| dep_int)
+                .find(|&dep_int| leftover(dep_int))
+        });
+        return Err(CriticalPathError::CircularDependency(canonical_cycle(
+            cycle
+                .into_iter()
+                .map(|id| ctx.index.resolve(id).unwrap_or("?").to_string())
+                .collect(),
+        )));
     }
 
     Ok(result)
@@ -838,7 +935,25 @@ fn topological_sort_subgraph<'a>(
     }
 
     if result.len() != nodes.len() {
-        return Err(CriticalPathError::CircularDependency);
+        let leftover = |id: &str| in_degree.get(id).is_some_and(|&d| d > 0);
+        let start = nodes
+            .iter()
+            .copied()
+            .filter(|&id| leftover(id))
+            .min()
+            .unwrap_or(target_id);
+        let cycle = trace_cycle(start, |id| {
+            tasks.get(id).and_then(|task| {
+                task.dependencies
+                    .iter()
+                    .filter_map(|dep| nodes.get(dep.entity_id.as_str()).copied())
+                    .filter(|&dep_id| leftover(dep_id))
+                    .min()
+            })
+        });
+        return Err(CriticalPathError::CircularDependency(canonical_cycle(
+            cycle.into_iter().map(str::to_string).collect(),
+        )));
     }
 
     Ok(result)
@@ -853,20 +968,16 @@ mod tests {
         Task {
             id: id.to_string(),
             duration_days: duration,
-            resources: vec![],
             dependencies: deps
                 .into_iter()
                 .map(|(dep_id, lag)| Dependency {
                     entity_id: dep_id.to_string(),
                     lag_days: lag,
+                    ..Default::default()
                 })
                 .collect(),
-            start_after: None,
-            end_before: None,
-            start_on: None,
-            end_on: None,
-            resource_spec: None,
             priority: Some(50),
+            ..Default::default()
         }
     }
 
@@ -1003,4 +1114,49 @@ mod tests {
         assert!(result.critical_path_tasks.contains("b"));
         assert!(!result.critical_path_tasks.contains("a"));
     }
+
+    #[test]
+    fn test_cycle_reported_in_error() {
+        // t -> c -> b -> a -> c, with t outside the cycle
+        let mut tasks = FxHashMap::default();
+        tasks.insert("a".to_string(), make_task("a", 1.0, vec![("c", 0.0)]));
+        tasks.insert("b".to_string(), make_task("b", 1.0, vec![("a", 0.0)]));
+        tasks.insert("c".to_string(), make_task("c", 1.0, vec![("b", 0.0)]));
+        tasks.insert("t".to_string(), make_task("t", 1.0, vec![("c", 0.0)]));
+
+        let err =
+            calculate_critical_path("t", &tasks, &FxHashMap::default(), &FxHashSet::default())
+                .unwrap_err();
+        let expected: Vec<String> = ["a", "c", "b"].iter().map(|s| s.to_string()).collect();
+        assert_eq!(err.cycle(), Some(&expected[..]));
+        assert!(err.to_string().ends_with("a -> c -> b -> a"));
+    }
+
+    #[test]
+    fn test_scratch_reused_across_targets() {
+        let mut tasks = FxHashMap::default();
+        tasks.insert("a".to_string(), make_task("a", 2.0, vec![]));
+        tasks.insert("b".to_string(), make_task("b", 3.0, vec![("a", 0.0)]));
+        tasks.insert("c".to_string(), make_task("c", 5.0, vec![("a", 1.0)]));
+        tasks.insert(
+            "d".to_string(),
+            make_task("d", 1.0, vec![("b", 0.0), ("c", 0.0)]),
+        );
+        let ctx = TaskData::new(&tasks, 0);
+        let scheduled = vec![f64::MAX; ctx.len()];
+        let completed = vec![false; ctx.len()];
+
+        let mut scratch = CriticalPathScratch::new(&ctx);
+        for target in ["d", "b", "a", "c", "d"] {
+            let reused =
+                calculate_critical_path_scratch(target, &ctx, &scheduled, &completed, &mut scratch)
+                    .unwrap();
+            let fresh =
+                calculate_critical_path_interned(target, &ctx, &scheduled, &completed).unwrap();
+            assert_eq!(reused.critical_path_length, fresh.critical_path_length);
+            assert_eq!(reused.total_work, fresh.total_work);
+            assert_eq!(reused.critical_path_tasks, fresh.critical_path_tasks);
+            assert_eq!(reused.task_timings.len(), fresh.task_timings.len());
+        }
+    }
 }
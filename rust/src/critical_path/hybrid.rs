@@ -0,0 +1,203 @@
+//! Hybrid task ordering: critical path targets, SGS strategy within them.
+//!
+//! The critical path scheduler normally orders eligible tasks by their
+//! unified score. With a `SgsOrdering`, tasks are grouped by the best-ranked
+//! target they feed, and each group is ordered by the parallel scheduler's
+//! sort strategy (`priority_first`, `cr_first`, `weighted` or `atc`), using
+//! deadlines and priorities from the backward pass.
+
+use chrono::NaiveDate;
+use rustc_hash::{FxHashMap, FxHashSet};
+
+use crate::backward_pass::{
+    backward_pass, BackwardPassConfig, BackwardPassError, DeadlineCalendar,
+};
+use crate::config::SchedulingConfig;
+use crate::models::Task;
+use crate::scheduler::ResourceConfig;
+use crate::sorting::{compute_critical_ratio, compute_sort_key, AtcParams, TaskSortInfo};
+
+use super::cache::CriticalPathCache;
+use super::calculation::TaskData;
+use super::types::{TargetInfo, TaskId};
+
+/// Sort strategy and backward-pass results for hybrid ordering.
+pub(crate) struct SgsOrdering {
+    config: SchedulingConfig,
+    deadlines: FxHashMap<String, NaiveDate>,
+    priorities: FxHashMap<String, i32>,
+}
+
+impl SgsOrdering {
+    /// Run the backward pass over `tasks` with the settings of `config`.
+    pub fn new(
+        tasks: &FxHashMap<String, Task>,
+        completed_task_ids: &FxHashSet<String>,
+        config: SchedulingConfig,
+        resource_config: Option<&ResourceConfig>,
+        global_dns_periods: &[(NaiveDate, NaiveDate)],
+    ) -> Result<Self, BackwardPassError> {
+        let bp_config = BackwardPassConfig {
+            default_priority: config.default_priority,
+            priority_decay: config.priority_decay,
+            contention_aware: config.contention_aware_deadlines,
+            calendar: config.calendar_aware_deadlines.then(|| DeadlineCalendar {
+                global_dns_periods: global_dns_periods.to_vec(),
+                dns_periods: resource_config
+                    .map(|rc| rc.dns_periods.clone().into_iter().collect())
+                    .unwrap_or_default(),
+            }),
+            lag_calendar: global_dns_periods.to_vec(),
+            candidate_resources: resource_config
+                .filter(|_| config.contention_aware_deadlines)
+                .map(|rc| rc.candidate_resources(tasks.values()))
+                .unwrap_or_default(),
+        };
+        let tasks: Vec<Task> = tasks.values().cloned().collect();
+        let result = backward_pass(&tasks, completed_task_ids, &bp_config)?;
+        Ok(Self {
+            config,
+            deadlines: result.computed_deadlines,
+            priorities: result.computed_priorities,
+        })
+    }
+
+    /// The scheduling config this ordering was built with.
+    pub fn into_config(self) -> SchedulingConfig {
+        self.config
+    }
+
+    /// Name of the configured sort strategy.
+    pub fn strategy(&self) -> &str {
+        &self.config.strategy
+    }
+
+    /// Reorder scored eligible tasks: by the rank of their best target, then
+    /// by the sort strategy.
+    pub fn reorder(
+        &self,
+        scored_tasks: &mut [(TaskId, f64)],
+        ranked_targets: &[TargetInfo],
+        cache: &CriticalPathCache,
+        ctx: &TaskData,
+        unscheduled_vec: &[bool],
+        current_time: NaiveDate,
+    ) {
+        let target_rank: FxHashMap<TaskId, usize> = ranked_targets
+            .iter()
+            .enumerate()
+            .map(|(rank, t)| (t.target_int, rank))
+            .collect();
+        let unscheduled: Vec<TaskId> = unscheduled_vec
+            .iter()
+            .enumerate()
+            .filter(|(_, &u)| u)
+            .map(|(idx, _)| idx as TaskId)
+            .collect();
+        let default_cr = self.default_cr(&unscheduled, ctx, current_time);
+        let atc_params = (self.config.strategy == "atc")
+            .then(|| self.atc_params(&unscheduled, ctx, current_time));
+
+        let mut keyed: Vec<_> = scored_tasks
+            .iter()
+            .map(|&(task_int, score)| {
+                let group = cache
+                    .get_task_targets(task_int)
+                    .iter()
+                    .filter_map(|(target_int, _)| target_rank.get(target_int).copied())
+                    .min()
+                    .unwrap_or(usize::MAX);
+                let task_id = ctx.index.resolve(task_int).unwrap_or_default();
+                let key = compute_sort_key(
+                    task_id,
+                    &self.sort_info(task_int, ctx),
+                    current_time,
+                    default_cr,
+                    &self.config,
+                    atc_params.as_ref(),
+                )
+                .expect("strategy is validated when hybrid ordering is set");
+                ((group, key), (task_int, score))
+            })
+            .collect();
+        keyed.sort_by(|a, b| a.0.cmp(&b.0));
+        for (slot, (_, entry)) in scored_tasks.iter_mut().zip(keyed) {
+            *slot = entry;
+        }
+    }
+
+    fn deadline(&self, task_int: TaskId, ctx: &TaskData) -> Option<NaiveDate> {
+        let task_id = ctx.index.resolve(task_int)?;
+        self.deadlines.get(task_id).copied()
+    }
+
+    fn sort_info(&self, task_int: TaskId, ctx: &TaskData) -> TaskSortInfo {
+        let task_id = ctx.index.resolve(task_int).unwrap_or_default();
+        TaskSortInfo {
+            duration_days: ctx.durations[task_int as usize],
+            deadline: self.deadline(task_int, ctx),
+            priority: self
+                .priorities
+                .get(task_id)
+                .copied()
+                .unwrap_or(self.config.default_priority),
+            rank: ctx.ranks[task_int as usize],
+        }
+    }
+
+    /// Critical ratio for tasks without deadlines, as in the parallel scheduler.
+    fn default_cr(&self, unscheduled: &[TaskId], ctx: &TaskData, current_time: NaiveDate) -> f64 {
+        let max_cr = unscheduled
+            .iter()
+            .filter_map(|&t| {
+                let deadline = self.deadline(t, ctx).filter(|d| *d != NaiveDate::MAX)?;
+                Some(compute_critical_ratio(
+                    Some(deadline),
+                    ctx.durations[t as usize],
+                    current_time,
+                    0.0,
+                ))
+            })
+            .fold(0.0, f64::max);
+        (max_cr * self.config.default_cr_multiplier).max(self.config.default_cr_floor)
+    }
+
+    /// ATC parameters over the unscheduled tasks, as in the parallel scheduler.
+    fn atc_params(
+        &self,
+        unscheduled: &[TaskId],
+        ctx: &TaskData,
+        current_time: NaiveDate,
+    ) -> AtcParams {
+        let avg_duration = if unscheduled.is_empty() {
+            1.0
+        } else {
+            unscheduled
+                .iter()
+                .map(|&t| ctx.durations[t as usize])
+                .sum::<f64>()
+                / unscheduled.len() as f64
+        };
+        let min_urgency = unscheduled
+            .iter()
+            .filter_map(|&t| {
+                let deadline = self.deadline(t, ctx).filter(|d| *d != NaiveDate::MAX)?;
+                let slack = (deadline - current_time).num_days() as f64 - ctx.durations[t as usize];
+                Some(if slack <= 0.0 {
+                    1.0
+                } else {
+                    (-slack / (self.config.atc_k * avg_duration)).exp()
+                })
+            })
+            .reduce(f64::min);
+        let default_urgency = match min_urgency {
+            Some(urgency) => (urgency * self.config.atc_default_urgency_multiplier)
+                .max(self.config.atc_default_urgency_floor),
+            None => self.config.atc_default_urgency_floor,
+        };
+        AtcParams {
+            avg_duration,
+            default_urgency,
+        }
+    }
+}
@@ -7,20 +7,26 @@
 
 mod cache;
 mod calculation;
+mod hybrid;
 pub mod rollout;
 mod scheduler;
 mod scoring;
 mod state;
 mod types;
 
+pub use cache::CriticalPathCache;
 pub use calculation::{
     build_dependents_map, calculate_critical_path, calculate_critical_path_interned,
-    calculate_critical_path_with_dependents, CriticalPathResult, DependentsMap, InternedContext,
+    calculate_critical_path_scratch, calculate_critical_path_with_dependents, CriticalPathResult,
+    CriticalPathScratch, DependentsMap, InternedContext,
+};
+pub use rollout::{
+    CriticalPathRolloutDecision, ReservationRecord, ResourceReservation, RolloutConfig,
 };
-pub use rollout::{ResourceReservation, RolloutConfig};
 pub use scheduler::{CriticalPathScheduler, CriticalPathSchedulerError};
 pub use scoring::{score_target, score_task};
 pub use state::CriticalPathSchedulerState;
 pub use types::{
-    CriticalPathConfig, ResourceIndex, ResourceMask, TargetInfo, TaskResourceReq, TaskTiming,
+    Adjacency, Bitset, CriticalPathConfig, CriticalPathConfigBuilder, ResourceIndex, ResourceMask,
+    TargetInfo, TaskResourceReq, TaskTiming, UrgencyDenominator, WorkTransform,
 };
@@ -6,7 +6,9 @@ use super::CompetingTarget;
 use crate::critical_path::calculation::TaskData;
 use crate::critical_path::state::CriticalPathSchedulerState;
 use crate::critical_path::types::{ResourceIndex, TargetInfo, TaskId};
+use crate::models::first_unblocked;
 use crate::scheduler::ResourceConfig;
+use crate::validation::offset_date;
 
 /// Find competing targets that may warrant delaying the current task.
 ///
@@ -33,7 +35,7 @@ pub fn find_competing_targets(
     let score_threshold = current_target_score * score_ratio_threshold;
 
     // Get resource ID for checking
-    let resource_id = resource_index.get_id(resource);
+    let resource_id = resource_index.get(resource);
 
     for target in all_targets {
         // Skip if target score is not high enough
@@ -113,10 +115,10 @@ fn find_eligible_cp_task_for_resource(
         }
 
         // Estimate completion date
-        let estimated_completion = eligible_date + chrono::Duration::days(duration.ceil() as i64);
+        let estimated_completion = offset_date(eligible_date, duration).ok()?;
 
         // Get task name for result
-        let task_id = ctx.index.get_name(task_int)?.to_string();
+        let task_id = ctx.index.resolve(task_int)?.to_string();
 
         return Some(CompetingTarget {
             target_id: target.target_id.clone(),
@@ -149,8 +151,12 @@ fn task_needs_resource(
 
     // Check resource spec (if auto-assignment)
     if let Some(spec) = &ctx.resource_specs[idx] {
+        if let Some(pinned) = &ctx.pinned_resources[idx] {
+            return pinned == resource;
+        }
         if let Some(config) = resource_config {
-            let candidates = config.expand_resource_spec(spec);
+            let candidates =
+                config.expand_resource_spec_with_skills(spec, &ctx.required_skills[idx]);
             if candidates.contains(&resource.to_string()) {
                 return true;
             }
@@ -178,9 +184,8 @@ fn calculate_eligible_date(
         let (_, dep_end_offset) = state.scheduled_vec[dep_idx];
         if dep_end_offset < f64::MAX {
             // Dependency is scheduled - task eligible after it completes + lag
-            let dep_end = state.offset_to_date(dep_end_offset);
-            let lag_days = lag.ceil() as i64;
-            let dep_eligible = dep_end + chrono::Duration::days(1 + lag_days);
+            let dep_end = state.offset_to_date(dep_end_offset).ok()?;
+            let dep_eligible = ctx.dependency_ready(task_int, dep_int, dep_end, lag);
             if dep_eligible > eligible {
                 eligible = dep_eligible;
             }
@@ -197,7 +202,7 @@ fn calculate_eligible_date(
         }
     }
 
-    Some(eligible)
+    Some(first_unblocked(&ctx.blocked_periods[idx], eligible))
 }
 
 #[cfg(test)]
@@ -215,14 +220,9 @@ mod tests {
         Task {
             id: id.to_string(),
             duration_days: duration,
-            resources: vec![],
-            dependencies: vec![],
-            start_after: None,
-            end_before: None,
-            start_on: None,
-            end_on: None,
             resource_spec: resource_spec.map(|s| s.to_string()),
             priority: Some(50),
+            ..Default::default()
         }
     }
 
@@ -260,22 +260,17 @@ mod tests {
             Task {
                 id: "milestone".to_string(),
                 duration_days: 0.0,
-                resources: vec![],
                 dependencies: vec![Dependency {
                     entity_id: "current_task".to_string(),
-                    lag_days: 0.0,
+                    ..Default::default()
                 }],
-                start_after: None,
-                end_before: None,
-                start_on: None,
-                end_on: None,
-                resource_spec: None,
                 priority: Some(90),
+                ..Default::default()
             },
         );
 
         let ctx = TaskData::new(&tasks, 50);
-        let current_task_int = ctx.index.get_id("current_task").unwrap();
+        let current_task_int = ctx.index.get("current_task").unwrap();
 
         let n = ctx.index.len();
         let state = CriticalPathSchedulerState::new(
@@ -298,6 +293,7 @@ mod tests {
                 m.insert("dev".to_string(), vec!["alice".to_string()]);
                 m
             },
+            ..Default::default()
         };
 
         let resource_index = ResourceIndex::new(["alice".to_string()].into_iter());
@@ -345,29 +341,24 @@ mod tests {
             Task {
                 id: "milestone".to_string(),
                 duration_days: 0.0,
-                resources: vec![],
                 dependencies: vec![
                     Dependency {
                         entity_id: "current_task".to_string(),
-                        lag_days: 0.0,
+                        ..Default::default()
                     },
                     Dependency {
                         entity_id: "other_task".to_string(),
-                        lag_days: 0.0,
+                        ..Default::default()
                     },
                 ],
-                start_after: None,
-                end_before: None,
-                start_on: None,
-                end_on: None,
-                resource_spec: None,
                 priority: Some(90),
+                ..Default::default()
             },
         );
 
         let ctx = TaskData::new(&tasks, 50);
-        let current_task_int = ctx.index.get_id("current_task").unwrap();
-        let other_task_int = ctx.index.get_id("other_task").unwrap();
+        let current_task_int = ctx.index.get("current_task").unwrap();
+        let other_task_int = ctx.index.get("other_task").unwrap();
 
         let n = ctx.index.len();
         let state = CriticalPathSchedulerState::new(
@@ -394,6 +385,7 @@ mod tests {
                 m.insert("dev".to_string(), vec!["alice".to_string()]);
                 m
             },
+            ..Default::default()
         };
 
         let resource_index = ResourceIndex::new(["alice".to_string()].into_iter());
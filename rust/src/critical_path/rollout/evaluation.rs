@@ -4,7 +4,10 @@ use rustc_hash::{FxHashMap, FxHashSet};
 
 use chrono::NaiveDate;
 
+use crate::config::ScheduleObjective;
 use crate::models::{ScheduledTask, Task};
+use crate::scheduler::ResourceConfig;
+use crate::validation::offset_date;
 
 /// Score a partial schedule for comparison (lower is better).
 ///
@@ -12,6 +15,11 @@ use crate::models::{ScheduledTask, Task};
 /// 1. Priority-weighted completion times (earlier is better for high-priority tasks)
 /// 2. Tardiness penalties (heavy multiplier for missing deadlines)
 /// 3. Penalties for unscheduled high-priority eligible tasks
+/// 4. Weighted resource cost, when a resource config with cost rates is given
+/// 5. Makespan and resource idle time, as weighted by `objective`
+/// 6. Reassignments away from the resource config's prior assignments
+///
+/// Per-task terms are weighted by `objective`'s flow time and tardiness weights.
 #[allow(clippy::too_many_arguments)]
 pub fn score_schedule(
     scheduled_tasks: &[ScheduledTask],
@@ -23,24 +31,27 @@ pub fn score_schedule(
     start_date: NaiveDate,
     horizon: NaiveDate,
     default_priority: i32,
+    resource_config: Option<&ResourceConfig>,
+    objective: &ScheduleObjective,
+    lag_calendar: &[(NaiveDate, NaiveDate)],
 ) -> f64 {
-    let mut score = 0.0;
+    let mut score = objective.schedule_terms(scheduled_tasks, start_date, horizon);
 
     // 1. Priority-weighted completion times
     for task in scheduled_tasks {
         let priority = get_priority(&task.task_id, tasks, computed_priorities, default_priority);
         let days_to_complete = (task.end_date - start_date).num_days() as f64;
-        score += days_to_complete * (priority as f64 / 100.0);
+        score += objective.flow_time_weight * days_to_complete * objective.task_weight(priority);
     }
 
-    // 2. Tardiness penalty (10x multiplier)
+    // 2. Tardiness penalty
     for task in scheduled_tasks {
         if let Some(deadline) = computed_deadlines.get(&task.task_id) {
             if task.end_date > *deadline {
                 let tardiness = (task.end_date - *deadline).num_days() as f64;
                 let priority =
                     get_priority(&task.task_id, tasks, computed_priorities, default_priority);
-                score += tardiness * priority as f64 * 10.0;
+                score += objective.tardiness_weight * tardiness * objective.task_weight(priority);
             }
         }
     }
@@ -49,7 +60,7 @@ pub fn score_schedule(
     for task_id in unscheduled {
         if let Some(task) = tasks.get(task_id) {
             // Check if task is eligible (all dependencies scheduled)
-            let is_eligible = is_task_eligible(task, scheduled_dates, start_date, horizon);
+            let is_eligible = is_task_eligible(task, scheduled_dates, horizon, lag_calendar);
 
             if is_eligible {
                 let priority = get_priority(task_id, tasks, computed_priorities, default_priority);
@@ -67,21 +78,35 @@ pub fn score_schedule(
                     1.0 // Default urgency for tasks without deadlines
                 };
 
-                score += urgency_multiplier * (priority as f64 / 100.0) * days_delayed;
+                score += objective.flow_time_weight
+                    * urgency_multiplier
+                    * objective.task_weight(priority)
+                    * days_delayed;
 
                 // Add expected tardiness penalty if task won't make deadline
                 if let Some(deadline) = computed_deadlines.get(task_id) {
+                    // Past the supported range is as late as a date can be
                     let expected_end =
-                        horizon + chrono::Duration::days(task.duration_days.ceil() as i64);
+                        offset_date(horizon, task.duration_days).unwrap_or(NaiveDate::MAX);
                     if expected_end > *deadline {
                         let expected_tardiness = (expected_end - *deadline).num_days() as f64;
-                        score += expected_tardiness * priority as f64 * 10.0;
+                        score += objective.tardiness_weight
+                            * expected_tardiness
+                            * objective.task_weight(priority);
                     }
                 }
             }
         }
     }
 
+    // 4. Resource cost and 6. reassignments
+    if let Some(rc) = resource_config {
+        if rc.cost_weight > 0.0 {
+            score += rc.cost_weight * rc.schedule_cost(scheduled_tasks);
+        }
+        score += objective.reassignment_term(scheduled_tasks, &rc.prior_assignments);
+    }
+
     score
 }
 
@@ -108,11 +133,13 @@ fn get_priority(
 }
 
 /// Check if a task is eligible to be scheduled.
+///
+/// Working-day lags skip `lag_calendar`.
 fn is_task_eligible(
     task: &Task,
     scheduled_dates: &FxHashMap<String, (NaiveDate, NaiveDate)>,
-    _current_time: NaiveDate,
     horizon: NaiveDate,
+    lag_calendar: &[(NaiveDate, NaiveDate)],
 ) -> bool {
     // Check start_after constraint
     if let Some(start_after) = task.start_after {
@@ -129,9 +156,7 @@ fn is_task_eligible(
 
         // Check if dependency completes before horizon
         if let Some((_, end)) = scheduled_dates.get(&dep.entity_id) {
-            let lag_days = dep.lag_days.ceil() as i64;
-            let eligible_after = *end + chrono::Duration::days(1 + lag_days);
-            if eligible_after > horizon {
+            if dep.ready_date(*end, lag_calendar) > horizon {
                 return false;
             }
         }
@@ -155,6 +180,7 @@ mod tests {
             end_date: end,
             duration_days: (end - start).num_days() as f64,
             resources: vec!["alice".to_string()],
+            ..Default::default()
         }
     }
 
@@ -177,6 +203,9 @@ mod tests {
             d(2025, 1, 1),
             d(2025, 1, 31),
             50,
+            None,
+            &ScheduleObjective::default(),
+            &[],
         );
 
         assert!((score - 0.0).abs() < 1e-9);
@@ -206,6 +235,9 @@ mod tests {
             d(2025, 1, 1),
             d(2025, 1, 31),
             50,
+            None,
+            &ScheduleObjective::default(),
+            &[],
         );
 
         let score2 = score_schedule(
@@ -218,6 +250,9 @@ mod tests {
             d(2025, 1, 1),
             d(2025, 1, 31),
             50,
+            None,
+            &ScheduleObjective::default(),
+            &[],
         );
 
         assert!(score1 < score2); // Earlier completion = lower score = better
@@ -245,10 +280,101 @@ mod tests {
             d(2025, 1, 1),
             d(2025, 1, 31),
             50,
+            None,
+            &ScheduleObjective::default(),
+            &[],
         );
 
         // Score should include tardiness penalty: 5 days * 100 priority * 10 = 5000
         // Plus completion time: 19 days * 1.0 = 19
         assert!(score > 5000.0);
     }
+
+    #[test]
+    fn test_score_objective_weights() {
+        let task = make_scheduled_task("task1", d(2025, 1, 1), d(2025, 1, 11));
+        let unscheduled: FxHashSet<String> = FxHashSet::default();
+        let tasks: FxHashMap<String, Task> = FxHashMap::default();
+        let computed_deadlines: FxHashMap<String, NaiveDate> = FxHashMap::default();
+        let mut computed_priorities: FxHashMap<String, i32> = FxHashMap::default();
+        computed_priorities.insert("task1".to_string(), 100);
+        let scheduled_dates: FxHashMap<String, (NaiveDate, NaiveDate)> = FxHashMap::default();
+
+        let score_with = |objective: &ScheduleObjective| {
+            score_schedule(
+                std::slice::from_ref(&task),
+                &unscheduled,
+                &tasks,
+                &computed_deadlines,
+                &computed_priorities,
+                &scheduled_dates,
+                d(2025, 1, 1),
+                d(2025, 1, 31),
+                50,
+                None,
+                objective,
+                &[],
+            )
+        };
+
+        // Default: 10 days of flow time at weight 1.0
+        assert!((score_with(&ScheduleObjective::default()) - 10.0).abs() < 1e-9);
+
+        // Makespan only: 10 days
+        let makespan = ScheduleObjective {
+            flow_time_weight: 0.0,
+            makespan_weight: 2.0,
+            ..Default::default()
+        };
+        assert!((score_with(&makespan) - 20.0).abs() < 1e-9);
+
+        // Idle only: alice is idle 20 of the 30 days in the window
+        let idle = ScheduleObjective {
+            flow_time_weight: 0.0,
+            idle_time_weight: 1.0,
+            ..Default::default()
+        };
+        assert!((score_with(&idle) - 20.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_score_reassignment_penalty() {
+        let scheduled = [
+            make_scheduled_task("kept", d(2025, 1, 1), d(2025, 1, 2)),
+            make_scheduled_task("moved", d(2025, 1, 1), d(2025, 1, 2)),
+            make_scheduled_task("new", d(2025, 1, 1), d(2025, 1, 2)),
+        ];
+        let resource_config = ResourceConfig {
+            prior_assignments: [("kept", "alice"), ("moved", "bob")]
+                .into_iter()
+                .map(|(task, resource)| (task.to_string(), resource.to_string()))
+                .collect(),
+            ..Default::default()
+        };
+        let score_with = |reassignment_weight: f64| {
+            let objective = ScheduleObjective {
+                flow_time_weight: 0.0,
+                reassignment_weight,
+                ..Default::default()
+            };
+            score_schedule(
+                &scheduled,
+                &FxHashSet::default(),
+                &FxHashMap::default(),
+                &FxHashMap::default(),
+                &FxHashMap::default(),
+                &FxHashMap::default(),
+                d(2025, 1, 1),
+                d(2025, 1, 31),
+                50,
+                Some(&resource_config),
+                &objective,
+                &[],
+            )
+        };
+
+        assert_eq!(score_with(0.0), 0.0);
+        // Only "moved" left its prior resource
+        assert!((score_with(5.0) - 5.0).abs() < 1e-9);
+    }
 }
@@ -0,0 +1,141 @@
+//! Memoization of rollout decisions across scheduling iterations.
+//!
+//! Consecutive iterations often ask the same rollout question: the same task
+//! against the same competitors while the contested resource is unchanged.
+//! Answers are cached under a [`RolloutKey`] with a least-recently-used budget.
+
+use std::collections::BTreeMap;
+use std::hash::{Hash, Hasher};
+
+use rustc_hash::{FxHashMap, FxHasher};
+
+use crate::critical_path::types::TaskId;
+
+/// Identity of a rollout question.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct RolloutKey {
+    /// Task being considered.
+    pub task_int: TaskId,
+    /// Hash of the competing targets (and beam candidates).
+    pub competing: u64,
+    /// Hash of the current time and contested resource state.
+    pub resources: u64,
+}
+
+/// Hash any value with the crate's fast hasher, for building [`RolloutKey`]s.
+pub fn fingerprint<T: Hash + ?Sized>(value: &T) -> u64 {
+    let mut hasher = FxHasher::default();
+    value.hash(&mut hasher);
+    hasher.finish()
+}
+
+/// LRU cache of rollout outcomes. A capacity of 0 disables caching.
+#[derive(Clone, Debug)]
+pub struct RolloutMemo<V> {
+    capacity: usize,
+    entries: FxHashMap<RolloutKey, (u64, V)>,
+    /// Last-use tick -> key, oldest first.
+    recency: BTreeMap<u64, RolloutKey>,
+    tick: u64,
+    /// Lookups answered from the cache.
+    pub hits: usize,
+    /// Lookups that required a simulation.
+    pub misses: usize,
+}
+
+impl<V: Clone> RolloutMemo<V> {
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            capacity,
+            entries: FxHashMap::default(),
+            recency: BTreeMap::new(),
+            tick: 0,
+            hits: 0,
+            misses: 0,
+        }
+    }
+
+    pub fn is_enabled(&self) -> bool {
+        self.capacity > 0
+    }
+
+    /// Look up a cached outcome, counting the hit or miss.
+    pub fn get(&mut self, key: &RolloutKey) -> Option<V> {
+        if !self.is_enabled() {
+            return None;
+        }
+        self.tick += 1;
+        match self.entries.get_mut(key) {
+            Some((last_used, value)) => {
+                self.recency.remove(last_used);
+                *last_used = self.tick;
+                self.recency.insert(self.tick, *key);
+                self.hits += 1;
+                Some(value.clone())
+            }
+            None => {
+                self.misses += 1;
+                None
+            }
+        }
+    }
+
+    /// Cache an outcome, evicting the least recently used entry if over budget.
+    pub fn insert(&mut self, key: RolloutKey, value: V) {
+        if !self.is_enabled() {
+            return;
+        }
+        self.tick += 1;
+        if let Some((last_used, _)) = self.entries.insert(key, (self.tick, value)) {
+            self.recency.remove(&last_used);
+        }
+        self.recency.insert(self.tick, key);
+        while self.entries.len() > self.capacity {
+            let Some((_, oldest)) = self.recency.pop_first() else {
+                break;
+            };
+            self.entries.remove(&oldest);
+        }
+    }
+}
+
+impl<V: Clone> Default for RolloutMemo<V> {
+    fn default() -> Self {
+        Self::new(0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn key(task_int: TaskId) -> RolloutKey {
+        RolloutKey {
+            task_int,
+            competing: 0,
+            resources: 0,
+        }
+    }
+
+    #[test]
+    fn test_lru_eviction_and_counts() {
+        let mut memo = RolloutMemo::new(2);
+        memo.insert(key(1), "a");
+        memo.insert(key(2), "b");
+        assert_eq!(memo.get(&key(1)), Some("a"));
+        // key(2) is now least recently used
+        memo.insert(key(3), "c");
+        assert_eq!(memo.get(&key(2)), None);
+        assert_eq!(memo.get(&key(1)), Some("a"));
+        assert_eq!(memo.get(&key(3)), Some("c"));
+        assert_eq!((memo.hits, memo.misses), (3, 1));
+    }
+
+    #[test]
+    fn test_disabled_memo_counts_nothing() {
+        let mut memo = RolloutMemo::default();
+        memo.insert(key(1), 1);
+        assert_eq!(memo.get(&key(1)), None);
+        assert_eq!((memo.hits, memo.misses), (0, 0));
+    }
+}
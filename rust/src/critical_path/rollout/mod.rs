@@ -11,13 +11,16 @@
 
 mod detection;
 mod evaluation;
+mod memo;
 
 pub use detection::find_competing_targets;
 pub use evaluation::score_schedule;
+pub use memo::{fingerprint, RolloutKey, RolloutMemo};
 
 use chrono::NaiveDate;
 
 use crate::models::ScheduledTask;
+use crate::snapshot::HeldReservation;
 
 /// A competing target that may warrant delaying the current task.
 #[derive(Clone, Debug)]
@@ -75,6 +78,83 @@ pub struct ResourceReservation {
     pub target_score: f64,
     /// The date from which this reservation is valid.
     pub reserved_from: NaiveDate,
+    /// The date the reservation is released if its task has not been scheduled.
+    pub release_on: NaiveDate,
+}
+
+/// What happened to a resource reservation during a schedule run.
+#[derive(Clone, Debug)]
+pub struct ReservationRecord {
+    /// The resource that was held.
+    pub resource: String,
+    /// The target the resource was held for.
+    pub target_id: String,
+    /// The task the resource was held for.
+    pub task_id: String,
+    /// The date the reservation was made.
+    pub reserved_from: NaiveDate,
+    /// The date the reservation ended.
+    pub released_on: NaiveDate,
+    /// How it ended: "fulfilled" (the task was scheduled), "expired",
+    /// "replaced" (by another reservation), or "open" (still held at the end).
+    pub outcome: String,
+}
+
+impl ReservationRecord {
+    pub fn new(reservation: &ResourceReservation, released_on: NaiveDate, outcome: &str) -> Self {
+        Self {
+            resource: reservation.resource.clone(),
+            target_id: reservation.target_id.clone(),
+            task_id: reservation.task_id.clone(),
+            reserved_from: reservation.reserved_from,
+            released_on,
+            outcome: outcome.to_string(),
+        }
+    }
+
+    /// Record how a reservation held by the parallel scheduler ended.
+    pub fn from_held(held: &HeldReservation, released_on: NaiveDate, outcome: &str) -> Self {
+        Self {
+            resource: held.resource.clone(),
+            target_id: held.target_id.clone(),
+            task_id: held.task_id.clone(),
+            reserved_from: held.reserved_from,
+            released_on,
+            outcome: outcome.to_string(),
+        }
+    }
+
+    /// Days the resource was held.
+    pub fn held_days(&self) -> i64 {
+        (self.released_on - self.reserved_from).num_days()
+    }
+}
+
+/// Record of a rollout decision made by the critical path scheduler.
+#[derive(Clone, Debug)]
+pub struct CriticalPathRolloutDecision {
+    /// Date the decision was made
+    pub decision_date: NaiveDate,
+    /// Task that was being considered
+    pub task_id: String,
+    /// Score of the task being considered
+    pub task_score: f64,
+    /// Highest-scored competing target
+    pub competing_target_id: String,
+    /// Score of the competing target
+    pub competing_target_score: f64,
+    /// Critical path task of the competing target that needs the resource
+    pub competing_task_id: String,
+    /// When the competing task becomes eligible
+    pub competing_eligible_date: NaiveDate,
+    /// Simulated score if we schedule the task now (lower is better)
+    pub schedule_score: f64,
+    /// Simulated score if we skip the task (lower is better)
+    pub skip_score: f64,
+    /// Decision made: "schedule" or "skip"
+    pub decision: String,
+    /// Resource reserved for the competing task, if the task was skipped
+    pub reserved_resource: Option<String>,
 }
 
 impl Default for RolloutConfig {
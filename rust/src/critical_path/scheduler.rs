@@ -1,43 +1,205 @@
 //! Critical path scheduler implementation.
 
+use std::time::Instant;
+
 use chrono::{Days, NaiveDate};
 use rustc_hash::{FxHashMap, FxHashSet};
 use thiserror::Error;
 
-use crate::models::{AlgorithmResult, ScheduledTask, Task};
-use crate::scheduler::{ResourceConfig, ResourceSchedule};
-use crate::{log_changes, log_checks, log_debug};
+use crate::backward_pass::{format_cycle, BackwardPassError};
+use crate::cancellation::{CancellationToken, StopCondition};
+use crate::config::{RolloutFidelity, SchedulingConfig, UnschedulablePolicy};
+use crate::eligibility::{eligible_tasks, TaskEligibility};
+use crate::models::{
+    first_unblocked, AlgorithmMetadata, AlgorithmResult, DeadlineStatus, Dependency,
+    ScheduleSummary, ScheduleWarning, ScheduledTask, Task, TaskChange, UnschedulableTask,
+};
+use crate::preprocessing::{
+    apply_external_completions, apply_external_deadlines, occupy_fixed, take_fixed_tasks,
+};
+use crate::profiling::{self, PhaseTimings};
+use crate::progress::ProgressReporter;
+use crate::recurrence::{expand_recurring_tasks, recurrence_warnings};
+use crate::scheduler::{
+    task_categories, AdvanceLimit, EventQueue, EventSource, ExclusionGroups, IterationLimitReached,
+    ResourceConfig, ResourceSchedule, RolloutTrace,
+};
+use crate::snapshot::{
+    resume_date, starting_placements, HeldReservation, ScheduleStep, SchedulerSnapshot,
+};
+use crate::sorting::cmp_rank;
+use crate::validation::{
+    apply_unknown_dependency_policy, best_effort_relaxation, date_range_errors, duplicate_task_ids,
+    fixed_dependency_violations, input_warnings, offset_date, out_of_range_placements,
+    resolve_fixed_conflicts, restore_dropped_dependencies, task_warnings, unschedulable_tasks,
+    DateOverflow,
+};
+use crate::{log_changes, log_checks, log_debug, trace_placement, trace_span};
 
 use super::cache::CriticalPathCache;
 use super::calculation::{CriticalPathError, TaskData};
-use super::rollout::{score_schedule, ResourceReservation};
+use super::hybrid::SgsOrdering;
+use super::rollout::{
+    fingerprint, score_schedule, CompetingTarget, CriticalPathRolloutDecision, ReservationRecord,
+    ResourceReservation, RolloutKey, RolloutMemo,
+};
 use super::state::CriticalPathSchedulerState;
 use super::types::{
     CriticalPathConfig, ResourceIndex, ResourceMask, TargetInfo, TaskId, TaskResourceReq,
 };
 
+/// A rollout decision, the reservation it makes, if any, and its trace when tracing.
+type RolloutOutcome = (
+    CriticalPathRolloutDecision,
+    Option<ResourceReservation>,
+    Option<RolloutTrace>,
+);
+
+/// Date `task` becomes eligible: the day after dependency `dep`'s end offset plus lag.
+fn ready_date(
+    ctx: &TaskData,
+    task: TaskId,
+    dep: TaskId,
+    initial_time: NaiveDate,
+    dep_end_offset: f64,
+    lag: f64,
+) -> Result<NaiveDate, DateOverflow> {
+    let dep_end = offset_date(initial_time, dep_end_offset)?;
+    Ok(ctx.dependency_ready(task, dep, dep_end, lag))
+}
+
+/// Expand recurring tasks and resolve fixed dates and in-progress work as of `current_date`.
+///
+/// Dependencies on `external_completions` become `start_after` dates first.
+/// Also returns the durations nearest-day rounding changed, keyed by task ID.
+fn prepare_tasks(
+    source: &[Task],
+    current_date: NaiveDate,
+    config: &CriticalPathConfig,
+    global_dns_periods: &[(NaiveDate, NaiveDate)],
+    resource_config: Option<&ResourceConfig>,
+    external_completions: &FxHashMap<String, NaiveDate>,
+    completed_task_ids: &FxHashSet<String>,
+) -> Result<(Vec<Task>, FxHashMap<String, f64>), CriticalPathSchedulerError> {
+    let source =
+        apply_external_completions(source.to_vec(), external_completions, global_dns_periods);
+    let freeze: &[(NaiveDate, NaiveDate)] = if config.allow_fixed_in_global_dns {
+        &[]
+    } else {
+        global_dns_periods
+    };
+    let (tasks, planned_durations) = config.duration_rounding.apply_all(
+        expand_recurring_tasks(&source, current_date, completed_task_ids)
+            .map_err(CriticalPathSchedulerError::InvalidRecurrence)?
+            .into_iter()
+            .map(|t| t.resolve_in_progress(current_date).keep_out_of(freeze)),
+    );
+    let duplicates = duplicate_task_ids(&tasks);
+    if !duplicates.is_empty() {
+        return Err(CriticalPathSchedulerError::DuplicateTaskIds(duplicates));
+    }
+    let resource_dns = resource_config
+        .into_iter()
+        .flat_map(|rc| rc.dns_periods.values());
+    let overflow = date_range_errors(
+        &tasks,
+        current_date,
+        global_dns_periods.iter().chain(resource_dns.flatten()),
+    );
+    if !overflow.is_empty() {
+        return Err(CriticalPathSchedulerError::DateOverflow(overflow));
+    }
+    Ok((tasks, planned_durations))
+}
+
+/// Task data and resource calendars kept between runs until the tasks change.
+struct PreparedRun {
+    ctx: TaskData,
+    /// Resource calendars before fixed tasks are placed, indexed by resource ID.
+    resource_schedules: Vec<ResourceSchedule>,
+}
+
 /// Errors that can occur during critical path scheduling.
 #[derive(Error, Debug)]
 pub enum CriticalPathSchedulerError {
     #[error("Failed to schedule tasks: {0:?}")]
     FailedToSchedule(Vec<String>),
-    #[error("Circular dependency detected")]
-    CircularDependency,
+    #[error("Circular dependency detected: {}", format_cycle(.0))]
+    CircularDependency(Vec<String>),
     #[error("Resource not found: {0}")]
     ResourceNotFound(String),
+    #[error("Resource groups contain each other: {}", format_cycle(.0))]
+    ResourceGroupCycle(Vec<String>),
+    #[error("Dependencies on unknown tasks: {}", .0.join(", "))]
+    UnknownDependencies(Vec<String>),
+    #[error("Invalid configuration: {0}")]
+    InvalidConfig(String),
+    #[error("Unknown scheduling strategy: {0}")]
+    UnknownStrategy(String),
+    #[error("Unknown target tasks: {}", .0.join(", "))]
+    UnknownTargets(Vec<String>),
+    #[error("Duplicate task IDs: {}", .0.join(", "))]
+    DuplicateTaskIds(Vec<String>),
+    #[error("Invalid recurrences: {}", .0.join("; "))]
+    InvalidRecurrence(Vec<String>),
+    #[error("Dates out of range: {}", .0.join("; "))]
+    DateOverflow(Vec<String>),
+    #[error("Fixed tasks overlap: {}", .0.join("; "))]
+    FixedTaskConflicts(Vec<String>),
+    #[error("Scheduling cancelled after {} tasks were scheduled", .0.len())]
+    Cancelled(Vec<ScheduledTask>),
+    #[error("{0}")]
+    IterationLimit(IterationLimitReached),
 }
 
 impl From<CriticalPathError> for CriticalPathSchedulerError {
     fn from(err: CriticalPathError) -> Self {
         match err {
-            CriticalPathError::CircularDependency => CriticalPathSchedulerError::CircularDependency,
+            CriticalPathError::CircularDependency(cycle) => {
+                CriticalPathSchedulerError::CircularDependency(cycle)
+            }
+        }
+    }
+}
+
+impl From<BackwardPassError> for CriticalPathSchedulerError {
+    fn from(err: BackwardPassError) -> Self {
+        match err {
+            BackwardPassError::CircularDependency(cycle) => {
+                CriticalPathSchedulerError::CircularDependency(cycle)
+            }
+            BackwardPassError::DateOverflow(message) => {
+                CriticalPathSchedulerError::DateOverflow(vec![message])
+            }
+        }
+    }
+}
+
+impl From<DateOverflow> for CriticalPathSchedulerError {
+    fn from(err: DateOverflow) -> Self {
+        CriticalPathSchedulerError::DateOverflow(vec![err.to_string()])
+    }
+}
+
+impl CriticalPathSchedulerError {
+    /// The offending dependency cycle, if this error describes one.
+    pub fn cycle(&self) -> Option<&[String]> {
+        match self {
+            CriticalPathSchedulerError::CircularDependency(cycle) => Some(cycle),
+            _ => None,
         }
     }
 }
 
 /// Critical path scheduler that eliminates priority contamination.
 pub struct CriticalPathScheduler {
+    /// Tasks as given, before recurrence expansion; replans derive `tasks` from them.
+    source_tasks: Vec<Task>,
     tasks: FxHashMap<String, Task>,
+    /// Durations before nearest-day rounding, reported in results.
+    planned_durations: FxHashMap<String, f64>,
+    /// Completion dates of external events that tasks may depend on.
+    external_completions: FxHashMap<String, NaiveDate>,
     current_date: NaiveDate,
     completed_task_ids: FxHashSet<String>,
     default_priority: i32,
@@ -51,10 +213,60 @@ pub struct CriticalPathScheduler {
     /// For each resource ID, tasks that explicitly require it (requires_all=true).
     /// Used for prefer_fungible_resources optimization.
     resource_exclusive_tasks: Vec<Vec<TaskId>>,
+    /// Optional token for cooperative cancellation of the next run.
+    cancellation_token: Option<CancellationToken>,
+    /// Cancellation/timeout state for the current run.
+    stop_condition: StopCondition,
+    /// Optional progress reporter for the next run's main scheduling loop.
+    progress: Option<ProgressReporter>,
+    /// Progress reporter of the current run.
+    run_progress: Option<ProgressReporter>,
+    /// Rollout decisions from the last schedule() run.
+    rollout_decisions: Vec<CriticalPathRolloutDecision>,
+    /// Rollout memo (hits, misses) from the last schedule() run.
+    rollout_cache_stats: (usize, usize),
+    /// Main-loop iterations from the last schedule() run.
+    iterations: usize,
+    /// Phase timings from the last schedule() run.
+    timings: PhaseTimings,
+    /// Resource reservations from the last schedule() run.
+    reservation_log: Vec<ReservationRecord>,
+    /// Snapshot the next run resumes from, if any.
+    start_from: Option<SchedulerSnapshot>,
+    /// Last decision date of a schedule_until() run.
+    pause_at: Option<NaiveDate>,
+    /// Reservations still held when a schedule_until() run paused.
+    held_reservations: Vec<HeldReservation>,
+    /// Whether the current run is a schedule_step() call.
+    stepping: bool,
+    /// Decision made by the last schedule_step() run.
+    last_step: Option<ScheduleStep>,
+    /// Simulations behind the last rollout decision of the last run, when tracing.
+    last_rollout_trace: Option<RolloutTrace>,
+    /// Mutually exclusive groups of every task, including fixed ones.
+    exclusions: ExclusionGroups,
+    /// Task categories for changeover gaps, including fixed tasks.
+    task_categories: FxHashMap<String, String>,
+    /// Warnings from the last schedule() run.
+    warnings: Vec<ScheduleWarning>,
+    /// Dependencies of fixed tasks, which leave the task map.
+    fixed_dependencies: FxHashMap<String, Vec<Dependency>>,
+    /// Metadata of the last completed schedule() run.
+    last_metadata: Option<AlgorithmMetadata>,
+    /// SGS ordering of eligible tasks within targets (hybrid mode).
+    task_ordering: Option<SgsOrdering>,
+    /// Fixed tasks taken out of `tasks` by the last run, put back by the next.
+    fixed_source: Vec<Task>,
+    /// Dependency lists the last run dropped as unknown, put back by the next.
+    dropped_dependencies: Vec<(String, Vec<Dependency>)>,
+    /// Task data and resource calendars reused by the next run, if still valid.
+    prepared: Option<PreparedRun>,
 }
 
 impl CriticalPathScheduler {
     /// Create a new critical path scheduler.
+    ///
+    /// Returns `DuplicateTaskIds` if task IDs (after recurrence expansion) repeat.
     #[allow(clippy::too_many_arguments)]
     pub fn new(
         tasks: Vec<Task>,
@@ -64,12 +276,33 @@ impl CriticalPathScheduler {
         config: CriticalPathConfig,
         resource_config: Option<ResourceConfig>,
         global_dns_periods: Vec<(NaiveDate, NaiveDate)>,
-    ) -> Self {
+    ) -> Result<Self, CriticalPathSchedulerError> {
+        config
+            .validate()
+            .map_err(|e| CriticalPathSchedulerError::InvalidConfig(e.problems.join("; ")))?;
+        let source_tasks = tasks;
+        let (tasks, planned_durations) = prepare_tasks(
+            &source_tasks,
+            current_date,
+            &config,
+            &global_dns_periods,
+            resource_config.as_ref(),
+            &FxHashMap::default(),
+            &completed_task_ids,
+        )?;
+        if let Some(cycle) = resource_config.as_ref().and_then(|rc| rc.group_cycle()) {
+            return Err(CriticalPathSchedulerError::ResourceGroupCycle(cycle));
+        }
+        let exclusions = ExclusionGroups::new(&tasks);
+        let task_categories = task_categories(&tasks);
         let tasks_map: FxHashMap<String, Task> =
-            tasks.iter().map(|t| (t.id.clone(), t.clone())).collect();
+            tasks.into_iter().map(|t| (t.id.clone(), t)).collect();
 
-        Self {
+        Ok(Self {
+            source_tasks,
             tasks: tasks_map,
+            planned_durations,
+            external_completions: FxHashMap::default(),
             current_date,
             completed_task_ids,
             default_priority,
@@ -80,109 +313,614 @@ impl CriticalPathScheduler {
             resource_index: ResourceIndex::new(std::iter::empty()),
             task_resource_reqs: FxHashMap::default(),
             resource_exclusive_tasks: Vec::new(),
+            cancellation_token: None,
+            stop_condition: StopCondition::default(),
+            progress: None,
+            run_progress: None,
+            rollout_decisions: Vec::new(),
+            rollout_cache_stats: (0, 0),
+            iterations: 0,
+            timings: PhaseTimings::default(),
+            reservation_log: Vec::new(),
+            start_from: None,
+            pause_at: None,
+            held_reservations: Vec::new(),
+            stepping: false,
+            last_step: None,
+            last_rollout_trace: None,
+            exclusions,
+            task_categories,
+            warnings: Vec::new(),
+            fixed_dependencies: FxHashMap::default(),
+            last_metadata: None,
+            task_ordering: None,
+            fixed_source: Vec::new(),
+            dropped_dependencies: Vec::new(),
+            prepared: None,
+        })
+    }
+
+    /// Apply task edits for a replan, keeping what later runs can reuse.
+    ///
+    /// Completions keep the interned task data and resource calendars; added,
+    /// replaced, removed or completed recurring tasks rebuild them on the
+    /// next run. All changes
+    /// are applied or, on error, none are.
+    pub fn update_tasks(
+        &mut self,
+        changes: Vec<TaskChange>,
+    ) -> Result<(), CriticalPathSchedulerError> {
+        let mut source = None;
+        let mut completed = Vec::new();
+        for change in changes {
+            match change {
+                TaskChange::Upsert(task) => {
+                    let source = source.get_or_insert_with(|| self.source_tasks.clone());
+                    match source.iter_mut().find(|t| t.id == task.id) {
+                        Some(existing) => *existing = *task,
+                        None => source.push(*task),
+                    }
+                }
+                TaskChange::Remove(task_id) => {
+                    source
+                        .get_or_insert_with(|| self.source_tasks.clone())
+                        .retain(|t| t.id != task_id);
+                }
+                TaskChange::Complete(task_id) => {
+                    // A completed recurring task is no longer expanded
+                    if self
+                        .source_tasks
+                        .iter()
+                        .any(|t| t.id == task_id && t.recurrence.is_some())
+                    {
+                        source.get_or_insert_with(|| self.source_tasks.clone());
+                    }
+                    completed.push(task_id);
+                }
+            }
+        }
+        match source {
+            Some(source) => {
+                let mut completed_task_ids = self.completed_task_ids.clone();
+                completed_task_ids.extend(completed);
+                let prepared = prepare_tasks(
+                    &source,
+                    self.current_date,
+                    &self.config,
+                    &self.global_dns_periods,
+                    self.resource_config.as_ref(),
+                    &self.external_completions,
+                    &completed_task_ids,
+                )?;
+                self.source_tasks = source;
+                self.completed_task_ids = completed_task_ids;
+                self.set_tasks(prepared, true)
+            }
+            None => {
+                self.completed_task_ids.extend(completed);
+                self.refresh_task_ordering()
+            }
+        }
+    }
+
+    /// Move the scheduling date for a replan.
+    ///
+    /// Tasks are re-resolved and re-validated against the new date, so a date
+    /// that overflows fails here; the interned task data is rebuilt only when
+    /// tasks recur.
+    pub fn set_current_date(
+        &mut self,
+        current_date: NaiveDate,
+    ) -> Result<(), CriticalPathSchedulerError> {
+        if current_date == self.current_date {
+            return Ok(());
+        }
+        let recurring = self.source_tasks.iter().any(|t| t.recurrence.is_some());
+        let prepared = prepare_tasks(
+            &self.source_tasks,
+            current_date,
+            &self.config,
+            &self.global_dns_periods,
+            self.resource_config.as_ref(),
+            &self.external_completions,
+            &self.completed_task_ids,
+        )?;
+        self.current_date = current_date;
+        self.set_tasks(prepared, recurring)
+    }
+
+    /// Replace the task map, dropping reused run data if the task graph changed.
+    fn set_tasks(
+        &mut self,
+        (tasks, planned_durations): (Vec<Task>, FxHashMap<String, f64>),
+        graph_changed: bool,
+    ) -> Result<(), CriticalPathSchedulerError> {
+        self.planned_durations = planned_durations;
+        self.exclusions = ExclusionGroups::new(&tasks);
+        self.task_categories = task_categories(&tasks);
+        self.tasks = tasks.into_iter().map(|t| (t.id.clone(), t)).collect();
+        self.fixed_source.clear();
+        self.dropped_dependencies.clear();
+        self.fixed_dependencies.clear();
+        if graph_changed {
+            self.prepared = None;
+        }
+        self.refresh_task_ordering()
+    }
+
+    /// Rerun the hybrid ordering's backward pass after the tasks changed.
+    fn refresh_task_ordering(&mut self) -> Result<(), CriticalPathSchedulerError> {
+        match self.task_ordering.take() {
+            Some(ordering) => self.set_task_ordering(ordering.into_config()),
+            None => Ok(()),
+        }
+    }
+
+    /// Set a token that can be used to cancel the next run.
+    ///
+    /// The token applies to one run only; set it again before each call.
+    pub fn set_cancellation_token(&mut self, token: CancellationToken) {
+        self.cancellation_token = Some(token);
+    }
+
+    /// Set a reporter invoked periodically with progress updates during the next run.
+    pub fn set_progress_reporter(&mut self, reporter: ProgressReporter) {
+        self.progress = Some(reporter);
+    }
+
+    /// Resolve dependencies on external events from their completion dates.
+    ///
+    /// Dependencies on IDs in `external_completions` that are not tasks become
+    /// `start_after` dates, for these tasks and any later `update_tasks` edits.
+    pub fn set_external_completions(
+        &mut self,
+        external_completions: FxHashMap<String, NaiveDate>,
+    ) -> Result<(), CriticalPathSchedulerError> {
+        let prepared = prepare_tasks(
+            &self.source_tasks,
+            self.current_date,
+            &self.config,
+            &self.global_dns_periods,
+            self.resource_config.as_ref(),
+            &external_completions,
+            &self.completed_task_ids,
+        )?;
+        self.external_completions = external_completions;
+        self.set_tasks(prepared, true)
+    }
+
+    /// Switch to hybrid mode: targets are still ranked by critical path, but
+    /// eligible tasks feeding the same best-ranked target are ordered by
+    /// `config.strategy`, with deadlines and priorities from the backward pass.
+    pub fn set_task_ordering(
+        &mut self,
+        config: SchedulingConfig,
+    ) -> Result<(), CriticalPathSchedulerError> {
+        if !["priority_first", "cr_first", "weighted", "atc"].contains(&config.strategy.as_str()) {
+            return Err(CriticalPathSchedulerError::UnknownStrategy(
+                config.strategy.clone(),
+            ));
+        }
+        let ordering = SgsOrdering::new(
+            &self.tasks,
+            &self.completed_task_ids,
+            config,
+            self.resource_config.as_ref(),
+            &self.global_dns_periods,
+        )?;
+        self.task_ordering = Some(ordering);
+        Ok(())
+    }
+
+    fn algorithm_name(&self) -> &'static str {
+        if self.task_ordering.is_some() {
+            "hybrid"
+        } else {
+            "critical_path"
         }
     }
 
     /// Run the scheduling algorithm.
+    ///
+    /// Returns `CriticalPathSchedulerError::Cancelled` with the partial schedule if
+    /// the cancellation token is triggered or the configured timeout elapses.
     pub fn schedule(&mut self) -> Result<AlgorithmResult, CriticalPathSchedulerError> {
+        let started = Instant::now();
+        let _span = trace_span!(
+            INFO,
+            "schedule",
+            algorithm = self.algorithm_name(),
+            tasks = self.tasks.len()
+        );
+
         // Phase 0: Process fixed tasks (with start_on/end_on)
-        let fixed_tasks = self.process_fixed_tasks();
+        let (ignored_dependencies, fixed_tasks) = self.start_run()?;
 
         // Phase 1: Critical path scheduling
-        let scheduled_tasks = self.schedule_critical_path(&fixed_tasks)?;
+        let mut scheduled_tasks = self.run_critical_path(&fixed_tasks)?;
+        let (unschedulable, relaxed) =
+            self.resolve_unschedulable(&fixed_tasks, &mut scheduled_tasks)?;
+
+        self.warnings.extend(fixed_dependency_violations(
+            &fixed_tasks,
+            &scheduled_tasks,
+            &self.fixed_dependencies,
+            &self.global_dns_periods,
+        ));
 
         // Combine fixed and scheduled tasks
         let mut all_tasks = fixed_tasks;
         all_tasks.extend(scheduled_tasks);
+        for placement in &mut all_tasks {
+            placement.set_effective_duration(
+                false,
+                self.planned_durations.get(&placement.task_id).copied(),
+            );
+            // Auto-assigned remaining work of a started task
+            placement.in_progress |= self
+                .tasks
+                .get(&placement.task_id)
+                .is_some_and(Task::is_in_progress);
+        }
+        let overflow = out_of_range_placements(&all_tasks);
+        if !overflow.is_empty() {
+            return Err(CriticalPathSchedulerError::DateOverflow(overflow));
+        }
 
         let mut metadata = std::collections::HashMap::new();
-        metadata.insert("algorithm".to_string(), "critical_path".to_string());
+        metadata.insert("algorithm".to_string(), self.algorithm_name().to_string());
+        if let Some(rc) = self.resource_config.as_ref().filter(|rc| rc.has_costs()) {
+            metadata.insert(
+                "total_cost".to_string(),
+                format!("{:.2}", rc.schedule_cost(&all_tasks)),
+            );
+        }
+        if !ignored_dependencies.is_empty() {
+            metadata.insert(
+                "ignored_dependencies".to_string(),
+                ignored_dependencies.join(","),
+            );
+        }
+        if self.config.rollout_enabled {
+            metadata.insert(
+                "rollout_decisions".to_string(),
+                self.rollout_decisions.len().to_string(),
+            );
+        }
+        if self.config.rollout_enabled {
+            let fulfilled = self
+                .reservation_log
+                .iter()
+                .filter(|r| r.outcome == "fulfilled")
+                .count();
+            let held_days: i64 = self.reservation_log.iter().map(|r| r.held_days()).sum();
+            metadata.insert(
+                "reservations".to_string(),
+                self.reservation_log.len().to_string(),
+            );
+            metadata.insert("reservations_fulfilled".to_string(), fulfilled.to_string());
+            metadata.insert("reservation_held_days".to_string(), held_days.to_string());
+        }
+        if self.config.rollout_enabled && self.config.rollout_cache_size > 0 {
+            let (hits, misses) = self.rollout_cache_stats;
+            metadata.insert("rollout_cache_hits".to_string(), hits.to_string());
+            metadata.insert("rollout_cache_misses".to_string(), misses.to_string());
+        }
+        if !relaxed.is_empty() {
+            metadata.insert("relaxed_dependencies".to_string(), relaxed.join(","));
+        }
 
+        let typed = AlgorithmMetadata {
+            algorithm: self.algorithm_name().to_string(),
+            strategy: self
+                .task_ordering
+                .as_ref()
+                .map(|o| o.strategy().to_string()),
+            rollout_decision_count: self.rollout_decisions.len(),
+            iterations: self.iterations,
+            wall_time_ms: started.elapsed().as_secs_f64() * 1000.0,
+            metrics: ScheduleSummary::measure(
+                &all_tasks,
+                &self.tasks,
+                self.current_date,
+                self.default_priority,
+            ),
+            timings: self.config.profile.then(|| self.timings.clone()),
+            deadlines: DeadlineStatus::check_all(&all_tasks, &self.tasks),
+        };
+        self.last_metadata = Some(typed.clone());
         Ok(AlgorithmResult {
             scheduled_tasks: all_tasks,
             algorithm_metadata: metadata,
+            unschedulable,
+            warnings: std::mem::take(&mut self.warnings),
+            metadata: Some(typed),
         })
     }
 
-    /// Process tasks with fixed dates (start_on/end_on).
-    fn process_fixed_tasks(&mut self) -> Vec<ScheduledTask> {
-        let mut fixed_results: Vec<ScheduledTask> = Vec::new();
-        let mut to_remove: Vec<String> = Vec::new();
+    /// Schedule decisions up to and including `pause_at` and return the state.
+    ///
+    /// Pass the snapshot (possibly edited) to `restore` to continue, on this
+    /// scheduler or a new one built from the same tasks. Unschedulable tasks
+    /// are not reported until the run finishes.
+    pub fn schedule_until(
+        &mut self,
+        pause_at: NaiveDate,
+    ) -> Result<SchedulerSnapshot, CriticalPathSchedulerError> {
+        let (_, fixed_tasks) = self.start_run()?;
+        self.pause_at = Some(pause_at);
+        let outcome = self.run_critical_path(&fixed_tasks);
+        self.pause_at = None;
+        let mut scheduled_tasks = fixed_tasks;
+        scheduled_tasks.extend(outcome?);
+        Ok(SchedulerSnapshot {
+            current_time: resume_date(pause_at),
+            scheduled_tasks,
+            reservations: std::mem::take(&mut self.held_reservations),
+        })
+    }
 
-        for (task_id, task) in &self.tasks {
-            if task.start_on.is_none() && task.end_on.is_none() {
-                continue;
-            }
+    /// Make exactly one decision (place one task or advance time) and return it.
+    ///
+    /// Each call resumes from the previous step's snapshot (or the one given to
+    /// `restore`). Once a step reports "finished", `schedule()` returns the
+    /// complete result.
+    pub fn schedule_step(&mut self) -> Result<ScheduleStep, CriticalPathSchedulerError> {
+        self.run_step(true)
+    }
 
-            let (start, end) = match (task.start_on, task.end_on) {
-                (Some(s), Some(e)) => (s, e),
-                (Some(s), None) => {
-                    let e = self.calculate_dns_aware_end_date(task, s);
-                    (s, e)
-                }
-                (None, Some(e)) => {
-                    let s = e
-                        .checked_sub_days(Days::new(task.duration_days.ceil() as u64))
-                        .unwrap_or(e);
-                    (s, e)
-                }
-                (None, None) => unreachable!(),
-            };
+    /// Report the decision the next `schedule_step()` would make, without making it.
+    ///
+    /// The returned step shows the ranked targets, the chosen task and its
+    /// resources; the scheduler stays where it was.
+    pub fn preview_step(&mut self) -> Result<ScheduleStep, CriticalPathSchedulerError> {
+        self.run_step(false)
+    }
 
-            let resources = if task.duration_days == 0.0 {
-                vec![]
-            } else {
-                task.resources.iter().map(|(r, _)| r.clone()).collect()
-            };
+    fn run_step(&mut self, commit: bool) -> Result<ScheduleStep, CriticalPathSchedulerError> {
+        let (_, fixed_tasks) = self.start_run()?;
+        self.stepping = true;
+        let outcome = self.run_critical_path(&fixed_tasks);
+        self.stepping = false;
+        let scheduled = outcome?;
+        let mut step = self
+            .last_step
+            .take()
+            .unwrap_or_else(|| ScheduleStep::new(self.current_date));
+        step.snapshot.scheduled_tasks = fixed_tasks;
+        step.snapshot.scheduled_tasks.extend(scheduled);
+        if commit {
+            self.start_from = Some(step.snapshot.clone());
+        }
+        Ok(step)
+    }
 
-            fixed_results.push(ScheduledTask {
-                task_id: task_id.clone(),
-                start_date: start,
-                end_date: end,
-                duration_days: task.duration_days,
-                resources,
-            });
+    /// Resume later runs from `snapshot` instead of from the scheduling date.
+    ///
+    /// The snapshot's placements are kept as given (edit them to pin decisions)
+    /// and scheduling continues from its `current_time`. `None` starts over.
+    pub fn restore(&mut self, snapshot: Option<SchedulerSnapshot>) {
+        self.start_from = snapshot;
+    }
 
-            to_remove.push(task_id.clone());
-        }
+    /// Reset per-run state and return ignored dependencies and starting placements.
+    fn start_run(
+        &mut self,
+    ) -> Result<(Vec<String>, Vec<ScheduledTask>), CriticalPathSchedulerError> {
+        self.stop_condition =
+            StopCondition::new(self.cancellation_token.take(), self.config.timeout_secs);
+        self.run_progress = self.progress.take();
+        self.rollout_decisions.clear();
+        self.reservation_log.clear();
+        self.last_rollout_trace = None;
+        self.restore_fixed_tasks();
+
+        let ignored_dependencies = apply_unknown_dependency_policy(
+            &mut self.tasks,
+            &self.completed_task_ids,
+            self.config.unknown_dependencies,
+            &mut self.dropped_dependencies,
+        )
+        .map_err(CriticalPathSchedulerError::UnknownDependencies)?;
+        self.warnings = input_warnings(
+            self.tasks.values(),
+            self.current_date,
+            &self.completed_task_ids,
+        );
+        self.warnings.extend(task_warnings(
+            self.tasks.values(),
+            self.current_date,
+            &self.completed_task_ids,
+        ));
+        self.warnings.extend(recurrence_warnings(
+            &self.source_tasks,
+            self.current_date,
+            &self.completed_task_ids,
+        ));
+        let fixed = take_fixed_tasks(
+            &mut self.tasks,
+            &mut self.fixed_dependencies,
+            self.resource_config.as_ref(),
+            &self.global_dns_periods,
+            self.current_date,
+        )?;
+        apply_external_deadlines(&mut self.tasks, &fixed.external_deadlines);
+        self.fixed_source = fixed.tasks;
+        self.warnings.extend(fixed.warnings);
+        let mut fixed_tasks = fixed.placements;
+        self.warnings.extend(
+            resolve_fixed_conflicts(&mut fixed_tasks, self.config.on_fixed_conflict)
+                .map_err(CriticalPathSchedulerError::FixedTaskConflicts)?,
+        );
+        Ok((
+            ignored_dependencies,
+            starting_placements(self.start_from.as_ref(), fixed_tasks),
+        ))
+    }
 
-        for task_id in to_remove {
-            self.tasks.remove(&task_id);
+    /// Put back the fixed tasks the last run took out of the task map.
+    fn restore_fixed_tasks(&mut self) {
+        for task in self.fixed_source.drain(..) {
+            self.tasks.insert(task.id.clone(), task);
         }
+        restore_dropped_dependencies(&mut self.tasks, &mut self.dropped_dependencies);
+    }
 
-        fixed_results
+    /// Run critical path scheduling, adding fixed tasks to a cancelled partial schedule.
+    fn run_critical_path(
+        &mut self,
+        fixed_tasks: &[ScheduledTask],
+    ) -> Result<Vec<ScheduledTask>, CriticalPathSchedulerError> {
+        self.schedule_critical_path(fixed_tasks)
+            .map_err(|e| match e {
+                CriticalPathSchedulerError::Cancelled(partial) => {
+                    let mut all_tasks = fixed_tasks.to_vec();
+                    all_tasks.extend(partial);
+                    CriticalPathSchedulerError::Cancelled(all_tasks)
+                }
+                e => e,
+            })
     }
 
-    /// Calculate end date accounting for DNS periods.
-    fn calculate_dns_aware_end_date(&self, task: &Task, start: NaiveDate) -> NaiveDate {
-        let resource_config = match &self.resource_config {
-            Some(rc) => rc,
-            None => {
-                return start
-                    .checked_add_days(Days::new(task.duration_days.ceil() as u64))
-                    .unwrap_or(start)
-            }
+    /// Apply `on_unschedulable` to the tasks scheduling left over.
+    ///
+    /// Under best effort, reschedules once with unsatisfiable dependencies of
+    /// leftover tasks dropped for this run only. Returns the unschedulable tasks and the tasks
+    /// whose dependencies were dropped.
+    fn resolve_unschedulable(
+        &mut self,
+        fixed_tasks: &[ScheduledTask],
+        scheduled_tasks: &mut Vec<ScheduledTask>,
+    ) -> Result<(Vec<UnschedulableTask>, Vec<String>), CriticalPathSchedulerError> {
+        let Some((relaxed_tasks, relaxed)) = best_effort_relaxation(
+            self.config.on_unschedulable,
+            &self.tasks,
+            fixed_tasks.iter().chain(scheduled_tasks.iter()),
+            &self.completed_task_ids,
+        ) else {
+            return Ok((self.unschedulable(fixed_tasks, scheduled_tasks), Vec::new()));
         };
+        // Reschedule from a relaxed copy so later runs see the tasks as given;
+        // the interned task data is rebuilt for it and again afterwards
+        let tasks = std::mem::replace(&mut self.tasks, relaxed_tasks);
+        self.prepared = None;
+        self.rollout_decisions.clear();
+        self.reservation_log.clear();
+        let rerun = self.run_critical_path(fixed_tasks).map(|placed| {
+            let unschedulable = self.unschedulable(fixed_tasks, &placed);
+            (placed, unschedulable)
+        });
+        self.tasks = tasks;
+        self.prepared = None;
+        let (placed, unschedulable) = rerun?;
+        *scheduled_tasks = placed;
+        Ok((unschedulable, relaxed))
+    }
 
-        if task.resources.is_empty() {
-            return start
-                .checked_add_days(Days::new(task.duration_days.ceil() as u64))
-                .unwrap_or(start);
+    /// Tasks neither fixed nor placed, under `on_unschedulable`.
+    fn unschedulable(
+        &self,
+        fixed_tasks: &[ScheduledTask],
+        scheduled_tasks: &[ScheduledTask],
+    ) -> Vec<UnschedulableTask> {
+        unschedulable_tasks(
+            self.config.on_unschedulable,
+            &self.tasks,
+            fixed_tasks.iter().chain(scheduled_tasks),
+            &self.completed_task_ids,
+            self.resource_config.as_ref(),
+            &self.global_dns_periods,
+        )
+    }
+
+    /// Schedule only the work the given targets need.
+    ///
+    /// Restricts scheduling to the targets and their transitive dependencies.
+    /// Every other task is left unscheduled and listed in the `deferred_tasks`
+    /// metadata. The scheduler's full task set is kept for later runs.
+    pub fn schedule_for_targets(
+        &mut self,
+        target_ids: &[String],
+    ) -> Result<AlgorithmResult, CriticalPathSchedulerError> {
+        self.restore_fixed_tasks();
+        let unknown: Vec<String> = target_ids
+            .iter()
+            .filter(|id| !self.tasks.contains_key(*id))
+            .cloned()
+            .collect();
+        if !unknown.is_empty() {
+            return Err(CriticalPathSchedulerError::UnknownTargets(unknown));
         }
 
-        let mut max_end = start;
-        for (resource_name, _) in &task.resources {
-            let dns_periods =
-                resource_config.get_dns_periods(resource_name, &self.global_dns_periods);
-            let mut resource_schedule =
-                ResourceSchedule::new(Some(dns_periods), resource_name.clone());
-            let completion = resource_schedule.calculate_completion_time(start, task.duration_days);
-            if completion > max_end {
-                max_end = completion;
+        let mut needed: FxHashSet<&str> = FxHashSet::default();
+        let mut stack: Vec<&str> = target_ids.iter().map(String::as_str).collect();
+        while let Some(id) = stack.pop() {
+            if !needed.insert(id) {
+                continue;
             }
+            if let Some(task) = self.tasks.get(id) {
+                stack.extend(task.dependencies.iter().map(|d| d.entity_id.as_str()));
+            }
+        }
+
+        let mut deferred: Vec<String> = self
+            .tasks
+            .keys()
+            .filter(|id| !needed.contains(id.as_str()))
+            .cloned()
+            .collect();
+        deferred.sort();
+        let focused: FxHashMap<String, Task> = self
+            .tasks
+            .iter()
+            .filter(|(id, _)| needed.contains(id.as_str()))
+            .map(|(id, task)| (id.clone(), task.clone()))
+            .collect();
+
+        let all_tasks = std::mem::replace(&mut self.tasks, focused);
+        let prepared = self.prepared.take();
+        let outcome = self.schedule();
+        self.tasks = all_tasks;
+        self.fixed_source.clear();
+        self.dropped_dependencies.clear();
+        self.prepared = prepared;
+
+        let mut result = outcome?;
+        if !deferred.is_empty() {
+            result
+                .algorithm_metadata
+                .insert("deferred_tasks".to_string(), deferred.join(","));
         }
+        Ok(result)
+    }
+
+    /// Which tasks could start on `as_of`, and what blocks the rest.
+    ///
+    /// Tasks with fixed dates leave the scheduler during a run, so query
+    /// before scheduling to include them.
+    pub fn get_eligible_tasks(&self, as_of: NaiveDate) -> Vec<TaskEligibility> {
+        eligible_tasks(self.tasks.values(), &self.completed_task_ids, as_of)
+    }
+
+    /// Metadata of the last completed schedule() run.
+    pub fn get_metadata(&self) -> Option<&AlgorithmMetadata> {
+        self.last_metadata.as_ref()
+    }
+
+    /// Get rollout decisions made during the last schedule() run.
+    pub fn get_rollout_decisions(&self) -> Vec<CriticalPathRolloutDecision> {
+        self.rollout_decisions.clone()
+    }
+
+    /// Simulations behind the last rollout decision of the last run
+    /// (requires `CriticalPathConfig.trace_rollouts`).
+    pub fn get_last_rollout_trace(&self) -> Option<RolloutTrace> {
+        self.last_rollout_trace.clone()
+    }
 
-        max_end
+    /// Get the resource reservations held during the last schedule() run.
+    pub fn get_reservations(&self) -> Vec<ReservationRecord> {
+        self.reservation_log.clone()
     }
 
     /// Main critical path scheduling loop.
@@ -190,23 +928,115 @@ impl CriticalPathScheduler {
         &mut self,
         fixed_tasks: &[ScheduledTask],
     ) -> Result<Vec<ScheduledTask>, CriticalPathSchedulerError> {
-        // Initialize state
+        // Initialize state, pre-populating scheduled dict with fixed tasks
         let mut scheduled: FxHashMap<String, (NaiveDate, NaiveDate)> = FxHashMap::default();
+        for fixed_task in fixed_tasks {
+            scheduled.insert(
+                fixed_task.task_id.clone(),
+                (fixed_task.start_date, fixed_task.end_date),
+            );
+        }
         let unscheduled: FxHashSet<String> = self
             .tasks
             .keys()
-            .filter(|id| !self.completed_task_ids.contains(*id))
+            .filter(|id| !self.completed_task_ids.contains(*id) && !scheduled.contains_key(*id))
             .cloned()
             .collect();
 
-        // Pre-populate scheduled dict with fixed tasks
+        let prepared = match self.prepared.take() {
+            Some(prepared) => prepared,
+            None => self.prepare_run(fixed_tasks),
+        };
+        let ctx = &prepared.ctx;
+        let mut resource_schedules = prepared.resource_schedules.clone();
+
+        // Mark fixed tasks as busy in resource schedules
         for fixed_task in fixed_tasks {
-            scheduled.insert(
-                fixed_task.task_id.clone(),
-                (fixed_task.start_date, fixed_task.end_date),
-            );
+            let category = self.task_categories.get(&fixed_task.task_id);
+            for resource_name in &fixed_task.resources {
+                if let Some(id) = self.resource_index.get(resource_name) {
+                    occupy_fixed(&mut resource_schedules[id as usize], fixed_task, category);
+                }
+            }
+        }
+
+        // Create Vec-based state
+        let initial_time = self.current_date;
+        let scheduled_vec = ctx.to_scheduled_times_vec(&scheduled, initial_time);
+        let unscheduled_vec = ctx.to_unscheduled_vec(&unscheduled);
+
+        let start_time = self
+            .start_from
+            .as_ref()
+            .map_or(self.current_date, |s| s.current_time.max(self.current_date));
+        let mut state = CriticalPathSchedulerState::new(
+            scheduled_vec,
+            unscheduled_vec,
+            initial_time,
+            resource_schedules,
+            start_time,
+        );
+        state.exclusions = self.exclusions.clone();
+        for fixed_task in fixed_tasks {
+            state.exclusions.occupy(fixed_task);
         }
+        for held in self.start_from.iter().flat_map(|s| &s.reservations) {
+            let (Some(res_id), Some(task_int)) = (
+                self.resource_index.get(&held.resource),
+                ctx.index.get(&held.task_id),
+            ) else {
+                continue;
+            };
+            if state.unscheduled_vec[task_int as usize] {
+                state.reservations.insert(
+                    res_id,
+                    ResourceReservation {
+                        resource: held.resource.clone(),
+                        target_id: held.target_id.clone(),
+                        task_id: held.task_id.clone(),
+                        task_int,
+                        target_score: held.target_score,
+                        reserved_from: held.reserved_from,
+                        release_on: held.release_on,
+                    },
+                );
+            }
+        }
+
+        if self.stepping {
+            state.step = Some(ScheduleStep::new(state.current_time));
+        }
+
+        // Run the main scheduling loop with rollout enabled
+        let main_loop_started = profiling::start(self.config.profile);
+        let outcome = self.schedule_from_state_internal(state, ctx, self.pause_at, true, None);
+        self.prepared = Some(prepared);
+        let mut final_state = outcome?;
+        profiling::stop(main_loop_started, &mut final_state.timings.main_loop_ms);
+        if self.pause_at.is_some() {
+            self.held_reservations = final_state.held_reservations();
+        }
+        if let Some(mut step) = final_state.step.take() {
+            step.snapshot.current_time = final_state.current_time;
+            step.snapshot.reservations = final_state.held_reservations();
+            self.last_step = Some(step);
+        }
+        final_state.release_reservations("open", |_| true);
+        self.reservation_log = final_state.reservation_log;
+        self.rollout_decisions = final_state.rollout_decisions;
+        self.rollout_cache_stats = final_state.rollout_cache_stats;
+        self.iterations = final_state.iterations;
+        self.timings = final_state.timings;
+        if final_state.rollout_trace.is_some() {
+            self.last_rollout_trace = final_state.rollout_trace;
+        }
+        Ok(final_state.result)
+    }
 
+    /// Intern the tasks and build resource calendars for a run.
+    ///
+    /// Also sets the resource index, resource requirements and exclusive tasks.
+    fn prepare_run(&mut self, fixed_tasks: &[ScheduledTask]) -> PreparedRun {
         // Build ResourceIndex.
         // If resource_config exists: use resource_order (in order) and validate assignments.
         // If no resource_config: collect resources from tasks (legacy mode, no validation).
@@ -264,26 +1094,15 @@ impl CriticalPathScheduler {
         let mut resource_schedules: Vec<ResourceSchedule> =
             Vec::with_capacity(self.resource_index.len());
         for (id, name) in self.resource_index.iter() {
-            let unavailable_periods = match &self.resource_config {
-                Some(rc) => rc.get_dns_periods(name, &self.global_dns_periods),
-                None => self.global_dns_periods.clone(),
+            let schedule = match &self.resource_config {
+                Some(rc) => rc.build_schedule(name, &self.global_dns_periods),
+                None => {
+                    ResourceSchedule::new(Some(self.global_dns_periods.clone()), name.to_string())
+                }
             };
             // Ensure we're adding at the right index
             debug_assert_eq!(resource_schedules.len(), id as usize);
-            resource_schedules.push(ResourceSchedule::new(
-                Some(unavailable_periods),
-                name.to_string(),
-            ));
-        }
-
-        // Mark fixed tasks as busy in resource schedules
-        for fixed_task in fixed_tasks {
-            for resource_name in &fixed_task.resources {
-                if let Some(id) = self.resource_index.get_id(resource_name) {
-                    resource_schedules[id as usize]
-                        .add_busy_period(fixed_task.start_date, fixed_task.end_date);
-                }
-            }
+            resource_schedules.push(schedule);
         }
 
         // Build task resource requirements (precompute masks for fast availability checks)
@@ -291,10 +1110,11 @@ impl CriticalPathScheduler {
 
         // Pre-compute task data once (for integer ID lookups)
         let mut ctx = TaskData::new(&self.tasks, self.default_priority);
+        ctx.set_lag_calendar(self.global_dns_periods.clone());
         let n = ctx.len();
         let mut resource_reqs: Vec<Option<TaskResourceReq>> = vec![None; n];
         for task_id in self.tasks.keys() {
-            if let Some(task_int) = ctx.index.get_id(task_id) {
+            if let Some(task_int) = ctx.index.get(task_id) {
                 if let Some(req) = self.task_resource_reqs.get(task_id) {
                     resource_reqs[task_int as usize] = Some(*req);
                 }
@@ -302,25 +1122,26 @@ impl CriticalPathScheduler {
         }
         ctx.set_resource_reqs(resource_reqs);
 
+        // Part-time resources stretch critical path estimates
+        if let Some(rc) = self
+            .resource_config
+            .as_ref()
+            .filter(|rc| !rc.capacity.is_empty())
+        {
+            for (task_id, task) in &self.tasks {
+                if let Some(task_int) = ctx.index.get(task_id) {
+                    ctx.durations[task_int as usize] = task.duration_days / rc.task_capacity(task);
+                }
+            }
+        }
+
         // Build resource_exclusive_tasks map (for prefer_fungible_resources optimization)
         self.resource_exclusive_tasks = self.build_resource_exclusive_tasks(&ctx);
 
-        // Create Vec-based state
-        let initial_time = self.current_date;
-        let scheduled_vec = ctx.to_scheduled_times_vec(&scheduled, initial_time);
-        let unscheduled_vec = ctx.to_unscheduled_vec(&unscheduled);
-
-        let state = CriticalPathSchedulerState::new(
-            scheduled_vec,
-            unscheduled_vec,
-            initial_time,
+        PreparedRun {
+            ctx,
             resource_schedules,
-            self.current_date,
-        );
-
-        // Run the main scheduling loop with rollout enabled
-        let final_state = self.schedule_from_state_internal(state, &ctx, None, true, None)?;
-        Ok(final_state.result)
+        }
     }
 
     /// Build precomputed resource requirements for all tasks.
@@ -340,17 +1161,16 @@ impl CriticalPathScheduler {
                 // Explicit resources: ALL must be available
                 requires_all = true;
                 for (resource_name, _) in &task.resources {
-                    if let Some(id) = self.resource_index.get_id(resource_name) {
+                    if let Some(id) = self.resource_index.get(resource_name) {
                         mask.set(id);
                     }
                 }
-            } else if let Some(spec) = &task.resource_spec {
+            } else if task.resource_spec.is_some() {
                 // Auto-assignment: ANY candidate must be available
                 requires_all = false;
                 if let Some(config) = &self.resource_config {
-                    let candidates = config.expand_resource_spec(spec);
-                    for candidate in candidates {
-                        if let Some(id) = self.resource_index.get_id(&candidate) {
+                    for candidate in config.candidates_for(task) {
+                        if let Some(id) = self.resource_index.get(&candidate) {
                             mask.set(id);
                         }
                     }
@@ -388,14 +1208,14 @@ impl CriticalPathScheduler {
             }
 
             // Get the task's integer ID
-            let task_int = match ctx.index.get_id(task_id) {
+            let task_int = match ctx.index.get(task_id) {
                 Some(id) => id,
                 None => continue,
             };
 
             // Add this task to each resource it explicitly requires
             for (resource_name, _) in &task.resources {
-                if let Some(res_id) = self.resource_index.get_id(resource_name) {
+                if let Some(res_id) = self.resource_index.get(resource_name) {
                     result[res_id as usize].push(task_int);
                 }
             }
@@ -424,7 +1244,8 @@ impl CriticalPathScheduler {
         skip_task_int_at_initial_time: Option<TaskId>,
     ) -> Result<CriticalPathSchedulerState, CriticalPathSchedulerError> {
         let initial_time = state.initial_time;
-        let max_iterations = self.tasks.len() * 100;
+        let mut advances =
+            AdvanceLimit::new(self.config.max_iterations.unwrap_or(self.tasks.len() * 100));
         let verbosity = if enable_rollout {
             self.config.verbosity
         } else {
@@ -443,9 +1264,10 @@ impl CriticalPathScheduler {
             .iter()
             .enumerate()
             .filter(|(_, &is_unscheduled)| is_unscheduled)
-            .filter_map(|(idx, _)| ctx.index.get_name(idx as u32).map(|s| s.to_string()))
+            .filter_map(|(idx, _)| ctx.index.resolve(idx as u32).map(|s| s.to_string()))
             .collect();
 
+        let cache_started = profiling::start(self.config.profile);
         let mut cache = CriticalPathCache::new(
             &unscheduled_set,
             &self.tasks,
@@ -454,15 +1276,84 @@ impl CriticalPathScheduler {
             &completed_vec,
             self.default_priority,
         )?;
+        profiling::stop(cache_started, &mut state.timings.cache_build_ms);
 
         // Extract end offsets view for eligibility checks (mutable to update)
         // We use a separate scheduled_end_vec that we keep in sync with state.scheduled_vec
         let mut scheduled_end_vec = scheduled_end_vec;
 
-        for _iteration in 0..max_iterations {
+        let mut rollout_memo = RolloutMemo::new(if enable_rollout {
+            self.config.rollout_cache_size
+        } else {
+            0
+        });
+
+        let mut events = EventQueue::default();
+        for (task_idx, &is_unscheduled) in state.unscheduled_vec.iter().enumerate() {
+            if !is_unscheduled {
+                continue;
+            }
+            let task_int = task_idx as TaskId;
+            if let Some(start_after) = ctx.start_afters[task_idx] {
+                events.push(start_after, EventSource::Task(task_int));
+            }
+            for &(_, end) in &ctx.blocked_periods[task_idx] {
+                if let Some(next) = end.succ_opt() {
+                    events.push(next, EventSource::Task(task_int));
+                }
+            }
+            for &(dep_int, lag) in &ctx.deps[task_idx] {
+                let dep_end_offset = scheduled_end_vec[dep_int as usize];
+                if dep_end_offset < f64::MAX {
+                    events.push(
+                        ready_date(ctx, task_int, dep_int, initial_time, dep_end_offset, lag)?,
+                        EventSource::Task(task_int),
+                    );
+                }
+            }
+        }
+        for (res_idx, schedule) in state.resource_schedules.iter().enumerate() {
+            for (_, busy_end) in schedule.busy_periods() {
+                if let Some(release) = busy_end.checked_add_days(Days::new(1)) {
+                    events.push(release, EventSource::Resource(res_idx as u32));
+                }
+            }
+            if let Some(rc) = &self.resource_config {
+                for (end, category) in schedule.categories() {
+                    for release in rc.changeover_releases(category, end) {
+                        events.push(release, EventSource::Changeover(res_idx as u32));
+                    }
+                }
+            }
+        }
+        if self.config.reservation_max_idle_days > 0 {
+            for (&res_id, reservation) in &state.reservations {
+                events.push(reservation.release_on, EventSource::Reservation(res_id));
+            }
+        }
+
+        for iteration in 0.. {
             if cache.is_empty() {
                 break;
             }
+            state.iterations += 1;
+
+            if enable_rollout {
+                if let Some(progress) = &self.run_progress {
+                    let remaining = state.unscheduled_vec.iter().filter(|&&u| u).count();
+                    progress.maybe_report(
+                        iteration,
+                        state.result.len(),
+                        remaining,
+                        state.current_time,
+                    );
+                }
+            }
+
+            if self.stop_condition.should_stop() {
+                log_changes!(verbosity, "Scheduling cancelled at {}", state.current_time);
+                return Err(CriticalPathSchedulerError::Cancelled(state.result));
+            }
 
             // Check horizon limit
             if let Some(h) = horizon {
@@ -511,6 +1402,22 @@ impl CriticalPathScheduler {
                     state.current_time,
                 );
 
+                // Simulations may leave tasks out under reduced rollout fidelity
+                let fidelity = if enable_rollout {
+                    RolloutFidelity::Full
+                } else {
+                    self.config.rollout_fidelity
+                };
+                let critical_tasks: FxHashSet<TaskId> =
+                    if fidelity == RolloutFidelity::CriticalTasksOnly {
+                        ranked_targets
+                            .iter()
+                            .flat_map(|t| t.critical_path_ints.iter().copied())
+                            .collect()
+                    } else {
+                        FxHashSet::default()
+                    };
+
                 // Score each eligible task and collect those with resources available
                 let mut scored_tasks: Vec<(TaskId, f64)> = eligible_tasks
                     .iter()
@@ -524,6 +1431,10 @@ impl CriticalPathScheduler {
                             }
                         }
 
+                        if !Self::simulates_task(task_int, fidelity, ctx, &critical_tasks) {
+                            return None;
+                        }
+
                         // Check if task has any available resource
                         if !self.task_has_available_resource(task_int, ctx, available_mask) {
                             return None;
@@ -534,17 +1445,40 @@ impl CriticalPathScheduler {
                     })
                     .collect();
 
-                // Sort by score descending (highest score first)
-                scored_tasks
-                    .sort_by(|a, b| b.1.partial_cmp(&a.1).unwrap_or(std::cmp::Ordering::Equal));
+                // Sort by score descending (highest score first), then backlog rank
+                scored_tasks.sort_by(|a, b| {
+                    b.1.partial_cmp(&a.1)
+                        .unwrap_or(std::cmp::Ordering::Equal)
+                        .then_with(|| cmp_rank(ctx.ranks[a.0 as usize], ctx.ranks[b.0 as usize]))
+                });
+                if let Some(ordering) = &self.task_ordering {
+                    ordering.reorder(
+                        &mut scored_tasks,
+                        &ranked_targets,
+                        &cache,
+                        ctx,
+                        &state.unscheduled_vec,
+                        state.current_time,
+                    );
+                }
+
+                if let Some(step) = &mut state.step {
+                    step.ranked_target_ids =
+                        ranked_targets.iter().map(|t| t.target_id.clone()).collect();
+                    step.eligible_task_ids = scored_tasks
+                        .iter()
+                        .filter_map(|&(task_int, _)| ctx.index.resolve(task_int))
+                        .map(str::to_string)
+                        .collect();
+                }
 
                 if verbosity >= crate::logging::VERBOSITY_DEBUG {
                     eprintln!("  Eligible tasks:");
                     for (task_int, _score) in scored_tasks.iter().take(5) {
-                        let task_name = ctx.index.get_name(*task_int).unwrap_or("?");
+                        let task_name = ctx.index.resolve(*task_int).unwrap_or("?");
                         let (best_target, slack, denom, task_urg, target_score, final_score) =
                             cache.score_eligible_task_breakdown(*task_int, &self.config);
-                        let target_name = ctx.index.get_name(best_target).unwrap_or("?");
+                        let target_name = ctx.index.resolve(best_target).unwrap_or("?");
                         eprintln!(
                             "    {} via {} slack={:.1} denom={:.1} urg={:.3} tgt={:.3} => {:.3}",
                             task_name,
@@ -559,9 +1493,11 @@ impl CriticalPathScheduler {
                 }
 
                 // Try to schedule tasks in score order
-                'task_loop: for (best_task_int, task_score) in scored_tasks {
+                'task_loop: for (rank, &(best_task_int, task_score)) in
+                    scored_tasks.iter().enumerate()
+                {
                     // Convert to string ID for operations that still need it
-                    let best_task_id = match ctx.index.get_name(best_task_int) {
+                    let best_task_id = match ctx.index.resolve(best_task_int) {
                         Some(name) => name.to_string(),
                         None => continue,
                     };
@@ -576,29 +1512,63 @@ impl CriticalPathScheduler {
                         task_score
                     );
 
+                    if let Some(free) = state
+                        .exclusions
+                        .blocked_until(&best_task_id, state.current_time)
+                    {
+                        log_checks!(
+                            verbosity,
+                            "    Skipping {}: exclusive group busy until {}",
+                            best_task_id,
+                            free
+                        );
+                        events.push(free, EventSource::Task(best_task_int));
+                        continue;
+                    }
+
                     // Check rollout: should we skip this task for a better upcoming task?
                     if enable_rollout && self.config.rollout_enabled {
-                        if let Some((skip_reason, reservation)) = self.check_rollout_skip(
+                        let rollout_started = profiling::start(self.config.profile);
+                        let outcome = self.check_rollout_skip(
                             best_task_int,
                             &best_task_id,
                             task_score,
+                            &scored_tasks[rank + 1..],
                             &ranked_targets,
                             &state,
                             ctx,
                             available_mask,
-                        ) {
-                            log_checks!(
-                                verbosity,
-                                "    Skipping {} for rollout: {}",
-                                best_task_id,
-                                skip_reason
-                            );
-                            // Store the reservation (keyed by resource ID)
-                            if let Some(res_id) = self.resource_index.get_id(&reservation.resource)
-                            {
-                                state.reservations.insert(res_id, reservation);
+                            &mut rollout_memo,
+                        );
+                        profiling::stop(rollout_started, &mut state.timings.rollout_ms);
+                        if let Some((decision, reservation, trace)) = outcome {
+                            if trace.is_some() {
+                                state.rollout_trace = trace;
+                            }
+                            if let Some(reservation) = reservation {
+                                log_checks!(
+                                    verbosity,
+                                    "    Skipping {} for rollout: better to wait for {} (target score {:.2} vs {:.2})",
+                                    best_task_id,
+                                    decision.competing_task_id,
+                                    decision.competing_target_score,
+                                    task_score
+                                );
+                                state.rollout_decisions.push(decision);
+                                // Store the reservation (keyed by resource ID)
+                                if let Some(res_id) = self.resource_index.get(&reservation.resource)
+                                {
+                                    if self.config.reservation_max_idle_days > 0 {
+                                        events.push(
+                                            reservation.release_on,
+                                            EventSource::Reservation(res_id),
+                                        );
+                                    }
+                                    state.reserve(res_id, reservation);
+                                }
+                                continue;
                             }
-                            continue;
+                            state.rollout_decisions.push(decision);
                         }
                     }
 
@@ -625,15 +1595,50 @@ impl CriticalPathScheduler {
                         state.unscheduled_vec[task_idx] = false;
                         scheduled_end_vec[task_idx] = end_offset;
 
+                        for &(dependent, lag) in &ctx.dependents[task_idx] {
+                            events.push(
+                                ready_date(
+                                    ctx,
+                                    dependent,
+                                    best_task_int,
+                                    initial_time,
+                                    end_offset,
+                                    lag,
+                                )?,
+                                EventSource::Task(dependent),
+                            );
+                        }
+                        let category = self.task_categories.get(&best_task_id);
+                        for resource in &scheduled_task.resources {
+                            let Some(res_id) = self.resource_index.get(resource) else {
+                                continue;
+                            };
+                            let schedule = &mut state.resource_schedules[res_id as usize];
+                            if let Some(release) = schedule.next_release_after(state.current_time) {
+                                events.push(release, EventSource::Resource(res_id));
+                            }
+                            if let (Some(category), Some(rc)) = (category, &self.resource_config) {
+                                schedule.record_category(scheduled_task.end_date, category);
+                                for release in
+                                    rc.changeover_releases(category, scheduled_task.end_date)
+                                {
+                                    events.push(release, EventSource::Changeover(res_id));
+                                }
+                            }
+                        }
+
                         // Incrementally update the cache
+                        let invalidation_started = profiling::start(self.config.profile);
                         cache.on_task_scheduled(
                             &best_task_id,
-                            &self.tasks,
                             ctx,
                             &scheduled_end_vec,
                             &completed_vec,
-                            self.default_priority,
-                        )?;
+                        );
+                        profiling::stop(
+                            invalidation_started,
+                            &mut state.timings.cache_invalidation_ms,
+                        );
 
                         if scheduled_task.duration_days == 0.0 {
                             log_changes!(
@@ -642,6 +1647,12 @@ impl CriticalPathScheduler {
                                 best_task_id,
                                 state.current_time
                             );
+                            trace_placement!(
+                                best_task_id,
+                                "",
+                                state.current_time,
+                                state.current_time
+                            );
                         } else {
                             log_changes!(
                                 verbosity,
@@ -651,11 +1662,23 @@ impl CriticalPathScheduler {
                                 scheduled_task.start_date,
                                 scheduled_task.end_date
                             );
+                            trace_placement!(
+                                best_task_id,
+                                scheduled_task.resources.join(", "),
+                                scheduled_task.start_date,
+                                scheduled_task.end_date
+                            );
                         }
 
                         // Clear any reservation for this task (it's now scheduled)
-                        state.reservations.retain(|_, r| r.task_id != best_task_id);
+                        state.release_reservations("fulfilled", |r| r.task_int == best_task_int);
 
+                        if let Some(step) = &mut state.step {
+                            step.kind = "placed".to_string();
+                            step.time = state.current_time;
+                            step.placed = Some(scheduled_task.clone());
+                        }
+                        state.exclusions.occupy(&scheduled_task);
                         state.result.push(scheduled_task);
                         scheduled_any = true;
                         break 'task_loop; // One task per iteration (single-target focus preserved)
@@ -673,14 +1696,20 @@ impl CriticalPathScheduler {
 
             if !scheduled_any {
                 // No eligible tasks - advance time
-                match self.find_next_event_time(
-                    ctx,
-                    &scheduled_end_vec,
-                    &state.unscheduled_vec,
-                    &state.resource_schedules,
-                    initial_time,
-                    state.current_time,
-                ) {
+                let current_time = state.current_time;
+                let next_event = events.next_after(current_time, |date, source| match source {
+                    EventSource::Task(task_int) => state.unscheduled_vec[task_int as usize],
+                    EventSource::Resource(res_id) => {
+                        state.resource_schedules[res_id as usize].next_release_after(current_time)
+                            == Some(date)
+                    }
+                    EventSource::Reservation(res_id) => state
+                        .reservations
+                        .get(&res_id)
+                        .is_some_and(|r| r.release_on == date),
+                    EventSource::Changeover(_) => true,
+                });
+                match next_event {
                     Some(next_time) => {
                         // Check horizon before advancing
                         if let Some(h) = horizon {
@@ -695,6 +1724,23 @@ impl CriticalPathScheduler {
                             next_time
                         );
                         state.current_time = next_time;
+                        if advances.record(next_time) {
+                            // Simulations stop quietly; the real run reports the limit
+                            if horizon.is_some() {
+                                break;
+                            }
+                            let remaining = state
+                                .unscheduled_vec
+                                .iter()
+                                .enumerate()
+                                .filter(|(_, &is_unscheduled)| is_unscheduled)
+                                .filter_map(|(idx, _)| ctx.index.resolve(idx as u32))
+                                .map(str::to_string)
+                                .collect();
+                            return Err(CriticalPathSchedulerError::IterationLimit(
+                                advances.exhausted(next_time, remaining),
+                            ));
+                        }
                     }
                     None => {
                         log_debug!(verbosity, "  No more events, stopping");
@@ -702,23 +1748,35 @@ impl CriticalPathScheduler {
                     }
                 }
 
-                // Clear expired reservations (reserved_from is in the past)
-                state
-                    .reservations
-                    .retain(|_, r| r.reserved_from >= state.current_time);
+                // Release reservations whose idle allowance has run out
+                let now = state.current_time;
+                state.release_reservations("expired", |r| r.release_on <= now);
+                if let Some(step) = &mut state.step {
+                    step.kind = "advanced".to_string();
+                    step.time = now;
+                }
+            }
+
+            if state.step.as_ref().is_some_and(ScheduleStep::is_decided) {
+                break;
             }
         }
 
+        state.rollout_cache_stats = (rollout_memo.hits, rollout_memo.misses);
+
         // For normal scheduling, error if not all tasks scheduled
         // For simulation (with horizon), partial schedule is OK
-        if horizon.is_none() {
+        if horizon.is_none()
+            && state.step.is_none()
+            && self.config.on_unschedulable == UnschedulablePolicy::Error
+        {
             // Check for unscheduled tasks using Vec state
             let unscheduled_ids: Vec<String> = state
                 .unscheduled_vec
                 .iter()
                 .enumerate()
                 .filter(|(_, &is_unscheduled)| is_unscheduled)
-                .filter_map(|(idx, _)| ctx.index.get_name(idx as u32).map(|s| s.to_string()))
+                .filter_map(|(idx, _)| ctx.index.resolve(idx as u32).map(|s| s.to_string()))
                 .collect();
             if !unscheduled_ids.is_empty() {
                 return Err(CriticalPathSchedulerError::FailedToSchedule(
@@ -736,18 +1794,18 @@ impl CriticalPathScheduler {
         state: &CriticalPathSchedulerState,
         ctx: &TaskData,
         horizon: NaiveDate,
-    ) -> f64 {
+    ) -> Result<f64, DateOverflow> {
         // Build list of all scheduled tasks from Vec state
         let mut all_scheduled_tasks: Vec<ScheduledTask> = Vec::new();
         let mut unscheduled_set: FxHashSet<String> = FxHashSet::default();
         let mut scheduled_map: FxHashMap<String, (NaiveDate, NaiveDate)> = FxHashMap::default();
 
         for (idx, (start_offset, end_offset)) in state.scheduled_vec.iter().enumerate() {
-            if let Some(task_id) = ctx.index.get_name(idx as u32) {
+            if let Some(task_id) = ctx.index.resolve(idx as u32) {
                 if *end_offset < f64::MAX {
                     // Task is scheduled
-                    let start_date = state.offset_to_date(*start_offset);
-                    let end_date = state.offset_to_date(*end_offset);
+                    let start_date = state.offset_to_date(*start_offset)?;
+                    let end_date = state.offset_to_date(*end_offset)?;
                     if let Some(task) = self.tasks.get(task_id) {
                         all_scheduled_tasks.push(ScheduledTask {
                             task_id: task_id.to_string(),
@@ -755,6 +1813,7 @@ impl CriticalPathScheduler {
                             end_date,
                             duration_days: task.duration_days,
                             resources: task.resources.iter().map(|(r, _)| r.clone()).collect(),
+                            ..Default::default()
                         });
                     }
                     scheduled_map.insert(task_id.to_string(), (start_date, end_date));
@@ -764,7 +1823,7 @@ impl CriticalPathScheduler {
 
         for (idx, &is_unscheduled) in state.unscheduled_vec.iter().enumerate() {
             if is_unscheduled {
-                if let Some(task_id) = ctx.index.get_name(idx as u32) {
+                if let Some(task_id) = ctx.index.resolve(idx as u32) {
                     unscheduled_set.insert(task_id.to_string());
                 }
             }
@@ -774,7 +1833,7 @@ impl CriticalPathScheduler {
         let computed_deadlines: FxHashMap<String, NaiveDate> = self
             .tasks
             .iter()
-            .filter_map(|(id, t)| t.end_before.map(|d| (id.clone(), d)))
+            .filter_map(|(id, t)| t.tightest_deadline().map(|d| (id.clone(), d)))
             .collect();
         let computed_priorities: FxHashMap<String, i32> = self
             .tasks
@@ -782,7 +1841,7 @@ impl CriticalPathScheduler {
             .filter_map(|(id, t)| t.priority.map(|p| (id.clone(), p)))
             .collect();
 
-        score_schedule(
+        Ok(score_schedule(
             &all_scheduled_tasks,
             &unscheduled_set,
             &self.tasks,
@@ -792,7 +1851,10 @@ impl CriticalPathScheduler {
             state.initial_time,
             horizon,
             self.default_priority,
-        )
+            self.resource_config.as_ref(),
+            &self.config.objective,
+            &self.global_dns_periods,
+        ))
     }
     /// Get all eligible tasks from any target's dependency subgraph.
     ///
@@ -844,7 +1906,10 @@ impl CriticalPathScheduler {
 
                 // Check if dependency is scheduled
                 let dep_end = scheduled_vec[dep_idx];
-                if dep_end < f64::MAX {
+                if dep_end < f64::MAX && ctx.working_lags.contains(&(task_int, dep_int)) {
+                    ready_date(ctx, task_int, dep_int, initial_time, dep_end, lag)
+                        .is_ok_and(|ready| ready <= current_time)
+                } else if dep_end < f64::MAX {
                     let eligible_after = dep_end + lag;
                     eligible_after < current_offset
                 } else {
@@ -863,6 +1928,10 @@ impl CriticalPathScheduler {
                 }
             }
 
+            if first_unblocked(&ctx.blocked_periods[idx], current_time) != current_time {
+                continue;
+            }
+
             eligible.push(task_int);
         }
 
@@ -894,75 +1963,6 @@ impl CriticalPathScheduler {
         }
     }
 
-    /// Find next event time using integer IDs and Vec-based state.
-    ///
-    /// Returns the earliest time when something changes:
-    /// - A dependency completes (task becomes eligible)
-    /// - A start_after constraint is satisfied
-    /// - A resource becomes available
-    fn find_next_event_time(
-        &self,
-        ctx: &TaskData,
-        scheduled_vec: &[f64],
-        unscheduled_vec: &[bool],
-        resource_schedules: &[ResourceSchedule],
-        initial_time: NaiveDate,
-        current_time: NaiveDate,
-    ) -> Option<NaiveDate> {
-        let mut next_event: Option<NaiveDate> = None;
-
-        // Check all unscheduled tasks for when they become eligible
-        for (task_int, &is_unscheduled) in unscheduled_vec.iter().enumerate() {
-            if !is_unscheduled {
-                continue;
-            }
-
-            // Check dependencies - when will this task become eligible?
-            for &(dep_int, lag) in &ctx.deps[task_int] {
-                let dep_end_offset = scheduled_vec[dep_int as usize];
-                if dep_end_offset < f64::MAX {
-                    // Dependency is scheduled - compute when task becomes eligible
-                    // eligible = dep_end + lag + 1 day (next day after lag)
-                    let eligible_offset = dep_end_offset + lag.ceil() + 1.0;
-                    let eligible_date =
-                        initial_time + chrono::Duration::days(eligible_offset as i64);
-                    if eligible_date > current_time {
-                        next_event = Some(match next_event {
-                            Some(e) => e.min(eligible_date),
-                            None => eligible_date,
-                        });
-                    }
-                }
-            }
-
-            // Check start_after constraint
-            if let Some(start_after) = ctx.start_afters[task_int] {
-                if start_after > current_time {
-                    next_event = Some(match next_event {
-                        Some(e) => e.min(start_after),
-                        None => start_after,
-                    });
-                }
-            }
-        }
-
-        // Resource busy period ends
-        for schedule in resource_schedules.iter() {
-            for (_, busy_end) in &schedule.busy_periods {
-                if *busy_end >= current_time {
-                    if let Some(next_day) = busy_end.checked_add_days(Days::new(1)) {
-                        next_event = Some(match next_event {
-                            Some(e) => e.min(next_day),
-                            None => next_day,
-                        });
-                    }
-                }
-            }
-        }
-
-        next_event
-    }
-
     /// Try to schedule a task at current_time, optionally respecting reservations.
     ///
     /// Reservations protect resources for higher-priority tasks. A task can only
@@ -991,6 +1991,7 @@ impl CriticalPathScheduler {
                 end_date: current_time,
                 duration_days: 0.0,
                 resources: vec![],
+                ..Default::default()
             });
         }
 
@@ -1055,6 +2056,13 @@ impl CriticalPathScheduler {
             }
         }
 
+        // Filter out resources still changing over from another category
+        for res_id in candidates_mask.iter() {
+            if !self.changed_over(&resource_schedules[res_id as usize], task, current_time) {
+                candidates_mask.clear(res_id);
+            }
+        }
+
         if candidates_mask.is_empty() {
             return None;
         }
@@ -1067,26 +2075,84 @@ impl CriticalPathScheduler {
 
         for resource_id in candidates_mask.iter() {
             let schedule = &mut resource_schedules[resource_id as usize];
-            let completion = schedule.calculate_completion_time(current_time, task.duration_days);
+            let duration = self.duration_on(resource_id, task);
+            let (completion, _) = schedule.completion_point_avoiding(
+                current_time,
+                0.0,
+                duration,
+                &task.blocked_periods,
+            );
             valid_candidates.push((resource_id, completion));
         }
 
-        // Find the best completion time
-        let best_completion = valid_candidates.iter().map(|(_, c)| *c).min().unwrap();
+        // Find the best completion time, charging switch and preference penalties
+        let penalty = |id: u32| {
+            self.resource_config.as_ref().map_or(0.0, |rc| {
+                rc.assignment_penalty(task, self.resource_index.resolve(id).unwrap_or(""))
+            })
+        };
+        let score = |id: u32, c: NaiveDate| (c - current_time).num_days() as f64 + penalty(id);
+        let best_score = valid_candidates
+            .iter()
+            .map(|&(id, c)| score(id, c))
+            .fold(f64::INFINITY, f64::min);
 
         // Filter to candidates with the best completion time (ties)
-        let tied_candidates: Vec<_> = valid_candidates
+        let mut tied_candidates: Vec<_> = valid_candidates
             .into_iter()
-            .filter(|(_, c)| *c == best_completion)
+            .filter(|&(id, c)| score(id, c) <= best_score)
             .collect();
 
+        // Resources without a preference handicap win ties
+        let min_penalty = tied_candidates
+            .iter()
+            .map(|&(id, _)| penalty(id))
+            .fold(f64::INFINITY, f64::min);
+        tied_candidates.retain(|&(id, _)| penalty(id) <= min_penalty);
+
+        // A task's prior assignment wins remaining ties
+        if let Some(prior) = self
+            .resource_config
+            .as_ref()
+            .and_then(|rc| rc.prior_assignments.get(task_id))
+        {
+            if let Some(&kept) = tied_candidates
+                .iter()
+                .find(|(id, _)| self.resource_index.resolve(*id) == Some(prior.as_str()))
+            {
+                tied_candidates = vec![kept];
+            }
+        }
+
+        // Among ties, keep only the cheapest resources
+        if let Some(rc) = self.resource_config.as_ref().filter(|rc| rc.has_costs()) {
+            let rate_of = |id: u32| rc.cost_rate(self.resource_index.resolve(id).unwrap_or(""));
+            let min_rate = tied_candidates
+                .iter()
+                .map(|(id, _)| rate_of(*id))
+                .fold(f64::INFINITY, f64::min);
+            tied_candidates.retain(|(id, _)| rate_of(*id) <= min_rate);
+        }
+
+        // Among remaining ties, prefer the least-overqualified resources
+        if let Some(rc) = self.resource_config.as_ref() {
+            if !task.required_skills.is_empty() {
+                let extra_of = |id: u32| {
+                    let name = self.resource_index.resolve(id).unwrap_or("");
+                    rc.overqualification(name, &task.required_skills)
+                };
+                if let Some(min_extra) = tied_candidates.iter().map(|(id, _)| extra_of(*id)).min() {
+                    tied_candidates.retain(|(id, _)| extra_of(*id) == min_extra);
+                }
+            }
+        }
+
         let num_tied = tied_candidates.len();
 
         // Select the best resource (integer ID only)
         let best_resource_id = if num_tied == 1 || !self.config.prefer_fungible_resources {
             // Only one option, or fungibility optimization disabled - take first
-            let (id, _) = tied_candidates.into_iter().next().unwrap();
-            id
+            tied_candidates[0].0
         } else {
             // Multiple tied candidates - use smart resource selection
             // (fast path for fungible, rollout for scarce)
@@ -1095,10 +2161,10 @@ impl CriticalPathScheduler {
                 "    Checking fungibility for {} candidates:",
                 num_tied
             );
-            for (res_id, _) in &tied_candidates {
+            for (res_id, completion) in &tied_candidates {
                 let (blocking, blocking_details) = self.get_exclusive_blocking_details(
                     *res_id,
-                    best_completion,
+                    *completion,
                     ctx,
                     scheduled_vec,
                     unscheduled_vec,
@@ -1107,10 +2173,10 @@ impl CriticalPathScheduler {
                 );
                 if verbosity >= crate::logging::VERBOSITY_DEBUG {
                     let exclusive_count = self.resource_exclusive_tasks[*res_id as usize].len();
-                    let res_name = self.resource_index.get_name(*res_id).unwrap_or("?");
+                    let res_name = self.resource_index.resolve(*res_id).unwrap_or("?");
                     eprintln!(
                         "      {}: {} exclusive tasks total, {} blocking before {}",
-                        res_name, exclusive_count, blocking, best_completion
+                        res_name, exclusive_count, blocking, completion
                     );
                     for detail in blocking_details {
                         eprintln!("        - {}", detail);
@@ -1130,10 +2196,15 @@ impl CriticalPathScheduler {
             )
         };
 
+        let best_completion = tied_candidates
+            .iter()
+            .find(|(id, _)| *id == best_resource_id)
+            .map_or(tied_candidates[0].1, |(_, c)| *c);
+
         // Look up resource name only at the end for the result
         let best_resource_name = self
             .resource_index
-            .get_name(best_resource_id)
+            .resolve(best_resource_id)
             .unwrap_or("unknown")
             .to_string();
 
@@ -1154,11 +2225,30 @@ impl CriticalPathScheduler {
             task_id: task_id.to_string(),
             start_date: current_time,
             end_date: best_completion,
-            duration_days: task.duration_days,
+            duration_days: self.duration_on(best_resource_id, task),
             resources: vec![best_resource_name],
+            ..Default::default()
         })
     }
 
+    /// Whether any changeover `task` needs on `schedule` is over by `time`.
+    fn changed_over(&self, schedule: &ResourceSchedule, task: &Task, time: NaiveDate) -> bool {
+        self.resource_config
+            .as_ref()
+            .is_none_or(|rc| rc.changeover_ready(schedule, task.category.as_deref(), time) == time)
+    }
+
+    /// Duration of a task on a resource, scaled by the resource's efficiency.
+    fn duration_on(&self, resource_id: u32, task: &Task) -> f64 {
+        match &self.resource_config {
+            Some(rc) if rc.has_efficiency() => {
+                let name = self.resource_index.resolve(resource_id).unwrap_or("");
+                rc.effective_duration(name, task)
+            }
+            _ => task.duration_days,
+        }
+    }
+
     /// Select the best resource from tied candidates (integer-only version).
     ///
     /// Strategy:
@@ -1237,7 +2327,7 @@ impl CriticalPathScheduler {
             .min_by_key(|(_, _, count)| *count)
             .unwrap();
         if verbosity >= crate::logging::VERBOSITY_DEBUG {
-            let best_name = self.resource_index.get_name(best.0).unwrap_or("?");
+            let best_name = self.resource_index.resolve(best.0).unwrap_or("?");
             eprintln!(
                 "    Fallback: picking {} with {} blocking tasks",
                 best_name, best.2
@@ -1266,7 +2356,7 @@ impl CriticalPathScheduler {
         let horizon = self.calculate_resource_choice_horizon(candidates, current_time, ctx);
 
         if verbosity >= crate::logging::VERBOSITY_DEBUG {
-            let task_name = ctx.index.get_name(task_int).unwrap_or("?");
+            let task_name = ctx.index.resolve(task_int).unwrap_or("?");
             eprintln!(
                 "    Resource choice rollout for {}: {} candidates, horizon={}",
                 task_name,
@@ -1280,8 +2370,8 @@ impl CriticalPathScheduler {
 
         for (resource_id, completion, _blocking_count) in candidates {
             if verbosity >= crate::logging::VERBOSITY_DEBUG {
-                let task_name = ctx.index.get_name(task_int).unwrap_or("?");
-                let res_name = self.resource_index.get_name(*resource_id).unwrap_or("?");
+                let task_name = ctx.index.resolve(task_int).unwrap_or("?");
+                let res_name = self.resource_index.resolve(*resource_id).unwrap_or("?");
                 eprintln!(
                     "    --- Rollout scenario: {} -> {} (completes {}) ---",
                     task_name, res_name, completion
@@ -1321,10 +2411,13 @@ impl CriticalPathScheduler {
                         current_time,
                     )
                 });
-            let score = self.score_state(&final_state, ctx, horizon);
+            // Dates past the supported range score worst
+            let score = self
+                .score_state(&final_state, ctx, horizon)
+                .unwrap_or(f64::INFINITY);
 
             if verbosity >= crate::logging::VERBOSITY_DEBUG {
-                let res_name = self.resource_index.get_name(*resource_id).unwrap_or("?");
+                let res_name = self.resource_index.resolve(*resource_id).unwrap_or("?");
                 eprintln!("      {}: score={:.2}", res_name, score);
             }
 
@@ -1335,7 +2428,7 @@ impl CriticalPathScheduler {
         }
 
         if verbosity >= crate::logging::VERBOSITY_DEBUG {
-            let best_name = self.resource_index.get_name(best_id).unwrap_or("?");
+            let best_name = self.resource_index.resolve(best_id).unwrap_or("?");
             eprintln!(
                 "    Best resource: {} with score={:.2}",
                 best_name, best_score
@@ -1361,7 +2454,7 @@ impl CriticalPathScheduler {
                 // Estimate when this task becomes eligible
                 if let Some(start_after) = ctx.start_afters[task_int as usize] {
                     if start_after > current_time
-                        && start_after < horizon + chrono::Duration::days(30)
+                        && offset_date(horizon, 30.0).map_or(true, |limit| start_after < limit)
                     {
                         horizon = horizon.max(start_after);
                     }
@@ -1370,8 +2463,7 @@ impl CriticalPathScheduler {
         }
 
         // Cap at reasonable horizon
-        let max_horizon = current_time + chrono::Duration::days(90);
-        horizon.min(max_horizon)
+        offset_date(current_time, 90.0).map_or(horizon, |max_horizon| horizon.min(max_horizon))
     }
 
     /// Count exclusive tasks for a resource that would become eligible before task_end.
@@ -1446,11 +2538,7 @@ impl CriticalPathScheduler {
             }
 
             // Get task name
-            let task_name = ctx
-                .index
-                .get_name(task_int)
-                .unwrap_or("unknown")
-                .to_string();
+            let task_name = ctx.index.resolve(task_int).unwrap_or("unknown").to_string();
 
             // Check if this task would become eligible before task_end
             if let Some(eligible_date) = self.calculate_eligible_date(
@@ -1494,9 +2582,8 @@ impl CriticalPathScheduler {
             let (_, dep_end_offset) = scheduled_vec[dep_idx];
             if dep_end_offset < f64::MAX {
                 // Dependency is scheduled - task eligible after it completes + lag
-                let dep_end = initial_time + chrono::Duration::days(dep_end_offset as i64);
-                let lag_days = lag.ceil() as i64;
-                let dep_eligible = dep_end + chrono::Duration::days(1 + lag_days);
+                let dep_eligible =
+                    ready_date(ctx, task_int, dep_int, initial_time, dep_end_offset, lag).ok()?;
                 if dep_eligible > eligible {
                     eligible = dep_eligible;
                 }
@@ -1513,7 +2600,7 @@ impl CriticalPathScheduler {
             }
         }
 
-        Some(eligible)
+        Some(first_unblocked(&ctx.blocked_periods[idx], eligible))
     }
 
     /// Try to schedule with explicit resources, optionally respecting reservations.
@@ -1533,10 +2620,16 @@ impl CriticalPathScheduler {
         // Check all resources are available NOW and not reserved for other tasks
         // (availability already checked via task_has_available_resource, but verify reservations)
         for (resource_name, _) in &task.resources {
-            let resource_id = self.resource_index.get_id(resource_name)?;
+            let resource_id = self.resource_index.get(resource_name)?;
 
             // Double-check availability via bitmask
-            if !available_mask.is_set(resource_id) {
+            if !available_mask.is_set(resource_id)
+                || !self.changed_over(
+                    &resource_schedules[resource_id as usize],
+                    task,
+                    current_time,
+                )
+            {
                 return None;
             }
 
@@ -1551,10 +2644,14 @@ impl CriticalPathScheduler {
         // Calculate completion time
         let mut max_completion = current_time;
         for (resource_name, _) in &task.resources {
-            if let Some(resource_id) = self.resource_index.get_id(resource_name) {
+            if let Some(resource_id) = self.resource_index.get(resource_name) {
                 let schedule = &mut resource_schedules[resource_id as usize];
-                let completion =
-                    schedule.calculate_completion_time(current_time, task.duration_days);
+                let (completion, _) = schedule.completion_point_avoiding(
+                    current_time,
+                    0.0,
+                    task.duration_days,
+                    &task.blocked_periods,
+                );
                 if completion > max_completion {
                     max_completion = completion;
                 }
@@ -1563,7 +2660,7 @@ impl CriticalPathScheduler {
 
         // Update resource schedules
         for (resource_name, _) in &task.resources {
-            if let Some(resource_id) = self.resource_index.get_id(resource_name) {
+            if let Some(resource_id) = self.resource_index.get(resource_name) {
                 resource_schedules[resource_id as usize]
                     .add_busy_period(current_time, max_completion);
             }
@@ -1577,25 +2674,36 @@ impl CriticalPathScheduler {
             end_date: max_completion,
             duration_days: task.duration_days,
             resources,
+            ..Default::default()
         })
     }
 
     /// Check if we should skip scheduling this task due to rollout analysis.
     ///
     /// Uses Vec-based state for efficient simulation.
-    /// Returns Some((reason, reservation)) if we should skip, None if we should proceed.
+    /// Returns the decision record when a simulation was run, with a reservation
+    /// if the task should be skipped; None if no competing target was found.
+    ///
+    /// Outcomes are memoized in `memo` by task, competitors, and contested resource state.
+    ///
+    /// In beam search mode the skip side is the best of: holding the resource for
+    /// each of the top `rollout_beam_k` competitors, or starting one of the first
+    /// `rollout_beam_width` lower-scored `candidates` on it instead.
     #[allow(clippy::too_many_arguments)]
     fn check_rollout_skip(
         &self,
         task_int: TaskId,
         task_id: &str,
         current_score: f64,
+        candidates: &[(TaskId, f64)],
         all_targets: &[TargetInfo],
         state: &CriticalPathSchedulerState,
         ctx: &TaskData,
         available_mask: ResourceMask,
-    ) -> Option<(String, ResourceReservation)> {
+        memo: &mut RolloutMemo<RolloutOutcome>,
+    ) -> Option<RolloutOutcome> {
         use super::rollout::find_competing_targets;
+        let _span = trace_span!(DEBUG, "rollout", task_id);
 
         let task = self.tasks.get(task_id)?;
 
@@ -1609,7 +2717,7 @@ impl CriticalPathScheduler {
 
         // Estimate completion time for this task
         let current_time = state.current_time;
-        let completion = current_time + chrono::Duration::days(task.duration_days.ceil() as i64);
+        let completion = offset_date(current_time, task.duration_days).ok()?;
 
         // Find competing targets with higher-scored tasks that need this resource
         let competing = find_competing_targets(
@@ -1638,75 +2746,311 @@ impl CriticalPathScheduler {
 
         // Cap horizon if configured
         let horizon = if let Some(max_days) = self.config.rollout_max_horizon_days {
-            let max_horizon = current_time + chrono::Duration::days(max_days as i64);
-            horizon.min(max_horizon)
+            offset_date(current_time, f64::from(max_days))
+                .map_or(horizon, |max_horizon| horizon.min(max_horizon))
         } else {
             horizon
         };
 
+        let resource_id = self.resource_index.get(&resource);
+        let key = RolloutKey {
+            task_int,
+            competing: fingerprint(&(
+                competing
+                    .iter()
+                    .map(|c| {
+                        (
+                            &c.target_id,
+                            c.critical_task_int,
+                            c.eligible_date,
+                            c.estimated_completion,
+                        )
+                    })
+                    .collect::<Vec<_>>(),
+                (self.config.rollout_beam_width > 0)
+                    .then(|| candidates.iter().map(|(id, _)| *id).collect::<Vec<_>>()),
+            )),
+            resources: fingerprint(&(
+                current_time,
+                &resource,
+                resource_id.map(|id| {
+                    state.resource_schedules[id as usize]
+                        .busy_periods()
+                        .collect::<Vec<_>>()
+                }),
+                resource_id
+                    .and_then(|id| state.reservations.get(&id))
+                    .map(|r| r.task_int),
+            )),
+        };
+        if let Some(outcome) = memo.get(&key) {
+            return Some(outcome);
+        }
+
+        let outcome = self.simulate_rollout(
+            task_int,
+            task_id,
+            current_score,
+            candidates,
+            &competing,
+            resource,
+            completion,
+            horizon,
+            state,
+            ctx,
+            available_mask,
+        )?;
+        memo.insert(key, outcome.clone());
+        Some(outcome)
+    }
+
+    /// Simulate scheduling `task_int` now against the skip scenarios and decide.
+    #[allow(clippy::too_many_arguments)]
+    fn simulate_rollout(
+        &self,
+        task_int: TaskId,
+        task_id: &str,
+        current_score: f64,
+        candidates: &[(TaskId, f64)],
+        competing: &[CompetingTarget],
+        resource: String,
+        completion: NaiveDate,
+        horizon: NaiveDate,
+        state: &CriticalPathSchedulerState,
+        ctx: &TaskData,
+        available_mask: ResourceMask,
+    ) -> Option<RolloutOutcome> {
+        let current_time = state.current_time;
+        let resource_id = self.resource_index.get(&resource);
+
         // Scenario A: Schedule this task now
-        let mut state_a = state.clone_for_rollout();
-        let start_offset = state_a.date_to_offset(current_time);
-        let end_offset = state_a.date_to_offset(completion);
-        state_a.scheduled_vec[task_int as usize] = (start_offset, end_offset);
-        state_a.unscheduled_vec[task_int as usize] = false;
-        if let Some(resource_id) = self.resource_index.get_id(&resource) {
-            state_a.resource_schedules[resource_id as usize]
+        let state_a = Self::state_with_placement(state, task_int, resource_id, completion);
+        let (score_a, tasks_a) = self.simulate(state_a, ctx, horizon, None);
+
+        // Skip scenarios: (score, competitor) and (score, alternate task, its score),
+        // each with its simulated placements
+        let mut skips: Vec<(f64, &CompetingTarget, Vec<ScheduledTask>)> = Vec::new();
+        let mut alternates: Vec<(f64, TaskId, f64, Vec<ScheduledTask>)> = Vec::new();
+        if !self.config.beam_search_enabled() {
+            // Scenario B: Skip this task (leave resource idle)
+            let (score_b, tasks_b) =
+                self.simulate(state.clone_for_rollout(), ctx, horizon, Some(task_int));
+            skips.push((score_b, &competing[0], tasks_b));
+        } else {
+            // Skip while holding the resource for each of the top-K competitors
+            for competitor in competing.iter().take(self.config.rollout_beam_k.max(1)) {
+                let mut state_b = state.clone_for_rollout();
+                if let Some(res_id) = resource_id {
+                    state_b.reservations.insert(
+                        res_id,
+                        self.reservation_for(&resource, competitor, current_time),
+                    );
+                }
+                let (score, tasks) = self.simulate(state_b, ctx, horizon, Some(task_int));
+                skips.push((score, competitor, tasks));
+            }
+            // Skip while starting a lower-scored eligible task on the resource instead
+            for &(alt_int, alt_score) in candidates
+                .iter()
+                .filter(|(alt_int, _)| {
+                    ctx.index
+                        .resolve(*alt_int)
+                        .and_then(|id| self.tasks.get(id))
+                        .is_some_and(|alt| {
+                            alt.duration_days > 0.0
+                                && self.get_task_resource(alt, available_mask).as_ref()
+                                    == Some(&resource)
+                        })
+                })
+                .take(self.config.rollout_beam_width)
+            {
+                let Ok(alt_completion) = offset_date(current_time, ctx.durations[alt_int as usize])
+                else {
+                    continue;
+                };
+                let state_c =
+                    Self::state_with_placement(state, alt_int, resource_id, alt_completion);
+                let (score, tasks) = self.simulate(state_c, ctx, horizon, Some(task_int));
+                alternates.push((score, alt_int, alt_score, tasks));
+            }
+        }
+
+        let best_skip = skips
+            .iter()
+            .min_by(|a, b| a.0.partial_cmp(&b.0).unwrap_or(std::cmp::Ordering::Equal));
+        let best_alternate = alternates
+            .iter()
+            .min_by(|a, b| a.0.partial_cmp(&b.0).unwrap_or(std::cmp::Ordering::Equal));
+        let (score_b, competitor, tasks_b) =
+            best_skip.map(|(score, c, tasks)| (*score, *c, tasks))?;
+        let mut skip_tasks = tasks_b;
+
+        // Compare: lower score is better
+        let mut decision = CriticalPathRolloutDecision {
+            decision_date: current_time,
+            task_id: task_id.to_string(),
+            task_score: current_score,
+            competing_target_id: competitor.target_id.clone(),
+            competing_target_score: competitor.target_score,
+            competing_task_id: competitor.critical_task_id.clone(),
+            competing_eligible_date: competitor.eligible_date,
+            schedule_score: score_a,
+            skip_score: score_b,
+            decision: "schedule".to_string(),
+            reserved_resource: None,
+        };
+        let mut reservation = None;
+
+        match best_alternate {
+            Some(&(score_c, alt_int, alt_score, ref tasks_c))
+                if score_c < score_b && score_c < score_a =>
+            {
+                skip_tasks = tasks_c;
+                let alt_id = ctx.index.resolve(alt_int).unwrap_or_default().to_string();
+                decision.competing_target_id = alt_id.clone();
+                decision.competing_target_score = alt_score;
+                decision.competing_task_id = alt_id.clone();
+                decision.competing_eligible_date = current_time;
+                decision.skip_score = score_c;
+                reservation = Some(ResourceReservation {
+                    resource: resource.clone(),
+                    target_id: alt_id.clone(),
+                    task_id: alt_id,
+                    task_int: alt_int,
+                    target_score: alt_score,
+                    reserved_from: current_time,
+                    release_on: self.reservation_release_date(current_time),
+                });
+            }
+            _ if score_b < score_a => {
+                reservation = Some(self.reservation_for(&resource, competitor, current_time));
+            }
+            _ => {}
+        }
+        if reservation.is_some() {
+            decision.decision = "skip".to_string();
+            decision.reserved_resource = Some(resource.clone());
+        }
+
+        let trace = self.config.trace_rollouts.then(|| {
+            let simulated =
+                |tasks: &[ScheduledTask]| tasks[state.result.len().min(tasks.len())..].to_vec();
+            let mut schedule_tasks = vec![ScheduledTask {
+                task_id: task_id.to_string(),
+                start_date: current_time,
+                end_date: completion,
+                duration_days: ctx.durations[task_int as usize],
+                resources: vec![resource],
+                ..Default::default()
+            }];
+            schedule_tasks.extend(simulated(&tasks_a));
+            RolloutTrace::new(
+                current_time,
+                task_id.to_string(),
+                decision.decision.clone(),
+                decision.schedule_score,
+                decision.skip_score,
+                schedule_tasks,
+                simulated(skip_tasks),
+                state.held_reservations(),
+            )
+        });
+        Some((decision, reservation, trace))
+    }
+
+    fn reservation_for(
+        &self,
+        resource: &str,
+        competitor: &CompetingTarget,
+        reserved_from: NaiveDate,
+    ) -> ResourceReservation {
+        ResourceReservation {
+            resource: resource.to_string(),
+            target_id: competitor.target_id.clone(),
+            task_id: competitor.critical_task_id.clone(),
+            task_int: competitor.critical_task_int,
+            target_score: competitor.target_score,
+            reserved_from,
+            release_on: self.reservation_release_date(reserved_from),
+        }
+    }
+
+    /// First date a reservation made on `reserved_from` no longer holds its resource.
+    fn reservation_release_date(&self, reserved_from: NaiveDate) -> NaiveDate {
+        reserved_from
+            .checked_add_days(Days::new(
+                u64::from(self.config.reservation_max_idle_days) + 1,
+            ))
+            .unwrap_or(NaiveDate::MAX)
+    }
+
+    /// Clone `state` with `task_int` placed from the current time until `completion`.
+    fn state_with_placement(
+        state: &CriticalPathSchedulerState,
+        task_int: TaskId,
+        resource_id: Option<u32>,
+        completion: NaiveDate,
+    ) -> CriticalPathSchedulerState {
+        let mut placed = state.clone_for_rollout();
+        let current_time = state.current_time;
+        let start_offset = placed.date_to_offset(current_time);
+        let end_offset = placed.date_to_offset(completion);
+        placed.scheduled_vec[task_int as usize] = (start_offset, end_offset);
+        placed.unscheduled_vec[task_int as usize] = false;
+        if let Some(resource_id) = resource_id {
+            placed.resource_schedules[resource_id as usize]
                 .add_busy_period(current_time, completion);
         }
+        placed
+    }
 
+    /// Simulate forward from `state` to `horizon` without rollout; return the
+    /// score and every placement of the final state.
+    fn simulate(
+        &self,
+        state: CriticalPathSchedulerState,
+        ctx: &TaskData,
+        horizon: NaiveDate,
+        skip_task_int: Option<TaskId>,
+    ) -> (f64, Vec<ScheduledTask>) {
+        let initial_time = state.initial_time;
+        let current_time = state.current_time;
         // Run the scheduler (without rollout to prevent infinite recursion)
-        let final_state_a = self
-            .schedule_from_state_internal(state_a, ctx, Some(horizon), false, None)
+        let final_state = self
+            .schedule_from_state_internal(state, ctx, Some(horizon), false, skip_task_int)
             .unwrap_or_else(|_| {
                 CriticalPathSchedulerState::new(
                     vec![(f64::MAX, f64::MAX); ctx.len()],
                     vec![false; ctx.len()],
-                    state.initial_time,
+                    initial_time,
                     Vec::new(),
                     current_time,
                 )
             });
-        let score_a = self.score_state(&final_state_a, ctx, horizon);
+        // Dates past the supported range score worst
+        let score = self
+            .score_state(&final_state, ctx, horizon)
+            .unwrap_or(f64::INFINITY);
+        (score, final_state.result)
+    }
 
-        // Scenario B: Skip this task (leave resource idle)
-        let final_state_b = self
-            .schedule_from_state_internal(
-                state.clone_for_rollout(),
-                ctx,
-                Some(horizon),
-                false,
-                Some(task_int),
-            )
-            .unwrap_or_else(|_| {
-                CriticalPathSchedulerState::new(
-                    vec![(f64::MAX, f64::MAX); ctx.len()],
-                    vec![false; ctx.len()],
-                    state.initial_time,
-                    Vec::new(),
-                    current_time,
-                )
-            });
-        let score_b = self.score_state(&final_state_b, ctx, horizon);
-
-        // Compare: lower score is better
-        if score_b < score_a {
-            let best_competing = &competing[0];
-            let reason = format!(
-                "better to wait for {} (target score {:.2} vs {:.2})",
-                best_competing.critical_task_id, best_competing.target_score, current_score
-            );
-            let reservation = ResourceReservation {
-                resource: resource.clone(),
-                target_id: best_competing.target_id.clone(),
-                task_id: best_competing.critical_task_id.clone(),
-                task_int: best_competing.critical_task_int,
-                target_score: best_competing.target_score,
-                reserved_from: current_time,
-            };
-            Some((reason, reservation))
-        } else {
-            None
+    /// Whether a scheduling run at `fidelity` places this task.
+    fn simulates_task(
+        task_int: TaskId,
+        fidelity: RolloutFidelity,
+        ctx: &TaskData,
+        critical_tasks: &FxHashSet<TaskId>,
+    ) -> bool {
+        let idx = task_int as usize;
+        if ctx.durations[idx] == 0.0 {
+            return true;
+        }
+        match fidelity {
+            RolloutFidelity::Full => true,
+            RolloutFidelity::NoAutoAssignment => {
+                !ctx.explicit_resources[idx].is_empty() || ctx.resource_specs[idx].is_none()
+            }
+            RolloutFidelity::CriticalTasksOnly => critical_tasks.contains(&task_int),
         }
     }
 
@@ -1718,11 +3062,10 @@ impl CriticalPathScheduler {
         }
 
         // Check resource spec (auto-assignment)
-        if let Some(spec) = &task.resource_spec {
+        if task.resource_spec.is_some() {
             if let Some(config) = &self.resource_config {
-                let candidates = config.expand_resource_spec(spec);
-                for resource_name in candidates {
-                    if let Some(id) = self.resource_index.get_id(&resource_name) {
+                for resource_name in config.candidates_for(task) {
+                    if let Some(id) = self.resource_index.get(&resource_name) {
                         if available_mask.is_set(id) {
                             return Some(resource_name);
                         }
@@ -1738,7 +3081,8 @@ impl CriticalPathScheduler {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::models::Dependency;
+    use crate::config::{DurationRounding, UnknownDependencyPolicy};
+    use crate::models::{Dependency, LagUnit};
 
     fn d(year: i32, month: u32, day: u32) -> NaiveDate {
         NaiveDate::from_ymd_opt(year, month, day).unwrap()
@@ -1763,14 +3107,11 @@ mod tests {
                 .map(|(dep_id, lag)| Dependency {
                     entity_id: dep_id.to_string(),
                     lag_days: lag,
+                    ..Default::default()
                 })
                 .collect(),
-            start_after: None,
-            end_before: None,
-            start_on: None,
-            end_on: None,
-            resource_spec: None,
             priority,
+            ..Default::default()
         }
     }
 
@@ -1779,9 +3120,145 @@ mod tests {
             resource_order: resources.into_iter().map(|s| s.to_string()).collect(),
             dns_periods: std::collections::HashMap::new(),
             spec_expansion: std::collections::HashMap::new(),
+            ..Default::default()
         }
     }
 
+    /// Scheduler for `tasks` from 2025-01-01 over `resources`, with default priority 50.
+    fn scheduler_for(
+        tasks: Vec<Task>,
+        config: CriticalPathConfig,
+        resources: Vec<&str>,
+        global_dns_periods: Vec<(NaiveDate, NaiveDate)>,
+    ) -> CriticalPathScheduler {
+        CriticalPathScheduler::new(
+            tasks,
+            d(2025, 1, 1),
+            FxHashSet::default(),
+            50,
+            config,
+            Some(simple_resource_config(resources)),
+            global_dns_periods,
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_replan_matches_fresh_scheduler() {
+        let tasks = vec![
+            Task {
+                start_on: Some(d(2025, 1, 1)),
+                ..make_task("kickoff", 1.0, vec![], Some(50), vec!["r1"])
+            },
+            make_task("a", 2.0, vec![("kickoff", 0.0)], Some(50), vec!["r1"]),
+            make_task("b", 3.0, vec![("a", 0.0)], Some(50), vec!["r1"]),
+            make_task("c", 1.0, vec![], Some(30), vec!["r1"]),
+        ];
+        let fresh = |tasks: Vec<Task>, date: NaiveDate, completed: &[&str]| {
+            CriticalPathScheduler::new(
+                tasks,
+                date,
+                completed.iter().map(|s| s.to_string()).collect(),
+                50,
+                CriticalPathConfig::default(),
+                None,
+                vec![],
+            )
+            .unwrap()
+            .schedule()
+            .unwrap()
+            .scheduled_tasks
+        };
+        let dates = |mut placed: Vec<ScheduledTask>| {
+            placed.sort_by(|x, y| x.task_id.cmp(&y.task_id));
+            placed
+                .into_iter()
+                .map(|t| (t.task_id, t.start_date, t.end_date))
+                .collect::<Vec<_>>()
+        };
+
+        let mut scheduler = CriticalPathScheduler::new(
+            tasks.clone(),
+            d(2025, 1, 1),
+            FxHashSet::default(),
+            50,
+            CriticalPathConfig::default(),
+            None,
+            vec![],
+        )
+        .unwrap();
+        let first = dates(scheduler.schedule().unwrap().scheduled_tasks);
+        // A second run keeps the fixed task
+        assert_eq!(dates(scheduler.schedule().unwrap().scheduled_tasks), first);
+
+        scheduler.set_current_date(d(2025, 1, 6)).unwrap();
+        scheduler
+            .update_tasks(vec![
+                TaskChange::Complete("a".to_string()),
+                TaskChange::Remove("c".to_string()),
+                TaskChange::Upsert(Box::new(make_task(
+                    "d",
+                    2.0,
+                    vec![("b", 0.0)],
+                    None,
+                    vec!["r1"],
+                ))),
+            ])
+            .unwrap();
+        let mut replanned = tasks;
+        replanned.retain(|t| t.id != "c");
+        replanned.push(make_task("d", 2.0, vec![("b", 0.0)], None, vec!["r1"]));
+        assert_eq!(
+            dates(scheduler.schedule().unwrap().scheduled_tasks),
+            dates(fresh(replanned, d(2025, 1, 6), &["a"]))
+        );
+    }
+
+    #[test]
+    fn test_external_completions_apply_to_updated_tasks() {
+        let mut scheduler = CriticalPathScheduler::new(
+            vec![make_task("a", 1.0, vec![("vendor", 0.0)], None, vec!["r1"])],
+            d(2025, 1, 1),
+            FxHashSet::default(),
+            50,
+            CriticalPathConfig::default(),
+            None,
+            vec![],
+        )
+        .unwrap();
+        scheduler
+            .set_external_completions(
+                [("vendor".to_string(), d(2025, 1, 9))]
+                    .into_iter()
+                    .collect(),
+            )
+            .unwrap();
+        scheduler
+            .update_tasks(vec![TaskChange::Upsert(Box::new(make_task(
+                "b",
+                1.0,
+                vec![("vendor", 0.0)],
+                None,
+                vec!["r2"],
+            )))])
+            .unwrap();
+        let mut starts: Vec<(String, NaiveDate)> = scheduler
+            .schedule()
+            .unwrap()
+            .scheduled_tasks
+            .into_iter()
+            .map(|t| (t.task_id, t.start_date))
+            .collect();
+        starts.sort();
+        assert_eq!(
+            starts,
+            vec![
+                ("a".to_string(), d(2025, 1, 10)),
+                ("b".to_string(), d(2025, 1, 10))
+            ]
+        );
+    }
+
     #[test]
     fn test_simple_chain() {
         let tasks = vec![
@@ -1797,7 +3274,8 @@ mod tests {
             CriticalPathConfig::default(),
             Some(simple_resource_config(vec!["r1"])),
             vec![],
-        );
+        )
+        .unwrap();
 
         let result = scheduler.schedule().unwrap();
         assert_eq!(result.scheduled_tasks.len(), 2);
@@ -1815,6 +3293,300 @@ mod tests {
 
         assert_eq!(task_a.start_date, d(2025, 1, 1));
         assert!(task_b.start_date > task_a.end_date);
+
+        let metadata = result.metadata.unwrap();
+        assert_eq!(metadata.algorithm, "critical_path");
+        assert_eq!(metadata.strategy, None);
+        assert!(metadata.iterations > 0);
+        assert_eq!(metadata.metrics.makespan_days, 6.0);
+        assert_eq!(metadata.timings, None);
+    }
+
+    #[test]
+    fn test_degenerate_inputs_warn_with_empty_schedule() {
+        let run = |tasks: Vec<Task>, completed: &[&str]| {
+            let completed = completed.iter().map(|s| s.to_string()).collect();
+            CriticalPathScheduler::new(
+                tasks,
+                d(2025, 1, 1),
+                completed,
+                50,
+                CriticalPathConfig::default(),
+                Some(simple_resource_config(vec!["r1"])),
+                vec![],
+            )
+            .unwrap()
+            .schedule()
+            .unwrap()
+        };
+        let task = make_task("a", 2.0, vec![], Some(50), vec!["r1"]);
+
+        for (result, code) in [
+            (run(vec![], &[]), "no_tasks"),
+            (run(vec![task], &["a"]), "all_completed"),
+        ] {
+            assert!(result.scheduled_tasks.is_empty());
+            assert!(result.unschedulable.is_empty());
+            let codes: Vec<&str> = result.warnings.iter().map(|w| w.code.as_str()).collect();
+            assert_eq!(codes, vec![code]);
+        }
+    }
+
+    #[test]
+    fn test_nearest_rounding_shortens_fractional_tasks() {
+        let run = |duration_rounding: DurationRounding| {
+            let config = CriticalPathConfig {
+                duration_rounding,
+                ..Default::default()
+            };
+            let result = scheduler_for(
+                vec![make_task("a", 1.4, vec![], Some(50), vec!["r1"])],
+                config,
+                vec!["r1"],
+                vec![],
+            )
+            .schedule()
+            .unwrap();
+            let a = &result.scheduled_tasks[0];
+            (a.end_date, a.duration_days, a.effective_duration_days)
+        };
+
+        assert_eq!(run(DurationRounding::Up), (d(2025, 1, 3), 1.4, 2.0));
+        assert_eq!(run(DurationRounding::Nearest), (d(2025, 1, 2), 1.4, 1.0));
+    }
+
+    #[test]
+    fn test_working_day_lag_skips_global_dns() {
+        let run = |lag_unit: LagUnit| {
+            let mut b = make_task("b", 1.0, vec![("a", 2.0)], Some(50), vec!["r2"]);
+            b.dependencies[0].lag_unit = lag_unit;
+            let result = scheduler_for(
+                vec![make_task("a", 1.0, vec![], Some(50), vec!["r1"]), b],
+                CriticalPathConfig::default(),
+                vec!["r1", "r2"],
+                vec![(d(2025, 1, 4), d(2025, 1, 5))],
+            )
+            .schedule()
+            .unwrap();
+            result
+                .scheduled_tasks
+                .iter()
+                .find(|t| t.task_id == "b")
+                .unwrap()
+                .start_date
+        };
+
+        assert_eq!(run(LagUnit::Calendar), d(2025, 1, 6));
+        assert_eq!(run(LagUnit::Working), d(2025, 1, 7));
+    }
+
+    #[test]
+    fn test_extreme_dates_schedule_or_fail_with_overflow() {
+        let run = |lag_days: f64| {
+            CriticalPathScheduler::new(
+                vec![
+                    make_task("a", 10.0, vec![], Some(50), vec!["r1"]),
+                    make_task("b", 1.0, vec![("a", lag_days)], Some(50), vec!["r1"]),
+                ],
+                d(3000, 1, 1),
+                FxHashSet::default(),
+                50,
+                CriticalPathConfig::default(),
+                Some(simple_resource_config(vec!["r1"])),
+                vec![],
+            )
+            .and_then(|mut scheduler| scheduler.schedule())
+        };
+
+        let result = run(100_000.0).unwrap();
+        let b = result.scheduled_tasks.iter().find(|t| t.task_id == "b");
+        assert_eq!(b.unwrap().start_date, d(3273, 10, 27));
+        assert!(matches!(
+            run(3_000_000.0),
+            Err(CriticalPathSchedulerError::DateOverflow(_))
+        ));
+    }
+
+    #[test]
+    fn test_deadline_near_min_date_fails_with_overflow() {
+        let near_min = NaiveDate::MIN.checked_add_days(Days::new(5)).unwrap();
+        for duration in [f64::INFINITY, 1e18, 10.0] {
+            let task = Task {
+                end_before: Some(near_min),
+                ..make_task("a", duration, vec![], Some(50), vec!["r1"])
+            };
+            let result = CriticalPathScheduler::new(
+                vec![task],
+                d(2025, 1, 1),
+                FxHashSet::default(),
+                50,
+                CriticalPathConfig::default(),
+                Some(simple_resource_config(vec!["r1"])),
+                vec![],
+            )
+            .and_then(|mut scheduler| scheduler.schedule());
+            assert!(matches!(
+                result,
+                Err(CriticalPathSchedulerError::DateOverflow(_))
+            ));
+        }
+    }
+
+    #[test]
+    fn test_set_current_date_revalidates_date_range() {
+        let mut scheduler = scheduler_for(
+            vec![make_task("a", 1_000_000.0, vec![], Some(50), vec!["r1"])],
+            CriticalPathConfig::default(),
+            vec!["r1"],
+            vec![],
+        );
+        assert!(matches!(
+            scheduler.set_current_date(d(9000, 1, 1)),
+            Err(CriticalPathSchedulerError::DateOverflow(_))
+        ));
+        assert!(scheduler.set_current_date(d(2025, 1, 2)).is_ok());
+    }
+
+    #[test]
+    fn test_profile_records_phase_timings() {
+        let tasks = vec![
+            make_task("a", 2.0, vec![], Some(50), vec!["r1"]),
+            make_task("b", 3.0, vec![("a", 0.0)], Some(50), vec!["r1"]),
+        ];
+        let config = CriticalPathConfig {
+            profile: true,
+            ..Default::default()
+        };
+        let mut scheduler = scheduler_for(tasks, config, vec!["r1"], vec![]);
+
+        let metadata = scheduler.schedule().unwrap().metadata.unwrap();
+        let timings = metadata.timings.unwrap();
+        assert!(timings.main_loop_ms > 0.0);
+        assert!(timings.cache_build_ms > 0.0);
+        assert!(timings.main_loop_ms >= timings.cache_build_ms);
+        assert_eq!(timings.backward_pass_ms, 0.0);
+    }
+
+    #[test]
+    fn test_schedule_until_resumes_to_same_schedule() {
+        let tasks = vec![
+            make_task("a", 2.0, vec![], Some(50), vec!["r1"]),
+            make_task("b", 3.0, vec![("a", 0.0)], Some(50), vec!["r1"]),
+            make_task("c", 4.0, vec![], Some(30), vec!["r2"]),
+        ];
+        let new_scheduler = || {
+            scheduler_for(
+                tasks.clone(),
+                CriticalPathConfig::default(),
+                vec!["r1", "r2"],
+                vec![],
+            )
+        };
+        let dates = |tasks: &[ScheduledTask]| {
+            let mut dates: Vec<(String, NaiveDate, NaiveDate)> = tasks
+                .iter()
+                .map(|t| (t.task_id.clone(), t.start_date, t.end_date))
+                .collect();
+            dates.sort();
+            dates
+        };
+
+        let snapshot = new_scheduler().schedule_until(d(2025, 1, 2)).unwrap();
+        assert_eq!(snapshot.scheduled_tasks.len(), 2);
+        let mut resumed = new_scheduler();
+        resumed.restore(Some(snapshot));
+        assert_eq!(
+            dates(&resumed.schedule().unwrap().scheduled_tasks),
+            dates(&new_scheduler().schedule().unwrap().scheduled_tasks)
+        );
+    }
+
+    #[test]
+    fn test_schedule_step_walks_to_same_schedule() {
+        let tasks = vec![
+            make_task("a", 2.0, vec![], Some(50), vec!["r1"]),
+            make_task("b", 3.0, vec![("a", 0.0)], Some(50), vec!["r1"]),
+            make_task("c", 4.0, vec![], Some(30), vec!["r2"]),
+        ];
+        let new_scheduler = || {
+            scheduler_for(
+                tasks.clone(),
+                CriticalPathConfig::default(),
+                vec!["r1", "r2"],
+                vec![],
+            )
+        };
+
+        let mut stepper = new_scheduler();
+        let first = stepper.schedule_step().unwrap();
+        assert_eq!(first.kind, "placed");
+        assert_eq!(first.placed.as_ref().unwrap().task_id, "a");
+        assert_eq!(first.eligible_task_ids, vec!["a", "c"]);
+        assert!(!first.ranked_target_ids.is_empty());
+
+        let mut kinds = vec![first.kind];
+        loop {
+            let step = stepper.schedule_step().unwrap();
+            if step.kind == "finished" {
+                break;
+            }
+            kinds.push(step.kind);
+        }
+        assert_eq!(kinds.iter().filter(|k| *k == "placed").count(), 3);
+        assert!(kinds.iter().any(|k| k == "advanced"));
+        let mut full = new_scheduler().schedule().unwrap().scheduled_tasks;
+        let mut stepped = stepper.schedule().unwrap().scheduled_tasks;
+        full.sort_by(|a, b| a.task_id.cmp(&b.task_id));
+        stepped.sort_by(|a, b| a.task_id.cmp(&b.task_id));
+        assert_eq!(stepped, full);
+    }
+
+    #[test]
+    fn test_preview_step_does_not_advance() {
+        let mut fixed = make_task("f", 1.0, vec![], Some(50), vec!["r2"]);
+        fixed.start_on = Some(d(2025, 1, 1));
+        let tasks = vec![
+            make_task("a", 2.0, vec![], Some(50), vec!["r1"]),
+            make_task("b", 3.0, vec![("a", 0.0)], Some(50), vec!["r1"]),
+            fixed,
+        ];
+        let mut scheduler = scheduler_for(
+            tasks,
+            CriticalPathConfig::default(),
+            vec!["r1", "r2"],
+            vec![],
+        );
+
+        let preview = scheduler.preview_step().unwrap();
+        let placed = preview.placed.unwrap();
+        assert_eq!(placed.task_id, "a");
+        assert_eq!(placed.resources, vec!["r1"]);
+        assert!(!preview.ranked_target_ids.is_empty());
+        assert_eq!(scheduler.preview_step().unwrap().placed.unwrap(), placed);
+        assert_eq!(scheduler.schedule_step().unwrap().placed.unwrap(), placed);
+        assert_eq!(scheduler.schedule().unwrap().scheduled_tasks.len(), 3);
+    }
+
+    #[test]
+    fn test_rank_orders_equal_tasks() {
+        let ranked = |id: &str, rank| Task {
+            rank: Some(rank),
+            ..make_task(id, 2.0, vec![], Some(50), vec!["r1"])
+        };
+        let mut scheduler = scheduler_for(
+            vec![ranked("a", 2), ranked("b", 1)],
+            CriticalPathConfig::default(),
+            vec!["r1"],
+            vec![],
+        );
+
+        let result = scheduler.schedule().unwrap();
+        let first = result
+            .scheduled_tasks
+            .iter()
+            .min_by_key(|t| t.start_date)
+            .unwrap();
+        assert_eq!(first.task_id, "b");
     }
 
     #[test]
@@ -1833,7 +3605,8 @@ mod tests {
             CriticalPathConfig::default(),
             Some(simple_resource_config(vec!["r1", "r2"])),
             vec![],
-        );
+        )
+        .unwrap();
 
         let result = scheduler.schedule().unwrap();
         assert_eq!(result.scheduled_tasks.len(), 2);
@@ -1861,7 +3634,8 @@ mod tests {
             CriticalPathConfig::default(),
             Some(simple_resource_config(vec!["r1"])),
             vec![],
-        );
+        )
+        .unwrap();
 
         let result = scheduler.schedule().unwrap();
 
@@ -1880,6 +3654,58 @@ mod tests {
         assert!(high.start_date < low.start_date);
     }
 
+    #[test]
+    fn test_hybrid_orders_within_target_by_strategy() {
+        // Both tasks feed the top target "release". Critical path scoring
+        // starts "long", which has no slack; ATC prefers the shorter "quick".
+        let tasks = vec![
+            make_task("long", 5.0, vec![], Some(10), vec!["r1"]),
+            make_task("quick", 1.0, vec![], Some(10), vec!["r1"]),
+            make_task(
+                "release",
+                0.0,
+                vec![("long", 0.0), ("quick", 0.0)],
+                Some(90),
+                vec![],
+            ),
+        ];
+        let first_placed = |hybrid: bool| {
+            let mut scheduler = scheduler_for(
+                tasks.clone(),
+                CriticalPathConfig::default(),
+                vec!["r1"],
+                vec![],
+            );
+            if hybrid {
+                scheduler
+                    .set_task_ordering(SchedulingConfig {
+                        strategy: "atc".to_string(),
+                        ..Default::default()
+                    })
+                    .unwrap();
+            }
+            let result = scheduler.schedule().unwrap();
+            let metadata = result.metadata.unwrap();
+            let first = result
+                .scheduled_tasks
+                .iter()
+                .find(|t| t.start_date == d(2025, 1, 1))
+                .unwrap()
+                .task_id
+                .clone();
+            (metadata.algorithm, first)
+        };
+
+        assert_eq!(
+            first_placed(false),
+            ("critical_path".to_string(), "long".to_string())
+        );
+        assert_eq!(
+            first_placed(true),
+            ("hybrid".to_string(), "quick".to_string())
+        );
+    }
+
     #[test]
     fn test_low_hanging_fruit() {
         // Low effort task should be preferred due to P/W scoring
@@ -1897,7 +3723,8 @@ mod tests {
             CriticalPathConfig::default(),
             Some(simple_resource_config(vec!["r1"])),
             vec![],
-        );
+        )
+        .unwrap();
 
         let result = scheduler.schedule().unwrap();
 
@@ -1921,14 +3748,8 @@ mod tests {
         let tasks = vec![Task {
             id: "milestone".to_string(),
             duration_days: 0.0,
-            resources: vec![],
-            dependencies: vec![],
-            start_after: None,
-            end_before: None,
-            start_on: None,
-            end_on: None,
-            resource_spec: None,
             priority: Some(50),
+            ..Default::default()
         }];
 
         let mut scheduler = CriticalPathScheduler::new(
@@ -1939,7 +3760,8 @@ mod tests {
             CriticalPathConfig::default(),
             None,
             vec![],
-        );
+        )
+        .unwrap();
 
         let result = scheduler.schedule().unwrap();
         assert_eq!(result.scheduled_tasks.len(), 1);
@@ -1949,6 +3771,226 @@ mod tests {
         assert_eq!(milestone.end_date, d(2025, 1, 1));
     }
 
+    #[test]
+    fn test_changeover_gap_between_categories() {
+        let task = |id: &str, priority: i32, category: &str| {
+            let mut task = make_task(id, 2.0, vec![], Some(priority), vec!["lab"]);
+            task.category = Some(category.to_string());
+            task
+        };
+        let mut resource_config = simple_resource_config(vec!["lab"]);
+        resource_config.changeover_days = [(
+            "wet".to_string(),
+            [("dry".to_string(), 2.0)].into_iter().collect(),
+        )]
+        .into_iter()
+        .collect();
+        let mut scheduler = CriticalPathScheduler::new(
+            vec![task("a", 90, "wet"), task("b", 20, "dry")],
+            d(2025, 1, 1),
+            FxHashSet::default(),
+            50,
+            CriticalPathConfig::default(),
+            Some(resource_config),
+            vec![],
+        )
+        .unwrap();
+
+        let result = scheduler.schedule().unwrap();
+        let task = |id: &str| {
+            result
+                .scheduled_tasks
+                .iter()
+                .find(|t| t.task_id == id)
+                .unwrap()
+                .clone()
+        };
+        assert_eq!(task("a").start_date, d(2025, 1, 1));
+        assert_eq!(task("b").start_date, d(2025, 1, 6));
+    }
+
+    #[test]
+    fn test_exclusive_groups_do_not_overlap() {
+        let mut a = make_task("a", 3.0, vec![], Some(80), vec!["r1"]);
+        let mut b = make_task("b", 2.0, vec![], Some(50), vec!["r2"]);
+        a.exclusive_groups = vec!["staging".to_string()];
+        b.exclusive_groups = vec!["staging".to_string()];
+        let mut scheduler = scheduler_for(
+            vec![a, b],
+            CriticalPathConfig::default(),
+            vec!["r1", "r2"],
+            vec![],
+        );
+
+        let result = scheduler.schedule().unwrap();
+        let task = |id: &str| {
+            result
+                .scheduled_tasks
+                .iter()
+                .find(|t| t.task_id == id)
+                .unwrap()
+                .clone()
+        };
+        let (a, b) = (task("a"), task("b"));
+        assert_eq!(a.start_date, d(2025, 1, 1));
+        assert!(b.start_date > a.end_date);
+    }
+
+    #[test]
+    fn test_pinned_and_prior_assignments() {
+        let assign = |pinned: Option<&str>, prior: Option<&str>, penalty: f64| {
+            let mut task = make_auto_assign_task("a", 4.0, vec![], Some(50), "*");
+            task.pinned_resource = pinned.map(str::to_string);
+            let resource_config = ResourceConfig {
+                resource_order: vec!["junior".to_string(), "senior".to_string()],
+                efficiency: [("senior".to_string(), 2.0)].into_iter().collect(),
+                prior_assignments: prior
+                    .map(|r| ("a".to_string(), r.to_string()))
+                    .into_iter()
+                    .collect(),
+                switch_penalty_days: penalty,
+                ..Default::default()
+            };
+            let mut scheduler = CriticalPathScheduler::new(
+                vec![task],
+                d(2025, 1, 1),
+                FxHashSet::default(),
+                50,
+                CriticalPathConfig::default(),
+                Some(resource_config),
+                vec![],
+            )
+            .unwrap();
+            scheduler.schedule().unwrap().scheduled_tasks[0].resources[0].clone()
+        };
+
+        assert_eq!(assign(None, None, 0.0), "senior");
+        assert_eq!(assign(Some("junior"), None, 0.0), "junior");
+        assert_eq!(assign(None, Some("junior"), 3.0), "junior");
+        assert_eq!(assign(None, Some("junior"), 1.0), "senior");
+    }
+
+    #[test]
+    fn test_spec_preference_penalty() {
+        let assign = |spec: &str| {
+            let resource_config = ResourceConfig {
+                resource_order: vec!["junior".to_string(), "senior".to_string()],
+                efficiency: [("senior".to_string(), 2.0)].into_iter().collect(),
+                ..Default::default()
+            };
+            let mut scheduler = CriticalPathScheduler::new(
+                vec![make_auto_assign_task("a", 4.0, vec![], Some(50), spec)],
+                d(2025, 1, 1),
+                FxHashSet::default(),
+                50,
+                CriticalPathConfig::default(),
+                Some(resource_config),
+                vec![],
+            )
+            .unwrap();
+            scheduler.schedule().unwrap().scheduled_tasks[0].resources[0].clone()
+        };
+
+        // senior saves 2 days; an exact tie keeps the unhandicapped junior
+        assert_eq!(assign("junior|senior+3"), "junior");
+        assert_eq!(assign("junior|senior+2"), "junior");
+        assert_eq!(assign("junior|senior+1.5"), "senior");
+    }
+
+    #[test]
+    fn test_blocked_periods_delay_and_split_work() {
+        let mut blocked = make_task("blocked", 4.0, vec![], Some(50), vec!["r1"]);
+        blocked.blocked_periods = vec![
+            (d(2025, 1, 1), d(2025, 1, 2)),
+            (d(2025, 1, 5), d(2025, 1, 6)),
+        ];
+        let mut scheduler = CriticalPathScheduler::new(
+            vec![
+                blocked,
+                make_task("free", 2.0, vec![], Some(10), vec!["r2"]),
+            ],
+            d(2025, 1, 1),
+            FxHashSet::default(),
+            50,
+            CriticalPathConfig::default(),
+            Some(simple_resource_config(vec!["r1", "r2"])),
+            vec![],
+        )
+        .unwrap();
+        let result = scheduler.schedule().unwrap();
+        let get = |id: &str| {
+            let st = result
+                .scheduled_tasks
+                .iter()
+                .find(|t| t.task_id == id)
+                .unwrap();
+            (st.start_date, st.end_date)
+        };
+
+        // Two days before the second block, two after it
+        assert_eq!(get("blocked"), (d(2025, 1, 3), d(2025, 1, 9)));
+        assert_eq!(get("free"), (d(2025, 1, 1), d(2025, 1, 3)));
+    }
+
+    #[test]
+    fn test_fixed_task_dependency_violation_warned() {
+        let mut build = make_task("build", 1.0, vec![("design", 0.0)], Some(50), vec!["r2"]);
+        build.start_on = Some(d(2025, 1, 2));
+        let mut scheduler = CriticalPathScheduler::new(
+            vec![
+                make_task("design", 3.0, vec![], Some(50), vec!["r1"]),
+                build,
+            ],
+            d(2025, 1, 1),
+            FxHashSet::default(),
+            50,
+            CriticalPathConfig::default(),
+            Some(simple_resource_config(vec!["r1", "r2"])),
+            vec![],
+        )
+        .unwrap();
+        let result = scheduler.schedule().unwrap();
+
+        assert_eq!(result.warnings.len(), 1);
+        let warning = &result.warnings[0];
+        assert_eq!(warning.code, "dependency_violation");
+        assert_eq!(warning.task_ids, vec!["build", "design"]);
+        assert!(warning.message.contains("(2025-01-05)"));
+    }
+
+    #[test]
+    fn test_fixed_tasks_in_global_dns_policy() {
+        let run = |allow_fixed_in_global_dns: bool| {
+            let mut milestone = make_task("m", 0.0, vec![], Some(50), vec![]);
+            milestone.end_on = Some(d(2025, 1, 4));
+            let mut fixed = make_task("f", 1.0, vec![], Some(50), vec!["r2"]);
+            fixed.start_on = Some(d(2025, 1, 4));
+            let config = CriticalPathConfig {
+                allow_fixed_in_global_dns,
+                ..Default::default()
+            };
+            let mut scheduler = scheduler_for(
+                vec![milestone, fixed],
+                config,
+                vec!["r2"],
+                vec![(d(2025, 1, 3), d(2025, 1, 5))],
+            );
+            let result = scheduler.schedule().unwrap();
+            let start = |id: &str| {
+                result
+                    .scheduled_tasks
+                    .iter()
+                    .find(|t| t.task_id == id)
+                    .unwrap()
+                    .start_date
+            };
+            (start("m"), start("f"))
+        };
+
+        assert_eq!(run(true), (d(2025, 1, 4), d(2025, 1, 4)));
+        assert_eq!(run(false), (d(2025, 1, 6), d(2025, 1, 6)));
+    }
+
     fn make_auto_assign_task(
         id: &str,
         duration: f64,
@@ -1959,20 +4001,17 @@ mod tests {
         Task {
             id: id.to_string(),
             duration_days: duration,
-            resources: vec![],
             dependencies: deps
                 .into_iter()
                 .map(|(dep_id, lag)| Dependency {
                     entity_id: dep_id.to_string(),
                     lag_days: lag,
+                    ..Default::default()
                 })
                 .collect(),
-            start_after: None,
-            end_before: None,
-            start_on: None,
-            end_on: None,
             resource_spec: Some(resource_spec.to_string()),
             priority,
+            ..Default::default()
         }
     }
 
@@ -1989,6 +4028,7 @@ mod tests {
             resource_order: vec!["alice".to_string(), "bob".to_string()],
             dns_periods: std::collections::HashMap::new(),
             spec_expansion: std::collections::HashMap::new(),
+            ..Default::default()
         };
 
         let tasks = vec![
@@ -1998,8 +4038,10 @@ mod tests {
             make_task("task_b", 3.0, vec![], Some(50), vec!["alice"]),
         ];
 
-        let mut config = CriticalPathConfig::default();
-        config.prefer_fungible_resources = true;
+        let config = CriticalPathConfig {
+            prefer_fungible_resources: true,
+            ..CriticalPathConfig::default()
+        };
 
         let mut scheduler = CriticalPathScheduler::new(
             tasks,
@@ -2009,7 +4051,8 @@ mod tests {
             config,
             Some(resource_config),
             vec![],
-        );
+        )
+        .unwrap();
 
         let result = scheduler.schedule().unwrap();
         assert_eq!(result.scheduled_tasks.len(), 2);
@@ -2034,6 +4077,76 @@ mod tests {
         assert_eq!(task_b.start_date, d(2025, 1, 1));
     }
 
+    #[test]
+    fn test_cost_breaks_assignment_ties() {
+        // alice and bob finish at the same time; bob is cheaper
+        let resource_config = ResourceConfig {
+            resource_order: vec!["alice".to_string(), "bob".to_string()],
+            cost_rates: [("alice".to_string(), 800.0), ("bob".to_string(), 500.0)]
+                .into_iter()
+                .collect(),
+            ..Default::default()
+        };
+
+        let tasks = vec![make_auto_assign_task("task_a", 2.0, vec![], Some(50), "*")];
+
+        let mut scheduler = CriticalPathScheduler::new(
+            tasks,
+            d(2025, 1, 1),
+            FxHashSet::default(),
+            50,
+            CriticalPathConfig::default(),
+            Some(resource_config),
+            vec![],
+        )
+        .unwrap();
+
+        let result = scheduler.schedule().unwrap();
+        assert_eq!(result.scheduled_tasks[0].resources, vec!["bob".to_string()]);
+        assert_eq!(
+            result
+                .algorithm_metadata
+                .get("total_cost")
+                .map(String::as_str),
+            Some("1000.00")
+        );
+    }
+
+    #[test]
+    fn test_required_skills_pick_least_overqualified() {
+        let resource_config = ResourceConfig {
+            resource_order: vec!["alice".to_string(), "bob".to_string(), "carol".to_string()],
+            skills: [
+                (
+                    "alice".to_string(),
+                    vec!["rust".to_string(), "ml".to_string()],
+                ),
+                ("bob".to_string(), vec!["rust".to_string()]),
+                ("carol".to_string(), vec!["python".to_string()]),
+            ]
+            .into_iter()
+            .collect(),
+            ..Default::default()
+        };
+
+        let mut task = make_auto_assign_task("task_a", 2.0, vec![], Some(50), "*");
+        task.required_skills = vec!["rust".to_string()];
+
+        let mut scheduler = CriticalPathScheduler::new(
+            vec![task],
+            d(2025, 1, 1),
+            FxHashSet::default(),
+            50,
+            CriticalPathConfig::default(),
+            Some(resource_config),
+            vec![],
+        )
+        .unwrap();
+
+        let result = scheduler.schedule().unwrap();
+        assert_eq!(result.scheduled_tasks[0].resources, vec!["bob".to_string()]);
+    }
+
     #[test]
     fn test_prefer_fungible_resources_disabled() {
         // Same scenario but with prefer_fungible_resources=false.
@@ -2044,6 +4157,7 @@ mod tests {
             resource_order: vec!["alice".to_string(), "bob".to_string()],
             dns_periods: std::collections::HashMap::new(),
             spec_expansion: std::collections::HashMap::new(),
+            ..Default::default()
         };
 
         // task_a is shorter (2 days) than task_b (3 days), so it has better P/W and
@@ -2054,8 +4168,10 @@ mod tests {
             make_task("task_b", 3.0, vec![], Some(50), vec!["alice"]),
         ];
 
-        let mut config = CriticalPathConfig::default();
-        config.prefer_fungible_resources = false;
+        let config = CriticalPathConfig {
+            prefer_fungible_resources: false,
+            ..CriticalPathConfig::default()
+        };
 
         let mut scheduler = CriticalPathScheduler::new(
             tasks,
@@ -2065,7 +4181,8 @@ mod tests {
             config,
             Some(resource_config),
             vec![],
-        );
+        )
+        .unwrap();
 
         let result = scheduler.schedule().unwrap();
         assert_eq!(result.scheduled_tasks.len(), 2);
@@ -2086,4 +4203,545 @@ mod tests {
         // Task B still needs alice, so it has to wait
         assert!(task_b.start_date > task_a.start_date);
     }
+
+    #[test]
+    fn test_cancelled_token_keeps_fixed_tasks() {
+        let mut fixed = make_task("fixed", 2.0, vec![], Some(50), vec!["r1"]);
+        fixed.start_on = Some(d(2025, 1, 1));
+        let tasks = vec![fixed, make_task("a", 3.0, vec![], Some(50), vec!["r1"])];
+
+        let mut scheduler = scheduler_for(tasks, CriticalPathConfig::default(), vec!["r1"], vec![]);
+        let token = CancellationToken::new();
+        token.cancel();
+        scheduler.set_cancellation_token(token);
+
+        match scheduler.schedule() {
+            Err(CriticalPathSchedulerError::Cancelled(partial)) => {
+                assert_eq!(partial.len(), 1);
+                assert_eq!(partial[0].task_id, "fixed");
+            }
+            other => panic!("expected Cancelled, got {:?}", other),
+        }
+        // The token only applied to the cancelled run
+        assert_eq!(scheduler.schedule().unwrap().scheduled_tasks.len(), 2);
+    }
+
+    #[test]
+    fn test_timeout_validated_at_construction() {
+        let timeout = |secs| CriticalPathConfig {
+            timeout_secs: Some(secs),
+            ..CriticalPathConfig::default()
+        };
+        let build = |config| {
+            CriticalPathScheduler::new(
+                vec![],
+                d(2025, 1, 1),
+                FxHashSet::default(),
+                50,
+                config,
+                None,
+                vec![],
+            )
+        };
+        assert!(matches!(
+            build(timeout(-1.0)),
+            Err(CriticalPathSchedulerError::InvalidConfig(_))
+        ));
+        let tasks = vec![make_task("a", 3.0, vec![], Some(50), vec!["r1"])];
+        let mut scheduler = scheduler_for(tasks, timeout(0.0), vec!["r1"], vec![]);
+        assert!(matches!(
+            scheduler.schedule(),
+            Err(CriticalPathSchedulerError::Cancelled(_))
+        ));
+    }
+
+    #[test]
+    fn test_external_date_milestone_gates_dependents() {
+        let mut launch = make_task("launch", 0.0, vec![("prep", 1.0)], Some(50), vec![]);
+        launch.external_date = Some(d(2025, 1, 10));
+        let tasks = vec![
+            make_task("prep", 2.0, vec![], Some(50), vec!["r1"]),
+            launch,
+            make_task(
+                "follow_up",
+                1.0,
+                vec![("launch", 0.0)],
+                Some(50),
+                vec!["r1"],
+            ),
+        ];
+
+        let mut scheduler = scheduler_for(tasks, CriticalPathConfig::default(), vec!["r1"], vec![]);
+        let result = scheduler.schedule().unwrap();
+        let find = |id: &str| {
+            result
+                .scheduled_tasks
+                .iter()
+                .find(|t| t.task_id == id)
+                .unwrap()
+        };
+
+        assert_eq!(find("launch").start_date, d(2025, 1, 10));
+        assert!(find("follow_up").start_date > d(2025, 1, 10));
+        assert_eq!(scheduler.tasks["prep"].end_before, Some(d(2025, 1, 9)));
+    }
+
+    #[test]
+    fn test_actual_start_keeps_start_and_gates_successors() {
+        let mut started = make_task("started", 10.0, vec![], Some(50), vec!["r1"]);
+        started.actual_start = Some(d(2024, 12, 30));
+        started.remaining_days = Some(2.0);
+        let tasks = vec![
+            started,
+            make_task("next", 1.0, vec![("started", 0.0)], Some(50), vec!["r1"]),
+        ];
+
+        let mut scheduler = scheduler_for(tasks, CriticalPathConfig::default(), vec!["r1"], vec![]);
+        let result = scheduler.schedule().unwrap();
+        let find = |id: &str| {
+            result
+                .scheduled_tasks
+                .iter()
+                .find(|t| t.task_id == id)
+                .unwrap()
+        };
+
+        let started = find("started");
+        assert!(started.in_progress);
+        assert_eq!(started.start_date, d(2024, 12, 30));
+        // Two days elapsed plus two remaining
+        assert_eq!(started.end_date, d(2025, 1, 3));
+        assert!(find("next").start_date > d(2025, 1, 3));
+    }
+
+    #[test]
+    fn test_in_progress_end_ignores_dns_before_current_date() {
+        let mut started = make_task("started", 10.0, vec![], Some(50), vec!["r1"]);
+        started.actual_start = Some(d(2024, 12, 20));
+        started.remaining_days = Some(2.0);
+        let mut spec_only = make_task("spec_only", 5.0, vec![], Some(50), vec![]);
+        spec_only.resource_spec = Some("*".to_string());
+        spec_only.remaining_days = Some(3.0);
+        let mut config = simple_resource_config(vec!["r1", "r2"]);
+        config
+            .dns_periods
+            .insert("r1".to_string(), vec![(d(2024, 12, 23), d(2024, 12, 31))]);
+
+        let result = CriticalPathScheduler::new(
+            vec![started, spec_only],
+            d(2025, 1, 1),
+            FxHashSet::default(),
+            50,
+            CriticalPathConfig::default(),
+            Some(config),
+            vec![],
+        )
+        .unwrap()
+        .schedule()
+        .unwrap();
+        let find = |id: &str| {
+            result
+                .scheduled_tasks
+                .iter()
+                .find(|t| t.task_id == id)
+                .unwrap()
+        };
+
+        // The remaining two days run from the current date, past the DNS period
+        let started = find("started");
+        assert_eq!(
+            (started.start_date, started.end_date),
+            (d(2024, 12, 20), d(2025, 1, 3))
+        );
+        // The spec-only task has no resource to keep, so it takes the free one
+        let spec_only = find("spec_only");
+        assert!(spec_only.in_progress);
+        assert_eq!(spec_only.resources, vec!["r2".to_string()]);
+        assert_eq!(
+            (spec_only.start_date, spec_only.duration_days),
+            (d(2025, 1, 1), 3.0)
+        );
+    }
+
+    #[test]
+    fn test_rollout_decisions_recorded() {
+        // "urgent" needs r1 once "prep" finishes; rollout holds r1 rather than start "filler"
+        let tasks = vec![
+            make_task("filler", 10.0, vec![], Some(10), vec!["r1"]),
+            make_task("prep", 1.0, vec![], Some(90), vec!["r2"]),
+            make_task("urgent", 2.0, vec![("prep", 0.0)], Some(90), vec!["r1"]),
+        ];
+
+        let mut scheduler = scheduler_for(
+            tasks,
+            CriticalPathConfig::default(),
+            vec!["r1", "r2"],
+            vec![],
+        );
+        let result = scheduler.schedule().unwrap();
+        let decisions = scheduler.get_rollout_decisions();
+
+        let decision = decisions.iter().find(|d| d.task_id == "filler").unwrap();
+        assert_eq!(decision.competing_target_id, "urgent");
+        assert_eq!(decision.decision_date, d(2025, 1, 1));
+        assert_eq!(
+            decision.decision == "skip",
+            decision.skip_score < decision.schedule_score
+        );
+        assert_eq!(
+            decision.reserved_resource.as_deref(),
+            (decision.decision == "skip").then_some("r1")
+        );
+        assert_eq!(
+            result.algorithm_metadata["rollout_decisions"],
+            decisions.len().to_string()
+        );
+    }
+
+    #[test]
+    fn test_rollout_trace_captures_both_scenarios() {
+        let tasks = vec![
+            make_task("filler", 10.0, vec![], Some(10), vec!["r1"]),
+            make_task("prep", 1.0, vec![], Some(90), vec!["r2"]),
+            make_task("urgent", 2.0, vec![("prep", 0.0)], Some(90), vec!["r1"]),
+        ];
+        let run = |trace_rollouts: bool| {
+            let config = CriticalPathConfig {
+                trace_rollouts,
+                ..Default::default()
+            };
+            let mut scheduler = scheduler_for(tasks.clone(), config, vec!["r1", "r2"], vec![]);
+            scheduler.schedule().unwrap();
+            scheduler.get_last_rollout_trace()
+        };
+
+        assert!(run(false).is_none());
+        let trace = run(true).unwrap();
+        assert_eq!(trace.task_id, "filler");
+        let starts = |tasks: &[ScheduledTask], id: &str| {
+            tasks.iter().find(|t| t.task_id == id).map(|t| t.start_date)
+        };
+        assert_eq!(starts(&trace.schedule_tasks, "filler"), Some(d(2025, 1, 1)));
+        assert_ne!(starts(&trace.skip_tasks, "filler"), Some(d(2025, 1, 1)));
+        assert_eq!(trace.divergence_date, Some(d(2025, 1, 1)));
+    }
+
+    #[test]
+    fn test_schedule_for_targets_defers_unrelated_work() {
+        let tasks = vec![
+            make_task("design", 2.0, vec![], Some(50), vec!["r1"]),
+            make_task("release", 1.0, vec![("design", 0.0)], Some(50), vec!["r1"]),
+            make_task("cleanup", 3.0, vec![], Some(90), vec!["r1"]),
+        ];
+        let mut scheduler = scheduler_for(tasks, CriticalPathConfig::default(), vec!["r1"], vec![]);
+
+        let result = scheduler
+            .schedule_for_targets(&["release".to_string()])
+            .unwrap();
+        let mut ids: Vec<&str> = result
+            .scheduled_tasks
+            .iter()
+            .map(|t| t.task_id.as_str())
+            .collect();
+        ids.sort();
+        assert_eq!(ids, vec!["design", "release"]);
+        assert_eq!(result.algorithm_metadata["deferred_tasks"], "cleanup");
+
+        let err = scheduler
+            .schedule_for_targets(&["missing".to_string()])
+            .unwrap_err();
+        assert!(
+            matches!(err, CriticalPathSchedulerError::UnknownTargets(ids) if ids == ["missing"])
+        );
+    }
+
+    #[test]
+    fn test_duplicate_task_ids_rejected() {
+        let result = CriticalPathScheduler::new(
+            vec![
+                make_task("dup", 1.0, vec![], Some(50), vec!["r1"]),
+                make_task("dup", 2.0, vec![], Some(50), vec!["r1"]),
+            ],
+            d(2025, 1, 1),
+            FxHashSet::default(),
+            50,
+            CriticalPathConfig::default(),
+            Some(simple_resource_config(vec!["r1"])),
+            vec![],
+        );
+        assert!(
+            matches!(result, Err(CriticalPathSchedulerError::DuplicateTaskIds(ids)) if ids == ["dup"])
+        );
+    }
+
+    #[test]
+    fn test_iteration_limit_is_configurable() {
+        let mut late = make_task("late", 1.0, vec![], Some(50), vec!["r1"]);
+        late.start_after = Some(d(2025, 3, 1));
+        let mut later = make_task("later", 1.0, vec![], Some(50), vec!["r1"]);
+        later.start_after = Some(d(2025, 4, 1));
+        let run = |max_iterations: Option<usize>, tasks: Vec<Task>| {
+            let config = CriticalPathConfig {
+                max_iterations,
+                ..Default::default()
+            };
+            scheduler_for(tasks, config, vec!["r1"], vec![]).schedule()
+        };
+
+        // One advance is enough for a single late task
+        assert_eq!(
+            run(Some(1), vec![late.clone()])
+                .unwrap()
+                .scheduled_tasks
+                .len(),
+            1
+        );
+        match run(Some(1), vec![late.clone(), later.clone()]) {
+            Err(CriticalPathSchedulerError::IterationLimit(info)) => {
+                assert_eq!(info.time_reached, d(2025, 3, 3));
+                assert_eq!(info.remaining, vec!["later"]);
+            }
+            other => panic!("expected IterationLimit, got {:?}", other),
+        }
+        assert_eq!(
+            run(None, vec![late, later]).unwrap().scheduled_tasks.len(),
+            2
+        );
+    }
+
+    #[test]
+    fn test_ignored_dependencies_reported_on_every_run() {
+        let config = CriticalPathConfig {
+            unknown_dependencies: UnknownDependencyPolicy::Ignore,
+            ..Default::default()
+        };
+        let mut scheduler = CriticalPathScheduler::new(
+            vec![
+                make_task("a", 1.0, vec![("ghost", 0.0)], Some(50), vec!["r1"]),
+                make_task("b", 1.0, vec![("a", 0.0)], Some(50), vec!["r1"]),
+            ],
+            d(2025, 1, 1),
+            FxHashSet::default(),
+            50,
+            config,
+            Some(simple_resource_config(vec!["r1"])),
+            vec![],
+        )
+        .unwrap();
+        for _ in 0..2 {
+            let result = scheduler.schedule().unwrap();
+            assert_eq!(
+                result.algorithm_metadata.get("ignored_dependencies"),
+                Some(&"ghost".to_string())
+            );
+            assert_eq!(result.scheduled_tasks.len(), 2);
+        }
+    }
+
+    #[test]
+    fn test_on_unschedulable_returns_partial_schedule() {
+        let tasks = vec![
+            make_task("blocked", 2.0, vec![("ghost", 0.0)], Some(50), vec!["r1"]),
+            make_task("follow", 1.0, vec![("blocked", 0.0)], Some(50), vec!["r1"]),
+            make_task("free", 3.0, vec![], Some(50), vec!["r1"]),
+        ];
+        let run = |policy: UnschedulablePolicy| {
+            let config = CriticalPathConfig {
+                on_unschedulable: policy,
+                ..Default::default()
+            };
+            scheduler_for(tasks.clone(), config, vec!["r1"], vec![])
+        };
+
+        assert!(matches!(
+            run(UnschedulablePolicy::Error).schedule(),
+            Err(CriticalPathSchedulerError::FailedToSchedule(_))
+        ));
+
+        let result = run(UnschedulablePolicy::Defer).schedule().unwrap();
+        assert_eq!(result.scheduled_tasks.len(), 1);
+        assert_eq!(result.scheduled_tasks[0].task_id, "free");
+        let details: Vec<&str> = result
+            .unschedulable
+            .iter()
+            .map(|u| u.detail.as_str())
+            .collect();
+        assert_eq!(
+            details,
+            vec![
+                "Depends on unknown task 'ghost'",
+                "Depends on unschedulable task 'blocked'"
+            ]
+        );
+
+        let mut scheduler = run(UnschedulablePolicy::BestEffort);
+        let result = scheduler.schedule().unwrap();
+        assert_eq!(result.scheduled_tasks.len(), 3);
+        assert!(result.unschedulable.is_empty());
+        assert_eq!(result.algorithm_metadata["relaxed_dependencies"], "blocked");
+        // Relaxing is per run; the tasks keep their dependencies for the next
+        let rerun = scheduler.schedule().unwrap();
+        assert_eq!(rerun.algorithm_metadata["relaxed_dependencies"], "blocked");
+    }
+
+    #[test]
+    fn test_reservations_reported_and_expire_after_idle_cap() {
+        // Rollout holds r1 for "urgent" while "prep" runs on r2
+        let tasks = vec![
+            make_task("filler", 10.0, vec![], Some(10), vec!["r1"]),
+            make_task("prep", 3.0, vec![], Some(90), vec!["r2"]),
+            make_task("urgent", 2.0, vec![("prep", 0.0)], Some(90), vec!["r1"]),
+        ];
+        let run = |max_idle_days: u32| {
+            let config = CriticalPathConfig {
+                reservation_max_idle_days: max_idle_days,
+                ..Default::default()
+            };
+            let mut scheduler = scheduler_for(tasks.clone(), config, vec!["r1", "r2"], vec![]);
+            let result = scheduler.schedule().unwrap();
+            (result, scheduler.get_reservations())
+        };
+
+        // Held until the reserved task arrives
+        let (result, reservations) = run(30);
+        let held = reservations.iter().find(|r| r.task_id == "urgent").unwrap();
+        assert_eq!(held.resource, "r1");
+        assert_eq!(held.outcome, "fulfilled");
+        assert_eq!(held.reserved_from, d(2025, 1, 1));
+        assert!(held.held_days() > 0);
+        assert_eq!(
+            result.algorithm_metadata["reservations"],
+            reservations.len().to_string()
+        );
+        assert_eq!(result.algorithm_metadata["reservations_fulfilled"], "1");
+
+        // A one-day cap releases r1 before "prep" finishes
+        let (_, reservations) = run(1);
+        let expired = reservations.iter().find(|r| r.task_id == "urgent").unwrap();
+        assert_eq!(expired.outcome, "expired");
+        assert_eq!(expired.released_on, d(2025, 1, 3));
+    }
+
+    #[test]
+    fn test_beam_search_fills_idle_resource_with_alternate() {
+        // While r1 waits for "urgent", "small" fits in the gap; "big" would block it
+        let tasks = vec![
+            make_task("big", 10.0, vec![], Some(30), vec!["r1"]),
+            make_task("small", 1.0, vec![], Some(2), vec!["r1"]),
+            make_task("prep", 1.0, vec![], Some(90), vec!["r2"]),
+            make_task("urgent", 2.0, vec![("prep", 0.0)], Some(90), vec!["r1"]),
+        ];
+        let config = CriticalPathConfig {
+            rollout_beam_k: 2,
+            rollout_beam_width: 1,
+            ..Default::default()
+        };
+
+        let mut scheduler = scheduler_for(tasks, config, vec!["r1", "r2"], vec![]);
+        let result = scheduler.schedule().unwrap();
+        let find = |id: &str| {
+            result
+                .scheduled_tasks
+                .iter()
+                .find(|t| t.task_id == id)
+                .unwrap()
+        };
+
+        let decision = scheduler
+            .get_rollout_decisions()
+            .into_iter()
+            .find(|d| d.task_id == "big")
+            .unwrap();
+        assert_eq!(decision.decision, "skip");
+        assert_eq!(decision.competing_task_id, "small");
+        assert_eq!(find("small").start_date, d(2025, 1, 1));
+        assert_eq!(find("urgent").start_date, d(2025, 1, 3));
+        assert!(find("big").start_date > d(2025, 1, 3));
+    }
+
+    #[test]
+    fn test_rollout_cache_matches_uncached_schedule() {
+        let tasks = vec![
+            make_task("filler", 10.0, vec![], Some(10), vec!["r1"]),
+            make_task("prep", 1.0, vec![], Some(90), vec!["r2"]),
+            make_task("urgent", 2.0, vec![("prep", 0.0)], Some(90), vec!["r1"]),
+        ];
+        let run = |rollout_cache_size| {
+            let config = CriticalPathConfig {
+                rollout_cache_size,
+                ..Default::default()
+            };
+            let mut scheduler = scheduler_for(tasks.clone(), config, vec!["r1", "r2"], vec![]);
+            scheduler.schedule().unwrap()
+        };
+
+        let uncached = run(0);
+        let cached = run(16);
+        let dates = |result: &AlgorithmResult| {
+            let mut dates: Vec<_> = result
+                .scheduled_tasks
+                .iter()
+                .map(|t| (t.task_id.clone(), t.start_date, t.end_date))
+                .collect();
+            dates.sort();
+            dates
+        };
+        assert_eq!(dates(&cached), dates(&uncached));
+        assert!(!uncached
+            .algorithm_metadata
+            .contains_key("rollout_cache_hits"));
+        let count = |key: &str| cached.algorithm_metadata[key].parse::<usize>().unwrap();
+        assert_eq!(
+            count("rollout_cache_hits") + count("rollout_cache_misses"),
+            count("rollout_decisions")
+        );
+    }
+
+    #[test]
+    fn test_rollout_fidelity_filters_simulated_tasks() {
+        let auto = Task {
+            id: "auto".to_string(),
+            duration_days: 2.0,
+            resource_spec: Some("*".to_string()),
+            ..Default::default()
+        };
+        let tasks: FxHashMap<String, Task> = [
+            auto,
+            make_task("explicit", 2.0, vec![], None, vec!["r1"]),
+            make_task("milestone", 0.0, vec![], None, vec![]),
+        ]
+        .into_iter()
+        .map(|t| (t.id.clone(), t))
+        .collect();
+        let ctx = TaskData::new(&tasks, 50);
+        let id = |name: &str| ctx.index.get(name).unwrap();
+        let critical: FxHashSet<TaskId> = [id("explicit")].into_iter().collect();
+        let simulated = |fidelity| {
+            let mut names: Vec<&str> = ["auto", "explicit", "milestone"]
+                .into_iter()
+                .filter(|name| {
+                    CriticalPathScheduler::simulates_task(id(name), fidelity, &ctx, &critical)
+                })
+                .collect();
+            names.sort();
+            names
+        };
+
+        assert_eq!(
+            simulated(RolloutFidelity::Full),
+            vec!["auto", "explicit", "milestone"]
+        );
+        assert_eq!(
+            simulated(RolloutFidelity::NoAutoAssignment),
+            vec!["explicit", "milestone"]
+        );
+        assert_eq!(
+            simulated(RolloutFidelity::CriticalTasksOnly),
+            vec!["explicit", "milestone"]
+        );
+        assert_eq!(
+            RolloutFidelity::from_str("critical-tasks-only"),
+            Ok(RolloutFidelity::CriticalTasksOnly)
+        );
+    }
 }
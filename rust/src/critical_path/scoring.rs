@@ -356,20 +356,20 @@ mod tests {
     #[test]
     fn test_no_deadline_urgency_respects_floor() {
         // Custom config with low multiplier
-        let config = CriticalPathConfig::new(
-            2.0,  // k
-            0.01, // no_deadline_urgency_multiplier (very low)
-            0.1,  // urgency_floor
-            0,
-            true,
-            1.0,
-            Some(30),
-            "power",
-            1.0,
-            true,         // prefer_fungible_resources
-            "global_avg", // urgency_denominator
-        )
-        .unwrap();
+        let config = CriticalPathConfig::builder()
+            .k(2.0)
+            .no_deadline_urgency_multiplier(0.01) // very low
+            .urgency_floor(0.1)
+            .verbosity(0)
+            .rollout_enabled(true)
+            .rollout_score_ratio_threshold(1.0)
+            .rollout_max_horizon_days(Some(30))
+            .work_transform(WorkTransform::Power)
+            .work_exponent(1.0)
+            .prefer_fungible_resources(true)
+            .urgency_denominator(UrgencyDenominator::GlobalAvg)
+            .build()
+            .unwrap();
 
         // Even with very low min_deadline_urgency * multiplier, should respect floor
         let urg = compute_no_deadline_urgency(Some(0.5), &config);
@@ -379,20 +379,20 @@ mod tests {
     #[test]
     fn test_no_deadline_urgency_with_small_floor() {
         // Config like user's: urgency_floor=0.001, multiplier=0.9
-        let config = CriticalPathConfig::new(
-            1.5,   // k
-            0.9,   // no_deadline_urgency_multiplier
-            0.001, // urgency_floor (very low)
-            0,
-            true,
-            1.0,
-            Some(60),
-            "power",
-            1.0,
-            true,         // prefer_fungible_resources
-            "global_avg", // urgency_denominator
-        )
-        .unwrap();
+        let config = CriticalPathConfig::builder()
+            .k(1.5)
+            .no_deadline_urgency_multiplier(0.9)
+            .urgency_floor(0.001) // very low
+            .verbosity(0)
+            .rollout_enabled(true)
+            .rollout_score_ratio_threshold(1.0)
+            .rollout_max_horizon_days(Some(60))
+            .work_transform(WorkTransform::Power)
+            .work_exponent(1.0)
+            .prefer_fungible_resources(true)
+            .urgency_denominator(UrgencyDenominator::GlobalAvg)
+            .build()
+            .unwrap();
 
         // With some deadline urgency
         let urg = compute_no_deadline_urgency(Some(0.5), &config);
@@ -414,20 +414,20 @@ mod tests {
 
     #[test]
     fn test_transform_work_power_sqrt() {
-        let config = CriticalPathConfig::new(
-            2.0,
-            0.5,
-            0.1,
-            0,
-            true,
-            1.0,
-            Some(30),
-            "power",
-            0.5,
-            true,
-            "global_avg",
-        )
-        .unwrap();
+        let config = CriticalPathConfig::builder()
+            .k(2.0)
+            .no_deadline_urgency_multiplier(0.5)
+            .urgency_floor(0.1)
+            .verbosity(0)
+            .rollout_enabled(true)
+            .rollout_score_ratio_threshold(1.0)
+            .rollout_max_horizon_days(Some(30))
+            .work_transform(WorkTransform::Power)
+            .work_exponent(0.5)
+            .prefer_fungible_resources(true)
+            .urgency_denominator(UrgencyDenominator::GlobalAvg)
+            .build()
+            .unwrap();
         // sqrt transform
         assert!((transform_work(4.0, &config) - 2.0).abs() < 1e-9);
         assert!((transform_work(100.0, &config) - 10.0).abs() < 1e-9);
@@ -435,20 +435,20 @@ mod tests {
 
     #[test]
     fn test_transform_work_power_zero() {
-        let config = CriticalPathConfig::new(
-            2.0,
-            0.5,
-            0.1,
-            0,
-            true,
-            1.0,
-            Some(30),
-            "power",
-            0.0,
-            true,
-            "global_avg",
-        )
-        .unwrap();
+        let config = CriticalPathConfig::builder()
+            .k(2.0)
+            .no_deadline_urgency_multiplier(0.5)
+            .urgency_floor(0.1)
+            .verbosity(0)
+            .rollout_enabled(true)
+            .rollout_score_ratio_threshold(1.0)
+            .rollout_max_horizon_days(Some(30))
+            .work_transform(WorkTransform::Power)
+            .work_exponent(0.0)
+            .prefer_fungible_resources(true)
+            .urgency_denominator(UrgencyDenominator::GlobalAvg)
+            .build()
+            .unwrap();
         // exponent=0 means no work term (returns 1.0)
         assert!((transform_work(10.0, &config) - 1.0).abs() < 1e-9);
         assert!((transform_work(100.0, &config) - 1.0).abs() < 1e-9);
@@ -456,20 +456,20 @@ mod tests {
 
     #[test]
     fn test_transform_work_log() {
-        let config = CriticalPathConfig::new(
-            2.0,
-            0.5,
-            0.1,
-            0,
-            true,
-            1.0,
-            Some(30),
-            "log",
-            1.0,
-            true,
-            "global_avg",
-        )
-        .unwrap();
+        let config = CriticalPathConfig::builder()
+            .k(2.0)
+            .no_deadline_urgency_multiplier(0.5)
+            .urgency_floor(0.1)
+            .verbosity(0)
+            .rollout_enabled(true)
+            .rollout_score_ratio_threshold(1.0)
+            .rollout_max_horizon_days(Some(30))
+            .work_transform(WorkTransform::Log)
+            .work_exponent(1.0)
+            .prefer_fungible_resources(true)
+            .urgency_denominator(UrgencyDenominator::GlobalAvg)
+            .build()
+            .unwrap();
         // ln(e) = 1, ln(e^2) = 2
         let e = std::f64::consts::E;
         assert!((transform_work(e, &config) - 1.0).abs() < 1e-9);
@@ -478,20 +478,20 @@ mod tests {
 
     #[test]
     fn test_transform_work_log10() {
-        let config = CriticalPathConfig::new(
-            2.0,
-            0.5,
-            0.1,
-            0,
-            true,
-            1.0,
-            Some(30),
-            "log10",
-            1.0,
-            true,
-            "global_avg",
-        )
-        .unwrap();
+        let config = CriticalPathConfig::builder()
+            .k(2.0)
+            .no_deadline_urgency_multiplier(0.5)
+            .urgency_floor(0.1)
+            .verbosity(0)
+            .rollout_enabled(true)
+            .rollout_score_ratio_threshold(1.0)
+            .rollout_max_horizon_days(Some(30))
+            .work_transform(WorkTransform::Log10)
+            .work_exponent(1.0)
+            .prefer_fungible_resources(true)
+            .urgency_denominator(UrgencyDenominator::GlobalAvg)
+            .build()
+            .unwrap();
         // log10(10) = 1, log10(100) = 2
         assert!((transform_work(10.0, &config) - 1.0).abs() < 1e-9);
         assert!((transform_work(100.0, &config) - 2.0).abs() < 1e-9);
@@ -499,37 +499,37 @@ mod tests {
 
     #[test]
     fn test_transform_work_floors_small_values() {
-        let config_log = CriticalPathConfig::new(
-            2.0,
-            0.5,
-            0.1,
-            0,
-            true,
-            1.0,
-            Some(30),
-            "log",
-            1.0,
-            true,
-            "global_avg",
-        )
-        .unwrap();
+        let config_log = CriticalPathConfig::builder()
+            .k(2.0)
+            .no_deadline_urgency_multiplier(0.5)
+            .urgency_floor(0.1)
+            .verbosity(0)
+            .rollout_enabled(true)
+            .rollout_score_ratio_threshold(1.0)
+            .rollout_max_horizon_days(Some(30))
+            .work_transform(WorkTransform::Log)
+            .work_exponent(1.0)
+            .prefer_fungible_resources(true)
+            .urgency_denominator(UrgencyDenominator::GlobalAvg)
+            .build()
+            .unwrap();
         // Very small work values should be floored to avoid negative/tiny log values
         assert!(transform_work(0.01, &config_log) >= 0.1);
 
-        let config_log10 = CriticalPathConfig::new(
-            2.0,
-            0.5,
-            0.1,
-            0,
-            true,
-            1.0,
-            Some(30),
-            "log10",
-            1.0,
-            true,
-            "global_avg",
-        )
-        .unwrap();
+        let config_log10 = CriticalPathConfig::builder()
+            .k(2.0)
+            .no_deadline_urgency_multiplier(0.5)
+            .urgency_floor(0.1)
+            .verbosity(0)
+            .rollout_enabled(true)
+            .rollout_score_ratio_threshold(1.0)
+            .rollout_max_horizon_days(Some(30))
+            .work_transform(WorkTransform::Log10)
+            .work_exponent(1.0)
+            .prefer_fungible_resources(true)
+            .urgency_denominator(UrgencyDenominator::GlobalAvg)
+            .build()
+            .unwrap();
         assert!(transform_work(0.01, &config_log10) >= 0.1);
     }
 
@@ -570,34 +570,34 @@ mod tests {
     #[test]
     fn test_compute_task_urgency_k_parameter() {
         // Higher K = more tolerant of slack (slower decay)
-        let config_low_k = CriticalPathConfig::new(
-            1.0,
-            0.5,
-            0.1,
-            0,
-            true,
-            1.0,
-            Some(30),
-            "power",
-            1.0,
-            true,
-            "global_avg",
-        )
-        .unwrap();
-        let config_high_k = CriticalPathConfig::new(
-            4.0,
-            0.5,
-            0.1,
-            0,
-            true,
-            1.0,
-            Some(30),
-            "power",
-            1.0,
-            true,
-            "global_avg",
-        )
-        .unwrap();
+        let config_low_k = CriticalPathConfig::builder()
+            .k(1.0)
+            .no_deadline_urgency_multiplier(0.5)
+            .urgency_floor(0.1)
+            .verbosity(0)
+            .rollout_enabled(true)
+            .rollout_score_ratio_threshold(1.0)
+            .rollout_max_horizon_days(Some(30))
+            .work_transform(WorkTransform::Power)
+            .work_exponent(1.0)
+            .prefer_fungible_resources(true)
+            .urgency_denominator(UrgencyDenominator::GlobalAvg)
+            .build()
+            .unwrap();
+        let config_high_k = CriticalPathConfig::builder()
+            .k(4.0)
+            .no_deadline_urgency_multiplier(0.5)
+            .urgency_floor(0.1)
+            .verbosity(0)
+            .rollout_enabled(true)
+            .rollout_score_ratio_threshold(1.0)
+            .rollout_max_horizon_days(Some(30))
+            .work_transform(WorkTransform::Power)
+            .work_exponent(1.0)
+            .prefer_fungible_resources(true)
+            .urgency_denominator(UrgencyDenominator::GlobalAvg)
+            .build()
+            .unwrap();
 
         let slack = 10.0;
         let denominator = 10.0;
@@ -616,54 +616,54 @@ mod tests {
         let avg_work = 50.0;
 
         // GlobalAvg mode
-        let config_global = CriticalPathConfig::new(
-            2.0,
-            0.5,
-            0.1,
-            0,
-            true,
-            1.0,
-            Some(30),
-            "power",
-            1.0,
-            true,
-            "global_avg",
-        )
-        .unwrap();
+        let config_global = CriticalPathConfig::builder()
+            .k(2.0)
+            .no_deadline_urgency_multiplier(0.5)
+            .urgency_floor(0.1)
+            .verbosity(0)
+            .rollout_enabled(true)
+            .rollout_score_ratio_threshold(1.0)
+            .rollout_max_horizon_days(Some(30))
+            .work_transform(WorkTransform::Power)
+            .work_exponent(1.0)
+            .prefer_fungible_resources(true)
+            .urgency_denominator(UrgencyDenominator::GlobalAvg)
+            .build()
+            .unwrap();
         assert!((get_urgency_denominator(&target, avg_work, &config_global) - 50.0).abs() < 1e-9);
 
         // TargetWork mode
-        let config_work = CriticalPathConfig::new(
-            2.0,
-            0.5,
-            0.1,
-            0,
-            true,
-            1.0,
-            Some(30),
-            "power",
-            1.0,
-            true,
-            "target_work",
-        )
-        .unwrap();
+        let config_work = CriticalPathConfig::builder()
+            .k(2.0)
+            .no_deadline_urgency_multiplier(0.5)
+            .urgency_floor(0.1)
+            .verbosity(0)
+            .rollout_enabled(true)
+            .rollout_score_ratio_threshold(1.0)
+            .rollout_max_horizon_days(Some(30))
+            .work_transform(WorkTransform::Power)
+            .work_exponent(1.0)
+            .prefer_fungible_resources(true)
+            .urgency_denominator(UrgencyDenominator::TargetWork)
+            .build()
+            .unwrap();
         assert!((get_urgency_denominator(&target, avg_work, &config_work) - 100.0).abs() < 1e-9);
 
         // CriticalPath mode
-        let config_cp = CriticalPathConfig::new(
-            2.0,
-            0.5,
-            0.1,
-            0,
-            true,
-            1.0,
-            Some(30),
-            "power",
-            1.0,
-            true,
-            "critical_path",
-        )
-        .unwrap();
+        let config_cp = CriticalPathConfig::builder()
+            .k(2.0)
+            .no_deadline_urgency_multiplier(0.5)
+            .urgency_floor(0.1)
+            .verbosity(0)
+            .rollout_enabled(true)
+            .rollout_score_ratio_threshold(1.0)
+            .rollout_max_horizon_days(Some(30))
+            .work_transform(WorkTransform::Power)
+            .work_exponent(1.0)
+            .prefer_fungible_resources(true)
+            .urgency_denominator(UrgencyDenominator::CriticalPath)
+            .build()
+            .unwrap();
         assert!((get_urgency_denominator(&target, avg_work, &config_cp) - 25.0).abs() < 1e-9);
     }
 
@@ -676,7 +676,7 @@ mod tests {
         let target_denominators = vec![10.0, 10.0, 10.0];
 
         // Task on critical path of target 1 (slack=0)
-        let task_slacks = vec![(1u32, 0.0)];
+        let task_slacks = [(1u32, 0.0)];
         let score = score_task_unified(
             task_slacks.iter(),
             &target_scores,
@@ -686,7 +686,7 @@ mod tests {
         assert!((score - 10.0).abs() < 1e-9); // target_score * urgency(0) = 10 * 1.0 = 10
 
         // Task with slack of 10 on target 1
-        let task_slacks = vec![(1u32, 10.0)];
+        let task_slacks = [(1u32, 10.0)];
         let score = score_task_unified(
             task_slacks.iter(),
             &target_scores,
@@ -707,7 +707,7 @@ mod tests {
         // Target 1: 10 * exp(-10 / 20) ≈ 10 * 0.606 = 6.06
         // Target 2: 8 * 1.0 = 8.0
         // Max = 8.0
-        let task_slacks = vec![(1u32, 10.0), (2u32, 0.0)];
+        let task_slacks = [(1u32, 10.0), (2u32, 0.0)];
         let score = score_task_unified(
             task_slacks.iter(),
             &target_scores,
@@ -742,7 +742,7 @@ mod tests {
         let target_denominators = vec![10.0, 10.0, 10.0];
 
         // Task A: critical for target 1 (top target)
-        let task_a_slacks = vec![(1u32, 0.0)];
+        let task_a_slacks = [(1u32, 0.0)];
         let score_a = score_task_unified(
             task_a_slacks.iter(),
             &target_scores,
@@ -751,7 +751,7 @@ mod tests {
         );
 
         // Task B: critical for target 2
-        let task_b_slacks = vec![(2u32, 0.0)];
+        let task_b_slacks = [(2u32, 0.0)];
         let score_b = score_task_unified(
             task_b_slacks.iter(),
             &target_scores,
@@ -760,7 +760,7 @@ mod tests {
         );
 
         // Task C: critical for both target 2 and has some slack on target 1
-        let task_c_slacks = vec![(1u32, 5.0), (2u32, 0.0)];
+        let task_c_slacks = [(1u32, 5.0), (2u32, 0.0)];
         let score_c = score_task_unified(
             task_c_slacks.iter(),
             &target_scores,
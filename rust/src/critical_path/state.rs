@@ -4,9 +4,12 @@ use chrono::NaiveDate;
 use rustc_hash::FxHashMap;
 
 use crate::models::ScheduledTask;
-use crate::scheduler::ResourceSchedule;
+use crate::profiling::PhaseTimings;
+use crate::scheduler::{ExclusionGroups, ResourceSchedule, RolloutTrace};
+use crate::snapshot::{HeldReservation, ScheduleStep};
+use crate::validation::{offset_date, DateOverflow};
 
-use super::rollout::ResourceReservation;
+use super::rollout::{CriticalPathRolloutDecision, ReservationRecord, ResourceReservation};
 use super::types::ResourceMask;
 
 /// Snapshot of critical path scheduler state for rollout simulations.
@@ -30,6 +33,22 @@ pub struct CriticalPathSchedulerState {
     pub result: Vec<ScheduledTask>,
     /// Resource reservations from rollout decisions, keyed by resource ID.
     pub reservations: FxHashMap<u32, ResourceReservation>,
+    /// Reservations released so far (not carried into simulations).
+    pub reservation_log: Vec<ReservationRecord>,
+    /// Rollout decisions made so far (not carried into simulations).
+    pub rollout_decisions: Vec<CriticalPathRolloutDecision>,
+    /// Rollout memo (hits, misses) for this run.
+    pub rollout_cache_stats: (usize, usize),
+    /// Main-loop iterations so far (not carried into simulations).
+    pub iterations: usize,
+    /// Phase timings when profiling (not carried into simulations).
+    pub timings: PhaseTimings,
+    /// Decision of a stepped run, which stops after one (not carried into simulations).
+    pub step: Option<ScheduleStep>,
+    /// Busy mutually exclusive groups.
+    pub exclusions: ExclusionGroups,
+    /// Simulations behind the last rollout decision, when tracing (not carried into simulations).
+    pub rollout_trace: Option<RolloutTrace>,
 }
 
 impl CriticalPathSchedulerState {
@@ -56,6 +75,14 @@ impl CriticalPathSchedulerState {
             current_time,
             result: Vec::new(),
             reservations: FxHashMap::default(),
+            reservation_log: Vec::new(),
+            rollout_decisions: Vec::new(),
+            rollout_cache_stats: (0, 0),
+            iterations: 0,
+            timings: PhaseTimings::default(),
+            step: None,
+            exclusions: ExclusionGroups::default(),
+            rollout_trace: None,
         }
     }
 
@@ -71,9 +98,64 @@ impl CriticalPathSchedulerState {
             current_time: self.current_time,
             result: self.result.clone(),
             reservations: self.reservations.clone(),
+            reservation_log: Vec::new(),
+            rollout_decisions: Vec::new(),
+            rollout_cache_stats: (0, 0),
+            iterations: 0,
+            timings: PhaseTimings::default(),
+            step: None,
+            exclusions: self.exclusions.clone(),
+            rollout_trace: None,
         }
     }
 
+    /// Hold resource `res_id` for a reservation, logging any reservation it replaces.
+    pub fn reserve(&mut self, res_id: u32, reservation: ResourceReservation) {
+        if let Some(previous) = self.reservations.insert(res_id, reservation) {
+            self.reservation_log.push(ReservationRecord::new(
+                &previous,
+                self.current_time,
+                "replaced",
+            ));
+        }
+    }
+
+    /// Release the reservations `release` selects, logging them with `outcome`.
+    pub fn release_reservations(
+        &mut self,
+        outcome: &str,
+        mut release: impl FnMut(&ResourceReservation) -> bool,
+    ) {
+        let now = self.current_time;
+        let log = &mut self.reservation_log;
+        self.reservations.retain(|_, r| {
+            if release(r) {
+                log.push(ReservationRecord::new(r, now, outcome));
+                false
+            } else {
+                true
+            }
+        });
+    }
+
+    /// Reservations still held, sorted by resource.
+    pub fn held_reservations(&self) -> Vec<HeldReservation> {
+        let mut held: Vec<HeldReservation> = self
+            .reservations
+            .values()
+            .map(|r| HeldReservation {
+                resource: r.resource.clone(),
+                target_id: r.target_id.clone(),
+                task_id: r.task_id.clone(),
+                target_score: r.target_score,
+                reserved_from: r.reserved_from,
+                release_on: r.release_on,
+            })
+            .collect();
+        held.sort_by(|a, b| a.resource.cmp(&b.resource));
+        held
+    }
+
     /// Compute the bitmask of resources available at current_time.
     pub fn available_mask(&self) -> ResourceMask {
         let mut mask = ResourceMask::new();
@@ -112,8 +194,8 @@ impl CriticalPathSchedulerState {
 
     /// Convert an offset back to a date.
     #[inline]
-    pub fn offset_to_date(&self, offset: f64) -> NaiveDate {
-        self.initial_time + chrono::Duration::days(offset as i64)
+    pub fn offset_to_date(&self, offset: f64) -> Result<NaiveDate, DateOverflow> {
+        offset_date(self.initial_time, offset)
     }
 
     /// Convert a date to an offset from initial_time.
@@ -191,7 +273,7 @@ mod tests {
             CriticalPathSchedulerState::new(vec![], vec![], initial_time, vec![], initial_time);
 
         let offset = 10.0;
-        let date = state.offset_to_date(offset);
+        let date = state.offset_to_date(offset).unwrap();
         assert_eq!(date, NaiveDate::from_ymd_opt(2025, 1, 11).unwrap());
 
         let back_offset = state.date_to_offset(date);
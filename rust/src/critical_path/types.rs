@@ -2,116 +2,63 @@
 
 use chrono::NaiveDate;
 use pyo3::prelude::*;
-use rustc_hash::{FxHashMap, FxHashSet};
+use rustc_hash::FxHashSet;
 
-/// Maps resource names to consecutive integer IDs for fast lookups.
-///
-/// Avoids expensive string hashing during scheduling by using array indexing.
-#[derive(Clone, Debug)]
-pub struct ResourceIndex {
-    name_to_id: FxHashMap<String, u32>,
-    id_to_name: Vec<String>,
-}
-
-impl ResourceIndex {
-    /// Create a new resource index from an iterator of resource names.
-    pub fn new(resource_names: impl Iterator<Item = String>) -> Self {
-        let id_to_name: Vec<String> = resource_names.collect();
-        let name_to_id: FxHashMap<String, u32> = id_to_name
-            .iter()
-            .enumerate()
-            .map(|(i, name)| (name.clone(), i as u32))
-            .collect();
-        Self {
-            name_to_id,
-            id_to_name,
-        }
-    }
-
-    /// Get the integer ID for a resource name.
-    pub fn get_id(&self, name: &str) -> Option<u32> {
-        self.name_to_id.get(name).copied()
-    }
-
-    /// Get the resource name for an integer ID.
-    pub fn get_name(&self, id: u32) -> Option<&str> {
-        self.id_to_name.get(id as usize).map(|s| s.as_str())
-    }
-
-    /// Get the number of resources in the index.
-    pub fn len(&self) -> usize {
-        self.id_to_name.len()
-    }
-
-    /// Check if the index is empty.
-    pub fn is_empty(&self) -> bool {
-        self.id_to_name.is_empty()
-    }
+use crate::config::{
+    builder_setters, unknown_preset, ConfigError, DurationRounding, FixedConflictPolicy, Overrides,
+    Problems, RolloutFidelity, ScheduleObjective, UnknownDependencyPolicy, UnschedulablePolicy,
+};
+use crate::exceptions::InvalidConfigError;
+use crate::interner::{ResourceIdInterner, TaskIdInt, TaskIdInterner};
 
-    /// Iterate over all (id, name) pairs.
-    pub fn iter(&self) -> impl Iterator<Item = (u32, &str)> {
-        self.id_to_name
-            .iter()
-            .enumerate()
-            .map(|(i, name)| (i as u32, name.as_str()))
-    }
-}
+/// Maps resource names to consecutive integer IDs for fast lookups.
+pub type ResourceIndex = ResourceIdInterner;
 
 /// Task ID type (u32 for compact storage and fast indexing).
-pub type TaskId = u32;
+pub type TaskId = TaskIdInt;
 
 /// Maps task ID strings to consecutive integer IDs for fast lookups.
+pub type TaskIndex = TaskIdInterner;
+
+/// Weighted adjacency lists in compressed sparse row form.
 ///
-/// Avoids expensive string hashing during scheduling by using array indexing.
-#[derive(Clone, Debug)]
-pub struct TaskIndex {
-    name_to_id: FxHashMap<String, TaskId>,
-    id_to_name: Vec<String>,
+/// The `(neighbour, lag)` edges of every task live in one allocation; task
+/// `i`'s edges are `adjacency[i]`, in the order they were added.
+#[derive(Clone, Debug, Default)]
+pub struct Adjacency {
+    offsets: Vec<u32>,
+    edges: Vec<(TaskId, f64)>,
 }
 
-impl TaskIndex {
-    /// Create a new task index from an iterator of task ID strings.
-    pub fn new(task_ids: impl Iterator<Item = String>) -> Self {
-        let id_to_name: Vec<String> = task_ids.collect();
-        let name_to_id: FxHashMap<String, TaskId> = id_to_name
-            .iter()
-            .enumerate()
-            .map(|(i, name)| (name.clone(), i as TaskId))
-            .collect();
+impl Adjacency {
+    /// Build from `(from, to, lag)` edges over `n` tasks.
+    pub fn from_edges(n: usize, edges: &[(TaskId, TaskId, f64)]) -> Self {
+        let mut offsets = vec![0u32; n + 1];
+        for &(from, _, _) in edges {
+            offsets[from as usize + 1] += 1;
+        }
+        for i in 0..n {
+            offsets[i + 1] += offsets[i];
+        }
+        let mut next: Vec<u32> = offsets[..n].to_vec();
+        let mut flat = vec![(0, 0.0); edges.len()];
+        for &(from, to, lag) in edges {
+            let slot = &mut next[from as usize];
+            flat[*slot as usize] = (to, lag);
+            *slot += 1;
+        }
         Self {
-            name_to_id,
-            id_to_name,
+            offsets,
+            edges: flat,
         }
     }
+}
 
-    /// Get the integer ID for a task ID string.
-    #[inline]
-    pub fn get_id(&self, name: &str) -> Option<TaskId> {
-        self.name_to_id.get(name).copied()
-    }
-
-    /// Get the task ID string for an integer ID.
-    #[inline]
-    pub fn get_name(&self, id: TaskId) -> Option<&str> {
-        self.id_to_name.get(id as usize).map(|s| s.as_str())
-    }
-
-    /// Get the number of tasks in the index.
-    pub fn len(&self) -> usize {
-        self.id_to_name.len()
-    }
-
-    /// Check if the index is empty.
-    pub fn is_empty(&self) -> bool {
-        self.id_to_name.is_empty()
-    }
+impl std::ops::Index<usize> for Adjacency {
+    type Output = [(TaskId, f64)];
 
-    /// Iterate over all (id, name) pairs.
-    pub fn iter(&self) -> impl Iterator<Item = (TaskId, &str)> {
-        self.id_to_name
-            .iter()
-            .enumerate()
-            .map(|(i, name)| (i as TaskId, name.as_str()))
+    fn index(&self, task: usize) -> &Self::Output {
+        &self.edges[self.offsets[task] as usize..self.offsets[task + 1] as usize]
     }
 }
 
@@ -198,6 +145,45 @@ impl Iterator for ResourceMaskIter {
     }
 }
 
+/// Fixed-size set of task IDs stored as 64-bit words.
+#[derive(Clone, Debug, Default)]
+pub struct Bitset {
+    words: Vec<u64>,
+}
+
+impl Bitset {
+    /// Create an empty set able to hold IDs below `len`.
+    pub fn new(len: usize) -> Self {
+        Self {
+            words: vec![0; len.div_ceil(64)],
+        }
+    }
+
+    /// Check if an ID is in the set.
+    #[inline]
+    pub fn contains(&self, id: TaskId) -> bool {
+        let id = id as usize;
+        self.words[id / 64] & (1u64 << (id % 64)) != 0
+    }
+
+    /// Add an ID, returning false if it was already present.
+    #[inline]
+    pub fn insert(&mut self, id: TaskId) -> bool {
+        let id = id as usize;
+        let word = &mut self.words[id / 64];
+        let bit = 1u64 << (id % 64);
+        let added = *word & bit == 0;
+        *word |= bit;
+        added
+    }
+
+    /// Remove every ID, keeping the allocation.
+    #[inline]
+    pub fn clear(&mut self) {
+        self.words.fill(0);
+    }
+}
+
 /// Task resource requirements for fast availability checking.
 #[derive(Clone, Copy, Debug)]
 pub struct TaskResourceReq {
@@ -234,6 +220,7 @@ pub enum WorkTransform {
 
 impl WorkTransform {
     /// Parse from string (for Python interop).
+    #[allow(clippy::should_implement_trait)]
     pub fn from_str(s: &str) -> Result<Self, String> {
         match s.to_lowercase().as_str() {
             "power" => Ok(Self::Power),
@@ -273,6 +260,7 @@ pub enum UrgencyDenominator {
 
 impl UrgencyDenominator {
     /// Parse from string (for Python interop).
+    #[allow(clippy::should_implement_trait)]
     pub fn from_str(s: &str) -> Result<Self, String> {
         match s.to_lowercase().replace('_', "").as_str() {
             "globalavg" | "global" | "avg" => Ok(Self::GlobalAvg),
@@ -327,6 +315,32 @@ pub struct CriticalPathConfig {
     #[pyo3(get, set)]
     pub rollout_max_horizon_days: Option<i32>,
 
+    /// Beam search: number of top competing targets to simulate reserving for.
+    #[pyo3(get, set)]
+    pub rollout_beam_k: usize,
+
+    /// Beam search: number of alternate eligible tasks to simulate starting instead.
+    #[pyo3(get, set)]
+    pub rollout_beam_width: usize,
+
+    /// Number of rollout outcomes memoized across iterations (LRU; 0 = disabled).
+    #[pyo3(get, set)]
+    pub rollout_cache_size: usize,
+
+    /// How much detail rollout simulations replicate. Critical tasks are those on
+    /// a ranked target's critical path.
+    /// Not directly exposed to Python; use rollout_fidelity_str getter/setter.
+    pub rollout_fidelity: RolloutFidelity,
+
+    /// Objective used to score rollout scenarios.
+    #[pyo3(get, set)]
+    pub objective: ScheduleObjective,
+
+    /// Days a rollout reservation may hold an idle resource for its task before
+    /// it is released (0 = released as soon as time advances).
+    #[pyo3(get, set)]
+    pub reservation_max_idle_days: u32,
+
     /// How to transform the work term in score calculation.
     /// Not directly exposed to Python; use work_transform_str getter/setter.
     pub work_transform: WorkTransform,
@@ -344,10 +358,53 @@ pub struct CriticalPathConfig {
     /// How to compute the denominator for task urgency calculation.
     /// Not directly exposed to Python; use urgency_denominator_str getter/setter.
     pub urgency_denominator: UrgencyDenominator,
+
+    /// Wall-clock timeout in seconds (None = no timeout).
+    #[pyo3(get, set)]
+    pub timeout_secs: Option<f64>,
+
+    /// Handling of dependencies on unknown task IDs.
+    /// Not directly exposed to Python; use unknown_dependencies_str getter/setter.
+    pub unknown_dependencies: UnknownDependencyPolicy,
+
+    /// Handling of tasks left unscheduled at the end of a run.
+    /// Not directly exposed to Python; use on_unschedulable_str getter/setter.
+    pub on_unschedulable: UnschedulablePolicy,
+
+    /// Maximum time advances before failing with IterationLimit (None = 100 per task).
+    #[pyo3(get, set)]
+    pub max_iterations: Option<usize>,
+
+    /// Keep both simulated schedules of the last rollout decision for debugging.
+    #[pyo3(get, set)]
+    pub trace_rollouts: bool,
+
+    /// Let milestones and start_on tasks fall inside global DNS periods; when
+    /// false they move to the day after the period.
+    #[pyo3(get, set)]
+    pub allow_fixed_in_global_dns: bool,
+
+    /// Handling of fixed-date tasks that overlap on a resource.
+    /// Not directly exposed to Python; use on_fixed_conflict_str getter/setter.
+    pub on_fixed_conflict: FixedConflictPolicy,
+
+    /// Record per-phase timings in the result metadata.
+    #[pyo3(get, set)]
+    pub profile: bool,
+
+    /// Number of top-scoring targets ranked each iteration (None = all).
+    /// Every target is still scored; only the best are selected and sorted.
+    #[pyo3(get, set)]
+    pub max_targets_per_iteration: Option<usize>,
+
+    /// Rounding of fractional durations to whole days.
+    /// Not directly exposed to Python; use duration_rounding_str getter/setter.
+    pub duration_rounding: DurationRounding,
 }
 
 #[pymethods]
 impl CriticalPathConfig {
+    /// Python constructor; Rust code uses `CriticalPathConfig::builder()`.
     #[new]
     #[pyo3(signature = (
         k=2.0,
@@ -360,10 +417,26 @@ impl CriticalPathConfig {
         work_transform="power",
         work_exponent=1.0,
         prefer_fungible_resources=true,
-        urgency_denominator="global_avg"
+        urgency_denominator="global_avg",
+        timeout_secs=None,
+        unknown_dependencies="block",
+        rollout_beam_k=1,
+        rollout_beam_width=0,
+        rollout_cache_size=0,
+        rollout_fidelity="full",
+        objective=None,
+        reservation_max_idle_days=0,
+        on_unschedulable="error",
+        max_iterations=None,
+        trace_rollouts=false,
+        allow_fixed_in_global_dns=true,
+        on_fixed_conflict="warn",
+        profile=false,
+        max_targets_per_iteration=None,
+        duration_rounding="up"
     ))]
     #[allow(clippy::too_many_arguments)]
-    pub fn new(
+    fn py_new(
         k: f64,
         no_deadline_urgency_multiplier: f64,
         urgency_floor: f64,
@@ -375,11 +448,37 @@ impl CriticalPathConfig {
         work_exponent: f64,
         prefer_fungible_resources: bool,
         urgency_denominator: &str,
+        timeout_secs: Option<f64>,
+        unknown_dependencies: &str,
+        rollout_beam_k: usize,
+        rollout_beam_width: usize,
+        rollout_cache_size: usize,
+        rollout_fidelity: &str,
+        objective: Option<ScheduleObjective>,
+        reservation_max_idle_days: u32,
+        on_unschedulable: &str,
+        max_iterations: Option<usize>,
+        trace_rollouts: bool,
+        allow_fixed_in_global_dns: bool,
+        on_fixed_conflict: &str,
+        profile: bool,
+        max_targets_per_iteration: Option<usize>,
+        duration_rounding: &str,
     ) -> PyResult<Self> {
-        let work_transform = WorkTransform::from_str(work_transform)
-            .map_err(pyo3::exceptions::PyValueError::new_err)?;
+        let work_transform =
+            WorkTransform::from_str(work_transform).map_err(InvalidConfigError::new_err)?;
         let urgency_denominator = UrgencyDenominator::from_str(urgency_denominator)
-            .map_err(pyo3::exceptions::PyValueError::new_err)?;
+            .map_err(InvalidConfigError::new_err)?;
+        let unknown_dependencies = UnknownDependencyPolicy::from_str(unknown_dependencies)
+            .map_err(InvalidConfigError::new_err)?;
+        let rollout_fidelity =
+            RolloutFidelity::from_str(rollout_fidelity).map_err(InvalidConfigError::new_err)?;
+        let on_unschedulable =
+            UnschedulablePolicy::from_str(on_unschedulable).map_err(InvalidConfigError::new_err)?;
+        let on_fixed_conflict = FixedConflictPolicy::from_str(on_fixed_conflict)
+            .map_err(InvalidConfigError::new_err)?;
+        let duration_rounding =
+            DurationRounding::from_str(duration_rounding).map_err(InvalidConfigError::new_err)?;
         Ok(Self {
             k,
             no_deadline_urgency_multiplier,
@@ -388,13 +487,52 @@ impl CriticalPathConfig {
             rollout_enabled,
             rollout_score_ratio_threshold,
             rollout_max_horizon_days,
+            rollout_beam_k,
+            rollout_beam_width,
+            rollout_cache_size,
+            rollout_fidelity,
+            objective: objective.unwrap_or_default(),
+            reservation_max_idle_days,
             work_transform,
             work_exponent,
             prefer_fungible_resources,
             urgency_denominator,
+            timeout_secs,
+            unknown_dependencies,
+            on_unschedulable,
+            max_iterations,
+            trace_rollouts,
+            allow_fixed_in_global_dns,
+            on_fixed_conflict,
+            profile,
+            max_targets_per_iteration,
+            duration_rounding,
         })
     }
 
+    /// A named preset: "deadline_focused", "throughput", "stable_plan", or "fast".
+    #[staticmethod]
+    #[pyo3(name = "preset")]
+    fn py_preset(name: &str) -> PyResult<Self> {
+        Self::preset(name).map_err(|e| InvalidConfigError::new_err(e.to_string()))
+    }
+
+    /// Load a JSON or TOML profile of overrides, optionally naming a `preset`.
+    #[staticmethod]
+    #[pyo3(name = "from_profile")]
+    fn py_from_profile(text: &str) -> PyResult<Self> {
+        Self::from_profile(text).map_err(|e| InvalidConfigError::new_err(e.to_string()))
+    }
+
+    /// Problems with this configuration's values (empty if valid).
+    #[pyo3(name = "validate")]
+    fn py_validate(&self) -> Vec<String> {
+        self.validate()
+            .err()
+            .map(|e| e.problems)
+            .unwrap_or_default()
+    }
+
     /// Get the work transform as a string.
     #[getter]
     fn work_transform_str(&self) -> &'static str {
@@ -405,7 +543,77 @@ impl CriticalPathConfig {
     #[setter]
     fn set_work_transform_str(&mut self, value: &str) -> PyResult<()> {
         self.work_transform =
-            WorkTransform::from_str(value).map_err(pyo3::exceptions::PyValueError::new_err)?;
+            WorkTransform::from_str(value).map_err(InvalidConfigError::new_err)?;
+        Ok(())
+    }
+
+    /// Get the unknown dependency policy as a string.
+    #[getter]
+    fn unknown_dependencies_str(&self) -> &'static str {
+        self.unknown_dependencies.as_str()
+    }
+
+    /// Set the unknown dependency policy from a string.
+    #[setter]
+    fn set_unknown_dependencies_str(&mut self, value: &str) -> PyResult<()> {
+        self.unknown_dependencies =
+            UnknownDependencyPolicy::from_str(value).map_err(InvalidConfigError::new_err)?;
+        Ok(())
+    }
+
+    /// Get the unschedulable-task policy as a string.
+    #[getter]
+    fn on_unschedulable_str(&self) -> &'static str {
+        self.on_unschedulable.as_str()
+    }
+
+    /// Set the unschedulable-task policy from a string.
+    #[setter]
+    fn set_on_unschedulable_str(&mut self, value: &str) -> PyResult<()> {
+        self.on_unschedulable =
+            UnschedulablePolicy::from_str(value).map_err(InvalidConfigError::new_err)?;
+        Ok(())
+    }
+
+    /// Get the fixed-task conflict policy as a string.
+    #[getter]
+    fn on_fixed_conflict_str(&self) -> &'static str {
+        self.on_fixed_conflict.as_str()
+    }
+
+    /// Set the fixed-task conflict policy from a string.
+    #[setter]
+    fn set_on_fixed_conflict_str(&mut self, value: &str) -> PyResult<()> {
+        self.on_fixed_conflict =
+            FixedConflictPolicy::from_str(value).map_err(InvalidConfigError::new_err)?;
+        Ok(())
+    }
+
+    /// Get the duration rounding policy as a string.
+    #[getter]
+    fn duration_rounding_str(&self) -> &'static str {
+        self.duration_rounding.as_str()
+    }
+
+    /// Set the duration rounding policy from a string.
+    #[setter]
+    fn set_duration_rounding_str(&mut self, value: &str) -> PyResult<()> {
+        self.duration_rounding =
+            DurationRounding::from_str(value).map_err(InvalidConfigError::new_err)?;
+        Ok(())
+    }
+
+    /// Get the rollout fidelity as a string.
+    #[getter]
+    fn rollout_fidelity_str(&self) -> &'static str {
+        self.rollout_fidelity.as_str()
+    }
+
+    /// Set the rollout fidelity from a string.
+    #[setter]
+    fn set_rollout_fidelity_str(&mut self, value: &str) -> PyResult<()> {
+        self.rollout_fidelity =
+            RolloutFidelity::from_str(value).map_err(InvalidConfigError::new_err)?;
         Ok(())
     }
 
@@ -419,7 +627,7 @@ impl CriticalPathConfig {
     #[setter]
     fn set_urgency_denominator_str(&mut self, value: &str) -> PyResult<()> {
         self.urgency_denominator =
-            UrgencyDenominator::from_str(value).map_err(pyo3::exceptions::PyValueError::new_err)?;
+            UrgencyDenominator::from_str(value).map_err(InvalidConfigError::new_err)?;
         Ok(())
     }
 
@@ -444,15 +652,36 @@ impl Default for CriticalPathConfig {
             rollout_enabled: true,
             rollout_score_ratio_threshold: 1.0,
             rollout_max_horizon_days: Some(30),
+            rollout_beam_k: 1,
+            rollout_beam_width: 0,
+            rollout_cache_size: 0,
+            rollout_fidelity: RolloutFidelity::Full,
+            objective: ScheduleObjective::default(),
+            reservation_max_idle_days: 0,
             work_transform: WorkTransform::Power,
             work_exponent: 1.0,
             prefer_fungible_resources: true,
             urgency_denominator: UrgencyDenominator::GlobalAvg,
+            timeout_secs: None,
+            unknown_dependencies: UnknownDependencyPolicy::Block,
+            on_unschedulable: UnschedulablePolicy::Error,
+            max_iterations: None,
+            trace_rollouts: false,
+            allow_fixed_in_global_dns: true,
+            on_fixed_conflict: FixedConflictPolicy::Warn,
+            profile: false,
+            max_targets_per_iteration: None,
+            duration_rounding: DurationRounding::Up,
         }
     }
 }
 
 impl CriticalPathConfig {
+    /// Whether rollout compares more than "schedule now" vs a single plain skip.
+    pub fn beam_search_enabled(&self) -> bool {
+        self.rollout_beam_k > 1 || self.rollout_beam_width > 0
+    }
+
     /// Extract rollout configuration as a separate struct.
     pub fn rollout_config(&self) -> super::rollout::RolloutConfig {
         super::rollout::RolloutConfig {
@@ -461,20 +690,248 @@ impl CriticalPathConfig {
             max_horizon_days: self.rollout_max_horizon_days,
         }
     }
+
+    /// Start building a configuration from the defaults.
+    pub fn builder() -> CriticalPathConfigBuilder {
+        CriticalPathConfigBuilder {
+            config: Self::default(),
+        }
+    }
+
+    /// A named starting point for common goals; see `PRESETS`.
+    ///
+    /// - `deadline_focused`: urgency rises early and rollout weighs three competing targets
+    /// - `throughput`: slack-tolerant urgency and a makespan term in rollout scoring
+    /// - `stable_plan`: rollout only overrides the greedy choice for clearly better targets
+    /// - `fast`: no rollout simulation
+    pub fn preset(name: &str) -> Result<Self, ConfigError> {
+        let defaults = Self::default();
+        match name {
+            "deadline_focused" => Ok(Self {
+                k: 1.5,
+                no_deadline_urgency_multiplier: 0.25,
+                urgency_floor: 0.05,
+                rollout_beam_k: 3,
+                ..defaults
+            }),
+            "throughput" => Ok(Self {
+                k: 3.0,
+                no_deadline_urgency_multiplier: 1.0,
+                urgency_floor: 0.3,
+                objective: ScheduleObjective {
+                    makespan_weight: 1.0,
+                    ..Default::default()
+                },
+                ..defaults
+            }),
+            "stable_plan" => Ok(Self {
+                rollout_score_ratio_threshold: 1.5,
+                ..defaults
+            }),
+            "fast" => Ok(Self {
+                rollout_enabled: false,
+                ..defaults
+            }),
+            _ => Err(unknown_preset(name)),
+        }
+    }
+
+    /// Load a JSON or TOML profile of field overrides.
+    ///
+    /// An optional `preset` field picks the base configuration (default
+    /// otherwise) and an `objective` table overrides rollout scoring weights.
+    /// Unknown fields, wrong types and out-of-range values are all reported.
+    pub fn from_profile(text: &str) -> Result<Self, ConfigError> {
+        let mut overrides = Overrides::parse(text)?;
+        let mut config = match overrides.take_preset() {
+            Some(name) => Self::preset(&name)?,
+            None => Self::default(),
+        };
+        overrides.apply("k", &mut config.k);
+        overrides.apply(
+            "no_deadline_urgency_multiplier",
+            &mut config.no_deadline_urgency_multiplier,
+        );
+        overrides.apply("urgency_floor", &mut config.urgency_floor);
+        overrides.apply("verbosity", &mut config.verbosity);
+        overrides.apply("rollout_enabled", &mut config.rollout_enabled);
+        overrides.apply(
+            "rollout_score_ratio_threshold",
+            &mut config.rollout_score_ratio_threshold,
+        );
+        overrides.apply(
+            "rollout_max_horizon_days",
+            &mut config.rollout_max_horizon_days,
+        );
+        overrides.apply("rollout_beam_k", &mut config.rollout_beam_k);
+        overrides.apply("rollout_beam_width", &mut config.rollout_beam_width);
+        overrides.apply("rollout_cache_size", &mut config.rollout_cache_size);
+        overrides.apply_str(
+            "rollout_fidelity",
+            &mut config.rollout_fidelity,
+            RolloutFidelity::from_str,
+        );
+        let mut objective = overrides.section("objective");
+        config.objective.apply_overrides(&mut objective);
+        overrides.merge(objective);
+        overrides.apply(
+            "reservation_max_idle_days",
+            &mut config.reservation_max_idle_days,
+        );
+        overrides.apply_str(
+            "work_transform",
+            &mut config.work_transform,
+            WorkTransform::from_str,
+        );
+        overrides.apply("work_exponent", &mut config.work_exponent);
+        overrides.apply(
+            "prefer_fungible_resources",
+            &mut config.prefer_fungible_resources,
+        );
+        overrides.apply_str(
+            "urgency_denominator",
+            &mut config.urgency_denominator,
+            UrgencyDenominator::from_str,
+        );
+        overrides.apply("timeout_secs", &mut config.timeout_secs);
+        overrides.apply_str(
+            "unknown_dependencies",
+            &mut config.unknown_dependencies,
+            UnknownDependencyPolicy::from_str,
+        );
+        overrides.apply_str(
+            "on_unschedulable",
+            &mut config.on_unschedulable,
+            UnschedulablePolicy::from_str,
+        );
+        overrides.apply("max_iterations", &mut config.max_iterations);
+        overrides.apply("trace_rollouts", &mut config.trace_rollouts);
+        overrides.apply(
+            "allow_fixed_in_global_dns",
+            &mut config.allow_fixed_in_global_dns,
+        );
+        overrides.apply_str(
+            "on_fixed_conflict",
+            &mut config.on_fixed_conflict,
+            FixedConflictPolicy::from_str,
+        );
+        overrides.apply("profile", &mut config.profile);
+        overrides.apply(
+            "max_targets_per_iteration",
+            &mut config.max_targets_per_iteration,
+        );
+        overrides.apply_str(
+            "duration_rounding",
+            &mut config.duration_rounding,
+            DurationRounding::from_str,
+        );
+        overrides.finish_with(config.validate())?;
+        Ok(config)
+    }
+
+    /// Check value ranges, reporting every problem found.
+    pub fn validate(&self) -> Result<(), ConfigError> {
+        let mut problems = Problems::default();
+        problems.positive("k", self.k);
+        problems.non_negative(
+            "no_deadline_urgency_multiplier",
+            self.no_deadline_urgency_multiplier,
+        );
+        problems.fraction("urgency_floor", self.urgency_floor);
+        problems.require(self.verbosity <= 3, || {
+            format!("verbosity must be at most 3, got {}", self.verbosity)
+        });
+        problems.positive(
+            "rollout_score_ratio_threshold",
+            self.rollout_score_ratio_threshold,
+        );
+        if let Some(days) = self.rollout_max_horizon_days {
+            problems.require(days > 0, || {
+                format!("rollout_max_horizon_days must be positive, got {}", days)
+            });
+        }
+        problems.positive("objective.priority_scale", self.objective.priority_scale);
+        problems.require(self.rollout_beam_k >= 1, || {
+            "rollout_beam_k must be at least 1".to_string()
+        });
+        if self.work_transform == WorkTransform::Power {
+            problems.non_negative("work_exponent", self.work_exponent);
+        }
+        if let Some(timeout) = self.timeout_secs {
+            problems.non_negative("timeout_secs", timeout);
+        }
+        problems.require(self.max_iterations != Some(0), || {
+            "max_iterations must be positive".to_string()
+        });
+        problems.require(self.max_targets_per_iteration != Some(0), || {
+            "max_targets_per_iteration must be positive".to_string()
+        });
+        problems.finish()
+    }
+}
+
+/// Builder for `CriticalPathConfig` with one named setter per field.
+#[derive(Clone, Debug)]
+pub struct CriticalPathConfigBuilder {
+    config: CriticalPathConfig,
+}
+
+impl CriticalPathConfigBuilder {
+    builder_setters! {
+        k: f64,
+        no_deadline_urgency_multiplier: f64,
+        urgency_floor: f64,
+        verbosity: u8,
+        rollout_enabled: bool,
+        rollout_score_ratio_threshold: f64,
+        rollout_max_horizon_days: Option<i32>,
+        rollout_beam_k: usize,
+        rollout_beam_width: usize,
+        rollout_cache_size: usize,
+        rollout_fidelity: RolloutFidelity,
+        objective: ScheduleObjective,
+        reservation_max_idle_days: u32,
+        work_transform: WorkTransform,
+        work_exponent: f64,
+        prefer_fungible_resources: bool,
+        urgency_denominator: UrgencyDenominator,
+        timeout_secs: Option<f64>,
+        unknown_dependencies: UnknownDependencyPolicy,
+        on_unschedulable: UnschedulablePolicy,
+        max_iterations: Option<usize>,
+        trace_rollouts: bool,
+        allow_fixed_in_global_dns: bool,
+        on_fixed_conflict: FixedConflictPolicy,
+        profile: bool,
+        max_targets_per_iteration: Option<usize>,
+        duration_rounding: DurationRounding,
+    }
+
+    /// Validate and return the configuration.
+    pub fn build(self) -> Result<CriticalPathConfig, ConfigError> {
+        self.config.validate()?;
+        Ok(self.config)
+    }
 }
 
 /// Per-task timing information for critical path calculation.
+#[pyclass]
 #[derive(Clone, Debug, Default)]
 pub struct TaskTiming {
     /// Earliest possible start time (from forward pass).
+    #[pyo3(get)]
     pub earliest_start: f64,
     /// Earliest possible finish time (from forward pass).
+    #[pyo3(get)]
     pub earliest_finish: f64,
     /// Latest allowable start time (from backward pass).
+    #[pyo3(get)]
     pub latest_start: f64,
     /// Latest allowable finish time (from backward pass).
+    #[pyo3(get)]
     pub latest_finish: f64,
     /// Slack = latest_start - earliest_start.
+    #[pyo3(get)]
     pub slack: f64,
 }
 
@@ -485,6 +942,25 @@ impl TaskTiming {
     }
 }
 
+#[pymethods]
+impl TaskTiming {
+    #[getter(is_critical)]
+    fn py_is_critical(&self) -> bool {
+        self.is_critical()
+    }
+
+    fn __repr__(&self) -> String {
+        format!(
+            "TaskTiming(es={}, ef={}, ls={}, lf={}, slack={})",
+            self.earliest_start,
+            self.earliest_finish,
+            self.latest_start,
+            self.latest_finish,
+            self.slack
+        )
+    }
+}
+
 /// Information about a target and its critical path.
 #[derive(Clone, Debug)]
 pub struct TargetInfo {
@@ -553,6 +1029,55 @@ mod tests {
         assert!((config.urgency_floor - 0.1).abs() < 1e-9);
     }
 
+    #[test]
+    fn test_config_builder_validates() {
+        let config = CriticalPathConfig::builder()
+            .k(3.0)
+            .rollout_enabled(false)
+            .work_transform(WorkTransform::Log)
+            .build()
+            .unwrap();
+        assert_eq!(config.k, 3.0);
+        assert!(!config.rollout_enabled);
+        assert_eq!(config.work_transform, WorkTransform::Log);
+        assert_eq!(
+            config.urgency_floor,
+            CriticalPathConfig::default().urgency_floor
+        );
+
+        let err = CriticalPathConfig::builder()
+            .k(0.0)
+            .urgency_floor(1.5)
+            .rollout_beam_k(0)
+            .build()
+            .unwrap_err();
+        assert_eq!(err.problems.len(), 3);
+        assert!(err.problems[0].starts_with("k must be positive"));
+        assert!(err.to_string().contains("urgency_floor must be in [0, 1]"));
+    }
+
+    #[test]
+    fn test_config_profile_overrides_preset() {
+        let profile = r#"
+            preset = "fast"
+            k = 3.0
+            work_transform = "log"
+
+            [objective]
+            makespan_weight = 2.0
+            speed = 1
+        "#;
+        let err = CriticalPathConfig::from_profile(profile).unwrap_err();
+        assert_eq!(err.problems, vec!["unknown setting objective.speed"]);
+
+        let config = CriticalPathConfig::from_profile(&profile.replace("speed = 1", "")).unwrap();
+        assert!(!config.rollout_enabled);
+        assert_eq!(config.k, 3.0);
+        assert_eq!(config.work_transform, WorkTransform::Log);
+        assert_eq!(config.objective.makespan_weight, 2.0);
+        assert_eq!(config.objective.tardiness_weight, 1000.0);
+    }
+
     #[test]
     fn test_task_timing_critical() {
         let timing = TaskTiming {
@@ -0,0 +1,131 @@
+//! Which tasks could start on a given date, without scheduling.
+//!
+//! Answers "what can the team pick up today?": a task is eligible when every
+//! dependency is completed and its `start_after`, `start_on` and blocked
+//! periods allow starting on the date. Resource availability is not checked.
+
+use chrono::NaiveDate;
+use pyo3::prelude::*;
+use rustc_hash::FxHashSet;
+
+use crate::models::{first_unblocked, Task};
+
+/// Whether a task can start on the queried date, and what blocks it if not.
+#[pyclass]
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct TaskEligibility {
+    #[pyo3(get)]
+    pub task_id: String,
+    #[pyo3(get)]
+    pub eligible: bool,
+    /// Dependencies that are not completed yet.
+    #[pyo3(get)]
+    pub waiting_on: Vec<String>,
+    /// One message per blocking constraint; empty when eligible.
+    #[pyo3(get)]
+    pub reasons: Vec<String>,
+}
+
+#[pymethods]
+impl TaskEligibility {
+    fn __repr__(&self) -> String {
+        format!(
+            "TaskEligibility(task_id={:?}, eligible={}, reasons={:?})",
+            self.task_id, self.eligible, self.reasons
+        )
+    }
+}
+
+/// Eligibility on `as_of` of every task not in `completed_task_ids`, sorted by ID.
+pub fn eligible_tasks<'a>(
+    tasks: impl IntoIterator<Item = &'a Task>,
+    completed_task_ids: &FxHashSet<String>,
+    as_of: NaiveDate,
+) -> Vec<TaskEligibility> {
+    let mut statuses: Vec<TaskEligibility> = tasks
+        .into_iter()
+        .filter(|task| !completed_task_ids.contains(&task.id))
+        .map(|task| {
+            let waiting_on: Vec<String> = task
+                .dependencies
+                .iter()
+                .filter(|dep| !completed_task_ids.contains(&dep.entity_id))
+                .map(|dep| dep.entity_id.clone())
+                .collect();
+            let mut reasons = Vec::new();
+            if !waiting_on.is_empty() {
+                reasons.push(format!("waiting on {}", waiting_on.join(", ")));
+            }
+            if let Some(date) = task.start_after.filter(|&date| date > as_of) {
+                reasons.push(format!("start_after {date}"));
+            }
+            if let Some(date) = task.start_on.filter(|&date| date != as_of) {
+                reasons.push(format!("fixed to start on {date}"));
+            }
+            let unblocked = first_unblocked(&task.blocked_periods, as_of);
+            if unblocked != as_of {
+                reasons.push(format!("blocked until {unblocked}"));
+            }
+            TaskEligibility {
+                task_id: task.id.clone(),
+                eligible: reasons.is_empty(),
+                waiting_on,
+                reasons,
+            }
+        })
+        .collect();
+    statuses.sort_by(|a, b| a.task_id.cmp(&b.task_id));
+    statuses
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::Dependency;
+
+    fn d(day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(2025, 1, day).unwrap()
+    }
+
+    #[test]
+    fn test_eligible_tasks_reports_blockers() {
+        let dep = |id: &str| Dependency {
+            entity_id: id.to_string(),
+            ..Default::default()
+        };
+        let tasks = vec![
+            Task {
+                id: "done".to_string(),
+                ..Default::default()
+            },
+            Task {
+                id: "ready".to_string(),
+                dependencies: vec![dep("done")],
+                ..Default::default()
+            },
+            Task {
+                id: "waiting".to_string(),
+                dependencies: vec![dep("done"), dep("ready")],
+                start_after: Some(d(10)),
+                ..Default::default()
+            },
+            Task {
+                id: "away".to_string(),
+                blocked_periods: vec![(d(1), d(3))],
+                ..Default::default()
+            },
+        ];
+        let completed: FxHashSet<String> = ["done".to_string()].into_iter().collect();
+
+        let statuses = eligible_tasks(&tasks, &completed, d(2));
+        let ids: Vec<&str> = statuses.iter().map(|s| s.task_id.as_str()).collect();
+        assert_eq!(ids, ["away", "ready", "waiting"]);
+        assert_eq!(statuses[0].reasons, ["blocked until 2025-01-04"]);
+        assert!(statuses[1].eligible);
+        assert_eq!(statuses[2].waiting_on, ["ready"]);
+        assert_eq!(
+            statuses[2].reasons,
+            ["waiting on ready", "start_after 2025-01-10"]
+        );
+    }
+}
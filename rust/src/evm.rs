@@ -0,0 +1,155 @@
+//! Earned value metrics over a baseline schedule.
+//!
+//! Each scheduled task's budget is its `duration_days`. Planned value accrues
+//! linearly across the task's baseline span; earned value is credited in full
+//! on the task's actual completion date.
+
+use chrono::NaiveDate;
+use pyo3::prelude::*;
+use rustc_hash::FxHashMap;
+
+use crate::models::{AlgorithmResult, ScheduledTask};
+
+/// Daily earned value time series. All vectors are indexed like `dates`.
+#[pyclass]
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct EarnedValueSeries {
+    #[pyo3(get)]
+    pub dates: Vec<NaiveDate>,
+    /// Planned value (PV): baseline work scheduled to be done by each date.
+    #[pyo3(get)]
+    pub planned_value: Vec<f64>,
+    /// Earned value (EV): baseline work of tasks actually completed by each date.
+    #[pyo3(get)]
+    pub earned_value: Vec<f64>,
+    /// Schedule variance (SV = EV - PV).
+    #[pyo3(get)]
+    pub schedule_variance: Vec<f64>,
+    /// Schedule performance index (SPI = EV / PV); 1.0 while PV is zero.
+    #[pyo3(get)]
+    pub schedule_performance_index: Vec<f64>,
+    /// Budget at completion (BAC): total baseline work.
+    #[pyo3(get)]
+    pub budget_at_completion: f64,
+}
+
+#[pymethods]
+impl EarnedValueSeries {
+    fn __repr__(&self) -> String {
+        format!(
+            "EarnedValueSeries(days={}, budget_at_completion={})",
+            self.dates.len(),
+            self.budget_at_completion
+        )
+    }
+}
+
+fn planned_fraction(task: &ScheduledTask, date: NaiveDate) -> f64 {
+    if date < task.start_date {
+        return 0.0;
+    }
+    let span = (task.end_date - task.start_date).num_days();
+    if span <= 0 || date >= task.end_date {
+        return 1.0;
+    }
+    (date - task.start_date).num_days() as f64 / span as f64
+}
+
+/// Compute PV/EV/SV/SPI for every day from the baseline's first start through
+/// `current_date`.
+///
+/// `actual_completions` maps task IDs to the date they finished; completions of
+/// tasks not in the baseline are ignored. Returns an empty series when the
+/// baseline is empty or starts after `current_date`.
+pub fn earned_value(
+    baseline: &AlgorithmResult,
+    actual_completions: &FxHashMap<String, NaiveDate>,
+    current_date: NaiveDate,
+) -> EarnedValueSeries {
+    let budget_at_completion = baseline
+        .scheduled_tasks
+        .iter()
+        .map(|t| t.duration_days)
+        .sum();
+    let mut series = EarnedValueSeries {
+        budget_at_completion,
+        ..Default::default()
+    };
+
+    let Some(first) = baseline.scheduled_tasks.iter().map(|t| t.start_date).min() else {
+        return series;
+    };
+
+    let completed: Vec<(NaiveDate, f64)> = baseline
+        .scheduled_tasks
+        .iter()
+        .filter_map(|t| {
+            actual_completions
+                .get(&t.task_id)
+                .map(|&done| (done, t.duration_days))
+        })
+        .collect();
+
+    for date in first.iter_days().take_while(|d| *d <= current_date) {
+        let pv: f64 = baseline
+            .scheduled_tasks
+            .iter()
+            .map(|t| t.duration_days * planned_fraction(t, date))
+            .sum();
+        let ev: f64 = completed
+            .iter()
+            .filter(|(done, _)| *done <= date)
+            .map(|(_, budget)| budget)
+            .sum();
+
+        series.dates.push(date);
+        series.planned_value.push(pv);
+        series.earned_value.push(ev);
+        series.schedule_variance.push(ev - pv);
+        series
+            .schedule_performance_index
+            .push(if pv > 0.0 { ev / pv } else { 1.0 });
+    }
+
+    series
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Days;
+
+    fn d(day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(2025, 1, day).unwrap()
+    }
+
+    fn scheduled(id: &str, start: NaiveDate, days: u64) -> ScheduledTask {
+        ScheduledTask {
+            task_id: id.to_string(),
+            start_date: start,
+            end_date: start + Days::new(days),
+            duration_days: days as f64,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_earned_value_series() {
+        let baseline = AlgorithmResult {
+            scheduled_tasks: vec![scheduled("a", d(1), 4), scheduled("b", d(5), 2)],
+            ..Default::default()
+        };
+        // "a" finishes a day late
+        let completions: FxHashMap<String, NaiveDate> =
+            [("a".to_string(), d(6))].into_iter().collect();
+
+        let series = earned_value(&baseline, &completions, d(6));
+        assert_eq!(series.budget_at_completion, 6.0);
+        assert_eq!(series.dates, (1..=6).map(d).collect::<Vec<_>>());
+        assert_eq!(series.planned_value, vec![0.0, 1.0, 2.0, 3.0, 4.0, 5.0]);
+        assert_eq!(series.earned_value, vec![0.0, 0.0, 0.0, 0.0, 0.0, 4.0]);
+        assert_eq!(series.schedule_variance[4], -4.0);
+        assert_eq!(series.schedule_performance_index[0], 1.0);
+        assert!((series.schedule_performance_index[5] - 0.8).abs() < 1e-9);
+    }
+}
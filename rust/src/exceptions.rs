@@ -0,0 +1,197 @@
+//! Python exception hierarchy for scheduler failures.
+//!
+//! Every exception derives from `MoucSchedulerError`, itself a `ValueError`, so
+//! callers that caught `ValueError` keep working while new code can catch the
+//! specific failure.
+
+// create_exception! checks pyo3's `gil-refs` feature from inside this crate
+#![allow(unexpected_cfgs)]
+
+use pyo3::create_exception;
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+
+use crate::critical_path::CriticalPathSchedulerError;
+use crate::models::ScheduledTask;
+use crate::scheduler::SchedulerError;
+
+create_exception!(
+    mouc.rust,
+    MoucSchedulerError,
+    PyValueError,
+    "Base class for scheduler failures."
+);
+create_exception!(
+    mouc.rust,
+    CircularDependencyError,
+    MoucSchedulerError,
+    "Tasks depend on each other in a cycle, listed in `cycle`."
+);
+create_exception!(
+    mouc.rust,
+    UnschedulableError,
+    MoucSchedulerError,
+    "Tasks could not be scheduled; their IDs are in `task_ids`."
+);
+create_exception!(
+    mouc.rust,
+    InvalidConfigError,
+    MoucSchedulerError,
+    "A configuration value or option name is invalid."
+);
+create_exception!(
+    mouc.rust,
+    ScheduleCancelledError,
+    MoucSchedulerError,
+    "Scheduling was cancelled or timed out; the tasks placed so far are in `partial`."
+);
+
+/// A scheduler failure, ready to raise as the matching Python exception.
+///
+/// Unlike `PyErr` it can be built and cloned without the GIL, so background
+/// runs can hold on to it until `join()`.
+#[derive(Clone, Debug, PartialEq)]
+pub enum ScheduleFailure {
+    Cycle(String, Vec<String>),
+    Unschedulable(String, Vec<String>),
+    InvalidConfig(String),
+    Cancelled(String, Vec<ScheduledTask>),
+    Other(String),
+}
+
+impl From<&SchedulerError> for ScheduleFailure {
+    fn from(err: &SchedulerError) -> Self {
+        let message = err.to_string();
+        match err {
+            SchedulerError::CircularDependency(cycle) => Self::Cycle(message, cycle.clone()),
+            SchedulerError::FailedToSchedule(ids) => Self::Unschedulable(message, ids.clone()),
+            SchedulerError::Cancelled(partial) => Self::Cancelled(message, partial.clone()),
+            SchedulerError::InvalidConfig(_)
+            | SchedulerError::UnknownStrategy(_)
+            | SchedulerError::ResourceGroupCycle(_) => Self::InvalidConfig(message),
+            _ => Self::Other(message),
+        }
+    }
+}
+
+impl From<&CriticalPathSchedulerError> for ScheduleFailure {
+    fn from(err: &CriticalPathSchedulerError) -> Self {
+        let message = err.to_string();
+        match err {
+            CriticalPathSchedulerError::CircularDependency(cycle) => {
+                Self::Cycle(message, cycle.clone())
+            }
+            CriticalPathSchedulerError::FailedToSchedule(ids) => {
+                Self::Unschedulable(message, ids.clone())
+            }
+            CriticalPathSchedulerError::Cancelled(partial) => {
+                Self::Cancelled(message, partial.clone())
+            }
+            CriticalPathSchedulerError::InvalidConfig(_)
+            | CriticalPathSchedulerError::ResourceGroupCycle(_)
+            | CriticalPathSchedulerError::UnknownStrategy(_) => Self::InvalidConfig(message),
+            _ => Self::Other(message),
+        }
+    }
+}
+
+impl From<SchedulerError> for PyErr {
+    fn from(err: SchedulerError) -> Self {
+        ScheduleFailure::from(&err).into()
+    }
+}
+
+impl From<CriticalPathSchedulerError> for PyErr {
+    fn from(err: CriticalPathSchedulerError) -> Self {
+        ScheduleFailure::from(&err).into()
+    }
+}
+
+impl From<ScheduleFailure> for PyErr {
+    fn from(failure: ScheduleFailure) -> Self {
+        Python::with_gil(|py| {
+            let (err, attribute) = match failure {
+                ScheduleFailure::Cycle(message, cycle) => (
+                    CircularDependencyError::new_err(message),
+                    Some(("cycle", cycle.into_py(py))),
+                ),
+                ScheduleFailure::Unschedulable(message, ids) => (
+                    UnschedulableError::new_err(message),
+                    Some(("task_ids", ids.into_py(py))),
+                ),
+                ScheduleFailure::InvalidConfig(message) => {
+                    (InvalidConfigError::new_err(message), None)
+                }
+                ScheduleFailure::Cancelled(message, partial) => (
+                    ScheduleCancelledError::new_err(message),
+                    Some(("partial", partial.into_py(py))),
+                ),
+                ScheduleFailure::Other(message) => (MoucSchedulerError::new_err(message), None),
+            };
+            if let Some((name, value)) = attribute {
+                // Setting an attribute on a fresh exception cannot fail
+                let _ = err.value_bound(py).setattr(name, value);
+            }
+            err
+        })
+    }
+}
+
+/// Register the exception classes on the module.
+pub(crate) fn register(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    let py = m.py();
+    m.add(
+        "MoucSchedulerError",
+        py.get_type_bound::<MoucSchedulerError>(),
+    )?;
+    m.add(
+        "CircularDependencyError",
+        py.get_type_bound::<CircularDependencyError>(),
+    )?;
+    m.add(
+        "UnschedulableError",
+        py.get_type_bound::<UnschedulableError>(),
+    )?;
+    m.add(
+        "InvalidConfigError",
+        py.get_type_bound::<InvalidConfigError>(),
+    )?;
+    m.add(
+        "ScheduleCancelledError",
+        py.get_type_bound::<ScheduleCancelledError>(),
+    )?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_scheduler_errors_map_to_failures() {
+        let err = SchedulerError::FailedToSchedule(vec!["a".to_string(), "b".to_string()]);
+        assert_eq!(
+            ScheduleFailure::from(&err),
+            ScheduleFailure::Unschedulable(err.to_string(), vec!["a".to_string(), "b".to_string()])
+        );
+        let err = SchedulerError::UnknownStrategy("fastest".to_string());
+        assert_eq!(
+            ScheduleFailure::from(&err),
+            ScheduleFailure::InvalidConfig(err.to_string())
+        );
+        let err = CriticalPathSchedulerError::CircularDependency(vec!["x".to_string()]);
+        assert!(matches!(
+            ScheduleFailure::from(&err),
+            ScheduleFailure::Cycle(_, cycle) if cycle == ["x"]
+        ));
+        let partial = vec![ScheduledTask {
+            task_id: "a".to_string(),
+            ..Default::default()
+        }];
+        let err = SchedulerError::Cancelled(partial.clone());
+        assert_eq!(
+            ScheduleFailure::from(&err),
+            ScheduleFailure::Cancelled(err.to_string(), partial)
+        );
+    }
+}
@@ -0,0 +1,99 @@
+//! Apache Arrow export (requires the `arrow` feature).
+//!
+//! Produces one row per (task, resource) pair so the batch loads directly
+//! into pandas/polars without per-object conversion. Tasks with no resources
+//! get a single row with a null `resource`.
+
+use std::sync::Arc;
+
+use arrow::array::{ArrayRef, Date32Array, Float64Array, StringArray};
+use arrow::datatypes::{DataType, Field, Schema};
+use arrow::error::ArrowError;
+use arrow::record_batch::RecordBatch;
+use chrono::NaiveDate;
+
+use crate::models::AlgorithmResult;
+
+fn days_since_epoch(date: NaiveDate) -> i32 {
+    (date - NaiveDate::default()).num_days() as i32
+}
+
+/// Schema of the batches produced by `to_record_batch`.
+pub fn schedule_schema() -> Schema {
+    Schema::new(vec![
+        Field::new("task_id", DataType::Utf8, false),
+        Field::new("start", DataType::Date32, false),
+        Field::new("end", DataType::Date32, false),
+        Field::new("resource", DataType::Utf8, true),
+        Field::new("duration", DataType::Float64, false),
+    ])
+}
+
+/// Convert a schedule into a single Arrow `RecordBatch`.
+pub fn to_record_batch(result: &AlgorithmResult) -> Result<RecordBatch, ArrowError> {
+    let rows: Vec<(&str, NaiveDate, NaiveDate, Option<&str>, f64)> = result
+        .scheduled_tasks
+        .iter()
+        .flat_map(|t| {
+            let resources: Vec<Option<&str>> = if t.resources.is_empty() {
+                vec![None]
+            } else {
+                t.resources.iter().map(|r| Some(r.as_str())).collect()
+            };
+            resources.into_iter().map(move |resource| {
+                (
+                    t.task_id.as_str(),
+                    t.start_date,
+                    t.end_date,
+                    resource,
+                    t.duration_days,
+                )
+            })
+        })
+        .collect();
+
+    let columns: Vec<ArrayRef> = vec![
+        Arc::new(StringArray::from_iter_values(rows.iter().map(|r| r.0))),
+        Arc::new(Date32Array::from_iter_values(
+            rows.iter().map(|r| days_since_epoch(r.1)),
+        )),
+        Arc::new(Date32Array::from_iter_values(
+            rows.iter().map(|r| days_since_epoch(r.2)),
+        )),
+        Arc::new(rows.iter().map(|r| r.3).collect::<StringArray>()),
+        Arc::new(Float64Array::from_iter_values(rows.iter().map(|r| r.4))),
+    ];
+    RecordBatch::try_new(Arc::new(schedule_schema()), columns)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::export::test_support::sample;
+    use arrow::array::Array;
+
+    #[test]
+    fn test_record_batch() {
+        let (result, _) = sample();
+        let batch = to_record_batch(&result).unwrap();
+        assert_eq!(batch.num_rows(), 3);
+        assert_eq!(batch.schema().as_ref(), &schedule_schema());
+
+        let starts = batch
+            .column(1)
+            .as_any()
+            .downcast_ref::<Date32Array>()
+            .unwrap();
+        assert_eq!(
+            starts.value_as_date(1),
+            Some(NaiveDate::from_ymd_opt(2025, 1, 1).unwrap())
+        );
+        let resources = batch
+            .column(3)
+            .as_any()
+            .downcast_ref::<StringArray>()
+            .unwrap();
+        assert_eq!(resources.value(0), "bob");
+        assert!(resources.is_null(2));
+    }
+}
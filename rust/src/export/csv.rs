@@ -0,0 +1,76 @@
+//! CSV export, one row per scheduled task.
+
+use super::{rows, Row};
+use crate::models::{AlgorithmResult, Task};
+
+const HEADER: &str =
+    "task_id,start_date,end_date,duration_days,resources,dependencies,priority,end_before,in_progress";
+
+fn escape(field: &str) -> String {
+    if field.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
+
+fn record(row: &Row) -> String {
+    let s = row.scheduled;
+    let fields = [
+        s.task_id.clone(),
+        s.start_date.to_string(),
+        s.end_date.to_string(),
+        s.duration_days.to_string(),
+        s.resources.join(";"),
+        row.dependency_ids().join(";"),
+        row.task
+            .and_then(|t| t.priority)
+            .map(|p| p.to_string())
+            .unwrap_or_default(),
+        row.task
+            .and_then(|t| t.end_before)
+            .map(|d| d.to_string())
+            .unwrap_or_default(),
+        s.in_progress.to_string(),
+    ];
+    fields
+        .iter()
+        .map(|f| escape(f))
+        .collect::<Vec<_>>()
+        .join(",")
+}
+
+/// Render the schedule as CSV with a header row, ordered by start date.
+///
+/// Columns: `task_id, start_date, end_date, duration_days, resources,
+/// dependencies, priority, end_before, in_progress`. List columns are
+/// `;`-separated; missing values are empty.
+pub fn to_csv(result: &AlgorithmResult, tasks: &[Task]) -> String {
+    let mut out = String::from(HEADER);
+    out.push('\n');
+    for row in rows(result, tasks) {
+        out.push_str(&record(&row));
+        out.push('\n');
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::export::test_support::sample;
+
+    #[test]
+    fn test_csv_export() {
+        let (result, tasks) = sample();
+        let csv = to_csv(&result, &tasks);
+        let lines: Vec<&str> = csv.lines().collect();
+        assert_eq!(lines[0], HEADER);
+        assert_eq!(lines[1], "design,2025-01-01,2025-01-04,3,alice,,80,,false");
+        assert_eq!(
+            lines[2],
+            "\"build, v2\",2025-01-04,2025-01-06,2,bob,design,,2025-01-10,false"
+        );
+        assert_eq!(lines[3], "launch,2025-01-06,2025-01-06,0,,,,,false");
+    }
+}
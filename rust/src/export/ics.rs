@@ -0,0 +1,217 @@
+//! iCalendar (RFC 5545) export, one calendar per resource.
+//!
+//! Scheduled tasks become all-day `VEVENT`s; DNS periods become opaque
+//! "Unavailable" events so subscribers see the resource as busy.
+
+use std::collections::BTreeMap;
+
+use chrono::NaiveDate;
+
+use super::{rows, Row};
+use crate::models::{AlgorithmResult, Task};
+use crate::scheduler::ResourceConfig;
+use crate::validation::{offset_date, DateOverflow};
+
+const PRODID: &str = "-//mouc//schedule export//EN";
+
+fn escape(text: &str) -> String {
+    text.replace('\\', "\\\\")
+        .replace(';', "\\;")
+        .replace(',', "\\,")
+        .replace('\n', "\\n")
+}
+
+/// Fold content lines longer than 75 octets, per RFC 5545 section 3.1.
+fn push_line(out: &mut String, line: &str) {
+    let mut width = 0;
+    for c in line.chars() {
+        if width + c.len_utf8() > 75 {
+            out.push_str("\r\n ");
+            width = 1;
+        }
+        out.push(c);
+        width += c.len_utf8();
+    }
+    out.push_str("\r\n");
+}
+
+fn date(d: NaiveDate) -> String {
+    d.format("%Y%m%d").to_string()
+}
+
+fn push_event(
+    out: &mut String,
+    uid: &str,
+    summary: &str,
+    start: NaiveDate,
+    end_exclusive: NaiveDate,
+    description: Option<&str>,
+) {
+    push_line(out, "BEGIN:VEVENT");
+    push_line(out, &format!("UID:{}", escape(uid)));
+    push_line(out, &format!("DTSTAMP:{}T000000Z", date(start)));
+    push_line(out, &format!("DTSTART;VALUE=DATE:{}", date(start)));
+    push_line(out, &format!("DTEND;VALUE=DATE:{}", date(end_exclusive)));
+    push_line(out, &format!("SUMMARY:{}", escape(summary)));
+    if let Some(description) = description {
+        push_line(out, &format!("DESCRIPTION:{}", escape(description)));
+    }
+    push_line(out, "TRANSP:OPAQUE");
+    push_line(out, "END:VEVENT");
+}
+
+fn task_description(row: &Row) -> String {
+    let mut lines = vec![format!("Duration: {} days", row.scheduled.duration_days)];
+    let deps = row.dependency_ids();
+    if !deps.is_empty() {
+        lines.push(format!("Depends on: {}", deps.join(", ")));
+    }
+    if let Some(deadline) = row.task.and_then(|t| t.end_before) {
+        lines.push(format!("Deadline: {}", deadline));
+    }
+    lines.join("\n")
+}
+
+/// Render one resource's calendar.
+///
+/// `dns_periods` are inclusive `(start, end)` date ranges, as in `ResourceConfig`.
+/// Fails if an event's exclusive end falls past the last representable date.
+pub fn to_ics(
+    result: &AlgorithmResult,
+    tasks: &[Task],
+    resource: &str,
+    dns_periods: &[(NaiveDate, NaiveDate)],
+) -> Result<String, DateOverflow> {
+    let mut out = String::new();
+    push_line(&mut out, "BEGIN:VCALENDAR");
+    push_line(&mut out, "VERSION:2.0");
+    push_line(&mut out, &format!("PRODID:{}", PRODID));
+    push_line(&mut out, &format!("X-WR-CALNAME:{}", escape(resource)));
+
+    for row in rows(result, tasks) {
+        let s = row.scheduled;
+        if !s.resources.iter().any(|r| r == resource) {
+            continue;
+        }
+        // All-day DTEND is exclusive; keep milestones one day long
+        let end = s.end_date.max(offset_date(s.start_date, 1.0)?);
+        push_event(
+            &mut out,
+            &format!("{}/{}@mouc", s.task_id, resource),
+            &s.task_id,
+            s.start_date,
+            end,
+            Some(&task_description(&row)),
+        );
+    }
+
+    for (start, end) in dns_periods {
+        push_event(
+            &mut out,
+            &format!("dns-{}-{}/{}@mouc", date(*start), date(*end), resource),
+            "Unavailable",
+            *start,
+            offset_date(*end, 1.0)?,
+            None,
+        );
+    }
+
+    push_line(&mut out, "END:VCALENDAR");
+    Ok(out)
+}
+
+/// Render a calendar for every resource that has scheduled work or DNS periods.
+///
+/// Each resource's DNS periods come from `resource_config` (if given) plus
+/// `global_dns_periods`. Returns calendars keyed by resource name.
+pub fn to_ics_by_resource(
+    result: &AlgorithmResult,
+    tasks: &[Task],
+    resource_config: Option<&ResourceConfig>,
+    global_dns_periods: &[(NaiveDate, NaiveDate)],
+) -> Result<BTreeMap<String, String>, DateOverflow> {
+    let mut resources: Vec<&str> = result
+        .scheduled_tasks
+        .iter()
+        .flat_map(|t| t.resources.iter().map(|r| r.as_str()))
+        .collect();
+    if let Some(rc) = resource_config {
+        resources.extend(rc.resource_order.iter().map(|r| r.as_str()));
+    }
+    resources.sort_unstable();
+    resources.dedup();
+
+    resources
+        .into_iter()
+        .map(|resource| {
+            let mut dns: Vec<(NaiveDate, NaiveDate)> = global_dns_periods.to_vec();
+            if let Some(periods) = resource_config.and_then(|rc| rc.dns_periods.get(resource)) {
+                dns.extend(periods.iter().copied());
+            }
+            dns.sort();
+            dns.dedup();
+            Ok((resource.to_string(), to_ics(result, tasks, resource, &dns)?))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::export::test_support::{d, sample};
+
+    #[test]
+    fn test_ics_per_resource() {
+        let (result, tasks) = sample();
+        let mut config = ResourceConfig {
+            resource_order: vec!["alice".to_string(), "bob".to_string(), "carol".to_string()],
+            ..Default::default()
+        };
+        config
+            .dns_periods
+            .insert("alice".to_string(), vec![(d(20), d(21))]);
+
+        let calendars = to_ics_by_resource(&result, &tasks, Some(&config), &[]).unwrap();
+        assert_eq!(
+            calendars.keys().collect::<Vec<_>>(),
+            vec!["alice", "bob", "carol"]
+        );
+
+        let alice = &calendars["alice"];
+        assert!(alice.starts_with("BEGIN:VCALENDAR\r\nVERSION:2.0\r\n"));
+        assert!(alice.contains("SUMMARY:design\r\n"));
+        assert!(alice.contains("DTSTART;VALUE=DATE:20250101\r\nDTEND;VALUE=DATE:20250104\r\n"));
+        assert!(alice.contains("SUMMARY:Unavailable\r\n"));
+        assert!(alice.contains("DTSTART;VALUE=DATE:20250120\r\nDTEND;VALUE=DATE:20250122\r\n"));
+        assert!(!alice.contains("build"));
+
+        let bob = &calendars["bob"];
+        assert!(bob.contains("SUMMARY:build\\, v2\r\n"));
+        assert!(bob.contains(
+            "DESCRIPTION:Duration: 2 days\\nDepends on: design\\nDeadline: 2025-01-10\r\n"
+        ));
+        assert_eq!(calendars["carol"].matches("BEGIN:VEVENT").count(), 0);
+    }
+
+    #[test]
+    fn test_ics_out_of_range_is_an_error() {
+        let (mut result, tasks) = sample();
+        let dns = [(d(20), NaiveDate::MAX)];
+        assert!(to_ics_by_resource(&result, &tasks, None, &dns).is_err());
+
+        let last = &mut result.scheduled_tasks[0];
+        (last.start_date, last.end_date) = (NaiveDate::MAX, NaiveDate::MAX);
+        let resource = last.resources[0].clone();
+        assert!(to_ics(&result, &tasks, &resource, &[]).is_err());
+    }
+
+    #[test]
+    fn test_long_lines_are_folded() {
+        let mut out = String::new();
+        push_line(&mut out, &format!("SUMMARY:{}", "x".repeat(100)));
+        let lines: Vec<&str> = out.split("\r\n").collect();
+        assert_eq!(lines[0].len(), 75);
+        assert!(lines[1].starts_with(' '));
+        assert_eq!(lines[1].len(), 1 + 108 - 75);
+    }
+}
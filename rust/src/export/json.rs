@@ -0,0 +1,127 @@
+//! JSON export.
+//!
+//! Schema (all dates ISO `YYYY-MM-DD`):
+//!
+//! ```text
+//! {
+//!   "metadata": { "<key>": "<value>", ... },
+//!   "tasks": [
+//!     {
+//!       "id": string,
+//!       "start_date": string,
+//!       "end_date": string,
+//!       "duration_days": number,
+//!       "resources": [string],
+//!       "dependencies": [string],
+//!       "priority": number | null,
+//!       "end_before": string | null,
+//!       "in_progress": boolean
+//!     }
+//!   ]
+//! }
+//! ```
+//!
+//! Tasks are ordered by start date, then ID; metadata keys are sorted.
+
+use super::{rows, Row};
+use crate::models::{AlgorithmResult, Task};
+
+fn string(value: &str) -> String {
+    let mut out = String::with_capacity(value.len() + 2);
+    out.push('"');
+    for c in value.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            c if (c as u32) < 0x20 => out.push_str(&format!("\\u{:04x}", c as u32)),
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+    out
+}
+
+fn number(value: f64) -> String {
+    if value.is_finite() {
+        value.to_string()
+    } else {
+        "null".to_string()
+    }
+}
+
+fn array<'a>(values: impl IntoIterator<Item = &'a str>) -> String {
+    let items: Vec<String> = values.into_iter().map(string).collect();
+    format!("[{}]", items.join(", "))
+}
+
+fn task_object(row: &Row) -> String {
+    let s = row.scheduled;
+    let priority = row
+        .task
+        .and_then(|t| t.priority)
+        .map_or("null".to_string(), |p| p.to_string());
+    let end_before = row
+        .task
+        .and_then(|t| t.end_before)
+        .map_or("null".to_string(), |d| string(&d.to_string()));
+    format!(
+        "{{\"id\": {}, \"start_date\": \"{}\", \"end_date\": \"{}\", \"duration_days\": {}, \
+         \"resources\": {}, \"dependencies\": {}, \"priority\": {}, \"end_before\": {}, \
+         \"in_progress\": {}}}",
+        string(&s.task_id),
+        s.start_date,
+        s.end_date,
+        number(s.duration_days),
+        array(s.resources.iter().map(|r| r.as_str())),
+        array(row.dependency_ids()),
+        priority,
+        end_before,
+        s.in_progress
+    )
+}
+
+/// Render the schedule as JSON following the schema in the module docs.
+pub fn to_json(result: &AlgorithmResult, tasks: &[Task]) -> String {
+    let mut metadata: Vec<(&String, &String)> = result.algorithm_metadata.iter().collect();
+    metadata.sort();
+    let metadata: Vec<String> = metadata
+        .into_iter()
+        .map(|(k, v)| format!("{}: {}", string(k), string(v)))
+        .collect();
+    let task_objects: Vec<String> = rows(result, tasks)
+        .iter()
+        .map(|row| format!("    {}", task_object(row)))
+        .collect();
+
+    format!(
+        "{{\n  \"metadata\": {{{}}},\n  \"tasks\": [\n{}\n  ]\n}}\n",
+        metadata.join(", "),
+        task_objects.join(",\n")
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::export::test_support::sample;
+
+    #[test]
+    fn test_json_export() {
+        let (mut result, tasks) = sample();
+        result
+            .algorithm_metadata
+            .insert("algorithm".to_string(), "parallel_sgs".to_string());
+        let json = to_json(&result, &tasks);
+
+        assert!(json.contains("\"metadata\": {\"algorithm\": \"parallel_sgs\"}"));
+        assert!(json.contains(
+            "{\"id\": \"build, v2\", \"start_date\": \"2025-01-04\", \"end_date\": \"2025-01-06\", \
+             \"duration_days\": 2, \"resources\": [\"bob\"], \"dependencies\": [\"design\"], \
+             \"priority\": null, \"end_before\": \"2025-01-10\", \"in_progress\": false}"
+        ));
+        assert_eq!(string("a\"b\\c\n"), "\"a\\\"b\\\\c\\n\"");
+    }
+}
@@ -0,0 +1,102 @@
+//! Mermaid gantt chart export.
+
+use std::collections::BTreeMap;
+
+use super::rows;
+use crate::models::{AlgorithmResult, Task};
+
+const UNASSIGNED: &str = "Unassigned";
+
+/// Mermaid treats `:` as the title separator and `#`/`;` as syntax.
+fn title(text: &str) -> String {
+    text.replace([':', '#', ';'], " ")
+}
+
+/// Section title for unassigned tasks, numbered if a resource already uses it.
+fn unassigned_title<'a>(resources: impl Iterator<Item = &'a str> + Clone) -> String {
+    let taken = |label: &str| resources.clone().any(|name| title(name) == label);
+    let mut label = UNASSIGNED.to_string();
+    let mut n = 1;
+    while taken(&label) {
+        n += 1;
+        label = format!("{} {}", UNASSIGNED, n);
+    }
+    label
+}
+
+/// Render the schedule as a Mermaid `gantt` diagram.
+///
+/// Tasks are grouped into one section per primary (first) resource, with
+/// unassigned tasks last. Zero-duration tasks become milestones and
+/// in-progress tasks are tagged `active`.
+pub fn to_mermaid(result: &AlgorithmResult, tasks: &[Task]) -> String {
+    let mut sections: BTreeMap<&str, Vec<String>> = BTreeMap::new();
+    let mut unassigned: Vec<String> = Vec::new();
+
+    for row in rows(result, tasks) {
+        let s = row.scheduled;
+        let line = if s.duration_days == 0.0 {
+            format!("    {} :milestone, {}, 0d", title(&s.task_id), s.start_date)
+        } else {
+            let tag = if s.in_progress { "active, " } else { "" };
+            format!(
+                "    {} :{}{}, {}",
+                title(&s.task_id),
+                tag,
+                s.start_date,
+                s.end_date
+            )
+        };
+        match s.resources.first() {
+            Some(resource) => sections.entry(resource.as_str()).or_default().push(line),
+            None => unassigned.push(line),
+        }
+    }
+
+    let mut out = String::from("gantt\n    dateFormat YYYY-MM-DD\n");
+    let unassigned_label = unassigned_title(sections.keys().copied());
+    let named = sections.iter().map(|(name, lines)| (title(name), lines));
+    let unassigned = (!unassigned.is_empty()).then_some((unassigned_label, &unassigned));
+    for (name, lines) in named.chain(unassigned) {
+        out.push_str(&format!("    section {}\n", name));
+        for line in lines {
+            out.push_str(line);
+            out.push('\n');
+        }
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::export::test_support::sample;
+
+    #[test]
+    fn test_mermaid_export() {
+        let (result, tasks) = sample();
+        assert_eq!(
+            to_mermaid(&result, &tasks),
+            "gantt\n    dateFormat YYYY-MM-DD\n\
+             \x20   section alice\n\
+             \x20   design :2025-01-01, 2025-01-04\n\
+             \x20   section bob\n\
+             \x20   build, v2 :2025-01-04, 2025-01-06\n\
+             \x20   section Unassigned\n\
+             \x20   launch :milestone, 2025-01-06, 0d\n"
+        );
+    }
+
+    #[test]
+    fn test_mermaid_milestones_and_unassigned_section() {
+        let (mut result, tasks) = sample();
+        // A sub-day task starting and ending on one day is not a milestone
+        result.scheduled_tasks[1].end_date = result.scheduled_tasks[1].start_date;
+        result.scheduled_tasks[1].duration_days = 0.5;
+        result.scheduled_tasks[1].resources = vec!["Unassigned".to_string()];
+        let out = to_mermaid(&result, &tasks);
+        assert!(out.contains("    design :2025-01-01, 2025-01-01\n"));
+        assert!(out.contains("    section Unassigned\n"));
+        assert!(out.contains("    section Unassigned 2\n    launch :milestone"));
+    }
+}
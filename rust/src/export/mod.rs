@@ -0,0 +1,116 @@
+//! Exporters that render an `AlgorithmResult` in formats other tools understand.
+//!
+//! Every exporter takes the schedule plus the tasks it was built from, so task
+//! metadata (dependencies, priority, deadline) can be included alongside dates.
+//! Scheduled tasks with no matching `Task` (e.g. buffer pseudo-tasks) are still
+//! exported, just without that metadata.
+
+#[cfg(feature = "arrow")]
+mod arrow;
+mod csv;
+mod ics;
+mod json;
+mod mermaid;
+
+#[cfg(feature = "arrow")]
+pub use arrow::{schedule_schema, to_record_batch};
+pub use csv::to_csv;
+pub use ics::{to_ics, to_ics_by_resource};
+pub use json::to_json;
+pub use mermaid::to_mermaid;
+
+use rustc_hash::FxHashMap;
+
+use crate::models::{AlgorithmResult, ScheduledTask, Task};
+
+/// A scheduled task joined with its source task, in start order.
+struct Row<'a> {
+    scheduled: &'a ScheduledTask,
+    task: Option<&'a Task>,
+}
+
+impl Row<'_> {
+    fn dependency_ids(&self) -> Vec<&str> {
+        self.task
+            .map(|t| {
+                t.dependencies
+                    .iter()
+                    .map(|dep| dep.entity_id.as_str())
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+}
+
+fn rows<'a>(result: &'a AlgorithmResult, tasks: &'a [Task]) -> Vec<Row<'a>> {
+    let by_id: FxHashMap<&str, &Task> = tasks.iter().map(|t| (t.id.as_str(), t)).collect();
+    let mut rows: Vec<Row> = result
+        .scheduled_tasks
+        .iter()
+        .map(|scheduled| Row {
+            scheduled,
+            task: by_id.get(scheduled.task_id.as_str()).copied(),
+        })
+        .collect();
+    rows.sort_by(|a, b| {
+        a.scheduled
+            .start_date
+            .cmp(&b.scheduled.start_date)
+            .then_with(|| a.scheduled.task_id.cmp(&b.scheduled.task_id))
+    });
+    rows
+}
+
+#[cfg(test)]
+pub(crate) mod test_support {
+    use chrono::{Days, NaiveDate};
+
+    use crate::models::{AlgorithmResult, Dependency, ScheduledTask, Task};
+
+    pub fn d(day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(2025, 1, day).unwrap()
+    }
+
+    /// "design" (alice) then "build, v2" (bob), plus a "launch" milestone.
+    pub fn sample() -> (AlgorithmResult, Vec<Task>) {
+        let tasks = vec![
+            Task {
+                id: "design".to_string(),
+                duration_days: 3.0,
+                priority: Some(80),
+                ..Default::default()
+            },
+            Task {
+                id: "build, v2".to_string(),
+                duration_days: 2.0,
+                dependencies: vec![Dependency {
+                    entity_id: "design".to_string(),
+                    ..Default::default()
+                }],
+                end_before: Some(d(10)),
+                ..Default::default()
+            },
+        ];
+        let scheduled = |id: &str, start: u32, days: u64, resource: &str| ScheduledTask {
+            task_id: id.to_string(),
+            start_date: d(start),
+            end_date: d(start) + Days::new(days),
+            duration_days: days as f64,
+            resources: if resource.is_empty() {
+                vec![]
+            } else {
+                vec![resource.to_string()]
+            },
+            ..Default::default()
+        };
+        let result = AlgorithmResult {
+            scheduled_tasks: vec![
+                scheduled("build, v2", 4, 2, "bob"),
+                scheduled("design", 1, 3, "alice"),
+                scheduled("launch", 6, 0, ""),
+            ],
+            ..Default::default()
+        };
+        (result, tasks)
+    }
+}
@@ -0,0 +1,646 @@
+//! Dependency graph utilities.
+//!
+//! Traversals over the task dependency graph that the schedulers use
+//! internally, exposed for callers that want to reason about the graph without
+//! scheduling: transitive dependents and dependencies, a deterministic
+//! topological order, and the impact of a task slipping, either on dependency
+//! chains alone or on an existing schedule, and the incomplete work blocking a
+//! task.
+
+use chrono::NaiveDate;
+use pyo3::prelude::*;
+use rustc_hash::{FxHashMap, FxHashSet};
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
+use thiserror::Error;
+
+use crate::backward_pass::{canonical_cycle, format_cycle, trace_cycle};
+use crate::models::{AlgorithmResult, ScheduledTask, Task};
+use crate::validation::{offset_date, DateOverflow};
+
+/// Pre-computed reverse dependency map: task_id -> Vec<(dependent_id, lag)>
+/// This allows O(1) lookup of all tasks that depend on a given task.
+pub type DependentsMap<'a> = FxHashMap<&'a str, Vec<(&'a str, f64)>>;
+
+/// Build a global dependents map from all tasks.
+/// This should be computed once and reused across multiple critical path calculations.
+pub fn build_dependents_map(tasks: &FxHashMap<String, Task>) -> DependentsMap<'_> {
+    // Pre-size: most tasks have at least one dependency
+    let mut dependents: DependentsMap =
+        FxHashMap::with_capacity_and_hasher(tasks.len(), Default::default());
+    for (task_id, task) in tasks {
+        for dep in &task.dependencies {
+            dependents
+                .entry(&dep.entity_id)
+                .or_default()
+                .push((task_id.as_str(), dep.lag_days));
+        }
+    }
+    dependents
+}
+
+/// Errors from graph queries.
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+pub enum GraphError {
+    /// Holds the task IDs on the cycle, each depending on the next.
+    #[error("Circular dependency detected in task graph: {}", format_cycle(.0))]
+    CircularDependency(Vec<String>),
+    #[error("Unknown task: {0}")]
+    UnknownTask(String),
+    #[error("{0}")]
+    DateOverflow(String),
+}
+
+impl From<DateOverflow> for GraphError {
+    fn from(err: DateOverflow) -> Self {
+        GraphError::DateOverflow(err.to_string())
+    }
+}
+
+impl GraphError {
+    /// The offending dependency cycle, if this error describes one.
+    pub fn cycle(&self) -> Option<&[String]> {
+        match self {
+            GraphError::CircularDependency(cycle) => Some(cycle),
+            GraphError::UnknownTask(_) | GraphError::DateOverflow(_) => None,
+        }
+    }
+}
+
+/// A target whose earliest finish moves when a task slips.
+#[pyclass]
+#[derive(Clone, Debug, PartialEq)]
+pub struct TargetImpact {
+    #[pyo3(get)]
+    pub target_id: String,
+    /// Days the target's earliest finish moves; less than the slip when slack absorbs part of it.
+    #[pyo3(get)]
+    pub delay_days: f64,
+}
+
+#[pymethods]
+impl TargetImpact {
+    fn __repr__(&self) -> String {
+        format!(
+            "TargetImpact(target_id={}, delay_days={})",
+            self.target_id, self.delay_days
+        )
+    }
+}
+
+/// Effect of a task slipping on an existing schedule.
+#[pyclass]
+#[derive(Clone, Debug, Default)]
+pub struct SlipAnalysis {
+    /// Tasks whose dates moved, with their new dates, sorted by task ID.
+    #[pyo3(get)]
+    pub affected_tasks: Vec<ScheduledTask>,
+    /// Tasks that met their `end_before` before the slip and now miss it, sorted.
+    #[pyo3(get)]
+    pub missed_deadlines: Vec<String>,
+}
+
+#[pymethods]
+impl SlipAnalysis {
+    fn __repr__(&self) -> String {
+        format!(
+            "SlipAnalysis(affected={}, missed_deadlines={:?})",
+            self.affected_tasks.len(),
+            self.missed_deadlines
+        )
+    }
+}
+
+/// An incomplete task standing between a task and its start.
+#[pyclass]
+#[derive(Clone, Debug, PartialEq)]
+pub struct Blocker {
+    #[pyo3(get)]
+    pub task_id: String,
+    /// Tasks on the chain that depend on this one directly, sorted.
+    #[pyo3(get)]
+    pub blocks: Vec<String>,
+    /// End date in the given schedule; None when unscheduled.
+    #[pyo3(get)]
+    pub expected_end: Option<NaiveDate>,
+    /// False for dependencies on tasks that are neither known nor completed.
+    #[pyo3(get)]
+    pub known: bool,
+}
+
+#[pymethods]
+impl Blocker {
+    fn __repr__(&self) -> String {
+        format!(
+            "Blocker(task_id={}, blocks={:?}, expected_end={:?})",
+            self.task_id, self.blocks, self.expected_end
+        )
+    }
+}
+
+fn index_tasks(tasks: &[Task]) -> FxHashMap<&str, &Task> {
+    tasks.iter().map(|t| (t.id.as_str(), t)).collect()
+}
+
+/// Walk edges from `task_id` and return every task reached, sorted.
+fn reachable<'a>(task_id: &'a str, edges: &FxHashMap<&'a str, Vec<&'a str>>) -> Vec<String> {
+    let mut seen: FxHashSet<&str> = FxHashSet::default();
+    let mut stack = vec![task_id];
+    while let Some(id) = stack.pop() {
+        for &next in edges.get(id).into_iter().flatten() {
+            if next != task_id && seen.insert(next) {
+                stack.push(next);
+            }
+        }
+    }
+    let mut ids: Vec<String> = seen.into_iter().map(str::to_string).collect();
+    ids.sort();
+    ids
+}
+
+/// All tasks that depend on `task_id`, directly or transitively, sorted.
+pub fn transitive_dependents(tasks: &[Task], task_id: &str) -> Result<Vec<String>, GraphError> {
+    let by_id = index_tasks(tasks);
+    if !by_id.contains_key(task_id) {
+        return Err(GraphError::UnknownTask(task_id.to_string()));
+    }
+    let mut edges: FxHashMap<&str, Vec<&str>> = FxHashMap::default();
+    for task in tasks {
+        for dep in &task.dependencies {
+            edges
+                .entry(dep.entity_id.as_str())
+                .or_default()
+                .push(task.id.as_str());
+        }
+    }
+    Ok(reachable(task_id, &edges))
+}
+
+/// All known tasks `task_id` depends on, directly or transitively, sorted.
+pub fn transitive_dependencies(tasks: &[Task], task_id: &str) -> Result<Vec<String>, GraphError> {
+    let by_id = index_tasks(tasks);
+    if !by_id.contains_key(task_id) {
+        return Err(GraphError::UnknownTask(task_id.to_string()));
+    }
+    let edges: FxHashMap<&str, Vec<&str>> = tasks
+        .iter()
+        .map(|t| {
+            let deps = t
+                .dependencies
+                .iter()
+                .map(|d| d.entity_id.as_str())
+                .filter(|id| by_id.contains_key(id))
+                .collect();
+            (t.id.as_str(), deps)
+        })
+        .collect();
+    Ok(reachable(task_id, &edges))
+}
+
+/// Order tasks so every task comes after its dependencies.
+///
+/// Ties are broken by task ID, so the order is stable across runs.
+/// Dependencies on unknown tasks are ignored.
+pub fn topological_order(tasks: &[Task]) -> Result<Vec<String>, GraphError> {
+    let by_id = index_tasks(tasks);
+    let mut in_degree: FxHashMap<&str, usize> = by_id.keys().map(|&id| (id, 0)).collect();
+    let mut dependents: FxHashMap<&str, Vec<&str>> = FxHashMap::default();
+    for (&id, task) in &by_id {
+        for dep in &task.dependencies {
+            if by_id.contains_key(dep.entity_id.as_str()) {
+                *in_degree.entry(id).or_default() += 1;
+                dependents
+                    .entry(dep.entity_id.as_str())
+                    .or_default()
+                    .push(id);
+            }
+        }
+    }
+
+    let mut ready: BinaryHeap<Reverse<&str>> = in_degree
+        .iter()
+        .filter(|(_, &degree)| degree == 0)
+        .map(|(&id, _)| Reverse(id))
+        .collect();
+    let mut order: Vec<String> = Vec::with_capacity(by_id.len());
+    while let Some(Reverse(id)) = ready.pop() {
+        order.push(id.to_string());
+        for &dependent in dependents.get(id).into_iter().flatten() {
+            if let Some(degree) = in_degree.get_mut(dependent) {
+                *degree -= 1;
+                if *degree == 0 {
+                    ready.push(Reverse(dependent));
+                }
+            }
+        }
+    }
+
+    if order.len() != by_id.len() {
+        let leftover = |id: &str| in_degree.get(id).is_some_and(|&d| d > 0);
+        let start = in_degree
+            .iter()
+            .filter(|(_, &d)| d > 0)
+            .map(|(&id, _)| id)
+            .min()
+            .unwrap_or_default();
+        let cycle = trace_cycle(start, |id| {
+            by_id[id]
+                .dependencies
+                .iter()
+                .map(|d| d.entity_id.as_str())
+                .filter(|&dep| leftover(dep))
+                .min()
+        });
+        return Err(GraphError::CircularDependency(canonical_cycle(
+            cycle.into_iter().map(str::to_string).collect(),
+        )));
+    }
+    Ok(order)
+}
+
+/// Earliest finish of each task in days, assuming unlimited resources.
+fn earliest_finishes<'a>(
+    order: &'a [String],
+    by_id: &FxHashMap<&str, &Task>,
+    slipped: Option<(&str, f64)>,
+) -> FxHashMap<&'a str, f64> {
+    let mut finish: FxHashMap<&str, f64> = FxHashMap::default();
+    for id in order {
+        let task = by_id[id.as_str()];
+        let start = task
+            .dependencies
+            .iter()
+            .filter_map(|d| finish.get(d.entity_id.as_str()).map(|&f| f + d.lag_days))
+            .fold(0.0, f64::max);
+        let delay = match slipped {
+            Some((slipped_id, days)) if slipped_id == id => days,
+            _ => 0.0,
+        };
+        finish.insert(id.as_str(), start + task.duration_days + delay);
+    }
+    finish
+}
+
+/// Which targets move, and by how much, if `task_id` slips by `delay_days`.
+///
+/// Targets are the tasks nothing depends on. Dates come from dependency chains
+/// alone (unlimited resources), so a slip absorbed by slack moves nothing.
+/// Results are sorted by target ID.
+pub fn slip_impact(
+    tasks: &[Task],
+    task_id: &str,
+    delay_days: f64,
+) -> Result<Vec<TargetImpact>, GraphError> {
+    let affected = transitive_dependents(tasks, task_id)?;
+    let order = topological_order(tasks)?;
+    let by_id = index_tasks(tasks);
+    let baseline = earliest_finishes(&order, &by_id, None);
+    let slipped = earliest_finishes(&order, &by_id, Some((task_id, delay_days)));
+
+    let has_dependents: FxHashSet<&str> = tasks
+        .iter()
+        .flat_map(|t| t.dependencies.iter().map(|d| d.entity_id.as_str()))
+        .collect();
+    Ok(std::iter::once(task_id)
+        .chain(affected.iter().map(String::as_str))
+        .filter(|id| !has_dependents.contains(id))
+        .filter_map(|id| {
+            let moved = slipped[id] - baseline[id];
+            (moved > 1e-9).then(|| TargetImpact {
+                target_id: id.to_string(),
+                delay_days: moved,
+            })
+        })
+        .collect())
+}
+
+/// Incomplete tasks that `task_id` waits on, directly or transitively.
+///
+/// The walk stops at completed tasks. Expected end dates come from `result`,
+/// typically a partial schedule. Blockers are ordered so each comes before the
+/// tasks it blocks, ties broken by ID.
+pub fn compute_blockers(
+    tasks: &[Task],
+    task_id: &str,
+    completed_task_ids: &FxHashSet<String>,
+    result: Option<&AlgorithmResult>,
+) -> Result<Vec<Blocker>, GraphError> {
+    let by_id = index_tasks(tasks);
+    if !by_id.contains_key(task_id) {
+        return Err(GraphError::UnknownTask(task_id.to_string()));
+    }
+    let ends: FxHashMap<&str, NaiveDate> = result
+        .into_iter()
+        .flat_map(|r| &r.scheduled_tasks)
+        .map(|st| (st.task_id.as_str(), st.end_date))
+        .collect();
+
+    let mut blocks: FxHashMap<&str, Vec<String>> = FxHashMap::default();
+    let mut stack = vec![task_id];
+    while let Some(id) = stack.pop() {
+        for dep in by_id.get(id).into_iter().flat_map(|t| &t.dependencies) {
+            let dep_id = dep.entity_id.as_str();
+            if dep_id == task_id || completed_task_ids.contains(dep_id) {
+                continue;
+            }
+            let entry = blocks.entry(dep_id).or_default();
+            if entry.is_empty() {
+                stack.push(dep_id);
+            }
+            entry.push(id.to_string());
+        }
+    }
+
+    let blocker = |id: &str, mut blocks: Vec<String>| {
+        blocks.sort();
+        blocks.dedup();
+        Blocker {
+            task_id: id.to_string(),
+            blocks,
+            expected_end: ends.get(id).copied(),
+            known: by_id.contains_key(id),
+        }
+    };
+    let mut unknown: Vec<Blocker> = blocks
+        .iter()
+        .filter(|(id, _)| !by_id.contains_key(*id))
+        .map(|(id, b)| blocker(id, b.clone()))
+        .collect();
+    unknown.sort_by(|a, b| a.task_id.cmp(&b.task_id));
+    let known = topological_order(tasks)?
+        .into_iter()
+        .filter_map(|id| blocks.remove(id.as_str()).map(|b| blocker(&id, b)));
+    Ok(unknown.into_iter().chain(known).collect())
+}
+
+/// Push the dates of a schedule downstream of `task_id` finishing `delay_days` late.
+///
+/// Dependents start no earlier than the day after their dependencies end plus
+/// lag, with working-day lags skipping `global_dns_periods`. With `respect_resources`, a task also waits for any task that started
+/// before it on a shared resource and was pushed. Tasks keep their calendar
+/// length and DNS periods are not consulted, so this is an estimate; rerun the
+/// scheduler for an exact answer.
+pub fn analyze_slip(
+    tasks: &[Task],
+    result: &AlgorithmResult,
+    task_id: &str,
+    delay_days: f64,
+    respect_resources: bool,
+    global_dns_periods: &[(NaiveDate, NaiveDate)],
+) -> Result<SlipAnalysis, GraphError> {
+    if !result
+        .scheduled_tasks
+        .iter()
+        .any(|st| st.task_id == task_id)
+    {
+        return Err(GraphError::UnknownTask(task_id.to_string()));
+    }
+    let by_id = index_tasks(tasks);
+    let mut order: Vec<&ScheduledTask> = result.scheduled_tasks.iter().collect();
+    order.sort_by(|a, b| (a.start_date, &a.task_id).cmp(&(b.start_date, &b.task_id)));
+
+    let mut moved: FxHashMap<&str, ScheduledTask> = FxHashMap::default();
+    // Original start and pushed end of each moved task, per resource
+    let mut resource_ends: FxHashMap<&str, Vec<(NaiveDate, NaiveDate)>> = FxHashMap::default();
+
+    for st in order {
+        let mut start = st.start_date;
+        if let Some(task) = by_id.get(st.task_id.as_str()) {
+            for dep in &task.dependencies {
+                if let Some(dep_task) = moved.get(dep.entity_id.as_str()) {
+                    start = start.max(dep.ready_date(dep_task.end_date, global_dns_periods));
+                }
+            }
+        }
+        if respect_resources {
+            for resource in &st.resources {
+                for &(other_start, other_end) in
+                    resource_ends.get(resource.as_str()).into_iter().flatten()
+                {
+                    if other_start < st.start_date {
+                        start = start.max(offset_date(other_end, 1.0)?);
+                    }
+                }
+            }
+        }
+        let mut end = offset_date(st.end_date, (start - st.start_date).num_days() as f64)?;
+        if st.task_id == task_id {
+            end = offset_date(end, delay_days.max(0.0))?;
+        }
+        if end != st.end_date {
+            if respect_resources {
+                for resource in &st.resources {
+                    resource_ends
+                        .entry(resource.as_str())
+                        .or_default()
+                        .push((st.start_date, end));
+                }
+            }
+            moved.insert(
+                st.task_id.as_str(),
+                ScheduledTask {
+                    start_date: start,
+                    end_date: end,
+                    ..st.clone()
+                },
+            );
+        }
+    }
+
+    let mut missed_deadlines: Vec<String> = result
+        .scheduled_tasks
+        .iter()
+        .filter_map(|st| {
+            let deadline = by_id.get(st.task_id.as_str())?.end_before?;
+            let new_end = moved.get(st.task_id.as_str())?.end_date;
+            (st.end_date <= deadline && new_end > deadline).then(|| st.task_id.clone())
+        })
+        .collect();
+    missed_deadlines.sort();
+    let mut affected_tasks: Vec<ScheduledTask> = moved.into_values().collect();
+    affected_tasks.sort_by(|a, b| a.task_id.cmp(&b.task_id));
+    Ok(SlipAnalysis {
+        affected_tasks,
+        missed_deadlines,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::Dependency;
+
+    fn d(day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(2025, 1, day).unwrap()
+    }
+
+    fn placed(id: &str, start: u32, end: u32, resource: &str) -> ScheduledTask {
+        ScheduledTask {
+            task_id: id.to_string(),
+            start_date: d(start),
+            end_date: d(end),
+            resources: vec![resource.to_string()],
+            ..Default::default()
+        }
+    }
+
+    fn task(id: &str, duration_days: f64, deps: &[&str]) -> Task {
+        Task {
+            id: id.to_string(),
+            duration_days,
+            dependencies: deps
+                .iter()
+                .map(|dep| Dependency {
+                    entity_id: dep.to_string(),
+                    ..Default::default()
+                })
+                .collect(),
+            ..Default::default()
+        }
+    }
+
+    fn diamond() -> Vec<Task> {
+        vec![
+            task("launch", 1.0, &["api", "docs"]),
+            task("api", 5.0, &["design"]),
+            task("docs", 2.0, &["design"]),
+            task("design", 3.0, &[]),
+            task("retro", 1.0, &["docs"]),
+        ]
+    }
+
+    #[test]
+    fn test_transitive_closures_and_order() {
+        let tasks = diamond();
+        assert_eq!(
+            transitive_dependents(&tasks, "design").unwrap(),
+            ["api", "docs", "launch", "retro"]
+        );
+        assert_eq!(
+            transitive_dependencies(&tasks, "launch").unwrap(),
+            ["api", "design", "docs"]
+        );
+        assert_eq!(
+            topological_order(&tasks).unwrap(),
+            ["design", "api", "docs", "launch", "retro"]
+        );
+        assert_eq!(
+            transitive_dependents(&tasks, "ghost").unwrap_err(),
+            GraphError::UnknownTask("ghost".to_string())
+        );
+    }
+
+    #[test]
+    fn test_topological_order_reports_cycle() {
+        let tasks = vec![task("a", 1.0, &["b"]), task("b", 1.0, &["a"])];
+        let err = topological_order(&tasks).unwrap_err();
+        assert_eq!(err.cycle(), Some(&["a".to_string(), "b".to_string()][..]));
+    }
+
+    #[test]
+    fn test_compute_blockers_walks_incomplete_chain() {
+        let mut tasks = diamond();
+        tasks.push(task("qa", 1.0, &["launch", "vendor"]));
+        let completed: FxHashSet<String> = ["design".to_string()].into_iter().collect();
+        let result = AlgorithmResult {
+            scheduled_tasks: vec![placed("api", 1, 6, "alice")],
+            ..Default::default()
+        };
+
+        let blockers = compute_blockers(&tasks, "qa", &completed, Some(&result)).unwrap();
+        let ids: Vec<&str> = blockers.iter().map(|b| b.task_id.as_str()).collect();
+        assert_eq!(ids, ["vendor", "api", "docs", "launch"]);
+        assert!(!blockers[0].known);
+        assert_eq!(blockers[1].expected_end, Some(d(6)));
+        assert_eq!(blockers[1].blocks, ["launch"]);
+        assert_eq!(blockers[2].expected_end, None);
+        assert_eq!(blockers[3].blocks, ["qa"]);
+    }
+
+    #[test]
+    fn test_slip_impact_absorbs_slack() {
+        let tasks = diamond();
+        // docs has 3 days of slack before launch, none before retro
+        let impact = slip_impact(&tasks, "docs", 4.0).unwrap();
+        assert_eq!(
+            impact,
+            vec![
+                TargetImpact {
+                    target_id: "launch".to_string(),
+                    delay_days: 1.0,
+                },
+                TargetImpact {
+                    target_id: "retro".to_string(),
+                    delay_days: 4.0,
+                },
+            ]
+        );
+        assert!(slip_impact(&tasks, "docs", 0.0).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_analyze_slip_pushes_dependents_and_resource_queue() {
+        let mut tasks = diamond();
+        tasks[0].end_before = Some(d(11));
+        let result = AlgorithmResult {
+            scheduled_tasks: vec![
+                placed("design", 1, 3, "alice"),
+                placed("api", 4, 8, "bob"),
+                placed("docs", 4, 5, "alice"),
+                placed("launch", 9, 9, "bob"),
+                placed("retro", 6, 6, "carol"),
+                placed("blog", 6, 7, "alice"),
+            ],
+            ..Default::default()
+        };
+
+        let analysis = analyze_slip(&tasks, &result, "design", 2.0, false, &[]).unwrap();
+        let dates: Vec<(&str, NaiveDate, NaiveDate)> = analysis
+            .affected_tasks
+            .iter()
+            .map(|t| (t.task_id.as_str(), t.start_date, t.end_date))
+            .collect();
+        assert_eq!(
+            dates,
+            vec![
+                ("api", d(6), d(10)),
+                ("design", d(1), d(5)),
+                ("docs", d(6), d(7)),
+                ("launch", d(11), d(11)),
+                ("retro", d(8), d(8)),
+            ]
+        );
+        assert!(analysis.missed_deadlines.is_empty());
+
+        // "blog" shares alice with "docs" and queues behind it
+        let analysis = analyze_slip(&tasks, &result, "design", 3.0, true, &[]).unwrap();
+        let blog = analysis
+            .affected_tasks
+            .iter()
+            .find(|t| t.task_id == "blog")
+            .unwrap();
+        assert_eq!((blog.start_date, blog.end_date), (d(9), d(10)));
+        assert_eq!(analysis.missed_deadlines, vec!["launch".to_string()]);
+
+        assert!(analyze_slip(&tasks, &result, "ghost", 1.0, false, &[]).is_err());
+    }
+
+    #[test]
+    fn test_analyze_slip_out_of_range_is_an_error() {
+        let tasks = diamond();
+        let result = AlgorithmResult {
+            scheduled_tasks: vec![
+                placed("design", 1, 3, "alice"),
+                placed("api", 4, 8, "alice"),
+            ],
+            ..Default::default()
+        };
+        for delay in [f64::INFINITY, 1e18] {
+            for respect_resources in [false, true] {
+                let err = analyze_slip(&tasks, &result, "design", delay, respect_resources, &[])
+                    .unwrap_err();
+                assert!(matches!(err, GraphError::DateOverflow(_)));
+            }
+        }
+    }
+}
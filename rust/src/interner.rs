@@ -1,20 +1,42 @@
 //! String interning for fast hash lookups.
 //!
-//! Converts string task IDs to integer IDs for faster HashMap operations.
+//! Converts string task and resource IDs to consecutive integer IDs so the
+//! schedulers' hot paths can use array indexing instead of string hashing.
+//! Strings are only materialized again at the API boundary.
 
 use rustc_hash::FxHashMap;
 
 /// Interned task ID (u32 for compact storage and fast hashing).
 pub type TaskIdInt = u32;
 
-/// String interner that maps task ID strings to integers.
+/// Interned resource ID.
+pub type ResourceIdInt = u32;
+
+/// Interner for task IDs.
+pub type TaskIdInterner = Interner;
+
+/// Interner for resource names.
+pub type ResourceIdInterner = Interner;
+
+/// String interner that maps strings to consecutive integers.
 #[derive(Debug, Clone)]
-pub struct TaskIdInterner {
-    to_int: FxHashMap<String, TaskIdInt>,
+pub struct Interner {
+    to_int: FxHashMap<String, u32>,
     from_int: Vec<String>,
 }
 
-impl TaskIdInterner {
+impl Interner {
+    /// Create an interner from strings, assigning IDs in iteration order.
+    /// Duplicates keep the ID of their first occurrence.
+    pub fn new(strings: impl IntoIterator<Item = String>) -> Self {
+        let strings = strings.into_iter();
+        let mut interner = Self::with_capacity(strings.size_hint().0);
+        for s in strings {
+            interner.intern(&s);
+        }
+        interner
+    }
+
     /// Create a new interner with pre-allocated capacity.
     pub fn with_capacity(capacity: usize) -> Self {
         Self {
@@ -25,11 +47,11 @@ impl TaskIdInterner {
 
     /// Intern a string, returning its integer ID.
     /// If already interned, returns the existing ID.
-    pub fn intern(&mut self, s: &str) -> TaskIdInt {
+    pub fn intern(&mut self, s: &str) -> u32 {
         if let Some(&id) = self.to_int.get(s) {
             return id;
         }
-        let id = self.from_int.len() as TaskIdInt;
+        let id = self.from_int.len() as u32;
         self.from_int.push(s.to_string());
         self.to_int.insert(s.to_string(), id);
         id
@@ -37,13 +59,13 @@ impl TaskIdInterner {
 
     /// Get the integer ID for a string, if it exists.
     #[inline]
-    pub fn get(&self, s: &str) -> Option<TaskIdInt> {
+    pub fn get(&self, s: &str) -> Option<u32> {
         self.to_int.get(s).copied()
     }
 
     /// Get the string for an integer ID.
     #[inline]
-    pub fn resolve(&self, id: TaskIdInt) -> Option<&str> {
+    pub fn resolve(&self, id: u32) -> Option<&str> {
         self.from_int.get(id as usize).map(|s| s.as_str())
     }
 
@@ -56,9 +78,17 @@ impl TaskIdInterner {
     pub fn is_empty(&self) -> bool {
         self.from_int.is_empty()
     }
+
+    /// Iterate over all (id, string) pairs in ID order.
+    pub fn iter(&self) -> impl Iterator<Item = (u32, &str)> {
+        self.from_int
+            .iter()
+            .enumerate()
+            .map(|(i, s)| (i as u32, s.as_str()))
+    }
 }
 
-impl Default for TaskIdInterner {
+impl Default for Interner {
     fn default() -> Self {
         Self::with_capacity(0)
     }
@@ -84,4 +114,14 @@ mod tests {
         assert_eq!(interner.get("task_a"), Some(id1));
         assert_eq!(interner.get("nonexistent"), None);
     }
+
+    #[test]
+    fn test_new_assigns_ids_in_order() {
+        let interner = Interner::new(["b", "a", "b"].map(String::from));
+        assert_eq!(interner.len(), 2);
+        assert_eq!(
+            interner.iter().collect::<Vec<_>>(),
+            vec![(0, "b"), (1, "a")]
+        );
+    }
 }
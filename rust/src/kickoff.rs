@@ -0,0 +1,350 @@
+//! Reverse planning: latest kickoff dates for deadline targets.
+//!
+//! Runs the backward pass over the targets' dependency subgraphs twice, once
+//! assuming unlimited resources and once with resource contention stacking
+//! each explicitly assigned resource's work. The latest start of the earliest
+//! task in a target's subgraph is its kickoff date. When contention makes it
+//! earlier, the chain of binding deadlines is followed from that task to the
+//! task whose deadline stacking tightened, and its resources are reported.
+
+use chrono::NaiveDate;
+use pyo3::prelude::*;
+use rustc_hash::{FxHashMap, FxHashSet};
+use thiserror::Error;
+
+use crate::backward_pass::{
+    backward_pass, compute_dependency_deadline, BackwardPassConfig, BackwardPassError,
+};
+use crate::models::{Dependency, Task};
+use crate::validation::{offset_date, DateOverflow};
+
+/// Errors from kickoff suggestions.
+#[derive(Error, Debug)]
+pub enum KickoffError {
+    #[error("{0}")]
+    BackwardPass(#[from] BackwardPassError),
+    #[error("Unknown target tasks: {}", .0.join(", "))]
+    UnknownTargets(Vec<String>),
+}
+
+impl From<DateOverflow> for KickoffError {
+    fn from(err: DateOverflow) -> Self {
+        KickoffError::BackwardPass(err.into())
+    }
+}
+
+impl KickoffError {
+    /// The offending dependency cycle, if this error describes one.
+    pub fn cycle(&self) -> Option<&[String]> {
+        match self {
+            KickoffError::BackwardPass(e) => e.cycle(),
+            KickoffError::UnknownTargets(_) => None,
+        }
+    }
+}
+
+/// Latest feasible kickoff for one deadline target.
+#[pyclass]
+#[derive(Clone, Debug, PartialEq)]
+pub struct KickoffSuggestion {
+    #[pyo3(get)]
+    pub target_id: String,
+    /// The target's deadline (explicit or inherited from a dependent).
+    #[pyo3(get)]
+    pub deadline: NaiveDate,
+    /// Latest date work on the target can start, accounting for resource loading.
+    #[pyo3(get)]
+    pub kickoff_date: NaiveDate,
+    /// Latest start from dependency chains alone (unlimited resources).
+    #[pyo3(get)]
+    pub dependency_kickoff_date: NaiveDate,
+    /// Task that must start on the kickoff date.
+    #[pyo3(get)]
+    pub critical_task_id: String,
+    /// Resources whose loading pulls the kickoff before the dependency-only date.
+    #[pyo3(get)]
+    pub constraining_resources: Vec<String>,
+}
+
+#[pymethods]
+impl KickoffSuggestion {
+    fn __repr__(&self) -> String {
+        format!(
+            "KickoffSuggestion(target={}, kickoff_date={}, constraining_resources={:?})",
+            self.target_id, self.kickoff_date, self.constraining_resources
+        )
+    }
+}
+
+/// Suggest the latest kickoff date for each target that has a deadline.
+///
+/// All targets' dependency subgraphs share resources, so a target competing
+/// with another for the same person starts earlier. Only explicitly assigned
+/// resources count toward loading. Working-day lags skip `global_dns_periods`.
+/// Targets without a deadline are omitted.
+pub fn suggest_kickoffs(
+    tasks: &[Task],
+    target_ids: &[String],
+    completed_task_ids: &FxHashSet<String>,
+    default_priority: i32,
+    global_dns_periods: &[(NaiveDate, NaiveDate)],
+) -> Result<Vec<KickoffSuggestion>, KickoffError> {
+    let by_id: FxHashMap<&str, &Task> = tasks.iter().map(|t| (t.id.as_str(), t)).collect();
+    let unknown: Vec<String> = target_ids
+        .iter()
+        .filter(|id| !by_id.contains_key(id.as_str()))
+        .cloned()
+        .collect();
+    if !unknown.is_empty() {
+        return Err(KickoffError::UnknownTargets(unknown));
+    }
+
+    let closures: Vec<FxHashSet<&str>> = target_ids
+        .iter()
+        .map(|target| {
+            let mut needed: FxHashSet<&str> = FxHashSet::default();
+            let mut stack = vec![target.as_str()];
+            while let Some(id) = stack.pop() {
+                if completed_task_ids.contains(id) || !needed.insert(id) {
+                    continue;
+                }
+                if let Some(task) = by_id.get(id) {
+                    stack.extend(task.dependencies.iter().map(|d| d.entity_id.as_str()));
+                }
+            }
+            needed
+        })
+        .collect();
+    let involved: FxHashSet<&str> = closures.iter().flatten().copied().collect();
+    let subset: Vec<Task> = tasks
+        .iter()
+        .filter(|t| involved.contains(t.id.as_str()))
+        .cloned()
+        .collect();
+
+    let mut config = BackwardPassConfig {
+        default_priority,
+        lag_calendar: global_dns_periods.to_vec(),
+        ..Default::default()
+    };
+    let unlimited = backward_pass(&subset, completed_task_ids, &config)?.computed_deadlines;
+    config.contention_aware = true;
+    let loaded = backward_pass(&subset, completed_task_ids, &config)?.computed_deadlines;
+
+    let mut dependents: FxHashMap<&str, Vec<(&Task, &Dependency)>> = FxHashMap::default();
+    for task in tasks.iter().filter(|t| involved.contains(t.id.as_str())) {
+        for dep in &task.dependencies {
+            dependents
+                .entry(dep.entity_id.as_str())
+                .or_default()
+                .push((task, dep));
+        }
+    }
+    let latest_start = |deadlines: &FxHashMap<String, NaiveDate>, id: &str| {
+        let task = by_id[id];
+        let duration = if task.external_date.is_some() {
+            0.0
+        } else {
+            task.duration_days
+        };
+        deadlines
+            .get(id)
+            .map(|&d| Ok((offset_date(d, -duration)?, task.id.as_str())))
+    };
+
+    let mut suggestions = Vec::new();
+    for (target_id, closure) in target_ids.iter().zip(&closures) {
+        let Some(&deadline) = unlimited.get(target_id) else {
+            continue;
+        };
+        let mut ids: Vec<&str> = closure.iter().copied().collect();
+        ids.sort();
+        let Some((kickoff_date, critical_task_id)) = ids
+            .iter()
+            .filter_map(|id| latest_start(&loaded, id))
+            .collect::<Result<Vec<_>, DateOverflow>>()?
+            .into_iter()
+            .min()
+        else {
+            continue;
+        };
+        let dependency_kickoff_date = ids
+            .iter()
+            .filter_map(|id| latest_start(&unlimited, id))
+            .collect::<Result<Vec<_>, DateOverflow>>()?
+            .into_iter()
+            .map(|(date, _)| date)
+            .min()
+            .unwrap_or(kickoff_date);
+        let constraining_resources = if kickoff_date < dependency_kickoff_date {
+            binding_resources(
+                critical_task_id,
+                &by_id,
+                &dependents,
+                &loaded,
+                global_dns_periods,
+                subset.len(),
+            )?
+        } else {
+            Vec::new()
+        };
+        suggestions.push(KickoffSuggestion {
+            target_id: target_id.clone(),
+            deadline,
+            kickoff_date,
+            dependency_kickoff_date,
+            critical_task_id: critical_task_id.to_string(),
+            constraining_resources,
+        });
+    }
+    Ok(suggestions)
+}
+
+/// Follow binding deadlines downstream from `id` to the task that resource
+/// stacking tightened, and return its resources.
+fn binding_resources<'a>(
+    mut id: &'a str,
+    by_id: &FxHashMap<&'a str, &'a Task>,
+    dependents: &FxHashMap<&'a str, Vec<(&'a Task, &'a Dependency)>>,
+    loaded: &FxHashMap<String, NaiveDate>,
+    lag_calendar: &[(NaiveDate, NaiveDate)],
+    max_steps: usize,
+) -> Result<Vec<String>, DateOverflow> {
+    for _ in 0..max_steps {
+        let task = by_id[id];
+        let Some(&deadline) = loaded.get(id) else {
+            break;
+        };
+        let explicit = match (task.end_before, task.external_date) {
+            (Some(a), Some(b)) => Some(a.min(b)),
+            (a, b) => a.or(b),
+        };
+        let binding_dependent = dependents
+            .get(id)
+            .into_iter()
+            .flatten()
+            .filter_map(|&(dependent, dep)| {
+                let duration = if dependent.external_date.is_some() {
+                    0.0
+                } else {
+                    dependent.duration_days
+                };
+                loaded.get(&dependent.id).map(|&d| {
+                    Ok((
+                        compute_dependency_deadline(d, duration, dep, None, lag_calendar)?,
+                        dependent.id.as_str(),
+                    ))
+                })
+            })
+            .collect::<Result<Vec<_>, DateOverflow>>()?
+            .into_iter()
+            .min();
+        let implied = match (explicit, binding_dependent) {
+            (Some(e), Some((b, _))) => Some(e.min(b)),
+            (e, b) => e.or(b.map(|(date, _)| date)),
+        };
+        if implied.is_some_and(|implied| deadline < implied) {
+            return Ok(task.resources.iter().map(|(r, _)| r.clone()).collect());
+        }
+        match binding_dependent {
+            Some((date, next)) if date == deadline => id = next,
+            _ => break,
+        }
+    }
+    Ok(Vec::new())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::Dependency;
+
+    fn d(day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(2025, 1, day).unwrap()
+    }
+
+    fn task(id: &str, duration_days: f64, deps: &[&str], resource: &str) -> Task {
+        Task {
+            id: id.to_string(),
+            duration_days,
+            dependencies: deps
+                .iter()
+                .map(|dep| Dependency {
+                    entity_id: dep.to_string(),
+                    ..Default::default()
+                })
+                .collect(),
+            resources: vec![(resource.to_string(), 1.0)],
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_kickoff_reports_constraining_resource() {
+        let mut alpha = task("alpha", 2.0, &["alpha_prep"], "alice");
+        alpha.end_before = Some(d(20));
+        let mut beta = task("beta", 4.0, &[], "alice");
+        beta.end_before = Some(d(20));
+        let tasks = vec![
+            task("alpha_prep", 3.0, &[], "bob"),
+            alpha,
+            beta,
+            task("unplanned", 5.0, &[], "carol"),
+        ];
+
+        let suggestions = suggest_kickoffs(
+            &tasks,
+            &[
+                "alpha".to_string(),
+                "beta".to_string(),
+                "unplanned".to_string(),
+            ],
+            &FxHashSet::default(),
+            50,
+            &[],
+        )
+        .unwrap();
+
+        // "unplanned" has no deadline
+        assert_eq!(suggestions.len(), 2);
+        let alpha = &suggestions[0];
+        assert_eq!(alpha.deadline, d(20));
+        // Alone, alpha_prep must finish by the 18th, so it starts by the 15th
+        assert_eq!(alpha.dependency_kickoff_date, d(15));
+        // beta holds alice for the 16th-20th, so alpha must finish by the 16th
+        assert_eq!(alpha.kickoff_date, d(11));
+        assert_eq!(alpha.critical_task_id, "alpha_prep");
+        assert_eq!(alpha.constraining_resources, vec!["alice".to_string()]);
+
+        let beta = &suggestions[1];
+        assert_eq!(beta.kickoff_date, d(16));
+        assert_eq!(beta.dependency_kickoff_date, d(16));
+        assert!(beta.constraining_resources.is_empty());
+    }
+
+    #[test]
+    fn test_kickoff_out_of_range_is_an_error() {
+        let near_min = NaiveDate::MIN
+            .checked_add_days(chrono::Days::new(5))
+            .unwrap();
+        for duration in [f64::INFINITY, 1e18, 10.0] {
+            let mut target = task("t", duration, &["prep"], "alice");
+            target.end_before = Some(near_min);
+            let tasks = vec![task("prep", 1.0, &[], "bob"), target];
+            let err = suggest_kickoffs(&tasks, &["t".to_string()], &FxHashSet::default(), 50, &[])
+                .unwrap_err();
+            assert!(matches!(
+                err,
+                KickoffError::BackwardPass(BackwardPassError::DateOverflow(_))
+            ));
+            assert_eq!(err.cycle(), None);
+        }
+    }
+
+    #[test]
+    fn test_kickoff_unknown_target() {
+        let err = suggest_kickoffs(&[], &["ghost".to_string()], &FxHashSet::default(), 50, &[])
+            .unwrap_err();
+        assert!(matches!(err, KickoffError::UnknownTargets(ids) if ids == ["ghost"]));
+    }
+}
@@ -0,0 +1,380 @@
+//! Resource-constrained backward pass: latest responsible start dates.
+//!
+//! Tasks are placed backwards from their deadlines, latest finish first, each
+//! as late as its deadline, its dependents' placements (with lag) and its
+//! resources allow. A resource already holding later work pushes the next
+//! task earlier, so the resulting start is the latest date work can begin
+//! with every deadline downstream still met. Fixed-date tasks and external
+//! milestones keep their dates and constrain their dependencies.
+
+use chrono::{Days, NaiveDate};
+use pyo3::prelude::*;
+use rustc_hash::{FxHashMap, FxHashSet};
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
+
+use crate::graph::{topological_order, GraphError};
+use crate::models::Task;
+use crate::preprocessing::{dns_aware_end_date, take_fixed_tasks};
+use crate::scheduler::{ResourceConfig, ResourceSchedule};
+
+/// How far before its latest finish a task's slot is searched for.
+const MAX_SEARCH_DAYS: usize = 3660;
+
+/// Latest responsible start of one task.
+#[pyclass]
+#[derive(Clone, Debug, PartialEq)]
+pub struct LatestStart {
+    #[pyo3(get)]
+    pub task_id: String,
+    /// Latest start that keeps every deadline downstream, with resources loaded.
+    #[pyo3(get)]
+    pub latest_start: NaiveDate,
+    /// End of the task when started on `latest_start`.
+    #[pyo3(get)]
+    pub latest_finish: NaiveDate,
+    /// Latest start from dependency chains alone (unlimited resources).
+    #[pyo3(get)]
+    pub dependency_latest_start: NaiveDate,
+    /// Task whose deadline (or fixed start) binds this one.
+    #[pyo3(get)]
+    pub deadline_task_id: String,
+    /// That task's deadline, or its start date if it is fixed.
+    #[pyo3(get)]
+    pub deadline: NaiveDate,
+    /// Days from the current date to `latest_start`; negative when already late.
+    #[pyo3(get)]
+    pub runway_days: i64,
+    #[pyo3(get)]
+    pub resources: Vec<String>,
+}
+
+#[pymethods]
+impl LatestStart {
+    fn __repr__(&self) -> String {
+        format!(
+            "LatestStart(task={}, latest_start={}, runway_days={}, deadline_task={})",
+            self.task_id, self.latest_start, self.runway_days, self.deadline_task_id
+        )
+    }
+}
+
+/// Latest finish allowed for a task and where it comes from.
+#[derive(Clone)]
+struct Bound {
+    finish: NaiveDate,
+    free_finish: NaiveDate,
+    driver: String,
+    deadline: NaiveDate,
+}
+
+impl Bound {
+    fn tighten(slot: &mut Option<Bound>, other: Bound) {
+        match slot {
+            Some(bound) => {
+                if other.finish < bound.finish {
+                    bound.finish = other.finish;
+                    bound.driver = other.driver;
+                    bound.deadline = other.deadline;
+                }
+                bound.free_finish = bound.free_finish.min(other.free_finish);
+            }
+            None => *slot = Some(other),
+        }
+    }
+}
+
+fn days_before(date: NaiveDate, days: f64) -> NaiveDate {
+    date.checked_sub_days(Days::new(days.ceil() as u64))
+        .unwrap_or(NaiveDate::MIN)
+}
+
+/// Latest responsible start for every incomplete task with a deadline downstream.
+///
+/// Only explicitly assigned (or pinned) resources are loaded; auto-assigned
+/// work is placed as if its resource were free. Tasks with no deadline on
+/// any path to their dependents are omitted. Results are sorted by
+/// `latest_start`, then task ID.
+pub fn latest_starts(
+    tasks: &[Task],
+    current_date: NaiveDate,
+    completed_task_ids: &FxHashSet<String>,
+    resource_config: Option<&ResourceConfig>,
+    global_dns_periods: &[(NaiveDate, NaiveDate)],
+) -> Result<Vec<LatestStart>, GraphError> {
+    topological_order(tasks)?;
+
+    let mut open: FxHashMap<String, Task> = tasks
+        .iter()
+        .filter(|t| !completed_task_ids.contains(&t.id))
+        .map(|t| (t.id.clone(), t.clone().resolve_in_progress(current_date)))
+        .collect();
+    let mut fixed_dependencies = FxHashMap::default();
+    let fixed = take_fixed_tasks(
+        &mut open,
+        &mut fixed_dependencies,
+        resource_config,
+        global_dns_periods,
+        current_date,
+    )?;
+
+    let mut bounds: FxHashMap<String, Option<Bound>> =
+        open.keys().map(|id| (id.clone(), None)).collect();
+    let mut pending_dependents: FxHashMap<&str, usize> = FxHashMap::default();
+    for task in open.values() {
+        for dep in &task.dependencies {
+            if open.contains_key(&dep.entity_id) {
+                *pending_dependents
+                    .entry(dep.entity_id.as_str())
+                    .or_default() += 1;
+            }
+        }
+    }
+
+    let mut occupancy: FxHashMap<String, Vec<(NaiveDate, NaiveDate)>> = FxHashMap::default();
+    for placement in &fixed.placements {
+        for resource in &placement.resources {
+            occupancy
+                .entry(resource.clone())
+                .or_default()
+                .push((placement.start_date, placement.end_date));
+        }
+        for dep in fixed_dependencies
+            .get(&placement.task_id)
+            .into_iter()
+            .flatten()
+        {
+            if let Some(slot) = bounds.get_mut(&dep.entity_id) {
+                let finish = dep.latest_end(placement.start_date, global_dns_periods);
+                Bound::tighten(
+                    slot,
+                    Bound {
+                        finish,
+                        free_finish: finish,
+                        driver: placement.task_id.clone(),
+                        deadline: placement.start_date,
+                    },
+                );
+            }
+        }
+    }
+    for task in open.values() {
+        if let Some(deadline) = task.tightest_deadline() {
+            let slot = bounds
+                .get_mut(&task.id)
+                .expect("every open task has a bound slot");
+            Bound::tighten(
+                slot,
+                Bound {
+                    finish: deadline,
+                    free_finish: deadline,
+                    driver: task.id.clone(),
+                    deadline,
+                },
+            );
+        }
+    }
+
+    let finish_key = |bounds: &FxHashMap<String, Option<Bound>>, id: &str| {
+        bounds.get(id).and_then(|b| b.as_ref().map(|b| b.finish))
+    };
+    let mut ready: BinaryHeap<(Option<NaiveDate>, Reverse<String>)> = open
+        .keys()
+        .filter(|id| !pending_dependents.contains_key(id.as_str()))
+        .map(|id| (finish_key(&bounds, id), Reverse(id.clone())))
+        .collect();
+    let mut calendars: FxHashMap<String, ResourceSchedule> = FxHashMap::default();
+    let mut results = Vec::new();
+
+    while let Some((_, Reverse(task_id))) = ready.pop() {
+        let task = &open[&task_id];
+        let placed = bounds[&task_id].clone().map(|bound| {
+            let resources: Vec<String> = if task.resources.is_empty() {
+                task.pinned_resource.iter().cloned().collect()
+            } else {
+                task.resources.iter().map(|(r, _)| r.clone()).collect()
+            };
+            for resource in &resources {
+                calendars
+                    .entry(resource.clone())
+                    .or_insert_with(|| match resource_config {
+                        Some(rc) => rc.build_schedule(resource, global_dns_periods),
+                        None => ResourceSchedule::new(
+                            Some(global_dns_periods.to_vec()),
+                            resource.clone(),
+                        ),
+                    });
+            }
+            let end_for = |start: NaiveDate| -> Option<NaiveDate> {
+                if resources.is_empty() {
+                    return Some(dns_aware_end_date(task, &[], start, None, &[]));
+                }
+                let mut end = start;
+                for resource in &resources {
+                    let calendar = &calendars[resource];
+                    if calendar.next_available_time(start) != start {
+                        return None;
+                    }
+                    let mut calendar = calendar.clone();
+                    let (completion, _) = calendar.completion_point_avoiding(
+                        start,
+                        0.0,
+                        task.duration_days,
+                        &task.blocked_periods,
+                    );
+                    end = end.max(completion);
+                }
+                Some(end)
+            };
+            let conflicts = |start: NaiveDate, end: NaiveDate| {
+                resources.iter().any(|resource| {
+                    occupancy
+                        .get(resource)
+                        .is_some_and(|periods| periods.iter().any(|&(s, e)| s <= end && start <= e))
+                })
+            };
+
+            let mut start = days_before(bound.finish, task.duration_days);
+            let mut slot = None;
+            for _ in 0..MAX_SEARCH_DAYS {
+                if let Some(end) = end_for(start) {
+                    if end <= bound.finish && !conflicts(start, end) {
+                        slot = Some((start, end));
+                        break;
+                    }
+                }
+                match start.pred_opt() {
+                    Some(previous) => start = previous,
+                    None => break,
+                }
+            }
+            let (start, end) = slot.unwrap_or((start, bound.finish));
+            for resource in &resources {
+                occupancy
+                    .entry(resource.clone())
+                    .or_default()
+                    .push((start, end));
+            }
+            let free_start = days_before(bound.free_finish, task.duration_days);
+            results.push(LatestStart {
+                task_id: task_id.clone(),
+                latest_start: start,
+                latest_finish: end,
+                dependency_latest_start: free_start,
+                deadline_task_id: bound.driver.clone(),
+                deadline: bound.deadline,
+                runway_days: (start - current_date).num_days(),
+                resources,
+            });
+            (start, free_start, bound)
+        });
+
+        for dep in &task.dependencies {
+            let Some(slot) = bounds.get_mut(&dep.entity_id) else {
+                continue;
+            };
+            if let Some((start, free_start, bound)) = &placed {
+                Bound::tighten(
+                    slot,
+                    Bound {
+                        finish: dep.latest_end(*start, global_dns_periods),
+                        free_finish: dep.latest_end(*free_start, global_dns_periods),
+                        driver: bound.driver.clone(),
+                        deadline: bound.deadline,
+                    },
+                );
+            }
+            let pending = pending_dependents
+                .get_mut(dep.entity_id.as_str())
+                .expect("open dependencies are counted");
+            *pending -= 1;
+            if *pending == 0 {
+                ready.push((
+                    finish_key(&bounds, &dep.entity_id),
+                    Reverse(dep.entity_id.clone()),
+                ));
+            }
+        }
+    }
+
+    results.sort_by(|a, b| (a.latest_start, &a.task_id).cmp(&(b.latest_start, &b.task_id)));
+    Ok(results)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::Dependency;
+
+    fn d(day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(2025, 1, day).unwrap()
+    }
+
+    fn task(id: &str, duration_days: f64, deps: &[&str], end_before: Option<NaiveDate>) -> Task {
+        Task {
+            id: id.to_string(),
+            duration_days,
+            dependencies: deps
+                .iter()
+                .map(|dep| Dependency {
+                    entity_id: dep.to_string(),
+                    ..Default::default()
+                })
+                .collect(),
+            resources: vec![("alice".to_string(), 1.0)],
+            end_before,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_latest_starts_stack_shared_resource() {
+        // "a" and "b" both feed "ship" and share alice, so one of them must
+        // finish before the other starts
+        let tasks = vec![
+            task("a", 2.0, &[], None),
+            task("b", 3.0, &[], None),
+            Task {
+                resources: vec![],
+                ..task("ship", 0.0, &["a", "b"], Some(d(20)))
+            },
+            task("unrelated", 1.0, &[], None),
+        ];
+        let starts = latest_starts(&tasks, d(1), &FxHashSet::default(), None, &[]).unwrap();
+
+        let summary: Vec<(&str, NaiveDate, NaiveDate, &str)> = starts
+            .iter()
+            .map(|s| {
+                (
+                    s.task_id.as_str(),
+                    s.latest_start,
+                    s.dependency_latest_start,
+                    s.deadline_task_id.as_str(),
+                )
+            })
+            .collect();
+        assert_eq!(
+            summary,
+            [
+                ("b", d(13), d(16), "ship"),
+                ("a", d(17), d(17), "ship"),
+                ("ship", d(20), d(20), "ship"),
+            ]
+        );
+        assert_eq!(starts[0].latest_finish, d(16));
+        assert_eq!(starts[0].runway_days, 12);
+    }
+
+    #[test]
+    fn test_out_of_range_milestone_lag_is_an_error() {
+        let near_min = NaiveDate::MIN.checked_add_days(Days::new(5)).unwrap();
+        for lag_days in [f64::INFINITY, 1e18, 10.0] {
+            let mut launch = task("launch", 0.0, &["a"], None);
+            launch.external_date = Some(near_min);
+            launch.dependencies[0].lag_days = lag_days;
+            let tasks = vec![task("a", 1.0, &[], None), launch];
+            let err = latest_starts(&tasks, d(1), &FxHashSet::default(), None, &[]).unwrap_err();
+            assert!(matches!(err, GraphError::DateOverflow(_)));
+        }
+    }
+}
@@ -8,24 +8,94 @@
 use chrono::NaiveDate;
 use pyo3::prelude::*;
 use std::collections::{HashMap, HashSet};
+use std::ops::{Deref, DerefMut};
+use std::panic::AssertUnwindSafe;
+use std::sync::{Arc, Mutex, MutexGuard};
+use std::thread::JoinHandle;
 
+pub mod algorithm;
 pub mod backward_pass;
+pub mod buffers;
+pub mod cancellation;
 mod config;
+pub mod crashing;
 pub mod critical_path;
+pub mod eligibility;
+pub mod evm;
+pub mod exceptions;
+pub mod export;
+pub mod graph;
 pub mod interner;
+pub mod kickoff;
+pub mod latest_start;
+pub mod loaders;
 pub mod logging;
 mod models;
+pub mod pareto;
+mod preprocessing;
+pub mod profiling;
+pub mod progress;
+pub mod recurrence;
 pub mod scheduler;
+pub mod snapshot;
 pub mod sorting;
+pub mod tuning;
+pub mod validation;
+pub mod verify;
 
-pub use backward_pass::{backward_pass, BackwardPassConfig, BackwardPassError, BackwardPassResult};
-pub use config::{RolloutConfig, SchedulingConfig};
+pub use algorithm::{build_scheduler, Scheduler};
+pub use backward_pass::{
+    backward_pass, BackwardPassConfig, BackwardPassError, BackwardPassResult, DeadlineCalendar,
+};
+pub use buffers::{
+    buffer_consumption, compute_buffers, insert_buffer_tasks, Buffer, BufferError, BufferStatus,
+};
+pub use cancellation::{CancellationToken, StopCondition};
+pub use config::{
+    ConfigError, DurationRounding, FixedConflictPolicy, RolloutConfig, RolloutFidelity,
+    ScheduleObjective, SchedulingAlgorithm, SchedulingConfig, SchedulingConfigBuilder,
+    UnknownDependencyPolicy, UnschedulablePolicy, PRESETS,
+};
+pub use crashing::{suggest_crashes, CrashAction, CrashError, CrashPlan};
 pub use critical_path::{
-    CriticalPathConfig, CriticalPathScheduler, CriticalPathSchedulerError, TargetInfo, TaskTiming,
+    calculate_critical_path, CriticalPathConfig, CriticalPathConfigBuilder, CriticalPathResult,
+    CriticalPathRolloutDecision, CriticalPathScheduler, CriticalPathSchedulerError,
+    ReservationRecord, TargetInfo, TaskTiming, UrgencyDenominator, WorkTransform,
+};
+pub use eligibility::{eligible_tasks, TaskEligibility};
+pub use evm::{earned_value, EarnedValueSeries};
+pub use exceptions::{
+    CircularDependencyError, InvalidConfigError, MoucSchedulerError, ScheduleCancelledError,
+    ScheduleFailure, UnschedulableError,
 };
-pub use models::{AlgorithmResult, Dependency, PreProcessResult, ScheduledTask, Task};
-pub use scheduler::{ParallelScheduler, ResourceConfig, RolloutDecision, SchedulerError};
+pub use graph::{
+    analyze_slip, compute_blockers, slip_impact, topological_order, transitive_dependencies,
+    transitive_dependents, Blocker, GraphError, SlipAnalysis, TargetImpact,
+};
+pub use kickoff::{suggest_kickoffs, KickoffError, KickoffSuggestion};
+pub use latest_start::{latest_starts, LatestStart};
+pub use loaders::{tasks_from_columns, tasks_from_csv, tasks_from_json, LoadError, TaskColumns};
+pub use models::{
+    AlgorithmMetadata, AlgorithmResult, CrashSpec, DeadlineStatus, Dependency, LagUnit,
+    PreProcessResult, Provenance, Recurrence, ScheduleSummary, ScheduleWarning, ScheduledTask,
+    ScheduledTaskBatches, Task, TaskChange, UnschedulableTask,
+};
+pub use pareto::{non_dominated, pareto_frontier, ParetoSchedule, ScheduleMetrics};
+pub use preprocessing::apply_external_completions;
+pub use progress::{ProgressCallback, ProgressReporter};
+pub use scheduler::{
+    IterationLimitReached, ParallelScheduler, ResourceConfig, RolloutDecision, RolloutTrace,
+    SchedulerError,
+};
+pub use snapshot::{HeldReservation, ScheduleStep, SchedulerSnapshot};
 pub use sorting::{sort_tasks, AtcParams, SortKey, SortingError, TaskSortInfo};
+pub use tuning::{
+    objective_cost, tune_critical_path, TuningGrid, TuningInstance, TuningResult, TuningTrial,
+};
+pub use validation::{
+    check_feasibility, duplicate_task_ids, validate_tasks, Diagnostic, ResourceOverload,
+};
+pub use verify::verify_schedule;
 
 /// Run the backward pass algorithm to compute deadlines and priorities.
 ///
@@ -37,33 +107,88 @@ pub use sorting::{sort_tasks, AtcParams, SortKey, SortingError, TaskSortInfo};
 /// * `tasks` - List of tasks to process
 /// * `completed_task_ids` - Set of task IDs already completed (excluded from propagation)
 /// * `default_priority` - Default priority for tasks without explicit priority (0-100)
+/// * `priority_decay` - Multiplier applied to inherited priority per dependency level
+/// * `contention_aware` - Tighten deadlines of tasks that compete for the same explicit resource
+/// * `dns_periods` - DNS periods per resource; with `global_dns_periods`, makes deadline
+///   propagation count durations in working days
+/// * `global_dns_periods` - DNS periods applying to everyone
 ///
 /// # Returns
-/// * PreProcessResult with computed deadlines and priorities
+/// * PreProcessResult with computed deadlines, priorities and earliest starts
 ///
 /// # Raises
-/// * ValueError if circular dependency is detected
+/// * ValueError if circular dependency is detected; its `cycle` attribute lists the task IDs
+/// * ValueError if a propagated date falls outside the supported range
 #[pyfunction]
-#[pyo3(signature = (tasks, completed_task_ids, default_priority))]
+#[pyo3(signature = (
+    tasks,
+    completed_task_ids,
+    default_priority,
+    priority_decay=1.0,
+    contention_aware=false,
+    dns_periods=None,
+    global_dns_periods=None
+))]
 fn run_backward_pass(
     tasks: Vec<Task>,
     completed_task_ids: HashSet<String>,
     default_priority: i32,
+    priority_decay: f64,
+    contention_aware: bool,
+    dns_periods: Option<HashMap<String, Vec<(NaiveDate, NaiveDate)>>>,
+    global_dns_periods: Option<Vec<(NaiveDate, NaiveDate)>>,
 ) -> PyResult<PreProcessResult> {
     use rustc_hash::FxHashSet;
 
-    let config = BackwardPassConfig { default_priority };
+    let config = BackwardPassConfig {
+        default_priority,
+        priority_decay,
+        contention_aware,
+        lag_calendar: global_dns_periods.clone().unwrap_or_default(),
+        calendar: (dns_periods.is_some() || global_dns_periods.is_some()).then(|| {
+            DeadlineCalendar {
+                global_dns_periods: global_dns_periods.unwrap_or_default(),
+                dns_periods: dns_periods.unwrap_or_default().into_iter().collect(),
+            }
+        }),
+        ..Default::default()
+    };
     // Convert std HashSet to FxHashSet for internal use
     let completed: FxHashSet<String> = completed_task_ids.into_iter().collect();
 
     match backward_pass(&tasks, &completed, &config) {
-        Ok(result) => Ok(PreProcessResult {
-            // Convert FxHashMap to HashMap for Python interface
-            computed_deadlines: result.computed_deadlines.into_iter().collect(),
-            computed_priorities: result.computed_priorities.into_iter().collect(),
-        }),
-        Err(e) => Err(pyo3::exceptions::PyValueError::new_err(e.to_string())),
+        Ok(result) => {
+            let provenance = result
+                .deadline_sources
+                .keys()
+                .chain(result.priority_sources.keys())
+                .map(|id| {
+                    let paths = Provenance {
+                        deadline_path: result.deadline_path(id),
+                        priority_path: result.priority_path(id),
+                    };
+                    (id.clone(), paths)
+                })
+                .collect();
+            Ok(PreProcessResult {
+                // Convert FxHashMap to HashMap for Python interface
+                computed_deadlines: result.computed_deadlines.into_iter().collect(),
+                computed_priorities: result.computed_priorities.into_iter().collect(),
+                provenance,
+                computed_earliest_starts: result.computed_earliest_starts.into_iter().collect(),
+            })
+        }
+        Err(e) => Err(value_error(e.to_string(), e.cycle())),
+    }
+}
+
+/// Build a MoucSchedulerError, or a CircularDependencyError exposing `cycle`.
+fn value_error(message: String, cycle: Option<&[String]>) -> PyErr {
+    match cycle {
+        Some(cycle) => ScheduleFailure::Cycle(message, cycle.to_vec()),
+        None => ScheduleFailure::Other(message),
     }
+    .into()
 }
 
 /// Task information needed for sorting (PyO3 wrapper).
@@ -76,17 +201,25 @@ pub struct PyTaskSortInfo {
     pub deadline: Option<NaiveDate>,
     #[pyo3(get, set)]
     pub priority: i32,
+    #[pyo3(get, set)]
+    pub rank: Option<i64>,
 }
 
 #[pymethods]
 impl PyTaskSortInfo {
     #[new]
-    #[pyo3(signature = (duration_days, priority, deadline=None))]
-    fn new(duration_days: f64, priority: i32, deadline: Option<NaiveDate>) -> Self {
+    #[pyo3(signature = (duration_days, priority, deadline=None, rank=None))]
+    fn new(
+        duration_days: f64,
+        priority: i32,
+        deadline: Option<NaiveDate>,
+        rank: Option<i64>,
+    ) -> Self {
         Self {
             duration_days,
             deadline,
             priority,
+            rank,
         }
     }
 
@@ -141,6 +274,7 @@ fn py_sort_tasks(
                     duration_days: v.duration_days,
                     deadline: v.deadline,
                     priority: v.priority,
+                    rank: v.rank,
                 },
             )
         })
@@ -168,6 +302,710 @@ fn py_sort_tasks(
     }
 }
 
+/// Validate tasks before scheduling and return every problem found.
+///
+/// # Arguments
+/// * `tasks` - List of tasks to check
+/// * `resource_config` - Optional resource configuration; enables resource name checks
+///
+/// # Returns
+/// * List of Diagnostic (empty if the tasks are valid)
+#[pyfunction]
+#[pyo3(name = "validate_tasks", signature = (tasks, resource_config=None))]
+fn py_validate_tasks(
+    tasks: Vec<Task>,
+    resource_config: Option<PyResourceConfig>,
+) -> Vec<Diagnostic> {
+    let rust_resource_config = resource_config.map(ResourceConfig::from);
+    validate_tasks(&tasks, rust_resource_config.as_ref())
+}
+
+/// Compare required work per resource with available capacity over a horizon.
+///
+/// A fast pre-check before scheduling: work assigned explicitly counts toward
+/// each resource, and work assigned by resource spec is checked against the
+/// spec's candidates as a pool.
+///
+/// # Arguments
+/// * `tasks` - List of tasks
+/// * `resource_config` - Resource configuration (DNS periods, capacity, efficiency)
+/// * `horizon` - `(start, end)` dates; days from start up to (not including) end count
+/// * `global_dns_periods` - DNS periods applying to every resource
+///
+/// # Returns
+/// * List of ResourceOverload, largest overload first (empty if nothing is overloaded)
+#[pyfunction]
+#[pyo3(name = "check_feasibility", signature = (tasks, resource_config, horizon, global_dns_periods=None))]
+fn py_check_feasibility(
+    tasks: TaskInput,
+    resource_config: PyResourceConfig,
+    horizon: (NaiveDate, NaiveDate),
+    global_dns_periods: Option<Vec<(NaiveDate, NaiveDate)>>,
+) -> Vec<ResourceOverload> {
+    let tasks = tasks.into_tasks();
+    let resource_config = ResourceConfig::from(resource_config);
+    check_feasibility(
+        &tasks,
+        &resource_config,
+        horizon,
+        &global_dns_periods.unwrap_or_default(),
+    )
+}
+
+/// Check a schedule for double-booked resources, broken dependencies, DNS
+/// violations and moved fixed dates.
+///
+/// # Arguments
+/// * `result` - Schedule to check, e.g. one edited outside the scheduler
+/// * `tasks` - Tasks the schedule was built from
+/// * `resource_config` - Resource configuration (per-resource DNS periods)
+/// * `global_dns_periods` - DNS periods applying to every resource
+/// * `sub_day` - Whether the schedule packs work within days (default: False)
+///
+/// # Returns
+/// * List of ScheduleWarning, one per violation (empty if the schedule is valid)
+#[pyfunction]
+#[pyo3(name = "verify_schedule", signature = (result, tasks, resource_config=None, global_dns_periods=None, sub_day=false))]
+fn py_verify_schedule(
+    result: AlgorithmResult,
+    tasks: TaskInput,
+    resource_config: Option<PyResourceConfig>,
+    global_dns_periods: Option<Vec<(NaiveDate, NaiveDate)>>,
+    sub_day: bool,
+) -> Vec<ScheduleWarning> {
+    let tasks = tasks.into_tasks();
+    let resource_config = resource_config.map(ResourceConfig::from);
+    verify_schedule(
+        &result,
+        &tasks,
+        resource_config.as_ref(),
+        &global_dns_periods.unwrap_or_default(),
+        sub_day,
+    )
+}
+
+/// Which tasks could start on `as_of_date`, without scheduling.
+///
+/// A task is eligible when its dependencies are all in `completed_task_ids` and
+/// its `start_after`, `start_on` and blocked periods allow the date; resources
+/// are not checked. Completed tasks are left out.
+///
+/// # Returns
+/// * List of TaskEligibility sorted by task ID, with reasons for blocked tasks
+#[pyfunction]
+#[pyo3(name = "get_eligible_tasks", signature = (tasks, as_of_date, completed_task_ids=None))]
+fn py_get_eligible_tasks(
+    tasks: TaskInput,
+    as_of_date: NaiveDate,
+    completed_task_ids: Option<HashSet<String>>,
+) -> Vec<TaskEligibility> {
+    let completed = completed_task_ids.unwrap_or_default().into_iter().collect();
+    eligible_tasks(&tasks.into_tasks(), &completed, as_of_date)
+}
+
+/// Compute CCPM project and feeding buffers for a scheduled target.
+///
+/// # Arguments
+/// * `result` - Schedule to analyze
+/// * `tasks` - Tasks the schedule was built from (for dependencies)
+/// * `target_id` - Task whose critical chain gets the project buffer
+/// * `buffer_ratio` - Buffer size as a fraction of chain duration (default: 0.5)
+///
+/// # Returns
+/// * List of Buffer, project buffer first (empty if target is not scheduled)
+///
+/// # Raises
+/// * ValueError if `buffer_ratio` is negative or not finite, or a buffer
+///   falls outside the supported date range
+#[pyfunction]
+#[pyo3(name = "compute_buffers", signature = (result, tasks, target_id, buffer_ratio=0.5))]
+fn py_compute_buffers(
+    result: AlgorithmResult,
+    tasks: Vec<Task>,
+    target_id: &str,
+    buffer_ratio: f64,
+) -> PyResult<Vec<Buffer>> {
+    compute_buffers(&result, &tasks, target_id, buffer_ratio)
+        .map_err(|e| value_error(e.to_string(), None))
+}
+
+/// Return a copy of `result` with a pseudo-task appended for each buffer.
+#[pyfunction]
+#[pyo3(name = "insert_buffer_tasks")]
+fn py_insert_buffer_tasks(mut result: AlgorithmResult, buffers: Vec<Buffer>) -> AlgorithmResult {
+    insert_buffer_tasks(&mut result, &buffers);
+    result
+}
+
+/// Report how much of each buffer a rescheduled plan consumes.
+#[pyfunction]
+#[pyo3(name = "buffer_consumption")]
+fn py_buffer_consumption(buffers: Vec<Buffer>, result: AlgorithmResult) -> Vec<BufferStatus> {
+    buffer_consumption(&buffers, &result)
+}
+
+/// Compute daily earned value metrics (PV/EV/SV/SPI) against a baseline.
+///
+/// # Arguments
+/// * `baseline` - Baseline schedule; each task's budget is its duration
+/// * `actual_completions` - Map of task ID to actual completion date
+/// * `current_date` - Last date of the series
+///
+/// # Returns
+/// * EarnedValueSeries from the baseline's first start through `current_date`
+#[pyfunction]
+#[pyo3(name = "earned_value")]
+fn py_earned_value(
+    baseline: AlgorithmResult,
+    actual_completions: HashMap<String, NaiveDate>,
+    current_date: NaiveDate,
+) -> EarnedValueSeries {
+    let completions: rustc_hash::FxHashMap<String, NaiveDate> =
+        actual_completions.into_iter().collect();
+    earned_value(&baseline, &completions, current_date)
+}
+
+/// Compute ES/EF/LS/LF and slack for the work leading to a target.
+///
+/// Times are in days relative to the start of the target's unscheduled
+/// dependency subgraph, so results can be laid out directly in a graph view.
+///
+/// # Arguments
+/// * `target_id` - Task whose critical path to compute
+/// * `tasks` - List of tasks
+/// * `scheduled` - Already scheduled task IDs mapped to their end offset in days
+/// * `completed_task_ids` - Task IDs already completed
+///
+/// # Raises
+/// * ValueError if the target is unknown or the graph has a cycle
+#[pyfunction]
+#[pyo3(name = "calculate_critical_path", signature = (target_id, tasks, scheduled=None, completed_task_ids=None))]
+fn py_calculate_critical_path(
+    target_id: &str,
+    tasks: TaskInput,
+    scheduled: Option<HashMap<String, f64>>,
+    completed_task_ids: Option<HashSet<String>>,
+) -> PyResult<PyCriticalPathResult> {
+    let tasks: rustc_hash::FxHashMap<String, Task> = tasks
+        .into_tasks()
+        .into_iter()
+        .map(|t| (t.id.clone(), t))
+        .collect();
+    if !tasks.contains_key(target_id) {
+        return Err(pyo3::exceptions::PyValueError::new_err(format!(
+            "Unknown target task: {}",
+            target_id
+        )));
+    }
+    let scheduled: rustc_hash::FxHashMap<String, f64> =
+        scheduled.unwrap_or_default().into_iter().collect();
+    let completed: rustc_hash::FxHashSet<String> =
+        completed_task_ids.unwrap_or_default().into_iter().collect();
+    calculate_critical_path(target_id, &tasks, &scheduled, &completed)
+        .map(PyCriticalPathResult::from)
+        .map_err(|e| value_error(e.to_string(), e.cycle()))
+}
+
+/// All tasks that depend on `task_id`, directly or transitively, sorted.
+///
+/// # Raises
+/// * ValueError if the task is unknown
+#[pyfunction]
+#[pyo3(name = "transitive_dependents")]
+fn py_transitive_dependents(tasks: TaskInput, task_id: &str) -> PyResult<Vec<String>> {
+    transitive_dependents(&tasks.into_tasks(), task_id)
+        .map_err(|e| value_error(e.to_string(), e.cycle()))
+}
+
+/// All known tasks `task_id` depends on, directly or transitively, sorted.
+///
+/// # Raises
+/// * ValueError if the task is unknown
+#[pyfunction]
+#[pyo3(name = "transitive_dependencies")]
+fn py_transitive_dependencies(tasks: TaskInput, task_id: &str) -> PyResult<Vec<String>> {
+    transitive_dependencies(&tasks.into_tasks(), task_id)
+        .map_err(|e| value_error(e.to_string(), e.cycle()))
+}
+
+/// Task IDs ordered so each task follows its dependencies, ties broken by ID.
+///
+/// # Raises
+/// * ValueError if the graph has a cycle; its `cycle` attribute lists the task IDs
+#[pyfunction]
+#[pyo3(name = "topological_order")]
+fn py_topological_order(tasks: TaskInput) -> PyResult<Vec<String>> {
+    topological_order(&tasks.into_tasks()).map_err(|e| value_error(e.to_string(), e.cycle()))
+}
+
+/// Which targets (tasks nothing depends on) move if `task_id` slips by `delay_days`.
+///
+/// Uses dependency chains alone, assuming unlimited resources.
+///
+/// # Raises
+/// * ValueError if the task is unknown or the graph has a cycle
+#[pyfunction]
+#[pyo3(name = "slip_impact")]
+fn py_slip_impact(tasks: TaskInput, task_id: &str, delay_days: f64) -> PyResult<Vec<TargetImpact>> {
+    slip_impact(&tasks.into_tasks(), task_id, delay_days)
+        .map_err(|e| value_error(e.to_string(), e.cycle()))
+}
+
+/// Incomplete tasks blocking `task_id`, each before the tasks it blocks.
+///
+/// # Arguments
+/// * `result` - Partial schedule supplying expected end dates (optional)
+/// * `completed_task_ids` - Finished tasks; the walk stops at them
+///
+/// # Raises
+/// * ValueError if the task is unknown or the graph has a cycle
+#[pyfunction]
+#[pyo3(name = "compute_blockers", signature = (tasks, task_id, result=None, completed_task_ids=None))]
+fn py_compute_blockers(
+    tasks: TaskInput,
+    task_id: &str,
+    result: Option<AlgorithmResult>,
+    completed_task_ids: Option<HashSet<String>>,
+) -> PyResult<Vec<Blocker>> {
+    let completed = completed_task_ids.unwrap_or_default().into_iter().collect();
+    compute_blockers(&tasks.into_tasks(), task_id, &completed, result.as_ref())
+        .map_err(|e| value_error(e.to_string(), e.cycle()))
+}
+
+/// Estimate how a schedule shifts if `task_id` finishes `delay_days` late.
+///
+/// Pushes dependents (and, with `respect_resources`, later tasks on shared
+/// resources) without rescheduling. DNS periods only matter for working-day lags.
+///
+/// # Returns
+/// * SlipAnalysis with the moved tasks' new dates and newly missed deadlines
+///
+/// # Raises
+/// * ValueError if the task is not in the schedule
+#[pyfunction]
+#[pyo3(name = "analyze_slip", signature = (task_id, delay_days, result, tasks, respect_resources=false, global_dns_periods=None))]
+fn py_analyze_slip(
+    task_id: &str,
+    delay_days: f64,
+    result: AlgorithmResult,
+    tasks: TaskInput,
+    respect_resources: bool,
+    global_dns_periods: Option<Vec<(NaiveDate, NaiveDate)>>,
+) -> PyResult<SlipAnalysis> {
+    analyze_slip(
+        &tasks.into_tasks(),
+        &result,
+        task_id,
+        delay_days,
+        respect_resources,
+        &global_dns_periods.unwrap_or_default(),
+    )
+    .map_err(|e| value_error(e.to_string(), e.cycle()))
+}
+
+/// Suggest the latest kickoff date for each deadline target.
+///
+/// Combines the backward pass with resource loading: targets' dependency
+/// subgraphs share explicitly assigned resources, and resources whose loading
+/// pulls a kickoff earlier than its dependency chains alone are reported.
+///
+/// # Arguments
+/// * `tasks` - List of tasks
+/// * `target_ids` - Targets to plan; those without a deadline are omitted
+/// * `completed_task_ids` - Task IDs already completed
+/// * `default_priority` - Default priority for tasks without explicit priority
+/// * `global_dns_periods` - DNS periods that working-day lags skip
+///
+/// # Raises
+/// * ValueError if a target is unknown, the graph has a cycle, or a date falls outside
+///   the supported range
+#[pyfunction]
+#[pyo3(name = "suggest_kickoffs", signature = (tasks, target_ids, completed_task_ids=None, default_priority=None, global_dns_periods=None))]
+fn py_suggest_kickoffs(
+    tasks: TaskInput,
+    target_ids: Vec<String>,
+    completed_task_ids: Option<HashSet<String>>,
+    default_priority: Option<i32>,
+    global_dns_periods: Option<Vec<(NaiveDate, NaiveDate)>>,
+) -> PyResult<Vec<KickoffSuggestion>> {
+    let tasks = tasks.into_tasks();
+    let default_priority =
+        default_priority.unwrap_or_else(|| SchedulingConfig::default().default_priority);
+    let completed: rustc_hash::FxHashSet<String> =
+        completed_task_ids.unwrap_or_default().into_iter().collect();
+    suggest_kickoffs(
+        &tasks,
+        &target_ids,
+        &completed,
+        default_priority,
+        &global_dns_periods.unwrap_or_default(),
+    )
+    .map_err(|e| value_error(e.to_string(), e.cycle()))
+}
+
+/// Latest responsible start for each task, scheduling backwards from deadlines.
+///
+/// Tasks are placed as late as their deadlines and dependents allow, with
+/// explicitly assigned resources taking one task at a time, so work sharing
+/// a person with later work starts earlier.
+///
+/// # Arguments
+/// * `tasks` - List of tasks
+/// * `current_date` - Date `runway_days` counts from
+/// * `completed_task_ids` - Tasks already done (omitted)
+/// * `resource_config` - Resource configuration (DNS periods)
+/// * `global_dns_periods` - DNS periods applying to every resource
+///
+/// # Returns
+/// * List of LatestStart, earliest first; tasks with no deadline downstream are omitted
+///
+/// # Raises
+/// * ValueError if the graph has a cycle or a date falls outside the supported range
+#[pyfunction]
+#[pyo3(name = "latest_starts", signature = (tasks, current_date, completed_task_ids=None, resource_config=None, global_dns_periods=None))]
+fn py_latest_starts(
+    tasks: TaskInput,
+    current_date: NaiveDate,
+    completed_task_ids: Option<HashSet<String>>,
+    resource_config: Option<PyResourceConfig>,
+    global_dns_periods: Option<Vec<(NaiveDate, NaiveDate)>>,
+) -> PyResult<Vec<LatestStart>> {
+    let completed: rustc_hash::FxHashSet<String> =
+        completed_task_ids.unwrap_or_default().into_iter().collect();
+    let resource_config = resource_config.map(ResourceConfig::from);
+    latest_starts(
+        &tasks.into_tasks(),
+        current_date,
+        &completed,
+        resource_config.as_ref(),
+        &global_dns_periods.unwrap_or_default(),
+    )
+    .map_err(|e| value_error(e.to_string(), e.cycle()))
+}
+
+/// Suggest the cheapest crash set for each target that misses its deadline.
+///
+/// Follows each late target's chain of binding predecessors in `result` and
+/// takes days from its `crashable` tasks, cheapest per day saved first.
+///
+/// # Arguments
+/// * `tasks` - List of tasks
+/// * `result` - Schedule to recover
+/// * `target_ids` - Targets to recover (default: every task past its deadline)
+/// * `global_dns_periods` - DNS periods that working-day lags skip
+///
+/// # Raises
+/// * ValueError if a target is not in the schedule
+/// * InvalidConfigError if a crash spec is negative, non-finite, or longer than its task
+#[pyfunction]
+#[pyo3(name = "suggest_crashes", signature = (tasks, result, target_ids=None, global_dns_periods=None))]
+fn py_suggest_crashes(
+    tasks: TaskInput,
+    result: AlgorithmResult,
+    target_ids: Option<Vec<String>>,
+    global_dns_periods: Option<Vec<(NaiveDate, NaiveDate)>>,
+) -> PyResult<Vec<CrashPlan>> {
+    suggest_crashes(
+        &tasks.into_tasks(),
+        &result,
+        target_ids.as_deref(),
+        &global_dns_periods.unwrap_or_default(),
+    )
+    .map_err(|e| match e {
+        CrashError::InvalidCrashSpec(_) => InvalidConfigError::new_err(e.to_string()),
+        CrashError::UnknownTargets(_) => value_error(e.to_string(), None),
+    })
+}
+
+/// Schedule once per objective and return the Pareto-optimal schedules.
+///
+/// Runs the critical path scheduler with each `ScheduleObjective` in
+/// `objectives` and keeps the schedules whose `ScheduleMetrics` (makespan,
+/// weighted tardiness, weighted flow time, idle days, cost) no other schedule
+/// beats on every metric. Objectives act through rollout, so `config` should
+/// have rollout enabled.
+///
+/// # Raises
+/// * ValueError if scheduling fails
+#[pyfunction]
+#[pyo3(name = "pareto_frontier", signature = (
+    tasks,
+    current_date,
+    objectives,
+    completed_task_ids=None,
+    default_priority=None,
+    config=None,
+    resource_config=None,
+    global_dns_periods=None
+))]
+#[allow(clippy::too_many_arguments)]
+fn py_pareto_frontier(
+    py: Python<'_>,
+    tasks: TaskInput,
+    current_date: NaiveDate,
+    objectives: Vec<ScheduleObjective>,
+    completed_task_ids: Option<HashSet<String>>,
+    default_priority: Option<i32>,
+    config: Option<CriticalPathConfig>,
+    resource_config: Option<PyResourceConfig>,
+    global_dns_periods: Option<Vec<(NaiveDate, NaiveDate)>>,
+) -> PyResult<Vec<ParetoSchedule>> {
+    let tasks = tasks.into_tasks();
+    let completed: rustc_hash::FxHashSet<String> =
+        completed_task_ids.unwrap_or_default().into_iter().collect();
+    let default_priority =
+        default_priority.unwrap_or_else(|| SchedulingConfig::default().default_priority);
+    let config = config.unwrap_or_default();
+    let resource_config = resource_config.map(ResourceConfig::from);
+    let global_dns_periods = global_dns_periods.unwrap_or_default();
+    py.allow_threads(|| {
+        pareto_frontier(
+            &tasks,
+            current_date,
+            &completed,
+            default_priority,
+            &config,
+            resource_config.as_ref(),
+            &global_dns_periods,
+            &objectives,
+        )
+    })
+    .map_err(PyErr::from)
+}
+
+/// Search k, urgency_floor, work_exponent and no_deadline_urgency_multiplier.
+///
+/// Schedules `tasks` once per `grid` combination (or `samples` random ones)
+/// plus `config` itself, and returns the combination with the lowest
+/// objective cost under `config.objective`, with every trial ranked.
+///
+/// # Raises
+/// * ValueError if scheduling fails
+#[pyfunction]
+#[pyo3(name = "tune_critical_path", signature = (
+    tasks,
+    current_date,
+    completed_task_ids=None,
+    default_priority=None,
+    config=None,
+    resource_config=None,
+    global_dns_periods=None,
+    grid=None,
+    samples=None,
+    seed=0
+))]
+#[allow(clippy::too_many_arguments)]
+fn py_tune_critical_path(
+    py: Python<'_>,
+    tasks: TaskInput,
+    current_date: NaiveDate,
+    completed_task_ids: Option<HashSet<String>>,
+    default_priority: Option<i32>,
+    config: Option<CriticalPathConfig>,
+    resource_config: Option<PyResourceConfig>,
+    global_dns_periods: Option<Vec<(NaiveDate, NaiveDate)>>,
+    grid: Option<TuningGrid>,
+    samples: Option<usize>,
+    seed: u64,
+) -> PyResult<TuningResult> {
+    let instance = TuningInstance {
+        tasks: tasks.into_tasks(),
+        current_date,
+        completed_task_ids: completed_task_ids.unwrap_or_default().into_iter().collect(),
+        resource_config: resource_config.map(ResourceConfig::from),
+        global_dns_periods: global_dns_periods.unwrap_or_default(),
+    };
+    let default_priority =
+        default_priority.unwrap_or_else(|| SchedulingConfig::default().default_priority);
+    let config = config.unwrap_or_default();
+    let grid = grid.unwrap_or_default();
+    py.allow_threads(|| {
+        tune_critical_path(&[instance], &config, default_priority, &grid, samples, seed)
+    })
+    .map_err(PyErr::from)
+}
+
+/// Render a schedule as a Mermaid gantt chart, one section per primary resource.
+#[pyfunction]
+#[pyo3(name = "export_mermaid")]
+fn py_export_mermaid(result: AlgorithmResult, tasks: Vec<Task>) -> String {
+    export::to_mermaid(&result, &tasks)
+}
+
+/// Render a schedule as CSV, one row per scheduled task ordered by start date.
+#[pyfunction]
+#[pyo3(name = "export_csv")]
+fn py_export_csv(result: AlgorithmResult, tasks: Vec<Task>) -> String {
+    export::to_csv(&result, &tasks)
+}
+
+/// Render a schedule as JSON (schema documented in `mouc.rust` stubs).
+#[pyfunction]
+#[pyo3(name = "export_json")]
+fn py_export_json(result: AlgorithmResult, tasks: Vec<Task>) -> String {
+    export::to_json(&result, &tasks)
+}
+
+/// Render per-resource iCalendar (.ics) calendars for a schedule.
+///
+/// # Arguments
+/// * `result` - Schedule to export
+/// * `tasks` - Tasks the schedule was built from (for descriptions)
+/// * `resource_config` - Optional resource configuration; adds its resources and DNS periods
+/// * `global_dns_periods` - Unavailable periods applied to every resource
+///
+/// # Returns
+/// * Dict mapping resource name to calendar text
+///
+/// # Raises
+/// * ValueError if an event ends past the last representable date
+#[pyfunction]
+#[pyo3(name = "export_ics", signature = (result, tasks, resource_config=None, global_dns_periods=None))]
+fn py_export_ics(
+    result: AlgorithmResult,
+    tasks: Vec<Task>,
+    resource_config: Option<PyResourceConfig>,
+    global_dns_periods: Option<Vec<(NaiveDate, NaiveDate)>>,
+) -> PyResult<HashMap<String, String>> {
+    let rust_resource_config = resource_config.map(ResourceConfig::from);
+    let calendars = export::to_ics_by_resource(
+        &result,
+        &tasks,
+        rust_resource_config.as_ref(),
+        &global_dns_periods.unwrap_or_default(),
+    )
+    .map_err(|e| value_error(e.to_string(), None))?;
+    Ok(calendars.into_iter().collect())
+}
+
+/// Convert a schedule to a `pyarrow.RecordBatch`, one row per (task, resource).
+///
+/// Columns: task_id, start, end, resource (null if unassigned), duration.
+#[cfg(feature = "arrow")]
+#[pyfunction]
+#[pyo3(name = "export_arrow")]
+fn py_export_arrow(py: Python<'_>, result: AlgorithmResult) -> PyResult<PyObject> {
+    use arrow::pyarrow::ToPyArrow;
+
+    export::to_record_batch(&result)
+        .map_err(|e| pyo3::exceptions::PyValueError::new_err(e.to_string()))?
+        .to_pyarrow(py)
+}
+
+/// Parse tasks from JSON text without constructing them in Python.
+///
+/// # Raises
+/// * ValueError naming the offending task, field, or line
+#[pyfunction]
+#[pyo3(name = "tasks_from_json")]
+fn py_tasks_from_json(text: &str) -> PyResult<Vec<Task>> {
+    tasks_from_json(text).map_err(|e| pyo3::exceptions::PyValueError::new_err(e.to_string()))
+}
+
+/// Parse tasks from CSV text (with a header row) without constructing them in Python.
+///
+/// # Raises
+/// * ValueError naming the offending line and field
+#[pyfunction]
+#[pyo3(name = "tasks_from_csv")]
+fn py_tasks_from_csv(text: &str) -> PyResult<Vec<Task>> {
+    tasks_from_csv(text).map_err(|e| pyo3::exceptions::PyValueError::new_err(e.to_string()))
+}
+
+/// Tasks built in Rust from columnar arrays (PyO3 wrapper).
+///
+/// Pass this in place of a task list to the scheduler constructors to skip
+/// building one Python `Task` object per task.
+#[pyclass(name = "TaskColumns")]
+#[derive(Clone, Debug)]
+pub struct PyTaskColumns {
+    tasks: Vec<Task>,
+}
+
+#[pymethods]
+impl PyTaskColumns {
+    /// Build tasks from parallel arrays (lists or numpy arrays).
+    ///
+    /// `dependency_edges` is a `(dependents, dependencies)` pair of index arrays;
+    /// edge `i` makes `ids[dependents[i]]` depend on `ids[dependencies[i]]`.
+    #[new]
+    #[pyo3(signature = (
+        ids,
+        durations,
+        priorities=None,
+        dependency_edges=None,
+        lags=None,
+        resources=None,
+        resource_specs=None,
+        start_afters=None,
+        end_befores=None
+    ))]
+    #[allow(clippy::too_many_arguments)]
+    fn new(
+        ids: Vec<String>,
+        durations: Vec<f64>,
+        priorities: Option<Vec<Option<i32>>>,
+        dependency_edges: Option<(Vec<u32>, Vec<u32>)>,
+        lags: Option<Vec<f64>>,
+        resources: Option<Vec<Option<String>>>,
+        resource_specs: Option<Vec<Option<String>>>,
+        start_afters: Option<Vec<Option<NaiveDate>>>,
+        end_befores: Option<Vec<Option<NaiveDate>>>,
+    ) -> PyResult<Self> {
+        let (dependents, dependencies) = dependency_edges.unwrap_or_default();
+        if dependents.len() != dependencies.len() {
+            return Err(pyo3::exceptions::PyValueError::new_err(format!(
+                "dependency_edges arrays differ in length ({} vs {})",
+                dependents.len(),
+                dependencies.len()
+            )));
+        }
+        let columns = TaskColumns {
+            ids,
+            durations,
+            priorities,
+            dependency_edges: dependents.into_iter().zip(dependencies).collect(),
+            lags,
+            resources,
+            resource_specs,
+            start_afters,
+            end_befores,
+        };
+        tasks_from_columns(columns)
+            .map(|tasks| Self { tasks })
+            .map_err(|e| pyo3::exceptions::PyValueError::new_err(e.to_string()))
+    }
+
+    /// Materialize the tasks as Python `Task` objects.
+    fn to_tasks(&self) -> Vec<Task> {
+        self.tasks.clone()
+    }
+
+    fn __len__(&self) -> usize {
+        self.tasks.len()
+    }
+
+    fn __repr__(&self) -> String {
+        format!("TaskColumns(tasks={})", self.tasks.len())
+    }
+}
+
+/// Scheduler input: a list of `Task` objects or prebuilt `TaskColumns`.
+#[derive(FromPyObject)]
+enum TaskInput {
+    Columns(PyTaskColumns),
+    List(Vec<Task>),
+}
+
+impl TaskInput {
+    fn into_tasks(self) -> Vec<Task> {
+        match self {
+            TaskInput::Columns(columns) => columns.tasks,
+            TaskInput::List(tasks) => tasks,
+        }
+    }
+}
+
 /// Resource configuration for the scheduler (PyO3 wrapper).
 #[pyclass(name = "ResourceConfig")]
 #[derive(Clone, Debug, Default)]
@@ -178,21 +1016,81 @@ pub struct PyResourceConfig {
     pub dns_periods: HashMap<String, Vec<(NaiveDate, NaiveDate)>>,
     #[pyo3(get, set)]
     pub spec_expansion: HashMap<String, Vec<String>>,
+    #[pyo3(get, set)]
+    pub cost_rates: HashMap<String, f64>,
+    #[pyo3(get, set)]
+    pub cost_weight: f64,
+    #[pyo3(get, set)]
+    pub skills: HashMap<String, Vec<String>>,
+    #[pyo3(get, set)]
+    pub efficiency: HashMap<String, f64>,
+    #[pyo3(get, set)]
+    pub skill_efficiency: HashMap<String, HashMap<String, f64>>,
+    #[pyo3(get, set)]
+    pub capacity: HashMap<String, f64>,
+    #[pyo3(get, set)]
+    pub prior_assignments: HashMap<String, String>,
+    #[pyo3(get, set)]
+    pub switch_penalty_days: f64,
+    #[pyo3(get, set)]
+    pub changeover_days: HashMap<String, HashMap<String, f64>>,
+    #[pyo3(get, set)]
+    pub capacity_segments: HashMap<String, Vec<(NaiveDate, f64)>>,
+    #[pyo3(get, set)]
+    pub attributes: HashMap<String, HashMap<String, String>>,
 }
 
 #[pymethods]
 impl PyResourceConfig {
     #[new]
-    #[pyo3(signature = (resource_order=None, dns_periods=None, spec_expansion=None))]
+    #[pyo3(signature = (
+        resource_order=None,
+        dns_periods=None,
+        spec_expansion=None,
+        cost_rates=None,
+        cost_weight=0.0,
+        skills=None,
+        efficiency=None,
+        skill_efficiency=None,
+        capacity=None,
+        prior_assignments=None,
+        switch_penalty_days=0.0,
+        changeover_days=None,
+        capacity_segments=None,
+        attributes=None
+    ))]
+    #[allow(clippy::too_many_arguments)]
     fn new(
         resource_order: Option<Vec<String>>,
         dns_periods: Option<HashMap<String, Vec<(NaiveDate, NaiveDate)>>>,
         spec_expansion: Option<HashMap<String, Vec<String>>>,
+        cost_rates: Option<HashMap<String, f64>>,
+        cost_weight: f64,
+        skills: Option<HashMap<String, Vec<String>>>,
+        efficiency: Option<HashMap<String, f64>>,
+        skill_efficiency: Option<HashMap<String, HashMap<String, f64>>>,
+        capacity: Option<HashMap<String, f64>>,
+        prior_assignments: Option<HashMap<String, String>>,
+        switch_penalty_days: f64,
+        changeover_days: Option<HashMap<String, HashMap<String, f64>>>,
+        capacity_segments: Option<HashMap<String, Vec<(NaiveDate, f64)>>>,
+        attributes: Option<HashMap<String, HashMap<String, String>>>,
     ) -> Self {
         Self {
             resource_order: resource_order.unwrap_or_default(),
             dns_periods: dns_periods.unwrap_or_default(),
             spec_expansion: spec_expansion.unwrap_or_default(),
+            cost_rates: cost_rates.unwrap_or_default(),
+            cost_weight,
+            skills: skills.unwrap_or_default(),
+            efficiency: efficiency.unwrap_or_default(),
+            skill_efficiency: skill_efficiency.unwrap_or_default(),
+            capacity: capacity.unwrap_or_default(),
+            prior_assignments: prior_assignments.unwrap_or_default(),
+            switch_penalty_days,
+            changeover_days: changeover_days.unwrap_or_default(),
+            capacity_segments: capacity_segments.unwrap_or_default(),
+            attributes: attributes.unwrap_or_default(),
         }
     }
 
@@ -206,6 +1104,27 @@ impl PyResourceConfig {
     }
 }
 
+impl From<PyResourceConfig> for ResourceConfig {
+    fn from(rc: PyResourceConfig) -> Self {
+        Self {
+            resource_order: rc.resource_order,
+            dns_periods: rc.dns_periods,
+            spec_expansion: rc.spec_expansion,
+            cost_rates: rc.cost_rates,
+            cost_weight: rc.cost_weight,
+            skills: rc.skills,
+            efficiency: rc.efficiency,
+            skill_efficiency: rc.skill_efficiency,
+            capacity: rc.capacity,
+            prior_assignments: rc.prior_assignments,
+            switch_penalty_days: rc.switch_penalty_days,
+            changeover_days: rc.changeover_days,
+            capacity_segments: rc.capacity_segments,
+            attributes: rc.attributes,
+        }
+    }
+}
+
 /// Rollout decision record (PyO3 wrapper).
 #[pyclass(name = "RolloutDecision")]
 #[derive(Clone, Debug)]
@@ -259,10 +1178,311 @@ impl From<RolloutDecision> for PyRolloutDecision {
     }
 }
 
+/// Critical path rollout decision record (PyO3 wrapper).
+#[pyclass(name = "CriticalPathRolloutDecision")]
+#[derive(Clone, Debug)]
+pub struct PyCriticalPathRolloutDecision {
+    #[pyo3(get)]
+    pub decision_date: NaiveDate,
+    #[pyo3(get)]
+    pub task_id: String,
+    #[pyo3(get)]
+    pub task_score: f64,
+    #[pyo3(get)]
+    pub competing_target_id: String,
+    #[pyo3(get)]
+    pub competing_target_score: f64,
+    #[pyo3(get)]
+    pub competing_task_id: String,
+    #[pyo3(get)]
+    pub competing_eligible_date: NaiveDate,
+    #[pyo3(get)]
+    pub schedule_score: f64,
+    #[pyo3(get)]
+    pub skip_score: f64,
+    #[pyo3(get)]
+    pub decision: String,
+    #[pyo3(get)]
+    pub reserved_resource: Option<String>,
+}
+
+#[pymethods]
+impl PyCriticalPathRolloutDecision {
+    fn __repr__(&self) -> String {
+        format!(
+            "CriticalPathRolloutDecision(task={}, competing_target={}, decision={})",
+            self.task_id, self.competing_target_id, self.decision
+        )
+    }
+}
+
+impl From<CriticalPathRolloutDecision> for PyCriticalPathRolloutDecision {
+    fn from(rd: CriticalPathRolloutDecision) -> Self {
+        Self {
+            decision_date: rd.decision_date,
+            task_id: rd.task_id,
+            task_score: rd.task_score,
+            competing_target_id: rd.competing_target_id,
+            competing_target_score: rd.competing_target_score,
+            competing_task_id: rd.competing_task_id,
+            competing_eligible_date: rd.competing_eligible_date,
+            schedule_score: rd.schedule_score,
+            skip_score: rd.skip_score,
+            decision: rd.decision,
+            reserved_resource: rd.reserved_resource,
+        }
+    }
+}
+
+/// Resource reservation record (PyO3 wrapper).
+#[pyclass(name = "ReservationRecord")]
+#[derive(Clone, Debug)]
+pub struct PyReservationRecord {
+    #[pyo3(get)]
+    pub resource: String,
+    #[pyo3(get)]
+    pub target_id: String,
+    #[pyo3(get)]
+    pub task_id: String,
+    #[pyo3(get)]
+    pub reserved_from: NaiveDate,
+    #[pyo3(get)]
+    pub released_on: NaiveDate,
+    #[pyo3(get)]
+    pub held_days: i64,
+    #[pyo3(get)]
+    pub outcome: String,
+}
+
+#[pymethods]
+impl PyReservationRecord {
+    fn __repr__(&self) -> String {
+        format!(
+            "ReservationRecord(resource={}, task={}, held_days={}, outcome={})",
+            self.resource, self.task_id, self.held_days, self.outcome
+        )
+    }
+}
+
+impl From<ReservationRecord> for PyReservationRecord {
+    fn from(r: ReservationRecord) -> Self {
+        Self {
+            held_days: r.held_days(),
+            resource: r.resource,
+            target_id: r.target_id,
+            task_id: r.task_id,
+            reserved_from: r.reserved_from,
+            released_on: r.released_on,
+            outcome: r.outcome,
+        }
+    }
+}
+
+/// Critical path of a target (PyO3 wrapper).
+#[pyclass(name = "CriticalPathResult")]
+#[derive(Clone, Debug)]
+pub struct PyCriticalPathResult {
+    /// Timing of each task in the target's unscheduled dependency subgraph.
+    #[pyo3(get)]
+    pub task_timings: HashMap<String, TaskTiming>,
+    /// Zero-slack task IDs, sorted.
+    #[pyo3(get)]
+    pub critical_path_tasks: Vec<String>,
+    #[pyo3(get)]
+    pub critical_path_length: f64,
+    /// Sum of task durations in the subgraph.
+    #[pyo3(get)]
+    pub total_work: f64,
+}
+
+#[pymethods]
+impl PyCriticalPathResult {
+    fn __repr__(&self) -> String {
+        format!(
+            "CriticalPathResult(tasks={}, critical={}, length={}, total_work={})",
+            self.task_timings.len(),
+            self.critical_path_tasks.len(),
+            self.critical_path_length,
+            self.total_work
+        )
+    }
+}
+
+impl From<CriticalPathResult> for PyCriticalPathResult {
+    fn from(r: CriticalPathResult) -> Self {
+        let mut critical_path_tasks: Vec<String> = r.critical_path_tasks.into_iter().collect();
+        critical_path_tasks.sort();
+        Self {
+            task_timings: r.task_timings.into_iter().collect(),
+            critical_path_tasks,
+            critical_path_length: r.critical_path_length,
+            total_work: r.total_work,
+        }
+    }
+}
+
+/// Holds a scheduler that can be lent out to a background thread.
+///
+/// The slot is empty while a `schedule()` run owns the scheduler, so other calls
+/// fail fast instead of blocking while the GIL is held.
+struct SchedulerSlot<S>(Arc<Mutex<Option<S>>>);
+
+impl<S: Send + 'static> SchedulerSlot<S> {
+    fn new(scheduler: S) -> Self {
+        Self(Arc::new(Mutex::new(Some(scheduler))))
+    }
+
+    fn lock(&self) -> MutexGuard<'_, Option<S>> {
+        self.0
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+
+    fn busy_error() -> PyErr {
+        pyo3::exceptions::PyRuntimeError::new_err("Scheduler is busy running schedule()")
+    }
+
+    /// Borrow the scheduler, failing if a run is in progress.
+    fn with<R>(&self, f: impl FnOnce(&mut S) -> R) -> PyResult<R> {
+        self.lock().as_mut().map(f).ok_or_else(Self::busy_error)
+    }
+
+    /// Take the scheduler out of the slot for the duration of a run.
+    ///
+    /// The scheduler goes back when the lease is dropped, even if the run panics.
+    fn take(&self) -> PyResult<SlotLease<S>> {
+        let scheduler = self.lock().take().ok_or_else(Self::busy_error)?;
+        Ok(SlotLease {
+            slot: Arc::clone(&self.0),
+            scheduler: Some(scheduler),
+        })
+    }
+
+    /// Run `f` on a background thread, returning the scheduler to the slot afterwards.
+    ///
+    /// A panic in `f` is reported as a failed run by `join()`.
+    fn spawn(
+        &self,
+        cancel_token: CancellationToken,
+        f: impl FnOnce(&mut S) -> Result<AlgorithmResult, ScheduleFailure> + Send + 'static,
+    ) -> PyResult<PyScheduleHandle> {
+        let mut scheduler = self.take()?;
+        let thread = std::thread::spawn(move || {
+            std::panic::catch_unwind(AssertUnwindSafe(|| f(&mut scheduler)))
+                .unwrap_or_else(|panic| Err(panic_failure(panic.as_ref())))
+        });
+        Ok(PyScheduleHandle {
+            thread: Some(thread),
+            outcome: None,
+            cancel_token,
+        })
+    }
+}
+
+/// A scheduler taken out of its `SchedulerSlot`, put back when dropped.
+struct SlotLease<S> {
+    slot: Arc<Mutex<Option<S>>>,
+    scheduler: Option<S>,
+}
+
+impl<S> Deref for SlotLease<S> {
+    type Target = S;
+
+    fn deref(&self) -> &S {
+        self.scheduler
+            .as_ref()
+            .expect("scheduler is held until drop")
+    }
+}
+
+impl<S> DerefMut for SlotLease<S> {
+    fn deref_mut(&mut self) -> &mut S {
+        self.scheduler
+            .as_mut()
+            .expect("scheduler is held until drop")
+    }
+}
+
+impl<S> Drop for SlotLease<S> {
+    fn drop(&mut self) {
+        let mut slot = self
+            .slot
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner());
+        *slot = self.scheduler.take();
+    }
+}
+
+/// The failure reported for a scheduling run that panicked.
+fn panic_failure(panic: &(dyn std::any::Any + Send)) -> ScheduleFailure {
+    let message = panic
+        .downcast_ref::<&str>()
+        .copied()
+        .or_else(|| panic.downcast_ref::<String>().map(String::as_str))
+        .unwrap_or("unknown cause");
+    ScheduleFailure::Other(format!("Scheduling thread panicked: {}", message))
+}
+
+/// Handle to a schedule running on a background thread.
+#[pyclass(name = "ScheduleHandle")]
+pub struct PyScheduleHandle {
+    thread: Option<JoinHandle<Result<AlgorithmResult, ScheduleFailure>>>,
+    outcome: Option<Result<AlgorithmResult, ScheduleFailure>>,
+    cancel_token: CancellationToken,
+}
+
+#[pymethods]
+impl PyScheduleHandle {
+    /// Check whether the background run has finished (non-blocking).
+    fn is_done(&self) -> bool {
+        self.thread.as_ref().is_none_or(|t| t.is_finished())
+    }
+
+    /// Request cancellation of the background run.
+    fn cancel(&self) {
+        self.cancel_token.cancel();
+    }
+
+    /// Wait for the run to finish (releasing the GIL) and return its result.
+    fn join(&mut self, py: Python<'_>) -> PyResult<AlgorithmResult> {
+        if let Some(thread) = self.thread.take() {
+            let outcome = py
+                .allow_threads(|| thread.join())
+                .unwrap_or_else(|panic| Err(panic_failure(panic.as_ref())));
+            self.outcome = Some(outcome);
+        }
+        match &self.outcome {
+            Some(Ok(result)) => Ok(result.clone()),
+            Some(Err(failure)) => Err(failure.clone().into()),
+            None => unreachable!("outcome is set once the thread is joined"),
+        }
+    }
+
+    fn __repr__(&self) -> String {
+        format!("ScheduleHandle(done={})", self.is_done())
+    }
+}
+
+/// Task list with dependencies on `external_completions` resolved to `start_after` dates.
+fn with_external_completions(
+    tasks: TaskInput,
+    external_completions: Option<HashMap<String, NaiveDate>>,
+    global_dns_periods: &[(NaiveDate, NaiveDate)],
+) -> Vec<Task> {
+    match external_completions {
+        Some(completions) => apply_external_completions(
+            tasks.into_tasks(),
+            &completions.into_iter().collect(),
+            global_dns_periods,
+        ),
+        None => tasks.into_tasks(),
+    }
+}
+
 /// Rust parallel scheduler (PyO3 wrapper).
 #[pyclass(name = "ParallelScheduler")]
 pub struct PyParallelScheduler {
-    inner: ParallelScheduler,
+    inner: SchedulerSlot<ParallelScheduler>,
 }
 
 #[pymethods]
@@ -276,11 +1496,12 @@ impl PyParallelScheduler {
         rollout_config=None,
         resource_config=None,
         global_dns_periods=None,
-        preprocess_result=None
+        preprocess_result=None,
+        external_completions=None
     ))]
     #[allow(clippy::too_many_arguments)]
     fn new(
-        tasks: Vec<Task>,
+        tasks: TaskInput,
         current_date: NaiveDate,
         completed_task_ids: Option<HashSet<String>>,
         config: Option<SchedulingConfig>,
@@ -288,14 +1509,11 @@ impl PyParallelScheduler {
         resource_config: Option<PyResourceConfig>,
         global_dns_periods: Option<Vec<(NaiveDate, NaiveDate)>>,
         preprocess_result: Option<PreProcessResult>,
+        external_completions: Option<HashMap<String, NaiveDate>>,
     ) -> PyResult<Self> {
         use rustc_hash::{FxHashMap, FxHashSet};
 
-        let rust_resource_config = resource_config.map(|rc| ResourceConfig {
-            resource_order: rc.resource_order,
-            dns_periods: rc.dns_periods,
-            spec_expansion: rc.spec_expansion,
-        });
+        let rust_resource_config = resource_config.map(ResourceConfig::from);
 
         // Convert std HashMap to FxHashMap for internal use
         let (deadlines, priorities) = match preprocess_result {
@@ -318,6 +1536,9 @@ impl PyParallelScheduler {
         let completed: FxHashSet<String> =
             completed_task_ids.unwrap_or_default().into_iter().collect();
 
+        let global_dns_periods = global_dns_periods.unwrap_or_default();
+        let tasks = with_external_completions(tasks, external_completions, &global_dns_periods);
+
         match ParallelScheduler::new(
             tasks,
             current_date,
@@ -325,40 +1546,136 @@ impl PyParallelScheduler {
             config.unwrap_or_default(),
             rollout_config,
             rust_resource_config,
-            global_dns_periods.unwrap_or_default(),
+            global_dns_periods,
             deadlines,
             priorities,
         ) {
-            Ok(scheduler) => Ok(Self { inner: scheduler }),
-            Err(e) => Err(pyo3::exceptions::PyValueError::new_err(e.to_string())),
+            Ok(scheduler) => Ok(Self {
+                inner: SchedulerSlot::new(scheduler),
+            }),
+            Err(e) => Err(e.into()),
         }
     }
 
     /// Run the scheduling algorithm.
-    fn schedule(&mut self) -> PyResult<AlgorithmResult> {
-        match self.inner.schedule() {
-            Ok(result) => Ok(result),
-            Err(e) => Err(pyo3::exceptions::PyValueError::new_err(e.to_string())),
+    ///
+    /// If `cancel_token` is given, calling `cancel()` on it stops the run.
+    /// If `progress_callback` is given, it is called every `progress_interval`
+    /// iterations with `(tasks_scheduled, tasks_remaining, current_time)`.
+    #[pyo3(signature = (cancel_token=None, progress_callback=None, progress_interval=1))]
+    fn schedule(
+        &self,
+        py: Python<'_>,
+        cancel_token: Option<CancellationToken>,
+        progress_callback: Option<PyObject>,
+        progress_interval: usize,
+    ) -> PyResult<AlgorithmResult> {
+        let mut scheduler = self.inner.take()?;
+        if let Some(token) = cancel_token {
+            scheduler.set_cancellation_token(token);
+        }
+        if let Some(callback) = progress_callback {
+            scheduler.set_progress_reporter(ProgressReporter::from_py(progress_interval, callback));
         }
+        let outcome = py.allow_threads(|| scheduler.schedule());
+        outcome.map_err(PyErr::from)
+    }
+
+    /// Start scheduling on a background thread and return a `ScheduleHandle`.
+    ///
+    /// The GIL is released while scheduling runs; poll `is_done()` or call
+    /// `join()` on the handle to retrieve the result.
+    #[pyo3(signature = (cancel_token=None, progress_callback=None, progress_interval=1))]
+    fn schedule_background(
+        &self,
+        cancel_token: Option<CancellationToken>,
+        progress_callback: Option<PyObject>,
+        progress_interval: usize,
+    ) -> PyResult<PyScheduleHandle> {
+        let token = cancel_token.unwrap_or_default();
+        let reporter = progress_callback
+            .map(|callback| ProgressReporter::from_py(progress_interval, callback));
+        self.inner.spawn(token.clone(), move |scheduler| {
+            scheduler.set_cancellation_token(token);
+            if let Some(reporter) = reporter {
+                scheduler.set_progress_reporter(reporter);
+            }
+            scheduler.schedule().map_err(|e| ScheduleFailure::from(&e))
+        })
+    }
+
+    /// Schedule decisions up to and including `pause_at` and return a `SchedulerSnapshot`.
+    ///
+    /// Pass the snapshot, possibly edited to pin or drop placements, to
+    /// `restore()` to continue later.
+    fn schedule_until(&self, py: Python<'_>, pause_at: NaiveDate) -> PyResult<SchedulerSnapshot> {
+        let mut scheduler = self.inner.take()?;
+        let outcome = py.allow_threads(|| scheduler.schedule_until(pause_at));
+        outcome.map_err(PyErr::from)
+    }
+
+    /// Make exactly one decision (place a task or advance time) and return a `ScheduleStep`.
+    ///
+    /// Each call continues from the previous step; kind "finished" means
+    /// nothing is left to decide. With `dry_run` the decision is reported but
+    /// not made, so the next call starts from the same place.
+    #[pyo3(signature = (dry_run=false))]
+    fn schedule_step(&self, py: Python<'_>, dry_run: bool) -> PyResult<ScheduleStep> {
+        let mut scheduler = self.inner.take()?;
+        let outcome = py.allow_threads(|| {
+            if dry_run {
+                scheduler.preview_step()
+            } else {
+                scheduler.schedule_step()
+            }
+        });
+        outcome.map_err(PyErr::from)
+    }
+
+    /// Make later runs resume from `snapshot`; `None` schedules from scratch again.
+    #[pyo3(signature = (snapshot=None))]
+    fn restore(&self, snapshot: Option<SchedulerSnapshot>) -> PyResult<()> {
+        self.inner.with(|s| s.restore(snapshot))
     }
 
     /// Get computed deadlines.
-    fn get_computed_deadlines(&self) -> HashMap<String, NaiveDate> {
-        self.inner.get_computed_deadlines()
+    fn get_computed_deadlines(&self) -> PyResult<HashMap<String, NaiveDate>> {
+        self.inner.with(|s| s.get_computed_deadlines())
     }
 
     /// Get computed priorities.
-    fn get_computed_priorities(&self) -> HashMap<String, i32> {
-        self.inner.get_computed_priorities()
+    fn get_computed_priorities(&self) -> PyResult<HashMap<String, i32>> {
+        self.inner.with(|s| s.get_computed_priorities())
+    }
+
+    /// Tasks that could start on `as_of_date`, with the blockers of the rest.
+    fn get_eligible_tasks(&self, as_of_date: NaiveDate) -> PyResult<Vec<TaskEligibility>> {
+        self.inner.with(|s| s.get_eligible_tasks(as_of_date))
     }
 
     /// Get rollout decisions (only populated if rollout was enabled).
-    fn get_rollout_decisions(&self) -> Vec<PyRolloutDecision> {
-        self.inner
-            .get_rollout_decisions()
-            .into_iter()
-            .map(PyRolloutDecision::from)
-            .collect()
+    fn get_rollout_decisions(&self) -> PyResult<Vec<PyRolloutDecision>> {
+        self.inner.with(|s| {
+            s.get_rollout_decisions()
+                .into_iter()
+                .map(PyRolloutDecision::from)
+                .collect()
+        })
+    }
+
+    /// Simulated schedules behind the last rollout decision (needs `trace_rollouts`).
+    fn get_last_rollout_trace(&self) -> PyResult<Option<RolloutTrace>> {
+        self.inner.with(|s| s.get_last_rollout_trace())
+    }
+
+    /// Get resource reservations made by rollout skips during the last run.
+    fn get_reservations(&self) -> PyResult<Vec<PyReservationRecord>> {
+        self.inner.with(|s| {
+            s.get_reservations()
+                .into_iter()
+                .map(PyReservationRecord::from)
+                .collect()
+        })
     }
 
     fn __repr__(&self) -> String {
@@ -369,7 +1686,7 @@ impl PyParallelScheduler {
 /// Rust critical path scheduler (PyO3 wrapper).
 #[pyclass(name = "CriticalPathScheduler")]
 pub struct PyCriticalPathScheduler {
-    inner: CriticalPathScheduler,
+    inner: SchedulerSlot<CriticalPathScheduler>,
 }
 
 #[pymethods]
@@ -382,25 +1699,23 @@ impl PyCriticalPathScheduler {
         default_priority=None,
         config=None,
         resource_config=None,
-        global_dns_periods=None
+        global_dns_periods=None,
+        external_completions=None
     ))]
     #[allow(clippy::too_many_arguments)]
     fn new(
-        tasks: Vec<Task>,
+        tasks: TaskInput,
         current_date: NaiveDate,
         completed_task_ids: Option<HashSet<String>>,
         default_priority: Option<i32>,
         config: Option<CriticalPathConfig>,
         resource_config: Option<PyResourceConfig>,
         global_dns_periods: Option<Vec<(NaiveDate, NaiveDate)>>,
+        external_completions: Option<HashMap<String, NaiveDate>>,
     ) -> PyResult<Self> {
         use rustc_hash::FxHashSet;
 
-        let rust_resource_config = resource_config.map(|rc| ResourceConfig {
-            resource_order: rc.resource_order,
-            dns_periods: rc.dns_periods,
-            spec_expansion: rc.spec_expansion,
-        });
+        let rust_resource_config = resource_config.map(ResourceConfig::from);
 
         // Use provided default_priority or fall back to global SchedulingConfig default
         let effective_default_priority =
@@ -410,25 +1725,190 @@ impl PyCriticalPathScheduler {
         let completed: FxHashSet<String> =
             completed_task_ids.unwrap_or_default().into_iter().collect();
 
-        let scheduler = CriticalPathScheduler::new(
-            tasks,
+        let mut scheduler = CriticalPathScheduler::new(
+            tasks.into_tasks(),
             current_date,
             completed,
             effective_default_priority,
             config.unwrap_or_default(),
             rust_resource_config,
             global_dns_periods.unwrap_or_default(),
-        );
+        )
+        .map_err(PyErr::from)?;
+        if let Some(completions) = external_completions {
+            scheduler
+                .set_external_completions(completions.into_iter().collect())
+                .map_err(PyErr::from)?;
+        }
 
-        Ok(Self { inner: scheduler })
+        Ok(Self {
+            inner: SchedulerSlot::new(scheduler),
+        })
     }
 
     /// Run the scheduling algorithm.
-    fn schedule(&mut self) -> PyResult<AlgorithmResult> {
-        match self.inner.schedule() {
-            Ok(result) => Ok(result),
-            Err(e) => Err(pyo3::exceptions::PyValueError::new_err(e.to_string())),
+    ///
+    /// If `cancel_token` is given, calling `cancel()` on it stops the run.
+    /// If `progress_callback` is given, it is called every `progress_interval`
+    /// iterations with `(tasks_scheduled, tasks_remaining, current_time)`.
+    #[pyo3(signature = (cancel_token=None, progress_callback=None, progress_interval=1))]
+    fn schedule(
+        &self,
+        py: Python<'_>,
+        cancel_token: Option<CancellationToken>,
+        progress_callback: Option<PyObject>,
+        progress_interval: usize,
+    ) -> PyResult<AlgorithmResult> {
+        let mut scheduler = self.inner.take()?;
+        if let Some(token) = cancel_token {
+            scheduler.set_cancellation_token(token);
         }
+        if let Some(callback) = progress_callback {
+            scheduler.set_progress_reporter(ProgressReporter::from_py(progress_interval, callback));
+        }
+        let outcome = py.allow_threads(|| scheduler.schedule());
+        outcome.map_err(PyErr::from)
+    }
+
+    /// Start scheduling on a background thread and return a `ScheduleHandle`.
+    ///
+    /// The GIL is released while scheduling runs; poll `is_done()` or call
+    /// `join()` on the handle to retrieve the result.
+    #[pyo3(signature = (cancel_token=None, progress_callback=None, progress_interval=1))]
+    fn schedule_background(
+        &self,
+        cancel_token: Option<CancellationToken>,
+        progress_callback: Option<PyObject>,
+        progress_interval: usize,
+    ) -> PyResult<PyScheduleHandle> {
+        let token = cancel_token.unwrap_or_default();
+        let reporter = progress_callback
+            .map(|callback| ProgressReporter::from_py(progress_interval, callback));
+        self.inner.spawn(token.clone(), move |scheduler| {
+            scheduler.set_cancellation_token(token);
+            if let Some(reporter) = reporter {
+                scheduler.set_progress_reporter(reporter);
+            }
+            scheduler.schedule().map_err(|e| ScheduleFailure::from(&e))
+        })
+    }
+
+    /// Schedule decisions up to and including `pause_at` and return a `SchedulerSnapshot`.
+    ///
+    /// Pass the snapshot, possibly edited to pin or drop placements, to
+    /// `restore()` to continue later.
+    fn schedule_until(&self, py: Python<'_>, pause_at: NaiveDate) -> PyResult<SchedulerSnapshot> {
+        let mut scheduler = self.inner.take()?;
+        let outcome = py.allow_threads(|| scheduler.schedule_until(pause_at));
+        outcome.map_err(PyErr::from)
+    }
+
+    /// Make exactly one decision (place a task or advance time) and return a `ScheduleStep`.
+    ///
+    /// Each call continues from the previous step; kind "finished" means
+    /// nothing is left to decide. With `dry_run` the decision is reported but
+    /// not made, so the next call starts from the same place.
+    #[pyo3(signature = (dry_run=false))]
+    fn schedule_step(&self, py: Python<'_>, dry_run: bool) -> PyResult<ScheduleStep> {
+        let mut scheduler = self.inner.take()?;
+        let outcome = py.allow_threads(|| {
+            if dry_run {
+                scheduler.preview_step()
+            } else {
+                scheduler.schedule_step()
+            }
+        });
+        outcome.map_err(PyErr::from)
+    }
+
+    /// Make later runs resume from `snapshot`; `None` schedules from scratch again.
+    #[pyo3(signature = (snapshot=None))]
+    fn restore(&self, snapshot: Option<SchedulerSnapshot>) -> PyResult<()> {
+        self.inner.with(|s| s.restore(snapshot))
+    }
+
+    /// Edit the tasks for a replan: add or replace `upserts`, drop `removed`,
+    /// and mark `completed` as done.
+    ///
+    /// Completions keep the interned task data for the next run; other edits
+    /// rebuild it.
+    #[pyo3(signature = (upserts=None, removed=None, completed=None))]
+    fn update_tasks(
+        &self,
+        upserts: Option<Vec<Task>>,
+        removed: Option<Vec<String>>,
+        completed: Option<Vec<String>>,
+    ) -> PyResult<()> {
+        let changes: Vec<TaskChange> = upserts
+            .unwrap_or_default()
+            .into_iter()
+            .map(|task| TaskChange::Upsert(Box::new(task)))
+            .chain(
+                removed
+                    .unwrap_or_default()
+                    .into_iter()
+                    .map(TaskChange::Remove),
+            )
+            .chain(
+                completed
+                    .unwrap_or_default()
+                    .into_iter()
+                    .map(TaskChange::Complete),
+            )
+            .collect();
+        self.inner
+            .with(|s| s.update_tasks(changes))?
+            .map_err(PyErr::from)
+    }
+
+    /// Move the scheduling date for a replan, re-resolving and re-validating the tasks.
+    fn set_current_date(&self, current_date: NaiveDate) -> PyResult<()> {
+        self.inner
+            .with(|s| s.set_current_date(current_date))?
+            .map_err(PyErr::from)
+    }
+
+    /// Schedule only the targets in `target_ids` and their transitive dependencies.
+    ///
+    /// Other tasks are left unscheduled and listed in the `deferred_tasks` metadata.
+    fn schedule_for_targets(
+        &self,
+        py: Python<'_>,
+        target_ids: Vec<String>,
+    ) -> PyResult<AlgorithmResult> {
+        let mut scheduler = self.inner.take()?;
+        let outcome = py.allow_threads(|| scheduler.schedule_for_targets(&target_ids));
+        outcome.map_err(PyErr::from)
+    }
+
+    /// Tasks that could start on `as_of_date`, with the blockers of the rest.
+    fn get_eligible_tasks(&self, as_of_date: NaiveDate) -> PyResult<Vec<TaskEligibility>> {
+        self.inner.with(|s| s.get_eligible_tasks(as_of_date))
+    }
+
+    /// Get rollout decisions (only populated if rollout was enabled).
+    fn get_rollout_decisions(&self) -> PyResult<Vec<PyCriticalPathRolloutDecision>> {
+        self.inner.with(|s| {
+            s.get_rollout_decisions()
+                .into_iter()
+                .map(PyCriticalPathRolloutDecision::from)
+                .collect()
+        })
+    }
+
+    /// Simulated schedules behind the last rollout decision (needs `trace_rollouts`).
+    fn get_last_rollout_trace(&self) -> PyResult<Option<RolloutTrace>> {
+        self.inner.with(|s| s.get_last_rollout_trace())
+    }
+
+    /// Get resource reservations held during the last run (only populated if rollout was enabled).
+    fn get_reservations(&self) -> PyResult<Vec<PyReservationRecord>> {
+        self.inner.with(|s| {
+            s.get_reservations()
+                .into_iter()
+                .map(PyReservationRecord::from)
+                .collect()
+        })
     }
 
     fn __repr__(&self) -> String {
@@ -436,33 +1916,232 @@ impl PyCriticalPathScheduler {
     }
 }
 
+/// Scheduler whose algorithm is picked by `SchedulingConfig.algorithm` (PyO3 wrapper).
+#[pyclass(name = "Scheduler")]
+pub struct PyScheduler {
+    inner: SchedulerSlot<Box<dyn Scheduler>>,
+}
+
+#[pymethods]
+impl PyScheduler {
+    #[new]
+    #[pyo3(signature = (
+        tasks,
+        current_date,
+        completed_task_ids=None,
+        config=None,
+        rollout_config=None,
+        critical_path_config=None,
+        resource_config=None,
+        global_dns_periods=None,
+        external_completions=None
+    ))]
+    #[allow(clippy::too_many_arguments)]
+    fn new(
+        tasks: TaskInput,
+        current_date: NaiveDate,
+        completed_task_ids: Option<HashSet<String>>,
+        config: Option<SchedulingConfig>,
+        rollout_config: Option<RolloutConfig>,
+        critical_path_config: Option<CriticalPathConfig>,
+        resource_config: Option<PyResourceConfig>,
+        global_dns_periods: Option<Vec<(NaiveDate, NaiveDate)>>,
+        external_completions: Option<HashMap<String, NaiveDate>>,
+    ) -> PyResult<Self> {
+        let global_dns_periods = global_dns_periods.unwrap_or_default();
+        let scheduler = build_scheduler(
+            with_external_completions(tasks, external_completions, &global_dns_periods),
+            current_date,
+            completed_task_ids.unwrap_or_default().into_iter().collect(),
+            config.unwrap_or_default(),
+            rollout_config,
+            critical_path_config,
+            resource_config.map(ResourceConfig::from),
+            global_dns_periods,
+        )?;
+        Ok(Self {
+            inner: SchedulerSlot::new(scheduler),
+        })
+    }
+
+    /// Run the scheduling algorithm.
+    ///
+    /// If `cancel_token` is given, calling `cancel()` on it stops the run.
+    /// If `progress_callback` is given, it is called every `progress_interval`
+    /// iterations with `(tasks_scheduled, tasks_remaining, current_time)`.
+    #[pyo3(signature = (cancel_token=None, progress_callback=None, progress_interval=1))]
+    fn schedule(
+        &self,
+        py: Python<'_>,
+        cancel_token: Option<CancellationToken>,
+        progress_callback: Option<PyObject>,
+        progress_interval: usize,
+    ) -> PyResult<AlgorithmResult> {
+        let mut scheduler = self.inner.take()?;
+        if let Some(token) = cancel_token {
+            scheduler.set_cancellation_token(token);
+        }
+        if let Some(callback) = progress_callback {
+            scheduler.set_progress_reporter(ProgressReporter::from_py(progress_interval, callback));
+        }
+        let outcome = py.allow_threads(|| scheduler.schedule());
+        outcome.map_err(PyErr::from)
+    }
+
+    /// Start scheduling on a background thread and return a `ScheduleHandle`.
+    #[pyo3(signature = (cancel_token=None, progress_callback=None, progress_interval=1))]
+    fn schedule_background(
+        &self,
+        cancel_token: Option<CancellationToken>,
+        progress_callback: Option<PyObject>,
+        progress_interval: usize,
+    ) -> PyResult<PyScheduleHandle> {
+        let token = cancel_token.unwrap_or_default();
+        let reporter = progress_callback
+            .map(|callback| ProgressReporter::from_py(progress_interval, callback));
+        self.inner.spawn(token.clone(), move |scheduler| {
+            scheduler.set_cancellation_token(token);
+            if let Some(reporter) = reporter {
+                scheduler.set_progress_reporter(reporter);
+            }
+            scheduler.schedule()
+        })
+    }
+
+    /// Metadata of the last completed run, or None before the first.
+    fn get_metadata(&self) -> PyResult<Option<AlgorithmMetadata>> {
+        self.inner.with(|s| s.get_metadata().cloned())
+    }
+
+    fn __repr__(&self) -> String {
+        "Scheduler(...)".to_string()
+    }
+}
+
 /// The mouc.rust Python module.
 #[pymodule]
 fn rust(m: &Bound<'_, PyModule>) -> PyResult<()> {
     // Core data types
     m.add_class::<Dependency>()?;
     m.add_class::<Task>()?;
+    m.add_class::<Recurrence>()?;
+    m.add_class::<CrashSpec>()?;
     m.add_class::<ScheduledTask>()?;
     m.add_class::<AlgorithmResult>()?;
     m.add_class::<PreProcessResult>()?;
+    m.add_class::<Diagnostic>()?;
+    m.add_class::<UnschedulableTask>()?;
+    m.add_class::<ScheduleWarning>()?;
+    m.add_class::<ScheduleSummary>()?;
+    m.add_class::<AlgorithmMetadata>()?;
+    m.add_class::<profiling::PhaseTimings>()?;
+    m.add_class::<TaskEligibility>()?;
+    m.add_class::<Provenance>()?;
+    m.add_class::<DeadlineStatus>()?;
+    m.add_class::<ScheduledTaskBatches>()?;
+    m.add_class::<Buffer>()?;
+    m.add_class::<BufferStatus>()?;
+    m.add_class::<EarnedValueSeries>()?;
+    m.add_class::<ScheduleMetrics>()?;
+    m.add_class::<ParetoSchedule>()?;
+    m.add_class::<TuningGrid>()?;
+    m.add_class::<TuningTrial>()?;
+    m.add_class::<TuningResult>()?;
+    m.add_class::<KickoffSuggestion>()?;
+    m.add_class::<LatestStart>()?;
+    m.add_class::<CrashAction>()?;
+    m.add_class::<CrashPlan>()?;
+    m.add_class::<ResourceOverload>()?;
+    m.add_class::<TaskTiming>()?;
+    m.add_class::<PyCriticalPathResult>()?;
+    m.add_class::<TargetImpact>()?;
+    m.add_class::<Blocker>()?;
+    m.add_class::<SlipAnalysis>()?;
+    m.add_class::<SchedulerSnapshot>()?;
+    m.add_class::<HeldReservation>()?;
+    m.add_class::<ScheduleStep>()?;
     m.add_class::<PyTaskSortInfo>()?;
+    m.add_class::<PyTaskColumns>()?;
 
     // Config types
     m.add_class::<SchedulingConfig>()?;
     m.add_class::<RolloutConfig>()?;
+    m.add_class::<ScheduleObjective>()?;
     m.add_class::<PyResourceConfig>()?;
+    m.add_class::<CancellationToken>()?;
+    m.add_class::<PyScheduleHandle>()?;
 
     // Scheduler
     m.add_class::<PyParallelScheduler>()?;
     m.add_class::<PyRolloutDecision>()?;
+    m.add_class::<RolloutTrace>()?;
 
     // Critical path scheduler
     m.add_class::<CriticalPathConfig>()?;
     m.add_class::<PyCriticalPathScheduler>()?;
+    m.add_class::<PyCriticalPathRolloutDecision>()?;
+    m.add_class::<PyReservationRecord>()?;
+    m.add_class::<PyScheduler>()?;
 
     // Algorithms
     m.add_function(wrap_pyfunction!(run_backward_pass, m)?)?;
+    m.add_function(wrap_pyfunction!(logging::enable_python_logging, m)?)?;
     m.add_function(wrap_pyfunction!(py_sort_tasks, m)?)?;
+    m.add_function(wrap_pyfunction!(py_validate_tasks, m)?)?;
+    m.add_function(wrap_pyfunction!(py_compute_buffers, m)?)?;
+    m.add_function(wrap_pyfunction!(py_insert_buffer_tasks, m)?)?;
+    m.add_function(wrap_pyfunction!(py_buffer_consumption, m)?)?;
+    m.add_function(wrap_pyfunction!(py_earned_value, m)?)?;
+    m.add_function(wrap_pyfunction!(py_pareto_frontier, m)?)?;
+    m.add_function(wrap_pyfunction!(py_tune_critical_path, m)?)?;
+    m.add_function(wrap_pyfunction!(py_suggest_kickoffs, m)?)?;
+    m.add_function(wrap_pyfunction!(py_latest_starts, m)?)?;
+    m.add_function(wrap_pyfunction!(py_suggest_crashes, m)?)?;
+    m.add_function(wrap_pyfunction!(py_check_feasibility, m)?)?;
+    m.add_function(wrap_pyfunction!(py_verify_schedule, m)?)?;
+    m.add_function(wrap_pyfunction!(py_get_eligible_tasks, m)?)?;
+    m.add_function(wrap_pyfunction!(py_calculate_critical_path, m)?)?;
+    m.add_function(wrap_pyfunction!(py_transitive_dependents, m)?)?;
+    m.add_function(wrap_pyfunction!(py_transitive_dependencies, m)?)?;
+    m.add_function(wrap_pyfunction!(py_topological_order, m)?)?;
+    m.add_function(wrap_pyfunction!(py_slip_impact, m)?)?;
+    m.add_function(wrap_pyfunction!(py_analyze_slip, m)?)?;
+    m.add_function(wrap_pyfunction!(py_compute_blockers, m)?)?;
+    m.add_function(wrap_pyfunction!(py_export_mermaid, m)?)?;
+    m.add_function(wrap_pyfunction!(py_export_csv, m)?)?;
+    m.add_function(wrap_pyfunction!(py_export_json, m)?)?;
+    m.add_function(wrap_pyfunction!(py_export_ics, m)?)?;
+    #[cfg(feature = "arrow")]
+    m.add_function(wrap_pyfunction!(py_export_arrow, m)?)?;
+    m.add_function(wrap_pyfunction!(py_tasks_from_json, m)?)?;
+    m.add_function(wrap_pyfunction!(py_tasks_from_csv, m)?)?;
+
+    // Exceptions
+    exceptions::register(m)?;
 
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_scheduler_slot_restored_after_panic() {
+        let slot = SchedulerSlot::new(7_u32);
+        let handle = slot
+            .spawn(CancellationToken::default(), |_| panic!("boom"))
+            .unwrap();
+        let outcome = handle.thread.unwrap().join().unwrap();
+        assert!(matches!(outcome, Err(ScheduleFailure::Other(msg)) if msg.contains("boom")));
+        assert_eq!(slot.with(|n| *n).unwrap(), 7);
+
+        let run = std::panic::catch_unwind(AssertUnwindSafe(|| {
+            let mut scheduler = slot.take().unwrap();
+            *scheduler += 1;
+            panic!("boom");
+        }));
+        assert!(run.is_err());
+        assert_eq!(slot.with(|n| *n).unwrap(), 8);
+    }
+}
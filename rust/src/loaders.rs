@@ -0,0 +1,741 @@
+//! Build tasks directly from JSON text, CSV text, or columnar arrays.
+//!
+//! The text formats use the `Task` field names. Dates are ISO `YYYY-MM-DD`.
+//!
+//! JSON is either an array of task objects or an object with a `"tasks"` array:
+//!
+//! ```text
+//! {"id": "build", "duration_days": 3, "resources": {"alice": 1.0},
+//!  "dependencies": ["design", {"id": "review", "lag_days": 1, "lag_unit": "working"}],
+//!  "end_before": "2025-03-01", "priority": 80}
+//! ```
+//!
+//! `resources` may also be a list of names or `[name, allocation]` pairs, and
+//! `recurrence` is an object with `interval_days`, `until`, and optional `chained`,
+//! `crashable` an object with `min_duration_days` and `cost_per_day`, and
+//! `blocked_periods` a list of `[start, end]` date pairs, and `deadline_targets`
+//! an object mapping names to dates.
+//!
+//! CSV needs a header row with at least `id` and `duration_days`. List columns
+//! are `;`-separated: `resources` as `name` or `name:allocation`, `dependencies`
+//! as `id` or `id:lag_days`, `blocked_periods` as `start:end`,
+//! `deadline_targets` as `name:date`, and `required_skills` as plain names. Empty cells are treated as unset.
+
+use chrono::NaiveDate;
+use serde_json::{Map, Value};
+use thiserror::Error;
+
+use crate::models::{CrashSpec, Dependency, LagUnit, Recurrence, Task};
+
+/// Error raised while loading tasks, with enough context to find the bad input.
+#[derive(Debug, Error, Clone, PartialEq)]
+pub enum LoadError {
+    #[error("invalid {format} at line {line}: {message}")]
+    Syntax {
+        format: &'static str,
+        line: usize,
+        message: String,
+    },
+    #[error("{location}: field '{field}': {message}")]
+    Field {
+        location: String,
+        field: String,
+        message: String,
+    },
+    #[error("{location}: {message}")]
+    Record { location: String, message: String },
+}
+
+const FIELDS: &[&str] = &[
+    "id",
+    "duration_days",
+    "resources",
+    "dependencies",
+    "start_after",
+    "end_before",
+    "start_on",
+    "end_on",
+    "resource_spec",
+    "priority",
+    "required_skills",
+    "external_date",
+    "recurrence",
+    "remaining_days",
+    "actual_start",
+    "pinned_resource",
+    "exclusive_groups",
+    "category",
+    "crashable",
+    "blocked_periods",
+    "rank",
+    "deadline_targets",
+];
+
+/// Field-level parse error; the caller attaches the location.
+type FieldResult<T> = Result<T, String>;
+
+fn parse_date(text: &str) -> FieldResult<NaiveDate> {
+    NaiveDate::parse_from_str(text.trim(), "%Y-%m-%d")
+        .map_err(|_| format!("expected a YYYY-MM-DD date, got '{}'", text))
+}
+
+fn parse_number(text: &str) -> FieldResult<f64> {
+    text.trim()
+        .parse::<f64>()
+        .ok()
+        .filter(|n| n.is_finite())
+        .ok_or_else(|| format!("expected a number, got '{}'", text))
+}
+
+fn parse_priority(text: &str) -> FieldResult<i32> {
+    text.trim()
+        .parse::<i32>()
+        .map_err(|_| format!("expected an integer, got '{}'", text))
+}
+
+// JSON
+
+fn json_location(index: usize, id: Option<&str>) -> String {
+    match id {
+        Some(id) => format!("task {} ('{}')", index, id),
+        None => format!("task {}", index),
+    }
+}
+
+pub(crate) fn json_str(value: &Value) -> FieldResult<&str> {
+    value
+        .as_str()
+        .ok_or_else(|| format!("expected a string, got {}", value))
+}
+
+pub(crate) fn json_number(value: &Value) -> FieldResult<f64> {
+    value
+        .as_f64()
+        .filter(|n| n.is_finite())
+        .ok_or_else(|| format!("expected a number, got {}", value))
+}
+
+pub(crate) fn json_bool(value: &Value) -> FieldResult<bool> {
+    value
+        .as_bool()
+        .ok_or_else(|| format!("expected true or false, got {}", value))
+}
+
+pub(crate) fn json_array(value: &Value) -> FieldResult<&Vec<Value>> {
+    value
+        .as_array()
+        .ok_or_else(|| format!("expected a list, got {}", value))
+}
+
+pub(crate) fn json_date(value: &Value) -> FieldResult<NaiveDate> {
+    parse_date(json_str(value)?)
+}
+
+fn json_resources(value: &Value) -> FieldResult<Vec<(String, f64)>> {
+    if let Some(map) = value.as_object() {
+        return map
+            .iter()
+            .map(|(name, alloc)| Ok((name.clone(), json_number(alloc)?)))
+            .collect();
+    }
+    json_array(value)?
+        .iter()
+        .map(|item| match item {
+            Value::String(name) => Ok((name.clone(), 1.0)),
+            Value::Array(pair) if pair.len() == 2 => {
+                Ok((json_str(&pair[0])?.to_string(), json_number(&pair[1])?))
+            }
+            other => Err(format!(
+                "expected a name or [name, allocation] pair, got {}",
+                other
+            )),
+        })
+        .collect()
+}
+
+fn json_dependencies(value: &Value) -> FieldResult<Vec<Dependency>> {
+    json_array(value)?
+        .iter()
+        .map(|item| match item {
+            Value::String(id) => Ok(Dependency {
+                entity_id: id.clone(),
+                ..Default::default()
+            }),
+            Value::Object(map) => {
+                let id = map
+                    .get("id")
+                    .or_else(|| map.get("entity_id"))
+                    .ok_or_else(|| "dependency object needs an 'id'".to_string())?;
+                let lag_days = match map.get("lag_days") {
+                    Some(lag) => json_number(lag)?,
+                    None => 0.0,
+                };
+                let lag_unit = match map.get("lag_unit") {
+                    Some(unit) => LagUnit::from_str(json_str(unit)?)?,
+                    None => LagUnit::Calendar,
+                };
+                Ok(Dependency {
+                    entity_id: json_str(id)?.to_string(),
+                    lag_days,
+                    lag_unit,
+                })
+            }
+            other => Err(format!(
+                "expected a task ID or {{\"id\", \"lag_days\"}} object, got {}",
+                other
+            )),
+        })
+        .collect()
+}
+
+fn json_recurrence(value: &Value) -> FieldResult<Recurrence> {
+    let map = value
+        .as_object()
+        .ok_or_else(|| format!("expected an object, got {}", value))?;
+    let interval = map
+        .get("interval_days")
+        .and_then(Value::as_u64)
+        .and_then(|n| u32::try_from(n).ok())
+        .filter(|n| *n > 0)
+        .ok_or_else(|| "'interval_days' must be a positive integer".to_string())?;
+    let until = map
+        .get("until")
+        .ok_or_else(|| "missing 'until'".to_string())
+        .and_then(json_date)?;
+    let chained = match map.get("chained") {
+        Some(v) => v
+            .as_bool()
+            .ok_or_else(|| format!("'chained' must be a boolean, got {}", v))?,
+        None => true,
+    };
+    Ok(Recurrence {
+        interval_days: interval,
+        until,
+        chained,
+    })
+}
+
+fn json_crash_spec(value: &Value) -> FieldResult<CrashSpec> {
+    let map = value
+        .as_object()
+        .ok_or_else(|| format!("expected an object, got {}", value))?;
+    let number = |key: &str| {
+        map.get(key)
+            .ok_or_else(|| format!("missing '{}'", key))
+            .and_then(json_number)
+    };
+    CrashSpec::new(number("min_duration_days")?, number("cost_per_day")?).map_err(|e| e.to_string())
+}
+
+fn json_period(value: &Value) -> FieldResult<(NaiveDate, NaiveDate)> {
+    match value.as_array().map(Vec::as_slice) {
+        Some([start, end]) => Ok((json_date(start)?, json_date(end)?)),
+        _ => Err(format!("expected a [start, end] pair, got {}", value)),
+    }
+}
+
+fn json_task(index: usize, object: &Map<String, Value>) -> Result<Task, LoadError> {
+    let id = object.get("id").and_then(Value::as_str);
+    let location = json_location(index, id);
+    let field_error = |field: &str, message: String| LoadError::Field {
+        location: location.clone(),
+        field: field.to_string(),
+        message,
+    };
+
+    if let Some(unknown) = object.keys().find(|k| !FIELDS.contains(&k.as_str())) {
+        return Err(field_error(unknown, "unknown field".to_string()));
+    }
+    let id = match object.get("id") {
+        Some(value) => json_str(value).map_err(|m| field_error("id", m))?,
+        None => {
+            return Err(LoadError::Record {
+                location,
+                message: "missing required field 'id'".to_string(),
+            })
+        }
+    };
+    let duration_days = match object.get("duration_days") {
+        Some(value) => json_number(value).map_err(|m| field_error("duration_days", m))?,
+        None => {
+            return Err(LoadError::Record {
+                location,
+                message: "missing required field 'duration_days'".to_string(),
+            })
+        }
+    };
+
+    let mut task = Task {
+        id: id.to_string(),
+        duration_days,
+        ..Default::default()
+    };
+    for (field, value) in object {
+        if value.is_null() {
+            continue;
+        }
+        let result: FieldResult<()> = (|| {
+            match field.as_str() {
+                "resources" => task.resources = json_resources(value)?,
+                "dependencies" => task.dependencies = json_dependencies(value)?,
+                "start_after" => task.start_after = Some(json_date(value)?),
+                "end_before" => task.end_before = Some(json_date(value)?),
+                "start_on" => task.start_on = Some(json_date(value)?),
+                "end_on" => task.end_on = Some(json_date(value)?),
+                "resource_spec" => task.resource_spec = Some(json_str(value)?.to_string()),
+                "priority" => {
+                    task.priority = Some(
+                        value
+                            .as_i64()
+                            .and_then(|n| i32::try_from(n).ok())
+                            .ok_or_else(|| format!("expected an integer, got {}", value))?,
+                    )
+                }
+                "required_skills" => {
+                    task.required_skills = json_array(value)?
+                        .iter()
+                        .map(|s| json_str(s).map(str::to_string))
+                        .collect::<FieldResult<_>>()?
+                }
+                "external_date" => task.external_date = Some(json_date(value)?),
+                "recurrence" => task.recurrence = Some(json_recurrence(value)?),
+                "remaining_days" => task.remaining_days = Some(json_number(value)?),
+                "actual_start" => task.actual_start = Some(json_date(value)?),
+                "pinned_resource" => task.pinned_resource = Some(json_str(value)?.to_string()),
+                "category" => task.category = Some(json_str(value)?.to_string()),
+                "crashable" => task.crashable = Some(json_crash_spec(value)?),
+                "blocked_periods" => {
+                    task.blocked_periods = json_array(value)?
+                        .iter()
+                        .map(json_period)
+                        .collect::<FieldResult<_>>()?
+                }
+                "exclusive_groups" => {
+                    task.exclusive_groups = json_array(value)?
+                        .iter()
+                        .map(|s| json_str(s).map(str::to_string))
+                        .collect::<FieldResult<_>>()?
+                }
+                "rank" => {
+                    task.rank = Some(
+                        value
+                            .as_i64()
+                            .ok_or_else(|| format!("expected an integer, got {}", value))?,
+                    )
+                }
+                "deadline_targets" => {
+                    task.deadline_targets = value
+                        .as_object()
+                        .ok_or_else(|| format!("expected an object, got {}", value))?
+                        .iter()
+                        .map(|(name, date)| Ok((name.clone(), json_date(date)?)))
+                        .collect::<FieldResult<_>>()?
+                }
+                _ => {}
+            }
+            Ok(())
+        })();
+        result.map_err(|m| field_error(field, m))?;
+    }
+    Ok(task)
+}
+
+/// Parse tasks from JSON text (see module docs for the format).
+pub fn tasks_from_json(text: &str) -> Result<Vec<Task>, LoadError> {
+    let root: Value = serde_json::from_str(text).map_err(|e| LoadError::Syntax {
+        format: "JSON",
+        line: e.line(),
+        message: e.to_string(),
+    })?;
+    let items = match &root {
+        Value::Array(items) => items,
+        Value::Object(map) => match map.get("tasks") {
+            Some(Value::Array(items)) => items,
+            _ => {
+                return Err(LoadError::Record {
+                    location: "document".to_string(),
+                    message: "expected a list of tasks or an object with a 'tasks' list"
+                        .to_string(),
+                })
+            }
+        },
+        _ => {
+            return Err(LoadError::Record {
+                location: "document".to_string(),
+                message: "expected a list of tasks or an object with a 'tasks' list".to_string(),
+            })
+        }
+    };
+
+    items
+        .iter()
+        .enumerate()
+        .map(|(index, item)| match item.as_object() {
+            Some(object) => json_task(index, object),
+            None => Err(LoadError::Record {
+                location: json_location(index, None),
+                message: format!("expected an object, got {}", item),
+            }),
+        })
+        .collect()
+}
+
+// CSV
+
+fn csv_list(text: &str) -> impl Iterator<Item = &str> {
+    text.split(';').map(str::trim).filter(|s| !s.is_empty())
+}
+
+/// Split `name:value` on the last colon, parsing the value as a number.
+fn csv_pair(item: &str, default: f64) -> FieldResult<(String, f64)> {
+    match item.rsplit_once(':') {
+        Some((name, value)) => Ok((name.trim().to_string(), parse_number(value)?)),
+        None => Ok((item.to_string(), default)),
+    }
+}
+
+fn csv_field(task: &mut Task, field: &str, value: &str) -> FieldResult<()> {
+    match field {
+        "id" => task.id = value.to_string(),
+        "duration_days" => task.duration_days = parse_number(value)?,
+        "resources" => {
+            task.resources = csv_list(value)
+                .map(|item| csv_pair(item, 1.0))
+                .collect::<FieldResult<_>>()?
+        }
+        "dependencies" => {
+            task.dependencies = csv_list(value)
+                .map(|item| {
+                    csv_pair(item, 0.0).map(|(entity_id, lag_days)| Dependency {
+                        entity_id,
+                        lag_days,
+                        ..Default::default()
+                    })
+                })
+                .collect::<FieldResult<_>>()?
+        }
+        "start_after" => task.start_after = Some(parse_date(value)?),
+        "end_before" => task.end_before = Some(parse_date(value)?),
+        "start_on" => task.start_on = Some(parse_date(value)?),
+        "end_on" => task.end_on = Some(parse_date(value)?),
+        "resource_spec" => task.resource_spec = Some(value.to_string()),
+        "priority" => task.priority = Some(parse_priority(value)?),
+        "required_skills" => task.required_skills = csv_list(value).map(str::to_string).collect(),
+        "external_date" => task.external_date = Some(parse_date(value)?),
+        "remaining_days" => task.remaining_days = Some(parse_number(value)?),
+        "actual_start" => task.actual_start = Some(parse_date(value)?),
+        "pinned_resource" => task.pinned_resource = Some(value.to_string()),
+        "category" => task.category = Some(value.to_string()),
+        "exclusive_groups" => task.exclusive_groups = csv_list(value).map(str::to_string).collect(),
+        "blocked_periods" => {
+            task.blocked_periods = csv_list(value)
+                .map(|item| match item.split_once(':') {
+                    Some((start, end)) => Ok((parse_date(start)?, parse_date(end)?)),
+                    None => Err(format!("expected start:end dates, got '{}'", item)),
+                })
+                .collect::<FieldResult<_>>()?
+        }
+        "rank" => {
+            task.rank = Some(
+                value
+                    .trim()
+                    .parse::<i64>()
+                    .map_err(|_| format!("expected an integer, got '{}'", value))?,
+            )
+        }
+        "deadline_targets" => {
+            task.deadline_targets = csv_list(value)
+                .map(|item| match item.rsplit_once(':') {
+                    Some((name, date)) => Ok((name.trim().to_string(), parse_date(date)?)),
+                    None => Err(format!("expected name:date, got '{}'", item)),
+                })
+                .collect::<FieldResult<_>>()?
+        }
+        _ => unreachable!("columns are validated against the header"),
+    }
+    Ok(())
+}
+
+/// Parse tasks from CSV text with a header row (see module docs for the format).
+pub fn tasks_from_csv(text: &str) -> Result<Vec<Task>, LoadError> {
+    let syntax_error = |e: csv::Error| LoadError::Syntax {
+        format: "CSV",
+        line: e.position().map_or(0, |p| p.line() as usize),
+        message: e.to_string(),
+    };
+    let mut reader = csv::ReaderBuilder::new()
+        .trim(csv::Trim::All)
+        .from_reader(text.as_bytes());
+    let header: Vec<String> = reader
+        .headers()
+        .map_err(syntax_error)?
+        .iter()
+        .map(str::to_string)
+        .collect();
+
+    for column in &header {
+        if matches!(column.as_str(), "recurrence" | "crashable")
+            || !FIELDS.contains(&column.as_str())
+        {
+            return Err(LoadError::Field {
+                location: "line 1".to_string(),
+                field: column.clone(),
+                message: "unknown or unsupported column".to_string(),
+            });
+        }
+    }
+    for required in ["id", "duration_days"] {
+        if !header.iter().any(|c| c == required) {
+            return Err(LoadError::Record {
+                location: "line 1".to_string(),
+                message: format!("missing required column '{}'", required),
+            });
+        }
+    }
+
+    let mut tasks = Vec::new();
+    for record in reader.records() {
+        let record = record.map_err(syntax_error)?;
+        let line = record.position().map_or(0, |p| p.line());
+        let id = header
+            .iter()
+            .position(|c| c == "id")
+            .and_then(|i| record.get(i))
+            .unwrap_or_default();
+        let location = if id.is_empty() {
+            format!("line {}", line)
+        } else {
+            format!("line {} ('{}')", line, id)
+        };
+
+        let mut task = Task::default();
+        for (column, value) in header.iter().zip(record.iter()) {
+            if value.is_empty() {
+                if column == "id" || column == "duration_days" {
+                    return Err(LoadError::Field {
+                        location,
+                        field: column.clone(),
+                        message: "required value is empty".to_string(),
+                    });
+                }
+                continue;
+            }
+            csv_field(&mut task, column, value).map_err(|message| LoadError::Field {
+                location: location.clone(),
+                field: column.clone(),
+                message,
+            })?;
+        }
+        tasks.push(task);
+    }
+    Ok(tasks)
+}
+
+// Columns
+
+/// Task attributes as parallel arrays, one entry per task.
+///
+/// Dependencies are an edge list of `(dependent, dependency)` index pairs into
+/// `ids`, with optional per-edge `lags`. Optional columns must have one entry
+/// per task (or per edge, for `lags`) when present.
+#[derive(Clone, Debug, Default)]
+pub struct TaskColumns {
+    pub ids: Vec<String>,
+    pub durations: Vec<f64>,
+    pub priorities: Option<Vec<Option<i32>>>,
+    pub dependency_edges: Vec<(u32, u32)>,
+    pub lags: Option<Vec<f64>>,
+    /// Single resource per task at full allocation.
+    pub resources: Option<Vec<Option<String>>>,
+    pub resource_specs: Option<Vec<Option<String>>>,
+    pub start_afters: Option<Vec<Option<NaiveDate>>>,
+    pub end_befores: Option<Vec<Option<NaiveDate>>>,
+}
+
+fn check_len<T>(field: &str, column: Option<&Vec<T>>, expected: usize) -> Result<(), LoadError> {
+    match column {
+        Some(values) if values.len() != expected => Err(LoadError::Field {
+            location: "columns".to_string(),
+            field: field.to_string(),
+            message: format!("expected {} values, got {}", expected, values.len()),
+        }),
+        _ => Ok(()),
+    }
+}
+
+/// Build tasks from columnar arrays, validating lengths and edge indices.
+pub fn tasks_from_columns(columns: TaskColumns) -> Result<Vec<Task>, LoadError> {
+    let n = columns.ids.len();
+    check_len("durations", Some(&columns.durations), n)?;
+    check_len("priorities", columns.priorities.as_ref(), n)?;
+    check_len(
+        "lags",
+        columns.lags.as_ref(),
+        columns.dependency_edges.len(),
+    )?;
+    check_len("resources", columns.resources.as_ref(), n)?;
+    check_len("resource_specs", columns.resource_specs.as_ref(), n)?;
+    check_len("start_afters", columns.start_afters.as_ref(), n)?;
+    check_len("end_befores", columns.end_befores.as_ref(), n)?;
+
+    let take = |column: &mut Option<Vec<Option<String>>>, i: usize| {
+        column.as_mut().and_then(|values| values[i].take())
+    };
+    let mut resources = columns.resources;
+    let mut resource_specs = columns.resource_specs;
+
+    let mut tasks: Vec<Task> = Vec::with_capacity(n);
+    for (i, (id, duration_days)) in columns.ids.into_iter().zip(columns.durations).enumerate() {
+        tasks.push(Task {
+            id,
+            duration_days,
+            priority: columns.priorities.as_ref().and_then(|p| p[i]),
+            resources: take(&mut resources, i)
+                .map(|name| vec![(name, 1.0)])
+                .unwrap_or_default(),
+            resource_spec: take(&mut resource_specs, i),
+            start_after: columns.start_afters.as_ref().and_then(|d| d[i]),
+            end_before: columns.end_befores.as_ref().and_then(|d| d[i]),
+            ..Default::default()
+        });
+    }
+
+    for (edge, &(dependent, dependency)) in columns.dependency_edges.iter().enumerate() {
+        let (dependent, dependency) = (dependent as usize, dependency as usize);
+        if dependent >= n || dependency >= n {
+            return Err(LoadError::Field {
+                location: format!("edge {}", edge),
+                field: "dependency_edges".to_string(),
+                message: format!(
+                    "index ({}, {}) out of range for {} tasks",
+                    dependent, dependency, n
+                ),
+            });
+        }
+        let entity_id = tasks[dependency].id.clone();
+        tasks[dependent].dependencies.push(Dependency {
+            entity_id,
+            lag_days: columns.lags.as_ref().map_or(0.0, |lags| lags[edge]),
+            ..Default::default()
+        });
+    }
+    Ok(tasks)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn d(month: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(2025, month, day).unwrap()
+    }
+
+    #[test]
+    fn test_json_tasks() {
+        let text = r#"{"tasks": [
+            {"id": "design", "duration_days": 2, "resources": ["alice"],
+             "blocked_periods": [["2025-02-03", "2025-02-07"]]},
+            {"id": "build", "duration_days": 3.5, "resources": {"bob": 0.5},
+             "dependencies": ["design", {"id": "review", "lag_days": 1}],
+             "end_before": "2025-03-01", "priority": 80, "resource_spec": null,
+             "recurrence": {"interval_days": 7, "until": "2025-04-01", "chained": false}}
+        ]}"#;
+        let tasks = tasks_from_json(text).unwrap();
+        assert_eq!(tasks.len(), 2);
+        assert_eq!(tasks[0].resources, vec![("alice".to_string(), 1.0)]);
+        assert_eq!(tasks[0].blocked_periods, vec![(d(2, 3), d(2, 7))]);
+
+        let build = &tasks[1];
+        assert_eq!(build.duration_days, 3.5);
+        assert_eq!(build.resources, vec![("bob".to_string(), 0.5)]);
+        assert_eq!(build.dependencies[1].entity_id, "review");
+        assert_eq!(build.dependencies[1].lag_days, 1.0);
+        assert_eq!(build.end_before, Some(d(3, 1)));
+        assert_eq!(build.priority, Some(80));
+        assert!(!build.recurrence.as_ref().unwrap().chained);
+    }
+
+    #[test]
+    fn test_json_errors_name_the_task_and_field() {
+        let err = tasks_from_json(r#"[{"id": "a", "duration_days": 1, "end_before": "soon"}]"#)
+            .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "task 0 ('a'): field 'end_before': expected a YYYY-MM-DD date, got 'soon'"
+        );
+
+        let err = tasks_from_json(r#"[{"id": "a", "duraton_days": 1}]"#).unwrap_err();
+        assert!(matches!(err, LoadError::Field { ref field, .. } if field == "duraton_days"));
+
+        let err = tasks_from_json("[\n{\"id\": }]").unwrap_err();
+        assert!(matches!(err, LoadError::Syntax { line: 2, .. }));
+    }
+
+    #[test]
+    fn test_csv_tasks() {
+        let text = "id,duration_days,resources,dependencies,priority,end_before\n\
+                    design,2,alice,,,\n\
+                    build,3,alice:0.5;bob,design;review:1,80,2025-03-01\n";
+        let tasks = tasks_from_csv(text).unwrap();
+        assert_eq!(tasks.len(), 2);
+        assert_eq!(tasks[0].priority, None);
+
+        let build = &tasks[1];
+        assert_eq!(
+            build.resources,
+            vec![("alice".to_string(), 0.5), ("bob".to_string(), 1.0)]
+        );
+        assert_eq!(build.dependencies[1].lag_days, 1.0);
+        assert_eq!(build.priority, Some(80));
+        assert_eq!(build.end_before, Some(d(3, 1)));
+    }
+
+    #[test]
+    fn test_csv_errors_name_the_line_and_field() {
+        let err = tasks_from_csv("id,duration_days\na,1\nb,two\n").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "line 3 ('b'): field 'duration_days': expected a number, got 'two'"
+        );
+
+        let err = tasks_from_csv("id,duration\na,1\n").unwrap_err();
+        assert!(matches!(err, LoadError::Field { ref field, .. } if field == "duration"));
+    }
+
+    #[test]
+    fn test_columns() {
+        let columns = TaskColumns {
+            ids: vec!["a".to_string(), "b".to_string()],
+            durations: vec![1.0, 2.0],
+            priorities: Some(vec![None, Some(90)]),
+            dependency_edges: vec![(1, 0)],
+            lags: Some(vec![2.0]),
+            resources: Some(vec![Some("alice".to_string()), None]),
+            ..Default::default()
+        };
+        let tasks = tasks_from_columns(columns.clone()).unwrap();
+        assert_eq!(tasks[0].resources, vec![("alice".to_string(), 1.0)]);
+        assert_eq!(tasks[1].priority, Some(90));
+        assert_eq!(tasks[1].dependencies[0].entity_id, "a");
+        assert_eq!(tasks[1].dependencies[0].lag_days, 2.0);
+
+        let bad_edge = TaskColumns {
+            dependency_edges: vec![(0, 5)],
+            lags: None,
+            ..columns.clone()
+        };
+        assert!(tasks_from_columns(bad_edge)
+            .unwrap_err()
+            .to_string()
+            .contains("out of range"));
+
+        let short = TaskColumns {
+            durations: vec![1.0],
+            ..columns
+        };
+        assert_eq!(
+            tasks_from_columns(short).unwrap_err().to_string(),
+            "columns: field 'durations': expected 2 values, got 1"
+        );
+    }
+}
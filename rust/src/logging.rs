@@ -6,6 +6,26 @@
 //! - 1: CHANGES (task assignments, time advances)
 //! - 2: CHECKS (task consideration details)
 //! - 3: DEBUG (full algorithm internals)
+//!
+//! With the `tracing` feature the macros also emit `tracing` events at INFO,
+//! DEBUG and TRACE, whatever the verbosity, targeted at the calling module so
+//! subscribers can filter per module. Runs are wrapped in spans and each
+//! placement emits a structured `task scheduled` event.
+//!
+//! `enable_python_logging()` routes messages to Python's `logging` instead of
+//! stderr, on loggers named after the crate module (`mouc.rust.critical_path`,
+//! `mouc.rust.scheduler`, ...). Python logger levels then replace verbosity.
+
+use std::fmt;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Mutex;
+
+use pyo3::prelude::*;
+use rustc_hash::FxHashMap;
+
+#[cfg(feature = "tracing")]
+#[doc(hidden)]
+pub use tracing;
 
 /// Verbosity level constants.
 pub const VERBOSITY_SILENT: u8 = 0;
@@ -13,14 +33,175 @@ pub const VERBOSITY_CHANGES: u8 = 1;
 pub const VERBOSITY_CHECKS: u8 = 2;
 pub const VERBOSITY_DEBUG: u8 = 3;
 
+static PYTHON_LOGGING: AtomicBool = AtomicBool::new(false);
+
+/// Whether each logger is enabled for each verbosity level, looked up once.
+static PYTHON_LEVELS: Mutex<Option<FxHashMap<String, [bool; 3]>>> = Mutex::new(None);
+
+/// Python logging level for a verbosity level (DEBUG maps to 5, below `logging.DEBUG`).
+fn python_level(level: u8) -> u8 {
+    match level {
+        VERBOSITY_CHANGES => 20,
+        VERBOSITY_CHECKS => 10,
+        _ => 5,
+    }
+}
+
+/// Python logger name for a Rust module path: `mouc.rust.<top-level module>`.
+pub fn python_logger_name(module_path: &str) -> String {
+    match module_path.split("::").nth(1) {
+        Some(module) => format!("mouc.rust.{module}"),
+        None => "mouc.rust".to_string(),
+    }
+}
+
+/// Whether the Python logger for `module_path` is enabled for `level`.
+///
+/// The levels cache is never locked while the GIL is acquired, since
+/// `enable_python_logging` locks it while holding the GIL.
+fn python_enabled(level: u8, module_path: &str) -> bool {
+    let Some(index) = usize::from(level).checked_sub(1) else {
+        return false;
+    };
+    let name = python_logger_name(module_path);
+    let cached = PYTHON_LEVELS
+        .lock()
+        .unwrap_or_else(|e| e.into_inner())
+        .as_ref()
+        .and_then(|levels| levels.get(&name).copied());
+    let enabled = cached.unwrap_or_else(|| {
+        let enabled = Python::with_gil(|py| {
+            let logger = py
+                .import_bound("logging")
+                .and_then(|logging| logging.call_method1("getLogger", (&name,)))
+                .ok();
+            [VERBOSITY_CHANGES, VERBOSITY_CHECKS, VERBOSITY_DEBUG].map(|l| {
+                logger.as_ref().is_some_and(|logger| {
+                    logger
+                        .call_method1("isEnabledFor", (python_level(l),))
+                        .and_then(|r| r.extract::<bool>())
+                        .unwrap_or(false)
+                })
+            })
+        });
+        PYTHON_LEVELS
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .get_or_insert_with(FxHashMap::default)
+            .insert(name, enabled);
+        enabled
+    });
+    enabled.get(index).copied().unwrap_or(false)
+}
+
+/// Whether a message at `level` should be logged.
+#[doc(hidden)]
+pub fn enabled(verbosity: u8, level: u8, module_path: &str) -> bool {
+    if PYTHON_LOGGING.load(Ordering::Relaxed) {
+        python_enabled(level, module_path)
+    } else {
+        verbosity >= level
+    }
+}
+
+/// Write a message to Python logging when enabled, otherwise to stderr.
+#[doc(hidden)]
+pub fn emit(level: u8, module_path: &str, args: fmt::Arguments<'_>) {
+    if !PYTHON_LOGGING.load(Ordering::Relaxed) {
+        eprintln!("{args}");
+        return;
+    }
+    let name = python_logger_name(module_path);
+    Python::with_gil(|py| {
+        let logged = py
+            .import_bound("logging")
+            .and_then(|logging| logging.call_method1("getLogger", (name,)))
+            .and_then(|logger| logger.call_method1("log", (python_level(level), args.to_string())));
+        if let Err(err) = logged {
+            err.print(py);
+        }
+    });
+}
+
+/// Route scheduler logs to Python's `logging` module (or back to stderr).
+///
+/// Logger levels are read on first use; call again after changing them.
+#[pyfunction]
+#[pyo3(signature = (enabled=true))]
+pub fn enable_python_logging(enabled: bool) {
+    *PYTHON_LEVELS.lock().unwrap_or_else(|e| e.into_inner()) = None;
+    PYTHON_LOGGING.store(enabled, Ordering::Relaxed);
+}
+
+#[cfg(feature = "tracing")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __trace_event {
+    ($level:ident, $($arg:tt)*) => {
+        $crate::logging::tracing::event!($crate::logging::tracing::Level::$level, $($arg)*)
+    };
+}
+
+#[cfg(not(feature = "tracing"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __trace_event {
+    ($level:ident, $($arg:tt)*) => {};
+}
+
+/// Enter a `tracing` span until the returned guard drops.
+///
+/// Arguments are those of `tracing::span!` after the level. Without the
+/// `tracing` feature this does nothing and returns `()`.
+#[cfg(feature = "tracing")]
+#[macro_export]
+macro_rules! trace_span {
+    ($level:ident, $($arg:tt)*) => {
+        $crate::logging::tracing::span!($crate::logging::tracing::Level::$level, $($arg)*).entered()
+    };
+}
+
+#[cfg(not(feature = "tracing"))]
+#[macro_export]
+macro_rules! trace_span {
+    ($level:ident, $($arg:tt)*) => {
+        $crate::logging::NoSpan
+    };
+}
+
+/// Stand-in span guard when the `tracing` feature is off.
+#[cfg(not(feature = "tracing"))]
+#[doc(hidden)]
+pub struct NoSpan;
+
+/// Emit a structured INFO event for a placed task (needs the `tracing` feature).
+#[macro_export]
+macro_rules! trace_placement {
+    ($task_id:expr, $resources:expr, $start:expr, $end:expr) => {
+        $crate::__trace_event!(
+            INFO,
+            task_id = %$task_id,
+            resources = %$resources,
+            start = %$start,
+            end = %$end,
+            "task scheduled"
+        )
+    };
+}
+
 /// Log at CHANGES level (verbosity >= 1).
 ///
 /// Used for: task assignments, time advances, scheduling decisions.
 #[macro_export]
 macro_rules! log_changes {
     ($verbosity:expr, $($arg:tt)*) => {
-        if $verbosity >= $crate::logging::VERBOSITY_CHANGES {
-            eprintln!($($arg)*);
+        $crate::__trace_event!(INFO, $($arg)*);
+        if $crate::logging::enabled($verbosity, $crate::logging::VERBOSITY_CHANGES, module_path!()) {
+            $crate::logging::emit(
+                $crate::logging::VERBOSITY_CHANGES,
+                module_path!(),
+                format_args!($($arg)*),
+            );
         }
     };
 }
@@ -31,8 +212,13 @@ macro_rules! log_changes {
 #[macro_export]
 macro_rules! log_checks {
     ($verbosity:expr, $($arg:tt)*) => {
-        if $verbosity >= $crate::logging::VERBOSITY_CHECKS {
-            eprintln!($($arg)*);
+        $crate::__trace_event!(DEBUG, $($arg)*);
+        if $crate::logging::enabled($verbosity, $crate::logging::VERBOSITY_CHECKS, module_path!()) {
+            $crate::logging::emit(
+                $crate::logging::VERBOSITY_CHECKS,
+                module_path!(),
+                format_args!($($arg)*),
+            );
         }
     };
 }
@@ -43,8 +229,13 @@ macro_rules! log_checks {
 #[macro_export]
 macro_rules! log_debug {
     ($verbosity:expr, $($arg:tt)*) => {
-        if $verbosity >= $crate::logging::VERBOSITY_DEBUG {
-            eprintln!($($arg)*);
+        $crate::__trace_event!(TRACE, $($arg)*);
+        if $crate::logging::enabled($verbosity, $crate::logging::VERBOSITY_DEBUG, module_path!()) {
+            $crate::logging::emit(
+                $crate::logging::VERBOSITY_DEBUG,
+                module_path!(),
+                format_args!($($arg)*),
+            );
         }
     };
 }
@@ -61,6 +252,24 @@ mod tests {
         assert_eq!(VERBOSITY_DEBUG, 3);
     }
 
+    #[test]
+    fn test_python_logger_names_follow_modules() {
+        assert_eq!(
+            python_logger_name("mouc_rust::critical_path::scheduler"),
+            "mouc.rust.critical_path"
+        );
+        assert_eq!(
+            python_logger_name("mouc_rust::scheduler"),
+            "mouc.rust.scheduler"
+        );
+        assert_eq!(python_logger_name("mouc_rust"), "mouc.rust");
+        // Without the bridge, verbosity decides
+        assert!(enabled(VERBOSITY_CHECKS, VERBOSITY_CHANGES, "mouc_rust"));
+        assert!(!enabled(VERBOSITY_CHANGES, VERBOSITY_DEBUG, "mouc_rust"));
+        // Silent messages never reach Python
+        assert!(!python_enabled(VERBOSITY_SILENT, "mouc_rust"));
+    }
+
     #[test]
     fn test_log_macros_compile() {
         // Just verify macros compile and don't panic
@@ -68,5 +277,7 @@ mod tests {
         log_changes!(verbosity, "test {}", 1);
         log_checks!(verbosity, "test {}", 2);
         log_debug!(verbosity, "test {}", 3);
+        let _span = trace_span!(INFO, "schedule", algorithm = "test");
+        trace_placement!("a", "alice", 1, 2);
     }
 }
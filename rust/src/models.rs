@@ -1,43 +1,275 @@
 //! Core data types for the scheduling system.
 
+use crate::config::{ConfigError, Problems};
+use crate::exceptions::InvalidConfigError;
+use crate::profiling::PhaseTimings;
 use chrono::NaiveDate;
 use pyo3::prelude::*;
+use rustc_hash::{FxHashMap, FxHashSet};
 use std::collections::HashMap;
 
 // Note: We use std HashMap here for PyO3 interface compatibility
 
+/// How a dependency's lag is counted.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum LagUnit {
+    /// Calendar days, which elapse through non-working periods (e.g. curing time).
+    #[default]
+    Calendar,
+    /// Working days, which skip global non-working periods (e.g. a review).
+    Working,
+}
+
+impl LagUnit {
+    /// Parse from string (for Python interop).
+    #[allow(clippy::should_implement_trait)]
+    pub fn from_str(s: &str) -> Result<Self, String> {
+        match s.to_lowercase().as_str() {
+            "calendar" => Ok(Self::Calendar),
+            "working" => Ok(Self::Working),
+            _ => Err(format!(
+                "Invalid lag_unit '{}', expected 'calendar' or 'working'",
+                s
+            )),
+        }
+    }
+
+    /// Convert to string (for Python interop).
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Calendar => "calendar",
+            Self::Working => "working",
+        }
+    }
+
+    /// First date a dependent may start when its dependency ends on `end`.
+    ///
+    /// Working lags count only days outside `non_working`; calendar lags ignore it.
+    pub fn ready_date(
+        self,
+        end: NaiveDate,
+        lag_days: f64,
+        non_working: &[(NaiveDate, NaiveDate)],
+    ) -> NaiveDate {
+        let lag = lag_days.max(0.0).ceil() as u64;
+        if self == Self::Calendar || non_working.is_empty() {
+            return end
+                .checked_add_days(chrono::Days::new(1 + lag))
+                .unwrap_or(NaiveDate::MAX);
+        }
+        let mut day = end;
+        let mut remaining = lag;
+        while remaining > 0 {
+            let Some(next) = day.succ_opt() else {
+                return NaiveDate::MAX;
+            };
+            day = next;
+            if !in_periods(non_working, day) {
+                remaining -= 1;
+            }
+        }
+        day.succ_opt().unwrap_or(NaiveDate::MAX)
+    }
+
+    /// Latest date a dependency may end for its dependent to start on `start`.
+    pub fn latest_end(
+        self,
+        start: NaiveDate,
+        lag_days: f64,
+        non_working: &[(NaiveDate, NaiveDate)],
+    ) -> NaiveDate {
+        let lag = lag_days.max(0.0).ceil() as u64;
+        if self == Self::Calendar || non_working.is_empty() {
+            return start
+                .checked_sub_days(chrono::Days::new(1 + lag))
+                .unwrap_or(NaiveDate::MIN);
+        }
+        let mut day = start.pred_opt().unwrap_or(NaiveDate::MIN);
+        let mut remaining = lag;
+        while remaining > 0 {
+            if !in_periods(non_working, day) {
+                remaining -= 1;
+            }
+            let Some(prev) = day.pred_opt() else {
+                return NaiveDate::MIN;
+            };
+            day = prev;
+        }
+        day
+    }
+}
+
+fn in_periods(periods: &[(NaiveDate, NaiveDate)], day: NaiveDate) -> bool {
+    periods.iter().any(|&(s, e)| s <= day && day <= e)
+}
+
 /// A dependency on another entity with optional lag time.
 #[pyclass]
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, Default)]
 pub struct Dependency {
     #[pyo3(get, set)]
     pub entity_id: String,
     #[pyo3(get, set)]
     pub lag_days: f64,
+    /// Whether the lag counts calendar or working days.
+    /// Not directly exposed to Python; use lag_unit_str getter/setter.
+    pub lag_unit: LagUnit,
+}
+
+impl Dependency {
+    /// First date the dependent may start when this dependency ends on `end`.
+    pub fn ready_date(&self, end: NaiveDate, non_working: &[(NaiveDate, NaiveDate)]) -> NaiveDate {
+        self.lag_unit.ready_date(end, self.lag_days, non_working)
+    }
+
+    /// Latest date this dependency may end for the dependent to start on `start`.
+    pub fn latest_end(
+        &self,
+        start: NaiveDate,
+        non_working: &[(NaiveDate, NaiveDate)],
+    ) -> NaiveDate {
+        self.lag_unit.latest_end(start, self.lag_days, non_working)
+    }
 }
 
 #[pymethods]
 impl Dependency {
     #[new]
-    #[pyo3(signature = (entity_id, lag_days=0.0))]
-    fn new(entity_id: String, lag_days: f64) -> Self {
-        Self {
+    #[pyo3(signature = (entity_id, lag_days=0.0, lag_unit="calendar"))]
+    fn new(entity_id: String, lag_days: f64, lag_unit: &str) -> PyResult<Self> {
+        Ok(Self {
             entity_id,
             lag_days,
-        }
+            lag_unit: LagUnit::from_str(lag_unit).map_err(InvalidConfigError::new_err)?,
+        })
+    }
+
+    /// Get the lag unit as a string.
+    #[getter]
+    fn lag_unit_str(&self) -> &'static str {
+        self.lag_unit.as_str()
+    }
+
+    /// Set the lag unit from a string.
+    #[setter]
+    fn set_lag_unit_str(&mut self, value: &str) -> PyResult<()> {
+        self.lag_unit = LagUnit::from_str(value).map_err(InvalidConfigError::new_err)?;
+        Ok(())
+    }
+
+    fn __repr__(&self) -> String {
+        format!(
+            "Dependency(entity_id={:?}, lag_days={}, lag_unit={})",
+            self.entity_id,
+            self.lag_days,
+            self.lag_unit.as_str()
+        )
+    }
+}
+
+/// Repetition rule for a task that recurs on a fixed interval.
+///
+/// Occurrence `k` becomes available `k * interval_days` after the task's
+/// anchor date and is due before the next occurrence's window opens. With
+/// `chained`, each occurrence also depends on the previous one.
+#[pyclass]
+#[derive(Clone, Debug, PartialEq)]
+pub struct Recurrence {
+    #[pyo3(get, set)]
+    pub interval_days: u32,
+    #[pyo3(get, set)]
+    pub until: NaiveDate,
+    #[pyo3(get, set)]
+    pub chained: bool,
+}
+
+impl Recurrence {
+    /// Recurrence every `interval_days` (at least 1) until `until`.
+    pub fn new(interval_days: u32, until: NaiveDate, chained: bool) -> Result<Self, ConfigError> {
+        let mut problems = Problems::default();
+        problems.require(interval_days > 0, || {
+            "interval_days must be at least 1".to_string()
+        });
+        problems.finish()?;
+        Ok(Self {
+            interval_days,
+            until,
+            chained,
+        })
+    }
+}
+
+#[pymethods]
+impl Recurrence {
+    #[new]
+    #[pyo3(signature = (interval_days, until, chained=true))]
+    fn py_new(interval_days: u32, until: NaiveDate, chained: bool) -> PyResult<Self> {
+        Self::new(interval_days, until, chained)
+            .map_err(|e| InvalidConfigError::new_err(e.to_string()))
+    }
+
+    fn __repr__(&self) -> String {
+        format!(
+            "Recurrence(interval_days={}, until={}, chained={})",
+            self.interval_days, self.until, self.chained
+        )
+    }
+}
+
+/// How far a task can be shortened with overtime or extra help, and at what cost.
+#[pyclass]
+#[derive(Clone, Debug, PartialEq)]
+pub struct CrashSpec {
+    /// Shortest duration the task can be crashed to.
+    #[pyo3(get, set)]
+    pub min_duration_days: f64,
+    /// Cost of each day saved.
+    #[pyo3(get, set)]
+    pub cost_per_day: f64,
+}
+
+impl CrashSpec {
+    /// Crash spec with a finite, non-negative minimum duration and cost.
+    pub fn new(min_duration_days: f64, cost_per_day: f64) -> Result<Self, ConfigError> {
+        let spec = Self {
+            min_duration_days,
+            cost_per_day,
+        };
+        let mut problems = Problems::default();
+        spec.check(&mut problems, "");
+        problems.finish()?;
+        Ok(spec)
+    }
+
+    /// Record invalid values, naming each field after `prefix`.
+    pub(crate) fn check(&self, problems: &mut Problems, prefix: &str) {
+        problems.non_negative(
+            &format!("{}min_duration_days", prefix),
+            self.min_duration_days,
+        );
+        problems.non_negative(&format!("{}cost_per_day", prefix), self.cost_per_day);
+    }
+}
+
+#[pymethods]
+impl CrashSpec {
+    #[new]
+    fn py_new(min_duration_days: f64, cost_per_day: f64) -> PyResult<Self> {
+        Self::new(min_duration_days, cost_per_day)
+            .map_err(|e| InvalidConfigError::new_err(e.to_string()))
     }
 
     fn __repr__(&self) -> String {
         format!(
-            "Dependency(entity_id={:?}, lag_days={})",
-            self.entity_id, self.lag_days
+            "CrashSpec(min_duration_days={}, cost_per_day={})",
+            self.min_duration_days, self.cost_per_day
         )
     }
 }
 
 /// A task to be scheduled.
 #[pyclass]
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, Default)]
 pub struct Task {
     #[pyo3(get, set)]
     pub id: String,
@@ -59,6 +291,149 @@ pub struct Task {
     pub resource_spec: Option<String>,
     #[pyo3(get, set)]
     pub priority: Option<i32>,
+    #[pyo3(get, set)]
+    pub required_skills: Vec<String>,
+    /// Immovable external milestone date; the task is scheduled as a
+    /// zero-duration event on this date and its dependents wait for it.
+    #[pyo3(get, set)]
+    pub external_date: Option<NaiveDate>,
+    #[pyo3(get, set)]
+    pub recurrence: Option<Recurrence>,
+    /// Work left on an in-progress task; when set the task is pinned to start
+    /// at the scheduling date on its assigned resources with this duration.
+    #[pyo3(get, set)]
+    pub remaining_days: Option<f64>,
+    /// Date work actually began; the task keeps this start and its assigned
+    /// resources instead of being rescheduled.
+    #[pyo3(get, set)]
+    pub actual_start: Option<NaiveDate>,
+    /// Resource an auto-assigned task must go to; replaces the candidates
+    /// from `resource_spec` (skills are not checked).
+    #[pyo3(get, set)]
+    pub pinned_resource: Option<String>,
+    /// Tasks sharing a group never run concurrently.
+    #[pyo3(get, set)]
+    pub exclusive_groups: Vec<String>,
+    /// Kind of work, for changeover gaps between dissimilar tasks on a resource.
+    #[pyo3(get, set)]
+    pub category: Option<String>,
+    /// Allows `suggest_crashes` to shorten this task to recover a late target.
+    #[pyo3(get, set)]
+    pub crashable: Option<CrashSpec>,
+    /// Inclusive date ranges the task cannot be worked on, whatever its resources.
+    #[pyo3(get, set)]
+    pub blocked_periods: Vec<(NaiveDate, NaiveDate)>,
+    /// Backlog position; breaks ties between otherwise equal tasks, lowest first.
+    #[pyo3(get, set)]
+    pub rank: Option<i64>,
+    /// Named deadlines the task must also meet, e.g. a demo and a release;
+    /// scheduling uses the tightest of these and `end_before`.
+    #[pyo3(get, set)]
+    pub deadline_targets: Vec<(String, NaiveDate)>,
+}
+
+/// First date on or after `date` outside every inclusive `(start, end)` period.
+pub(crate) fn first_unblocked(
+    periods: &[(NaiveDate, NaiveDate)],
+    mut date: NaiveDate,
+) -> NaiveDate {
+    while let Some(&(_, end)) = periods.iter().find(|&&(s, e)| s <= date && date <= e) {
+        match end.succ_opt() {
+            Some(next) => date = next,
+            None => break,
+        }
+    }
+    date
+}
+
+impl Task {
+    /// Earliest of `end_before` and the `deadline_targets`.
+    pub fn tightest_deadline(&self) -> Option<NaiveDate> {
+        let targets = self.deadline_targets.iter().map(|&(_, date)| date);
+        self.end_before.into_iter().chain(targets).min()
+    }
+
+    /// True if work on this task has already begun.
+    pub fn is_in_progress(&self) -> bool {
+        self.actual_start.is_some() || self.remaining_days.is_some()
+    }
+
+    /// Pin an in-progress task to its actual progress.
+    ///
+    /// With only `remaining_days`, the task starts at `current_date` with the
+    /// remaining duration. With `actual_start`, it keeps that start; when
+    /// `remaining_days` is also given, the duration becomes the remaining work,
+    /// which fixed placement ends from `current_date`, otherwise it keeps the
+    /// planned duration. A task with only a `resource_spec` has no resource to
+    /// hold, so its remaining work (or planned duration) is auto-assigned from
+    /// `current_date` instead. Other tasks are returned unchanged.
+    pub fn resolve_in_progress(mut self, current_date: NaiveDate) -> Self {
+        if !self.is_in_progress() {
+            return self;
+        }
+        if self.resources.is_empty()
+            && self.pinned_resource.is_none()
+            && self.resource_spec.is_some()
+        {
+            if let Some(remaining) = self.remaining_days {
+                self.duration_days = remaining.max(0.0);
+            }
+            self.start_after = Some(
+                self.start_after
+                    .map_or(current_date, |d| d.max(current_date)),
+            );
+            self.start_on = None;
+            self.end_on = None;
+            return self;
+        }
+        match (self.actual_start, self.remaining_days) {
+            (Some(started), remaining) => {
+                if let Some(remaining) = remaining {
+                    self.duration_days = remaining.max(0.0);
+                }
+                self.start_on = Some(started);
+                self.end_on = None;
+            }
+            (None, Some(remaining)) => {
+                self.duration_days = remaining.max(0.0);
+                self.start_on = Some(current_date);
+                self.end_on = None;
+            }
+            (None, None) => {}
+        }
+        self
+    }
+
+    /// Keep a milestone or `start_on` date out of `freeze` periods.
+    ///
+    /// A milestone cannot be placed during them, and a `start_on` or milestone
+    /// `end_on` date inside one moves to the next day after it. Call after
+    /// `resolve_in_progress`; a recorded `actual_start` is kept.
+    pub fn keep_out_of(mut self, freeze: &[(NaiveDate, NaiveDate)]) -> Self {
+        if freeze.is_empty() {
+            return self;
+        }
+        if let Some(start) = self.start_on.filter(|_| self.actual_start.is_none()) {
+            let start = first_unblocked(freeze, start);
+            self.start_on = Some(start);
+            self.end_on = self.end_on.map(|end| end.max(start));
+        }
+        if self.duration_days == 0.0 {
+            self.end_on = self.end_on.map(|end| first_unblocked(freeze, end));
+            self.blocked_periods.extend_from_slice(freeze);
+        }
+        self
+    }
+
+    /// Round the duration to the nearest whole day, keeping at least one day
+    /// for non-zero work. Call after `resolve_in_progress`, which folds
+    /// remaining work into the duration.
+    pub fn round_to_nearest_day(mut self) -> Self {
+        if self.duration_days > 0.0 {
+            self.duration_days = self.duration_days.round().max(1.0);
+        }
+        self
+    }
 }
 
 #[pymethods]
@@ -74,7 +449,19 @@ impl Task {
         start_on=None,
         end_on=None,
         resource_spec=None,
-        priority=None
+        priority=None,
+        required_skills=None,
+        external_date=None,
+        recurrence=None,
+        remaining_days=None,
+        actual_start=None,
+        pinned_resource=None,
+        exclusive_groups=None,
+        category=None,
+        crashable=None,
+        blocked_periods=None,
+        rank=None,
+        deadline_targets=None
     ))]
     #[allow(clippy::too_many_arguments)]
     fn new(
@@ -88,6 +475,18 @@ impl Task {
         end_on: Option<NaiveDate>,
         resource_spec: Option<String>,
         priority: Option<i32>,
+        required_skills: Option<Vec<String>>,
+        external_date: Option<NaiveDate>,
+        recurrence: Option<Recurrence>,
+        remaining_days: Option<f64>,
+        actual_start: Option<NaiveDate>,
+        pinned_resource: Option<String>,
+        exclusive_groups: Option<Vec<String>>,
+        category: Option<String>,
+        crashable: Option<CrashSpec>,
+        blocked_periods: Option<Vec<(NaiveDate, NaiveDate)>>,
+        rank: Option<i64>,
+        deadline_targets: Option<Vec<(String, NaiveDate)>>,
     ) -> Self {
         Self {
             id,
@@ -100,6 +499,18 @@ impl Task {
             end_on,
             resource_spec,
             priority,
+            required_skills: required_skills.unwrap_or_default(),
+            external_date,
+            recurrence,
+            remaining_days,
+            actual_start,
+            pinned_resource,
+            exclusive_groups: exclusive_groups.unwrap_or_default(),
+            category,
+            crashable,
+            blocked_periods: blocked_periods.unwrap_or_default(),
+            rank,
+            deadline_targets: deadline_targets.unwrap_or_default(),
         }
     }
 
@@ -114,9 +525,26 @@ impl Task {
     }
 }
 
+/// An edit to a scheduler's task list between runs.
+#[derive(Clone, Debug)]
+pub enum TaskChange {
+    /// Add a task, or replace the task with the same ID.
+    Upsert(Box<Task>),
+    /// Remove the task with this ID.
+    Remove(String),
+    /// Mark the task with this ID as completed.
+    Complete(String),
+}
+
 /// A task that has been scheduled.
+///
+/// `start_offset`/`end_offset` give the fraction of the start/end day at which
+/// work begins/ends; they are always 0.0 unless sub-day scheduling is enabled.
+/// `duration_days` is the work planned for the task, while
+/// `effective_duration_days` is the work the schedule reserved for it: whole
+/// days unless sub-day scheduling kept the fraction.
 #[pyclass]
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, Default, PartialEq)]
 pub struct ScheduledTask {
     #[pyo3(get, set)]
     pub task_id: String,
@@ -128,17 +556,58 @@ pub struct ScheduledTask {
     pub duration_days: f64,
     #[pyo3(get, set)]
     pub resources: Vec<String>,
+    #[pyo3(get, set)]
+    pub start_offset: f64,
+    #[pyo3(get, set)]
+    pub end_offset: f64,
+    /// True for tasks that had already started before the scheduling date.
+    #[pyo3(get, set)]
+    pub in_progress: bool,
+    #[pyo3(get, set)]
+    pub effective_duration_days: f64,
+}
+
+impl ScheduledTask {
+    /// Record the work the schedule reserved: the duration rounded up to
+    /// whole days, or kept as is for sub-day scheduling. `planned` is the
+    /// duration before rounding to the nearest day, reported in its place.
+    pub(crate) fn set_effective_duration(&mut self, sub_day: bool, planned: Option<f64>) {
+        self.effective_duration_days = if sub_day {
+            self.duration_days
+        } else {
+            self.duration_days.ceil()
+        };
+        if let Some(planned) = planned {
+            self.duration_days = planned;
+        }
+    }
 }
 
 #[pymethods]
 impl ScheduledTask {
     #[new]
+    #[pyo3(signature = (
+        task_id,
+        start_date,
+        end_date,
+        duration_days,
+        resources,
+        start_offset=0.0,
+        end_offset=0.0,
+        in_progress=false,
+        effective_duration_days=None
+    ))]
+    #[allow(clippy::too_many_arguments)]
     fn new(
         task_id: String,
         start_date: NaiveDate,
         end_date: NaiveDate,
         duration_days: f64,
         resources: Vec<String>,
+        start_offset: f64,
+        end_offset: f64,
+        in_progress: bool,
+        effective_duration_days: Option<f64>,
     ) -> Self {
         Self {
             task_id,
@@ -146,6 +615,10 @@ impl ScheduledTask {
             end_date,
             duration_days,
             resources,
+            start_offset,
+            end_offset,
+            in_progress,
+            effective_duration_days: effective_duration_days.unwrap_or(duration_days),
         }
     }
 
@@ -157,6 +630,240 @@ impl ScheduledTask {
     }
 }
 
+/// A task a scheduler could not place, and why.
+#[pyclass]
+#[derive(Clone, Debug, PartialEq)]
+pub struct UnschedulableTask {
+    #[pyo3(get)]
+    pub task_id: String,
+    /// One of "no_resource", "unsatisfiable_dependency", "dns_forever", or "blocked".
+    #[pyo3(get)]
+    pub reason: String,
+    /// Human-readable explanation naming the offending resource or dependency.
+    #[pyo3(get)]
+    pub detail: String,
+}
+
+#[pymethods]
+impl UnschedulableTask {
+    fn __repr__(&self) -> String {
+        format!(
+            "UnschedulableTask(task_id={:?}, reason={:?}, detail={:?})",
+            self.task_id, self.reason, self.detail
+        )
+    }
+}
+
+/// A problem a scheduler worked around rather than failing on.
+#[pyclass]
+#[derive(Clone, Debug, PartialEq)]
+pub struct ScheduleWarning {
+    /// Machine-readable warning code, e.g. "fixed_conflict".
+    #[pyo3(get)]
+    pub code: String,
+    /// Tasks involved, the one the warning is about first.
+    #[pyo3(get)]
+    pub task_ids: Vec<String>,
+    /// Human-readable description.
+    #[pyo3(get)]
+    pub message: String,
+}
+
+#[pymethods]
+impl ScheduleWarning {
+    fn __repr__(&self) -> String {
+        format!(
+            "ScheduleWarning(code={:?}, task_ids={:?}, message={:?})",
+            self.code, self.task_ids, self.message
+        )
+    }
+}
+
+/// Summary metrics of a finished schedule, for comparing runs.
+#[pyclass]
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct ScheduleSummary {
+    /// Days from the scheduling date to the last completion.
+    #[pyo3(get)]
+    pub makespan_days: f64,
+    /// Sum of days past each task's tightest deadline, weighted by priority / 100.
+    #[pyo3(get)]
+    pub total_weighted_tardiness: f64,
+    /// Tasks that finish after their tightest deadline.
+    #[pyo3(get)]
+    pub late_targets: usize,
+    /// Mean days from the scheduling date to completion.
+    #[pyo3(get)]
+    pub average_flow_time_days: f64,
+    /// Most resources busy on any one day.
+    #[pyo3(get)]
+    pub peak_resource_load: usize,
+}
+
+impl ScheduleSummary {
+    /// Measure `scheduled`, looking up deadlines and priorities in `tasks`.
+    pub fn measure(
+        scheduled: &[ScheduledTask],
+        tasks: &FxHashMap<String, Task>,
+        current_date: NaiveDate,
+        default_priority: i32,
+    ) -> Self {
+        let mut summary = Self::default();
+        let mut busy: FxHashMap<NaiveDate, FxHashSet<&str>> = FxHashMap::default();
+        let mut total_flow_time = 0.0;
+        for st in scheduled {
+            let flow_time = (st.end_date - current_date).num_days().max(0) as f64;
+            summary.makespan_days = summary.makespan_days.max(flow_time);
+            total_flow_time += flow_time;
+
+            let task = tasks.get(&st.task_id);
+            if let Some(deadline) = task.and_then(Task::tightest_deadline) {
+                if st.end_date > deadline {
+                    let weight =
+                        task.and_then(|t| t.priority).unwrap_or(default_priority) as f64 / 100.0;
+                    summary.total_weighted_tardiness +=
+                        weight * (st.end_date - deadline).num_days() as f64;
+                    summary.late_targets += 1;
+                }
+            }
+
+            if !st.resources.is_empty() {
+                for day in st
+                    .start_date
+                    .iter_days()
+                    .take_while(|day| *day < st.end_date)
+                {
+                    busy.entry(day)
+                        .or_default()
+                        .extend(st.resources.iter().map(String::as_str));
+                }
+            }
+        }
+        if !scheduled.is_empty() {
+            summary.average_flow_time_days = total_flow_time / scheduled.len() as f64;
+        }
+        summary.peak_resource_load = busy.values().map(FxHashSet::len).max().unwrap_or(0);
+        summary
+    }
+}
+
+#[pymethods]
+impl ScheduleSummary {
+    fn __repr__(&self) -> String {
+        format!(
+            "ScheduleSummary(makespan_days={}, total_weighted_tardiness={:.2}, late_targets={}, average_flow_time_days={:.2}, peak_resource_load={})",
+            self.makespan_days,
+            self.total_weighted_tardiness,
+            self.late_targets,
+            self.average_flow_time_days,
+            self.peak_resource_load
+        )
+    }
+}
+
+/// Whether one deadline of a task was met.
+#[pyclass]
+#[derive(Clone, Debug, PartialEq)]
+pub struct DeadlineStatus {
+    #[pyo3(get)]
+    pub task_id: String,
+    /// Name of the deadline target, or `"end_before"`.
+    #[pyo3(get)]
+    pub name: String,
+    #[pyo3(get)]
+    pub deadline: NaiveDate,
+    #[pyo3(get)]
+    pub end_date: NaiveDate,
+    #[pyo3(get)]
+    pub met: bool,
+    /// Days past the deadline; 0 when met.
+    #[pyo3(get)]
+    pub days_late: i64,
+}
+
+impl DeadlineStatus {
+    /// Check every `end_before` and deadline target of the scheduled tasks,
+    /// sorted by task ID and then deadline.
+    pub fn check_all(scheduled: &[ScheduledTask], tasks: &FxHashMap<String, Task>) -> Vec<Self> {
+        let mut statuses = Vec::new();
+        for st in scheduled {
+            let Some(task) = tasks.get(&st.task_id) else {
+                continue;
+            };
+            let end_before = task.end_before.map(|date| ("end_before", date));
+            let targets = task
+                .deadline_targets
+                .iter()
+                .map(|(name, date)| (name.as_str(), *date));
+            for (name, deadline) in end_before.into_iter().chain(targets) {
+                let days_late = (st.end_date - deadline).num_days().max(0);
+                statuses.push(Self {
+                    task_id: st.task_id.clone(),
+                    name: name.to_string(),
+                    deadline,
+                    end_date: st.end_date,
+                    met: days_late == 0,
+                    days_late,
+                });
+            }
+        }
+        statuses.sort_by(|a, b| (&a.task_id, a.deadline).cmp(&(&b.task_id, b.deadline)));
+        statuses
+    }
+}
+
+#[pymethods]
+impl DeadlineStatus {
+    fn __repr__(&self) -> String {
+        format!(
+            "DeadlineStatus(task_id={:?}, name={:?}, deadline={}, met={})",
+            self.task_id, self.name, self.deadline, self.met
+        )
+    }
+}
+
+/// Typed run metadata, alongside the free-form `algorithm_metadata` map.
+#[pyclass]
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct AlgorithmMetadata {
+    #[pyo3(get)]
+    pub algorithm: String,
+    /// Sort strategy of the parallel scheduler (None for critical path).
+    #[pyo3(get)]
+    pub strategy: Option<String>,
+    /// Rollout decisions made (0 with rollout disabled).
+    #[pyo3(get)]
+    pub rollout_decision_count: usize,
+    /// Iterations of the main scheduling loop.
+    #[pyo3(get)]
+    pub iterations: usize,
+    /// Wall-clock time of the `schedule()` call.
+    #[pyo3(get)]
+    pub wall_time_ms: f64,
+    #[pyo3(get)]
+    pub metrics: ScheduleSummary,
+    /// Per-phase timings, recorded when the config sets `profile`.
+    #[pyo3(get)]
+    pub timings: Option<PhaseTimings>,
+    /// Each deadline of the scheduled tasks and whether it was met.
+    #[pyo3(get)]
+    pub deadlines: Vec<DeadlineStatus>,
+}
+
+#[pymethods]
+impl AlgorithmMetadata {
+    fn __repr__(&self) -> String {
+        format!(
+            "AlgorithmMetadata(algorithm={:?}, strategy={:?}, rollout_decision_count={}, iterations={}, wall_time_ms={:.1})",
+            self.algorithm,
+            self.strategy,
+            self.rollout_decision_count,
+            self.iterations,
+            self.wall_time_ms
+        )
+    }
+}
+
 /// Result from a scheduling algorithm.
 #[pyclass]
 #[derive(Clone, Debug, Default)]
@@ -165,22 +872,59 @@ pub struct AlgorithmResult {
     pub scheduled_tasks: Vec<ScheduledTask>,
     #[pyo3(get, set)]
     pub algorithm_metadata: HashMap<String, String>,
+    /// Tasks left out of a partial schedule (see `on_unschedulable`).
+    #[pyo3(get, set)]
+    pub unschedulable: Vec<UnschedulableTask>,
+    #[pyo3(get, set)]
+    pub warnings: Vec<ScheduleWarning>,
+    /// Typed metadata, filled in by the schedulers.
+    #[pyo3(get, set)]
+    pub metadata: Option<AlgorithmMetadata>,
 }
 
 #[pymethods]
 impl AlgorithmResult {
     #[new]
-    #[pyo3(signature = (scheduled_tasks, algorithm_metadata=None))]
+    #[pyo3(signature = (scheduled_tasks, algorithm_metadata=None, unschedulable=None, warnings=None, metadata=None))]
     fn new(
         scheduled_tasks: Vec<ScheduledTask>,
         algorithm_metadata: Option<HashMap<String, String>>,
+        unschedulable: Option<Vec<UnschedulableTask>>,
+        warnings: Option<Vec<ScheduleWarning>>,
+        metadata: Option<AlgorithmMetadata>,
     ) -> Self {
         Self {
             scheduled_tasks,
             algorithm_metadata: algorithm_metadata.unwrap_or_default(),
+            unschedulable: unschedulable.unwrap_or_default(),
+            warnings: warnings.unwrap_or_default(),
+            metadata,
         }
     }
 
+    /// Iterate over the scheduled tasks in lists of at most `batch_size`.
+    ///
+    /// Each batch is converted to Python objects only when it is reached,
+    /// unlike `scheduled_tasks`, which copies the whole list at once.
+    #[pyo3(signature = (batch_size=1000))]
+    fn iter_scheduled_tasks(slf: Py<Self>, batch_size: usize) -> PyResult<ScheduledTaskBatches> {
+        if batch_size == 0 {
+            return Err(pyo3::exceptions::PyValueError::new_err(
+                "batch_size must be at least 1",
+            ));
+        }
+        Ok(ScheduledTaskBatches {
+            result: slf,
+            position: 0,
+            batch_size,
+        })
+    }
+
+    #[pyo3(name = "fingerprint")]
+    fn py_fingerprint(&self) -> u64 {
+        self.fingerprint()
+    }
+
     fn __repr__(&self) -> String {
         format!(
             "AlgorithmResult(scheduled_tasks={}, metadata_keys={})",
@@ -190,6 +934,111 @@ impl AlgorithmResult {
     }
 }
 
+impl AlgorithmResult {
+    /// Stable hash of the placements: task, start date, end date and resources.
+    ///
+    /// Task and resource order and all other fields are ignored. The hash is
+    /// 64-bit FNV-1a over a fixed encoding, so it stays the same across runs,
+    /// platforms and releases and can be stored to detect changed plans.
+    pub fn fingerprint(&self) -> u64 {
+        const OFFSET: u64 = 0xcbf2_9ce4_8422_2325;
+        const PRIME: u64 = 0x0000_0100_0000_01b3;
+        fn write(hash: u64, bytes: &[u8]) -> u64 {
+            bytes
+                .iter()
+                .fold(hash, |h, &b| (h ^ u64::from(b)).wrapping_mul(PRIME))
+        }
+
+        let mut placements: Vec<(&str, NaiveDate, NaiveDate, Vec<&str>)> = self
+            .scheduled_tasks
+            .iter()
+            .map(|t| {
+                let mut resources: Vec<&str> = t.resources.iter().map(String::as_str).collect();
+                resources.sort_unstable();
+                (t.task_id.as_str(), t.start_date, t.end_date, resources)
+            })
+            .collect();
+        placements.sort_unstable();
+
+        let mut hash = OFFSET;
+        for (task_id, start, end, resources) in placements {
+            hash = write(hash, task_id.as_bytes());
+            for date in [start, end] {
+                hash = write(hash, &[0x1f]);
+                hash = write(hash, date.to_string().as_bytes());
+            }
+            for resource in resources {
+                hash = write(hash, &[0x1f]);
+                hash = write(hash, resource.as_bytes());
+            }
+            hash = write(hash, &[0x1e]);
+        }
+        hash
+    }
+}
+
+/// Iterator over an `AlgorithmResult`'s scheduled tasks, one batch at a time.
+#[pyclass]
+pub struct ScheduledTaskBatches {
+    result: Py<AlgorithmResult>,
+    position: usize,
+    batch_size: usize,
+}
+
+#[pymethods]
+impl ScheduledTaskBatches {
+    fn __iter__(slf: PyRef<'_, Self>) -> PyRef<'_, Self> {
+        slf
+    }
+
+    fn __next__(&mut self, py: Python<'_>) -> Option<Vec<ScheduledTask>> {
+        let result = self.result.borrow(py);
+        let remaining = result.scheduled_tasks.get(self.position..)?;
+        if remaining.is_empty() {
+            return None;
+        }
+        let batch = &remaining[..remaining.len().min(self.batch_size)];
+        self.position += batch.len();
+        Some(batch.to_vec())
+    }
+}
+
+/// Where a task's computed deadline and priority were inherited from.
+///
+/// Each path runs from the direct dependent to the task that set the value;
+/// an empty path means the task's own value (or, for deadlines, one tightened
+/// by resource contention).
+#[pyclass]
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Provenance {
+    #[pyo3(get)]
+    pub deadline_path: Vec<String>,
+    #[pyo3(get)]
+    pub priority_path: Vec<String>,
+}
+
+#[pymethods]
+impl Provenance {
+    /// Task whose deadline this task inherited, if any.
+    #[getter]
+    fn deadline_source(&self) -> Option<String> {
+        self.deadline_path.last().cloned()
+    }
+
+    /// Task whose priority this task inherited, if any.
+    #[getter]
+    fn priority_source(&self) -> Option<String> {
+        self.priority_path.last().cloned()
+    }
+
+    fn __repr__(&self) -> String {
+        format!(
+            "Provenance(deadline_path={:?}, priority_path={:?})",
+            self.deadline_path, self.priority_path
+        )
+    }
+}
+
 /// Result from a pre-processor (e.g., backward pass).
 #[pyclass]
 #[derive(Clone, Debug, Default)]
@@ -198,6 +1047,12 @@ pub struct PreProcessResult {
     pub computed_deadlines: HashMap<String, NaiveDate>,
     #[pyo3(get, set)]
     pub computed_priorities: HashMap<String, i32>,
+    /// Inheritance paths of tasks with an inherited deadline or priority.
+    #[pyo3(get, set)]
+    pub provenance: HashMap<String, Provenance>,
+    /// Earliest feasible starts of tasks reached by a start constraint.
+    #[pyo3(get, set)]
+    pub computed_earliest_starts: HashMap<String, NaiveDate>,
 }
 
 #[pymethods]
@@ -211,6 +1066,8 @@ impl PreProcessResult {
         Self {
             computed_deadlines: computed_deadlines.unwrap_or_default(),
             computed_priorities: computed_priorities.unwrap_or_default(),
+            provenance: HashMap::new(),
+            computed_earliest_starts: HashMap::new(),
         }
     }
 
@@ -222,3 +1079,52 @@ impl PreProcessResult {
         )
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_working_lag_skips_non_working_days() {
+        let d = |day| NaiveDate::from_ymd_opt(2025, 1, day).unwrap();
+        let weekend = [(d(4), d(5))];
+
+        assert_eq!(LagUnit::Calendar.ready_date(d(3), 2.0, &weekend), d(6));
+        assert_eq!(LagUnit::Working.ready_date(d(3), 2.0, &weekend), d(8));
+        assert_eq!(LagUnit::Working.ready_date(d(3), 2.0, &[]), d(6));
+        assert_eq!(LagUnit::Working.latest_end(d(8), 2.0, &weekend), d(5));
+        assert_eq!(LagUnit::Calendar.latest_end(d(6), 2.0, &weekend), d(3));
+        assert!(LagUnit::from_str("fortnights").is_err());
+    }
+
+    #[test]
+    fn test_recurrence_rejects_zero_interval() {
+        let until = NaiveDate::from_ymd_opt(2025, 6, 1).unwrap();
+        let err = Recurrence::new(0, until, true).unwrap_err();
+        assert_eq!(err.problems, ["interval_days must be at least 1"]);
+        assert!(Recurrence::new(7, until, false).is_ok());
+    }
+
+    #[test]
+    fn test_fingerprint_ignores_order_and_detects_moves() {
+        let placed = |id: &str, day: u32, resources: &[&str]| ScheduledTask {
+            task_id: id.to_string(),
+            start_date: NaiveDate::from_ymd_opt(2025, 1, day).unwrap(),
+            end_date: NaiveDate::from_ymd_opt(2025, 1, day + 1).unwrap(),
+            resources: resources.iter().map(|r| r.to_string()).collect(),
+            ..Default::default()
+        };
+        let result = |tasks| AlgorithmResult {
+            scheduled_tasks: tasks,
+            ..Default::default()
+        };
+
+        let base = result(vec![placed("a", 1, &["r1", "r2"]), placed("b", 3, &["r1"])]);
+        let reordered = result(vec![placed("b", 3, &["r1"]), placed("a", 1, &["r2", "r1"])]);
+        let moved = result(vec![placed("a", 1, &["r1", "r2"]), placed("b", 4, &["r1"])]);
+        assert_eq!(base.fingerprint(), reordered.fingerprint());
+        assert_ne!(base.fingerprint(), moved.fingerprint());
+        // Fixed encoding: the value must not change between releases
+        assert_eq!(base.fingerprint(), 12440090812511711419);
+    }
+}
@@ -0,0 +1,311 @@
+//! Pareto frontier of schedules across competing objectives.
+//!
+//! The critical path scheduler is run once per `ScheduleObjective` in a grid of
+//! weights. Each resulting schedule is measured on the same metric vector, and
+//! only schedules no other schedule beats on every metric are kept, so planners
+//! can choose the tradeoff instead of the algorithm.
+
+use chrono::NaiveDate;
+use pyo3::prelude::*;
+use rustc_hash::{FxHashMap, FxHashSet};
+
+use crate::config::{makespan_days, resource_idle_days, ScheduleObjective};
+use crate::critical_path::{CriticalPathConfig, CriticalPathScheduler, CriticalPathSchedulerError};
+use crate::models::{AlgorithmResult, Task};
+use crate::scheduler::ResourceConfig;
+
+/// Metric vector of a schedule. Every metric is lower-is-better.
+#[pyclass]
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct ScheduleMetrics {
+    /// Days from the scheduling date to the last completion.
+    #[pyo3(get)]
+    pub makespan_days: f64,
+    /// Sum of days past `end_before`, weighted by priority / 100.
+    #[pyo3(get)]
+    pub weighted_tardiness: f64,
+    /// Sum of days from the scheduling date to completion, weighted by priority / 100.
+    #[pyo3(get)]
+    pub weighted_flow_time: f64,
+    /// Resource-days left idle before the last completion.
+    #[pyo3(get)]
+    pub idle_days: f64,
+    /// Total resource cost (0 without cost rates).
+    #[pyo3(get)]
+    pub total_cost: f64,
+}
+
+impl ScheduleMetrics {
+    /// Measure a schedule produced from `tasks` starting at `current_date`.
+    pub fn measure(
+        result: &AlgorithmResult,
+        tasks: &[Task],
+        current_date: NaiveDate,
+        default_priority: i32,
+        resource_config: Option<&ResourceConfig>,
+    ) -> Self {
+        let by_id: FxHashMap<&str, &Task> = tasks.iter().map(|t| (t.id.as_str(), t)).collect();
+        let scheduled = &result.scheduled_tasks;
+        let mut metrics = Self::default();
+        for st in scheduled {
+            let task = by_id.get(st.task_id.as_str());
+            let weight = task.and_then(|t| t.priority).unwrap_or(default_priority) as f64 / 100.0;
+            metrics.weighted_flow_time +=
+                weight * (st.end_date - current_date).num_days().max(0) as f64;
+            if let Some(deadline) = task.and_then(|t| t.end_before) {
+                metrics.weighted_tardiness +=
+                    weight * (st.end_date - deadline).num_days().max(0) as f64;
+            }
+        }
+        let makespan = makespan_days(scheduled, current_date);
+        metrics.makespan_days = makespan as f64;
+        metrics.idle_days = resource_idle_days(
+            scheduled,
+            current_date,
+            current_date + chrono::Days::new(makespan as u64),
+        ) as f64;
+        metrics.total_cost = resource_config.map_or(0.0, |rc| rc.schedule_cost(scheduled));
+        metrics
+    }
+
+    fn values(&self) -> [f64; 5] {
+        [
+            self.makespan_days,
+            self.weighted_tardiness,
+            self.weighted_flow_time,
+            self.idle_days,
+            self.total_cost,
+        ]
+    }
+
+    /// Whether `self` is no worse than `other` on every metric and better on one.
+    pub fn dominates(&self, other: &Self) -> bool {
+        let (ours, theirs) = (self.values(), other.values());
+        ours.iter().zip(&theirs).all(|(a, b)| a <= b)
+            && ours.iter().zip(&theirs).any(|(a, b)| a < b)
+    }
+}
+
+#[pymethods]
+impl ScheduleMetrics {
+    #[pyo3(name = "dominates")]
+    fn py_dominates(&self, other: &Self) -> bool {
+        self.dominates(other)
+    }
+
+    fn __repr__(&self) -> String {
+        format!(
+            "ScheduleMetrics(makespan_days={}, weighted_tardiness={:.2}, weighted_flow_time={:.2}, idle_days={}, total_cost={:.2})",
+            self.makespan_days,
+            self.weighted_tardiness,
+            self.weighted_flow_time,
+            self.idle_days,
+            self.total_cost
+        )
+    }
+}
+
+/// A schedule on the Pareto frontier and the objective that produced it.
+#[pyclass]
+#[derive(Clone, Debug)]
+pub struct ParetoSchedule {
+    #[pyo3(get)]
+    pub objective: ScheduleObjective,
+    #[pyo3(get)]
+    pub metrics: ScheduleMetrics,
+    #[pyo3(get)]
+    pub result: AlgorithmResult,
+}
+
+#[pymethods]
+impl ParetoSchedule {
+    fn __repr__(&self) -> String {
+        format!("ParetoSchedule(metrics={})", self.metrics.__repr__())
+    }
+}
+
+/// Keep the non-dominated schedules, dropping later duplicates of a metric vector.
+pub fn non_dominated(candidates: Vec<ParetoSchedule>) -> Vec<ParetoSchedule> {
+    let mut frontier: Vec<ParetoSchedule> = Vec::new();
+    for (i, candidate) in candidates.iter().enumerate() {
+        let beaten = candidates
+            .iter()
+            .any(|other| other.metrics.dominates(&candidate.metrics));
+        let duplicate = candidates[..i]
+            .iter()
+            .any(|earlier| earlier.metrics == candidate.metrics);
+        if !beaten && !duplicate {
+            frontier.push(candidate.clone());
+        }
+    }
+    frontier
+}
+
+/// Run the critical path scheduler once per objective and return the Pareto frontier.
+///
+/// Objectives only change the schedule through rollout scoring, so `config`
+/// should have rollout enabled. Frontier schedules keep the order of `objectives`.
+#[allow(clippy::too_many_arguments)]
+pub fn pareto_frontier(
+    tasks: &[Task],
+    current_date: NaiveDate,
+    completed_task_ids: &FxHashSet<String>,
+    default_priority: i32,
+    config: &CriticalPathConfig,
+    resource_config: Option<&ResourceConfig>,
+    global_dns_periods: &[(NaiveDate, NaiveDate)],
+    objectives: &[ScheduleObjective],
+) -> Result<Vec<ParetoSchedule>, CriticalPathSchedulerError> {
+    let mut candidates = Vec::with_capacity(objectives.len());
+    for objective in objectives {
+        let mut run_config = config.clone();
+        run_config.objective = objective.clone();
+        let result = CriticalPathScheduler::new(
+            tasks.to_vec(),
+            current_date,
+            completed_task_ids.clone(),
+            default_priority,
+            run_config,
+            resource_config.cloned(),
+            global_dns_periods.to_vec(),
+        )?
+        .schedule()?;
+        let metrics = ScheduleMetrics::measure(
+            &result,
+            tasks,
+            current_date,
+            default_priority,
+            resource_config,
+        );
+        candidates.push(ParetoSchedule {
+            objective: objective.clone(),
+            metrics,
+            result,
+        });
+    }
+    Ok(non_dominated(candidates))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::ScheduledTask;
+
+    fn d(day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(2025, 1, day).unwrap()
+    }
+
+    fn candidate(makespan_days: f64, weighted_tardiness: f64) -> ParetoSchedule {
+        ParetoSchedule {
+            objective: ScheduleObjective::default(),
+            metrics: ScheduleMetrics {
+                makespan_days,
+                weighted_tardiness,
+                ..Default::default()
+            },
+            result: AlgorithmResult::default(),
+        }
+    }
+
+    #[test]
+    fn test_non_dominated_keeps_tradeoffs_only() {
+        let frontier = non_dominated(vec![
+            candidate(10.0, 5.0),
+            candidate(12.0, 0.0),
+            candidate(12.0, 6.0), // dominated by both
+            candidate(10.0, 5.0), // duplicate
+        ]);
+        let kept: Vec<(f64, f64)> = frontier
+            .iter()
+            .map(|p| (p.metrics.makespan_days, p.metrics.weighted_tardiness))
+            .collect();
+        assert_eq!(kept, vec![(10.0, 5.0), (12.0, 0.0)]);
+    }
+
+    #[test]
+    fn test_measure_schedule_metrics() {
+        let tasks = vec![
+            Task {
+                id: "a".to_string(),
+                priority: Some(100),
+                end_before: Some(d(3)),
+                ..Default::default()
+            },
+            Task {
+                id: "b".to_string(),
+                ..Default::default()
+            },
+        ];
+        let result = AlgorithmResult {
+            scheduled_tasks: vec![
+                ScheduledTask {
+                    task_id: "a".to_string(),
+                    start_date: d(1),
+                    end_date: d(5),
+                    resources: vec!["alice".to_string()],
+                    ..Default::default()
+                },
+                ScheduledTask {
+                    task_id: "b".to_string(),
+                    start_date: d(5),
+                    end_date: d(11),
+                    resources: vec!["bob".to_string()],
+                    ..Default::default()
+                },
+            ],
+            ..Default::default()
+        };
+        let metrics = ScheduleMetrics::measure(&result, &tasks, d(1), 50, None);
+        assert_eq!(metrics.makespan_days, 10.0);
+        assert_eq!(metrics.weighted_tardiness, 2.0);
+        assert_eq!(metrics.weighted_flow_time, 4.0 + 0.5 * 10.0);
+        // alice idle 6 of 10 days, bob idle 4
+        assert_eq!(metrics.idle_days, 10.0);
+        assert_eq!(metrics.total_cost, 0.0);
+    }
+
+    #[test]
+    fn test_pareto_frontier_runs_each_objective() {
+        let tasks: Vec<Task> = ["a", "b"]
+            .iter()
+            .map(|id| Task {
+                id: id.to_string(),
+                duration_days: 3.0,
+                resources: vec![("alice".to_string(), 1.0)],
+                ..Default::default()
+            })
+            .collect();
+        let objectives = vec![
+            ScheduleObjective::default(),
+            ScheduleObjective {
+                makespan_weight: 10.0,
+                ..Default::default()
+            },
+        ];
+        let frontier = pareto_frontier(
+            &tasks,
+            d(1),
+            &FxHashSet::default(),
+            50,
+            &CriticalPathConfig::default(),
+            None,
+            &[],
+            &objectives,
+        )
+        .unwrap();
+        // Both objectives produce the same serial schedule
+        assert_eq!(frontier.len(), 1);
+        let last_end = frontier[0]
+            .result
+            .scheduled_tasks
+            .iter()
+            .map(|t| t.end_date)
+            .max()
+            .unwrap();
+        assert_eq!(
+            frontier[0].metrics.makespan_days,
+            (last_end - d(1)).num_days() as f64
+        );
+        assert_eq!(frontier[0].result.scheduled_tasks.len(), 2);
+    }
+}
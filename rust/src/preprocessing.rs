@@ -0,0 +1,343 @@
+//! Task preprocessing shared by the parallel and critical path schedulers.
+//!
+//! Tasks with fixed dates (`start_on`/`end_on`) and external milestones are
+//! placed before scheduling starts and taken out of the task map. Both
+//! schedulers then block the placements on their resources with
+//! `occupy_fixed`.
+//!
+//! Dependencies on events outside the task list, such as another team's
+//! handoff, are resolved up front by `apply_external_completions`.
+
+use chrono::{Days, NaiveDate};
+use rustc_hash::{FxHashMap, FxHashSet};
+
+use crate::backward_pass::compute_dependency_deadline;
+use crate::models::{Dependency, ScheduleWarning, ScheduledTask, Task};
+use crate::scheduler::{ResourceConfig, ResourceSchedule};
+use crate::validation::DateOverflow;
+
+/// Placements taken out of a task map by `take_fixed_tasks`.
+pub(crate) struct FixedTasks {
+    pub placements: Vec<ScheduledTask>,
+    /// Latest end date for each dependency of an external milestone.
+    pub external_deadlines: Vec<(String, NaiveDate)>,
+    pub warnings: Vec<ScheduleWarning>,
+    /// The tasks taken out of the map, to put back before another run.
+    pub tasks: Vec<Task>,
+}
+
+/// Place tasks with fixed dates and external milestones and remove them from `tasks`.
+///
+/// Their dependencies move to `fixed_dependencies`. A fixed task uses its
+/// explicit resources, or its pinned resource when it has none; milestones
+/// use no resources. A task whose `start_on` is after its `end_on` keeps
+/// `start_on`, ends after its duration, and is reported as
+/// `conflicting_fixed_dates`. A started task with remaining work keeps its
+/// actual start and ends once that work is done from `current_date`. Fails,
+/// leaving `tasks` unchanged, if a milestone's lag reaches outside the
+/// supported date range.
+pub(crate) fn take_fixed_tasks(
+    tasks: &mut FxHashMap<String, Task>,
+    fixed_dependencies: &mut FxHashMap<String, Vec<Dependency>>,
+    resource_config: Option<&ResourceConfig>,
+    global_dns_periods: &[(NaiveDate, NaiveDate)],
+    current_date: NaiveDate,
+) -> Result<FixedTasks, DateOverflow> {
+    let mut fixed = FixedTasks {
+        placements: Vec::new(),
+        external_deadlines: Vec::new(),
+        warnings: Vec::new(),
+        tasks: Vec::new(),
+    };
+    let mut to_remove: Vec<String> = Vec::new();
+
+    for (task_id, task) in tasks.iter() {
+        if let Some(date) = task.external_date {
+            for dep in &task.dependencies {
+                let deadline =
+                    compute_dependency_deadline(date, 0.0, dep, None, global_dns_periods)?;
+                fixed
+                    .external_deadlines
+                    .push((dep.entity_id.clone(), deadline));
+            }
+            fixed.placements.push(ScheduledTask {
+                task_id: task_id.clone(),
+                start_date: date,
+                end_date: date,
+                ..Default::default()
+            });
+            to_remove.push(task_id.clone());
+            continue;
+        }
+
+        if task.start_on.is_none() && task.end_on.is_none() {
+            continue;
+        }
+
+        let assigned: Vec<String> = if task.resources.is_empty() {
+            task.pinned_resource.iter().cloned().collect()
+        } else {
+            task.resources.iter().map(|(r, _)| r.clone()).collect()
+        };
+        let end_from =
+            |start| dns_aware_end_date(task, &assigned, start, resource_config, global_dns_periods);
+        let (start, end) = match (task.start_on, task.end_on) {
+            (Some(s), None) if task.actual_start.is_some() && task.remaining_days.is_some() => {
+                (s, end_from(current_date.max(s)))
+            }
+            (Some(s), Some(e)) if e < s => {
+                fixed.warnings.push(ScheduleWarning {
+                    code: "conflicting_fixed_dates".to_string(),
+                    task_ids: vec![task_id.clone()],
+                    message: format!(
+                        "Task '{}' has start_on {} after end_on {}; end_on is ignored",
+                        task_id, s, e
+                    ),
+                });
+                (s, end_from(s))
+            }
+            (Some(s), Some(e)) => (s, e),
+            (Some(s), None) => (s, end_from(s)),
+            (None, Some(e)) => {
+                let s = e
+                    .checked_sub_days(Days::new(task.duration_days.ceil() as u64))
+                    .unwrap_or(NaiveDate::MIN);
+                (s, e)
+            }
+            (None, None) => unreachable!(),
+        };
+
+        fixed.placements.push(ScheduledTask {
+            task_id: task_id.clone(),
+            start_date: start,
+            end_date: end,
+            duration_days: task.duration_days,
+            // Milestones have no resources
+            resources: if task.duration_days == 0.0 {
+                vec![]
+            } else {
+                assigned
+            },
+            in_progress: task.is_in_progress(),
+            ..Default::default()
+        });
+        to_remove.push(task_id.clone());
+    }
+
+    for task_id in to_remove {
+        if let Some(task) = tasks.remove(&task_id) {
+            fixed_dependencies.insert(task_id, task.dependencies.clone());
+            fixed.tasks.push(task);
+        }
+    }
+    fixed.warnings.sort_by(|a, b| a.task_ids.cmp(&b.task_ids));
+    Ok(fixed)
+}
+
+/// Resolve dependencies on external events with known completion dates.
+///
+/// A dependency whose `entity_id` is not a task ID but has a date in
+/// `external_completions` is removed, and the task's `start_after` is raised
+/// to the day the dependency is ready, honoring its lag and lag unit.
+/// Working-day lags skip `lag_calendar`. Dependencies on task IDs are kept
+/// even when the map also has an entry for them.
+pub fn apply_external_completions(
+    mut tasks: Vec<Task>,
+    external_completions: &FxHashMap<String, NaiveDate>,
+    lag_calendar: &[(NaiveDate, NaiveDate)],
+) -> Vec<Task> {
+    if external_completions.is_empty() {
+        return tasks;
+    }
+    let task_ids: FxHashSet<String> = tasks.iter().map(|t| t.id.clone()).collect();
+    for task in &mut tasks {
+        let mut release = task.start_after;
+        task.dependencies.retain(|dep| {
+            if task_ids.contains(&dep.entity_id) {
+                return true;
+            }
+            match external_completions.get(&dep.entity_id) {
+                Some(&date) => {
+                    let ready = dep.ready_date(date, lag_calendar);
+                    release = Some(release.map_or(ready, |r| r.max(ready)));
+                    false
+                }
+                None => true,
+            }
+        });
+        task.start_after = release;
+    }
+    tasks
+}
+
+/// Tighten `end_before` so work feeding an external milestone finishes before its date.
+pub(crate) fn apply_external_deadlines(
+    tasks: &mut FxHashMap<String, Task>,
+    external_deadlines: &[(String, NaiveDate)],
+) {
+    for (task_id, deadline) in external_deadlines {
+        if let Some(task) = tasks.get_mut(task_id) {
+            task.end_before = Some(task.end_before.map_or(*deadline, |d| d.min(*deadline)));
+        }
+    }
+}
+
+/// End date of `task` started on `start`, accounting for the DNS periods of
+/// `resources` and the task's blocked periods.
+pub(crate) fn dns_aware_end_date(
+    task: &Task,
+    resources: &[String],
+    start: NaiveDate,
+    resource_config: Option<&ResourceConfig>,
+    global_dns_periods: &[(NaiveDate, NaiveDate)],
+) -> NaiveDate {
+    let resource_config = match resource_config {
+        Some(rc) if !resources.is_empty() => rc,
+        _ => {
+            return ResourceSchedule::new(Some(task.blocked_periods.clone()), String::new())
+                .calculate_completion_time(start, task.duration_days)
+        }
+    };
+
+    let mut max_end = start;
+    for resource_name in resources {
+        let mut resource_schedule =
+            resource_config.build_schedule(resource_name, global_dns_periods);
+        let (completion, _) = resource_schedule.completion_point_avoiding(
+            start,
+            0.0,
+            task.duration_days,
+            &task.blocked_periods,
+        );
+        max_end = max_end.max(completion);
+    }
+    max_end
+}
+
+/// Block a fixed placement on one of its resources, recording its changeover category.
+pub(crate) fn occupy_fixed(
+    schedule: &mut ResourceSchedule,
+    fixed: &ScheduledTask,
+    category: Option<&String>,
+) {
+    schedule.add_busy_period(fixed.start_date, fixed.end_date);
+    if let Some(category) = category {
+        schedule.record_category(fixed.end_date, category);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn d(day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(2025, 1, day).unwrap()
+    }
+
+    #[test]
+    fn test_take_fixed_tasks() {
+        let tasks = vec![
+            Task {
+                id: "pinned".to_string(),
+                duration_days: 2.0,
+                resource_spec: Some("*".to_string()),
+                pinned_resource: Some("alice".to_string()),
+                start_on: Some(d(6)),
+                ..Default::default()
+            },
+            Task {
+                id: "backwards".to_string(),
+                duration_days: 1.0,
+                start_on: Some(d(10)),
+                end_on: Some(d(3)),
+                ..Default::default()
+            },
+            Task {
+                id: "launch".to_string(),
+                external_date: Some(d(20)),
+                dependencies: vec![Dependency {
+                    entity_id: "work".to_string(),
+                    lag_days: 2.0,
+                    ..Default::default()
+                }],
+                ..Default::default()
+            },
+            Task {
+                id: "work".to_string(),
+                duration_days: 1.0,
+                ..Default::default()
+            },
+        ];
+        let mut tasks: FxHashMap<String, Task> =
+            tasks.into_iter().map(|t| (t.id.clone(), t)).collect();
+        let mut fixed_dependencies = FxHashMap::default();
+
+        let mut fixed =
+            take_fixed_tasks(&mut tasks, &mut fixed_dependencies, None, &[], d(1)).unwrap();
+        fixed.placements.sort_by(|a, b| a.task_id.cmp(&b.task_id));
+        apply_external_deadlines(&mut tasks, &fixed.external_deadlines);
+
+        let placed: Vec<(&str, NaiveDate, NaiveDate, &[String])> = fixed
+            .placements
+            .iter()
+            .map(|st| {
+                let resources: &[String] = &st.resources;
+                (st.task_id.as_str(), st.start_date, st.end_date, resources)
+            })
+            .collect();
+        assert_eq!(
+            placed,
+            [
+                ("backwards", d(10), d(11), &[][..]),
+                ("launch", d(20), d(20), &[][..]),
+                ("pinned", d(6), d(8), &["alice".to_string()][..]),
+            ]
+        );
+        assert_eq!(fixed.warnings.len(), 1);
+        assert_eq!(fixed.warnings[0].code, "conflicting_fixed_dates");
+        assert!(fixed_dependencies.contains_key("launch"));
+        assert_eq!(tasks.keys().collect::<Vec<_>>(), ["work"]);
+        assert_eq!(tasks["work"].end_before, Some(d(18)));
+    }
+
+    #[test]
+    fn test_external_completions_become_start_after() {
+        let dep = |entity_id: &str, lag_days: f64| Dependency {
+            entity_id: entity_id.to_string(),
+            lag_days,
+            ..Default::default()
+        };
+        let tasks = vec![
+            Task {
+                id: "build".to_string(),
+                duration_days: 1.0,
+                start_after: Some(d(2)),
+                dependencies: vec![dep("handoff", 1.0), dep("design", 0.0), dep("unknown", 0.0)],
+                ..Default::default()
+            },
+            Task {
+                id: "design".to_string(),
+                duration_days: 1.0,
+                start_after: Some(d(20)),
+                dependencies: vec![dep("handoff", 0.0)],
+                ..Default::default()
+            },
+        ];
+        let completions: FxHashMap<String, NaiveDate> =
+            [("handoff".to_string(), d(10)), ("design".to_string(), d(1))]
+                .into_iter()
+                .collect();
+
+        let tasks = apply_external_completions(tasks, &completions, &[]);
+
+        let dep_ids: Vec<&str> = tasks[0]
+            .dependencies
+            .iter()
+            .map(|dep| dep.entity_id.as_str())
+            .collect();
+        assert_eq!(dep_ids, ["design", "unknown"]);
+        assert_eq!(tasks[0].start_after, Some(d(12)));
+        assert!(tasks[1].dependencies.is_empty());
+        assert_eq!(tasks[1].start_after, Some(d(20)));
+    }
+}
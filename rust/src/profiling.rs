@@ -0,0 +1,53 @@
+//! Per-phase timing of scheduler runs, enabled by the `profile` option.
+
+use std::time::Instant;
+
+use pyo3::prelude::*;
+
+/// Milliseconds spent in each scheduling phase.
+///
+/// Phases nest: the main loop includes cache work and rollouts, and rollout
+/// time includes the caches built by its simulations.
+#[pyclass]
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct PhaseTimings {
+    /// Deadline and priority propagation (parallel scheduler).
+    #[pyo3(get)]
+    pub backward_pass_ms: f64,
+    /// Building the critical path cache (critical path scheduler).
+    #[pyo3(get)]
+    pub cache_build_ms: f64,
+    #[pyo3(get)]
+    pub main_loop_ms: f64,
+    #[pyo3(get)]
+    pub rollout_ms: f64,
+    /// Updating the critical path cache after each placement.
+    #[pyo3(get)]
+    pub cache_invalidation_ms: f64,
+}
+
+#[pymethods]
+impl PhaseTimings {
+    fn __repr__(&self) -> String {
+        format!(
+            "PhaseTimings(backward_pass_ms={:.1}, cache_build_ms={:.1}, main_loop_ms={:.1}, rollout_ms={:.1}, cache_invalidation_ms={:.1})",
+            self.backward_pass_ms,
+            self.cache_build_ms,
+            self.main_loop_ms,
+            self.rollout_ms,
+            self.cache_invalidation_ms
+        )
+    }
+}
+
+/// Start timing a phase when profiling.
+pub(crate) fn start(profile: bool) -> Option<Instant> {
+    profile.then(Instant::now)
+}
+
+/// Add the time since `started` to `total_ms`.
+pub(crate) fn stop(started: Option<Instant>, total_ms: &mut f64) {
+    if let Some(started) = started {
+        *total_ms += started.elapsed().as_secs_f64() * 1000.0;
+    }
+}
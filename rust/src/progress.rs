@@ -0,0 +1,107 @@
+//! Progress reporting for long-running schedules.
+//!
+//! A `ProgressReporter` wraps a callback that the schedulers invoke every N
+//! main-loop iterations with `(tasks_scheduled, tasks_remaining, current_time)`.
+
+use chrono::NaiveDate;
+use pyo3::prelude::*;
+
+/// Callback signature: `(tasks_scheduled, tasks_remaining, current_time)`.
+pub type ProgressCallback = Box<dyn Fn(usize, usize, NaiveDate) + Send + Sync>;
+
+/// Invokes a progress callback at a fixed iteration interval.
+pub struct ProgressReporter {
+    callback: ProgressCallback,
+    interval: usize,
+}
+
+impl ProgressReporter {
+    /// Create a reporter that fires every `interval` iterations (minimum 1).
+    pub fn new(interval: usize, callback: ProgressCallback) -> Self {
+        Self {
+            callback,
+            interval: interval.max(1),
+        }
+    }
+
+    /// Create a reporter that forwards progress to a Python callable.
+    ///
+    /// Exceptions raised by the callable are reported as unraisable and do
+    /// not interrupt scheduling.
+    pub fn from_py(interval: usize, callable: PyObject) -> Self {
+        Self::new(
+            interval,
+            Box::new(move |scheduled, remaining, current_time| {
+                Python::with_gil(|py| {
+                    if let Err(err) = callable.call1(py, (scheduled, remaining, current_time)) {
+                        err.write_unraisable_bound(py, Some(callable.bind(py)));
+                    }
+                });
+            }),
+        )
+    }
+
+    /// Invoke the callback if `iteration` falls on the reporting interval.
+    #[inline]
+    pub fn maybe_report(
+        &self,
+        iteration: usize,
+        scheduled: usize,
+        remaining: usize,
+        current_time: NaiveDate,
+    ) {
+        if iteration.is_multiple_of(self.interval) {
+            (self.callback)(scheduled, remaining, current_time);
+        }
+    }
+}
+
+impl std::fmt::Debug for ProgressReporter {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ProgressReporter")
+            .field("interval", &self.interval)
+            .finish_non_exhaustive()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Arc;
+
+    #[test]
+    fn test_reports_on_interval() {
+        let calls = Arc::new(AtomicUsize::new(0));
+        let counter = calls.clone();
+        let reporter = ProgressReporter::new(
+            3,
+            Box::new(move |_, _, _| {
+                counter.fetch_add(1, Ordering::Relaxed);
+            }),
+        );
+        let date = NaiveDate::from_ymd_opt(2025, 1, 1).unwrap();
+        for i in 0..7 {
+            reporter.maybe_report(i, i, 7 - i, date);
+        }
+        // Iterations 0, 3, 6
+        assert_eq!(calls.load(Ordering::Relaxed), 3);
+    }
+
+    #[test]
+    fn test_zero_interval_reports_every_iteration() {
+        let calls = Arc::new(AtomicUsize::new(0));
+        let counter = calls.clone();
+        let reporter = ProgressReporter::new(
+            0,
+            Box::new(move |_, _, _| {
+                counter.fetch_add(1, Ordering::Relaxed);
+            }),
+        );
+        let date = NaiveDate::from_ymd_opt(2025, 1, 1).unwrap();
+        for i in 0..4 {
+            reporter.maybe_report(i, 0, 0, date);
+        }
+        assert_eq!(calls.load(Ordering::Relaxed), 4);
+    }
+}
@@ -0,0 +1,351 @@
+//! Expansion of recurring tasks into individual occurrences.
+//!
+//! A task with a `Recurrence` is replaced by one task per occurrence, with IDs
+//! of the form `"{id}#{n}"` (1-based). Occurrences keep the original task's
+//! resources and dependencies; dependencies on a recurring task are rewritten
+//! to depend on every one of its occurrences. A completed recurring task is
+//! not expanded, so dependencies on it stay satisfied.
+
+use chrono::{Days, NaiveDate};
+use rustc_hash::{FxHashMap, FxHashSet};
+
+use crate::models::{Dependency, Recurrence, ScheduleWarning, Task};
+
+/// Most occurrences a single recurring task may expand to.
+pub const MAX_OCCURRENCES: usize = 10_000;
+
+/// Build the ID of the `n`th (1-based) occurrence of a recurring task.
+pub fn occurrence_id(task_id: &str, n: usize) -> String {
+    format!("{}#{}", task_id, n)
+}
+
+/// Expand all recurring tasks into concrete occurrences.
+///
+/// Each occurrence's window starts at the task's anchor (`start_on`, else
+/// `start_after`, else `current_date`) plus a multiple of the interval, and
+/// lasts until the next window starts. Occurrences are generated while the
+/// window start is on or before `until`. Tasks pinned with `start_on` stay
+/// pinned to their window start; otherwise the window start becomes
+/// `start_after` and the next window start becomes `end_before` (capped by
+/// the task's own `end_before`, if any).
+///
+/// Tasks in `completed_task_ids` are kept as single tasks. Returns the input
+/// unchanged (cloned) when no task recurs, or a description of each
+/// recurrence with a zero interval or more than [`MAX_OCCURRENCES`]
+/// occurrences.
+pub fn expand_recurring_tasks(
+    tasks: &[Task],
+    current_date: NaiveDate,
+    completed_task_ids: &FxHashSet<String>,
+) -> Result<Vec<Task>, Vec<String>> {
+    if tasks.iter().all(|t| t.recurrence.is_none()) {
+        return Ok(tasks.to_vec());
+    }
+    let errors = recurrence_errors(tasks, current_date);
+    if !errors.is_empty() {
+        return Err(errors);
+    }
+
+    let mut occurrences: FxHashMap<&str, Vec<Task>> = FxHashMap::default();
+    for task in tasks {
+        if completed_task_ids.contains(&task.id) {
+            continue;
+        }
+        if let Some(recurrence) = &task.recurrence {
+            occurrences.insert(&task.id, expand_one(task, recurrence, current_date));
+        }
+    }
+
+    let rewrite = |deps: &[Dependency]| -> Vec<Dependency> {
+        deps.iter()
+            .flat_map(|dep| match occurrences.get(dep.entity_id.as_str()) {
+                Some(expanded) => expanded
+                    .iter()
+                    .map(|occ| Dependency {
+                        entity_id: occ.id.clone(),
+                        ..dep.clone()
+                    })
+                    .collect(),
+                None => vec![dep.clone()],
+            })
+            .collect()
+    };
+
+    let mut result = Vec::with_capacity(tasks.len());
+    for task in tasks {
+        match occurrences.get(task.id.as_str()) {
+            Some(expanded) => {
+                let deps = rewrite(&task.dependencies);
+                for occ in expanded {
+                    let mut occ = occ.clone();
+                    occ.dependencies.extend(deps.iter().cloned());
+                    result.push(occ);
+                }
+            }
+            None => {
+                let mut task = task.clone();
+                task.recurrence = None;
+                task.dependencies = rewrite(&task.dependencies);
+                result.push(task);
+            }
+        }
+    }
+    Ok(result)
+}
+
+/// Number of occurrences `recurrence` yields from `anchor`; zero intervals
+/// count as one day.
+fn occurrence_count(anchor: NaiveDate, recurrence: &Recurrence) -> u64 {
+    let span = (recurrence.until - anchor).num_days();
+    if span < 0 {
+        return 0;
+    }
+    span as u64 / u64::from(recurrence.interval_days.max(1)) + 1
+}
+
+/// The date occurrences of `task` are counted from.
+fn anchor(task: &Task, current_date: NaiveDate) -> NaiveDate {
+    task.start_on.or(task.start_after).unwrap_or(current_date)
+}
+
+/// Recurrences that cannot be expanded, sorted by task ID.
+fn recurrence_errors(tasks: &[Task], current_date: NaiveDate) -> Vec<String> {
+    let mut errors: Vec<(&str, String)> = tasks
+        .iter()
+        .filter_map(|task| {
+            let recurrence = task.recurrence.as_ref()?;
+            if recurrence.interval_days == 0 {
+                return Some((
+                    task.id.as_str(),
+                    format!("task '{}' recurs every 0 days", task.id),
+                ));
+            }
+            let count = occurrence_count(anchor(task, current_date), recurrence);
+            (count > MAX_OCCURRENCES as u64).then(|| {
+                (
+                    task.id.as_str(),
+                    format!(
+                        "task '{}' recurs {} times, more than the limit of {}",
+                        task.id, count, MAX_OCCURRENCES
+                    ),
+                )
+            })
+        })
+        .collect();
+    errors.sort();
+    errors.into_iter().map(|(_, message)| message).collect()
+}
+
+/// Warn about incomplete recurring tasks with no occurrence on or before `until`.
+///
+/// Reported code: `empty_recurrence`. Dependencies on such a task are
+/// dropped, so its dependents do not wait for it. Sorted by task ID.
+pub(crate) fn recurrence_warnings(
+    tasks: &[Task],
+    current_date: NaiveDate,
+    completed_task_ids: &FxHashSet<String>,
+) -> Vec<ScheduleWarning> {
+    let mut warnings: Vec<ScheduleWarning> = tasks
+        .iter()
+        .filter(|task| !completed_task_ids.contains(&task.id))
+        .filter_map(|task| {
+            let recurrence = task.recurrence.as_ref()?;
+            let start = anchor(task, current_date);
+            (occurrence_count(start, recurrence) == 0).then(|| ScheduleWarning {
+                code: "empty_recurrence".to_string(),
+                task_ids: vec![task.id.clone()],
+                message: format!(
+                    "Recurring task '{}' has no occurrences: it starts {}, after its until date {}",
+                    task.id, start, recurrence.until
+                ),
+            })
+        })
+        .collect();
+    warnings.sort_by(|a, b| a.task_ids.cmp(&b.task_ids));
+    warnings
+}
+
+/// Materialize occurrences of a single task. Only the chaining dependency is
+/// set here; the original dependencies are added by the caller.
+fn expand_one(task: &Task, recurrence: &Recurrence, current_date: NaiveDate) -> Vec<Task> {
+    let interval = Days::new(u64::from(recurrence.interval_days.max(1)));
+
+    let mut result: Vec<Task> = Vec::new();
+    let mut window_start = anchor(task, current_date);
+    while window_start <= recurrence.until {
+        let next_start = window_start.checked_add_days(interval);
+        let mut occ = task.clone();
+        occ.id = occurrence_id(&task.id, result.len() + 1);
+        occ.recurrence = None;
+        occ.dependencies = Vec::new();
+        if recurrence.chained {
+            if let Some(prev) = result.last() {
+                occ.dependencies.push(Dependency {
+                    entity_id: prev.id.clone(),
+                    ..Default::default()
+                });
+            }
+        }
+
+        if task.start_on.is_some() {
+            occ.start_on = Some(window_start);
+            occ.end_on = None;
+        } else {
+            occ.start_after = Some(window_start);
+            occ.end_before = match (task.end_before, next_start) {
+                (Some(end), Some(next)) => Some(end.min(next)),
+                (end, next) => end.or(next),
+            };
+        }
+        result.push(occ);
+
+        match next_start {
+            Some(next) => window_start = next,
+            None => break,
+        }
+    }
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn d(year: i32, month: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(year, month, day).unwrap()
+    }
+
+    fn recurring(chained: bool) -> Task {
+        Task {
+            id: "ops".to_string(),
+            duration_days: 1.0,
+            resources: vec![("alice".to_string(), 1.0)],
+            dependencies: vec![Dependency {
+                entity_id: "setup".to_string(),
+                ..Default::default()
+            }],
+            recurrence: Some(Recurrence {
+                interval_days: 14,
+                until: d(2025, 2, 1),
+                chained,
+            }),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_chained_expansion() {
+        let tasks = vec![
+            Task {
+                id: "setup".to_string(),
+                duration_days: 1.0,
+                ..Default::default()
+            },
+            recurring(true),
+            Task {
+                id: "report".to_string(),
+                duration_days: 1.0,
+                dependencies: vec![Dependency {
+                    entity_id: "ops".to_string(),
+                    lag_days: 1.0,
+                    ..Default::default()
+                }],
+                ..Default::default()
+            },
+        ];
+
+        let expanded =
+            expand_recurring_tasks(&tasks, d(2025, 1, 1), &FxHashSet::default()).unwrap();
+        let ids: Vec<&str> = expanded.iter().map(|t| t.id.as_str()).collect();
+        // Windows start Jan 1, Jan 15, Jan 29
+        assert_eq!(ids, vec!["setup", "ops#1", "ops#2", "ops#3", "report"]);
+
+        let second = &expanded[2];
+        assert_eq!(second.start_after, Some(d(2025, 1, 15)));
+        assert_eq!(second.end_before, Some(d(2025, 1, 29)));
+        assert!(second.recurrence.is_none());
+        let dep_ids: Vec<&str> = second
+            .dependencies
+            .iter()
+            .map(|dep| dep.entity_id.as_str())
+            .collect();
+        assert_eq!(dep_ids, vec!["ops#1", "setup"]);
+
+        let report_deps: Vec<(&str, f64)> = expanded[4]
+            .dependencies
+            .iter()
+            .map(|dep| (dep.entity_id.as_str(), dep.lag_days))
+            .collect();
+        assert_eq!(
+            report_deps,
+            vec![("ops#1", 1.0), ("ops#2", 1.0), ("ops#3", 1.0)]
+        );
+    }
+
+    #[test]
+    fn test_independent_pinned_occurrences() {
+        let mut task = recurring(false);
+        task.dependencies.clear();
+        task.start_on = Some(d(2025, 1, 6));
+
+        let expanded =
+            expand_recurring_tasks(&[task], d(2025, 1, 1), &FxHashSet::default()).unwrap();
+        assert_eq!(expanded.len(), 2);
+        assert!(expanded.iter().all(|t| t.dependencies.is_empty()));
+        assert_eq!(expanded[0].start_on, Some(d(2025, 1, 6)));
+        assert_eq!(expanded[1].start_on, Some(d(2025, 1, 20)));
+    }
+
+    #[test]
+    fn test_completed_recurring_task_is_not_expanded() {
+        let report = Task {
+            id: "report".to_string(),
+            dependencies: vec![Dependency {
+                entity_id: "ops".to_string(),
+                ..Default::default()
+            }],
+            ..Default::default()
+        };
+        let completed: FxHashSet<String> = ["ops".to_string()].into_iter().collect();
+        let expanded =
+            expand_recurring_tasks(&[recurring(true), report], d(2025, 1, 1), &completed).unwrap();
+        let ids: Vec<&str> = expanded.iter().map(|t| t.id.as_str()).collect();
+        assert_eq!(ids, vec!["ops", "report"]);
+        assert!(expanded[0].recurrence.is_none());
+        assert_eq!(expanded[1].dependencies[0].entity_id, "ops");
+    }
+
+    #[test]
+    fn test_invalid_recurrences_rejected() {
+        let mut zero = recurring(true);
+        zero.recurrence.as_mut().unwrap().interval_days = 0;
+        let mut daily = recurring(false);
+        daily.id = "daily".to_string();
+        daily.recurrence = Some(Recurrence {
+            interval_days: 1,
+            until: d(2100, 1, 1),
+            chained: false,
+        });
+        let errors = expand_recurring_tasks(&[zero, daily], d(2025, 1, 1), &FxHashSet::default())
+            .unwrap_err();
+        assert_eq!(errors.len(), 2);
+        assert!(errors[0].contains("'daily'") && errors[0].contains("limit of 10000"));
+        assert!(errors[1].contains("'ops' recurs every 0 days"));
+    }
+
+    #[test]
+    fn test_empty_recurrence_warned() {
+        let mut late = recurring(true);
+        late.start_after = Some(d(2025, 3, 1));
+        let warnings = recurrence_warnings(
+            &[late.clone(), recurring(false)],
+            d(2025, 1, 1),
+            &FxHashSet::default(),
+        );
+        assert_eq!(warnings.len(), 1);
+        assert_eq!(warnings[0].code, "empty_recurrence");
+        assert_eq!(warnings[0].task_ids, vec!["ops"]);
+        let completed: FxHashSet<String> = ["ops".to_string()].into_iter().collect();
+        assert!(recurrence_warnings(&[late], d(2025, 1, 1), &completed).is_empty());
+    }
+}
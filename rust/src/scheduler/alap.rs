@@ -0,0 +1,235 @@
+//! As-late-as-possible (ALAP) post-pass for the parallel scheduler.
+//!
+//! The forward pass yields a feasible schedule. Walking it backward from the
+//! latest completion, each task is pushed toward the latest finish allowed by
+//! its computed deadline, its successors' starts (with lag), and the makespan,
+//! stopping earlier only where a resource is busy or unavailable.
+
+use chrono::NaiveDate;
+use rustc_hash::FxHashMap;
+
+use crate::models::{Dependency, ScheduledTask, Task};
+
+use super::resource_schedule::ResourceSchedule;
+
+/// Shift `scheduled` tasks as late as their constraints allow.
+///
+/// `fixed` tasks never move but still occupy resources and constrain their
+/// dependencies. `lag_calendar` holds the periods working-day lags skip, and
+/// `availability` builds a resource's schedule with only its DNS periods and
+/// capacity. Returns the number of tasks moved.
+pub(crate) fn shift_late(
+    scheduled: &mut [ScheduledTask],
+    fixed: &[ScheduledTask],
+    tasks: &FxHashMap<String, Task>,
+    deadlines: &FxHashMap<String, NaiveDate>,
+    lag_calendar: &[(NaiveDate, NaiveDate)],
+    mut availability: impl FnMut(&str) -> ResourceSchedule,
+) -> usize {
+    let Some(makespan_end) = scheduled.iter().chain(fixed).map(|t| t.end_date).max() else {
+        return 0;
+    };
+
+    let mut times: FxHashMap<String, (NaiveDate, NaiveDate)> = scheduled
+        .iter()
+        .chain(fixed)
+        .map(|t| (t.task_id.clone(), (t.start_date, t.end_date)))
+        .collect();
+    let mut dependents: FxHashMap<&str, Vec<(&str, &Dependency)>> = FxHashMap::default();
+    for task in tasks.values() {
+        for dep in &task.dependencies {
+            dependents
+                .entry(dep.entity_id.as_str())
+                .or_default()
+                .push((task.id.as_str(), dep));
+        }
+    }
+    let mut occupancy: FxHashMap<String, Vec<(NaiveDate, NaiveDate, String)>> =
+        FxHashMap::default();
+    for task in scheduled.iter().chain(fixed) {
+        for resource in &task.resources {
+            occupancy.entry(resource.clone()).or_default().push((
+                task.start_date,
+                task.end_date,
+                task.task_id.clone(),
+            ));
+        }
+    }
+    let mut calendars: FxHashMap<String, ResourceSchedule> = FxHashMap::default();
+
+    let mut order: Vec<usize> = (0..scheduled.len()).collect();
+    order.sort_by(|&a, &b| {
+        (scheduled[b].end_date, scheduled[b].start_date)
+            .cmp(&(scheduled[a].end_date, scheduled[a].start_date))
+    });
+
+    let mut moved = 0;
+    for idx in order {
+        let st = &scheduled[idx];
+        if st.in_progress {
+            continue;
+        }
+        let Some(task) = tasks.get(&st.task_id) else {
+            continue;
+        };
+
+        let mut latest_end = makespan_end;
+        if let Some(&deadline) = deadlines.get(&st.task_id) {
+            latest_end = latest_end.min(deadline);
+        }
+        for &(dependent, dep) in dependents.get(st.task_id.as_str()).into_iter().flatten() {
+            if let Some(&(dependent_start, _)) = times.get(dependent) {
+                let bound = dep
+                    .lag_unit
+                    .latest_end(dependent_start, dep.lag_days, lag_calendar);
+                latest_end = latest_end.min(bound);
+            }
+        }
+        if latest_end <= st.end_date {
+            continue;
+        }
+
+        for resource in &st.resources {
+            calendars
+                .entry(resource.clone())
+                .or_insert_with(|| availability(resource));
+        }
+        let mut end_for = |start: NaiveDate| -> Option<NaiveDate> {
+            let mut end = start;
+            for resource in &st.resources {
+                let calendar = calendars.get_mut(resource)?;
+                if calendar.next_available_time(start) != start {
+                    return None;
+                }
+                end = end.max(calendar.calculate_completion_time(start, task.duration_days));
+            }
+            Some(end)
+        };
+        // Durations we cannot reproduce (e.g. efficiency-scaled) stay put
+        if end_for(st.start_date) != Some(st.end_date) {
+            continue;
+        }
+
+        let conflicts = |start: NaiveDate, end: NaiveDate| {
+            st.resources.iter().any(|resource| {
+                occupancy.get(resource).is_some_and(|periods| {
+                    periods
+                        .iter()
+                        .any(|(s, e, id)| *id != st.task_id && *s <= end && start <= *e)
+                })
+            })
+        };
+        let mut new_slot = None;
+        let mut start = latest_end;
+        while start > st.start_date {
+            if let Some(end) = end_for(start) {
+                if end <= latest_end && !conflicts(start, end) {
+                    new_slot = Some((start, end));
+                    break;
+                }
+            }
+            start = start.pred_opt().unwrap_or(st.start_date);
+        }
+        let Some((new_start, new_end)) = new_slot else {
+            continue;
+        };
+
+        for resource in &st.resources {
+            if let Some(periods) = occupancy.get_mut(resource) {
+                for period in periods.iter_mut().filter(|p| p.2 == st.task_id) {
+                    period.0 = new_start;
+                    period.1 = new_end;
+                }
+            }
+        }
+        times.insert(st.task_id.clone(), (new_start, new_end));
+        let st = &mut scheduled[idx];
+        st.start_date = new_start;
+        st.end_date = new_end;
+        st.start_offset = 0.0;
+        moved += 1;
+    }
+    moved
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::Dependency;
+
+    fn d(day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(2025, 1, day).unwrap()
+    }
+
+    fn task(id: &str, duration_days: f64, deps: &[&str]) -> Task {
+        Task {
+            id: id.to_string(),
+            duration_days,
+            dependencies: deps
+                .iter()
+                .map(|dep| Dependency {
+                    entity_id: dep.to_string(),
+                    ..Default::default()
+                })
+                .collect(),
+            resources: vec![("alice".to_string(), 1.0)],
+            ..Default::default()
+        }
+    }
+
+    fn placed(id: &str, start: NaiveDate, end: NaiveDate) -> ScheduledTask {
+        ScheduledTask {
+            task_id: id.to_string(),
+            start_date: start,
+            end_date: end,
+            resources: vec!["alice".to_string()],
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_shift_late_respects_deadline_successor_and_resource() {
+        let tasks: FxHashMap<String, Task> = [
+            task("a", 2.0, &[]),
+            task("b", 2.0, &[]),
+            task("c", 2.0, &["a"]),
+        ]
+        .into_iter()
+        .map(|t| (t.id.clone(), t))
+        .collect();
+        let mut scheduled = vec![
+            placed("a", d(1), d(3)),
+            placed("b", d(4), d(6)),
+            placed("c", d(7), d(9)),
+        ];
+        let deadlines: FxHashMap<String, NaiveDate> =
+            [("b".to_string(), d(8))].into_iter().collect();
+
+        let moved = shift_late(&mut scheduled, &[], &tasks, &deadlines, &[], |name| {
+            ResourceSchedule::new(None, name.to_string())
+        });
+
+        let times: Vec<(NaiveDate, NaiveDate)> = scheduled
+            .iter()
+            .map(|t| (t.start_date, t.end_date))
+            .collect();
+        // "c" already ends the schedule; "b" cannot pass "c" on alice; "a" feeds "c"
+        assert_eq!(times, vec![(d(1), d(3)), (d(4), d(6)), (d(7), d(9))]);
+        assert_eq!(moved, 0);
+
+        // On another resource "b" slides up to its deadline, freeing alice for "a"
+        scheduled[1].resources = vec!["bob".to_string()];
+        let moved = shift_late(&mut scheduled, &[], &tasks, &deadlines, &[], |name| {
+            ResourceSchedule::new(None, name.to_string())
+        });
+        assert_eq!(moved, 2);
+        assert_eq!(
+            (scheduled[1].start_date, scheduled[1].end_date),
+            (d(6), d(8))
+        );
+        assert_eq!(
+            (scheduled[0].start_date, scheduled[0].end_date),
+            (d(4), d(6))
+        );
+    }
+}
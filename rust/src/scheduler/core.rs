@@ -2,32 +2,111 @@
 
 use chrono::{Days, NaiveDate};
 use rustc_hash::{FxHashMap, FxHashSet};
-use std::collections::HashMap;
+use std::cmp::Reverse;
+use std::collections::{BTreeSet, BinaryHeap, HashMap};
+use std::rc::Rc;
+use std::time::Instant;
 use thiserror::Error;
 
-use crate::backward_pass::{backward_pass, BackwardPassConfig};
-use crate::config::{RolloutConfig, SchedulingConfig};
-use crate::models::{AlgorithmResult, ScheduledTask, Task};
-use crate::sorting::{sort_tasks, AtcParams, SortingError, TaskSortInfo};
-use crate::{log_changes, log_checks, log_debug};
-
+use crate::backward_pass::{
+    backward_pass, canonical_cycle, format_cycle, BackwardPassConfig, BackwardPassError,
+    DeadlineCalendar,
+};
+use crate::cancellation::{CancellationToken, StopCondition};
+use crate::config::{
+    DurationRounding, RolloutConfig, RolloutFidelity, ScheduleObjective, SchedulingConfig,
+    UnschedulablePolicy,
+};
+use crate::critical_path::ReservationRecord;
+use crate::eligibility::{eligible_tasks, TaskEligibility};
+use crate::interner::{ResourceIdInterner, TaskIdInt, TaskIdInterner};
+use crate::models::{
+    first_unblocked, AlgorithmMetadata, AlgorithmResult, DeadlineStatus, Dependency, LagUnit,
+    ScheduleSummary, ScheduleWarning, ScheduledTask, Task, UnschedulableTask,
+};
+use crate::preprocessing::{occupy_fixed, take_fixed_tasks};
+use crate::profiling::{self, PhaseTimings};
+use crate::progress::ProgressReporter;
+use crate::recurrence::{expand_recurring_tasks, recurrence_warnings};
+use crate::snapshot::{
+    resume_date, starting_placements, HeldReservation, ScheduleStep, SchedulerSnapshot,
+};
+use crate::sorting::{compute_sort_key, AtcParams, SortKey, SortingError, TaskSortInfo};
+use crate::validation::{
+    apply_unknown_dependency_policy, best_effort_relaxation, date_range_errors, duplicate_task_ids,
+    fixed_dependency_violations, input_warnings, leftover_task_ids, out_of_range_placements,
+    placed_ids, resolve_fixed_conflicts, restore_dropped_dependencies, task_warnings,
+    unschedulable_tasks, DateOverflow,
+};
+use crate::{log_changes, log_checks, log_debug, trace_placement, trace_span};
+
+use super::alap::shift_late;
+use super::events::{AdvanceLimit, EventQueue, EventSource, IterationLimitReached};
+use super::exclusion::ExclusionGroups;
 use super::resource_schedule::ResourceSchedule;
-use super::rollout::RolloutDecision;
+use super::rolling_wave::{start_overflow, RollingWave};
+use super::rollout::{RolloutDecision, RolloutTrace};
 use super::state::SchedulerState;
 
+/// Start times tried for a rolling-wave task before it is given up on.
+const MAX_FAR_ATTEMPTS: usize = 10_000;
+
 /// Errors that can occur during scheduling.
 #[derive(Error, Debug)]
 pub enum SchedulerError {
     #[error("Failed to schedule tasks: {0:?}")]
     FailedToSchedule(Vec<String>),
-    #[error("Circular dependency detected")]
-    CircularDependency,
+    #[error("Circular dependency detected: {}", format_cycle(.0))]
+    CircularDependency(Vec<String>),
     #[error("Resource not found: {0}")]
     ResourceNotFound(String),
+    #[error("Resource groups contain each other: {}", format_cycle(.0))]
+    ResourceGroupCycle(Vec<String>),
+    #[error("Dependencies on unknown tasks: {}", .0.join(", "))]
+    UnknownDependencies(Vec<String>),
     #[error("Invalid configuration: {0}")]
     InvalidConfig(String),
     #[error("Unknown scheduling strategy: {0}")]
     UnknownStrategy(String),
+    #[error("Duplicate task IDs: {}", .0.join(", "))]
+    DuplicateTaskIds(Vec<String>),
+    #[error("Invalid recurrences: {}", .0.join("; "))]
+    InvalidRecurrence(Vec<String>),
+    #[error("Dates out of range: {}", .0.join("; "))]
+    DateOverflow(Vec<String>),
+    #[error("Fixed tasks overlap: {}", .0.join("; "))]
+    FixedTaskConflicts(Vec<String>),
+    #[error("Scheduling cancelled after {} tasks were scheduled", .0.len())]
+    Cancelled(Vec<ScheduledTask>),
+    #[error("{0}")]
+    IterationLimit(IterationLimitReached),
+}
+
+impl From<BackwardPassError> for SchedulerError {
+    fn from(err: BackwardPassError) -> Self {
+        match err {
+            BackwardPassError::CircularDependency(cycle) => {
+                SchedulerError::CircularDependency(cycle)
+            }
+            BackwardPassError::DateOverflow(message) => SchedulerError::DateOverflow(vec![message]),
+        }
+    }
+}
+
+impl From<DateOverflow> for SchedulerError {
+    fn from(err: DateOverflow) -> Self {
+        SchedulerError::DateOverflow(vec![err.to_string()])
+    }
+}
+
+impl SchedulerError {
+    /// The offending dependency cycle, if this error describes one.
+    pub fn cycle(&self) -> Option<&[String]> {
+        match self {
+            SchedulerError::CircularDependency(cycle) => Some(cycle),
+            _ => None,
+        }
+    }
 }
 
 impl From<SortingError> for SchedulerError {
@@ -51,11 +130,112 @@ pub struct ResourceConfig {
     pub resource_order: Vec<String>,
     /// DNS periods per resource: resource_name -> [(start, end)]
     pub dns_periods: HashMap<String, Vec<(NaiveDate, NaiveDate)>>,
-    /// Resource spec expansion: spec -> [resource_names]
+    /// Resource groups: group -> [resource or group names]. Groups may nest.
     pub spec_expansion: HashMap<String, Vec<String>>,
+    /// Cost per working day for each resource (missing resources cost nothing)
+    pub cost_rates: HashMap<String, f64>,
+    /// Weight of total schedule cost in rollout scoring (0 = cost only breaks ties)
+    pub cost_weight: f64,
+    /// Daily working fraction per resource (e.g. 0.6 FTE); missing resources are full time
+    pub capacity: HashMap<String, f64>,
+    /// Skills per resource: resource_name -> [skills]
+    pub skills: HashMap<String, Vec<String>>,
+    /// Speed multiplier per resource: effective duration = duration / efficiency
+    pub efficiency: HashMap<String, f64>,
+    /// Per-skill speed multipliers: resource_name -> skill -> efficiency
+    pub skill_efficiency: HashMap<String, HashMap<String, f64>>,
+    /// Resource each task had in a previous plan: task_id -> resource_name
+    pub prior_assignments: HashMap<String, String>,
+    /// Days earlier another resource must finish a task to take it from its prior assignment
+    pub switch_penalty_days: f64,
+    /// Idle days a resource needs between task categories: from -> to -> days
+    pub changeover_days: HashMap<String, HashMap<String, f64>>,
+    /// Dated capacity changes per resource: resource_name -> [(effective_from, capacity)]
+    pub capacity_segments: HashMap<String, Vec<(NaiveDate, f64)>>,
+    /// Free-form metadata per resource for spec filters: resource_name -> key -> value
+    pub attributes: HashMap<String, HashMap<String, String>>,
 }
 
 impl ResourceConfig {
+    /// Get the daily working fraction for a resource (1.0 if unset or invalid).
+    pub fn capacity_of(&self, resource_name: &str) -> f64 {
+        match self.capacity.get(resource_name) {
+            Some(&c) if c.is_finite() && c > 0.0 => c,
+            _ => 1.0,
+        }
+    }
+
+    /// Daily working fraction for a resource on `date`, after any capacity segments.
+    pub fn capacity_on(&self, resource_name: &str, date: NaiveDate) -> f64 {
+        self.capacity_segments
+            .get(resource_name)
+            .and_then(|segments| {
+                segments
+                    .iter()
+                    .filter(|&&(from, c)| from <= date && c.is_finite() && c > 0.0)
+                    .max_by_key(|&&(from, _)| from)
+            })
+            .map_or_else(|| self.capacity_of(resource_name), |&(_, c)| c)
+    }
+
+    /// Build a resource schedule with this resource's DNS periods and capacity.
+    pub fn build_schedule(
+        &self,
+        resource_name: &str,
+        global_dns_periods: &[(NaiveDate, NaiveDate)],
+    ) -> ResourceSchedule {
+        let dns_periods = self.get_dns_periods(resource_name, global_dns_periods);
+        ResourceSchedule::new(Some(dns_periods), resource_name.to_string())
+            .with_capacity(self.capacity_of(resource_name))
+            .with_capacity_segments(
+                self.capacity_segments
+                    .get(resource_name)
+                    .map_or(&[], Vec::as_slice),
+            )
+    }
+
+    /// Best-case daily working fraction available to a task.
+    ///
+    /// Explicit resources are limited by the slowest one; auto-assigned tasks
+    /// assume the fastest eligible candidate.
+    pub fn task_capacity(&self, task: &Task) -> f64 {
+        if !task.resources.is_empty() {
+            task.resources
+                .iter()
+                .map(|(r, _)| self.capacity_of(r))
+                .fold(f64::INFINITY, f64::min)
+        } else if task.resource_spec.is_some() {
+            self.candidates_for(task)
+                .iter()
+                .map(|r| self.capacity_of(r))
+                .reduce(f64::max)
+                .unwrap_or(1.0)
+        } else {
+            1.0
+        }
+    }
+
+    /// Get the daily cost rate for a resource.
+    pub fn cost_rate(&self, resource_name: &str) -> f64 {
+        self.cost_rates.get(resource_name).copied().unwrap_or(0.0)
+    }
+
+    /// Whether any resource has a cost rate configured.
+    pub fn has_costs(&self) -> bool {
+        !self.cost_rates.is_empty()
+    }
+
+    /// Total cost of a set of scheduled tasks (duration times assigned resource rates).
+    pub fn schedule_cost(&self, scheduled_tasks: &[ScheduledTask]) -> f64 {
+        scheduled_tasks
+            .iter()
+            .map(|st| {
+                let daily: f64 = st.resources.iter().map(|r| self.cost_rate(r)).sum();
+                daily * st.duration_days
+            })
+            .sum()
+    }
+
     /// Get DNS periods for a resource, including global periods.
     pub fn get_dns_periods(
         &self,
@@ -69,15 +249,323 @@ impl ResourceConfig {
         periods
     }
 
+    /// Check whether a resource has every required skill.
+    pub fn has_skills(&self, resource_name: &str, required_skills: &[String]) -> bool {
+        if required_skills.is_empty() {
+            return true;
+        }
+        self.skills
+            .get(resource_name)
+            .is_some_and(|skills| required_skills.iter().all(|s| skills.contains(s)))
+    }
+
+    /// Efficiency of a resource on a task (1.0 = nominal speed).
+    ///
+    /// If the task requires skills with per-skill factors, the slowest of those
+    /// applies; otherwise the resource-level factor is used.
+    pub fn efficiency_for(&self, resource_name: &str, required_skills: &[String]) -> f64 {
+        let base = self.efficiency.get(resource_name).copied().unwrap_or(1.0);
+        let factor = self
+            .skill_efficiency
+            .get(resource_name)
+            .and_then(|per_skill| {
+                required_skills
+                    .iter()
+                    .filter_map(|skill| per_skill.get(skill).copied())
+                    .reduce(f64::min)
+            })
+            .unwrap_or(base);
+        if factor.is_finite() && factor > 0.0 {
+            factor
+        } else {
+            1.0
+        }
+    }
+
+    /// Whether any efficiency factors are configured.
+    pub fn has_efficiency(&self) -> bool {
+        !self.efficiency.is_empty() || !self.skill_efficiency.is_empty()
+    }
+
+    /// Duration of a task when performed by the given resource.
+    pub fn effective_duration(&self, resource_name: &str, task: &Task) -> f64 {
+        task.duration_days / self.efficiency_for(resource_name, &task.required_skills)
+    }
+
+    /// Number of skills a resource has beyond those required.
+    pub fn overqualification(&self, resource_name: &str, required_skills: &[String]) -> usize {
+        self.skills.get(resource_name).map_or(0, |skills| {
+            skills
+                .iter()
+                .filter(|s| !required_skills.contains(s))
+                .count()
+        })
+    }
+
+    /// Candidate resources for auto-assigning `task`.
+    ///
+    /// A `pinned_resource` is the only candidate. Otherwise the task's spec is
+    /// expanded and its prior assignment, if still a candidate, is moved first
+    /// so it wins ties.
+    pub fn candidates_for(&self, task: &Task) -> Vec<String> {
+        if let Some(pinned) = &task.pinned_resource {
+            return vec![pinned.clone()];
+        }
+        let Some(spec) = &task.resource_spec else {
+            return Vec::new();
+        };
+        let mut candidates = self.expand_resource_spec_with_skills(spec, &task.required_skills);
+        if let Some(pos) = self
+            .prior_assignments
+            .get(&task.id)
+            .and_then(|prior| candidates.iter().position(|r| r == prior))
+        {
+            let prior = candidates.remove(pos);
+            candidates.insert(0, prior);
+        }
+        candidates
+    }
+
+    /// Candidates of every task without explicit resources, keyed by task ID,
+    /// for contention-aware deadlines.
+    pub(crate) fn candidate_resources<'a>(
+        &self,
+        tasks: impl IntoIterator<Item = &'a Task>,
+    ) -> FxHashMap<String, Vec<String>> {
+        tasks
+            .into_iter()
+            .filter(|task| task.resources.is_empty())
+            .map(|task| (task.id.clone(), self.candidates_for(task)))
+            .filter(|(_, candidates)| !candidates.is_empty())
+            .collect()
+    }
+
+    /// Days added to a task's completion on `resource_name` when it moves the
+    /// task off its prior assignment (0 when it stays or has none).
+    pub fn switch_penalty(&self, task_id: &str, resource_name: &str) -> f64 {
+        match self.prior_assignments.get(task_id) {
+            Some(prior) if prior != resource_name => self.switch_penalty_days.max(0.0),
+            _ => 0.0,
+        }
+    }
+
+    /// Days `spec` handicaps a resource by: the `+days` suffix of the first
+    /// inclusion naming it, so `alice|bob+2` takes bob only when he finishes
+    /// more than 2 days before alice.
+    pub fn preference_penalty(&self, spec: &str, resource_name: &str) -> f64 {
+        if !spec.contains('+') {
+            return 0.0;
+        }
+        for part in spec.split('|').map(str::trim) {
+            if part.starts_with('!') {
+                continue;
+            }
+            let (name, days) = split_preference(part);
+            let mut members = Vec::new();
+            self.expand_part(name, &mut members);
+            if members.iter().any(|m| m == resource_name) {
+                return days;
+            }
+        }
+        0.0
+    }
+
+    /// Days added to a task's completion on `resource_name` when comparing
+    /// candidates: its switch penalty plus its spec's preference penalty.
+    pub fn assignment_penalty(&self, task: &Task, resource_name: &str) -> f64 {
+        self.switch_penalty(&task.id, resource_name)
+            + task
+                .resource_spec
+                .as_deref()
+                .map_or(0.0, |spec| self.preference_penalty(spec, resource_name))
+    }
+
+    /// Earliest date at or after `time` that work of `category` can start on
+    /// `schedule`, waiting out any changeover from the resource's previous category.
+    ///
+    /// Changeover gaps are whole calendar days after the previous task's end.
+    pub fn changeover_ready(
+        &self,
+        schedule: &ResourceSchedule,
+        category: Option<&str>,
+        time: NaiveDate,
+    ) -> NaiveDate {
+        let (Some(to), Some((end, from))) = (category, schedule.category_before(time)) else {
+            return time;
+        };
+        let gap = self
+            .changeover_days
+            .get(from)
+            .and_then(|row| row.get(to))
+            .copied()
+            .unwrap_or(0.0);
+        if from == to || !(gap > 0.0 && gap.is_finite()) {
+            return time;
+        }
+        end.checked_add_days(Days::new(1 + gap.ceil() as u64))
+            .map_or(time, |ready| ready.max(time))
+    }
+
+    /// Dates after work of `category` ending on `end` when a changeover from it completes.
+    pub fn changeover_releases(&self, category: &str, end: NaiveDate) -> Vec<NaiveDate> {
+        self.changeover_days
+            .get(category)
+            .into_iter()
+            .flatten()
+            .filter(|(to, &gap)| *to != category && gap > 0.0 && gap.is_finite())
+            .filter_map(|(_, &gap)| end.checked_add_days(Days::new(1 + gap.ceil() as u64)))
+            .collect()
+    }
+
+    /// A cycle of resource groups that contain each other, if any.
+    pub fn group_cycle(&self) -> Option<Vec<String>> {
+        let mut groups: Vec<&String> = self.spec_expansion.keys().collect();
+        groups.sort();
+        let mut done: FxHashSet<&str> = FxHashSet::default();
+        for group in groups {
+            let mut path = Vec::new();
+            if let Some(cycle) = self.find_group_cycle(group, &mut path, &mut done) {
+                return Some(canonical_cycle(cycle));
+            }
+        }
+        None
+    }
+
+    fn find_group_cycle<'a>(
+        &'a self,
+        group: &'a str,
+        path: &mut Vec<&'a str>,
+        done: &mut FxHashSet<&'a str>,
+    ) -> Option<Vec<String>> {
+        if let Some(pos) = path.iter().position(|g| *g == group) {
+            return Some(path[pos..].iter().map(|g| g.to_string()).collect());
+        }
+        if done.contains(group) {
+            return None;
+        }
+        let members = self.spec_expansion.get(group)?;
+        path.push(group);
+        for member in members {
+            if let Some(cycle) = self.find_group_cycle(member, path, done) {
+                return Some(cycle);
+            }
+        }
+        path.pop();
+        done.insert(group);
+        None
+    }
+
+    /// Whether a resource satisfies every `&`-separated condition of a filter.
+    ///
+    /// `skill:X` checks the resource's skills; `key:V` and `key=V` compare an
+    /// attribute as text, `key!=V` negates that, and `<`, `<=`, `>`, `>=`
+    /// compare it as a number. A missing or non-numeric attribute fails.
+    fn matches_filter(&self, resource: &str, filter: &str) -> bool {
+        filter.split('&').map(str::trim).all(|condition| {
+            if let Some(skill) = condition.strip_prefix("skill:") {
+                return self
+                    .skills
+                    .get(resource)
+                    .is_some_and(|skills| skills.iter().any(|s| s == skill.trim()));
+            }
+            let Some(pos) = condition.find([':', '=', '!', '<', '>']) else {
+                return false;
+            };
+            let (key, rest) = condition.split_at(pos);
+            let (op, wanted) = ["<=", ">=", "!=", ":", "=", "<", ">"]
+                .iter()
+                .find_map(|op| rest.strip_prefix(op).map(|v| (*op, v.trim())))
+                .unwrap_or(("", ""));
+            let Some(value) = self
+                .attributes
+                .get(resource)
+                .and_then(|a| a.get(key.trim()))
+            else {
+                return false;
+            };
+            let numeric = || value.parse::<f64>().ok().zip(wanted.parse::<f64>().ok());
+            match op {
+                ":" | "=" => value == wanted,
+                "!=" => value != wanted,
+                "<" => numeric().is_some_and(|(v, w)| v < w),
+                "<=" => numeric().is_some_and(|(v, w)| v <= w),
+                ">" => numeric().is_some_and(|(v, w)| v > w),
+                ">=" => numeric().is_some_and(|(v, w)| v >= w),
+                _ => false,
+            }
+        })
+    }
+
+    /// Append the resources one spec part names: all, a filter, a glob, a group or a resource.
+    fn expand_part(&self, part: &str, out: &mut Vec<String>) {
+        let (part, _) = split_preference(part);
+        let matching = |keep: &dyn Fn(&str) -> bool| {
+            self.resource_order
+                .iter()
+                .filter(|r| keep(r))
+                .cloned()
+                .collect::<Vec<_>>()
+        };
+        if part == "*" {
+            out.extend(self.resource_order.iter().cloned());
+        } else if part.contains([':', '&', '=', '<', '>']) {
+            out.extend(matching(&|r| self.matches_filter(r, part)));
+        } else if part.contains(['*', '?']) {
+            out.extend(matching(&|r| glob_match(part, r)));
+        } else {
+            self.expand_group(part, &mut Vec::new(), out);
+        }
+    }
+
+    /// Append a group's resources, expanding nested groups depth-first.
+    ///
+    /// Groups already being expanded are skipped, so a cycle cannot recurse.
+    fn expand_group<'a>(&'a self, group: &'a str, path: &mut Vec<&'a str>, out: &mut Vec<String>) {
+        let Some(members) = self.spec_expansion.get(group) else {
+            out.push(group.to_string());
+            return;
+        };
+        if path.contains(&group) {
+            return;
+        }
+        path.push(group);
+        for member in members {
+            self.expand_group(member, path, out);
+        }
+        path.pop();
+    }
+
+    /// Expand a resource spec and keep only candidates covering `required_skills`.
+    ///
+    /// Candidates are ordered least-overqualified first; ties keep spec order.
+    pub fn expand_resource_spec_with_skills(
+        &self,
+        spec: &str,
+        required_skills: &[String],
+    ) -> Vec<String> {
+        let mut candidates = self.expand_resource_spec(spec);
+        if required_skills.is_empty() {
+            return candidates;
+        }
+        candidates.retain(|r| self.has_skills(r, required_skills));
+        candidates.sort_by_key(|r| self.overqualification(r, required_skills));
+        candidates
+    }
+
     /// Expand a resource spec to list of candidate resource names.
     ///
     /// Supports:
     /// - "*" -> all resources in config order
     /// - "john|mary|susan" -> split by | (preserves order)
-    /// - "team_a" -> expand group alias
+    /// - "team_a" -> expand group alias, including groups nested inside it
+    /// - "qa-*" -> resources whose names match the glob (`*` and `?`)
+    /// - "skill:rust&level>=3" -> resources matching every attribute condition
+    /// - "alice|bob+2" -> bob only if he finishes over 2 days sooner (see `preference_penalty`)
     /// - "!john" -> all resources except john
     /// - "*|!john|!mary" -> all resources except john and mary
     /// - "team_a|!john" -> team_a members except john
+    ///
+    /// Globs and filters match in `resource_order`; exclusions accept every form.
     pub fn expand_resource_spec(&self, spec: &str) -> Vec<String> {
         // Parse spec into parts separated by |
         let parts: Vec<&str> = spec.split('|').map(|s| s.trim()).collect();
@@ -100,15 +588,8 @@ impl ResourceConfig {
         if inclusions.is_empty() {
             result = self.resource_order.clone();
         } else {
-            // Process each inclusion
             for inclusion in &inclusions {
-                if *inclusion == "*" {
-                    result.extend(self.resource_order.clone());
-                } else if let Some(group_members) = self.spec_expansion.get(*inclusion) {
-                    result.extend(group_members.clone());
-                } else {
-                    result.push(inclusion.to_string());
-                }
+                self.expand_part(inclusion, &mut result);
             }
         }
 
@@ -118,7 +599,12 @@ impl ResourceConfig {
 
         // Apply exclusions
         if !exclusions.is_empty() {
-            let exclusion_set: std::collections::HashSet<&str> = exclusions.into_iter().collect();
+            let mut excluded = Vec::new();
+            for exclusion in exclusions {
+                self.expand_part(exclusion, &mut excluded);
+            }
+            let exclusion_set: std::collections::HashSet<&str> =
+                excluded.iter().map(String::as_str).collect();
             result.retain(|r| !exclusion_set.contains(r.as_str()));
         }
 
@@ -126,6 +612,362 @@ impl ResourceConfig {
     }
 }
 
+/// Split a `name+days` preference suffix off a spec part (0 days without one).
+fn split_preference(part: &str) -> (&str, f64) {
+    part.rsplit_once('+')
+        .and_then(|(name, days)| {
+            let days: f64 = days.trim().parse().ok()?;
+            (days.is_finite() && days >= 0.0).then_some((name.trim(), days))
+        })
+        .unwrap_or((part, 0.0))
+}
+
+/// Match a name against a glob where `*` is any run of characters and `?` one character.
+fn glob_match(pattern: &str, name: &str) -> bool {
+    let (pattern, name): (Vec<char>, Vec<char>) =
+        (pattern.chars().collect(), name.chars().collect());
+    let (mut p, mut n) = (0, 0);
+    let mut backtrack: Option<(usize, usize)> = None;
+    while n < name.len() {
+        if p < pattern.len() && (pattern[p] == '?' || pattern[p] == name[n]) {
+            p += 1;
+            n += 1;
+        } else if p < pattern.len() && pattern[p] == '*' {
+            backtrack = Some((p, n));
+            p += 1;
+        } else if let Some((star, matched)) = backtrack {
+            p = star + 1;
+            n = matched + 1;
+            backtrack = Some((star, matched + 1));
+        } else {
+            return false;
+        }
+    }
+    pattern[p..].iter().all(|&c| c == '*')
+}
+
+/// Task graph interned for the forward pass.
+///
+/// Eligibility checks and sorting run on integer IDs; task ID strings are only
+/// resolved when a task is placed. Schedulable tasks get IDs `0..n` in sorted
+/// order; fixed, completed, and unknown dependency targets are interned after.
+///
+/// Eligibility is tracked incrementally: each unscheduled task counts its
+/// dependencies not yet placed, and once the count reaches zero it waits in a
+/// heap until its release date, then joins the ready set. Each time step
+/// only looks at the ready set, not the whole backlog. The ready set is still
+/// sorted by strategy on every step: critical ratio and ATC keys depend on the
+/// current date and on the whole backlog, so they cannot be kept in a queue.
+///
+/// The graph's shape is shared, so rollout simulations clone only the
+/// placement state.
+#[derive(Clone)]
+pub(super) struct ForwardGraph {
+    pub(super) shape: Rc<GraphShape>,
+    /// Scheduled (start, end) per interned task, including fixed tasks.
+    times: Vec<Option<(NaiveDate, NaiveDate)>>,
+    /// Dependencies of each schedulable task that are neither placed nor completed.
+    unsatisfied: Vec<u32>,
+    /// Earliest start allowed by placed dependencies (with lag) and `start_after`.
+    release: Vec<NaiveDate>,
+    /// Tasks with every dependency placed, waiting for their release date.
+    waiting: BinaryHeap<Reverse<(NaiveDate, TaskIdInt)>>,
+    /// Released unscheduled tasks; eligible unless blocked on the current date.
+    ready: BTreeSet<TaskIdInt>,
+}
+
+/// The parts of a `ForwardGraph` that placements do not change.
+pub(super) struct GraphShape {
+    ids: TaskIdInterner,
+    deps: Vec<Vec<(TaskIdInt, f64, LagUnit)>>,
+    /// Reverse of `deps`, indexed by every interned ID (including fixed tasks).
+    dependents: Vec<Vec<(TaskIdInt, f64, LagUnit)>>,
+    /// Non-working periods that working-day lags skip.
+    lag_calendar: Vec<(NaiveDate, NaiveDate)>,
+    start_afters: Vec<Option<NaiveDate>>,
+    blocked_periods: Vec<Vec<(NaiveDate, NaiveDate)>>,
+    completed: Vec<bool>,
+}
+
+impl GraphShape {
+    fn name(&self, id: TaskIdInt) -> &str {
+        self.ids.resolve(id).unwrap_or_default()
+    }
+}
+
+impl ForwardGraph {
+    pub(super) fn new(
+        tasks: &FxHashMap<String, Task>,
+        completed_task_ids: &FxHashSet<String>,
+        fixed_tasks: &[ScheduledTask],
+        lag_calendar: &[(NaiveDate, NaiveDate)],
+    ) -> Self {
+        let mut task_ids: Vec<&String> = tasks.keys().collect();
+        task_ids.sort();
+        let mut ids = TaskIdInterner::new(task_ids.iter().map(|id| id.to_string()));
+
+        let mut deps = Vec::with_capacity(task_ids.len());
+        let mut start_afters = Vec::with_capacity(task_ids.len());
+        let mut blocked_periods = Vec::with_capacity(task_ids.len());
+        for id in &task_ids {
+            let task = &tasks[id.as_str()];
+            deps.push(
+                task.dependencies
+                    .iter()
+                    .map(|dep| (ids.intern(&dep.entity_id), dep.lag_days, dep.lag_unit))
+                    .collect(),
+            );
+            start_afters.push(task.start_after);
+            blocked_periods.push(task.blocked_periods.clone());
+        }
+        for fixed in fixed_tasks {
+            ids.intern(&fixed.task_id);
+        }
+
+        let mut dependents = vec![Vec::new(); ids.len()];
+        for (id, task_deps) in deps.iter().enumerate() {
+            for &(dep, lag_days, unit) in task_deps {
+                dependents[dep as usize].push((id as TaskIdInt, lag_days, unit));
+            }
+        }
+
+        let mut completed = vec![false; ids.len()];
+        for (id, name) in ids.iter() {
+            completed[id as usize] = completed_task_ids.contains(name);
+        }
+        let mut times = vec![None; ids.len()];
+        for fixed in fixed_tasks {
+            if let Some(id) = ids.get(&fixed.task_id) {
+                times[id as usize] = Some((fixed.start_date, fixed.end_date));
+            }
+        }
+
+        let mut graph = Self {
+            unsatisfied: vec![0; deps.len()],
+            release: start_afters
+                .iter()
+                .map(|s| s.unwrap_or(NaiveDate::MIN))
+                .collect(),
+            shape: Rc::new(GraphShape {
+                ids,
+                deps,
+                dependents,
+                lag_calendar: lag_calendar.to_vec(),
+                start_afters,
+                blocked_periods,
+                completed,
+            }),
+            times,
+            waiting: BinaryHeap::new(),
+            ready: BTreeSet::new(),
+        };
+        let shape = Rc::clone(&graph.shape);
+        for id in graph.schedulable_ids().collect::<Vec<_>>() {
+            let idx = id as usize;
+            if graph.times[idx].is_some() {
+                continue;
+            }
+            for &(dep, lag_days, unit) in &shape.deps[idx] {
+                if shape.completed[dep as usize] {
+                    continue;
+                }
+                match graph.times[dep as usize] {
+                    Some((_, end)) => {
+                        graph.release[idx] = graph.release[idx].max(unit.ready_date(
+                            end,
+                            lag_days,
+                            &shape.lag_calendar,
+                        ))
+                    }
+                    None => graph.unsatisfied[idx] += 1,
+                }
+            }
+            if graph.unsatisfied[idx] == 0 {
+                graph.waiting.push(Reverse((graph.release[idx], id)));
+            }
+        }
+        graph
+    }
+
+    /// Tasks to place; completed tasks in the task list are skipped.
+    fn schedulable_ids(&self) -> impl Iterator<Item = TaskIdInt> + '_ {
+        (0..self.shape.deps.len() as TaskIdInt).filter(|&id| !self.shape.completed[id as usize])
+    }
+
+    fn name(&self, id: TaskIdInt) -> &str {
+        self.shape.name(id)
+    }
+
+    /// Task ID strings for `ids`, in sorted order.
+    fn names(&self, ids: &FxHashSet<TaskIdInt>) -> Vec<String> {
+        let mut sorted: Vec<TaskIdInt> = ids.iter().copied().collect();
+        sorted.sort_unstable();
+        sorted
+            .into_iter()
+            .map(|id| self.name(id).to_string())
+            .collect()
+    }
+
+    /// Whether every dependency of `id` is completed or placed.
+    fn dependencies_placed(&self, id: TaskIdInt) -> bool {
+        self.shape.deps[id as usize].iter().all(|&(dep, _, _)| {
+            self.shape.completed[dep as usize] || self.times[dep as usize].is_some()
+        })
+    }
+
+    /// Record a placement and queue the dates its dependents become ready.
+    fn mark_scheduled(
+        &mut self,
+        id: TaskIdInt,
+        start: NaiveDate,
+        end: NaiveDate,
+        events: &mut EventQueue,
+    ) {
+        self.times[id as usize] = Some((start, end));
+        self.ready.remove(&id);
+        for &(dependent, lag_days, unit) in &self.shape.dependents[id as usize] {
+            let idx = dependent as usize;
+            if self.times[idx].is_some() {
+                continue;
+            }
+            let ready = unit.ready_date(end, lag_days, &self.shape.lag_calendar);
+            self.release[idx] = self.release[idx].max(ready);
+            self.unsatisfied[idx] -= 1;
+            if self.unsatisfied[idx] == 0 {
+                self.waiting.push(Reverse((self.release[idx], dependent)));
+            }
+        }
+        self.push_ready_events(id, end, events);
+    }
+
+    fn push_ready_events(&self, id: TaskIdInt, end: NaiveDate, events: &mut EventQueue) {
+        for &(dependent, lag_days, unit) in &self.shape.dependents[id as usize] {
+            let ready = unit.ready_date(end, lag_days, &self.shape.lag_calendar);
+            events.push(ready, EventSource::Task(dependent));
+        }
+    }
+
+    /// Queue `start_after` dates, blocked period ends and readiness from
+    /// fixed-task dependencies.
+    fn seed_events(&self, events: &mut EventQueue) {
+        for id in self.schedulable_ids() {
+            if let Some(start_after) = self.shape.start_afters[id as usize] {
+                events.push(start_after, EventSource::Task(id));
+            }
+            for &(_, end) in &self.shape.blocked_periods[id as usize] {
+                if let Some(next) = end.succ_opt() {
+                    events.push(next, EventSource::Task(id));
+                }
+            }
+        }
+        for (id, times) in self.times.iter().enumerate() {
+            if let Some((_, end)) = times {
+                self.push_ready_events(id as TaskIdInt, *end, events);
+            }
+        }
+    }
+
+    /// Tasks whose dependencies (with lag) and `start_after` allow starting by
+    /// `current_time`, and which are not blocked on it.
+    ///
+    /// `current_time` must not decrease between calls.
+    fn eligible(&mut self, current_time: NaiveDate) -> Vec<TaskIdInt> {
+        while let Some(&Reverse((release, id))) = self.waiting.peek() {
+            if release > current_time {
+                break;
+            }
+            self.waiting.pop();
+            self.ready.insert(id);
+        }
+        self.ready
+            .iter()
+            .copied()
+            .filter(|&id| {
+                first_unblocked(&self.shape.blocked_periods[id as usize], current_time)
+                    == current_time
+            })
+            .collect()
+    }
+}
+
+/// Rollout decisions, reservations and counters recorded by one run.
+///
+/// Kept apart from the scheduler so the forward pass can borrow tasks while
+/// it records decisions.
+#[derive(Default)]
+struct RunRecord {
+    rollout_decisions: Vec<RolloutDecision>,
+    last_rollout_trace: Option<RolloutTrace>,
+    reservation_log: Vec<ReservationRecord>,
+    /// Main-loop iterations of the current schedule() run
+    iterations: usize,
+    rollout_ms: f64,
+}
+
+impl RunRecord {
+    /// Log reservations released at `now` with `outcome`.
+    fn log_released(&mut self, released: Vec<HeldReservation>, now: NaiveDate, outcome: &str) {
+        self.reservation_log.extend(
+            released
+                .iter()
+                .map(|r| ReservationRecord::from_held(r, now, outcome)),
+        );
+    }
+}
+
+/// What one forward pass produced.
+struct ForwardOutcome {
+    placed: Vec<ScheduledTask>,
+    /// Reservations still held when the pass stopped
+    held: Vec<HeldReservation>,
+    step: Option<ScheduleStep>,
+}
+
+/// Why a task could not be placed at the current time.
+enum Skipped {
+    /// An exclusive group of the task stays busy until this date
+    ExclusiveGroup(NaiveDate),
+    /// Its resources are busy, held or changing over, or rollout chose to wait
+    Resources,
+}
+
+/// Whether `resource` is held for a task other than `task_id`.
+fn reserved_for_other(
+    reservations: &FxHashMap<String, HeldReservation>,
+    resource: &str,
+    task_id: &str,
+) -> bool {
+    reservations
+        .get(resource)
+        .is_some_and(|r| r.task_id != task_id)
+}
+
+/// Queue the next release of `resource` after `current_time`.
+fn push_release(
+    events: &mut EventQueue,
+    resource_ids: &ResourceIdInterner,
+    resource_schedules: &FxHashMap<String, ResourceSchedule>,
+    resource: &str,
+    current_time: NaiveDate,
+) {
+    let (Some(res_id), Some(schedule)) =
+        (resource_ids.get(resource), resource_schedules.get(resource))
+    else {
+        return;
+    };
+    if let Some(release) = schedule.next_release_after(current_time) {
+        events.push(release, EventSource::Resource(res_id));
+    }
+}
+
+/// Category of every categorized task, keyed by task ID.
+pub(crate) fn task_categories(tasks: &[Task]) -> FxHashMap<String, String> {
+    tasks
+        .iter()
+        .filter_map(|t| Some((t.id.clone(), t.category.clone()?)))
+        .collect()
+}
+
 /// Unified scheduler implementing Parallel SGS with optional bounded rollout.
 pub struct ParallelScheduler {
     // Input data
@@ -138,16 +980,63 @@ pub struct ParallelScheduler {
     // Resource configuration
     resource_config: Option<ResourceConfig>,
     global_dns_periods: Vec<(NaiveDate, NaiveDate)>,
+    /// Durations before nearest-day rounding, reported in results.
+    planned_durations: FxHashMap<String, f64>,
+    /// Recurring tasks that expanded to nothing, reported on every run.
+    recurrence_warnings: Vec<ScheduleWarning>,
 
     // Computed during backward pass
     computed_deadlines: FxHashMap<String, NaiveDate>,
     computed_priorities: FxHashMap<String, i32>,
 
-    // Rollout tracking
-    rollout_decisions: Vec<RolloutDecision>,
+    // Rollout decisions, reservations and iterations of the current run
+    record: RunRecord,
+    // Reservations still held when the last run paused
+    held_reservations: Vec<HeldReservation>,
+    timings: PhaseTimings,
 
     // Pre-computed for performance
     max_horizon_days: Option<i32>,
+
+    // Cancellation
+    cancellation_token: Option<CancellationToken>,
+    stop_condition: StopCondition,
+
+    // Progress reporting; set for the next run, then owned by the running one
+    progress: Option<ProgressReporter>,
+    run_progress: Option<ProgressReporter>,
+
+    // Pause and resume
+    start_from: Option<SchedulerSnapshot>,
+    pause_at: Option<NaiveDate>,
+
+    // Single-stepping
+    stepping: bool,
+    last_step: Option<ScheduleStep>,
+
+    // Tasks placed in rolling-wave buckets by the last run
+    rolling_wave_tasks: usize,
+
+    // Mutually exclusive tasks (groups of every task, including fixed ones)
+    exclusions: ExclusionGroups,
+
+    // Task categories for changeover gaps (including fixed tasks)
+    task_categories: FxHashMap<String, String>,
+
+    // Warnings from the last schedule() run
+    warnings: Vec<ScheduleWarning>,
+
+    // Metadata of the last completed schedule() run
+    last_metadata: Option<AlgorithmMetadata>,
+
+    // Dependencies of fixed tasks, which leave the task map
+    fixed_dependencies: FxHashMap<String, Vec<Dependency>>,
+
+    // Fixed tasks taken out of the task map by the last run, put back by the next
+    fixed_source: Vec<Task>,
+
+    // Dependency lists the last run dropped as unknown, put back by the next
+    dropped_dependencies: Vec<(String, Vec<Dependency>)>,
 }
 
 impl ParallelScheduler {
@@ -169,24 +1058,81 @@ impl ParallelScheduler {
         if !valid_strategies.contains(&config.strategy.as_str()) {
             return Err(SchedulerError::UnknownStrategy(config.strategy.clone()));
         }
+        config
+            .validate()
+            .map_err(|e| SchedulerError::InvalidConfig(e.problems.join("; ")))?;
 
+        let freeze: &[(NaiveDate, NaiveDate)] = if config.allow_fixed_in_global_dns {
+            &[]
+        } else {
+            &global_dns_periods
+        };
+        let rounding = if config.sub_day {
+            DurationRounding::Up
+        } else {
+            config.duration_rounding
+        };
+        let recurrence_warnings = recurrence_warnings(&tasks, current_date, &completed_task_ids);
+        let (tasks, planned_durations) = rounding.apply_all(
+            expand_recurring_tasks(&tasks, current_date, &completed_task_ids)
+                .map_err(SchedulerError::InvalidRecurrence)?
+                .into_iter()
+                .map(|t| t.resolve_in_progress(current_date).keep_out_of(freeze)),
+        );
+        let duplicates = duplicate_task_ids(&tasks);
+        if !duplicates.is_empty() {
+            return Err(SchedulerError::DuplicateTaskIds(duplicates));
+        }
+        let resource_dns = resource_config
+            .iter()
+            .flat_map(|rc| rc.dns_periods.values());
+        let overflow = date_range_errors(
+            &tasks,
+            current_date,
+            global_dns_periods.iter().chain(resource_dns.flatten()),
+        );
+        if !overflow.is_empty() {
+            return Err(SchedulerError::DateOverflow(overflow));
+        }
+        if let Some(cycle) = resource_config.as_ref().and_then(|rc| rc.group_cycle()) {
+            return Err(SchedulerError::ResourceGroupCycle(cycle));
+        }
         let tasks_map: FxHashMap<String, Task> =
             tasks.iter().map(|t| (t.id.clone(), t.clone())).collect();
+        let exclusions = ExclusionGroups::new(&tasks);
+        let task_categories = task_categories(&tasks);
 
         // Use precomputed values or run backward pass
         let completed_set: FxHashSet<String> = completed_task_ids.iter().cloned().collect();
+        let mut timings = PhaseTimings::default();
+        let backward_started = profiling::start(config.profile);
         let (computed_deadlines, computed_priorities) =
             match (precomputed_deadlines, precomputed_priorities) {
                 (Some(d), Some(p)) => (d, p),
                 _ => {
                     let bp_config = BackwardPassConfig {
                         default_priority: config.default_priority,
+                        priority_decay: config.priority_decay,
+                        contention_aware: config.contention_aware_deadlines,
+                        calendar: config.calendar_aware_deadlines.then(|| DeadlineCalendar {
+                            global_dns_periods: global_dns_periods.clone(),
+                            dns_periods: resource_config
+                                .as_ref()
+                                .map(|rc| rc.dns_periods.clone().into_iter().collect())
+                                .unwrap_or_default(),
+                        }),
+                        lag_calendar: global_dns_periods.clone(),
+                        candidate_resources: resource_config
+                            .as_ref()
+                            .filter(|_| config.contention_aware_deadlines)
+                            .map(|rc| rc.candidate_resources(&tasks))
+                            .unwrap_or_default(),
                     };
-                    let bp_result = backward_pass(&tasks, &completed_set, &bp_config)
-                        .map_err(|_| SchedulerError::CircularDependency)?;
+                    let bp_result = backward_pass(&tasks, &completed_set, &bp_config)?;
                     (bp_result.computed_deadlines, bp_result.computed_priorities)
                 }
             };
+        profiling::stop(backward_started, &mut timings.backward_pass_ms);
 
         let max_horizon_days = rollout_config.as_ref().and_then(|r| r.max_horizon_days);
 
@@ -198,41 +1144,537 @@ impl ParallelScheduler {
             rollout_config,
             resource_config,
             global_dns_periods,
+            planned_durations,
+            recurrence_warnings,
             computed_deadlines,
             computed_priorities,
-            rollout_decisions: Vec::new(),
+            record: RunRecord::default(),
+            held_reservations: Vec::new(),
+            timings,
             max_horizon_days,
+            cancellation_token: None,
+            stop_condition: StopCondition::default(),
+            progress: None,
+            run_progress: None,
+            start_from: None,
+            pause_at: None,
+            stepping: false,
+            last_step: None,
+            rolling_wave_tasks: 0,
+            exclusions,
+            task_categories,
+            warnings: Vec::new(),
+            fixed_dependencies: FxHashMap::default(),
+            fixed_source: Vec::new(),
+            dropped_dependencies: Vec::new(),
+            last_metadata: None,
         })
     }
 
-    /// Run the scheduling algorithm.
-    pub fn schedule(&mut self) -> Result<AlgorithmResult, SchedulerError> {
-        // Phase 0: Process fixed tasks (with start_on/end_on)
-        let fixed_tasks = self.process_fixed_tasks();
+    /// Set a token that can be used to cancel the next run.
+    ///
+    /// The token applies to one run only; set it again before each call.
+    pub fn set_cancellation_token(&mut self, token: CancellationToken) {
+        self.cancellation_token = Some(token);
+    }
 
-        // Phase 1: Forward pass with Parallel SGS
-        let scheduled_tasks = self.schedule_forward(&fixed_tasks)?;
+    /// Set a reporter invoked periodically with progress updates during the next run.
+    pub fn set_progress_reporter(&mut self, reporter: ProgressReporter) {
+        self.progress = Some(reporter);
+    }
 
-        // Combine fixed and scheduled tasks
+    /// Run the scheduling algorithm.
+    ///
+    /// Returns `SchedulerError::Cancelled` with the partial schedule if the
+    /// cancellation token is triggered or the configured timeout elapses.
+    pub fn schedule(&mut self) -> Result<AlgorithmResult, SchedulerError> {
+        let started = Instant::now();
+        let _span = trace_span!(
+            INFO,
+            "schedule",
+            algorithm = self.algorithm_name(),
+            tasks = self.tasks.len()
+        );
+        self.record.iterations = 0;
+        self.timings = PhaseTimings {
+            backward_pass_ms: self.timings.backward_pass_ms,
+            ..Default::default()
+        };
+
+        // Fixed tasks leave the task map below but still constrain the ALAP pass
+        self.restore_fixed_tasks();
+        let alap_tasks = self.config.alap.then(|| self.tasks.clone());
+
+        // Phase 0: Process fixed tasks (with start_on/end_on)
+        let (ignored_dependencies, fixed_tasks) = self.start_run()?;
+
+        // Phase 1: Forward pass with Parallel SGS
+        self.record.last_rollout_trace = None;
+        let main_loop_started = profiling::start(self.config.profile);
+        let mut scheduled_tasks = self.run_forward(&fixed_tasks)?;
+        profiling::stop(main_loop_started, &mut self.timings.main_loop_ms);
+        let (unschedulable, relaxed) =
+            self.resolve_unschedulable(&fixed_tasks, &mut scheduled_tasks)?;
+
+        // Phase 2 (optional): shift work as late as deadlines allow
+        let alap_shifted = alap_tasks.map(|tasks| {
+            shift_late(
+                &mut scheduled_tasks,
+                &fixed_tasks,
+                &tasks,
+                &self.computed_deadlines,
+                &self.global_dns_periods,
+                |resource| match &self.resource_config {
+                    Some(rc) => rc.build_schedule(resource, &self.global_dns_periods),
+                    None => ResourceSchedule::new(
+                        Some(self.global_dns_periods.clone()),
+                        resource.to_string(),
+                    ),
+                },
+            )
+        });
+
+        self.warnings.extend(fixed_dependency_violations(
+            &fixed_tasks,
+            &scheduled_tasks,
+            &self.fixed_dependencies,
+            &self.global_dns_periods,
+        ));
+
+        // Combine fixed and scheduled tasks
         let mut all_tasks = fixed_tasks;
         all_tasks.extend(scheduled_tasks);
+        for placement in &mut all_tasks {
+            placement.set_effective_duration(
+                self.config.sub_day,
+                self.planned_durations.get(&placement.task_id).copied(),
+            );
+            // Auto-assigned remaining work of a started task
+            placement.in_progress |= self
+                .tasks
+                .get(&placement.task_id)
+                .is_some_and(Task::is_in_progress);
+        }
+        let overflow = out_of_range_placements(&all_tasks);
+        if !overflow.is_empty() {
+            return Err(SchedulerError::DateOverflow(overflow));
+        }
 
         let mut metadata = HashMap::new();
         metadata.insert("algorithm".to_string(), self.algorithm_name().to_string());
         metadata.insert("strategy".to_string(), self.config.strategy.clone());
+        if let Some(shifted) = alap_shifted {
+            metadata.insert("alap_shifted".to_string(), shifted.to_string());
+        }
+        if self.config.rolling_wave_horizon_days.is_some() {
+            metadata.insert(
+                "rolling_wave_tasks".to_string(),
+                self.rolling_wave_tasks.to_string(),
+            );
+        }
+        if let Some(rc) = self.resource_config.as_ref().filter(|rc| rc.has_costs()) {
+            metadata.insert(
+                "total_cost".to_string(),
+                format!("{:.2}", rc.schedule_cost(&all_tasks)),
+            );
+        }
+        if !ignored_dependencies.is_empty() {
+            metadata.insert(
+                "ignored_dependencies".to_string(),
+                ignored_dependencies.join(","),
+            );
+        }
         if self.rollout_config.is_some() {
             metadata.insert(
                 "rollout_decisions".to_string(),
-                self.rollout_decisions.len().to_string(),
+                self.record.rollout_decisions.len().to_string(),
+            );
+            let fulfilled = self
+                .record
+                .reservation_log
+                .iter()
+                .filter(|r| r.outcome == "fulfilled")
+                .count();
+            let held_days: i64 = self
+                .record
+                .reservation_log
+                .iter()
+                .map(|r| r.held_days())
+                .sum();
+            metadata.insert(
+                "reservations".to_string(),
+                self.record.reservation_log.len().to_string(),
             );
+            metadata.insert("reservations_fulfilled".to_string(), fulfilled.to_string());
+            metadata.insert("reservation_held_days".to_string(), held_days.to_string());
+        }
+        if !relaxed.is_empty() {
+            metadata.insert("relaxed_dependencies".to_string(), relaxed.join(","));
         }
 
+        let typed = AlgorithmMetadata {
+            algorithm: self.algorithm_name().to_string(),
+            strategy: Some(self.config.strategy.clone()),
+            rollout_decision_count: self.record.rollout_decisions.len(),
+            iterations: self.record.iterations,
+            wall_time_ms: started.elapsed().as_secs_f64() * 1000.0,
+            metrics: ScheduleSummary::measure(
+                &all_tasks,
+                &self.tasks,
+                self.current_date,
+                self.config.default_priority,
+            ),
+            timings: self.config.profile.then(|| self.timings.clone()),
+            deadlines: DeadlineStatus::check_all(&all_tasks, &self.tasks),
+        };
+        self.last_metadata = Some(typed.clone());
         Ok(AlgorithmResult {
             scheduled_tasks: all_tasks,
             algorithm_metadata: metadata,
+            unschedulable,
+            warnings: std::mem::take(&mut self.warnings),
+            metadata: Some(typed),
+        })
+    }
+
+    /// Schedule decisions up to and including `pause_at` and return the state.
+    ///
+    /// Pass the snapshot (possibly edited) to `restore` to continue, on this
+    /// scheduler or a new one built from the same tasks. The ALAP pass and
+    /// unschedulable-task reporting wait until the run finishes.
+    pub fn schedule_until(
+        &mut self,
+        pause_at: NaiveDate,
+    ) -> Result<SchedulerSnapshot, SchedulerError> {
+        let (_, fixed_tasks) = self.start_run()?;
+        self.pause_at = Some(pause_at);
+        let outcome = self.run_forward(&fixed_tasks);
+        self.pause_at = None;
+        let mut scheduled_tasks = fixed_tasks;
+        scheduled_tasks.extend(outcome?);
+        Ok(SchedulerSnapshot {
+            current_time: resume_date(pause_at),
+            scheduled_tasks,
+            reservations: std::mem::take(&mut self.held_reservations),
         })
     }
 
+    /// Make exactly one decision (place one task or advance time) and return it.
+    ///
+    /// Each call resumes from the previous step's snapshot (or the one given to
+    /// `restore`). Once a step reports "finished", `schedule()` returns the
+    /// complete result.
+    pub fn schedule_step(&mut self) -> Result<ScheduleStep, SchedulerError> {
+        self.run_step(true)
+    }
+
+    /// Report the decision the next `schedule_step()` would make, without making it.
+    ///
+    /// The returned step shows the ranked targets, the chosen task and its
+    /// resources; the scheduler stays where it was.
+    pub fn preview_step(&mut self) -> Result<ScheduleStep, SchedulerError> {
+        self.run_step(false)
+    }
+
+    fn run_step(&mut self, commit: bool) -> Result<ScheduleStep, SchedulerError> {
+        // start_run() takes fixed tasks out of the task map; a preview puts them back
+        let saved = (!commit).then(|| (self.tasks.clone(), self.fixed_dependencies.clone()));
+        let started = self.start_run();
+        let outcome = started.and_then(|(_, fixed_tasks)| {
+            self.stepping = true;
+            let outcome = self.run_forward(&fixed_tasks);
+            self.stepping = false;
+            outcome.map(|scheduled| (fixed_tasks, scheduled))
+        });
+        if let Some((tasks, fixed_dependencies)) = saved {
+            self.tasks = tasks;
+            self.fixed_dependencies = fixed_dependencies;
+        }
+        let (fixed_tasks, scheduled) = outcome?;
+        let mut step = self
+            .last_step
+            .take()
+            .unwrap_or_else(|| ScheduleStep::new(self.current_date));
+        step.snapshot.scheduled_tasks = fixed_tasks;
+        step.snapshot.scheduled_tasks.extend(scheduled);
+        if commit {
+            self.start_from = Some(step.snapshot.clone());
+        }
+        Ok(step)
+    }
+
+    /// Resume later runs from `snapshot` instead of from the scheduling date.
+    ///
+    /// The snapshot's placements are kept as given (edit them to pin decisions)
+    /// and scheduling continues from its `current_time`. `None` starts over.
+    pub fn restore(&mut self, snapshot: Option<SchedulerSnapshot>) {
+        self.start_from = snapshot;
+    }
+
+    /// Reset per-run state and return ignored dependencies and starting placements.
+    fn start_run(&mut self) -> Result<(Vec<String>, Vec<ScheduledTask>), SchedulerError> {
+        self.stop_condition =
+            StopCondition::new(self.cancellation_token.take(), self.config.timeout_secs);
+        self.run_progress = self.progress.take();
+        self.record.reservation_log.clear();
+        self.restore_fixed_tasks();
+
+        let ignored_dependencies = apply_unknown_dependency_policy(
+            &mut self.tasks,
+            &self.completed_task_ids,
+            self.config.unknown_dependencies,
+            &mut self.dropped_dependencies,
+        )
+        .map_err(SchedulerError::UnknownDependencies)?;
+        self.warnings = input_warnings(
+            self.tasks.values(),
+            self.current_date,
+            &self.completed_task_ids,
+        );
+        self.warnings.extend(task_warnings(
+            self.tasks.values(),
+            self.current_date,
+            &self.completed_task_ids,
+        ));
+        self.warnings
+            .extend(self.recurrence_warnings.iter().cloned());
+        // The backward pass derives deadlines from external milestones itself
+        let fixed = take_fixed_tasks(
+            &mut self.tasks,
+            &mut self.fixed_dependencies,
+            self.resource_config.as_ref(),
+            &self.global_dns_periods,
+            self.current_date,
+        )?;
+        self.fixed_source = fixed.tasks;
+        self.warnings.extend(fixed.warnings);
+        let mut fixed_tasks = fixed.placements;
+        self.warnings.extend(
+            resolve_fixed_conflicts(&mut fixed_tasks, self.config.on_fixed_conflict)
+                .map_err(SchedulerError::FixedTaskConflicts)?,
+        );
+        Ok((
+            ignored_dependencies,
+            starting_placements(self.start_from.as_ref(), fixed_tasks),
+        ))
+    }
+
+    /// Put back the fixed tasks the last run took out of the task map.
+    fn restore_fixed_tasks(&mut self) {
+        for task in self.fixed_source.drain(..) {
+            self.tasks.insert(task.id.clone(), task);
+        }
+        restore_dropped_dependencies(&mut self.tasks, &mut self.dropped_dependencies);
+    }
+
+    /// Run the forward pass, adding fixed tasks to a cancelled partial schedule.
+    ///
+    /// With a rolling-wave horizon, only decisions before the horizon are made
+    /// day by day and later work is placed in coarse buckets.
+    fn run_forward(
+        &mut self,
+        fixed_tasks: &[ScheduledTask],
+    ) -> Result<Vec<ScheduledTask>, SchedulerError> {
+        let horizon = self.rolling_wave_horizon();
+        if horizon.is_some() {
+            self.pause_at = horizon.and_then(|h| h.pred_opt());
+        }
+        let outcome = self.schedule_forward(fixed_tasks);
+        if horizon.is_some() {
+            self.pause_at = None;
+        }
+        let mut scheduled = outcome.map_err(|e| match e {
+            SchedulerError::Cancelled(partial) => {
+                let mut all_tasks = fixed_tasks.to_vec();
+                all_tasks.extend(partial);
+                SchedulerError::Cancelled(all_tasks)
+            }
+            e => e,
+        })?;
+        if let Some(horizon) = horizon {
+            self.place_far_future(horizon, fixed_tasks, &mut scheduled)?;
+        }
+        Ok(scheduled)
+    }
+
+    /// First day of rolling-wave buckets, unless pausing or stepping.
+    fn rolling_wave_horizon(&self) -> Option<NaiveDate> {
+        let days = self.config.rolling_wave_horizon_days?;
+        if self.pause_at.is_some() || self.stepping {
+            return None;
+        }
+        self.current_date.checked_add_days(Days::new(days.into()))
+    }
+
+    /// Place work left after the horizon into rolling-wave buckets.
+    fn place_far_future(
+        &mut self,
+        horizon: NaiveDate,
+        fixed_tasks: &[ScheduledTask],
+        scheduled: &mut Vec<ScheduledTask>,
+    ) -> Result<(), SchedulerError> {
+        let mut placed = fixed_tasks.to_vec();
+        placed.extend(scheduled.iter().cloned());
+        let leftover =
+            leftover_task_ids(&self.tasks, &placed_ids(&placed), &self.completed_task_ids);
+        let wave = RollingWave {
+            horizon,
+            bucket_days: self.config.rolling_wave_bucket_days.max(1),
+            resource_config: self.resource_config.as_ref(),
+            global_dns_periods: &self.global_dns_periods,
+        };
+        // Placements go through the main pass's checks on the real calendar
+        let mut state = self.initial_state(&placed);
+        state.reservations.clear();
+        let far = wave.place(
+            &self.tasks,
+            &leftover,
+            &placed,
+            &self.completed_task_ids,
+            |task| {
+                let priority = self
+                    .computed_priorities
+                    .get(&task.id)
+                    .copied()
+                    .unwrap_or(self.config.default_priority);
+                let deadline = self.computed_deadlines.get(&task.id).copied();
+                (
+                    std::cmp::Reverse(priority),
+                    deadline.unwrap_or(NaiveDate::MAX),
+                    task.rank.unwrap_or(i64::MAX),
+                )
+            },
+            |task, from| self.place_from(&mut state, task, from),
+        )?;
+        self.rolling_wave_tasks = far.len();
+        if far.len() < leftover.len() && self.config.on_unschedulable == UnschedulablePolicy::Error
+        {
+            let far_ids = placed_ids(&far);
+            return Err(SchedulerError::FailedToSchedule(
+                leftover
+                    .into_iter()
+                    .filter(|id| !far_ids.contains(id.as_str()))
+                    .collect(),
+            ));
+        }
+        scheduled.extend(far);
+        Ok(())
+    }
+
+    /// Place `task` at the first time from `from` on that `try_place` allows.
+    ///
+    /// Returns `None` if its resources never come free in time.
+    fn place_from(
+        &self,
+        state: &mut SchedulerState,
+        task: &Task,
+        from: NaiveDate,
+    ) -> Result<Option<ScheduledTask>, SchedulerError> {
+        let Some(id) = state.graph.shape.ids.get(&task.id) else {
+            return Ok(None);
+        };
+        let mut time = from;
+        for _ in 0..MAX_FAR_ATTEMPTS {
+            state.current_time = first_unblocked(&task.blocked_periods, time);
+            match self.try_place(None, id, task, state) {
+                Ok(placed) => {
+                    self.commit_placement(state, id, placed.clone());
+                    return Ok(Some(placed));
+                }
+                Err(Skipped::ExclusiveGroup(free)) => time = free,
+                Err(Skipped::Resources) => {
+                    let Some(next) = self.next_free_time(state, task) else {
+                        return Ok(None);
+                    };
+                    time = next.ok_or_else(|| start_overflow(&task.id))?;
+                }
+            }
+        }
+        Ok(None)
+    }
+
+    /// Next time after the current one at which `task`'s resources may all be
+    /// free and changed over (any candidate, if auto-assigned).
+    ///
+    /// `None` if it has no resources to wait for; `Some(None)` past the calendar.
+    fn next_free_time(&self, state: &SchedulerState, task: &Task) -> Option<Option<NaiveDate>> {
+        let time = state.current_time;
+        let free_from = |resource: &str| {
+            let schedule = state.resource_schedules.get(resource)?;
+            let free = schedule.next_available_time(time);
+            Some(self.resource_config.as_ref().map_or(free, |rc| {
+                schedule.next_available_time(rc.changeover_ready(
+                    schedule,
+                    task.category.as_deref(),
+                    free,
+                ))
+            }))
+        };
+        let free = match (&task.resource_spec, &self.resource_config) {
+            (Some(_), Some(rc)) => rc
+                .candidates_for(task)
+                .iter()
+                .filter_map(|r| free_from(r))
+                .min(),
+            _ => task
+                .resources
+                .iter()
+                .map(|(r, _)| free_from(r))
+                .collect::<Option<Vec<_>>>()?
+                .into_iter()
+                .max(),
+        }?;
+        Some(time.succ_opt().map(|next| free.max(next)))
+    }
+
+    /// Apply `on_unschedulable` to the tasks the forward pass left over.
+    ///
+    /// Under best effort, reschedules once with unsatisfiable dependencies of
+    /// leftover tasks dropped for this run only. Returns the unschedulable tasks and the tasks
+    /// whose dependencies were dropped.
+    fn resolve_unschedulable(
+        &mut self,
+        fixed_tasks: &[ScheduledTask],
+        scheduled_tasks: &mut Vec<ScheduledTask>,
+    ) -> Result<(Vec<UnschedulableTask>, Vec<String>), SchedulerError> {
+        let Some((relaxed_tasks, relaxed)) = best_effort_relaxation(
+            self.config.on_unschedulable,
+            &self.tasks,
+            fixed_tasks.iter().chain(scheduled_tasks.iter()),
+            &self.completed_task_ids,
+        ) else {
+            return Ok((self.unschedulable(fixed_tasks, scheduled_tasks), Vec::new()));
+        };
+        // Reschedule from a relaxed copy so later runs see the tasks as given
+        let tasks = std::mem::replace(&mut self.tasks, relaxed_tasks);
+        self.record.rollout_decisions.clear();
+        self.record.reservation_log.clear();
+        let rerun = self.run_forward(fixed_tasks).map(|placed| {
+            let unschedulable = self.unschedulable(fixed_tasks, &placed);
+            (placed, unschedulable)
+        });
+        self.tasks = tasks;
+        let (placed, unschedulable) = rerun?;
+        *scheduled_tasks = placed;
+        Ok((unschedulable, relaxed))
+    }
+
+    /// Tasks neither fixed nor placed, under `on_unschedulable`.
+    fn unschedulable(
+        &self,
+        fixed_tasks: &[ScheduledTask],
+        scheduled_tasks: &[ScheduledTask],
+    ) -> Vec<UnschedulableTask> {
+        unschedulable_tasks(
+            self.config.on_unschedulable,
+            &self.tasks,
+            fixed_tasks.iter().chain(scheduled_tasks),
+            &self.completed_task_ids,
+            self.resource_config.as_ref(),
+            &self.global_dns_periods,
+        )
+    }
+
     fn algorithm_name(&self) -> &str {
         if self.rollout_config.is_some() {
             "bounded_rollout"
@@ -241,6 +1683,11 @@ impl ParallelScheduler {
         }
     }
 
+    /// Metadata of the last completed schedule() run.
+    pub fn get_metadata(&self) -> Option<&AlgorithmMetadata> {
+        self.last_metadata.as_ref()
+    }
+
     /// Get computed deadlines.
     pub fn get_computed_deadlines(&self) -> HashMap<String, NaiveDate> {
         // Convert FxHashMap to std HashMap for Python interface
@@ -259,91 +1706,28 @@ impl ParallelScheduler {
             .collect()
     }
 
+    /// Which tasks could start on `as_of`, and what blocks the rest.
+    ///
+    /// Tasks with fixed dates leave the scheduler during a run, so query
+    /// before scheduling to include them.
+    pub fn get_eligible_tasks(&self, as_of: NaiveDate) -> Vec<TaskEligibility> {
+        eligible_tasks(self.tasks.values(), &self.completed_task_ids, as_of)
+    }
+
     /// Get rollout decisions made during scheduling.
     pub fn get_rollout_decisions(&self) -> Vec<RolloutDecision> {
-        self.rollout_decisions.clone()
+        self.record.rollout_decisions.clone()
     }
 
-    /// Process tasks with fixed dates (start_on/end_on).
-    fn process_fixed_tasks(&mut self) -> Vec<ScheduledTask> {
-        let mut fixed_results: Vec<ScheduledTask> = Vec::new();
-        let mut to_remove: Vec<String> = Vec::new();
-
-        for (task_id, task) in &self.tasks {
-            if task.start_on.is_none() && task.end_on.is_none() {
-                continue;
-            }
-
-            let (start, end) = match (task.start_on, task.end_on) {
-                (Some(s), Some(e)) => (s, e),
-                (Some(s), None) => {
-                    let e = self.calculate_dns_aware_end_date(task, s);
-                    (s, e)
-                }
-                (None, Some(e)) => {
-                    let s = e
-                        .checked_sub_days(Days::new(task.duration_days.ceil() as u64))
-                        .unwrap_or(e);
-                    (s, e)
-                }
-                (None, None) => unreachable!(),
-            };
-
-            let resources = if task.duration_days == 0.0 {
-                vec![] // Milestones have no resources
-            } else {
-                task.resources.iter().map(|(r, _)| r.clone()).collect()
-            };
-
-            fixed_results.push(ScheduledTask {
-                task_id: task_id.clone(),
-                start_date: start,
-                end_date: end,
-                duration_days: task.duration_days,
-                resources,
-            });
-
-            to_remove.push(task_id.clone());
-        }
-
-        // Remove fixed tasks from scheduling problem
-        for task_id in to_remove {
-            self.tasks.remove(&task_id);
-        }
-
-        fixed_results
+    /// Simulations behind the last rollout decision of the last run
+    /// (requires `RolloutConfig.trace_rollouts`).
+    pub fn get_last_rollout_trace(&self) -> Option<RolloutTrace> {
+        self.record.last_rollout_trace.clone()
     }
 
-    /// Calculate end date accounting for DNS periods.
-    fn calculate_dns_aware_end_date(&self, task: &Task, start: NaiveDate) -> NaiveDate {
-        let resource_config = match &self.resource_config {
-            Some(rc) => rc,
-            None => {
-                return start
-                    .checked_add_days(Days::new(task.duration_days.ceil() as u64))
-                    .unwrap_or(start)
-            }
-        };
-
-        if task.resources.is_empty() {
-            return start
-                .checked_add_days(Days::new(task.duration_days.ceil() as u64))
-                .unwrap_or(start);
-        }
-
-        let mut max_end = start;
-        for (resource_name, _) in &task.resources {
-            let dns_periods =
-                resource_config.get_dns_periods(resource_name, &self.global_dns_periods);
-            let mut resource_schedule =
-                ResourceSchedule::new(Some(dns_periods), resource_name.clone());
-            let completion = resource_schedule.calculate_completion_time(start, task.duration_days);
-            if completion > max_end {
-                max_end = completion;
-            }
-        }
-
-        max_end
+    /// Resource reservations made by rollout skips during the last run.
+    pub fn get_reservations(&self) -> Vec<ReservationRecord> {
+        self.record.reservation_log.clone()
     }
 
     /// Main forward scheduling loop.
@@ -351,20 +1735,23 @@ impl ParallelScheduler {
         &mut self,
         fixed_tasks: &[ScheduledTask],
     ) -> Result<Vec<ScheduledTask>, SchedulerError> {
-        // Initialize state
-        let mut scheduled: FxHashMap<String, (NaiveDate, NaiveDate)> = FxHashMap::default();
-        let mut unscheduled: FxHashSet<String> = self.tasks.keys().cloned().collect();
-        let mut result: Vec<ScheduledTask> = Vec::new();
-
-        // Pre-populate scheduled dict with fixed tasks
-        for fixed_task in fixed_tasks {
-            scheduled.insert(
-                fixed_task.task_id.clone(),
-                (fixed_task.start_date, fixed_task.end_date),
-            );
+        let mut record = std::mem::take(&mut self.record);
+        let outcome = self.forward_loop(&mut record, fixed_tasks);
+        self.timings.rollout_ms += std::mem::take(&mut record.rollout_ms);
+        self.record = record;
+        let outcome = outcome?;
+        if self.pause_at.is_some() {
+            self.held_reservations = outcome.held;
+        }
+        if outcome.step.is_some() {
+            self.last_step = outcome.step;
         }
+        Ok(outcome.placed)
+    }
 
-        // Initialize resource schedules
+    /// Resource schedules with fixed tasks blocked, the interned graph and
+    /// the events known before any placement.
+    fn initial_state(&self, fixed_tasks: &[ScheduledTask]) -> SchedulerState {
         let mut all_resources: FxHashSet<String> = FxHashSet::default();
         for task in self.tasks.values() {
             for (resource_name, _) in &task.resources {
@@ -384,47 +1771,144 @@ impl ParallelScheduler {
 
         let mut resource_schedules: FxHashMap<String, ResourceSchedule> = FxHashMap::default();
         for resource in &all_resources {
-            let unavailable_periods = match &self.resource_config {
-                Some(rc) => rc.get_dns_periods(resource, &self.global_dns_periods),
-                None => self.global_dns_periods.clone(),
+            let schedule = match &self.resource_config {
+                Some(rc) => rc.build_schedule(resource, &self.global_dns_periods),
+                None => {
+                    ResourceSchedule::new(Some(self.global_dns_periods.clone()), resource.clone())
+                }
             };
-            resource_schedules.insert(
-                resource.clone(),
-                ResourceSchedule::new(Some(unavailable_periods), resource.clone()),
-            );
+            resource_schedules.insert(resource.clone(), schedule.with_sub_day(self.config.sub_day));
         }
 
         // Mark fixed tasks as busy in resource schedules
         for fixed_task in fixed_tasks {
+            let category = self.task_categories.get(&fixed_task.task_id);
             for resource_name in &fixed_task.resources {
                 if let Some(schedule) = resource_schedules.get_mut(resource_name) {
-                    schedule.add_busy_period(fixed_task.start_date, fixed_task.end_date);
+                    occupy_fixed(schedule, fixed_task, category);
+                }
+            }
+        }
+
+        let graph = ForwardGraph::new(
+            &self.tasks,
+            &self.completed_task_ids,
+            fixed_tasks,
+            &self.global_dns_periods,
+        );
+        let unscheduled: FxHashSet<TaskIdInt> = graph
+            .schedulable_ids()
+            .filter(|&id| graph.times[id as usize].is_none())
+            .collect();
+
+        let mut resource_names: Vec<&String> = all_resources.iter().collect();
+        resource_names.sort();
+        let resource_ids = ResourceIdInterner::new(resource_names.into_iter().cloned());
+        let mut events = EventQueue::default();
+        graph.seed_events(&mut events);
+        for (resource, schedule) in &resource_schedules {
+            let Some(res_id) = resource_ids.get(resource) else {
+                continue;
+            };
+            for (_, busy_end) in schedule.busy_periods() {
+                if let Some(release) = busy_end.checked_add_days(Days::new(1)) {
+                    events.push(release, EventSource::Resource(res_id));
                 }
             }
         }
+        for fixed_task in fixed_tasks {
+            self.push_changeovers(&mut events, &resource_ids, fixed_task);
+        }
+
+        let mut exclusions = self.exclusions.clone();
+        for fixed_task in fixed_tasks {
+            exclusions.occupy(fixed_task);
+        }
+        let reservations = self
+            .start_from
+            .iter()
+            .flat_map(|s| &s.reservations)
+            .filter(|held| {
+                graph
+                    .shape
+                    .ids
+                    .get(&held.task_id)
+                    .is_some_and(|id| unscheduled.contains(&id))
+            })
+            .map(|held| (held.resource.clone(), held.clone()))
+            .collect();
+
+        SchedulerState {
+            graph,
+            unscheduled,
+            resource_schedules,
+            resource_ids: Rc::new(resource_ids),
+            events,
+            exclusions,
+            reservations,
+            current_time: self
+                .start_from
+                .as_ref()
+                .map_or(self.current_date, |s| s.current_time.max(self.current_date)),
+            result: Vec::new(),
+        }
+    }
 
-        let mut current_time = self.current_date;
-        let max_iterations = self.tasks.len() * 100;
+    /// Place tasks day by day until every task is placed, time runs out or
+    /// the run pauses.
+    fn forward_loop(
+        &self,
+        record: &mut RunRecord,
+        fixed_tasks: &[ScheduledTask],
+    ) -> Result<ForwardOutcome, SchedulerError> {
+        let mut state = self.initial_state(fixed_tasks);
+        let shape = Rc::clone(&state.graph.shape);
+        let mut advances =
+            AdvanceLimit::new(self.config.max_iterations.unwrap_or(self.tasks.len() * 100));
         let verbosity = self.config.verbosity;
+        let mut step = self.stepping.then(|| ScheduleStep::new(state.current_time));
+        self.push_reservation_releases(&mut state, None);
 
-        for _iteration in 0..max_iterations {
-            if unscheduled.is_empty() {
+        for iteration in 0.. {
+            let current_time = state.current_time;
+            if state.unscheduled.is_empty() || self.pause_at.is_some_and(|p| current_time > p) {
                 break;
             }
+            record.iterations += 1;
+
+            if let Some(progress) = &self.run_progress {
+                progress.maybe_report(
+                    iteration,
+                    state.result.len(),
+                    state.unscheduled.len(),
+                    current_time,
+                );
+            }
+
+            if self.stop_condition.should_stop() {
+                log_changes!(verbosity, "Scheduling cancelled at {}", current_time);
+                return Err(SchedulerError::Cancelled(state.result));
+            }
 
             // Log current time
             log_changes!(verbosity, "Time: {}", current_time);
 
             // Find eligible tasks at current_time
-            let eligible = self.find_eligible_tasks(&scheduled, &unscheduled, current_time);
+            let eligible = state.graph.eligible(current_time);
 
             // Compute sorting parameters for this time step
-            let default_cr = self.compute_default_cr(&unscheduled, current_time);
-            let atc_params = self.compute_atc_params(&unscheduled, current_time);
+            let unscheduled_names = state.unscheduled.iter().map(|&id| shape.name(id));
+            let default_cr = self.compute_default_cr(unscheduled_names.clone(), current_time);
+            let atc_params = self.compute_atc_params(unscheduled_names, current_time);
 
             // Sort eligible tasks by strategy
-            let sorted_eligible =
-                self.sort_eligible_tasks(&eligible, current_time, default_cr, atc_params.as_ref())?;
+            let sorted_eligible = self.sort_eligible_ids(
+                &shape,
+                &eligible,
+                current_time,
+                default_cr,
+                atc_params.as_ref(),
+            )?;
 
             log_debug!(
                 verbosity,
@@ -433,21 +1917,31 @@ impl ParallelScheduler {
                 default_cr
             );
 
+            if let Some(step) = &mut step {
+                step.eligible_task_ids = sorted_eligible
+                    .iter()
+                    .map(|&id| shape.name(id).to_string())
+                    .collect();
+            }
+
             // Try to schedule each eligible task
             let mut scheduled_any = false;
-            for task_id in sorted_eligible {
-                let task = match self.tasks.get(&task_id) {
-                    Some(t) => t.clone(),
-                    None => continue,
+            for id in sorted_eligible {
+                if step.is_some() && scheduled_any {
+                    break;
+                }
+                let task_id = shape.name(id);
+                let Some(task) = self.tasks.get(task_id) else {
+                    continue;
                 };
 
                 // Get priority and CR for logging
                 let priority = self
                     .computed_priorities
-                    .get(&task_id)
+                    .get(task_id)
                     .copied()
                     .unwrap_or(self.config.default_priority);
-                let deadline = self.computed_deadlines.get(&task_id);
+                let deadline = self.computed_deadlines.get(task_id);
                 let cr_str = if let Some(dl) = deadline {
                     if *dl != NaiveDate::MAX {
                         let slack = (*dl - current_time).num_days() as f64;
@@ -467,114 +1961,63 @@ impl ParallelScheduler {
                     cr_str
                 );
 
-                // Zero-duration tasks (milestones)
-                if task.duration_days == 0.0 {
-                    scheduled.insert(task_id.clone(), (current_time, current_time));
-                    unscheduled.remove(&task_id);
-                    scheduled_any = true;
-                    log_changes!(
-                        verbosity,
-                        "  Scheduled milestone {} at {}",
-                        task_id,
-                        current_time
-                    );
-                    result.push(ScheduledTask {
-                        task_id,
-                        start_date: current_time,
-                        end_date: current_time,
-                        duration_days: 0.0,
-                        resources: vec![],
-                    });
-                    continue;
-                }
-
-                // Auto-assignment mode
-                if task.resource_spec.is_some() && self.resource_config.is_some() {
-                    let schedule_result = self.try_schedule_auto_assignment(
-                        &task_id,
-                        &task,
-                        current_time,
-                        &mut resource_schedules,
-                        &scheduled,
-                        &unscheduled,
-                    );
-
-                    if let Some((resource, end_date)) = schedule_result {
-                        scheduled.insert(task_id.clone(), (current_time, end_date));
-                        unscheduled.remove(&task_id);
-                        scheduled_any = true;
-                        log_changes!(
+                let placed = match self.try_place(Some(record), id, task, &mut state) {
+                    Ok(placed) => placed,
+                    Err(Skipped::ExclusiveGroup(free)) => {
+                        log_checks!(
                             verbosity,
-                            "  Scheduled task {} on {} from {} to {}",
+                            "    Skipping {}: exclusive group busy until {}",
                             task_id,
-                            resource,
-                            current_time,
-                            end_date
+                            free
                         );
-                        result.push(ScheduledTask {
-                            task_id,
-                            start_date: current_time,
-                            end_date,
-                            duration_days: task.duration_days,
-                            resources: vec![resource],
-                        });
-                    } else {
+                        continue;
+                    }
+                    Err(Skipped::Resources) => {
                         log_checks!(
                             verbosity,
-                            "    Skipping {}: No resource available now",
+                            "    Skipping {}: Resources not available now",
                             task_id
                         );
+                        continue;
                     }
+                };
+
+                scheduled_any = true;
+                let resources = placed.resources.join(", ");
+                if placed.resources.is_empty() {
+                    log_changes!(
+                        verbosity,
+                        "  Scheduled milestone {} at {}",
+                        task_id,
+                        current_time
+                    );
                 } else {
-                    // Explicit resource assignment
-                    let schedule_result = self.try_schedule_explicit_resources(
-                        &task_id,
-                        &task,
+                    log_changes!(
+                        verbosity,
+                        "  Scheduled task {} on {} from {} to {}",
+                        task_id,
+                        resources,
                         current_time,
-                        &mut resource_schedules,
-                        &scheduled,
-                        &unscheduled,
+                        placed.end_date
                     );
-
-                    if let Some(end_date) = schedule_result {
-                        let resources: Vec<String> =
-                            task.resources.iter().map(|(r, _)| r.clone()).collect();
-                        scheduled.insert(task_id.clone(), (current_time, end_date));
-                        unscheduled.remove(&task_id);
-                        scheduled_any = true;
-                        log_changes!(
-                            verbosity,
-                            "  Scheduled task {} on {} from {} to {}",
-                            task_id,
-                            resources.join(", "),
-                            current_time,
-                            end_date
-                        );
-                        result.push(ScheduledTask {
-                            task_id,
-                            start_date: current_time,
-                            end_date,
-                            duration_days: task.duration_days,
-                            resources,
-                        });
-                    } else {
-                        log_checks!(
-                            verbosity,
-                            "    Skipping {}: Resources not available now",
-                            task_id
-                        );
-                    }
                 }
+                trace_placement!(task_id, resources, current_time, placed.end_date);
+                let fulfilled = self.commit_placement(&mut state, id, placed);
+                record.log_released(fulfilled, current_time, "fulfilled");
+            }
+
+            if let Some(step) = step.as_mut().filter(|_| scheduled_any) {
+                step.kind = "placed".to_string();
+                step.time = current_time;
+                step.placed = state.result.last().cloned();
+                break;
             }
 
             // Advance time if nothing scheduled
             if !scheduled_any {
-                match self.find_next_event_time(
-                    &scheduled,
-                    &unscheduled,
-                    &resource_schedules,
-                    current_time,
-                ) {
+                self.push_reservation_releases(&mut state, Some(current_time));
+                match self.next_event_time(&mut state) {
+                    Some(next_time) if self.pause_at.is_some_and(|p| next_time > p) => break,
                     Some(next_time) => {
                         log_debug!(
                             verbosity,
@@ -582,7 +2025,19 @@ impl ParallelScheduler {
                             current_time,
                             next_time
                         );
-                        current_time = next_time;
+                        let expired = state.advance_to(next_time);
+                        record.log_released(expired, next_time, "expired");
+                        if advances.record(next_time) {
+                            return Err(SchedulerError::IterationLimit(
+                                advances
+                                    .exhausted(next_time, state.graph.names(&state.unscheduled)),
+                            ));
+                        }
+                        if let Some(step) = &mut step {
+                            step.kind = "advanced".to_string();
+                            step.time = next_time;
+                            break;
+                        }
                     }
                     None => {
                         log_debug!(verbosity, "  No more events, stopping");
@@ -592,129 +2047,134 @@ impl ParallelScheduler {
             }
         }
 
-        if !unscheduled.is_empty() {
+        let mut held = state.release_reservations(|_| true);
+        held.sort_by(|a, b| a.resource.cmp(&b.resource));
+        record.log_released(held.clone(), state.current_time, "open");
+        if let Some(step) = &mut step {
+            step.snapshot.current_time = state.current_time;
+            step.snapshot.reservations = held.clone();
+        } else if !state.unscheduled.is_empty()
+            && self.pause_at.is_none()
+            && self.config.on_unschedulable == UnschedulablePolicy::Error
+        {
             return Err(SchedulerError::FailedToSchedule(
-                unscheduled.into_iter().collect(),
+                state.graph.names(&state.unscheduled),
             ));
         }
 
-        Ok(result)
+        Ok(ForwardOutcome {
+            placed: state.result,
+            held,
+            step,
+        })
     }
 
-    /// Find tasks eligible at current time.
-    fn find_eligible_tasks(
+    /// Record a placement on `state`: the task's dependents, the resources'
+    /// next releases, its exclusive groups and any changeover it starts.
+    ///
+    /// Returns the reservations the placement fulfilled.
+    fn commit_placement(
         &self,
-        scheduled: &FxHashMap<String, (NaiveDate, NaiveDate)>,
-        unscheduled: &FxHashSet<String>,
-        current_time: NaiveDate,
-    ) -> Vec<String> {
-        let mut eligible = Vec::new();
+        state: &mut SchedulerState,
+        id: TaskIdInt,
+        placed: ScheduledTask,
+    ) -> Vec<HeldReservation> {
+        let now = state.current_time;
+        let fulfilled = state.release_reservations(|r| r.task_id == placed.task_id);
+        state.exclusions.occupy(&placed);
+        state
+            .graph
+            .mark_scheduled(id, placed.start_date, placed.end_date, &mut state.events);
+        for resource in &placed.resources {
+            push_release(
+                &mut state.events,
+                &state.resource_ids,
+                &state.resource_schedules,
+                resource,
+                now,
+            );
+        }
+        state.unscheduled.remove(&id);
+        self.record_changeover(&mut state.resource_schedules, &placed);
+        self.push_changeovers(&mut state.events, &state.resource_ids, &placed);
+        state.result.push(placed);
+        fulfilled
+    }
 
-        for task_id in unscheduled {
-            let task = match self.tasks.get(task_id) {
-                Some(t) => t,
-                None => continue,
-            };
-
-            // Check dependencies (with lag)
-            let all_deps_complete = task.dependencies.iter().all(|dep| {
-                if self.completed_task_ids.contains(&dep.entity_id) {
-                    return true;
-                }
-                if let Some((_, dep_end)) = scheduled.get(&dep.entity_id) {
-                    let lag_days = dep.lag_days.ceil() as u64;
-                    let eligible_after = dep_end
-                        .checked_add_days(Days::new(lag_days))
-                        .unwrap_or(*dep_end);
-                    eligible_after < current_time
-                } else {
-                    false
-                }
-            });
-
-            if !all_deps_complete {
-                continue;
-            }
-
-            // Calculate earliest possible start
-            let mut earliest = current_time;
-            for dep in &task.dependencies {
-                if self.completed_task_ids.contains(&dep.entity_id) {
-                    continue;
-                }
-                if let Some((_, dep_end)) = scheduled.get(&dep.entity_id) {
-                    let lag_days = 1 + dep.lag_days.ceil() as u64;
-                    let dep_eligible = dep_end
-                        .checked_add_days(Days::new(lag_days))
-                        .unwrap_or(*dep_end);
-                    if dep_eligible > earliest {
-                        earliest = dep_eligible;
-                    }
-                }
-            }
-
-            // Check start_after constraint
-            if let Some(start_after) = task.start_after {
-                if start_after > earliest {
-                    earliest = start_after;
-                }
-            }
-
-            // Task is eligible if it can start by current_time
-            if earliest <= current_time {
-                eligible.push(task_id.clone());
+    /// Earliest event after the current time that can still change what is
+    /// eligible or free.
+    fn next_event_time(&self, state: &mut SchedulerState) -> Option<NaiveDate> {
+        let SchedulerState {
+            unscheduled,
+            resource_schedules,
+            resource_ids,
+            events,
+            reservations,
+            current_time,
+            ..
+        } = state;
+        events.next_after(*current_time, |date, source| match source {
+            EventSource::Task(id) => unscheduled.contains(&id),
+            EventSource::Resource(res_id) => {
+                resource_ids
+                    .resolve(res_id)
+                    .and_then(|name| resource_schedules.get(name))
+                    .and_then(|schedule| schedule.next_release_after(*current_time))
+                    == Some(date)
             }
-        }
-
-        eligible
+            EventSource::Reservation(res_id) => resource_ids
+                .resolve(res_id)
+                .and_then(|name| reservations.get(name))
+                .is_some_and(|r| r.release_on == date),
+            EventSource::Changeover(_) => true,
+        })
     }
 
-    /// Sort eligible tasks by the configured strategy.
-    fn sort_eligible_tasks(
+    /// Sort interned eligible tasks by the configured strategy.
+    fn sort_eligible_ids(
         &self,
-        eligible: &[String],
+        shape: &GraphShape,
+        eligible: &[TaskIdInt],
         current_time: NaiveDate,
         default_cr: f64,
         atc_params: Option<&AtcParams>,
-    ) -> Result<Vec<String>, SchedulerError> {
-        if eligible.is_empty() {
-            return Ok(Vec::new());
-        }
-
-        // Build task info map
-        let mut task_infos: FxHashMap<String, TaskSortInfo> = FxHashMap::default();
-        for task_id in eligible {
-            if let Some(task) = self.tasks.get(task_id) {
-                let deadline = self.computed_deadlines.get(task_id).copied();
-                let priority = self
+    ) -> Result<Vec<TaskIdInt>, SchedulerError> {
+        let mut keyed: Vec<(SortKey, TaskIdInt)> = Vec::with_capacity(eligible.len());
+        for &id in eligible {
+            let task_id = shape.name(id);
+            let Some(task) = self.tasks.get(task_id) else {
+                continue;
+            };
+            let info = TaskSortInfo {
+                duration_days: task.duration_days,
+                deadline: self.computed_deadlines.get(task_id).copied(),
+                priority: self
                     .computed_priorities
                     .get(task_id)
                     .copied()
-                    .unwrap_or(self.config.default_priority);
-                task_infos.insert(
-                    task_id.clone(),
-                    TaskSortInfo {
-                        duration_days: task.duration_days,
-                        deadline,
-                        priority,
-                    },
-                );
-            }
+                    .unwrap_or(self.config.default_priority),
+                rank: task.rank,
+            };
+            let key = compute_sort_key(
+                task_id,
+                &info,
+                current_time,
+                default_cr,
+                &self.config,
+                atc_params,
+            )?;
+            keyed.push((key, id));
         }
-
-        let task_ids: Vec<String> = eligible.to_vec();
-        Ok(sort_tasks(
-            &task_ids,
-            &task_infos,
-            current_time,
-            default_cr,
-            &self.config,
-            atc_params,
-        )?)
+        keyed.sort_by(|a, b| a.0.cmp(&b.0));
+        Ok(keyed.into_iter().map(|(_, id)| id).collect())
     }
 
     /// Compute default CR for tasks without deadlines.
-    fn compute_default_cr(&self, unscheduled: &FxHashSet<String>, current_time: NaiveDate) -> f64 {
+    fn compute_default_cr<'a>(
+        &self,
+        unscheduled: impl Iterator<Item = &'a str>,
+        current_time: NaiveDate,
+    ) -> f64 {
         let mut max_cr = 0.0;
 
         for task_id in unscheduled {
@@ -736,18 +2196,17 @@ impl ParallelScheduler {
 
         (max_cr * self.config.default_cr_multiplier).max(self.config.default_cr_floor)
     }
-
     /// Compute ATC parameters if using ATC strategy.
-    fn compute_atc_params(
+    fn compute_atc_params<'a>(
         &self,
-        unscheduled: &FxHashSet<String>,
+        unscheduled: impl Iterator<Item = &'a str> + Clone,
         current_time: NaiveDate,
     ) -> Option<AtcParams> {
         if self.config.strategy != "atc" {
             return None;
         }
 
-        let avg_duration = self.compute_avg_duration(unscheduled);
+        let avg_duration = self.compute_avg_duration(unscheduled.clone());
         let default_urgency = self.compute_default_urgency(unscheduled, current_time, avg_duration);
 
         Some(AtcParams {
@@ -755,22 +2214,21 @@ impl ParallelScheduler {
             default_urgency,
         })
     }
-
-    fn compute_avg_duration(&self, unscheduled: &FxHashSet<String>) -> f64 {
-        if unscheduled.is_empty() {
+    fn compute_avg_duration<'a>(&self, unscheduled: impl Iterator<Item = &'a str>) -> f64 {
+        let mut count = 0;
+        let mut total = 0.0;
+        for task_id in unscheduled {
+            count += 1;
+            total += self.tasks.get(task_id).map_or(0.0, |t| t.duration_days);
+        }
+        if count == 0 {
             return 1.0;
         }
-        let total: f64 = unscheduled
-            .iter()
-            .filter_map(|tid| self.tasks.get(tid))
-            .map(|t| t.duration_days)
-            .sum();
-        total / unscheduled.len() as f64
+        total / count as f64
     }
-
-    fn compute_default_urgency(
+    fn compute_default_urgency<'a>(
         &self,
-        unscheduled: &FxHashSet<String>,
+        unscheduled: impl Iterator<Item = &'a str>,
         current_time: NaiveDate,
         avg_duration: f64,
     ) -> f64 {
@@ -806,197 +2264,320 @@ impl ParallelScheduler {
         (min_urgency * self.config.atc_default_urgency_multiplier)
             .max(self.config.atc_default_urgency_floor)
     }
-
-    /// Try to schedule a task with auto-assignment.
-    fn try_schedule_auto_assignment(
-        &mut self,
-        task_id: &str,
+    /// Place `task` at the current time, or say why it has to wait.
+    ///
+    /// The main pass and rollout simulations both place through here, so
+    /// simulations see the same exclusive groups, reservations and resource
+    /// choices. Rollout only runs when `record` is given.
+    fn try_place(
+        &self,
+        record: Option<&mut RunRecord>,
+        id: TaskIdInt,
         task: &Task,
-        current_time: NaiveDate,
-        resource_schedules: &mut FxHashMap<String, ResourceSchedule>,
-        scheduled: &FxHashMap<String, (NaiveDate, NaiveDate)>,
-        unscheduled: &FxHashSet<String>,
-    ) -> Option<(String, NaiveDate)> {
+        state: &mut SchedulerState,
+    ) -> Result<ScheduledTask, Skipped> {
+        let current_time = state.current_time;
+
+        // Zero-duration tasks (milestones)
+        if task.duration_days == 0.0 {
+            return Ok(ScheduledTask {
+                task_id: task.id.clone(),
+                start_date: current_time,
+                end_date: current_time,
+                duration_days: 0.0,
+                resources: vec![],
+                ..Default::default()
+            });
+        }
+
+        if let Some(free) = state.exclusions.blocked_until(&task.id, current_time) {
+            state.events.push(free, EventSource::Task(id));
+            return Err(Skipped::ExclusiveGroup(free));
+        }
+
+        let placed = if task.resource_spec.is_some() && self.resource_config.is_some() {
+            self.best_assignment(task, state)
+        } else {
+            self.explicit_placement(task, state)
+        }
+        .ok_or(Skipped::Resources)?;
+
+        // Check if rollout should override this decision
+        if let Some(record) = record {
+            if let Some(competitor) = self.check_rollout_skip(record, id, placed.end_date, state) {
+                self.reserve_for(record, state, &competitor, &placed.resources);
+                return Err(Skipped::Resources);
+            }
+        }
+
+        for resource_name in &placed.resources {
+            if let Some(schedule) = state.resource_schedules.get_mut(resource_name) {
+                schedule.reserve(current_time, (placed.end_date, placed.end_offset));
+            }
+        }
+        Ok(placed)
+    }
+
+    /// Best auto-assigned resource for `task`, if it is free now.
+    fn best_assignment(&self, task: &Task, state: &mut SchedulerState) -> Option<ScheduledTask> {
         let resource_config = self.resource_config.as_ref()?;
-        let spec = task.resource_spec.as_ref()?;
+        task.resource_spec.as_ref()?;
+        let current_time = state.current_time;
 
-        // Find best resource (earliest completion)
-        let candidates = resource_config.expand_resource_spec(spec);
+        // Find best resource (earliest completion, including switch and preference penalties)
+        let candidates = resource_config.candidates_for(task);
         let mut best_resource: Option<String> = None;
         let mut best_start: Option<NaiveDate> = None;
-        let mut best_completion: Option<NaiveDate> = None;
+        let mut best_completion: Option<(NaiveDate, f64)> = None;
+        let mut best_score = f64::INFINITY;
+        let mut best_rate = f64::INFINITY;
 
         for resource_name in candidates {
-            if let Some(schedule) = resource_schedules.get_mut(&resource_name) {
-                let available_at = schedule.next_available_time(current_time);
-                let completion =
-                    schedule.calculate_completion_time(available_at, task.duration_days);
-
-                if best_completion.is_none() || completion < best_completion.unwrap() {
+            if reserved_for_other(&state.reservations, &resource_name, &task.id) {
+                continue;
+            }
+            if let Some(schedule) = state.resource_schedules.get_mut(&resource_name) {
+                let free_at = schedule.next_available_time(current_time);
+                let available_at = schedule.next_available_time(resource_config.changeover_ready(
+                    schedule,
+                    task.category.as_deref(),
+                    free_at,
+                ));
+                let duration = resource_config.effective_duration(&resource_name, task);
+                let completion = schedule.completion_point_avoiding(
+                    available_at,
+                    0.0,
+                    duration,
+                    &task.blocked_periods,
+                );
+                let rate = resource_config.cost_rate(&resource_name);
+                let score = (completion.0 - current_time).num_days() as f64
+                    + completion.1
+                    + resource_config.assignment_penalty(task, &resource_name);
+
+                // Earliest completion wins; cheaper resource breaks ties
+                let better = best_completion.is_none()
+                    || score < best_score
+                    || (score == best_score && rate < best_rate);
+                if better {
                     best_resource = Some(resource_name);
                     best_start = Some(available_at);
                     best_completion = Some(completion);
+                    best_score = score;
+                    best_rate = rate;
                 }
             }
         }
 
         let best_resource = best_resource?;
         let best_start = best_start?;
-        let best_completion = best_completion?;
+        let (best_completion, end_offset) = best_completion?;
 
         // Greedy with foresight: only schedule if best resource is available NOW
         if best_start != current_time {
             return None;
         }
 
-        // Check if rollout should override this decision
-        if self.rollout_config.is_some() {
-            if let Some(skip) = self.check_rollout_skip(
-                task_id,
-                best_completion,
-                current_time,
-                scheduled,
-                unscheduled,
-                resource_schedules,
-            ) {
-                if skip {
-                    return None;
-                }
-            }
-        }
-
-        // Schedule the task
-        if let Some(schedule) = resource_schedules.get_mut(&best_resource) {
-            schedule.add_busy_period(current_time, best_completion);
-        }
-
-        Some((best_resource, best_completion))
+        let start_offset = state
+            .resource_schedules
+            .get(&best_resource)
+            .map_or(0.0, |schedule| schedule.start_offset(current_time));
+
+        Some(ScheduledTask {
+            task_id: task.id.clone(),
+            start_date: current_time,
+            end_date: best_completion,
+            duration_days: resource_config.effective_duration(&best_resource, task),
+            resources: vec![best_resource],
+            start_offset,
+            end_offset,
+            ..Default::default()
+        })
     }
 
-    /// Try to schedule a task with explicit resources.
-    fn try_schedule_explicit_resources(
-        &mut self,
-        task_id: &str,
-        task: &Task,
-        current_time: NaiveDate,
-        resource_schedules: &mut FxHashMap<String, ResourceSchedule>,
-        scheduled: &FxHashMap<String, (NaiveDate, NaiveDate)>,
-        unscheduled: &FxHashSet<String>,
-    ) -> Option<NaiveDate> {
-        if task.resources.is_empty() {
-            return None;
+    /// Whether `task_id` could be placed on `resource`.
+    fn can_use(&self, task_id: &str, resource: &str) -> bool {
+        let Some(task) = self.tasks.get(task_id) else {
+            return false;
+        };
+        match (&task.resource_spec, &self.resource_config) {
+            (Some(_), Some(rc)) => rc.candidates_for(task).iter().any(|c| c == resource),
+            _ => task.resources.iter().any(|(r, _)| r == resource),
         }
+    }
 
-        // Check if all resources are available to START now
-        for (resource_name, _) in &task.resources {
-            let schedule = resource_schedules.get(resource_name)?;
-            let next_avail = schedule.next_available_time(current_time);
-            if next_avail != current_time {
-                return None;
+    /// Hold the `resources` `competitor` can use for it after a rollout skip.
+    fn reserve_for(
+        &self,
+        record: &mut RunRecord,
+        state: &mut SchedulerState,
+        competitor: &str,
+        resources: &[String],
+    ) {
+        let Some(rollout_config) = &self.rollout_config else {
+            return;
+        };
+        let now = state.current_time;
+        let release_on = now
+            .checked_add_days(Days::new(
+                u64::from(rollout_config.reservation_max_idle_days) + 1,
+            ))
+            .unwrap_or(NaiveDate::MAX);
+        let target_score = f64::from(
+            self.computed_priorities
+                .get(competitor)
+                .copied()
+                .unwrap_or(self.config.default_priority),
+        );
+        for resource in resources {
+            let already_held = state
+                .reservations
+                .get(resource)
+                .is_some_and(|r| r.task_id == competitor);
+            if already_held || !self.can_use(competitor, resource) {
+                continue;
             }
-        }
-
-        // Calculate DNS-aware completion time (max across all resources)
-        let mut max_completion = current_time;
-        for (resource_name, _) in &task.resources {
-            if let Some(schedule) = resource_schedules.get_mut(resource_name) {
-                let completion =
-                    schedule.calculate_completion_time(current_time, task.duration_days);
-                if completion > max_completion {
-                    max_completion = completion;
-                }
+            let held = HeldReservation {
+                resource: resource.clone(),
+                target_id: competitor.to_string(),
+                task_id: competitor.to_string(),
+                target_score,
+                reserved_from: now,
+                release_on,
+            };
+            if let Some(previous) = state.reservations.insert(resource.clone(), held) {
+                record
+                    .reservation_log
+                    .push(ReservationRecord::from_held(&previous, now, "replaced"));
             }
         }
+    }
 
-        // Check if rollout should override this decision
-        if self.rollout_config.is_some() {
-            if let Some(skip) = self.check_rollout_skip(
-                task_id,
-                max_completion,
-                current_time,
-                scheduled,
-                unscheduled,
-                resource_schedules,
-            ) {
-                if skip {
-                    return None;
-                }
+    /// Queue wake-ups for reservation releases (only those made on `made_on`, if given).
+    fn push_reservation_releases(&self, state: &mut SchedulerState, made_on: Option<NaiveDate>) {
+        if self
+            .rollout_config
+            .as_ref()
+            .is_none_or(|rc| rc.reservation_max_idle_days == 0)
+        {
+            return;
+        }
+        for (resource, r) in &state.reservations {
+            if made_on.is_some_and(|d| d != r.reserved_from) {
+                continue;
+            }
+            if let Some(res_id) = state.resource_ids.get(resource) {
+                state
+                    .events
+                    .push(r.release_on, EventSource::Reservation(res_id));
             }
         }
+    }
 
-        // Update resource schedules
-        for (resource_name, _) in &task.resources {
-            if let Some(schedule) = resource_schedules.get_mut(resource_name) {
-                schedule.add_busy_period(current_time, max_completion);
+    /// Whether any changeover `task` needs on `schedule` is over by `time`.
+    fn changed_over(&self, schedule: &ResourceSchedule, task: &Task, time: NaiveDate) -> bool {
+        self.resource_config
+            .as_ref()
+            .is_none_or(|rc| rc.changeover_ready(schedule, task.category.as_deref(), time) == time)
+    }
+    /// Record a placement's category on the resources it uses.
+    fn record_changeover(
+        &self,
+        resource_schedules: &mut FxHashMap<String, ResourceSchedule>,
+        placed: &ScheduledTask,
+    ) {
+        let Some(category) = self.task_categories.get(&placed.task_id) else {
+            return;
+        };
+        for resource in &placed.resources {
+            if let Some(schedule) = resource_schedules.get_mut(resource) {
+                schedule.record_category(placed.end_date, category);
             }
         }
-
-        Some(max_completion)
     }
-
-    /// Find the next event time to advance to.
-    fn find_next_event_time(
+    /// Queue wake-ups for when changeovers after a placement complete.
+    fn push_changeovers(
         &self,
-        scheduled: &FxHashMap<String, (NaiveDate, NaiveDate)>,
-        unscheduled: &FxHashSet<String>,
-        resource_schedules: &FxHashMap<String, ResourceSchedule>,
-        current_time: NaiveDate,
-    ) -> Option<NaiveDate> {
-        let mut next_events: Vec<NaiveDate> = Vec::new();
-
-        // Task completions (with lag)
-        for task_id in unscheduled {
-            if let Some(task) = self.tasks.get(task_id) {
-                for dep in &task.dependencies {
-                    if let Some((_, dep_end)) = scheduled.get(&dep.entity_id) {
-                        let lag_days = 1 + dep.lag_days.ceil() as u64;
-                        let eligible_date = dep_end
-                            .checked_add_days(Days::new(lag_days))
-                            .unwrap_or(*dep_end);
-                        if eligible_date > current_time {
-                            next_events.push(eligible_date);
-                        }
-                    }
+        events: &mut EventQueue,
+        resource_ids: &ResourceIdInterner,
+        placed: &ScheduledTask,
+    ) {
+        let (Some(rc), Some(category)) = (
+            &self.resource_config,
+            self.task_categories.get(&placed.task_id),
+        ) else {
+            return;
+        };
+        for resource in &placed.resources {
+            if let Some(res_id) = resource_ids.get(resource) {
+                for release in rc.changeover_releases(category, placed.end_date) {
+                    events.push(release, EventSource::Changeover(res_id));
                 }
             }
         }
+    }
+    /// Placement of `task` on its explicit resources, if all are free now.
+    fn explicit_placement(&self, task: &Task, state: &mut SchedulerState) -> Option<ScheduledTask> {
+        if task.resources.is_empty() {
+            return None;
+        }
+        let current_time = state.current_time;
 
-        // Start constraints
-        for task_id in unscheduled {
-            if let Some(task) = self.tasks.get(task_id) {
-                if let Some(start_after) = task.start_after {
-                    if start_after > current_time {
-                        next_events.push(start_after);
-                    }
-                }
+        // Check if all resources are available to START now
+        let mut start_offset: f64 = 0.0;
+        for (resource_name, _) in &task.resources {
+            let schedule = state.resource_schedules.get(resource_name)?;
+            let next_avail = schedule.next_available_time(current_time);
+            if next_avail != current_time
+                || !self.changed_over(schedule, task, current_time)
+                || reserved_for_other(&state.reservations, resource_name, &task.id)
+            {
+                return None;
             }
+            start_offset = start_offset.max(schedule.start_offset(current_time));
         }
 
-        // Resource busy period ends
-        for schedule in resource_schedules.values() {
-            for (_, busy_end) in &schedule.busy_periods {
-                if *busy_end >= current_time {
-                    if let Some(next_day) = busy_end.checked_add_days(Days::new(1)) {
-                        next_events.push(next_day);
-                    }
+        // Calculate DNS-aware completion time (max across all resources)
+        let mut max_completion = (current_time, 0.0);
+        for (resource_name, _) in &task.resources {
+            if let Some(schedule) = state.resource_schedules.get_mut(resource_name) {
+                let completion = schedule.completion_point_avoiding(
+                    current_time,
+                    start_offset,
+                    task.duration_days,
+                    &task.blocked_periods,
+                );
+                if completion > max_completion {
+                    max_completion = completion;
                 }
             }
         }
+        let (end_date, end_offset) = max_completion;
 
-        next_events.into_iter().min()
+        Some(ScheduledTask {
+            task_id: task.id.clone(),
+            start_date: current_time,
+            end_date,
+            duration_days: task.duration_days,
+            resources: task.resources.iter().map(|(r, _)| r.clone()).collect(),
+            start_offset,
+            end_offset,
+            ..Default::default()
+        })
     }
 
-    /// Check if rollout suggests skipping this task.
+    /// Check if rollout suggests skipping this task; returns the task to wait for.
     fn check_rollout_skip(
-        &mut self,
-        task_id: &str,
+        &self,
+        record: &mut RunRecord,
+        id: TaskIdInt,
         completion_date: NaiveDate,
-        current_time: NaiveDate,
-        scheduled: &FxHashMap<String, (NaiveDate, NaiveDate)>,
-        unscheduled: &FxHashSet<String>,
-        resource_schedules: &FxHashMap<String, ResourceSchedule>,
-    ) -> Option<bool> {
+        state: &SchedulerState,
+    ) -> Option<String> {
         let rollout_config = self.rollout_config.as_ref()?;
+        let task_id = state.graph.name(id);
+        let _span = trace_span!(DEBUG, "rollout", task_id);
+        let current_time = state.current_time;
 
         let task_priority = self
             .computed_priorities
@@ -1010,69 +2591,51 @@ impl ParallelScheduler {
         let is_relaxed_cr = task_cr > rollout_config.cr_relaxed_threshold;
 
         if !is_low_priority && !is_relaxed_cr {
-            return Some(false);
+            return None;
         }
 
         // Zero-duration tasks don't warrant rollout
         if let Some(task) = self.tasks.get(task_id) {
             if task.duration_days == 0.0 {
-                return Some(false);
+                return None;
             }
         }
 
         // Find more urgent tasks becoming eligible before completion
-        let upcoming = self.find_upcoming_urgent_tasks(
-            task_id,
-            current_time,
-            completion_date,
-            scheduled,
-            unscheduled,
-        );
+        let upcoming = self.find_upcoming_urgent_tasks(id, completion_date, state);
 
-        if upcoming.is_empty() {
-            return Some(false);
-        }
+        let (competing_id, competing_priority, competing_cr, competing_date) =
+            upcoming.into_iter().next()?;
 
         let verbosity = self.config.verbosity;
 
         // Log rollout trigger
-        if let Some((competing_id, competing_priority, competing_cr, competing_date)) =
-            upcoming.first()
-        {
-            log_checks!(
-                verbosity,
-                "    Rollout triggered: {} (pri={}, CR={:.2}) vs {} (pri={}, CR={:.2}, eligible={})",
-                task_id,
-                task_priority,
-                task_cr,
-                competing_id,
-                competing_priority,
-                competing_cr,
-                competing_date
-            );
-        }
-
-        // Run rollout simulation to decide
-        let state = SchedulerState::new(
-            scheduled.clone(),
-            unscheduled.clone(),
-            resource_schedules.clone(),
-            current_time,
+        log_checks!(
+            verbosity,
+            "    Rollout triggered: {} (pri={}, CR={:.2}) vs {} (pri={}, CR={:.2}, eligible={})",
+            task_id,
+            task_priority,
+            task_cr,
+            competing_id,
+            competing_priority,
+            competing_cr,
+            competing_date
         );
 
         let horizon = self.cap_rollout_horizon(completion_date, current_time);
 
+        let rollout_started = profiling::start(self.config.profile);
+
         // Scenario A: Schedule the task
-        let schedule_state = state.clone_for_rollout();
-        let (_, schedule_score) = self
-            .run_rollout_simulation(schedule_state, horizon, None)
+        let (schedule_final, schedule_score) = self
+            .run_rollout_simulation(state.clone_for_rollout(), horizon, None)
             .ok()?;
 
         // Scenario B: Skip the task
-        let skip_state = state.clone_for_rollout();
-        let (_, skip_score) = self
-            .run_rollout_simulation(skip_state, horizon, Some(task_id))
+        let (skip_final, skip_score) = self
+            .run_rollout_simulation(state.clone_for_rollout(), horizon, Some(id))
             .ok()?;
+        profiling::stop(rollout_started, &mut record.rollout_ms);
 
         log_checks!(
             verbosity,
@@ -1082,41 +2645,48 @@ impl ParallelScheduler {
         );
 
         // Record decision
-        if let Some((competing_id, competing_priority, competing_cr, competing_date)) =
-            upcoming.first()
-        {
-            let decision = if skip_score < schedule_score {
-                "skip".to_string()
-            } else {
-                "schedule".to_string()
-            };
+        let decision = if skip_score < schedule_score {
+            "skip".to_string()
+        } else {
+            "schedule".to_string()
+        };
 
-            if decision == "skip" {
-                log_changes!(
-                    verbosity,
-                    "  Rollout: skipping {} to wait for {}",
-                    task_id,
-                    competing_id
-                );
-            }
+        if decision == "skip" {
+            log_changes!(
+                verbosity,
+                "  Rollout: skipping {} to wait for {}",
+                task_id,
+                competing_id
+            );
+        }
 
-            self.rollout_decisions.push(RolloutDecision::new(
+        if rollout_config.trace_rollouts {
+            record.last_rollout_trace = Some(RolloutTrace::new(
+                current_time,
                 task_id.to_string(),
-                task_priority,
-                task_cr,
-                competing_id.clone(),
-                *competing_priority,
-                *competing_cr,
-                *competing_date,
+                decision.clone(),
                 schedule_score,
                 skip_score,
-                decision.clone(),
+                schedule_final.result,
+                skip_final.result,
+                Vec::new(),
             ));
-
-            return Some(decision == "skip");
         }
 
-        Some(false)
+        record.rollout_decisions.push(RolloutDecision::new(
+            task_id.to_string(),
+            task_priority,
+            task_cr,
+            competing_id.clone(),
+            competing_priority,
+            competing_cr,
+            competing_date,
+            schedule_score,
+            skip_score,
+            decision.clone(),
+        ));
+
+        (decision == "skip").then_some(competing_id)
     }
 
     fn compute_task_cr(&self, task_id: &str, current_time: NaiveDate) -> f64 {
@@ -1135,31 +2705,31 @@ impl ParallelScheduler {
             _ => self.config.default_cr_floor,
         }
     }
-
     fn cap_rollout_horizon(&self, horizon: NaiveDate, current_time: NaiveDate) -> NaiveDate {
         match self.max_horizon_days {
             Some(max_days) => {
                 let max_horizon = current_time
                     .checked_add_days(Days::new(max_days as u64))
-                    .unwrap_or(horizon);
+                    .unwrap_or(NaiveDate::MAX);
                 horizon.min(max_horizon)
             }
             None => horizon,
         }
     }
-
     fn find_upcoming_urgent_tasks(
         &self,
-        task_id: &str,
-        current_time: NaiveDate,
+        id: TaskIdInt,
         horizon: NaiveDate,
-        scheduled: &FxHashMap<String, (NaiveDate, NaiveDate)>,
-        unscheduled: &FxHashSet<String>,
+        state: &SchedulerState,
     ) -> Vec<(String, i32, f64, NaiveDate)> {
         let rollout_config = match &self.rollout_config {
             Some(rc) => rc,
             None => return Vec::new(),
         };
+        let graph = &state.graph;
+        let shape = &graph.shape;
+        let task_id = shape.name(id);
+        let current_time = state.current_time;
 
         let task_priority = self
             .computed_priorities
@@ -1170,10 +2740,11 @@ impl ParallelScheduler {
 
         let mut upcoming: Vec<(String, i32, f64, NaiveDate)> = Vec::new();
 
-        for other_id in unscheduled {
-            if other_id == task_id {
+        for &other in &state.unscheduled {
+            if other == id {
                 continue;
             }
+            let other_id = shape.name(other);
 
             let other_priority = self
                 .computed_priorities
@@ -1193,23 +2764,15 @@ impl ParallelScheduler {
             }
 
             // Calculate when this task becomes eligible
-            let other_task = match self.tasks.get(other_id) {
-                Some(t) => t,
-                None => continue,
-            };
-
             let mut eligible_date = current_time;
             let mut can_estimate = true;
 
-            for dep in &other_task.dependencies {
-                if self.completed_task_ids.contains(&dep.entity_id) {
+            for &(dep, lag_days, unit) in &shape.deps[other as usize] {
+                if shape.completed[dep as usize] {
                     continue;
                 }
-                if let Some((_, dep_end)) = scheduled.get(&dep.entity_id) {
-                    let lag_days = 1 + dep.lag_days.ceil() as u64;
-                    let dep_eligible = dep_end
-                        .checked_add_days(Days::new(lag_days))
-                        .unwrap_or(*dep_end);
+                if let Some((_, dep_end)) = graph.times[dep as usize] {
+                    let dep_eligible = unit.ready_date(dep_end, lag_days, &shape.lag_calendar);
                     if dep_eligible > eligible_date {
                         eligible_date = dep_eligible;
                     }
@@ -1223,14 +2786,19 @@ impl ParallelScheduler {
                 continue;
             }
 
-            if let Some(start_after) = other_task.start_after {
+            if let Some(start_after) = shape.start_afters[other as usize] {
                 if start_after > eligible_date {
                     eligible_date = start_after;
                 }
             }
 
             if eligible_date < horizon {
-                upcoming.push((other_id.clone(), other_priority, other_cr, eligible_date));
+                upcoming.push((
+                    other_id.to_string(),
+                    other_priority,
+                    other_cr,
+                    eligible_date,
+                ));
             }
         }
 
@@ -1238,42 +2806,33 @@ impl ParallelScheduler {
     }
 
     /// Run rollout simulation from state to horizon.
+    ///
+    /// The simulation advances through the same ready set, event queue and
+    /// placement checks as the main loop, without rollout of its own.
     fn run_rollout_simulation(
         &self,
         mut state: SchedulerState,
         horizon: NaiveDate,
-        skip_task_id: Option<&str>,
+        skip: Option<TaskIdInt>,
     ) -> Result<(SchedulerState, f64), SchedulerError> {
         let max_iterations = self.tasks.len() * 10;
         let initial_time = state.current_time;
+        let shape = Rc::clone(&state.graph.shape);
 
         for _iteration in 0..max_iterations {
             if state.unscheduled.is_empty() || state.current_time > horizon {
                 break;
             }
 
-            // Find eligible tasks
-            let eligible =
-                self.find_eligible_tasks(&state.scheduled, &state.unscheduled, state.current_time);
-
-            if eligible.is_empty() {
-                // Advance time
-                match self.find_next_event_time(
-                    &state.scheduled,
-                    &state.unscheduled,
-                    &state.resource_schedules,
-                    state.current_time,
-                ) {
-                    Some(next_time) if next_time <= horizon => state.current_time = next_time,
-                    _ => break,
-                }
-                continue;
-            }
+            let mut eligible = state.graph.eligible(state.current_time);
+            eligible.retain(|&id| self.simulates_task(shape.name(id)));
 
-            // Sort by priority
-            let default_cr = self.compute_default_cr(&state.unscheduled, state.current_time);
-            let atc_params = self.compute_atc_params(&state.unscheduled, state.current_time);
-            let sorted = self.sort_eligible_tasks(
+            // Sort by strategy
+            let unscheduled_names = state.unscheduled.iter().map(|&id| shape.name(id));
+            let default_cr = self.compute_default_cr(unscheduled_names.clone(), state.current_time);
+            let atc_params = self.compute_atc_params(unscheduled_names, state.current_time);
+            let sorted = self.sort_eligible_ids(
+                &shape,
                 &eligible,
                 state.current_time,
                 default_cr,
@@ -1282,32 +2841,25 @@ impl ParallelScheduler {
 
             // Try to schedule
             let mut scheduled_any = false;
-            for task_id in sorted {
+            for id in sorted {
                 // Skip logic for rollout
-                if let Some(skip_id) = skip_task_id {
-                    if task_id == skip_id && state.current_time == initial_time {
-                        continue;
-                    }
+                if skip == Some(id) && state.current_time == initial_time {
+                    continue;
                 }
-
-                let task = match self.tasks.get(&task_id) {
-                    Some(t) => t.clone(),
-                    None => continue,
+                let Some(task) = self.tasks.get(shape.name(id)) else {
+                    continue;
                 };
-
-                if self.try_schedule_task_in_simulation(&task_id, &task, &mut state) {
+                if let Ok(placed) = self.try_place(None, id, task, &mut state) {
+                    self.commit_placement(&mut state, id, placed);
                     scheduled_any = true;
                 }
             }
 
             if !scheduled_any {
-                match self.find_next_event_time(
-                    &state.scheduled,
-                    &state.unscheduled,
-                    &state.resource_schedules,
-                    state.current_time,
-                ) {
-                    Some(next_time) if next_time <= horizon => state.current_time = next_time,
+                match self.next_event_time(&mut state) {
+                    Some(next_time) if next_time <= horizon => {
+                        state.advance_to(next_time);
+                    }
                     _ => break,
                 }
             }
@@ -1317,126 +2869,45 @@ impl ParallelScheduler {
         Ok((state, score))
     }
 
-    fn try_schedule_task_in_simulation(
-        &self,
-        task_id: &str,
-        task: &Task,
-        state: &mut SchedulerState,
-    ) -> bool {
-        // Zero-duration tasks
+    /// Whether rollout simulations place this task under the configured fidelity.
+    fn simulates_task(&self, task_id: &str) -> bool {
+        let (Some(rollout_config), Some(task)) = (&self.rollout_config, self.tasks.get(task_id))
+        else {
+            return true;
+        };
         if task.duration_days == 0.0 {
-            state.scheduled.insert(
-                task_id.to_string(),
-                (state.current_time, state.current_time),
-            );
-            state.unscheduled.remove(task_id);
-            state.result.push(ScheduledTask {
-                task_id: task_id.to_string(),
-                start_date: state.current_time,
-                end_date: state.current_time,
-                duration_days: 0.0,
-                resources: vec![],
-            });
             return true;
         }
-
-        // Auto-assignment
-        if task.resource_spec.is_some() && self.resource_config.is_some() {
-            let resource_config = self.resource_config.as_ref().unwrap();
-            let spec = task.resource_spec.as_ref().unwrap();
-            let candidates = resource_config.expand_resource_spec(spec);
-
-            let mut best_resource: Option<String> = None;
-            let mut best_completion: Option<NaiveDate> = None;
-
-            for resource_name in candidates {
-                if let Some(schedule) = state.resource_schedules.get_mut(&resource_name) {
-                    let available_at = schedule.next_available_time(state.current_time);
-                    if available_at == state.current_time {
-                        let completion =
-                            schedule.calculate_completion_time(available_at, task.duration_days);
-                        if best_completion.is_none() || completion < best_completion.unwrap() {
-                            best_resource = Some(resource_name);
-                            best_completion = Some(completion);
-                        }
-                    }
-                }
+        match rollout_config.rollout_fidelity {
+            RolloutFidelity::Full => true,
+            RolloutFidelity::NoAutoAssignment => {
+                task.resource_spec.is_none() || self.resource_config.is_none()
             }
-
-            if let (Some(resource), Some(completion)) = (best_resource, best_completion) {
-                if let Some(schedule) = state.resource_schedules.get_mut(&resource) {
-                    schedule.add_busy_period(state.current_time, completion);
-                }
-                state
-                    .scheduled
-                    .insert(task_id.to_string(), (state.current_time, completion));
-                state.unscheduled.remove(task_id);
-                state.result.push(ScheduledTask {
-                    task_id: task_id.to_string(),
-                    start_date: state.current_time,
-                    end_date: completion,
-                    duration_days: task.duration_days,
-                    resources: vec![resource],
-                });
-                return true;
+            RolloutFidelity::CriticalTasksOnly => {
+                let has_deadline = self
+                    .computed_deadlines
+                    .get(task_id)
+                    .is_some_and(|d| *d != NaiveDate::MAX);
+                let priority = self
+                    .computed_priorities
+                    .get(task_id)
+                    .copied()
+                    .unwrap_or(self.config.default_priority);
+                has_deadline || priority >= rollout_config.priority_threshold
             }
-            return false;
-        }
-
-        // Explicit resources
-        if task.resources.is_empty() {
-            return false;
         }
-
-        for (resource_name, _) in &task.resources {
-            if let Some(schedule) = state.resource_schedules.get(resource_name) {
-                let next_avail = schedule.next_available_time(state.current_time);
-                if next_avail != state.current_time {
-                    return false;
-                }
-            } else {
-                return false;
-            }
-        }
-
-        let mut max_completion = state.current_time;
-        for (resource_name, _) in &task.resources {
-            if let Some(schedule) = state.resource_schedules.get_mut(resource_name) {
-                let completion =
-                    schedule.calculate_completion_time(state.current_time, task.duration_days);
-                if completion > max_completion {
-                    max_completion = completion;
-                }
-            }
-        }
-
-        for (resource_name, _) in &task.resources {
-            if let Some(schedule) = state.resource_schedules.get_mut(resource_name) {
-                schedule.add_busy_period(state.current_time, max_completion);
-            }
-        }
-
-        let resources: Vec<String> = task.resources.iter().map(|(r, _)| r.clone()).collect();
-        state
-            .scheduled
-            .insert(task_id.to_string(), (state.current_time, max_completion));
-        state.unscheduled.remove(task_id);
-        state.result.push(ScheduledTask {
-            task_id: task_id.to_string(),
-            start_date: state.current_time,
-            end_date: max_completion,
-            duration_days: task.duration_days,
-            resources,
-        });
-        true
     }
-
     /// Evaluate a partial schedule. Lower score is better.
     fn evaluate_partial_schedule(&self, state: &SchedulerState, horizon: NaiveDate) -> f64 {
-        let mut score = 0.0;
-        let scheduled_ids: FxHashSet<String> =
-            state.result.iter().map(|st| st.task_id.clone()).collect();
-
+        let default_objective = ScheduleObjective::default();
+        let objective = self
+            .rollout_config
+            .as_ref()
+            .map_or(&default_objective, |rc| &rc.objective);
+        let mut score = objective.schedule_terms(&state.result, self.current_date, horizon);
+        if let Some(rc) = &self.resource_config {
+            score += objective.reassignment_term(&state.result, &rc.prior_assignments);
+        }
         for scheduled_task in &state.result {
             let priority = self
                 .computed_priorities
@@ -1446,23 +2917,22 @@ impl ParallelScheduler {
 
             // Reward earlier starts for high-priority tasks
             let days_from_start = (scheduled_task.start_date - self.current_date).num_days() as f64;
-            score += days_from_start * (priority as f64 / 100.0);
+            score += objective.flow_time_weight * days_from_start * objective.task_weight(priority);
 
             // Penalize tardiness heavily
             if let Some(deadline) = self.computed_deadlines.get(&scheduled_task.task_id) {
                 if scheduled_task.end_date > *deadline {
                     let tardiness = (scheduled_task.end_date - *deadline).num_days() as f64;
-                    score += tardiness * priority as f64 * 10.0;
+                    score +=
+                        objective.tardiness_weight * tardiness * objective.task_weight(priority);
                 }
             }
         }
 
         // Penalize eligible but unscheduled high-priority tasks
-        for task_id in &state.unscheduled {
-            if scheduled_ids.contains(task_id) {
-                continue;
-            }
-
+        let shape = &state.graph.shape;
+        for &id in &state.unscheduled {
+            let task_id = shape.name(id);
             let task = match self.tasks.get(task_id) {
                 Some(t) => t,
                 None => continue,
@@ -1476,46 +2946,42 @@ impl ParallelScheduler {
             let cr = self.compute_task_cr(task_id, self.current_date);
 
             // Check if task was eligible
-            let mut was_eligible = true;
-            for dep in &task.dependencies {
-                if self.completed_task_ids.contains(&dep.entity_id) {
-                    continue;
-                }
-                if !state.scheduled.contains_key(&dep.entity_id) {
-                    was_eligible = false;
-                    break;
-                }
-            }
-
-            if was_eligible {
-                if let Some(start_after) = task.start_after {
-                    if start_after > horizon {
-                        was_eligible = false;
-                    }
-                }
-            }
+            let was_eligible = state.graph.dependencies_placed(id)
+                && shape.start_afters[id as usize].is_none_or(|start_after| start_after <= horizon);
 
             if was_eligible {
                 // Penalize based on priority AND urgency
                 let urgency_multiplier = (10.0 / cr.max(0.1)).min(100.0);
                 let days_delayed = (horizon - self.current_date).num_days() as f64;
-                score += days_delayed * (priority as f64 / 100.0) * urgency_multiplier;
+                score += objective.flow_time_weight
+                    * days_delayed
+                    * objective.task_weight(priority)
+                    * urgency_multiplier;
 
                 // Add expected tardiness penalty
                 if let Some(deadline) = self.computed_deadlines.get(task_id) {
                     if *deadline != NaiveDate::MAX {
                         let expected_end = horizon
                             .checked_add_days(Days::new(task.duration_days.ceil() as u64))
-                            .unwrap_or(horizon);
+                            .unwrap_or(NaiveDate::MAX);
                         if expected_end > *deadline {
                             let expected_tardiness = (expected_end - *deadline).num_days() as f64;
-                            score += expected_tardiness * priority as f64 * 10.0;
+                            score += objective.tardiness_weight
+                                * expected_tardiness
+                                * objective.task_weight(priority);
                         }
                     }
                 }
             }
         }
 
+        // Weighted resource cost
+        if let Some(rc) = &self.resource_config {
+            if rc.cost_weight > 0.0 {
+                score += rc.cost_weight * rc.schedule_cost(&state.result);
+            }
+        }
+
         score
     }
 }
@@ -1523,12 +2989,36 @@ impl ParallelScheduler {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::config::{
+        DurationRounding, FixedConflictPolicy, UnknownDependencyPolicy, UnschedulablePolicy,
+    };
     use crate::models::Dependency;
 
     fn d(year: i32, month: u32, day: u32) -> NaiveDate {
         NaiveDate::from_ymd_opt(year, month, day).unwrap()
     }
 
+    /// Scheduler for `tasks` from 2025-01-01, without a resource config or
+    /// precomputed deadlines and priorities.
+    fn scheduler_for(
+        tasks: Vec<Task>,
+        config: SchedulingConfig,
+        global_dns_periods: Vec<(NaiveDate, NaiveDate)>,
+    ) -> ParallelScheduler {
+        ParallelScheduler::new(
+            tasks,
+            d(2025, 1, 1),
+            FxHashSet::default(),
+            config,
+            None,
+            None,
+            global_dns_periods,
+            None,
+            None,
+        )
+        .unwrap()
+    }
+
     #[test]
     fn test_simple_sequential_tasks() {
         let tasks = vec![
@@ -1536,13 +3026,8 @@ mod tests {
                 id: "a".to_string(),
                 duration_days: 5.0,
                 resources: vec![("r1".to_string(), 1.0)],
-                dependencies: vec![],
-                start_after: None,
-                end_before: None,
-                start_on: None,
-                end_on: None,
-                resource_spec: None,
                 priority: Some(50),
+                ..Default::default()
             },
             Task {
                 id: "b".to_string(),
@@ -1550,14 +3035,10 @@ mod tests {
                 resources: vec![("r1".to_string(), 1.0)],
                 dependencies: vec![Dependency {
                     entity_id: "a".to_string(),
-                    lag_days: 0.0,
+                    ..Default::default()
                 }],
-                start_after: None,
-                end_before: None,
-                start_on: None,
-                end_on: None,
-                resource_spec: None,
                 priority: Some(50),
+                ..Default::default()
             },
         ];
 
@@ -1594,6 +3075,262 @@ mod tests {
         assert_eq!(task_b.start_date, d(2025, 1, 7));
     }
 
+    #[test]
+    fn test_degenerate_inputs_warn_with_empty_schedule() {
+        let task = Task {
+            id: "a".to_string(),
+            duration_days: 2.0,
+            resources: vec![("r1".to_string(), 1.0)],
+            ..Default::default()
+        };
+        let run = |tasks: Vec<Task>, completed: &[&str]| {
+            let completed = completed.iter().map(|s| s.to_string()).collect();
+            ParallelScheduler::new(
+                tasks,
+                d(2025, 1, 1),
+                completed,
+                SchedulingConfig::default(),
+                None,
+                None,
+                vec![],
+                None,
+                None,
+            )
+            .unwrap()
+            .schedule()
+            .unwrap()
+        };
+
+        for (result, code) in [
+            (run(vec![], &[]), "no_tasks"),
+            (run(vec![task], &["a"]), "all_completed"),
+        ] {
+            assert!(result.scheduled_tasks.is_empty());
+            assert!(result.unschedulable.is_empty());
+            let codes: Vec<&str> = result.warnings.iter().map(|w| w.code.as_str()).collect();
+            assert_eq!(codes, vec![code]);
+        }
+    }
+
+    #[test]
+    fn test_duration_rounding_and_effective_duration() {
+        let run = |config: SchedulingConfig| {
+            let task = |id: &str, duration_days: f64| Task {
+                id: id.to_string(),
+                duration_days,
+                resources: vec![("r1".to_string(), 1.0)],
+                ..Default::default()
+            };
+            let mut scheduler = scheduler_for(vec![task("a", 0.5), task("b", 1.4)], config, vec![]);
+            let mut placed: Vec<(String, NaiveDate, f64, f64)> = scheduler
+                .schedule()
+                .unwrap()
+                .scheduled_tasks
+                .into_iter()
+                .map(|t| {
+                    (
+                        t.task_id,
+                        t.end_date,
+                        t.duration_days,
+                        t.effective_duration_days,
+                    )
+                })
+                .collect();
+            placed.sort_by(|x, y| x.0.cmp(&y.0));
+            placed
+        };
+        let row = |id: &str, end: u32, duration: f64, effective: f64| {
+            (id.to_string(), d(2025, 1, end), duration, effective)
+        };
+
+        assert_eq!(
+            run(SchedulingConfig::default()),
+            vec![row("a", 2, 0.5, 1.0), row("b", 5, 1.4, 2.0)]
+        );
+        let nearest = SchedulingConfig {
+            duration_rounding: DurationRounding::Nearest,
+            ..Default::default()
+        };
+        assert_eq!(
+            run(nearest.clone()),
+            vec![row("a", 2, 0.5, 1.0), row("b", 4, 1.4, 1.0)]
+        );
+        // Sub-day scheduling keeps the fractions and ignores the rounding policy
+        let sub_day = SchedulingConfig {
+            sub_day: true,
+            ..nearest
+        };
+        let placed = run(sub_day);
+        assert_eq!((placed[0].2, placed[0].3), (0.5, 0.5));
+        assert_eq!((placed[1].2, placed[1].3), (1.4, 1.4));
+    }
+
+    #[test]
+    fn test_extreme_dates_schedule_or_fail_with_overflow() {
+        let run = |duration_days: f64, lag_days: f64| {
+            let tasks = vec![
+                Task {
+                    id: "a".to_string(),
+                    duration_days,
+                    resources: vec![("r1".to_string(), 1.0)],
+                    ..Default::default()
+                },
+                Task {
+                    id: "b".to_string(),
+                    duration_days: 1.0,
+                    resources: vec![("r1".to_string(), 1.0)],
+                    dependencies: vec![Dependency {
+                        entity_id: "a".to_string(),
+                        lag_days,
+                        ..Default::default()
+                    }],
+                    ..Default::default()
+                },
+            ];
+            ParallelScheduler::new(
+                tasks,
+                d(3000, 1, 1),
+                FxHashSet::default(),
+                SchedulingConfig::default(),
+                None,
+                None,
+                vec![],
+                None,
+                None,
+            )
+            .and_then(|mut scheduler| scheduler.schedule())
+        };
+
+        let result = run(10.0, 100_000.0).unwrap();
+        let b = result.scheduled_tasks.iter().find(|t| t.task_id == "b");
+        assert_eq!(b.unwrap().start_date, d(3273, 10, 27));
+        for (duration, lag) in [(3_000_000.0, 0.0), (1.0, 3_000_000.0), (1.0, f64::NAN)] {
+            assert!(matches!(
+                run(duration, lag),
+                Err(SchedulerError::DateOverflow(_))
+            ));
+        }
+    }
+
+    #[test]
+    fn test_deadline_near_min_date_fails_with_overflow() {
+        let near_min = NaiveDate::MIN.checked_add_days(Days::new(5)).unwrap();
+        for duration_days in [f64::INFINITY, 1e18, 10.0] {
+            let tasks = vec![Task {
+                id: "a".to_string(),
+                duration_days,
+                resources: vec![("r1".to_string(), 1.0)],
+                end_before: Some(near_min),
+                ..Default::default()
+            }];
+            let result = ParallelScheduler::new(
+                tasks,
+                d(2025, 1, 1),
+                FxHashSet::default(),
+                SchedulingConfig::default(),
+                None,
+                None,
+                vec![],
+                None,
+                None,
+            )
+            .and_then(|mut scheduler| scheduler.schedule());
+            assert!(matches!(result, Err(SchedulerError::DateOverflow(_))));
+        }
+    }
+
+    #[test]
+    fn test_working_day_lag_skips_global_dns() {
+        let run = |lag_unit: LagUnit| {
+            let tasks = vec![
+                Task {
+                    id: "a".to_string(),
+                    duration_days: 1.0,
+                    resources: vec![("r1".to_string(), 1.0)],
+                    ..Default::default()
+                },
+                Task {
+                    id: "b".to_string(),
+                    duration_days: 1.0,
+                    resources: vec![("r2".to_string(), 1.0)],
+                    dependencies: vec![Dependency {
+                        entity_id: "a".to_string(),
+                        lag_days: 2.0,
+                        lag_unit,
+                    }],
+                    ..Default::default()
+                },
+            ];
+            let mut scheduler = scheduler_for(
+                tasks,
+                SchedulingConfig::default(),
+                vec![(d(2025, 1, 4), d(2025, 1, 5))],
+            );
+            let result = scheduler.schedule().unwrap();
+            result
+                .scheduled_tasks
+                .iter()
+                .find(|t| t.task_id == "b")
+                .unwrap()
+                .start_date
+        };
+
+        // a ends Jan 2; two calendar days are over by the DNS period, two working days are not
+        assert_eq!(run(LagUnit::Calendar), d(2025, 1, 6));
+        assert_eq!(run(LagUnit::Working), d(2025, 1, 7));
+    }
+
+    #[test]
+    fn test_deadline_targets_use_tightest_and_report_each() {
+        let task = |id: &str| Task {
+            id: id.to_string(),
+            duration_days: 3.0,
+            resources: vec![("r1".to_string(), 1.0)],
+            ..Default::default()
+        };
+        let tasks = vec![
+            Task {
+                end_before: Some(d(2025, 1, 20)),
+                ..task("x")
+            },
+            Task {
+                deadline_targets: vec![
+                    ("release".to_string(), d(2025, 1, 30)),
+                    ("demo".to_string(), d(2025, 1, 3)),
+                ],
+                ..task("y")
+            },
+        ];
+        let result = scheduler_for(tasks, SchedulingConfig::default(), vec![])
+            .schedule()
+            .unwrap();
+
+        // The demo deadline puts y ahead of x
+        let y = result
+            .scheduled_tasks
+            .iter()
+            .find(|t| t.task_id == "y")
+            .unwrap();
+        assert_eq!(y.start_date, d(2025, 1, 1));
+
+        let report: Vec<(&str, &str, i64)> = result
+            .metadata
+            .as_ref()
+            .unwrap()
+            .deadlines
+            .iter()
+            .map(|s| (s.task_id.as_str(), s.name.as_str(), s.days_late))
+            .collect();
+        assert_eq!(
+            report,
+            [
+                ("x", "end_before", 0),
+                ("y", "demo", 1),
+                ("y", "release", 0)
+            ]
+        );
+    }
+
     #[test]
     fn test_parallel_tasks() {
         let tasks = vec![
@@ -1601,25 +3338,15 @@ mod tests {
                 id: "a".to_string(),
                 duration_days: 5.0,
                 resources: vec![("r1".to_string(), 1.0)],
-                dependencies: vec![],
-                start_after: None,
-                end_before: None,
-                start_on: None,
-                end_on: None,
-                resource_spec: None,
                 priority: Some(50),
+                ..Default::default()
             },
             Task {
                 id: "b".to_string(),
                 duration_days: 3.0,
                 resources: vec![("r2".to_string(), 1.0)],
-                dependencies: vec![],
-                start_after: None,
-                end_before: None,
-                start_on: None,
-                end_on: None,
-                resource_spec: None,
                 priority: Some(50),
+                ..Default::default()
             },
         ];
 
@@ -1644,19 +3371,149 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_rolling_wave_buckets_far_future_work() {
+        let task = |id: &str, duration_days: f64, dep: Option<&str>, priority: i32| Task {
+            id: id.to_string(),
+            duration_days,
+            resources: vec![("r1".to_string(), 1.0)],
+            dependencies: dep
+                .map(|d| Dependency {
+                    entity_id: d.to_string(),
+                    ..Default::default()
+                })
+                .into_iter()
+                .collect(),
+            priority: Some(priority),
+            ..Default::default()
+        };
+        let tasks = vec![
+            task("a", 5.0, None, 50),
+            task("b", 5.0, Some("a"), 50),
+            task("c", 5.0, Some("b"), 50),
+            task("d", 5.0, Some("c"), 50),
+            task("e", 10.0, None, 10),
+        ];
+        let config = SchedulingConfig {
+            rolling_wave_horizon_days: Some(14),
+            ..Default::default()
+        };
+        let result = scheduler_for(tasks, config, vec![]).schedule().unwrap();
+
+        let dates = |id: &str| {
+            let st = result
+                .scheduled_tasks
+                .iter()
+                .find(|t| t.task_id == id)
+                .unwrap();
+            (st.start_date, st.end_date)
+        };
+        assert_eq!(dates("c"), (d(2025, 1, 13), d(2025, 1, 18)));
+        // d and e start after the Jan 15 horizon; e waits for r1 after d
+        assert_eq!(result.algorithm_metadata["rolling_wave_tasks"], "2");
+        assert_eq!(dates("d"), (d(2025, 1, 19), d(2025, 1, 24)));
+        assert_eq!(dates("e"), (d(2025, 1, 25), d(2025, 2, 4)));
+    }
+
+    #[test]
+    fn test_rolling_wave_placements_pass_verify_schedule() {
+        let task = |id: &str, resource: Option<&str>| Task {
+            id: id.to_string(),
+            duration_days: 1.0,
+            resources: resource.map(|r| (r.to_string(), 1.0)).into_iter().collect(),
+            resource_spec: resource.is_none().then(|| "r1|r2".to_string()),
+            ..Default::default()
+        };
+        let tasks = vec![
+            task("a", Some("r1")),
+            task("b", Some("r1")),
+            task("c", Some("r1")),
+            task("d", Some("r1")),
+            task("e", Some("r1")),
+            task("f", None),
+            task("g", None),
+        ];
+        let resource_config = ResourceConfig {
+            resource_order: vec!["r1".to_string(), "r2".to_string()],
+            dns_periods: HashMap::from([("r1".to_string(), vec![(d(2025, 1, 5), d(2025, 1, 6))])]),
+            ..Default::default()
+        };
+        let global_dns_periods = vec![(d(2025, 1, 10), d(2025, 1, 11))];
+        let result = ParallelScheduler::new(
+            tasks.clone(),
+            d(2025, 1, 1),
+            FxHashSet::default(),
+            SchedulingConfig {
+                rolling_wave_horizon_days: Some(1),
+                ..Default::default()
+            },
+            None,
+            Some(resource_config.clone()),
+            global_dns_periods.clone(),
+            None,
+            None,
+        )
+        .unwrap()
+        .schedule()
+        .unwrap();
+
+        // Work after the first day shares weekly buckets that span DNS days
+        assert_eq!(result.scheduled_tasks.len(), 7);
+        assert_eq!(result.algorithm_metadata["rolling_wave_tasks"], "5");
+        let violations = crate::verify_schedule(
+            &result,
+            &tasks,
+            Some(&resource_config),
+            &global_dns_periods,
+            false,
+        );
+        assert!(violations.is_empty(), "{:?}", violations);
+    }
+
+    #[test]
+    fn test_alap_shifts_slack_work_late() {
+        let tasks = vec![
+            Task {
+                id: "long".to_string(),
+                duration_days: 10.0,
+                resources: vec![("r1".to_string(), 1.0)],
+                ..Default::default()
+            },
+            Task {
+                id: "short".to_string(),
+                duration_days: 2.0,
+                resources: vec![("r2".to_string(), 1.0)],
+                end_before: Some(d(2025, 1, 8)),
+                ..Default::default()
+            },
+        ];
+        let config = SchedulingConfig {
+            alap: true,
+            ..Default::default()
+        };
+        let mut scheduler = scheduler_for(tasks, config, vec![]);
+
+        let result = scheduler.schedule().unwrap();
+        let short = result
+            .scheduled_tasks
+            .iter()
+            .find(|t| t.task_id == "short")
+            .unwrap();
+        // Ends exactly on its deadline instead of at the start of the plan
+        assert_eq!(
+            (short.start_date, short.end_date),
+            (d(2025, 1, 6), d(2025, 1, 8))
+        );
+        assert_eq!(result.algorithm_metadata["alap_shifted"], "1");
+    }
+
     #[test]
     fn test_milestone_zero_duration() {
         let tasks = vec![Task {
             id: "milestone".to_string(),
             duration_days: 0.0,
-            resources: vec![],
-            dependencies: vec![],
-            start_after: None,
-            end_before: None,
-            start_on: None,
-            end_on: None,
-            resource_spec: None,
             priority: Some(50),
+            ..Default::default()
         }];
 
         let mut scheduler = ParallelScheduler::new(
@@ -1687,13 +3544,9 @@ mod tests {
             id: "fixed".to_string(),
             duration_days: 5.0,
             resources: vec![("r1".to_string(), 1.0)],
-            dependencies: vec![],
-            start_after: None,
-            end_before: None,
             start_on: Some(d(2025, 2, 1)),
-            end_on: None,
-            resource_spec: None,
             priority: Some(50),
+            ..Default::default()
         }];
 
         let mut scheduler = ParallelScheduler::new(
@@ -1715,4 +3568,1449 @@ mod tests {
         let fixed = &result.scheduled_tasks[0];
         assert_eq!(fixed.start_date, d(2025, 2, 1));
     }
+
+    #[test]
+    fn test_fixed_tasks_survive_repeated_runs() {
+        let tasks = vec![
+            Task {
+                id: "fixed".to_string(),
+                duration_days: 5.0,
+                resources: vec![("r1".to_string(), 1.0)],
+                start_on: Some(d(2025, 2, 1)),
+                ..Default::default()
+            },
+            Task {
+                id: "after".to_string(),
+                duration_days: 2.0,
+                resources: vec![("r1".to_string(), 1.0)],
+                dependencies: vec![Dependency {
+                    entity_id: "fixed".to_string(),
+                    ..Default::default()
+                }],
+                ..Default::default()
+            },
+        ];
+        let mut scheduler = scheduler_for(tasks, SchedulingConfig::default(), vec![]);
+
+        let first = scheduler.schedule().unwrap();
+        let second = scheduler.schedule().unwrap();
+        assert_eq!(first.scheduled_tasks, second.scheduled_tasks);
+
+        scheduler.schedule_until(d(2025, 1, 10)).unwrap();
+        scheduler.restore(None);
+        let third = scheduler.schedule().unwrap();
+        assert_eq!(first.scheduled_tasks, third.scheduled_tasks);
+    }
+
+    #[test]
+    fn test_external_date_milestone() {
+        let tasks = vec![
+            Task {
+                id: "launch".to_string(),
+                duration_days: 3.0,
+                dependencies: vec![Dependency {
+                    entity_id: "prep".to_string(),
+                    ..Default::default()
+                }],
+                external_date: Some(d(2025, 2, 1)),
+                ..Default::default()
+            },
+            Task {
+                id: "prep".to_string(),
+                duration_days: 2.0,
+                resources: vec![("r1".to_string(), 1.0)],
+                ..Default::default()
+            },
+            Task {
+                id: "follow_up".to_string(),
+                duration_days: 1.0,
+                resources: vec![("r1".to_string(), 1.0)],
+                dependencies: vec![Dependency {
+                    entity_id: "launch".to_string(),
+                    ..Default::default()
+                }],
+                ..Default::default()
+            },
+        ];
+
+        let mut scheduler = scheduler_for(tasks, SchedulingConfig::default(), vec![]);
+
+        // The milestone's date is a deadline for the work feeding it
+        assert_eq!(
+            scheduler.computed_deadlines.get("prep"),
+            Some(&d(2025, 2, 1))
+        );
+
+        let result = scheduler.schedule().unwrap();
+        let find = |id: &str| {
+            result
+                .scheduled_tasks
+                .iter()
+                .find(|t| t.task_id == id)
+                .unwrap()
+        };
+
+        let launch = find("launch");
+        assert_eq!(launch.start_date, d(2025, 2, 1));
+        assert_eq!(launch.end_date, d(2025, 2, 1));
+        assert_eq!(launch.duration_days, 0.0);
+        assert!(launch.resources.is_empty());
+        assert!(find("follow_up").start_date > d(2025, 2, 1));
+    }
+
+    #[test]
+    fn test_unknown_dependencies() {
+        let tasks = vec![Task {
+            id: "a".to_string(),
+            duration_days: 1.0,
+            resources: vec![("r1".to_string(), 1.0)],
+            dependencies: vec![Dependency {
+                entity_id: "ghost".to_string(),
+                ..Default::default()
+            }],
+            ..Default::default()
+        }];
+        let run = |policy: UnknownDependencyPolicy| {
+            let config = SchedulingConfig {
+                unknown_dependencies: policy,
+                ..SchedulingConfig::default()
+            };
+            scheduler_for(tasks.clone(), config, vec![]).schedule()
+        };
+
+        match run(UnknownDependencyPolicy::Error) {
+            Err(SchedulerError::UnknownDependencies(ids)) => assert_eq!(ids, vec!["ghost"]),
+            other => panic!("expected UnknownDependencies, got {:?}", other),
+        }
+
+        let result = run(UnknownDependencyPolicy::Ignore).unwrap();
+        assert_eq!(result.scheduled_tasks[0].start_date, d(2025, 1, 1));
+        assert_eq!(
+            result.algorithm_metadata.get("ignored_dependencies"),
+            Some(&"ghost".to_string())
+        );
+    }
+
+    #[test]
+    fn test_on_unschedulable_policies() {
+        let task = |id: &str, dep: &str| Task {
+            id: id.to_string(),
+            duration_days: 1.0,
+            resources: vec![("r1".to_string(), 1.0)],
+            dependencies: (!dep.is_empty())
+                .then(|| Dependency {
+                    entity_id: dep.to_string(),
+                    ..Default::default()
+                })
+                .into_iter()
+                .collect(),
+            ..Default::default()
+        };
+        let tasks = vec![task("a", "ghost"), task("b", "a"), task("c", "")];
+        let run = |policy: UnschedulablePolicy| {
+            let config = SchedulingConfig {
+                on_unschedulable: policy,
+                ..SchedulingConfig::default()
+            };
+            scheduler_for(tasks.clone(), config, vec![])
+        };
+
+        assert!(matches!(
+            run(UnschedulablePolicy::Error).schedule(),
+            Err(SchedulerError::FailedToSchedule(_))
+        ));
+
+        let result = run(UnschedulablePolicy::Defer).schedule().unwrap();
+        let ids: Vec<&str> = result
+            .scheduled_tasks
+            .iter()
+            .map(|t| t.task_id.as_str())
+            .collect();
+        assert_eq!(ids, vec!["c"]);
+        let reasons: Vec<(&str, &str)> = result
+            .unschedulable
+            .iter()
+            .map(|u| (u.task_id.as_str(), u.reason.as_str()))
+            .collect();
+        assert_eq!(
+            reasons,
+            vec![
+                ("a", "unsatisfiable_dependency"),
+                ("b", "unsatisfiable_dependency")
+            ]
+        );
+
+        // Best effort drops "a"'s unknown dependency; "b" then follows "a"
+        let mut scheduler = run(UnschedulablePolicy::BestEffort);
+        let result = scheduler.schedule().unwrap();
+        assert_eq!(result.scheduled_tasks.len(), 3);
+        assert!(result.unschedulable.is_empty());
+        assert_eq!(
+            result.algorithm_metadata.get("relaxed_dependencies"),
+            Some(&"a".to_string())
+        );
+        // Relaxing is per run; the tasks keep their dependencies for the next
+        let rerun = scheduler.schedule().unwrap();
+        assert_eq!(
+            rerun.algorithm_metadata.get("relaxed_dependencies"),
+            Some(&"a".to_string())
+        );
+        let end = |id: &str| {
+            result
+                .scheduled_tasks
+                .iter()
+                .find(|t| t.task_id == id)
+                .map(|t| (t.start_date, t.end_date))
+                .unwrap()
+        };
+        assert!(end("b").0 > end("a").1);
+    }
+
+    #[test]
+    fn test_duplicate_task_ids_rejected() {
+        let task = |id: &str| Task {
+            id: id.to_string(),
+            duration_days: 1.0,
+            ..Default::default()
+        };
+        let result = ParallelScheduler::new(
+            vec![task("b"), task("a"), task("b"), task("a"), task("c")],
+            d(2025, 1, 1),
+            FxHashSet::default(),
+            SchedulingConfig::default(),
+            None,
+            None,
+            vec![],
+            None,
+            None,
+        );
+        match result {
+            Err(SchedulerError::DuplicateTaskIds(ids)) => assert_eq!(ids, vec!["a", "b"]),
+            Err(e) => panic!("expected DuplicateTaskIds, got {}", e),
+            Ok(_) => panic!("expected DuplicateTaskIds"),
+        }
+    }
+
+    #[test]
+    fn test_schedule_until_and_restore() {
+        let task = |id: &str, duration_days: f64, resource: &str, deps: &[&str]| Task {
+            id: id.to_string(),
+            duration_days,
+            resources: vec![(resource.to_string(), 1.0)],
+            dependencies: deps
+                .iter()
+                .map(|dep| Dependency {
+                    entity_id: dep.to_string(),
+                    ..Default::default()
+                })
+                .collect(),
+            ..Default::default()
+        };
+        let tasks = vec![
+            task("a", 5.0, "r1", &[]),
+            task("b", 3.0, "r1", &["a"]),
+            task("c", 2.0, "r2", &[]),
+        ];
+        let new_scheduler = || scheduler_for(tasks.clone(), SchedulingConfig::default(), vec![]);
+        let dates = |result: &AlgorithmResult| {
+            let mut dates: Vec<(String, NaiveDate, NaiveDate)> = result
+                .scheduled_tasks
+                .iter()
+                .map(|t| (t.task_id.clone(), t.start_date, t.end_date))
+                .collect();
+            dates.sort();
+            dates
+        };
+
+        let snapshot = new_scheduler().schedule_until(d(2025, 1, 3)).unwrap();
+        assert_eq!(snapshot.current_time, d(2025, 1, 4));
+        let placed: Vec<&str> = snapshot
+            .scheduled_tasks
+            .iter()
+            .map(|t| t.task_id.as_str())
+            .collect();
+        assert_eq!(placed, vec!["a", "c"]);
+
+        // Resuming from a serialized snapshot matches an uninterrupted run
+        let mut resumed = new_scheduler();
+        resumed.restore(Some(
+            SchedulerSnapshot::from_json(&snapshot.to_json()).unwrap(),
+        ));
+        assert_eq!(
+            dates(&resumed.schedule().unwrap()),
+            dates(&new_scheduler().schedule().unwrap())
+        );
+
+        // Stepping one decision at a time reaches the same schedule
+        let mut stepper = new_scheduler();
+        let first = stepper.schedule_step().unwrap();
+        assert_eq!(first.kind, "placed");
+        assert_eq!(first.snapshot.scheduled_tasks.len(), 1);
+        while stepper.schedule_step().unwrap().kind != "finished" {}
+        assert_eq!(
+            dates(&stepper.schedule().unwrap()),
+            dates(&new_scheduler().schedule().unwrap())
+        );
+
+        // Dropping a placement lets the resumed run decide it again
+        let mut edited = snapshot.clone();
+        edited.scheduled_tasks.retain(|t| t.task_id != "c");
+        let mut resumed = new_scheduler();
+        resumed.restore(Some(edited));
+        let result = resumed.schedule().unwrap();
+        let c = result
+            .scheduled_tasks
+            .iter()
+            .find(|t| t.task_id == "c")
+            .unwrap();
+        assert_eq!(c.start_date, d(2025, 1, 4));
+    }
+
+    #[test]
+    fn test_iteration_limit_reports_diagnostics() {
+        let task = |id: &str, day: u32| Task {
+            id: id.to_string(),
+            duration_days: 1.0,
+            resources: vec![("r1".to_string(), 1.0)],
+            start_after: Some(d(2025, 1, day)),
+            ..Default::default()
+        };
+        let run = |max_iterations: Option<usize>, tasks: Vec<Task>| {
+            let config = SchedulingConfig {
+                max_iterations,
+                ..SchedulingConfig::default()
+            };
+            scheduler_for(tasks, config, vec![]).schedule()
+        };
+
+        // A run needing exactly one advance fits a limit of one
+        assert_eq!(
+            run(Some(1), vec![task("a", 10)])
+                .unwrap()
+                .scheduled_tasks
+                .len(),
+            1
+        );
+        let tasks = vec![task("a", 10), task("b", 20)];
+        match run(Some(1), tasks.clone()) {
+            Err(SchedulerError::IterationLimit(info)) => {
+                assert_eq!(info.limit, 1);
+                assert_eq!(info.time_reached, d(2025, 1, 12));
+                assert_eq!(info.remaining, vec!["b"]);
+                assert_eq!(info.last_advances, vec![d(2025, 1, 10), d(2025, 1, 12)]);
+            }
+            other => panic!("expected IterationLimit, got {:?}", other),
+        }
+        assert_eq!(run(None, tasks).unwrap().scheduled_tasks.len(), 2);
+    }
+
+    #[test]
+    fn test_remaining_work_pins_in_progress_task() {
+        let tasks = vec![
+            Task {
+                id: "queued".to_string(),
+                duration_days: 2.0,
+                resources: vec![("r1".to_string(), 1.0)],
+                priority: Some(90),
+                ..Default::default()
+            },
+            Task {
+                id: "in_flight".to_string(),
+                duration_days: 10.0,
+                remaining_days: Some(3.0),
+                resources: vec![("r1".to_string(), 1.0)],
+                priority: Some(10),
+                ..Default::default()
+            },
+        ];
+
+        let mut scheduler = scheduler_for(tasks, SchedulingConfig::default(), vec![]);
+        let result = scheduler.schedule().unwrap();
+        let find = |id: &str| {
+            result
+                .scheduled_tasks
+                .iter()
+                .find(|t| t.task_id == id)
+                .unwrap()
+        };
+
+        let in_flight = find("in_flight");
+        assert_eq!(in_flight.start_date, d(2025, 1, 1));
+        assert_eq!(in_flight.duration_days, 3.0);
+        assert_eq!(in_flight.resources, vec!["r1".to_string()]);
+        assert!(in_flight.in_progress);
+        assert!(!find("queued").in_progress);
+        // Despite its higher priority, the queued task waits for the in-flight work
+        assert!(find("queued").start_date > in_flight.end_date);
+    }
+
+    #[test]
+    fn test_schedule_cost() {
+        let config = ResourceConfig {
+            cost_rates: [("alice".to_string(), 100.0), ("bob".to_string(), 50.0)]
+                .into_iter()
+                .collect(),
+            ..Default::default()
+        };
+        let scheduled = vec![ScheduledTask {
+            task_id: "a".to_string(),
+            start_date: d(2025, 1, 1),
+            end_date: d(2025, 1, 3),
+            duration_days: 3.0,
+            resources: vec!["alice".to_string(), "bob".to_string(), "carol".to_string()],
+            ..Default::default()
+        }];
+        assert!((config.schedule_cost(&scheduled) - 450.0).abs() < 1e-9);
+        assert!(config.has_costs());
+        assert!(!ResourceConfig::default().has_costs());
+    }
+
+    #[test]
+    fn test_expand_resource_spec_with_skills() {
+        let skills = |list: &[&str]| list.iter().map(|s| s.to_string()).collect::<Vec<_>>();
+        let config = ResourceConfig {
+            resource_order: skills(&["alice", "bob", "carol"]),
+            skills: [
+                ("alice".to_string(), skills(&["rust", "python", "sql"])),
+                ("bob".to_string(), skills(&["rust"])),
+                ("carol".to_string(), skills(&["python"])),
+            ]
+            .into_iter()
+            .collect(),
+            ..Default::default()
+        };
+
+        // Bob covers rust with no extra skills, so he comes before alice
+        assert_eq!(
+            config.expand_resource_spec_with_skills("*", &skills(&["rust"])),
+            skills(&["bob", "alice"])
+        );
+        assert_eq!(
+            config.expand_resource_spec_with_skills("*", &skills(&["rust", "sql"])),
+            skills(&["alice"])
+        );
+        assert_eq!(
+            config.expand_resource_spec_with_skills("*", &[]),
+            skills(&["alice", "bob", "carol"])
+        );
+    }
+
+    #[test]
+    fn test_expand_nested_resource_groups() {
+        let names = |list: &[&str]| list.iter().map(|s| s.to_string()).collect::<Vec<_>>();
+        let mut config = ResourceConfig {
+            resource_order: names(&["ann", "ben", "cat", "dan"]),
+            spec_expansion: [
+                ("eng".to_string(), names(&["frontend", "backend", "dan"])),
+                ("frontend".to_string(), names(&["ann", "ben"])),
+                ("backend".to_string(), names(&["ben", "cat"])),
+            ]
+            .into_iter()
+            .collect(),
+            ..Default::default()
+        };
+        assert_eq!(
+            config.expand_resource_spec("eng|!ben"),
+            names(&["ann", "cat", "dan"])
+        );
+        assert_eq!(config.group_cycle(), None);
+
+        config
+            .spec_expansion
+            .insert("backend".to_string(), names(&["cat", "eng"]));
+        assert_eq!(config.group_cycle(), Some(names(&["backend", "eng"])));
+        // Expansion still terminates, skipping the back edge
+        assert_eq!(
+            config.expand_resource_spec("eng"),
+            names(&["ann", "ben", "cat", "dan"])
+        );
+        let err = ParallelScheduler::new(
+            vec![],
+            NaiveDate::from_ymd_opt(2025, 1, 1).unwrap(),
+            FxHashSet::default(),
+            SchedulingConfig::default(),
+            None,
+            Some(config),
+            vec![],
+            None,
+            None,
+        )
+        .err()
+        .unwrap();
+        assert_eq!(
+            err.to_string(),
+            "Resource groups contain each other: backend -> eng -> backend"
+        );
+    }
+
+    #[test]
+    fn test_expand_glob_and_attribute_specs() {
+        let names = |list: &[&str]| list.iter().map(|s| s.to_string()).collect::<Vec<_>>();
+        let attrs = |level: &str, site: &str| {
+            [
+                ("level".to_string(), level.to_string()),
+                ("site".to_string(), site.to_string()),
+            ]
+            .into_iter()
+            .collect()
+        };
+        let config = ResourceConfig {
+            resource_order: names(&["qa-ann", "dev-ben", "qa-cat", "dev-dan"]),
+            skills: [
+                ("dev-ben".to_string(), names(&["rust"])),
+                ("dev-dan".to_string(), names(&["rust", "go"])),
+                ("qa-cat".to_string(), names(&["rust"])),
+            ]
+            .into_iter()
+            .collect(),
+            attributes: [
+                ("qa-ann".to_string(), attrs("2", "nyc")),
+                ("dev-ben".to_string(), attrs("3", "sf")),
+                ("qa-cat".to_string(), attrs("1", "sf")),
+                ("dev-dan".to_string(), attrs("5", "nyc")),
+            ]
+            .into_iter()
+            .collect(),
+            ..Default::default()
+        };
+        assert_eq!(
+            config.expand_resource_spec("qa-*"),
+            names(&["qa-ann", "qa-cat"])
+        );
+        assert_eq!(
+            config.expand_resource_spec("skill:rust&level>=3"),
+            names(&["dev-ben", "dev-dan"])
+        );
+        assert_eq!(
+            config.expand_resource_spec("site:sf|dev-?an"),
+            names(&["dev-ben", "qa-cat", "dev-dan"])
+        );
+        assert_eq!(
+            config.expand_resource_spec("*|!qa-*|!site=nyc"),
+            names(&["dev-ben"])
+        );
+        assert!(config.expand_resource_spec("level>x").is_empty());
+    }
+
+    #[test]
+    fn test_efficiency_for() {
+        let config = ResourceConfig {
+            efficiency: [("senior".to_string(), 2.0), ("broken".to_string(), 0.0)]
+                .into_iter()
+                .collect(),
+            skill_efficiency: [(
+                "senior".to_string(),
+                [("rust".to_string(), 4.0), ("ml".to_string(), 0.5)]
+                    .into_iter()
+                    .collect(),
+            )]
+            .into_iter()
+            .collect(),
+            ..Default::default()
+        };
+        let skills = |list: &[&str]| list.iter().map(|s| s.to_string()).collect::<Vec<_>>();
+
+        assert_eq!(config.efficiency_for("senior", &[]), 2.0);
+        assert_eq!(config.efficiency_for("senior", &skills(&["rust"])), 4.0);
+        // Slowest required skill dominates
+        assert_eq!(
+            config.efficiency_for("senior", &skills(&["rust", "ml"])),
+            0.5
+        );
+        // Skills without a per-skill factor fall back to the resource factor
+        assert_eq!(config.efficiency_for("senior", &skills(&["go"])), 2.0);
+        assert_eq!(config.efficiency_for("junior", &[]), 1.0);
+        assert_eq!(config.efficiency_for("broken", &[]), 1.0);
+    }
+
+    #[test]
+    fn test_auto_assignment_uses_efficiency() {
+        let tasks = vec![Task {
+            id: "a".to_string(),
+            duration_days: 4.0,
+            resource_spec: Some("*".to_string()),
+            priority: Some(50),
+            ..Default::default()
+        }];
+        let resource_config = ResourceConfig {
+            resource_order: vec!["junior".to_string(), "senior".to_string()],
+            efficiency: [("senior".to_string(), 2.0)].into_iter().collect(),
+            ..Default::default()
+        };
+
+        let mut scheduler = ParallelScheduler::new(
+            tasks,
+            d(2025, 1, 1),
+            FxHashSet::default(),
+            SchedulingConfig::default(),
+            None,
+            Some(resource_config),
+            vec![],
+            None,
+            None,
+        )
+        .unwrap();
+
+        let result = scheduler.schedule().unwrap();
+        let task = &result.scheduled_tasks[0];
+        assert_eq!(task.resources, vec!["senior".to_string()]);
+        assert_eq!(task.duration_days, 2.0);
+    }
+
+    #[test]
+    fn test_pinned_and_prior_assignments() {
+        let assign = |pinned: Option<&str>, prior: Option<&str>, penalty: f64| {
+            let tasks = vec![Task {
+                id: "a".to_string(),
+                duration_days: 4.0,
+                resource_spec: Some("*".to_string()),
+                pinned_resource: pinned.map(str::to_string),
+                ..Default::default()
+            }];
+            let resource_config = ResourceConfig {
+                resource_order: vec!["junior".to_string(), "senior".to_string()],
+                efficiency: [("senior".to_string(), 2.0)].into_iter().collect(),
+                prior_assignments: prior
+                    .map(|r| ("a".to_string(), r.to_string()))
+                    .into_iter()
+                    .collect(),
+                switch_penalty_days: penalty,
+                ..Default::default()
+            };
+            let mut scheduler = ParallelScheduler::new(
+                tasks,
+                d(2025, 1, 1),
+                FxHashSet::default(),
+                SchedulingConfig::default(),
+                None,
+                Some(resource_config),
+                vec![],
+                None,
+                None,
+            )
+            .unwrap();
+            scheduler.schedule().unwrap().scheduled_tasks[0].resources[0].clone()
+        };
+
+        assert_eq!(assign(None, None, 0.0), "senior");
+        assert_eq!(assign(Some("junior"), None, 0.0), "junior");
+        // senior saves 2 days: not enough to pay a 3-day switch penalty
+        assert_eq!(assign(None, Some("junior"), 3.0), "junior");
+        assert_eq!(assign(None, Some("junior"), 1.0), "senior");
+    }
+
+    #[test]
+    fn test_spec_preference_penalty() {
+        let assign = |spec: &str| {
+            let tasks = vec![Task {
+                id: "a".to_string(),
+                duration_days: 4.0,
+                resource_spec: Some(spec.to_string()),
+                ..Default::default()
+            }];
+            let resource_config = ResourceConfig {
+                resource_order: vec!["junior".to_string(), "senior".to_string()],
+                efficiency: [("senior".to_string(), 2.0)].into_iter().collect(),
+                ..Default::default()
+            };
+            let mut scheduler = ParallelScheduler::new(
+                tasks,
+                d(2025, 1, 1),
+                FxHashSet::default(),
+                SchedulingConfig::default(),
+                None,
+                Some(resource_config),
+                vec![],
+                None,
+                None,
+            )
+            .unwrap();
+            scheduler.schedule().unwrap().scheduled_tasks[0].resources[0].clone()
+        };
+
+        // senior saves 2 days
+        assert_eq!(assign("junior|senior+3"), "junior");
+        assert_eq!(assign("junior|senior+1.5"), "senior");
+        assert_eq!(assign("junior+3|senior"), "senior");
+    }
+
+    #[test]
+    fn test_blocked_periods_delay_and_split_work() {
+        let task = |id: &str, resource: &str, blocked_periods| Task {
+            id: id.to_string(),
+            duration_days: 4.0,
+            resources: vec![(resource.to_string(), 1.0)],
+            blocked_periods,
+            ..Default::default()
+        };
+        let tasks = vec![
+            task(
+                "blocked",
+                "r1",
+                vec![
+                    (d(2025, 1, 1), d(2025, 1, 2)),
+                    (d(2025, 1, 5), d(2025, 1, 6)),
+                ],
+            ),
+            task("free", "r2", vec![]),
+        ];
+        let mut scheduler = scheduler_for(tasks, SchedulingConfig::default(), vec![]);
+        let result = scheduler.schedule().unwrap();
+        let get = |id: &str| {
+            let st = result
+                .scheduled_tasks
+                .iter()
+                .find(|t| t.task_id == id)
+                .unwrap();
+            (st.start_date, st.end_date)
+        };
+
+        // Two days before the second block, two after it
+        assert_eq!(get("blocked"), (d(2025, 1, 3), d(2025, 1, 9)));
+        assert_eq!(get("free"), (d(2025, 1, 1), d(2025, 1, 5)));
+    }
+
+    #[test]
+    fn test_fixed_tasks_in_global_dns_policy() {
+        let run = |allow_fixed_in_global_dns: bool| {
+            let tasks = vec![
+                Task {
+                    id: "a".to_string(),
+                    duration_days: 2.0,
+                    resources: vec![("r1".to_string(), 1.0)],
+                    ..Default::default()
+                },
+                Task {
+                    id: "m".to_string(),
+                    dependencies: vec![Dependency {
+                        entity_id: "a".to_string(),
+                        ..Default::default()
+                    }],
+                    ..Default::default()
+                },
+                Task {
+                    id: "f".to_string(),
+                    duration_days: 1.0,
+                    resources: vec![("r2".to_string(), 1.0)],
+                    start_on: Some(d(2025, 1, 4)),
+                    ..Default::default()
+                },
+            ];
+            let config = SchedulingConfig {
+                allow_fixed_in_global_dns,
+                ..Default::default()
+            };
+            let mut scheduler = scheduler_for(tasks, config, vec![(d(2025, 1, 3), d(2025, 1, 5))]);
+            let result = scheduler.schedule().unwrap();
+            let start = |id: &str| {
+                result
+                    .scheduled_tasks
+                    .iter()
+                    .find(|t| t.task_id == id)
+                    .unwrap()
+                    .start_date
+            };
+            (start("m"), start("f"))
+        };
+
+        assert_eq!(run(true), (d(2025, 1, 4), d(2025, 1, 4)));
+        assert_eq!(run(false), (d(2025, 1, 6), d(2025, 1, 6)));
+    }
+
+    #[test]
+    fn test_in_progress_task_starts_after_freeze() {
+        let tasks = vec![Task {
+            id: "a".to_string(),
+            duration_days: 5.0,
+            remaining_days: Some(2.0),
+            resources: vec![("r1".to_string(), 1.0)],
+            ..Default::default()
+        }];
+        let config = SchedulingConfig {
+            allow_fixed_in_global_dns: false,
+            ..Default::default()
+        };
+        // The scheduling date falls inside the freeze
+        let mut scheduler = scheduler_for(tasks, config, vec![(d(2025, 1, 1), d(2025, 1, 3))]);
+        let result = scheduler.schedule().unwrap();
+        assert_eq!(result.scheduled_tasks[0].start_date, d(2025, 1, 4));
+    }
+
+    #[test]
+    fn test_fixed_task_conflicts_reported() {
+        let run = |on_fixed_conflict: FixedConflictPolicy| {
+            let fixed = |id: &str, start_on: NaiveDate| Task {
+                id: id.to_string(),
+                duration_days: 3.0,
+                resources: vec![("alice".to_string(), 1.0)],
+                start_on: Some(start_on),
+                ..Default::default()
+            };
+            let config = SchedulingConfig {
+                on_fixed_conflict,
+                ..Default::default()
+            };
+            scheduler_for(
+                vec![fixed("a", d(2025, 1, 1)), fixed("b", d(2025, 1, 2))],
+                config,
+                vec![],
+            )
+            .schedule()
+        };
+
+        let result = run(FixedConflictPolicy::Warn).unwrap();
+        assert_eq!(result.warnings.len(), 1);
+        assert_eq!(result.warnings[0].code, "fixed_conflict");
+        assert!(matches!(
+            run(FixedConflictPolicy::Error),
+            Err(SchedulerError::FixedTaskConflicts(overlaps)) if overlaps.len() == 1
+        ));
+        assert!(run(FixedConflictPolicy::Ignore)
+            .unwrap()
+            .warnings
+            .is_empty());
+    }
+
+    #[test]
+    fn test_exclusive_groups_do_not_overlap() {
+        let task = |id: &str, resource: &str, groups: &[&str]| Task {
+            id: id.to_string(),
+            duration_days: 3.0,
+            resources: vec![(resource.to_string(), 1.0)],
+            exclusive_groups: groups.iter().map(|g| g.to_string()).collect(),
+            ..Default::default()
+        };
+        let tasks = vec![
+            task("a", "r1", &["staging"]),
+            task("b", "r2", &["staging"]),
+            task("c", "r3", &[]),
+        ];
+        let mut scheduler = scheduler_for(tasks, SchedulingConfig::default(), vec![]);
+
+        let result = scheduler.schedule().unwrap();
+        let start = |id: &str| {
+            result
+                .scheduled_tasks
+                .iter()
+                .find(|t| t.task_id == id)
+                .unwrap()
+                .start_date
+        };
+        let mut starts = vec![start("a"), start("b")];
+        starts.sort();
+        assert_eq!(starts, vec![d(2025, 1, 1), d(2025, 1, 5)]);
+        assert_eq!(start("c"), d(2025, 1, 1));
+    }
+
+    #[test]
+    fn test_changeover_gap_between_categories() {
+        let task = |id: &str, priority: i32, category: &str| Task {
+            id: id.to_string(),
+            duration_days: 2.0,
+            resources: vec![("lab".to_string(), 1.0)],
+            priority: Some(priority),
+            category: Some(category.to_string()),
+            ..Default::default()
+        };
+        let resource_config = ResourceConfig {
+            resource_order: vec!["lab".to_string()],
+            changeover_days: [(
+                "wet".to_string(),
+                [("dry".to_string(), 2.0)].into_iter().collect(),
+            )]
+            .into_iter()
+            .collect(),
+            ..Default::default()
+        };
+        let mut scheduler = ParallelScheduler::new(
+            vec![
+                task("a", 90, "wet"),
+                task("b", 80, "wet"),
+                task("c", 70, "dry"),
+            ],
+            d(2025, 1, 1),
+            FxHashSet::default(),
+            SchedulingConfig::default(),
+            None,
+            Some(resource_config),
+            vec![],
+            None,
+            None,
+        )
+        .unwrap();
+
+        let result = scheduler.schedule().unwrap();
+        let start = |id: &str| {
+            result
+                .scheduled_tasks
+                .iter()
+                .find(|t| t.task_id == id)
+                .unwrap()
+                .start_date
+        };
+        // Same category follows immediately; switching to dry waits two days
+        assert_eq!(start("b"), d(2025, 1, 4));
+        assert_eq!(start("c"), d(2025, 1, 9));
+    }
+
+    #[test]
+    fn test_capacity_ramp_changes_assignment() {
+        let assign = |segments: Vec<(NaiveDate, f64)>| {
+            let tasks = vec![Task {
+                id: "a".to_string(),
+                duration_days: 4.0,
+                resource_spec: Some("*".to_string()),
+                ..Default::default()
+            }];
+            let resource_config = ResourceConfig {
+                resource_order: vec!["alice".to_string(), "contractor".to_string()],
+                dns_periods: [("alice".to_string(), vec![(d(2025, 1, 1), d(2025, 1, 3))])]
+                    .into_iter()
+                    .collect(),
+                capacity: [("contractor".to_string(), 0.5)].into_iter().collect(),
+                capacity_segments: [("contractor".to_string(), segments)].into_iter().collect(),
+                ..Default::default()
+            };
+            let mut scheduler = ParallelScheduler::new(
+                tasks,
+                d(2025, 1, 1),
+                FxHashSet::default(),
+                SchedulingConfig::default(),
+                None,
+                Some(resource_config),
+                vec![],
+                None,
+                None,
+            )
+            .unwrap();
+            let st = scheduler.schedule().unwrap().scheduled_tasks[0].clone();
+            (st.resources[0].clone(), st.end_date)
+        };
+
+        // Half time throughout: alice finishes first after her DNS period
+        assert_eq!(assign(vec![]), ("alice".to_string(), d(2025, 1, 8)));
+        // Full time from Jan 3: the contractor does 1 day, then 3 more
+        assert_eq!(
+            assign(vec![(d(2025, 1, 3), 1.0)]),
+            ("contractor".to_string(), d(2025, 1, 6))
+        );
+    }
+
+    #[test]
+    fn test_rollout_trace_captures_both_scenarios() {
+        let task = |id: &str, duration_days: f64, priority: i32, dep: Option<&str>, r: &str| Task {
+            id: id.to_string(),
+            duration_days,
+            priority: Some(priority),
+            dependencies: dep
+                .map(|dep| Dependency {
+                    entity_id: dep.to_string(),
+                    ..Default::default()
+                })
+                .into_iter()
+                .collect(),
+            resources: vec![(r.to_string(), 1.0)],
+            ..Default::default()
+        };
+        let mut scheduler = ParallelScheduler::new(
+            vec![
+                task("filler", 10.0, 10, None, "r1"),
+                task("prep", 1.0, 90, None, "r2"),
+                task("urgent", 2.0, 90, Some("prep"), "r1"),
+            ],
+            d(2025, 1, 1),
+            FxHashSet::default(),
+            SchedulingConfig::default(),
+            Some(RolloutConfig {
+                trace_rollouts: true,
+                ..Default::default()
+            }),
+            None,
+            vec![],
+            None,
+            None,
+        )
+        .unwrap();
+        scheduler.schedule().unwrap();
+
+        let trace = scheduler.get_last_rollout_trace().unwrap();
+        assert_eq!(trace.task_id, "filler");
+        assert_eq!(trace.decision_date, d(2025, 1, 1));
+        assert!(trace.schedule_tasks.iter().any(|t| t.task_id == "filler"));
+        assert_eq!(trace.divergence_date, Some(d(2025, 1, 1)));
+        assert_eq!(
+            trace.decision == "skip",
+            trace.skip_score < trace.schedule_score
+        );
+    }
+
+    #[test]
+    fn test_rollout_simulation_respects_exclusive_groups() {
+        let task = |id: &str, duration_days: f64, priority: i32, dep: Option<&str>, r: &str| Task {
+            id: id.to_string(),
+            duration_days,
+            priority: Some(priority),
+            dependencies: dep
+                .map(|dep| Dependency {
+                    entity_id: dep.to_string(),
+                    ..Default::default()
+                })
+                .into_iter()
+                .collect(),
+            resources: vec![(r.to_string(), 1.0)],
+            ..Default::default()
+        };
+        let in_group = |task: Task| Task {
+            exclusive_groups: vec!["staging".to_string()],
+            ..task
+        };
+        let mut scheduler = ParallelScheduler::new(
+            vec![
+                in_group(task("filler", 10.0, 10, None, "r1")),
+                in_group(task("other", 2.0, 5, None, "r3")),
+                task("prep", 1.0, 90, None, "r2"),
+                task("urgent", 2.0, 90, Some("prep"), "r1"),
+            ],
+            d(2025, 1, 1),
+            FxHashSet::default(),
+            SchedulingConfig::default(),
+            Some(RolloutConfig {
+                trace_rollouts: true,
+                ..Default::default()
+            }),
+            None,
+            vec![],
+            None,
+            None,
+        )
+        .unwrap();
+        scheduler.schedule().unwrap();
+
+        // Scheduling "filler" keeps "other" out of the group until it ends
+        let trace = scheduler.get_last_rollout_trace().unwrap();
+        assert_eq!(trace.task_id, "filler");
+        let simulated = |id: &str| {
+            trace
+                .schedule_tasks
+                .iter()
+                .find(|t| t.task_id == id)
+                .cloned()
+        };
+        let filler = simulated("filler").unwrap();
+        assert!(simulated("other").is_none_or(|other| other.start_date > filler.end_date));
+    }
+
+    #[test]
+    fn test_rollout_decision_sees_exclusive_group_conflict() {
+        let decide = |groups: Vec<String>| {
+            let task =
+                |id: &str, duration_days: f64, priority: i32, dep: Option<&str>, r: &str| Task {
+                    id: id.to_string(),
+                    duration_days,
+                    priority: Some(priority),
+                    dependencies: dep
+                        .map(|dep| Dependency {
+                            entity_id: dep.to_string(),
+                            ..Default::default()
+                        })
+                        .into_iter()
+                        .collect(),
+                    resources: vec![(r.to_string(), 1.0)],
+                    exclusive_groups: groups.clone(),
+                    ..Default::default()
+                };
+            let mut scheduler = ParallelScheduler::new(
+                vec![
+                    task("filler", 10.0, 10, None, "r1"),
+                    Task {
+                        exclusive_groups: Vec::new(),
+                        ..task("prep", 1.0, 90, None, "r2")
+                    },
+                    task("urgent", 2.0, 90, Some("prep"), "r3"),
+                ],
+                d(2025, 1, 1),
+                FxHashSet::default(),
+                SchedulingConfig::default(),
+                Some(RolloutConfig::default()),
+                None,
+                vec![],
+                None,
+                None,
+            )
+            .unwrap();
+            let result = scheduler.schedule().unwrap();
+            let urgent_start = result
+                .scheduled_tasks
+                .iter()
+                .find(|t| t.task_id == "urgent")
+                .unwrap()
+                .start_date;
+            let decision = scheduler.get_rollout_decisions()[0].decision.clone();
+            (decision, urgent_start)
+        };
+
+        // On separate resources "urgent" never waits for "filler"
+        assert_eq!(decide(Vec::new()), ("schedule".to_string(), d(2025, 1, 3)));
+        // A shared group would block "urgent" for ten days, so "filler" waits
+        assert_eq!(
+            decide(vec!["staging".to_string()]),
+            ("skip".to_string(), d(2025, 1, 3))
+        );
+    }
+
+    #[test]
+    fn test_rollout_skip_holds_resource_for_competing_task() {
+        let task = |id: &str, duration_days: f64, priority: i32, dep: Option<&str>, r: &str| Task {
+            id: id.to_string(),
+            duration_days,
+            priority: Some(priority),
+            dependencies: dep
+                .map(|dep| Dependency {
+                    entity_id: dep.to_string(),
+                    ..Default::default()
+                })
+                .into_iter()
+                .collect(),
+            resources: vec![(r.to_string(), 1.0)],
+            ..Default::default()
+        };
+        let mut scheduler = ParallelScheduler::new(
+            vec![
+                task("filler", 10.0, 10, None, "r1"),
+                task("quick", 1.0, 5, None, "r1"),
+                task("prep", 1.0, 90, None, "r2"),
+                task("urgent", 2.0, 90, Some("prep"), "r1"),
+            ],
+            d(2025, 1, 1),
+            FxHashSet::default(),
+            SchedulingConfig::default(),
+            Some(RolloutConfig {
+                reservation_max_idle_days: 3,
+                ..Default::default()
+            }),
+            None,
+            vec![],
+            None,
+            None,
+        )
+        .unwrap();
+        let result = scheduler.schedule().unwrap();
+        let start = |id: &str| {
+            result
+                .scheduled_tasks
+                .iter()
+                .find(|t| t.task_id == id)
+                .unwrap()
+                .start_date
+        };
+
+        // The skip holds r1, so "quick" cannot take it ahead of "urgent"
+        let reservations = scheduler.get_reservations();
+        assert_eq!(reservations.len(), 1);
+        assert_eq!(reservations[0].resource, "r1");
+        assert_eq!(reservations[0].task_id, "urgent");
+        assert_eq!(reservations[0].outcome, "fulfilled");
+        assert_eq!(start("urgent"), d(2025, 1, 3));
+        assert!(start("quick") > d(2025, 1, 1));
+        assert_eq!(result.algorithm_metadata["reservations_fulfilled"], "1");
+    }
+
+    #[test]
+    fn test_task_capacity() {
+        let config = ResourceConfig {
+            resource_order: vec!["alice".to_string(), "bob".to_string()],
+            capacity: [("bob".to_string(), 0.5)].into_iter().collect(),
+            ..Default::default()
+        };
+        let explicit = Task {
+            resources: vec![("alice".to_string(), 1.0), ("bob".to_string(), 1.0)],
+            ..Default::default()
+        };
+        let auto = Task {
+            resource_spec: Some("*".to_string()),
+            ..Default::default()
+        };
+        assert_eq!(config.task_capacity(&explicit), 0.5);
+        assert_eq!(config.task_capacity(&auto), 1.0);
+        assert_eq!(config.capacity_of("carol"), 1.0);
+    }
+
+    #[test]
+    fn test_auto_assignment_prefers_cheaper_resource() {
+        let tasks = vec![Task {
+            id: "a".to_string(),
+            duration_days: 2.0,
+            resource_spec: Some("*".to_string()),
+            priority: Some(50),
+            ..Default::default()
+        }];
+        let resource_config = ResourceConfig {
+            resource_order: vec!["alice".to_string(), "bob".to_string()],
+            cost_rates: [("alice".to_string(), 800.0), ("bob".to_string(), 500.0)]
+                .into_iter()
+                .collect(),
+            ..Default::default()
+        };
+
+        let mut scheduler = ParallelScheduler::new(
+            tasks,
+            d(2025, 1, 1),
+            FxHashSet::default(),
+            SchedulingConfig::default(),
+            None,
+            Some(resource_config),
+            vec![],
+            None,
+            None,
+        )
+        .unwrap();
+
+        let result = scheduler.schedule().unwrap();
+        assert_eq!(result.scheduled_tasks[0].resources, vec!["bob".to_string()]);
+        assert_eq!(
+            result
+                .algorithm_metadata
+                .get("total_cost")
+                .map(String::as_str),
+            Some("1000.00")
+        );
+    }
+
+    #[test]
+    fn test_sub_day_packs_short_tasks() {
+        let make_task = |id: &str| Task {
+            id: id.to_string(),
+            duration_days: 0.25,
+            resources: vec![("r1".to_string(), 1.0)],
+            priority: Some(50),
+            ..Default::default()
+        };
+        let tasks = vec![make_task("a"), make_task("b"), make_task("c")];
+
+        let run = |sub_day: bool| {
+            let config = SchedulingConfig {
+                sub_day,
+                ..SchedulingConfig::default()
+            };
+            let mut scheduler = scheduler_for(tasks.clone(), config, vec![]);
+            let mut result = scheduler.schedule().unwrap().scheduled_tasks;
+            result.sort_by(|a, b| {
+                (a.start_date, a.start_offset)
+                    .partial_cmp(&(b.start_date, b.start_offset))
+                    .unwrap()
+            });
+            result
+        };
+
+        // Day mode: each quarter-day task occupies whole days
+        let day_mode = run(false);
+        assert_eq!(day_mode.last().unwrap().start_date, d(2025, 1, 5));
+
+        // Sub-day mode: all three fit on the first day
+        let packed = run(true);
+        assert!(packed.iter().all(|t| t.start_date == d(2025, 1, 1)));
+        assert!(packed.iter().all(|t| t.end_date == d(2025, 1, 1)));
+        let offsets: Vec<f64> = packed.iter().map(|t| t.start_offset).collect();
+        assert_eq!(offsets, vec![0.0, 0.25, 0.5]);
+        assert_eq!(packed[2].end_offset, 0.75);
+    }
+
+    #[test]
+    fn test_cancelled_token_returns_partial_result() {
+        let tasks = vec![Task {
+            id: "a".to_string(),
+            duration_days: 5.0,
+            resources: vec![("r1".to_string(), 1.0)],
+            priority: Some(50),
+            ..Default::default()
+        }];
+
+        let mut scheduler = scheduler_for(tasks, SchedulingConfig::default(), vec![]);
+
+        let token = CancellationToken::new();
+        token.cancel();
+        scheduler.set_cancellation_token(token);
+
+        match scheduler.schedule() {
+            Err(SchedulerError::Cancelled(partial)) => assert!(partial.is_empty()),
+            other => panic!("expected Cancelled, got {:?}", other),
+        }
+        // The token only applied to the cancelled run
+        assert_eq!(scheduler.schedule().unwrap().scheduled_tasks.len(), 1);
+    }
+
+    #[test]
+    fn test_progress_reporter_sees_all_tasks() {
+        use std::sync::{Arc, Mutex};
+
+        let make_task = |id: &str| Task {
+            id: id.to_string(),
+            duration_days: 2.0,
+            resources: vec![("r1".to_string(), 1.0)],
+            priority: Some(50),
+            ..Default::default()
+        };
+
+        let mut scheduler = scheduler_for(
+            vec![make_task("a"), make_task("b")],
+            SchedulingConfig::default(),
+            vec![],
+        );
+
+        let reports = Arc::new(Mutex::new(Vec::new()));
+        let sink = reports.clone();
+        scheduler.set_progress_reporter(ProgressReporter::new(
+            1,
+            Box::new(move |done, remaining, time| {
+                sink.lock().unwrap().push((done, remaining, time));
+            }),
+        ));
+        scheduler.schedule().unwrap();
+
+        let reports = reports.lock().unwrap();
+        assert_eq!(reports.first(), Some(&(0, 2, d(2025, 1, 1))));
+        assert!(reports
+            .iter()
+            .all(|(done, remaining, _)| done + remaining == 2));
+    }
+
+    #[test]
+    fn test_zero_timeout_cancels() {
+        let tasks = vec![Task {
+            id: "a".to_string(),
+            duration_days: 5.0,
+            resources: vec![("r1".to_string(), 1.0)],
+            priority: Some(50),
+            ..Default::default()
+        }];
+
+        let config = SchedulingConfig {
+            timeout_secs: Some(0.0),
+            ..SchedulingConfig::default()
+        };
+        let mut scheduler = scheduler_for(tasks, config, vec![]);
+
+        assert!(matches!(
+            scheduler.schedule(),
+            Err(SchedulerError::Cancelled(_))
+        ));
+    }
+
+    #[test]
+    fn test_invalid_config_rejected_at_construction() {
+        let config = SchedulingConfig {
+            timeout_secs: Some(-1.0),
+            ..SchedulingConfig::default()
+        };
+        let result = ParallelScheduler::new(
+            vec![],
+            d(2025, 1, 1),
+            FxHashSet::default(),
+            config,
+            None,
+            None,
+            vec![],
+            None,
+            None,
+        );
+        assert!(matches!(result, Err(SchedulerError::InvalidConfig(_))));
+    }
+
+    #[test]
+    fn test_forward_graph_eligibility() {
+        let dep = |id: &str, lag_days: f64| Dependency {
+            entity_id: id.to_string(),
+            lag_days,
+            ..Default::default()
+        };
+        let tasks: FxHashMap<String, Task> = [
+            Task {
+                id: "after_fixed".to_string(),
+                dependencies: vec![dep("fixed", 1.0)],
+                ..Default::default()
+            },
+            Task {
+                id: "after_done".to_string(),
+                dependencies: vec![dep("done", 0.0)],
+                start_after: Some(d(2025, 1, 3)),
+                ..Default::default()
+            },
+            Task {
+                id: "after_unknown".to_string(),
+                dependencies: vec![dep("missing", 0.0)],
+                ..Default::default()
+            },
+            Task {
+                id: "after_both".to_string(),
+                dependencies: vec![dep("after_done", 0.0), dep("after_fixed", 0.0)],
+                ..Default::default()
+            },
+        ]
+        .into_iter()
+        .map(|t| (t.id.clone(), t))
+        .collect();
+        let completed: FxHashSet<String> = ["done".to_string()].into_iter().collect();
+        let fixed = vec![ScheduledTask {
+            task_id: "fixed".to_string(),
+            start_date: d(2025, 1, 1),
+            end_date: d(2025, 1, 2),
+            ..Default::default()
+        }];
+
+        let mut graph = ForwardGraph::new(&tasks, &completed, &fixed, &[]);
+        let eligible_on = |graph: &mut ForwardGraph, day: u32| {
+            let mut names: Vec<String> = graph
+                .eligible(d(2025, 1, day))
+                .into_iter()
+                .map(|id| graph.name(id).to_string())
+                .collect();
+            names.sort();
+            names
+        };
+
+        assert!(eligible_on(&mut graph, 1).is_empty());
+        assert_eq!(eligible_on(&mut graph, 3), ["after_done"]);
+        // Fixed task ends Jan 2; one day of lag makes its dependent eligible Jan 4
+        assert_eq!(eligible_on(&mut graph, 4), ["after_done", "after_fixed"]);
+
+        // Placing both dependencies releases their dependent the day after the later end
+        let mut events = EventQueue::default();
+        for (name, end) in [("after_done", 6), ("after_fixed", 5)] {
+            let id = graph.shape.ids.get(name).unwrap();
+            graph.mark_scheduled(id, d(2025, 1, 4), d(2025, 1, end), &mut events);
+        }
+        assert!(eligible_on(&mut graph, 6).is_empty());
+        assert_eq!(eligible_on(&mut graph, 7), ["after_both"]);
+    }
+
+    #[test]
+    fn test_result_includes_summary_metrics() {
+        let task =
+            |id: &str, duration_days: f64, resource: &str, end_before: Option<NaiveDate>| Task {
+                id: id.to_string(),
+                duration_days,
+                resources: vec![(resource.to_string(), 1.0)],
+                end_before,
+                priority: Some(100),
+                ..Default::default()
+            };
+        let tasks = vec![
+            task("a", 4.0, "alice", None),
+            task("b", 2.0, "bob", Some(d(2025, 1, 2))),
+        ];
+        let mut scheduler = scheduler_for(tasks, SchedulingConfig::default(), vec![]);
+        let metadata = scheduler.schedule().unwrap().metadata.unwrap();
+        assert_eq!(metadata.algorithm, "parallel_sgs");
+        assert_eq!(metadata.strategy.as_deref(), Some("weighted"));
+        assert_eq!(metadata.rollout_decision_count, 0);
+        assert!(metadata.iterations > 0);
+        let metrics = metadata.metrics;
+
+        assert_eq!(metrics.makespan_days, 4.0);
+        // b finishes Jan 3, a day past its deadline
+        assert_eq!(metrics.late_targets, 1);
+        assert_eq!(metrics.total_weighted_tardiness, 1.0);
+        assert_eq!(metrics.average_flow_time_days, 3.0);
+        assert_eq!(metrics.peak_resource_load, 2);
+    }
 }
@@ -0,0 +1,166 @@
+//! Priority queue of upcoming scheduling events.
+//!
+//! The forward loops advance time to the next date at which something can
+//! change: a dependency's lag elapses, a `start_after` passes, or a resource
+//! is released. Events are pushed as they are created and checked lazily when
+//! popped, so time advance costs O(log n) instead of a scan over all
+//! unscheduled tasks and busy periods.
+
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, VecDeque};
+
+use chrono::NaiveDate;
+use thiserror::Error;
+
+use crate::interner::{ResourceIdInt, TaskIdInt};
+
+/// What an event was pushed for; used to discard events that no longer apply.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub(crate) enum EventSource {
+    /// A dependency or `start_after` constraint of this task is satisfied.
+    Task(TaskIdInt),
+    /// This resource's busy period ends.
+    Resource(ResourceIdInt),
+    /// A rollout reservation on this resource is released.
+    Reservation(ResourceIdInt),
+    /// A changeover on this resource may have completed.
+    Changeover(ResourceIdInt),
+}
+
+/// Min-heap of `(date, source)` events.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct EventQueue {
+    heap: BinaryHeap<Reverse<(NaiveDate, EventSource)>>,
+}
+
+impl EventQueue {
+    pub(crate) fn push(&mut self, date: NaiveDate, source: EventSource) {
+        self.heap.push(Reverse((date, source)));
+    }
+
+    /// Earliest live event strictly after `current_time`.
+    ///
+    /// Events at or before `current_time`, and events `is_live` rejects, are
+    /// dropped. An event must never become live again once rejected.
+    pub(crate) fn next_after(
+        &mut self,
+        current_time: NaiveDate,
+        mut is_live: impl FnMut(NaiveDate, EventSource) -> bool,
+    ) -> Option<NaiveDate> {
+        while let Some(&Reverse((date, source))) = self.heap.peek() {
+            if date > current_time && is_live(date, source) {
+                return Some(date);
+            }
+            self.heap.pop();
+        }
+        None
+    }
+}
+
+/// Number of recent time advances kept for `IterationLimitReached`.
+const RECENT_ADVANCES: usize = 5;
+
+/// Diagnostics for a forward loop that hit its time-advance limit.
+#[derive(Error, Debug, Clone, PartialEq)]
+#[error(
+    "Scheduling exceeded {limit} time advances at {time_reached} with {} tasks remaining (first: {}); last advances: {}",
+    .remaining.len(),
+    .remaining.iter().take(5).cloned().collect::<Vec<_>>().join(", "),
+    .last_advances.iter().map(|d| d.to_string()).collect::<Vec<_>>().join(", ")
+)]
+pub struct IterationLimitReached {
+    /// The configured (or default) limit.
+    pub limit: usize,
+    /// Scheduling time when the limit was hit.
+    pub time_reached: NaiveDate,
+    /// Unscheduled task IDs, sorted.
+    pub remaining: Vec<String>,
+    /// Most recent dates time advanced to, oldest first.
+    pub last_advances: Vec<NaiveDate>,
+}
+
+/// Counts time advances of a forward loop against a limit.
+///
+/// Iterations that schedule a task are bounded by the task count, so only
+/// advances are limited; the bound therefore scales with how far time moves.
+#[derive(Debug)]
+pub(crate) struct AdvanceLimit {
+    limit: usize,
+    count: usize,
+    recent: VecDeque<NaiveDate>,
+}
+
+impl AdvanceLimit {
+    pub(crate) fn new(limit: usize) -> Self {
+        Self {
+            limit,
+            count: 0,
+            recent: VecDeque::with_capacity(RECENT_ADVANCES),
+        }
+    }
+
+    /// Record an advance to `date`; returns true once the limit is exceeded.
+    pub(crate) fn record(&mut self, date: NaiveDate) -> bool {
+        if self.recent.len() == RECENT_ADVANCES {
+            self.recent.pop_front();
+        }
+        self.recent.push_back(date);
+        self.count += 1;
+        self.count > self.limit
+    }
+
+    /// Build the diagnostics for a loop stopped at `time_reached`.
+    pub(crate) fn exhausted(
+        &self,
+        time_reached: NaiveDate,
+        mut remaining: Vec<String>,
+    ) -> IterationLimitReached {
+        remaining.sort();
+        IterationLimitReached {
+            limit: self.limit,
+            time_reached,
+            remaining,
+            last_advances: self.recent.iter().copied().collect(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn d(day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(2025, 1, day).unwrap()
+    }
+
+    #[test]
+    fn test_next_after_skips_past_and_dead_events() {
+        let mut events = EventQueue::default();
+        events.push(d(5), EventSource::Task(0));
+        events.push(d(3), EventSource::Resource(0));
+        events.push(d(1), EventSource::Task(1));
+        events.push(d(8), EventSource::Task(2));
+
+        let live = |_, source| source != EventSource::Task(0);
+        assert_eq!(events.next_after(d(1), live), Some(d(3)));
+        assert_eq!(events.next_after(d(3), live), Some(d(8)));
+        assert_eq!(events.next_after(d(8), live), None);
+    }
+
+    #[test]
+    fn test_advance_limit_keeps_recent_dates() {
+        let mut limit = AdvanceLimit::new(7);
+        let hit: Vec<bool> = (1..=8).map(|day| limit.record(d(day))).collect();
+        assert_eq!(
+            hit,
+            vec![false, false, false, false, false, false, false, true]
+        );
+
+        let info = limit.exhausted(d(8), vec!["b".to_string(), "a".to_string()]);
+        assert_eq!(info.remaining, vec!["a", "b"]);
+        assert_eq!(info.last_advances, vec![d(4), d(5), d(6), d(7), d(8)]);
+        assert!(info
+            .to_string()
+            .contains("exceeded 7 time advances at 2025-01-08"));
+    }
+}
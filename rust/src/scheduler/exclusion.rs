@@ -0,0 +1,97 @@
+//! Mutually exclusive tasks.
+//!
+//! Tasks sharing an entry in `Task::exclusive_groups` may not run at the same
+//! time, e.g. because they need one staging environment that is not modeled as
+//! a resource. Each group acts as a unit-capacity virtual resource: a member
+//! starts only after every member placed so far has finished.
+
+use chrono::{Days, NaiveDate};
+use rustc_hash::FxHashMap;
+
+use crate::models::{ScheduledTask, Task};
+
+/// Exclusive groups per task and the last busy day of each group.
+#[derive(Clone, Debug, Default)]
+pub struct ExclusionGroups {
+    /// task_id -> groups it belongs to (tasks without groups are omitted)
+    groups: FxHashMap<String, Vec<String>>,
+    /// group -> last day a placed member occupies (inclusive)
+    busy_until: FxHashMap<String, NaiveDate>,
+}
+
+impl ExclusionGroups {
+    /// Collect the groups of `tasks`; no group starts out busy.
+    pub fn new<'a>(tasks: impl IntoIterator<Item = &'a Task>) -> Self {
+        let groups = tasks
+            .into_iter()
+            .filter(|t| !t.exclusive_groups.is_empty())
+            .map(|t| (t.id.clone(), t.exclusive_groups.clone()))
+            .collect();
+        Self {
+            groups,
+            busy_until: FxHashMap::default(),
+        }
+    }
+
+    /// Mark the groups of a placed task busy through its end date.
+    ///
+    /// Milestones take no time and occupy nothing.
+    pub fn occupy(&mut self, placed: &ScheduledTask) {
+        if placed.duration_days == 0.0 {
+            return;
+        }
+        for group in self.groups.get(&placed.task_id).into_iter().flatten() {
+            let busy = self
+                .busy_until
+                .entry(group.clone())
+                .or_insert(placed.end_date);
+            *busy = (*busy).max(placed.end_date);
+        }
+    }
+
+    /// First day every group of `task_id` is free, if that is after `time`.
+    pub fn blocked_until(&self, task_id: &str, time: NaiveDate) -> Option<NaiveDate> {
+        self.groups
+            .get(task_id)?
+            .iter()
+            .filter_map(|group| self.busy_until.get(group))
+            .filter(|&&busy| busy >= time)
+            .max()
+            .and_then(|busy| busy.checked_add_days(Days::new(1)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn d(day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(2025, 1, day).unwrap()
+    }
+
+    #[test]
+    fn test_blocked_until_group_member_finishes() {
+        let task = |id: &str, groups: &[&str]| Task {
+            id: id.to_string(),
+            exclusive_groups: groups.iter().map(|g| g.to_string()).collect(),
+            ..Default::default()
+        };
+        let tasks = [
+            task("a", &["staging"]),
+            task("b", &["staging", "lab"]),
+            task("c", &[]),
+        ];
+        let mut exclusions = ExclusionGroups::new(&tasks);
+        exclusions.occupy(&ScheduledTask {
+            task_id: "a".to_string(),
+            start_date: d(1),
+            end_date: d(4),
+            duration_days: 3.0,
+            ..Default::default()
+        });
+
+        assert_eq!(exclusions.blocked_until("b", d(2)), Some(d(5)));
+        assert_eq!(exclusions.blocked_until("b", d(5)), None);
+        assert_eq!(exclusions.blocked_until("c", d(2)), None);
+    }
+}
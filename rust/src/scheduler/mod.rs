@@ -3,12 +3,19 @@
 //! This module provides a unified scheduler that implements the Parallel Schedule
 //! Generation Scheme (SGS) algorithm with optional bounded rollout lookahead.
 
+mod alap;
 mod core;
+mod events;
+mod exclusion;
 mod resource_schedule;
+mod rolling_wave;
 mod rollout;
 mod state;
 
+pub(crate) use core::task_categories;
 pub use core::{ParallelScheduler, ResourceConfig, SchedulerError};
+pub use events::IterationLimitReached;
+pub(crate) use events::{AdvanceLimit, EventQueue, EventSource};
+pub(crate) use exclusion::ExclusionGroups;
 pub use resource_schedule::ResourceSchedule;
-pub use rollout::RolloutDecision;
-pub use state::SchedulerState;
+pub use rollout::{RolloutDecision, RolloutTrace};
@@ -1,22 +1,41 @@
 //! Resource schedule tracking with sorted, non-overlapping busy periods.
 
+use std::collections::BTreeMap;
+use std::ops::Bound::{Excluded, Unbounded};
+
 use chrono::{Days, NaiveDate};
 use rustc_hash::FxHashMap;
 
+/// Tolerance when comparing fractional-day offsets.
+const OFFSET_EPSILON: f64 = 1e-9;
+
 /// Tracks busy periods for a resource using sorted, non-overlapping intervals.
 ///
-/// Maintains the invariant that busy_periods is always sorted by start date and
-/// contains no overlapping periods. This enables O(log n) binary search lookups.
+/// Busy periods are kept in a `BTreeMap` keyed by start date, coalesced so no
+/// two periods overlap or touch. Insertions and lookups are O(log n).
+///
+/// In sub-day mode, days that are only partly used are tracked separately as the
+/// fraction of the day already consumed, so short tasks can share a day.
 #[derive(Clone, Debug)]
 pub struct ResourceSchedule {
     /// Resource name (for debugging)
     pub resource_name: String,
-    /// Sorted list of (start, end) busy periods (inclusive dates)
-    /// Invariant: sorted by start, non-overlapping
-    pub busy_periods: Vec<(NaiveDate, NaiveDate)>,
+    /// Busy periods as start -> end (inclusive dates)
+    /// Invariant: non-overlapping and non-adjacent
+    busy_periods: BTreeMap<NaiveDate, NaiveDate>,
+    /// Fraction of each working day the resource is available (1.0 = full time)
+    capacity: f64,
+    /// Dated capacity changes: effective date -> capacity from that date on
+    capacity_segments: BTreeMap<NaiveDate, f64>,
+    /// Whether work is packed at fractional-day resolution
+    sub_day: bool,
+    /// Fraction of a partly used day already consumed (sub-day mode only)
+    day_usage: FxHashMap<NaiveDate, f64>,
     /// Cache for calculate_completion_time results
     /// Key is (start_date, duration_centdays) where duration is stored as centdays (i32)
     completion_cache: FxHashMap<(NaiveDate, i32), NaiveDate>,
+    /// Category of categorized work by end date (for changeover gaps)
+    categories: BTreeMap<NaiveDate, String>,
 }
 
 impl ResourceSchedule {
@@ -26,14 +45,87 @@ impl ResourceSchedule {
         resource_name: String,
     ) -> Self {
         let busy_periods = match unavailable_periods {
-            Some(periods) if !periods.is_empty() => Self::merge_periods(periods),
-            _ => Vec::new(),
+            Some(periods) if !periods.is_empty() => {
+                Self::merge_periods(periods).into_iter().collect()
+            }
+            _ => BTreeMap::new(),
         };
         Self {
             resource_name,
             busy_periods,
+            capacity: 1.0,
+            capacity_segments: BTreeMap::new(),
+            sub_day: false,
+            day_usage: FxHashMap::default(),
             completion_cache: FxHashMap::default(),
+            categories: BTreeMap::new(),
+        }
+    }
+
+    /// Enable or disable sub-day packing of work within a day.
+    pub fn with_sub_day(mut self, sub_day: bool) -> Self {
+        self.sub_day = sub_day;
+        self.completion_cache.clear();
+        self
+    }
+
+    /// Fraction of `date` already consumed by earlier work (always 0 in day mode).
+    pub fn start_offset(&self, date: NaiveDate) -> f64 {
+        self.day_usage.get(&date).copied().unwrap_or(0.0)
+    }
+
+    /// Set the daily working fraction (e.g. 0.6 for a 0.6 FTE resource).
+    ///
+    /// Non-positive or non-finite values are ignored.
+    pub fn with_capacity(mut self, capacity: f64) -> Self {
+        if capacity.is_finite() && capacity > 0.0 {
+            self.capacity = capacity;
+            self.completion_cache.clear();
+        }
+        self
+    }
+
+    /// Daily working fraction of this resource.
+    pub fn capacity(&self) -> f64 {
+        self.capacity
+    }
+
+    /// Add dated capacity changes, each effective from its date onward.
+    ///
+    /// Before the first segment the base capacity applies. Segments with
+    /// non-positive or non-finite capacity are ignored.
+    pub fn with_capacity_segments(mut self, segments: &[(NaiveDate, f64)]) -> Self {
+        for &(from, capacity) in segments {
+            if capacity.is_finite() && capacity > 0.0 {
+                self.capacity_segments.insert(from, capacity);
+            }
+        }
+        self.completion_cache.clear();
+        self
+    }
+
+    /// Daily working fraction of this resource on `date`.
+    pub fn capacity_on(&self, date: NaiveDate) -> f64 {
+        self.capacity_segments
+            .range(..=date)
+            .next_back()
+            .map_or(self.capacity, |(_, &c)| c)
+    }
+
+    /// First date after `current` on which the capacity changes.
+    fn next_capacity_change(&self, current: NaiveDate) -> Option<NaiveDate> {
+        self.capacity_segments
+            .range((Excluded(current), Unbounded))
+            .next()
+            .map(|(&d, _)| d)
+    }
+
+    /// Rescale calendar days of work left when moving from `from` to `to`.
+    fn rescale_work(&self, work_remaining: f64, from: NaiveDate, to: NaiveDate) -> f64 {
+        if self.capacity_segments.is_empty() {
+            return work_remaining;
         }
+        work_remaining * self.capacity_on(from) / self.capacity_on(to)
     }
 
     /// Merge overlapping or adjacent periods into a sorted, non-overlapping list.
@@ -49,7 +141,7 @@ impl ResourceSchedule {
         for (start, end) in periods.into_iter().skip(1) {
             let (last_start, last_end) = merged.last().unwrap();
             // Merge if overlapping or adjacent (within 1 day)
-            if start <= last_end.checked_add_days(Days::new(1)).unwrap_or(*last_end) {
+            if start <= last_end.succ_opt().unwrap_or(NaiveDate::MAX) {
                 let new_end = (*last_end).max(end);
                 *merged.last_mut().unwrap() = (*last_start, new_end);
             } else {
@@ -60,106 +152,116 @@ impl ResourceSchedule {
         merged
     }
 
-    /// Add a busy period, merging with existing periods if they overlap.
-    ///
-    /// Maintains the invariant that busy_periods is sorted and non-overlapping.
+    /// Add a busy period, merging with existing periods if they overlap or touch.
     pub fn add_busy_period(&mut self, start: NaiveDate, end: NaiveDate) {
         // Invalidate cache since busy periods are changing
         self.completion_cache.clear();
 
-        if self.busy_periods.is_empty() {
-            self.busy_periods.push((start, end));
-            return;
-        }
-
-        // Find insertion point using binary search
-        let idx = self.busy_periods.partition_point(|(s, _)| *s < start);
-
         let mut new_start = start;
         let mut new_end = end;
-        let mut merge_start = idx;
-        let mut merge_end = idx;
 
         // Merge with previous period if overlapping or adjacent
-        if idx > 0 {
-            let (prev_start, prev_end) = self.busy_periods[idx - 1];
-            if prev_end >= start.checked_sub_days(Days::new(1)).unwrap_or(start) {
+        if let Some((&prev_start, &prev_end)) = self.busy_periods.range(..start).next_back() {
+            if prev_end >= start.pred_opt().unwrap_or(NaiveDate::MIN) {
                 new_start = prev_start;
                 new_end = new_end.max(prev_end);
-                merge_start = idx - 1;
+                self.busy_periods.remove(&prev_start);
             }
         }
 
         // Merge with subsequent periods if overlapping or adjacent
-        while merge_end < self.busy_periods.len() {
-            let (next_start, next_end) = self.busy_periods[merge_end];
-            if next_start <= new_end.checked_add_days(Days::new(1)).unwrap_or(new_end) {
-                new_end = new_end.max(next_end);
-                merge_end += 1;
-            } else {
-                break;
+        loop {
+            let limit = new_end.succ_opt().unwrap_or(NaiveDate::MAX);
+            let next = self
+                .busy_periods
+                .range(new_start..=limit)
+                .next()
+                .map(|(&s, &e)| (s, e));
+            match next {
+                Some((next_start, next_end)) => {
+                    new_end = new_end.max(next_end);
+                    self.busy_periods.remove(&next_start);
+                }
+                None => break,
             }
         }
 
-        // Replace merged range with single period
-        if merge_start < merge_end {
-            self.busy_periods.drain(merge_start..merge_end);
-        }
-        self.busy_periods.insert(merge_start, (new_start, new_end));
+        self.busy_periods.insert(new_start, new_end);
     }
 
-    /// Find the next date when this resource is available (not in a busy period).
-    ///
-    /// Uses binary search for O(log n) lookup.
-    pub fn next_available_time(&self, from_date: NaiveDate) -> NaiveDate {
-        if self.busy_periods.is_empty() {
-            return from_date;
-        }
+    /// Busy periods as inclusive `(start, end)` pairs, in date order.
+    pub fn busy_periods(&self) -> impl Iterator<Item = (NaiveDate, NaiveDate)> + '_ {
+        self.busy_periods.iter().map(|(&s, &e)| (s, e))
+    }
 
-        let mut candidate = from_date;
+    /// Record that work of `category` on this resource ends on `end`.
+    pub fn record_category(&mut self, end: NaiveDate, category: &str) {
+        self.categories.insert(end, category.to_string());
+    }
 
-        loop {
-            match self.find_next_busy_period(candidate) {
-                None => return candidate,
-                Some((busy_start, busy_end)) => {
-                    if candidate < busy_start {
-                        // Candidate is before the busy period, so it's available
-                        return candidate;
-                    }
-                    // Candidate is within the busy period, advance past it
-                    candidate = busy_end.checked_add_days(Days::new(1)).unwrap_or(busy_end);
-                }
-            }
-        }
+    /// Recorded categorized work as `(end, category)`, in date order.
+    pub fn categories(&self) -> impl Iterator<Item = (NaiveDate, &str)> + '_ {
+        self.categories.iter().map(|(&end, c)| (end, c.as_str()))
+    }
+
+    /// Category and end date of the last categorized work ending before `time`.
+    pub fn category_before(&self, time: NaiveDate) -> Option<(NaiveDate, &str)> {
+        self.categories
+            .range(..time)
+            .next_back()
+            .map(|(&end, category)| (end, category.as_str()))
+    }
+
+    /// Number of (coalesced) busy periods.
+    pub fn busy_period_count(&self) -> usize {
+        self.busy_periods.len()
     }
 
-    /// Find the next busy period that contains or starts at/after current date.
+    /// Find the next date when this resource is available (not in a busy period).
     ///
-    /// Uses binary search for O(log n) lookup.
-    fn find_next_busy_period(&self, current: NaiveDate) -> Option<(NaiveDate, NaiveDate)> {
-        if self.busy_periods.is_empty() {
-            return None;
+    /// Periods are coalesced, so at most one busy period is skipped.
+    pub fn next_available_time(&self, from_date: NaiveDate) -> NaiveDate {
+        match self.find_next_busy_period(from_date) {
+            Some((busy_start, busy_end)) if busy_start <= from_date => {
+                busy_end.succ_opt().unwrap_or(NaiveDate::MAX)
+            }
+            _ => from_date,
         }
+    }
 
-        // Binary search: find leftmost period where end >= current
-        let idx = self.busy_periods.partition_point(|(_, end)| *end < current);
+    /// Day after the first busy period that ends on or after `current`, i.e. the
+    /// next date this resource is released. O(log n).
+    pub fn next_release_after(&self, current: NaiveDate) -> Option<NaiveDate> {
+        self.find_next_busy_period(current)
+            .and_then(|(_, busy_end)| busy_end.checked_add_days(Days::new(1)))
+    }
 
-        if idx < self.busy_periods.len() {
-            Some(self.busy_periods[idx])
-        } else {
-            None
+    /// Find the next busy period that contains or starts after the current date.
+    fn find_next_busy_period(&self, current: NaiveDate) -> Option<(NaiveDate, NaiveDate)> {
+        if let Some((&start, &end)) = self.busy_periods.range(..=current).next_back() {
+            if end >= current {
+                return Some((start, end));
+            }
         }
+        self.busy_periods
+            .range((Excluded(current), Unbounded))
+            .next()
+            .map(|(&s, &e)| (s, e))
     }
 
     /// Calculate when a task will actually complete, accounting for busy periods.
     ///
     /// This method walks through the schedule from start date, accumulating work days
     /// and skipping over busy periods (DNS, other tasks, etc.) until the full duration
-    /// is accounted for.
+    /// is accounted for. Part-time resources need `duration_days / capacity` calendar days,
+    /// with the capacity in effect on each day.
     pub fn calculate_completion_time(&mut self, start: NaiveDate, duration_days: f64) -> NaiveDate {
         if duration_days == 0.0 {
             return start;
         }
+        if self.sub_day {
+            return self.calculate_completion_point(start, 0.0, duration_days).0;
+        }
 
         // Convert duration to centdays for cache key (avoids float hashing issues)
         let duration_centdays = (duration_days * 100.0).round() as i32;
@@ -169,17 +271,35 @@ impl ResourceSchedule {
             return cached;
         }
 
-        let mut work_remaining = duration_days;
+        let mut work_remaining = duration_days / self.capacity_on(start);
         let mut current = start;
 
         // Walk through schedule, working around busy periods
         while work_remaining > 0.0 {
-            match self.find_next_busy_period(current) {
+            let next_busy = self.find_next_busy_period(current);
+            if let Some(change) = self.next_capacity_change(current) {
+                if next_busy.is_none_or(|(busy_start, _)| change < busy_start) {
+                    // Capacity changes before the next busy period
+                    let work_days_available = (change - current).num_days() as f64;
+                    if work_days_available >= work_remaining {
+                        let result = current
+                            .checked_add_days(Days::new(work_remaining.ceil() as u64))
+                            .unwrap_or(NaiveDate::MAX);
+                        self.completion_cache.insert(cache_key, result);
+                        return result;
+                    }
+                    work_remaining =
+                        self.rescale_work(work_remaining - work_days_available, current, change);
+                    current = change;
+                    continue;
+                }
+            }
+            match next_busy {
                 None => {
                     // No more busy periods ahead, can complete remaining work
                     let result = current
                         .checked_add_days(Days::new(work_remaining.ceil() as u64))
-                        .unwrap_or(current);
+                        .unwrap_or(NaiveDate::MAX);
                     self.completion_cache.insert(cache_key, result);
                     return result;
                 }
@@ -187,7 +307,9 @@ impl ResourceSchedule {
                     // Check if current date is within the busy period
                     if busy_start <= current {
                         // We're inside a busy period, skip to the end
-                        current = busy_end.checked_add_days(Days::new(1)).unwrap_or(busy_end);
+                        let next = busy_end.succ_opt().unwrap_or(NaiveDate::MAX);
+                        work_remaining = self.rescale_work(work_remaining, current, next);
+                        current = next;
                         continue;
                     }
 
@@ -198,14 +320,16 @@ impl ResourceSchedule {
                         // Can complete before next busy period
                         let result = current
                             .checked_add_days(Days::new(work_remaining.ceil() as u64))
-                            .unwrap_or(current);
+                            .unwrap_or(NaiveDate::MAX);
                         self.completion_cache.insert(cache_key, result);
                         return result;
                     }
 
                     // Use up available work days, then skip busy period
-                    work_remaining -= work_days_available;
-                    current = busy_end.checked_add_days(Days::new(1)).unwrap_or(busy_end);
+                    let next = busy_end.succ_opt().unwrap_or(NaiveDate::MAX);
+                    work_remaining =
+                        self.rescale_work(work_remaining - work_days_available, current, next);
+                    current = next;
                 }
             }
         }
@@ -215,25 +339,122 @@ impl ResourceSchedule {
         current
     }
 
-    /// Check if resource is available for the full duration starting at start.
-    pub fn is_available(&self, start: NaiveDate, duration_days: f64) -> bool {
-        let end = start
-            .checked_add_days(Days::new(duration_days.ceil() as u64))
-            .unwrap_or(start);
+    /// Calculate the completion point of work as `(date, fraction of that day)`.
+    ///
+    /// Work begins on `start` at the later of `start_offset` and the day's existing
+    /// usage. In day mode this matches `calculate_completion_time` with a 0 offset.
+    pub fn calculate_completion_point(
+        &mut self,
+        start: NaiveDate,
+        start_offset: f64,
+        duration_days: f64,
+    ) -> (NaiveDate, f64) {
+        if !self.sub_day {
+            return (self.calculate_completion_time(start, duration_days), 0.0);
+        }
+        if duration_days == 0.0 {
+            return (start, start_offset.max(self.start_offset(start)));
+        }
+
+        let mut work_remaining = duration_days / self.capacity_on(start);
+        let mut current = start;
+        let mut offset = start_offset.max(self.start_offset(start));
+
+        loop {
+            if let Some((busy_start, busy_end)) = self.find_next_busy_period(current) {
+                if busy_start <= current {
+                    let next = busy_end.succ_opt().unwrap_or(NaiveDate::MAX);
+                    work_remaining = self.rescale_work(work_remaining, current, next);
+                    current = next;
+                    offset = self.start_offset(current);
+                    continue;
+                }
+            }
 
-        for (busy_start, busy_end) in &self.busy_periods {
-            // If busy period is entirely after our window, we're done
-            if *busy_start > end {
-                break;
+            let available = 1.0 - offset;
+            if work_remaining <= available + OFFSET_EPSILON {
+                let end_offset = offset + work_remaining;
+                if end_offset >= 1.0 - OFFSET_EPSILON {
+                    let next = current.succ_opt().unwrap_or(NaiveDate::MAX);
+                    return (next, 0.0);
+                }
+                return (current, end_offset);
             }
 
-            // Check for overlap
-            if *busy_start <= end && *busy_end >= start {
-                return false;
+            let next = match current.checked_add_days(Days::new(1)) {
+                Some(next) => next,
+                None => return (current, 0.0),
+            };
+            work_remaining = self.rescale_work(work_remaining - available, current, next);
+            current = next;
+            offset = self.start_offset(current);
+        }
+    }
+
+    /// Completion point of work that also cannot run during `blocked` periods.
+    ///
+    /// The blocked periods only apply to this calculation; they are not booked.
+    pub fn completion_point_avoiding(
+        &mut self,
+        start: NaiveDate,
+        start_offset: f64,
+        duration_days: f64,
+        blocked: &[(NaiveDate, NaiveDate)],
+    ) -> (NaiveDate, f64) {
+        if blocked.is_empty() {
+            return self.calculate_completion_point(start, start_offset, duration_days);
+        }
+        let mut overlay = self.clone();
+        for &(blocked_start, blocked_end) in blocked {
+            overlay.add_busy_period(blocked_start, blocked_end);
+        }
+        overlay.calculate_completion_point(start, start_offset, duration_days)
+    }
+
+    /// Book work from `start` until the completion point `end`.
+    ///
+    /// In day mode this is equivalent to `add_busy_period(start, end.0)`. In sub-day
+    /// mode, fully used days become busy and the end day records its used fraction.
+    pub fn reserve(&mut self, start: NaiveDate, end: (NaiveDate, f64)) {
+        let (end_date, end_offset) = end;
+        if !self.sub_day {
+            self.add_busy_period(start, end_date);
+            return;
+        }
+
+        if end_date > start {
+            let last_full_day = end_date.pred_opt().unwrap_or(end_date);
+            let mut day = start;
+            while day <= last_full_day {
+                self.day_usage.remove(&day);
+                day = match day.succ_opt() {
+                    Some(next) => next,
+                    None => break,
+                };
             }
+            self.add_busy_period(start, last_full_day);
         }
 
-        true
+        if end_offset >= 1.0 - OFFSET_EPSILON {
+            self.day_usage.remove(&end_date);
+            self.add_busy_period(end_date, end_date);
+        } else if end_offset > OFFSET_EPSILON {
+            let usage = self.day_usage.entry(end_date).or_insert(0.0);
+            *usage = usage.max(end_offset);
+            self.completion_cache.clear();
+        }
+    }
+
+    /// Check if resource is available for the full duration starting at start.
+    pub fn is_available(&self, start: NaiveDate, duration_days: f64) -> bool {
+        let end = start
+            .checked_add_days(Days::new(duration_days.ceil() as u64))
+            .unwrap_or(NaiveDate::MAX);
+
+        match self.find_next_busy_period(start) {
+            Some((busy_start, _)) => busy_start > end,
+            None => true,
+        }
     }
 }
 
@@ -286,8 +507,11 @@ mod tests {
         );
         // Add adjacent period (should merge)
         schedule.add_busy_period(d(2025, 1, 16), d(2025, 1, 20));
-        assert_eq!(schedule.busy_periods.len(), 1);
-        assert_eq!(schedule.busy_periods[0], (d(2025, 1, 10), d(2025, 1, 20)));
+        assert_eq!(schedule.busy_period_count(), 1);
+        assert_eq!(
+            schedule.busy_periods().next(),
+            Some((d(2025, 1, 10), d(2025, 1, 20)))
+        );
     }
 
     #[test]
@@ -298,8 +522,11 @@ mod tests {
         );
         // Add overlapping period
         schedule.add_busy_period(d(2025, 1, 12), d(2025, 1, 20));
-        assert_eq!(schedule.busy_periods.len(), 1);
-        assert_eq!(schedule.busy_periods[0], (d(2025, 1, 10), d(2025, 1, 20)));
+        assert_eq!(schedule.busy_period_count(), 1);
+        assert_eq!(
+            schedule.busy_periods().next(),
+            Some((d(2025, 1, 10), d(2025, 1, 20)))
+        );
     }
 
     #[test]
@@ -310,7 +537,35 @@ mod tests {
         );
         // Add separate period
         schedule.add_busy_period(d(2025, 1, 20), d(2025, 1, 25));
-        assert_eq!(schedule.busy_periods.len(), 2);
+        assert_eq!(schedule.busy_period_count(), 2);
+    }
+
+    #[test]
+    fn test_add_busy_period_bridges_several() {
+        let mut schedule = ResourceSchedule::new(None, "test".to_string());
+        schedule.add_busy_period(d(2025, 1, 1), d(2025, 1, 2));
+        schedule.add_busy_period(d(2025, 1, 5), d(2025, 1, 6));
+        schedule.add_busy_period(d(2025, 1, 9), d(2025, 1, 10));
+        schedule.add_busy_period(d(2025, 1, 20), d(2025, 1, 21));
+        // Touches the first period and swallows the next two
+        schedule.add_busy_period(d(2025, 1, 3), d(2025, 1, 8));
+
+        assert_eq!(
+            schedule.busy_periods().collect::<Vec<_>>(),
+            vec![
+                (d(2025, 1, 1), d(2025, 1, 10)),
+                (d(2025, 1, 20), d(2025, 1, 21))
+            ]
+        );
+        assert_eq!(
+            schedule.next_release_after(d(2025, 1, 4)),
+            Some(d(2025, 1, 11))
+        );
+        assert_eq!(
+            schedule.next_release_after(d(2025, 1, 11)),
+            Some(d(2025, 1, 22))
+        );
+        assert_eq!(schedule.next_release_after(d(2025, 1, 22)), None);
     }
 
     #[test]
@@ -336,6 +591,78 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_calculate_completion_part_time() {
+        // 5 days of work at 0.6 FTE takes ~8.3 calendar days
+        let mut schedule = ResourceSchedule::new(None, "bob".to_string()).with_capacity(0.6);
+        assert_eq!(
+            schedule.calculate_completion_time(d(2025, 1, 1), 5.0),
+            d(2025, 1, 10)
+        );
+
+        let ignored = ResourceSchedule::new(None, "bob".to_string()).with_capacity(0.0);
+        assert_eq!(ignored.capacity(), 1.0);
+    }
+
+    #[test]
+    fn test_calculate_completion_capacity_ramp() {
+        // Half time until Jan 5, full time after
+        let mut schedule = ResourceSchedule::new(
+            Some(vec![(d(2025, 1, 7), d(2025, 1, 7))]),
+            "contractor".to_string(),
+        )
+        .with_capacity(0.5)
+        .with_capacity_segments(&[(d(2025, 1, 5), 1.0)]);
+        assert_eq!(schedule.capacity_on(d(2025, 1, 4)), 0.5);
+        assert_eq!(schedule.capacity_on(d(2025, 1, 5)), 1.0);
+        // Jan 1-4 does 2 days of work, Jan 5-6 two more, Jan 8 the last
+        assert_eq!(
+            schedule.calculate_completion_time(d(2025, 1, 1), 5.0),
+            d(2025, 1, 9)
+        );
+        // Entirely after the ramp runs at full time
+        assert_eq!(
+            schedule.calculate_completion_time(d(2025, 1, 10), 3.0),
+            d(2025, 1, 13)
+        );
+    }
+
+    #[test]
+    fn test_sub_day_packing() {
+        let mut schedule = ResourceSchedule::new(None, "test".to_string()).with_sub_day(true);
+
+        let end = schedule.calculate_completion_point(d(2025, 1, 1), 0.0, 0.25);
+        assert_eq!(end, (d(2025, 1, 1), 0.25));
+        schedule.reserve(d(2025, 1, 1), end);
+        assert_eq!(schedule.next_available_time(d(2025, 1, 1)), d(2025, 1, 1));
+        assert_eq!(schedule.start_offset(d(2025, 1, 1)), 0.25);
+
+        // Second task continues from the used fraction and spills into the next day
+        let end = schedule.calculate_completion_point(d(2025, 1, 1), 0.0, 1.0);
+        assert_eq!(end, (d(2025, 1, 2), 0.25));
+        schedule.reserve(d(2025, 1, 1), end);
+        assert_eq!(
+            schedule.busy_periods().collect::<Vec<_>>(),
+            vec![(d(2025, 1, 1), d(2025, 1, 1))]
+        );
+        assert_eq!(schedule.next_available_time(d(2025, 1, 1)), d(2025, 1, 2));
+        assert_eq!(schedule.start_offset(d(2025, 1, 2)), 0.25);
+    }
+
+    #[test]
+    fn test_sub_day_skips_busy_days() {
+        let mut schedule = ResourceSchedule::new(
+            Some(vec![(d(2025, 1, 2), d(2025, 1, 3))]),
+            "test".to_string(),
+        )
+        .with_sub_day(true);
+        // Half of Jan 1, then DNS Jan 2-3, finishing mid Jan 4
+        assert_eq!(
+            schedule.calculate_completion_point(d(2025, 1, 1), 0.5, 1.0),
+            (d(2025, 1, 4), 0.5)
+        );
+    }
+
     #[test]
     fn test_calculate_completion_zero_duration() {
         let mut schedule = ResourceSchedule::new(
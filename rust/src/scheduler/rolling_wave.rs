@@ -0,0 +1,280 @@
+//! Rolling-wave placement of far-future work for the parallel scheduler.
+//!
+//! Work starting past the planning horizon is not decided day by day. Each
+//! resource gets a budget of working days per bucket (a run of
+//! `bucket_days` calendar days, less DNS days, scaled by capacity) and tasks
+//! are taken in dependency order, most urgent first. A task may start no
+//! earlier than the first bucket its resources still have budget in; the
+//! caller then places it at the first time from there that its resources,
+//! calendars and exclusive groups allow.
+
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
+
+use chrono::{Days, NaiveDate};
+use rustc_hash::{FxHashMap, FxHashSet};
+
+use crate::models::{first_unblocked, ScheduledTask, Task};
+
+use super::core::{ResourceConfig, SchedulerError};
+
+/// Buckets searched for capacity before a task is given up on.
+const MAX_BUCKETS: i64 = 10_000;
+
+const WORK_EPSILON: f64 = 1e-9;
+
+/// Coarse capacity buckets starting at the horizon.
+pub(crate) struct RollingWave<'a> {
+    pub horizon: NaiveDate,
+    pub bucket_days: u32,
+    pub resource_config: Option<&'a ResourceConfig>,
+    pub global_dns_periods: &'a [(NaiveDate, NaiveDate)],
+}
+
+/// Working days drawn from each (resource, bucket) budget so far.
+type Usage = FxHashMap<(String, i64), f64>;
+
+/// Error for a task whose rolling-wave start runs past the calendar.
+pub(super) fn start_overflow(task_id: &str) -> SchedulerError {
+    SchedulerError::DateOverflow(vec![format!(
+        "Task '{}' would start after {}",
+        task_id,
+        NaiveDate::MAX
+    )])
+}
+
+impl RollingWave<'_> {
+    fn bucket_of(&self, date: NaiveDate) -> i64 {
+        (date - self.horizon).num_days().max(0) / i64::from(self.bucket_days)
+    }
+
+    fn bucket_start(&self, bucket: i64) -> Option<NaiveDate> {
+        let offset = u64::try_from(bucket * i64::from(self.bucket_days)).ok()?;
+        self.horizon.checked_add_days(Days::new(offset))
+    }
+
+    fn dns_periods(&self, resource: &str) -> Vec<(NaiveDate, NaiveDate)> {
+        match self.resource_config {
+            Some(rc) => rc.get_dns_periods(resource, self.global_dns_periods),
+            None => self.global_dns_periods.to_vec(),
+        }
+    }
+
+    /// Working days `resource` offers on `date` (0 during DNS periods).
+    fn day_capacity(&self, resource: &str, dns: &[(NaiveDate, NaiveDate)], date: NaiveDate) -> f64 {
+        if first_unblocked(dns, date) != date {
+            0.0
+        } else {
+            self.resource_config
+                .map_or(1.0, |rc| rc.capacity_on(resource, date))
+        }
+    }
+
+    fn bucket_capacity(
+        &self,
+        resource: &str,
+        dns: &[(NaiveDate, NaiveDate)],
+        start: NaiveDate,
+    ) -> f64 {
+        start
+            .iter_days()
+            .take(self.bucket_days as usize)
+            .map(|day| self.day_capacity(resource, dns, day))
+            .sum()
+    }
+
+    /// Earliest date from `ready` on at which `resource` has budget left;
+    /// `None` if no budget is found in time.
+    ///
+    /// Work already drawn from a bucket is assumed to come first in it.
+    fn first_free(
+        &self,
+        usage: &Usage,
+        task: &Task,
+        resource: &str,
+        ready: NaiveDate,
+    ) -> Result<Option<NaiveDate>, SchedulerError> {
+        let dns = self.dns_periods(resource);
+        let first = self.bucket_of(ready);
+        for bucket in first..first + MAX_BUCKETS {
+            let start = self
+                .bucket_start(bucket)
+                .ok_or_else(|| start_overflow(&task.id))?;
+            let capacity = self.bucket_capacity(resource, &dns, start);
+            let used = usage
+                .get(&(resource.to_string(), bucket))
+                .copied()
+                .unwrap_or(0.0);
+            if capacity - used <= WORK_EPSILON {
+                continue;
+            }
+            let offset = (used / capacity * f64::from(self.bucket_days)).ceil() as u64;
+            let free = start
+                .checked_add_days(Days::new(offset))
+                .ok_or_else(|| start_overflow(&task.id))?;
+            return Ok(Some(free.max(ready)));
+        }
+        Ok(None)
+    }
+
+    /// Draw the working days of `placement` from its resources' budgets.
+    fn book(&self, usage: &mut Usage, placement: &ScheduledTask) {
+        for resource in &placement.resources {
+            let dns = self.dns_periods(resource);
+            for day in placement.start_date.max(self.horizon).iter_days() {
+                if day >= placement.end_date {
+                    break;
+                }
+                *usage
+                    .entry((resource.clone(), self.bucket_of(day)))
+                    .or_default() += self.day_capacity(resource, &dns, day);
+            }
+        }
+    }
+
+    /// Place `leftover` tasks after the `placed` schedule.
+    ///
+    /// Tasks are taken in dependency order, lowest `order_key` first among
+    /// those ready. `place_from` puts a task on the real calendar at or after
+    /// the earliest date the budgets allow and returns the placement, or
+    /// `None` if it cannot be placed. Resource-less tasks skip the budgets.
+    /// Tasks that find no budget are left out.
+    pub(crate) fn place<K: Ord>(
+        &self,
+        tasks: &FxHashMap<String, Task>,
+        leftover: &[String],
+        placed: &[ScheduledTask],
+        completed_task_ids: &FxHashSet<String>,
+        order_key: impl Fn(&Task) -> K,
+        mut place_from: impl FnMut(&Task, NaiveDate) -> Result<Option<ScheduledTask>, SchedulerError>,
+    ) -> Result<Vec<ScheduledTask>, SchedulerError> {
+        let mut usage = Usage::default();
+        for st in placed.iter().filter(|st| st.end_date > self.horizon) {
+            self.book(&mut usage, st);
+        }
+
+        let pending: FxHashSet<&str> = leftover.iter().map(String::as_str).collect();
+        let mut waiting: FxHashMap<&str, usize> = FxHashMap::default();
+        let mut dependents: FxHashMap<&str, Vec<&str>> = FxHashMap::default();
+        for &id in &pending {
+            let Some(task) = tasks.get(id) else {
+                continue;
+            };
+            for dep in &task.dependencies {
+                if pending.contains(dep.entity_id.as_str()) {
+                    *waiting.entry(id).or_default() += 1;
+                    dependents
+                        .entry(dep.entity_id.as_str())
+                        .or_default()
+                        .push(id);
+                }
+            }
+        }
+        let mut ready: BinaryHeap<Reverse<(K, &str)>> = pending
+            .iter()
+            .filter(|id| !waiting.contains_key(*id))
+            .filter_map(|&id| tasks.get(id).map(|task| Reverse((order_key(task), id))))
+            .collect();
+
+        let mut ends: FxHashMap<&str, NaiveDate> = placed
+            .iter()
+            .map(|st| (st.task_id.as_str(), st.end_date))
+            .collect();
+        let mut result = Vec::new();
+        while let Some(Reverse((_, id))) = ready.pop() {
+            let task = &tasks[id];
+            if let Some(from) = self.earliest_start(task, &ends, completed_task_ids, &usage)? {
+                let placement = if self.uses_resources(task) {
+                    place_from(task, from)?
+                } else {
+                    Some(self.resource_less(task, from)?)
+                };
+                if let Some(placement) = placement {
+                    self.book(&mut usage, &placement);
+                    ends.insert(id, placement.end_date);
+                    result.push(placement);
+                }
+            }
+            for &dependent in dependents.get(id).map_or(&[][..], Vec::as_slice) {
+                let count = waiting.get_mut(dependent).expect("dependent is waiting");
+                *count -= 1;
+                if *count == 0 {
+                    ready.push(Reverse((order_key(&tasks[dependent]), dependent)));
+                }
+            }
+        }
+        Ok(result)
+    }
+
+    /// Whether `task` takes resources: explicit ones or auto-assigned ones.
+    fn uses_resources(&self, task: &Task) -> bool {
+        task.duration_days > 0.0
+            && (!task.resources.is_empty()
+                || (self.resource_config.is_some()
+                    && (task.resource_spec.is_some() || task.pinned_resource.is_some())))
+    }
+
+    /// Earliest date dependencies and resource budgets allow `task` to start;
+    /// `None` if a dependency was left out or no budget is found.
+    fn earliest_start(
+        &self,
+        task: &Task,
+        ends: &FxHashMap<&str, NaiveDate>,
+        completed_task_ids: &FxHashSet<String>,
+        usage: &Usage,
+    ) -> Result<Option<NaiveDate>, SchedulerError> {
+        let mut ready = self.horizon.max(task.start_after.unwrap_or(self.horizon));
+        for dep in &task.dependencies {
+            if completed_task_ids.contains(&dep.entity_id) {
+                continue;
+            }
+            // Dependencies left out of the schedule hold their dependents back too
+            let Some(end) = ends.get(dep.entity_id.as_str()) else {
+                return Ok(None);
+            };
+            ready = ready.max(dep.ready_date(*end, self.global_dns_periods));
+        }
+        if !self.uses_resources(task) {
+            return Ok(Some(ready));
+        }
+        if !task.resources.is_empty() {
+            let mut start = ready;
+            for (resource, _) in &task.resources {
+                let Some(free) = self.first_free(usage, task, resource, ready)? else {
+                    return Ok(None);
+                };
+                start = start.max(free);
+            }
+            return Ok(Some(start));
+        }
+        let Some(rc) = self.resource_config else {
+            return Ok(Some(ready));
+        };
+        let mut earliest = None;
+        for resource in rc.candidates_for(task) {
+            if let Some(free) = self.first_free(usage, task, &resource, ready)? {
+                earliest = Some(earliest.map_or(free, |e: NaiveDate| e.min(free)));
+            }
+        }
+        Ok(earliest)
+    }
+
+    /// Placement of a milestone or resource-less task at its first unblocked day.
+    fn resource_less(
+        &self,
+        task: &Task,
+        ready: NaiveDate,
+    ) -> Result<ScheduledTask, SchedulerError> {
+        let start = first_unblocked(&task.blocked_periods, ready);
+        let end = start
+            .checked_add_days(Days::new(task.duration_days.max(0.0).ceil() as u64))
+            .ok_or_else(|| start_overflow(&task.id))?;
+        Ok(ScheduledTask {
+            task_id: task.id.clone(),
+            start_date: start,
+            end_date: end,
+            duration_days: task.duration_days,
+            ..Default::default()
+        })
+    }
+}
@@ -1,6 +1,11 @@
 //! Bounded rollout simulation and scoring logic.
 
 use chrono::NaiveDate;
+use pyo3::prelude::*;
+use rustc_hash::FxHashMap;
+
+use crate::models::ScheduledTask;
+use crate::snapshot::HeldReservation;
 
 /// Record of a rollout decision for explainability.
 #[derive(Clone, Debug)]
@@ -56,3 +61,113 @@ impl RolloutDecision {
         }
     }
 }
+
+/// Both simulated schedules behind a rollout decision, for debugging.
+///
+/// Scenario A schedules the task now; scenario B skips it (or, in critical
+/// path beam search, takes the best skip alternative). Only tasks placed by the
+/// simulations are included.
+#[pyclass]
+#[derive(Clone, Debug)]
+pub struct RolloutTrace {
+    #[pyo3(get)]
+    pub decision_date: NaiveDate,
+    #[pyo3(get)]
+    pub task_id: String,
+    /// Decision made: "schedule" or "skip"
+    #[pyo3(get)]
+    pub decision: String,
+    #[pyo3(get)]
+    pub schedule_score: f64,
+    #[pyo3(get)]
+    pub skip_score: f64,
+    /// Simulated placements when the task is scheduled now
+    #[pyo3(get)]
+    pub schedule_tasks: Vec<ScheduledTask>,
+    /// Simulated placements when the task is skipped
+    #[pyo3(get)]
+    pub skip_tasks: Vec<ScheduledTask>,
+    /// Earliest start of a task placed differently in the two scenarios
+    #[pyo3(get)]
+    pub divergence_date: Option<NaiveDate>,
+    /// Reservations held when the decision was made (critical path only)
+    #[pyo3(get)]
+    pub reservations: Vec<HeldReservation>,
+}
+
+impl RolloutTrace {
+    /// Create a trace, computing where the two scenarios diverge.
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        decision_date: NaiveDate,
+        task_id: String,
+        decision: String,
+        schedule_score: f64,
+        skip_score: f64,
+        schedule_tasks: Vec<ScheduledTask>,
+        skip_tasks: Vec<ScheduledTask>,
+        reservations: Vec<HeldReservation>,
+    ) -> Self {
+        let divergence_date = divergence_date(&schedule_tasks, &skip_tasks);
+        Self {
+            decision_date,
+            task_id,
+            decision,
+            schedule_score,
+            skip_score,
+            schedule_tasks,
+            skip_tasks,
+            divergence_date,
+            reservations,
+        }
+    }
+}
+
+#[pymethods]
+impl RolloutTrace {
+    fn __repr__(&self) -> String {
+        format!(
+            "RolloutTrace(task_id={}, decision={}, schedule_score={:.2}, skip_score={:.2}, divergence_date={:?})",
+            self.task_id, self.decision, self.schedule_score, self.skip_score, self.divergence_date
+        )
+    }
+}
+
+/// Earliest start among tasks placed differently (or only) in one of two schedules.
+fn divergence_date(a: &[ScheduledTask], b: &[ScheduledTask]) -> Option<NaiveDate> {
+    let in_a: FxHashMap<&str, &ScheduledTask> = a.iter().map(|t| (t.task_id.as_str(), t)).collect();
+    let in_b: FxHashMap<&str, &ScheduledTask> = b.iter().map(|t| (t.task_id.as_str(), t)).collect();
+    let differs = |t: &ScheduledTask, other: &FxHashMap<&str, &ScheduledTask>| {
+        other.get(t.task_id.as_str()).is_none_or(|o| {
+            (o.start_date, o.end_date, &o.resources) != (t.start_date, t.end_date, &t.resources)
+        })
+    };
+    a.iter()
+        .filter(|t| differs(t, &in_b))
+        .chain(b.iter().filter(|t| differs(t, &in_a)))
+        .map(|t| t.start_date)
+        .min()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn placed(id: &str, start: u32, resource: &str) -> ScheduledTask {
+        ScheduledTask {
+            task_id: id.to_string(),
+            start_date: NaiveDate::from_ymd_opt(2025, 1, start).unwrap(),
+            end_date: NaiveDate::from_ymd_opt(2025, 1, start + 2).unwrap(),
+            resources: vec![resource.to_string()],
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_divergence_date() {
+        let a = vec![placed("low", 1, "alice"), placed("other", 2, "bob")];
+        let b = vec![placed("other", 2, "bob"), placed("urgent", 3, "alice")];
+        assert_eq!(divergence_date(&a, &b), NaiveDate::from_ymd_opt(2025, 1, 1));
+        assert_eq!(divergence_date(&a[1..], &b[..1]), None);
+    }
+}
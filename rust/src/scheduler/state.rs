@@ -1,85 +1,121 @@
-//! Scheduler state for rollout simulations.
+//! Placement state of the forward pass, shared with rollout simulations.
+
+use std::rc::Rc;
 
 use chrono::NaiveDate;
 use rustc_hash::{FxHashMap, FxHashSet};
 
+use crate::interner::{ResourceIdInterner, TaskIdInt};
 use crate::models::ScheduledTask;
+use crate::snapshot::HeldReservation;
 
+use super::core::ForwardGraph;
+use super::events::EventQueue;
+use super::exclusion::ExclusionGroups;
 use super::resource_schedule::ResourceSchedule;
 
-/// Snapshot of scheduler state for rollout simulations.
+/// Interned placement state of one forward pass.
 ///
-/// Designed for efficient cloning during rollout lookahead.
-#[derive(Clone)]
-pub struct SchedulerState {
-    /// Tasks already scheduled: task_id -> (start_date, end_date)
-    pub scheduled: FxHashMap<String, (NaiveDate, NaiveDate)>,
-    /// Task IDs not yet scheduled
-    pub unscheduled: FxHashSet<String>,
+/// The main loop owns one; rollout simulations run on copies made by
+/// `clone_for_rollout`, so both use the same eligibility and event tracking.
+pub(super) struct SchedulerState {
+    /// Dependency graph with placement times and the ready set
+    pub graph: ForwardGraph,
+    /// Tasks not yet scheduled
+    pub unscheduled: FxHashSet<TaskIdInt>,
     /// Resource schedules (each must be cloned for simulation)
     pub resource_schedules: FxHashMap<String, ResourceSchedule>,
+    /// Interned resource names, for resource events
+    pub resource_ids: Rc<ResourceIdInterner>,
+    /// Upcoming dates at which a task may become eligible or a resource free
+    pub events: EventQueue,
+    /// Exclusive groups and how long placed members keep them busy
+    pub exclusions: ExclusionGroups,
+    /// Resources held for competing tasks after rollout skips, keyed by resource
+    pub reservations: FxHashMap<String, HeldReservation>,
     /// Current simulation time
     pub current_time: NaiveDate,
-    /// Scheduled task results (for scoring)
+    /// Tasks placed on this state
     pub result: Vec<ScheduledTask>,
 }
 
 impl SchedulerState {
-    /// Create a new scheduler state.
-    pub fn new(
-        scheduled: FxHashMap<String, (NaiveDate, NaiveDate)>,
-        unscheduled: FxHashSet<String>,
-        resource_schedules: FxHashMap<String, ResourceSchedule>,
-        current_time: NaiveDate,
-    ) -> Self {
-        Self {
-            scheduled,
-            unscheduled,
-            resource_schedules,
-            current_time,
-            result: Vec::new(),
-        }
-    }
-
-    /// Create a deep copy for rollout simulation.
+    /// Copy the state for a rollout simulation, without its placed tasks.
     ///
-    /// This is a hot path during rollout - optimized for performance.
+    /// The simulation's `result` only holds what it places itself, which is
+    /// what rollout scoring compares.
     pub fn clone_for_rollout(&self) -> Self {
         Self {
-            scheduled: self.scheduled.clone(),
+            graph: self.graph.clone(),
             unscheduled: self.unscheduled.clone(),
-            resource_schedules: self
-                .resource_schedules
-                .iter()
-                .map(|(k, v)| (k.clone(), v.clone()))
-                .collect(),
+            resource_schedules: self.resource_schedules.clone(),
+            resource_ids: Rc::clone(&self.resource_ids),
+            events: self.events.clone(),
+            exclusions: self.exclusions.clone(),
+            reservations: self.reservations.clone(),
             current_time: self.current_time,
-            result: self.result.clone(),
+            result: Vec::new(),
         }
     }
+
+    /// Remove and return the reservations `release` selects.
+    pub fn release_reservations(
+        &mut self,
+        mut release: impl FnMut(&HeldReservation) -> bool,
+    ) -> Vec<HeldReservation> {
+        let mut released = Vec::new();
+        self.reservations.retain(|_, r| {
+            if release(r) {
+                released.push(r.clone());
+                false
+            } else {
+                true
+            }
+        });
+        released
+    }
+
+    /// Move to `time`, releasing the reservations that expire by then.
+    pub fn advance_to(&mut self, time: NaiveDate) -> Vec<HeldReservation> {
+        self.current_time = time;
+        self.release_reservations(|r| r.release_on <= time)
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::models::Task;
 
     #[test]
-    fn test_state_clone() {
-        let mut schedules = FxHashMap::default();
-        schedules.insert(
-            "r1".to_string(),
-            ResourceSchedule::new(None, "r1".to_string()),
-        );
-
-        let state = SchedulerState::new(
-            FxHashMap::default(),
-            FxHashSet::from_iter(["task1".to_string()]),
-            schedules,
-            NaiveDate::from_ymd_opt(2025, 1, 1).unwrap(),
-        );
+    fn test_clone_for_rollout_shares_graph_shape() {
+        let tasks: FxHashMap<String, Task> = [(
+            "task1".to_string(),
+            Task {
+                id: "task1".to_string(),
+                duration_days: 1.0,
+                ..Default::default()
+            },
+        )]
+        .into_iter()
+        .collect();
+        let graph = ForwardGraph::new(&tasks, &FxHashSet::default(), &[], &[]);
+        let mut state = SchedulerState {
+            graph,
+            unscheduled: FxHashSet::from_iter([0]),
+            resource_schedules: FxHashMap::default(),
+            resource_ids: Rc::new(ResourceIdInterner::new(Vec::new())),
+            events: EventQueue::default(),
+            exclusions: ExclusionGroups::default(),
+            reservations: FxHashMap::default(),
+            current_time: NaiveDate::from_ymd_opt(2025, 1, 1).unwrap(),
+            result: Vec::new(),
+        };
+        state.result.push(ScheduledTask::default());
 
         let cloned = state.clone_for_rollout();
-        assert_eq!(cloned.unscheduled.len(), 1);
-        assert!(cloned.unscheduled.contains("task1"));
+        assert!(cloned.unscheduled.contains(&0));
+        assert!(cloned.result.is_empty());
+        assert!(Rc::ptr_eq(&cloned.graph.shape, &state.graph.shape));
     }
 }
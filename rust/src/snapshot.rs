@@ -0,0 +1,394 @@
+//! Pausing and resuming a scheduling run.
+//!
+//! `schedule_until` on either scheduler stops after the last decision on or
+//! before a date and returns a `SchedulerSnapshot`: every placement so far, the
+//! date to resume from, and any resource reservations still held. Placements
+//! are the whole state: resource busy periods and dependency readiness are
+//! rebuilt from them on `restore`, so a caller can edit the snapshot (pin or
+//! drop a placement) before continuing, possibly in another process via JSON:
+//!
+//! ```text
+//! {
+//!   "current_time": "2025-01-10",
+//!   "scheduled_tasks": [{"task_id": string, "start_date": string, "end_date": string,
+//!                        "duration_days": number, "resources": [string],
+//!                        "start_offset": number, "end_offset": number, "in_progress": bool,
+//!                        "effective_duration_days": number}],
+//!   "reservations": [{"resource": string, "target_id": string, "task_id": string,
+//!                     "target_score": number, "reserved_from": string, "release_on": string}]
+//! }
+//! ```
+
+use chrono::NaiveDate;
+use pyo3::prelude::*;
+use rustc_hash::FxHashSet;
+use serde_json::{json, Map, Value};
+
+use crate::loaders::{json_array, json_bool, json_date, json_number, json_str, LoadError};
+use crate::models::ScheduledTask;
+
+/// A resource held by a rollout reservation for an upcoming task.
+#[pyclass]
+#[derive(Clone, Debug, PartialEq)]
+pub struct HeldReservation {
+    #[pyo3(get, set)]
+    pub resource: String,
+    #[pyo3(get, set)]
+    pub target_id: String,
+    #[pyo3(get, set)]
+    pub task_id: String,
+    #[pyo3(get, set)]
+    pub target_score: f64,
+    #[pyo3(get, set)]
+    pub reserved_from: NaiveDate,
+    #[pyo3(get, set)]
+    pub release_on: NaiveDate,
+}
+
+#[pymethods]
+impl HeldReservation {
+    fn __repr__(&self) -> String {
+        format!(
+            "HeldReservation(resource={}, task_id={}, release_on={})",
+            self.resource, self.task_id, self.release_on
+        )
+    }
+}
+
+/// Scheduler state at a pause point.
+#[pyclass]
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct SchedulerSnapshot {
+    /// Date scheduling resumes from.
+    #[pyo3(get, set)]
+    pub current_time: NaiveDate,
+    /// Every placement so far, including fixed tasks.
+    #[pyo3(get, set)]
+    pub scheduled_tasks: Vec<ScheduledTask>,
+    /// Reservations still held (critical path scheduler with rollout only).
+    #[pyo3(get, set)]
+    pub reservations: Vec<HeldReservation>,
+}
+
+/// One decision of a stepped scheduling run.
+#[pyclass]
+#[derive(Clone, Debug, Default)]
+pub struct ScheduleStep {
+    /// "placed" (one task was scheduled), "advanced" (time moved forward), or
+    /// "finished" (nothing left to decide).
+    #[pyo3(get)]
+    pub kind: String,
+    /// Date of the placement, or the date time advanced to.
+    #[pyo3(get)]
+    pub time: NaiveDate,
+    /// The task placed by this step.
+    #[pyo3(get)]
+    pub placed: Option<ScheduledTask>,
+    /// Tasks eligible at the decision point, in the order they were tried.
+    #[pyo3(get)]
+    pub eligible_task_ids: Vec<String>,
+    /// Targets by descending score at the decision point (critical path scheduler only).
+    #[pyo3(get)]
+    pub ranked_target_ids: Vec<String>,
+    /// State after the step; the scheduler resumes from it on the next step.
+    #[pyo3(get)]
+    pub snapshot: SchedulerSnapshot,
+}
+
+impl ScheduleStep {
+    pub(crate) fn new(time: NaiveDate) -> Self {
+        Self {
+            kind: "finished".to_string(),
+            time,
+            ..Default::default()
+        }
+    }
+
+    /// Whether the step has made its decision.
+    pub(crate) fn is_decided(&self) -> bool {
+        self.kind != "finished"
+    }
+}
+
+#[pymethods]
+impl ScheduleStep {
+    fn __repr__(&self) -> String {
+        match &self.placed {
+            Some(task) => format!(
+                "ScheduleStep(kind={}, time={}, task_id={})",
+                self.kind, self.time, task.task_id
+            ),
+            None => format!("ScheduleStep(kind={}, time={})", self.kind, self.time),
+        }
+    }
+}
+
+impl SchedulerSnapshot {
+    /// Serialize to JSON following the schema in the module docs.
+    pub fn to_json(&self) -> String {
+        let tasks: Vec<Value> = self
+            .scheduled_tasks
+            .iter()
+            .map(|t| {
+                json!({
+                    "task_id": t.task_id,
+                    "start_date": t.start_date.to_string(),
+                    "end_date": t.end_date.to_string(),
+                    "duration_days": t.duration_days,
+                    "resources": t.resources,
+                    "start_offset": t.start_offset,
+                    "end_offset": t.end_offset,
+                    "in_progress": t.in_progress,
+                    "effective_duration_days": t.effective_duration_days,
+                })
+            })
+            .collect();
+        let reservations: Vec<Value> = self
+            .reservations
+            .iter()
+            .map(|r| {
+                json!({
+                    "resource": r.resource,
+                    "target_id": r.target_id,
+                    "task_id": r.task_id,
+                    "target_score": r.target_score,
+                    "reserved_from": r.reserved_from.to_string(),
+                    "release_on": r.release_on.to_string(),
+                })
+            })
+            .collect();
+        json!({
+            "current_time": self.current_time.to_string(),
+            "scheduled_tasks": tasks,
+            "reservations": reservations,
+        })
+        .to_string()
+    }
+
+    /// Parse a snapshot written by `to_json`.
+    pub fn from_json(text: &str) -> Result<Self, LoadError> {
+        let root: Value = serde_json::from_str(text).map_err(|e| LoadError::Syntax {
+            format: "snapshot JSON",
+            line: e.line(),
+            message: e.to_string(),
+        })?;
+        let document = object(&root, "snapshot")?;
+        let current_time =
+            field(document, "snapshot", "current_time", json_date)?.ok_or_else(|| {
+                LoadError::Record {
+                    location: "snapshot".to_string(),
+                    message: "missing 'current_time'".to_string(),
+                }
+            })?;
+
+        let mut scheduled_tasks = Vec::new();
+        let items = field(document, "snapshot", "scheduled_tasks", json_array)?;
+        for (index, item) in items.into_iter().flatten().enumerate() {
+            let location = format!("scheduled task {}", index);
+            let map = object(item, &location)?;
+            let required = |name: &str| LoadError::Record {
+                location: location.clone(),
+                message: format!("missing '{}'", name),
+            };
+            let duration_days =
+                field(map, &location, "duration_days", json_number)?.unwrap_or_default();
+            scheduled_tasks.push(ScheduledTask {
+                task_id: field(map, &location, "task_id", json_str)?
+                    .ok_or_else(|| required("task_id"))?
+                    .to_string(),
+                start_date: field(map, &location, "start_date", json_date)?
+                    .ok_or_else(|| required("start_date"))?,
+                end_date: field(map, &location, "end_date", json_date)?
+                    .ok_or_else(|| required("end_date"))?,
+                duration_days,
+                resources: field(map, &location, "resources", json_array)?
+                    .into_iter()
+                    .flatten()
+                    .map(|r| json_str(r).map(str::to_string))
+                    .collect::<Result<_, _>>()
+                    .map_err(|message| LoadError::Field {
+                        location: location.clone(),
+                        field: "resources".to_string(),
+                        message,
+                    })?,
+                start_offset: field(map, &location, "start_offset", json_number)?
+                    .unwrap_or_default(),
+                end_offset: field(map, &location, "end_offset", json_number)?.unwrap_or_default(),
+                in_progress: field(map, &location, "in_progress", json_bool)?.unwrap_or_default(),
+                effective_duration_days: field(
+                    map,
+                    &location,
+                    "effective_duration_days",
+                    json_number,
+                )?
+                .unwrap_or(duration_days),
+            });
+        }
+
+        let mut reservations = Vec::new();
+        let items = field(document, "snapshot", "reservations", json_array)?;
+        for (index, item) in items.into_iter().flatten().enumerate() {
+            let location = format!("reservation {}", index);
+            let map = object(item, &location)?;
+            let required = |name: &str| LoadError::Record {
+                location: location.clone(),
+                message: format!("missing '{}'", name),
+            };
+            reservations.push(HeldReservation {
+                resource: field(map, &location, "resource", json_str)?
+                    .ok_or_else(|| required("resource"))?
+                    .to_string(),
+                target_id: field(map, &location, "target_id", json_str)?
+                    .unwrap_or_default()
+                    .to_string(),
+                task_id: field(map, &location, "task_id", json_str)?
+                    .ok_or_else(|| required("task_id"))?
+                    .to_string(),
+                target_score: field(map, &location, "target_score", json_number)?
+                    .unwrap_or_default(),
+                reserved_from: field(map, &location, "reserved_from", json_date)?
+                    .unwrap_or(current_time),
+                release_on: field(map, &location, "release_on", json_date)?
+                    .ok_or_else(|| required("release_on"))?,
+            });
+        }
+
+        Ok(Self {
+            current_time,
+            scheduled_tasks,
+            reservations,
+        })
+    }
+}
+
+#[pymethods]
+impl SchedulerSnapshot {
+    #[new]
+    #[pyo3(signature = (current_time, scheduled_tasks, reservations=None))]
+    fn py_new(
+        current_time: NaiveDate,
+        scheduled_tasks: Vec<ScheduledTask>,
+        reservations: Option<Vec<HeldReservation>>,
+    ) -> Self {
+        Self {
+            current_time,
+            scheduled_tasks,
+            reservations: reservations.unwrap_or_default(),
+        }
+    }
+
+    #[pyo3(name = "to_json")]
+    fn py_to_json(&self) -> String {
+        self.to_json()
+    }
+
+    /// Parse a snapshot written by `to_json`; raises ValueError on bad input.
+    #[staticmethod]
+    #[pyo3(name = "from_json")]
+    fn py_from_json(text: &str) -> PyResult<Self> {
+        Self::from_json(text).map_err(|e| pyo3::exceptions::PyValueError::new_err(e.to_string()))
+    }
+
+    fn __repr__(&self) -> String {
+        format!(
+            "SchedulerSnapshot(current_time={}, scheduled={}, reservations={})",
+            self.current_time,
+            self.scheduled_tasks.len(),
+            self.reservations.len()
+        )
+    }
+}
+
+/// Placements a run starts from: the snapshot's, then fixed tasks it lacks.
+pub(crate) fn starting_placements(
+    snapshot: Option<&SchedulerSnapshot>,
+    fixed_tasks: Vec<ScheduledTask>,
+) -> Vec<ScheduledTask> {
+    let Some(snapshot) = snapshot else {
+        return fixed_tasks;
+    };
+    let mut placements = snapshot.scheduled_tasks.clone();
+    let placed: FxHashSet<&str> = snapshot
+        .scheduled_tasks
+        .iter()
+        .map(|t| t.task_id.as_str())
+        .collect();
+    placements.extend(
+        fixed_tasks
+            .into_iter()
+            .filter(|t| !placed.contains(t.task_id.as_str())),
+    );
+    placements
+}
+
+/// Date a paused run resumes from: the day after its last decision date.
+pub(crate) fn resume_date(pause_at: NaiveDate) -> NaiveDate {
+    pause_at.succ_opt().unwrap_or(pause_at)
+}
+
+fn object<'a>(value: &'a Value, location: &str) -> Result<&'a Map<String, Value>, LoadError> {
+    value.as_object().ok_or_else(|| LoadError::Record {
+        location: location.to_string(),
+        message: format!("expected an object, got {}", value),
+    })
+}
+
+/// Parse an optional field, attaching its location to errors.
+fn field<'a, T>(
+    map: &'a Map<String, Value>,
+    location: &str,
+    name: &str,
+    parse: impl Fn(&'a Value) -> Result<T, String>,
+) -> Result<Option<T>, LoadError> {
+    match map.get(name) {
+        None | Some(Value::Null) => Ok(None),
+        Some(value) => parse(value).map(Some).map_err(|message| LoadError::Field {
+            location: location.to_string(),
+            field: name.to_string(),
+            message,
+        }),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn d(day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(2025, 1, day).unwrap()
+    }
+
+    #[test]
+    fn test_snapshot_json_round_trip() {
+        let snapshot = SchedulerSnapshot {
+            current_time: d(10),
+            scheduled_tasks: vec![ScheduledTask {
+                task_id: "a".to_string(),
+                start_date: d(1),
+                end_date: d(3),
+                duration_days: 3.0,
+                resources: vec!["alice".to_string()],
+                in_progress: true,
+                ..Default::default()
+            }],
+            reservations: vec![HeldReservation {
+                resource: "bob".to_string(),
+                target_id: "launch".to_string(),
+                task_id: "b".to_string(),
+                target_score: 1.5,
+                reserved_from: d(9),
+                release_on: d(14),
+            }],
+        };
+        let restored = SchedulerSnapshot::from_json(&snapshot.to_json()).unwrap();
+        assert_eq!(restored, snapshot);
+
+        let err = SchedulerSnapshot::from_json(
+            r#"{"current_time": "2025-01-10", "scheduled_tasks": [{"task_id": "a", "start_date": "soon"}]}"#,
+        )
+        .unwrap_err();
+        assert!(
+            matches!(err, LoadError::Field { ref location, ref field, .. } if location == "scheduled task 0" && field == "start_date")
+        );
+    }
+}
@@ -18,6 +18,8 @@ pub struct TaskSortInfo {
     pub duration_days: f64,
     pub deadline: Option<NaiveDate>,
     pub priority: i32,
+    /// Backlog position, the last tie-breaker before task ID.
+    pub rank: Option<i64>,
 }
 
 /// Parameters for ATC (Apparent Tardiness Cost) strategy.
@@ -52,22 +54,32 @@ impl std::error::Error for SortingError {}
 /// Implements `Ord` so tasks can be sorted (lower = more urgent).
 #[derive(Debug, Clone, PartialEq)]
 pub enum SortKey {
-    /// Priority-first: (-priority, CR, task_id)
+    /// Priority-first: (-priority, CR, rank, task_id)
     PriorityFirst {
         neg_priority: f64,
         cr: f64,
+        rank: Option<i64>,
         task_id: String,
     },
-    /// CR-first: (CR, -priority, task_id)
+    /// CR-first: (CR, -priority, rank, task_id)
     CRFirst {
         cr: f64,
         neg_priority: f64,
+        rank: Option<i64>,
+        task_id: String,
+    },
+    /// Weighted: (score, rank, task_id)
+    Weighted {
+        score: f64,
+        rank: Option<i64>,
+        task_id: String,
+    },
+    /// ATC: (-atc_score, rank, task_id)
+    ATC {
+        neg_atc: f64,
+        rank: Option<i64>,
         task_id: String,
     },
-    /// Weighted: (score, task_id)
-    Weighted { score: f64, task_id: String },
-    /// ATC: (-atc_score, task_id)
-    ATC { neg_atc: f64, task_id: String },
 }
 
 impl SortKey {
@@ -82,6 +94,11 @@ impl SortKey {
     }
 }
 
+/// Compare backlog ranks: ranked tasks first, lowest rank first.
+pub fn cmp_rank(a: Option<i64>, b: Option<i64>) -> Ordering {
+    (a.is_none(), a).cmp(&(b.is_none(), b))
+}
+
 /// Compare f64 values for sorting, treating NaN as greater than all other values.
 fn cmp_f64(a: f64, b: f64) -> Ordering {
     a.partial_cmp(&b).unwrap_or(Ordering::Equal)
@@ -96,53 +113,67 @@ impl Ord for SortKey {
                 Self::PriorityFirst {
                     neg_priority: p1,
                     cr: cr1,
+                    rank: r1,
                     task_id: id1,
                 },
                 Self::PriorityFirst {
                     neg_priority: p2,
                     cr: cr2,
+                    rank: r2,
                     task_id: id2,
                 },
             ) => cmp_f64(*p1, *p2)
                 .then(cmp_f64(*cr1, *cr2))
+                .then(cmp_rank(*r1, *r2))
                 .then(id1.cmp(id2)),
 
             (
                 Self::CRFirst {
                     cr: cr1,
                     neg_priority: p1,
+                    rank: r1,
                     task_id: id1,
                 },
                 Self::CRFirst {
                     cr: cr2,
                     neg_priority: p2,
+                    rank: r2,
                     task_id: id2,
                 },
             ) => cmp_f64(*cr1, *cr2)
                 .then(cmp_f64(*p1, *p2))
+                .then(cmp_rank(*r1, *r2))
                 .then(id1.cmp(id2)),
 
             (
                 Self::Weighted {
                     score: s1,
+                    rank: r1,
                     task_id: id1,
                 },
                 Self::Weighted {
                     score: s2,
+                    rank: r2,
                     task_id: id2,
                 },
-            ) => cmp_f64(*s1, *s2).then(id1.cmp(id2)),
+            ) => cmp_f64(*s1, *s2)
+                .then(cmp_rank(*r1, *r2))
+                .then(id1.cmp(id2)),
 
             (
                 Self::ATC {
                     neg_atc: a1,
+                    rank: r1,
                     task_id: id1,
                 },
                 Self::ATC {
                     neg_atc: a2,
+                    rank: r2,
                     task_id: id2,
                 },
-            ) => cmp_f64(*a1, *a2).then(id1.cmp(id2)),
+            ) => cmp_f64(*a1, *a2)
+                .then(cmp_rank(*r1, *r2))
+                .then(id1.cmp(id2)),
 
             // Different variants should not be compared, but provide a fallback
             _ => Ordering::Equal,
@@ -229,17 +260,20 @@ pub fn compute_sort_key(
         "priority_first" => Ok(SortKey::PriorityFirst {
             neg_priority: -(priority as f64),
             cr,
+            rank: info.rank,
             task_id: task_id.to_string(),
         }),
         "cr_first" => Ok(SortKey::CRFirst {
             cr,
             neg_priority: -(priority as f64),
+            rank: info.rank,
             task_id: task_id.to_string(),
         }),
         "weighted" => {
             let score = config.cr_weight * cr + config.priority_weight * (100.0 - priority as f64);
             Ok(SortKey::Weighted {
                 score,
+                rank: info.rank,
                 task_id: task_id.to_string(),
             })
         }
@@ -255,6 +289,7 @@ pub fn compute_sort_key(
             );
             Ok(SortKey::ATC {
                 neg_atc: -atc_score,
+                rank: info.rank,
                 task_id: task_id.to_string(),
             })
         }
@@ -310,6 +345,7 @@ mod tests {
             atc_default_urgency_multiplier: 1.0,
             atc_default_urgency_floor: 0.3,
             verbosity: 0,
+            ..SchedulingConfig::default()
         }
     }
 
@@ -355,6 +391,7 @@ mod tests {
                 duration_days: 5.0,
                 deadline: Some(deadline),
                 priority: 90,
+                rank: None,
             },
         );
         tasks.insert(
@@ -363,6 +400,7 @@ mod tests {
                 duration_days: 5.0,
                 deadline: Some(deadline),
                 priority: 30,
+                rank: None,
             },
         );
 
@@ -386,6 +424,7 @@ mod tests {
                 duration_days: 20.0,
                 deadline: Some(make_date(2025, 1, 31)),
                 priority: 50,
+                rank: None,
             },
         );
         // Relaxed deadline (CR = 30/5 = 6.0)
@@ -395,6 +434,7 @@ mod tests {
                 duration_days: 5.0,
                 deadline: Some(make_date(2025, 1, 31)),
                 priority: 50,
+                rank: None,
             },
         );
 
@@ -419,6 +459,7 @@ mod tests {
                 duration_days: 10.0,
                 deadline: Some(deadline),
                 priority: 90,
+                rank: None,
             },
         );
         // Task B: CR=6.0 (30/5), priority=50 -> score = 10*6.0 + 1*(100-50) = 110
@@ -428,6 +469,7 @@ mod tests {
                 duration_days: 5.0,
                 deadline: Some(deadline),
                 priority: 50,
+                rank: None,
             },
         );
 
@@ -456,6 +498,7 @@ mod tests {
                 duration_days: 5.0,
                 deadline: Some(make_date(2025, 1, 6)), // 5 days, slack=0
                 priority: 50,
+                rank: None,
             },
         );
         // Far deadline: low urgency
@@ -465,6 +508,7 @@ mod tests {
                 duration_days: 5.0,
                 deadline: Some(make_date(2025, 2, 28)), // ~60 days
                 priority: 50,
+                rank: None,
             },
         );
 
@@ -494,6 +538,7 @@ mod tests {
                 duration_days: 5.0,
                 deadline: None,
                 priority: 80, // High priority
+                rank: None,
             },
         );
         // Far deadline with low urgency
@@ -503,6 +548,7 @@ mod tests {
                 duration_days: 5.0,
                 deadline: Some(make_date(2025, 6, 30)), // Very far
                 priority: 50,
+                rank: None,
             },
         );
 
@@ -524,6 +570,7 @@ mod tests {
                 duration_days: 5.0,
                 deadline: None,
                 priority: 50,
+                rank: None,
             },
         );
         let result = sort_tasks(
@@ -547,6 +594,7 @@ mod tests {
                 duration_days: 5.0,
                 deadline: None,
                 priority: 50,
+                rank: None,
             },
         );
         let result = sort_tasks(
@@ -589,6 +637,7 @@ mod tests {
                 duration_days: 10.0,
                 deadline: Some(deadline),
                 priority: 50,
+                rank: None,
             },
         );
         tasks.insert(
@@ -597,6 +646,7 @@ mod tests {
                 duration_days: 10.0,
                 deadline: Some(deadline),
                 priority: 50,
+                rank: None,
             },
         );
 
@@ -606,4 +656,33 @@ mod tests {
         // Alphabetical tie-breaker
         assert_eq!(sorted, vec!["task_a", "task_b"]);
     }
+
+    #[test]
+    fn test_rank_breaks_ties_before_task_id() {
+        let config = make_config("priority_first");
+        let info = |rank| TaskSortInfo {
+            duration_days: 10.0,
+            deadline: None,
+            priority: 50,
+            rank,
+        };
+        let mut tasks = FxHashMap::default();
+        tasks.insert("a".to_string(), info(None));
+        tasks.insert("b".to_string(), info(Some(2)));
+        tasks.insert("c".to_string(), info(Some(1)));
+
+        let task_ids = vec!["a".to_string(), "b".to_string(), "c".to_string()];
+        let sorted = sort_tasks(
+            &task_ids,
+            &tasks,
+            make_date(2025, 1, 1),
+            10.0,
+            &config,
+            None,
+        )
+        .unwrap();
+
+        // Ranked tasks in backlog order, unranked last
+        assert_eq!(sorted, vec!["c", "b", "a"]);
+    }
 }
@@ -0,0 +1,342 @@
+//! Parameter tuning for the critical path scheduler.
+//!
+//! Sweeps `k`, `urgency_floor`, `work_exponent` and
+//! `no_deadline_urgency_multiplier` over a grid (or a random sample of it),
+//! schedules every instance with each candidate, and keeps the candidate with
+//! the lowest objective cost summed over the instances. The base configuration
+//! is always tried first, so tuning never returns anything worse than it.
+
+use chrono::NaiveDate;
+use pyo3::prelude::*;
+use rustc_hash::FxHashSet;
+
+use crate::config::ScheduleObjective;
+use crate::critical_path::{CriticalPathConfig, CriticalPathScheduler, CriticalPathSchedulerError};
+use crate::models::Task;
+use crate::pareto::ScheduleMetrics;
+use crate::scheduler::ResourceConfig;
+
+/// One scheduling problem to tune against, such as a past planning snapshot.
+#[derive(Clone, Debug, Default)]
+pub struct TuningInstance {
+    pub tasks: Vec<Task>,
+    pub current_date: NaiveDate,
+    pub completed_task_ids: FxHashSet<String>,
+    pub resource_config: Option<ResourceConfig>,
+    pub global_dns_periods: Vec<(NaiveDate, NaiveDate)>,
+}
+
+/// Values tried for each tuned parameter.
+#[pyclass]
+#[derive(Clone, Debug, PartialEq)]
+pub struct TuningGrid {
+    #[pyo3(get, set)]
+    pub k: Vec<f64>,
+    #[pyo3(get, set)]
+    pub urgency_floor: Vec<f64>,
+    #[pyo3(get, set)]
+    pub work_exponent: Vec<f64>,
+    #[pyo3(get, set)]
+    pub no_deadline_urgency_multiplier: Vec<f64>,
+}
+
+impl Default for TuningGrid {
+    fn default() -> Self {
+        Self {
+            k: vec![1.0, 1.5, 2.0, 3.0, 5.0],
+            urgency_floor: vec![0.05, 0.1, 0.2, 0.3],
+            work_exponent: vec![0.5, 1.0, 1.5],
+            no_deadline_urgency_multiplier: vec![0.25, 0.5, 1.0],
+        }
+    }
+}
+
+impl TuningGrid {
+    /// Every combination, `k` varying slowest. An empty list keeps the base value.
+    fn candidates(&self, base: &CriticalPathConfig) -> Vec<TuningTrial> {
+        let or_base = |values: &[f64], base: f64| {
+            if values.is_empty() {
+                vec![base]
+            } else {
+                values.to_vec()
+            }
+        };
+        let mut candidates = Vec::new();
+        for &k in &or_base(&self.k, base.k) {
+            for &urgency_floor in &or_base(&self.urgency_floor, base.urgency_floor) {
+                for &work_exponent in &or_base(&self.work_exponent, base.work_exponent) {
+                    for &no_deadline_urgency_multiplier in &or_base(
+                        &self.no_deadline_urgency_multiplier,
+                        base.no_deadline_urgency_multiplier,
+                    ) {
+                        candidates.push(TuningTrial {
+                            k,
+                            urgency_floor,
+                            work_exponent,
+                            no_deadline_urgency_multiplier,
+                            score: 0.0,
+                        });
+                    }
+                }
+            }
+        }
+        candidates
+    }
+}
+
+#[pymethods]
+impl TuningGrid {
+    #[new]
+    #[pyo3(signature = (
+        k=None,
+        urgency_floor=None,
+        work_exponent=None,
+        no_deadline_urgency_multiplier=None
+    ))]
+    fn new(
+        k: Option<Vec<f64>>,
+        urgency_floor: Option<Vec<f64>>,
+        work_exponent: Option<Vec<f64>>,
+        no_deadline_urgency_multiplier: Option<Vec<f64>>,
+    ) -> Self {
+        let defaults = Self::default();
+        Self {
+            k: k.unwrap_or(defaults.k),
+            urgency_floor: urgency_floor.unwrap_or(defaults.urgency_floor),
+            work_exponent: work_exponent.unwrap_or(defaults.work_exponent),
+            no_deadline_urgency_multiplier: no_deadline_urgency_multiplier
+                .unwrap_or(defaults.no_deadline_urgency_multiplier),
+        }
+    }
+
+    fn __repr__(&self) -> String {
+        format!(
+            "TuningGrid(k={:?}, urgency_floor={:?}, work_exponent={:?}, no_deadline_urgency_multiplier={:?})",
+            self.k, self.urgency_floor, self.work_exponent, self.no_deadline_urgency_multiplier
+        )
+    }
+}
+
+/// One evaluated parameter combination.
+#[pyclass]
+#[derive(Clone, Debug, PartialEq)]
+pub struct TuningTrial {
+    #[pyo3(get)]
+    pub k: f64,
+    #[pyo3(get)]
+    pub urgency_floor: f64,
+    #[pyo3(get)]
+    pub work_exponent: f64,
+    #[pyo3(get)]
+    pub no_deadline_urgency_multiplier: f64,
+    /// Objective cost summed over all instances (lower is better).
+    #[pyo3(get)]
+    pub score: f64,
+}
+
+impl TuningTrial {
+    fn apply(&self, config: &CriticalPathConfig) -> CriticalPathConfig {
+        CriticalPathConfig {
+            k: self.k,
+            urgency_floor: self.urgency_floor,
+            work_exponent: self.work_exponent,
+            no_deadline_urgency_multiplier: self.no_deadline_urgency_multiplier,
+            ..config.clone()
+        }
+    }
+}
+
+#[pymethods]
+impl TuningTrial {
+    fn __repr__(&self) -> String {
+        format!(
+            "TuningTrial(k={}, urgency_floor={}, work_exponent={}, no_deadline_urgency_multiplier={}, score={:.2})",
+            self.k,
+            self.urgency_floor,
+            self.work_exponent,
+            self.no_deadline_urgency_multiplier,
+            self.score
+        )
+    }
+}
+
+/// Best configuration found and every trial, best first.
+#[pyclass]
+#[derive(Clone, Debug)]
+pub struct TuningResult {
+    #[pyo3(get)]
+    pub best_config: CriticalPathConfig,
+    #[pyo3(get)]
+    pub best_score: f64,
+    /// Trials sorted by score; ties keep evaluation order, base first.
+    #[pyo3(get)]
+    pub trials: Vec<TuningTrial>,
+}
+
+#[pymethods]
+impl TuningResult {
+    fn __repr__(&self) -> String {
+        format!(
+            "TuningResult(best_score={:.2}, trials={})",
+            self.best_score,
+            self.trials.len()
+        )
+    }
+}
+
+/// Objective cost of a measured schedule.
+///
+/// Uses the same weights as rollout scoring, applied to the whole schedule.
+pub fn objective_cost(objective: &ScheduleObjective, metrics: &ScheduleMetrics) -> f64 {
+    // ScheduleMetrics weights tasks by priority / 100
+    let scale = 100.0 / objective.priority_scale;
+    objective.flow_time_weight * metrics.weighted_flow_time * scale
+        + objective.tardiness_weight * metrics.weighted_tardiness * scale
+        + objective.makespan_weight * metrics.makespan_days
+        + objective.idle_time_weight * metrics.idle_days
+}
+
+/// Pick `samples` distinct indices out of `0..len` in ascending order.
+fn sample_indices(len: usize, samples: usize, seed: u64) -> Vec<usize> {
+    let mut indices: Vec<usize> = (0..len).collect();
+    let mut state = seed ^ 0x9E37_79B9_7F4A_7C15;
+    for i in 0..samples.min(len) {
+        // xorshift64*
+        state ^= state >> 12;
+        state ^= state << 25;
+        state ^= state >> 27;
+        let r = state.wrapping_mul(0x2545_F491_4F6C_DD1D);
+        let j = i + (r % (len - i) as u64) as usize;
+        indices.swap(i, j);
+    }
+    indices.truncate(samples);
+    indices.sort_unstable();
+    indices
+}
+
+/// Search the grid for the configuration with the lowest total objective cost.
+///
+/// With `samples`, only that many grid points (chosen by `seed`) are tried
+/// in addition to `base`. Cost uses `base.objective`; every other setting of
+/// `base` is kept.
+pub fn tune_critical_path(
+    instances: &[TuningInstance],
+    base: &CriticalPathConfig,
+    default_priority: i32,
+    grid: &TuningGrid,
+    samples: Option<usize>,
+    seed: u64,
+) -> Result<TuningResult, CriticalPathSchedulerError> {
+    let mut candidates = grid.candidates(base);
+    if let Some(samples) = samples {
+        candidates = sample_indices(candidates.len(), samples, seed)
+            .into_iter()
+            .map(|i| candidates[i].clone())
+            .collect();
+    }
+    candidates.insert(
+        0,
+        TuningTrial {
+            k: base.k,
+            urgency_floor: base.urgency_floor,
+            work_exponent: base.work_exponent,
+            no_deadline_urgency_multiplier: base.no_deadline_urgency_multiplier,
+            score: 0.0,
+        },
+    );
+
+    let mut trials = Vec::with_capacity(candidates.len());
+    for mut trial in candidates {
+        let config = trial.apply(base);
+        for instance in instances {
+            let result = CriticalPathScheduler::new(
+                instance.tasks.clone(),
+                instance.current_date,
+                instance.completed_task_ids.clone(),
+                default_priority,
+                config.clone(),
+                instance.resource_config.clone(),
+                instance.global_dns_periods.clone(),
+            )?
+            .schedule()?;
+            let metrics = ScheduleMetrics::measure(
+                &result,
+                &instance.tasks,
+                instance.current_date,
+                default_priority,
+                instance.resource_config.as_ref(),
+            );
+            trial.score += objective_cost(&base.objective, &metrics);
+        }
+        trials.push(trial);
+    }
+    trials.sort_by(|a, b| a.score.total_cmp(&b.score));
+    Ok(TuningResult {
+        best_config: trials[0].apply(base),
+        best_score: trials[0].score,
+        trials,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::Dependency;
+
+    fn d(day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(2025, 1, day).unwrap()
+    }
+
+    #[test]
+    fn test_sample_indices_distinct_and_seeded() {
+        let picked = sample_indices(20, 5, 7);
+        assert_eq!(picked.len(), 5);
+        assert!(picked.windows(2).all(|w| w[0] < w[1]));
+        assert_eq!(picked, sample_indices(20, 5, 7));
+        assert_eq!(sample_indices(3, 10, 1), vec![0, 1, 2]);
+    }
+
+    #[test]
+    fn test_tune_keeps_base_and_sorts_trials() {
+        let task =
+            |id: &str, duration_days: f64, deps: &[&str], end_before: Option<NaiveDate>| Task {
+                id: id.to_string(),
+                duration_days,
+                resources: vec![("alice".to_string(), 1.0)],
+                dependencies: deps
+                    .iter()
+                    .map(|dep| Dependency {
+                        entity_id: dep.to_string(),
+                        ..Default::default()
+                    })
+                    .collect(),
+                end_before,
+                ..Default::default()
+            };
+        let instance = TuningInstance {
+            tasks: vec![
+                task("long", 8.0, &[], None),
+                task("short", 2.0, &[], Some(d(5))),
+                task("after", 1.0, &["short"], None),
+            ],
+            current_date: d(1),
+            ..Default::default()
+        };
+        let grid = TuningGrid {
+            k: vec![0.5, 4.0],
+            urgency_floor: vec![],
+            work_exponent: vec![0.5, 2.0],
+            no_deadline_urgency_multiplier: vec![],
+        };
+        let base = CriticalPathConfig::default();
+        let result = tune_critical_path(&[instance], &base, 50, &grid, None, 0).unwrap();
+
+        assert_eq!(result.trials.len(), 5);
+        assert!(result.trials.windows(2).all(|w| w[0].score <= w[1].score));
+        assert_eq!(result.best_score, result.trials[0].score);
+        assert_eq!(result.best_config.k, result.trials[0].k);
+        assert_eq!(result.best_config.urgency_floor, base.urgency_floor);
+        let base_trial = result.trials.iter().find(|t| t.k == base.k).unwrap();
+        assert!(result.best_score <= base_trial.score);
+    }
+}
@@ -0,0 +1,1416 @@
+//! Up-front validation of task graphs.
+//!
+//! `validate_tasks` collects every problem it can find in one pass so callers
+//! can report them together instead of failing partway through scheduling.
+//! `check_feasibility` compares required work with resource capacity so plans
+//! that cannot fit a horizon fail before the full scheduler runs.
+
+use chrono::{Days, NaiveDate};
+use pyo3::prelude::*;
+use rustc_hash::{FxHashMap, FxHashSet};
+use thiserror::Error;
+
+use crate::backward_pass::{backward_pass, BackwardPassConfig};
+use crate::config::{FixedConflictPolicy, UnknownDependencyPolicy, UnschedulablePolicy};
+use crate::graph::topological_order;
+use crate::models::{Dependency, ScheduleWarning, ScheduledTask, Task, UnschedulableTask};
+use crate::scheduler::ResourceConfig;
+
+/// A single problem found while validating tasks.
+#[pyclass]
+#[derive(Clone, Debug, PartialEq)]
+pub struct Diagnostic {
+    /// Machine-readable problem code, e.g. "dangling_dependency".
+    #[pyo3(get)]
+    pub code: String,
+    /// Task the problem belongs to, if any.
+    #[pyo3(get)]
+    pub task_id: Option<String>,
+    /// Human-readable description.
+    #[pyo3(get)]
+    pub message: String,
+}
+
+impl Diagnostic {
+    fn new(code: &str, task_id: &str, message: String) -> Self {
+        Self {
+            code: code.to_string(),
+            task_id: Some(task_id.to_string()),
+            message,
+        }
+    }
+}
+
+#[pymethods]
+impl Diagnostic {
+    fn __repr__(&self) -> String {
+        format!(
+            "Diagnostic(code={:?}, task_id={:?}, message={:?})",
+            self.code, self.task_id, self.message
+        )
+    }
+}
+
+/// Check tasks for problems that would make scheduling fail or behave unexpectedly.
+///
+/// Reported codes:
+/// - `duplicate_id`: more than one task shares an ID
+/// - `dangling_dependency`: a dependency names a task that does not exist
+/// - `circular_dependency`: the dependency graph contains a cycle
+/// - `negative_duration`: `duration_days` is negative or not finite
+/// - `invalid_allocation`: a resource allocation is not positive
+/// - `spec_and_resources`: both `resource_spec` and explicit resources are set
+/// - `unknown_resource`: a resource is not in `resource_order` (needs `resource_config`)
+/// - `unsatisfiable_resource_spec`: a spec matches no resource (needs `resource_config`)
+/// - `conflicting_fixed_dates`: `start_on` is after `end_on`
+/// - `empty_window`: `start_after` is after `end_before`
+/// - `date_out_of_range`: durations, lags or dates would push deadline or
+///   schedule dates outside the supported range
+///
+/// Returns an empty list when no problems are found.
+pub fn validate_tasks(tasks: &[Task], resource_config: Option<&ResourceConfig>) -> Vec<Diagnostic> {
+    let mut diagnostics: Vec<Diagnostic> = Vec::new();
+
+    let counts = id_counts(tasks);
+    let mut reported: FxHashSet<&str> = FxHashSet::default();
+    for task in tasks {
+        let count = counts[task.id.as_str()];
+        if count > 1 && reported.insert(task.id.as_str()) {
+            diagnostics.push(Diagnostic::new(
+                "duplicate_id",
+                &task.id,
+                format!("Task ID '{}' is used by {} tasks", task.id, count),
+            ));
+        }
+    }
+
+    let known_resources: Option<FxHashSet<&str>> =
+        resource_config.map(|rc| rc.resource_order.iter().map(|s| s.as_str()).collect());
+
+    for task in tasks {
+        for dep in &task.dependencies {
+            if !counts.contains_key(dep.entity_id.as_str()) {
+                diagnostics.push(Diagnostic::new(
+                    "dangling_dependency",
+                    &task.id,
+                    format!(
+                        "Task '{}' depends on unknown task '{}'",
+                        task.id, dep.entity_id
+                    ),
+                ));
+            }
+        }
+
+        if !task.duration_days.is_finite() || task.duration_days < 0.0 {
+            diagnostics.push(Diagnostic::new(
+                "negative_duration",
+                &task.id,
+                format!(
+                    "Task '{}' has invalid duration {}",
+                    task.id, task.duration_days
+                ),
+            ));
+        }
+
+        for (resource, allocation) in &task.resources {
+            if *allocation <= 0.0 || !allocation.is_finite() {
+                diagnostics.push(Diagnostic::new(
+                    "invalid_allocation",
+                    &task.id,
+                    format!(
+                        "Task '{}' allocates {} of resource '{}'",
+                        task.id, allocation, resource
+                    ),
+                ));
+            }
+            if let Some(known) = &known_resources {
+                if !known.contains(resource.as_str()) {
+                    diagnostics.push(Diagnostic::new(
+                        "unknown_resource",
+                        &task.id,
+                        format!(
+                            "Task '{}' references unknown resource '{}'",
+                            task.id, resource
+                        ),
+                    ));
+                }
+            }
+        }
+
+        if let Some(spec) = &task.resource_spec {
+            if !task.resources.is_empty() {
+                diagnostics.push(Diagnostic::new(
+                    "spec_and_resources",
+                    &task.id,
+                    format!(
+                        "Task '{}' has both resource_spec '{}' and explicit resources",
+                        task.id, spec
+                    ),
+                ));
+            }
+            if let (Some(rc), Some(known)) = (resource_config, &known_resources) {
+                let candidates = rc.expand_resource_spec_with_skills(spec, &task.required_skills);
+                for name in candidates.iter().filter(|n| !known.contains(n.as_str())) {
+                    diagnostics.push(Diagnostic::new(
+                        "unknown_resource",
+                        &task.id,
+                        format!(
+                            "Task '{}' resource_spec '{}' names unknown resource '{}'",
+                            task.id, spec, name
+                        ),
+                    ));
+                }
+                if candidates.is_empty() {
+                    diagnostics.push(Diagnostic::new(
+                        "unsatisfiable_resource_spec",
+                        &task.id,
+                        format!(
+                            "Task '{}' resource_spec '{}' matches no resource",
+                            task.id, spec
+                        ),
+                    ));
+                }
+            }
+        }
+
+        if let (Some(start), Some(end)) = (task.start_on, task.end_on) {
+            if start > end {
+                diagnostics.push(Diagnostic::new(
+                    "conflicting_fixed_dates",
+                    &task.id,
+                    format!(
+                        "Task '{}' has start_on {} after end_on {}",
+                        task.id, start, end
+                    ),
+                ));
+            }
+        }
+
+        if let (Some(after), Some(before)) = (task.start_after, task.end_before) {
+            if after > before {
+                diagnostics.push(Diagnostic::new(
+                    "empty_window",
+                    &task.id,
+                    format!(
+                        "Task '{}' has start_after {} after end_before {}",
+                        task.id, after, before
+                    ),
+                ));
+            }
+        }
+    }
+
+    // Without a current date, only the input's own dates bound the range
+    let out_of_range = date_range_errors(tasks, NaiveDate::MIN, &[]);
+    diagnostics.extend(out_of_range.iter().map(|message| Diagnostic {
+        code: "date_out_of_range".to_string(),
+        task_id: None,
+        message: message.clone(),
+    }));
+    // The backward pass would overflow on such input, so only look for cycles
+    let graph_error = if out_of_range.is_empty() {
+        backward_pass(tasks, &FxHashSet::default(), &BackwardPassConfig::default())
+            .err()
+            .map(|err| (err.cycle().map(<[String]>::to_vec), err.to_string()))
+    } else {
+        topological_order(tasks)
+            .err()
+            .map(|err| (err.cycle().map(<[String]>::to_vec), err.to_string()))
+    };
+    if let Some((cycle, message)) = graph_error {
+        diagnostics.push(Diagnostic {
+            code: if cycle.is_some() {
+                "circular_dependency"
+            } else {
+                "date_out_of_range"
+            }
+            .to_string(),
+            task_id: cycle.and_then(|cycle| cycle.first().cloned()),
+            message,
+        });
+    }
+
+    diagnostics
+}
+
+/// Work assigned to a resource (or a spec's candidate pool) beyond its capacity.
+#[pyclass]
+#[derive(Clone, Debug, PartialEq)]
+pub struct ResourceOverload {
+    /// Resource name, or the resource spec for auto-assigned work.
+    #[pyo3(get)]
+    pub resource: String,
+    /// Working days of work required, after efficiency.
+    #[pyo3(get)]
+    pub required_days: f64,
+    /// Working days available in the horizon, after DNS and capacity.
+    #[pyo3(get)]
+    pub available_days: f64,
+    /// `required_days - available_days`.
+    #[pyo3(get)]
+    pub overload_days: f64,
+}
+
+#[pymethods]
+impl ResourceOverload {
+    fn __repr__(&self) -> String {
+        format!(
+            "ResourceOverload(resource={:?}, required_days={:.2}, available_days={:.2}, overload_days={:.2})",
+            self.resource, self.required_days, self.available_days, self.overload_days
+        )
+    }
+}
+
+/// Days in `[start, end)` a resource can work, scaled by its capacity.
+fn available_days(
+    resource_config: &ResourceConfig,
+    resource: &str,
+    (start, end): (NaiveDate, NaiveDate),
+    global_dns_periods: &[(NaiveDate, NaiveDate)],
+) -> f64 {
+    let dns = resource_config.get_dns_periods(resource, global_dns_periods);
+    start
+        .iter_days()
+        .take_while(|day| *day < end)
+        .filter(|day| !dns.iter().any(|(s, e)| s <= day && day <= e))
+        .map(|day| resource_config.capacity_on(resource, day))
+        .sum()
+}
+
+/// Compare required work per resource with its capacity over `horizon`.
+///
+/// Explicitly assigned work counts toward each listed resource. Work assigned
+/// by `resource_spec` is checked against the spec's candidates as a pool,
+/// together with work assigned to those candidates explicitly. Both checks are
+/// necessary conditions only: an empty result does not guarantee the scheduler
+/// meets the horizon. Returns overloaded resources, largest overload first.
+pub fn check_feasibility(
+    tasks: &[Task],
+    resource_config: &ResourceConfig,
+    horizon: (NaiveDate, NaiveDate),
+    global_dns_periods: &[(NaiveDate, NaiveDate)],
+) -> Vec<ResourceOverload> {
+    let mut explicit: FxHashMap<&str, f64> = FxHashMap::default();
+    let mut by_spec: FxHashMap<&str, (f64, Vec<String>)> = FxHashMap::default();
+    for task in tasks {
+        if task.resources.is_empty() {
+            if let Some(spec) = &task.resource_spec {
+                let candidates = resource_config.candidates_for(task);
+                let fastest = candidates
+                    .iter()
+                    .map(|r| resource_config.efficiency_for(r, &task.required_skills))
+                    .fold(1.0_f64, f64::max);
+                let entry = by_spec.entry(spec.as_str()).or_default();
+                entry.0 += task.duration_days / fastest;
+                for candidate in candidates {
+                    if !entry.1.contains(&candidate) {
+                        entry.1.push(candidate);
+                    }
+                }
+            }
+            continue;
+        }
+        for (resource, _) in &task.resources {
+            let efficiency = resource_config.efficiency_for(resource, &task.required_skills);
+            *explicit.entry(resource.as_str()).or_default() += task.duration_days / efficiency;
+        }
+    }
+
+    let mut capacity: FxHashMap<String, f64> = FxHashMap::default();
+    let mut available = |resource: &str| {
+        *capacity.entry(resource.to_string()).or_insert_with(|| {
+            available_days(resource_config, resource, horizon, global_dns_periods)
+        })
+    };
+    let mut overloads = Vec::new();
+    let mut push = |resource: &str, required_days: f64, available_days: f64| {
+        if required_days > available_days + 1e-9 {
+            overloads.push(ResourceOverload {
+                resource: resource.to_string(),
+                required_days,
+                available_days,
+                overload_days: required_days - available_days,
+            });
+        }
+    };
+    for (&resource, &required) in &explicit {
+        push(resource, required, available(resource));
+    }
+    for (&spec, (required, candidates)) in &by_spec {
+        let pool_required = required
+            + candidates
+                .iter()
+                .filter_map(|r| explicit.get(r.as_str()))
+                .sum::<f64>();
+        let pool_available = candidates.iter().map(|r| available(r)).sum();
+        push(spec, pool_required, pool_available);
+    }
+
+    overloads.sort_by(|a, b| {
+        b.overload_days
+            .total_cmp(&a.overload_days)
+            .then_with(|| a.resource.cmp(&b.resource))
+    });
+    overloads
+}
+
+/// DNS periods ending on or after this date are treated as never ending.
+const OPEN_ENDED_DNS: NaiveDate = match NaiveDate::from_ymd_opt(9999, 12, 31) {
+    Some(date) => date,
+    None => panic!("invalid date"),
+};
+
+/// IDs of placed tasks, for `leftover_task_ids` and `relax_unsatisfiable_dependencies`.
+pub(crate) fn placed_ids<'a>(
+    scheduled: impl IntoIterator<Item = &'a ScheduledTask>,
+) -> FxHashSet<&'a str> {
+    scheduled.into_iter().map(|t| t.task_id.as_str()).collect()
+}
+
+/// IDs of tasks neither `placed` nor completed, sorted.
+pub(crate) fn leftover_task_ids(
+    tasks: &FxHashMap<String, Task>,
+    placed: &FxHashSet<&str>,
+    completed_task_ids: &FxHashSet<String>,
+) -> Vec<String> {
+    let mut leftover: Vec<String> = tasks
+        .keys()
+        .filter(|id| !placed.contains(id.as_str()) && !completed_task_ids.contains(*id))
+        .cloned()
+        .collect();
+    leftover.sort();
+    leftover
+}
+
+/// Drop dependencies of `leftover` tasks that can never be satisfied.
+///
+/// Dependencies on unknown tasks, and on leftover tasks that are not waiting
+/// on dependencies themselves, are dropped. Dependencies on leftover tasks
+/// that were only waiting are kept, since relaxing those frees them too.
+/// Returns the IDs of tasks that lost a dependency, sorted.
+pub(crate) fn relax_unsatisfiable_dependencies(
+    tasks: &mut FxHashMap<String, Task>,
+    leftover: &[String],
+    placed: &FxHashSet<&str>,
+    completed_task_ids: &FxHashSet<String>,
+) -> Vec<String> {
+    let met = |dep: &str| completed_task_ids.contains(dep) || placed.contains(dep);
+    let waiting: FxHashSet<String> = leftover
+        .iter()
+        .filter(|id| {
+            tasks.get(*id).is_some_and(|task| {
+                task.dependencies
+                    .iter()
+                    .any(|dep| !met(dep.entity_id.as_str()))
+            })
+        })
+        .cloned()
+        .collect();
+    let mut relaxed = Vec::new();
+    for id in leftover {
+        let Some(task) = tasks.get_mut(id) else {
+            continue;
+        };
+        let before = task.dependencies.len();
+        task.dependencies
+            .retain(|dep| met(dep.entity_id.as_str()) || waiting.contains(&dep.entity_id));
+        if task.dependencies.len() < before {
+            relaxed.push(id.clone());
+        }
+    }
+    relaxed
+}
+
+/// Under best effort, a copy of `tasks` for rescheduling with unsatisfiable
+/// dependencies of the tasks `placements` left over dropped, and the IDs of
+/// the tasks that lost one.
+///
+/// Returns `None` under other policies or when nothing can be relaxed. The
+/// scheduler's own tasks are left untouched so later runs start from them.
+pub(crate) fn best_effort_relaxation<'a>(
+    policy: UnschedulablePolicy,
+    tasks: &FxHashMap<String, Task>,
+    placements: impl IntoIterator<Item = &'a ScheduledTask>,
+    completed_task_ids: &FxHashSet<String>,
+) -> Option<(FxHashMap<String, Task>, Vec<String>)> {
+    if policy != UnschedulablePolicy::BestEffort {
+        return None;
+    }
+    let placed = placed_ids(placements);
+    let leftover = leftover_task_ids(tasks, &placed, completed_task_ids);
+    if leftover.is_empty() {
+        return None;
+    }
+    let mut relaxed_tasks = tasks.clone();
+    let relaxed = relax_unsatisfiable_dependencies(
+        &mut relaxed_tasks,
+        &leftover,
+        &placed,
+        completed_task_ids,
+    );
+    (!relaxed.is_empty()).then_some((relaxed_tasks, relaxed))
+}
+
+/// The tasks `placements` left over, with the reason each could not be
+/// scheduled; empty under `UnschedulablePolicy::Error`.
+pub(crate) fn unschedulable_tasks<'a>(
+    policy: UnschedulablePolicy,
+    tasks: &FxHashMap<String, Task>,
+    placements: impl IntoIterator<Item = &'a ScheduledTask>,
+    completed_task_ids: &FxHashSet<String>,
+    resource_config: Option<&ResourceConfig>,
+    global_dns_periods: &[(NaiveDate, NaiveDate)],
+) -> Vec<UnschedulableTask> {
+    if policy == UnschedulablePolicy::Error {
+        return Vec::new();
+    }
+    let leftover = leftover_task_ids(tasks, &placed_ids(placements), completed_task_ids);
+    classify_unschedulable(
+        &leftover,
+        tasks,
+        completed_task_ids,
+        resource_config,
+        global_dns_periods,
+    )
+}
+
+/// The first resource `a` and `b` both use while their dates overlap.
+fn shared_overlap<'a>(a: &'a ScheduledTask, b: &ScheduledTask) -> Option<&'a str> {
+    if a.start_date > b.end_date || b.start_date > a.end_date {
+        return None;
+    }
+    a.resources
+        .iter()
+        .find(|r| b.resources.contains(r))
+        .map(String::as_str)
+}
+
+/// Apply `policy` to fixed tasks that overlap on a resource.
+///
+/// Tasks are taken in order of start date, then ID. Under `Shift`, each task
+/// that collides with an earlier one moves to the day after it, keeping its
+/// calendar span. Returns the warnings to report, or under `Error` a
+/// description of every overlap.
+pub(crate) fn resolve_fixed_conflicts(
+    fixed: &mut [ScheduledTask],
+    policy: FixedConflictPolicy,
+) -> Result<Vec<ScheduleWarning>, Vec<String>> {
+    if policy == FixedConflictPolicy::Ignore {
+        return Ok(Vec::new());
+    }
+    let mut order: Vec<usize> = (0..fixed.len())
+        .filter(|&i| !fixed[i].resources.is_empty())
+        .collect();
+    order.sort_by(|&a, &b| {
+        (fixed[a].start_date, &fixed[a].task_id).cmp(&(fixed[b].start_date, &fixed[b].task_id))
+    });
+
+    let mut warnings = Vec::new();
+    let mut overlaps = Vec::new();
+    for (n, &i) in order.iter().enumerate() {
+        if policy == FixedConflictPolicy::Shift {
+            let original = fixed[i].start_date;
+            let mut blockers: Vec<String> = Vec::new();
+            while let Some(&p) = order[..n]
+                .iter()
+                .find(|&&p| shared_overlap(&fixed[p], &fixed[i]).is_some())
+            {
+                let span = (fixed[i].end_date - fixed[i].start_date).num_days().max(0) as u64;
+                let Some(start) = fixed[p].end_date.succ_opt() else {
+                    break;
+                };
+                fixed[i].start_date = start;
+                fixed[i].end_date = start
+                    .checked_add_days(Days::new(span))
+                    .unwrap_or(NaiveDate::MAX);
+                blockers.push(fixed[p].task_id.clone());
+            }
+            if !blockers.is_empty() {
+                let message = format!(
+                    "Fixed task '{}' moved from {} to {} to avoid {}",
+                    fixed[i].task_id,
+                    original,
+                    fixed[i].start_date,
+                    blockers.join(", ")
+                );
+                let mut task_ids = vec![fixed[i].task_id.clone()];
+                task_ids.extend(blockers);
+                warnings.push(ScheduleWarning {
+                    code: "fixed_conflict_shifted".to_string(),
+                    task_ids,
+                    message,
+                });
+            }
+            continue;
+        }
+        for &p in &order[..n] {
+            let (a, b) = (&fixed[p], &fixed[i]);
+            if let Some(resource) = shared_overlap(a, b) {
+                let message = format!(
+                    "Fixed tasks '{}' and '{}' both use {} from {} to {}",
+                    b.task_id,
+                    a.task_id,
+                    resource,
+                    a.start_date.max(b.start_date),
+                    a.end_date.min(b.end_date)
+                );
+                warnings.push(ScheduleWarning {
+                    code: "fixed_conflict".to_string(),
+                    task_ids: vec![b.task_id.clone(), a.task_id.clone()],
+                    message: message.clone(),
+                });
+                overlaps.push(message);
+            }
+        }
+    }
+    if policy == FixedConflictPolicy::Error && !overlaps.is_empty() {
+        return Err(overlaps);
+    }
+    Ok(warnings)
+}
+
+/// Warn about inputs for which an empty or meaningless schedule is the expected result.
+///
+/// Reported codes, with no task IDs:
+/// - `no_tasks`: the task list is empty
+/// - `all_completed`: every task is completed, so nothing is left to schedule
+/// - `current_date_after_all_dates`: every date constraint of the incomplete
+///   tasks lies before `current_date`, usually a mistyped or stale current date
+///
+/// Scheduling still succeeds in these cases; the warnings only explain the result.
+pub(crate) fn input_warnings<'a>(
+    tasks: impl IntoIterator<Item = &'a Task>,
+    current_date: NaiveDate,
+    completed_task_ids: &FxHashSet<String>,
+) -> Vec<ScheduleWarning> {
+    let warning = |code: &str, message: String| ScheduleWarning {
+        code: code.to_string(),
+        task_ids: Vec::new(),
+        message,
+    };
+    let mut total = 0;
+    let mut incomplete = 0;
+    let mut latest: Option<NaiveDate> = None;
+    for task in tasks {
+        total += 1;
+        if completed_task_ids.contains(&task.id) {
+            continue;
+        }
+        incomplete += 1;
+        let dates = [
+            task.start_after,
+            task.end_before,
+            task.start_on,
+            task.end_on,
+            task.external_date,
+        ];
+        let targets = task.deadline_targets.iter().map(|(_, date)| Some(*date));
+        latest = dates
+            .into_iter()
+            .chain(targets)
+            .flatten()
+            .chain(latest)
+            .max();
+    }
+    if total == 0 {
+        return vec![warning("no_tasks", "No tasks to schedule".to_string())];
+    }
+    if incomplete == 0 {
+        return vec![warning(
+            "all_completed",
+            format!("All {total} tasks are completed; nothing to schedule"),
+        )];
+    }
+    match latest {
+        Some(latest) if latest < current_date => vec![warning(
+            "current_date_after_all_dates",
+            format!(
+                "Current date {current_date} is after every task date constraint (latest {latest})"
+            ),
+        )],
+        _ => Vec::new(),
+    }
+}
+
+/// Warn about task definitions that schedule but probably do not mean what they say.
+///
+/// Reported codes:
+/// - `spec_and_resources`: both `resource_spec` and explicit resources are set
+/// - `past_deadline`: `end_before` is before `current_date`
+/// - `milestone_with_resources`: a zero-duration task lists resources, which it never uses
+///
+/// Completed tasks are skipped. Warnings are sorted by task ID.
+pub(crate) fn task_warnings<'a>(
+    tasks: impl IntoIterator<Item = &'a Task>,
+    current_date: NaiveDate,
+    completed_task_ids: &FxHashSet<String>,
+) -> Vec<ScheduleWarning> {
+    let mut warnings = Vec::new();
+    let mut warn = |code: &str, task: &Task, message: String| {
+        warnings.push(ScheduleWarning {
+            code: code.to_string(),
+            task_ids: vec![task.id.clone()],
+            message,
+        })
+    };
+    for task in tasks {
+        if completed_task_ids.contains(&task.id) {
+            continue;
+        }
+        if let (Some(spec), false) = (&task.resource_spec, task.resources.is_empty()) {
+            warn(
+                "spec_and_resources",
+                task,
+                format!(
+                    "Task '{}' has both resource_spec '{}' and explicit resources",
+                    task.id, spec
+                ),
+            );
+        }
+        if let Some(deadline) = task.end_before.filter(|d| *d < current_date) {
+            warn(
+                "past_deadline",
+                task,
+                format!(
+                    "Task '{}' has end_before {}, before the current date {}",
+                    task.id, deadline, current_date
+                ),
+            );
+        }
+        if task.duration_days == 0.0 && !task.resources.is_empty() {
+            warn(
+                "milestone_with_resources",
+                task,
+                format!(
+                    "Milestone '{}' lists resources, which zero-duration tasks do not use",
+                    task.id
+                ),
+            );
+        }
+    }
+    warnings.sort_by(|a, b| a.task_ids.cmp(&b.task_ids));
+    warnings
+}
+
+/// Warn about fixed tasks that start before one of their dependencies allows.
+///
+/// A dependency allows its dependent to start the day after it ends plus the
+/// lag; working-day lags skip `lag_calendar`. `dependencies` holds the
+/// dependencies of each fixed task; in-progress tasks and dependencies that
+/// were not placed are skipped.
+pub(crate) fn fixed_dependency_violations(
+    fixed: &[ScheduledTask],
+    scheduled: &[ScheduledTask],
+    dependencies: &FxHashMap<String, Vec<Dependency>>,
+    lag_calendar: &[(NaiveDate, NaiveDate)],
+) -> Vec<ScheduleWarning> {
+    let ends: FxHashMap<&str, NaiveDate> = fixed
+        .iter()
+        .chain(scheduled)
+        .map(|t| (t.task_id.as_str(), t.end_date))
+        .collect();
+    let mut warnings = Vec::new();
+    for task in fixed.iter().filter(|t| !t.in_progress) {
+        for dep in dependencies.get(&task.task_id).into_iter().flatten() {
+            let Some(&dep_end) = ends.get(dep.entity_id.as_str()) else {
+                continue;
+            };
+            let allowed = dep.ready_date(dep_end, lag_calendar);
+            if task.start_date < allowed {
+                warnings.push(ScheduleWarning {
+                    code: "dependency_violation".to_string(),
+                    task_ids: vec![task.task_id.clone(), dep.entity_id.clone()],
+                    message: format!(
+                        "Fixed task '{}' starts {}, before dependency '{}' allows ({})",
+                        task.task_id, task.start_date, dep.entity_id, allowed
+                    ),
+                });
+            }
+        }
+    }
+    warnings.sort_by(|a, b| a.task_ids.cmp(&b.task_ids));
+    warnings
+}
+
+/// Explain why each `leftover` task could not be scheduled.
+///
+/// Dependency problems are reported first, then resource specs matching no
+/// resource, then resources whose DNS never ends. Anything else is "blocked".
+pub(crate) fn classify_unschedulable(
+    leftover: &[String],
+    tasks: &FxHashMap<String, Task>,
+    completed_task_ids: &FxHashSet<String>,
+    resource_config: Option<&ResourceConfig>,
+    global_dns_periods: &[(NaiveDate, NaiveDate)],
+) -> Vec<UnschedulableTask> {
+    let leftover_set: FxHashSet<&str> = leftover.iter().map(|s| s.as_str()).collect();
+    let dns_forever = |resource: &str| {
+        let periods = match resource_config {
+            Some(rc) => rc.get_dns_periods(resource, global_dns_periods),
+            None => global_dns_periods.to_vec(),
+        };
+        periods.iter().any(|(_, end)| *end >= OPEN_ENDED_DNS)
+    };
+
+    let mut result = Vec::with_capacity(leftover.len());
+    for id in leftover {
+        let Some(task) = tasks.get(id) else {
+            continue;
+        };
+        let entry = |reason: &str, detail: String| UnschedulableTask {
+            task_id: id.clone(),
+            reason: reason.to_string(),
+            detail,
+        };
+
+        let blocking_dep = task
+            .dependencies
+            .iter()
+            .map(|dep| dep.entity_id.as_str())
+            .find(|dep| {
+                !completed_task_ids.contains(*dep)
+                    && (leftover_set.contains(dep) || !tasks.contains_key(*dep))
+            });
+        if let Some(dep) = blocking_dep {
+            let detail = if leftover_set.contains(dep) {
+                format!("Depends on unschedulable task '{}'", dep)
+            } else {
+                format!("Depends on unknown task '{}'", dep)
+            };
+            result.push(entry("unsatisfiable_dependency", detail));
+            continue;
+        }
+
+        let configured = |resource: &str| {
+            resource_config.is_none_or(|rc| {
+                rc.resource_order.is_empty() || rc.resource_order.iter().any(|r| r == resource)
+            })
+        };
+        let candidates: Vec<String> = if !task.resources.is_empty() {
+            if let Some((resource, _)) = task.resources.iter().find(|(r, _)| !configured(r)) {
+                result.push(entry(
+                    "no_resource",
+                    format!("Resource '{}' is not configured", resource),
+                ));
+                continue;
+            }
+            task.resources.iter().map(|(r, _)| r.clone()).collect()
+        } else {
+            match (&task.resource_spec, resource_config) {
+                (Some(spec), Some(rc)) => {
+                    let candidates: Vec<String> = rc
+                        .candidates_for(task)
+                        .into_iter()
+                        .filter(|r| configured(r))
+                        .collect();
+                    if candidates.is_empty() {
+                        result.push(entry(
+                            "no_resource",
+                            format!("Resource spec '{}' matches no resource", spec),
+                        ));
+                        continue;
+                    }
+                    candidates
+                }
+                _ => Vec::new(),
+            }
+        };
+        let blocked_forever = if task.resources.is_empty() {
+            !candidates.is_empty() && candidates.iter().all(|r| dns_forever(r))
+        } else {
+            candidates.iter().any(|r| dns_forever(r))
+        };
+        if blocked_forever {
+            result.push(entry(
+                "dns_forever",
+                format!(
+                    "No working days left for resource(s) {}",
+                    candidates.join(", ")
+                ),
+            ));
+            continue;
+        }
+
+        result.push(entry(
+            "blocked",
+            "Could not be placed before scheduling stopped".to_string(),
+        ));
+    }
+    result
+}
+
+fn id_counts(tasks: &[Task]) -> FxHashMap<&str, usize> {
+    let mut counts: FxHashMap<&str, usize> = FxHashMap::default();
+    for task in tasks {
+        *counts.entry(task.id.as_str()).or_default() += 1;
+    }
+    counts
+}
+
+/// IDs used by more than one task, sorted.
+pub fn duplicate_task_ids(tasks: &[Task]) -> Vec<String> {
+    let mut duplicates: Vec<String> = id_counts(tasks)
+        .into_iter()
+        .filter(|&(_, count)| count > 1)
+        .map(|(id, _)| id.to_string())
+        .collect();
+    duplicates.sort();
+    duplicates
+}
+
+/// Latest date a schedule may contain, the last date Python's `datetime.date` can hold.
+pub(crate) const MAX_SCHEDULE_DATE: NaiveDate = match NaiveDate::from_ymd_opt(9999, 12, 31) {
+    Some(date) => date,
+    None => unreachable!(),
+};
+
+/// Earliest date a schedule may contain, the first date Python's `datetime.date` can hold.
+pub(crate) const MIN_SCHEDULE_DATE: NaiveDate = match NaiveDate::from_ymd_opt(1, 1, 1) {
+    Some(date) => date,
+    None => unreachable!(),
+};
+
+/// Date arithmetic that would leave the range `NaiveDate` can represent.
+#[derive(Error, Debug, Clone, PartialEq)]
+#[error("{date} shifted by {days} days is outside the supported date range")]
+pub struct DateOverflow {
+    pub date: NaiveDate,
+    pub days: f64,
+}
+
+/// `date` moved by `days`, rounded away from zero to whole days.
+///
+/// Fails instead of panicking when `days` is not finite or the result is not
+/// a representable date.
+pub(crate) fn offset_date(date: NaiveDate, days: f64) -> Result<NaiveDate, DateOverflow> {
+    let whole = days.abs().ceil().copysign(days);
+    let shifted = if !whole.is_finite() {
+        None
+    } else if whole >= 0.0 {
+        date.checked_add_days(Days::new(whole as u64))
+    } else {
+        date.checked_sub_days(Days::new(-whole as u64))
+    };
+    shifted.ok_or(DateOverflow { date, days })
+}
+
+/// Problems that would push date arithmetic outside the supported range, sorted.
+///
+/// Durations, remaining work and lags must be finite. Beyond that, running
+/// every task back to back after the latest date in the input or in
+/// `calendar_periods` must still end by [`MAX_SCHEDULE_DATE`], which bounds
+/// every date either scheduler can compute. Deadlines propagate backwards, so
+/// the same span before the earliest date in the input must not reach past
+/// [`MIN_SCHEDULE_DATE`].
+pub(crate) fn date_range_errors<'a>(
+    tasks: &[Task],
+    current_date: NaiveDate,
+    calendar_periods: impl IntoIterator<Item = &'a (NaiveDate, NaiveDate)>,
+) -> Vec<String> {
+    let mut errors = Vec::new();
+    let mut latest = current_date;
+    let mut earliest: Option<NaiveDate> = None;
+    let mut span_days = 0.0;
+    for task in tasks {
+        let mut values = vec![("duration_days", task.duration_days)];
+        values.extend(task.remaining_days.map(|r| ("remaining_days", r)));
+        values.extend(task.dependencies.iter().map(|d| ("lag_days", d.lag_days)));
+        for (field, value) in &values {
+            if !value.is_finite() {
+                errors.push(format!("Task '{}' has {} {}", task.id, field, value));
+            }
+        }
+        let dates = [
+            task.start_after,
+            task.end_before,
+            task.start_on,
+            task.end_on,
+            task.external_date,
+            task.actual_start,
+        ];
+        let periods = task.blocked_periods.iter().map(|&(_, end)| Some(end));
+        let targets = task.deadline_targets.iter().map(|&(_, date)| Some(date));
+        for date in dates.into_iter().chain(periods).chain(targets).flatten() {
+            latest = latest.max(date);
+            earliest = Some(earliest.map_or(date, |e| e.min(date)));
+        }
+        let work = task.duration_days.max(task.remaining_days.unwrap_or(0.0));
+        let lag = task
+            .dependencies
+            .iter()
+            .map(|d| d.lag_days)
+            .fold(0.0, f64::max);
+        span_days += work.max(0.0).ceil() + 1.0 + lag.ceil();
+    }
+    if !errors.is_empty() {
+        errors.sort();
+        return errors;
+    }
+    latest = calendar_periods
+        .into_iter()
+        .map(|&(_, end)| end)
+        .fold(latest, NaiveDate::max);
+    let available = (MAX_SCHEDULE_DATE - latest).num_days() as f64;
+    if span_days > available {
+        errors.push(format!(
+            "Scheduling {} tasks after {} needs up to {} days, past {}",
+            tasks.len(),
+            latest,
+            span_days,
+            MAX_SCHEDULE_DATE
+        ));
+    }
+    if let Some(earliest) = earliest {
+        if span_days > (earliest - MIN_SCHEDULE_DATE).num_days() as f64 {
+            errors.push(format!(
+                "Planning {} tasks back from {} needs up to {} days, before {}",
+                tasks.len(),
+                earliest,
+                span_days,
+                MIN_SCHEDULE_DATE
+            ));
+        }
+    }
+    errors
+}
+
+/// Placements ending after [`MAX_SCHEDULE_DATE`], which date arithmetic saturated into.
+pub(crate) fn out_of_range_placements(placements: &[ScheduledTask]) -> Vec<String> {
+    placements
+        .iter()
+        .filter(|p| p.end_date > MAX_SCHEDULE_DATE)
+        .map(|p| format!("Task '{}' would end after {}", p.task_id, MAX_SCHEDULE_DATE))
+        .collect()
+}
+
+/// Collect dependency IDs that are neither tasks nor completed, sorted and deduplicated.
+pub fn unknown_dependency_ids<'a>(
+    tasks: impl IntoIterator<Item = &'a Task> + Clone,
+    completed_task_ids: &FxHashSet<String>,
+) -> Vec<String> {
+    let known: FxHashSet<&str> = tasks.clone().into_iter().map(|t| t.id.as_str()).collect();
+    let mut missing: Vec<String> = tasks
+        .into_iter()
+        .flat_map(|t| t.dependencies.iter())
+        .map(|dep| dep.entity_id.as_str())
+        .filter(|id| !known.contains(id) && !completed_task_ids.contains(*id))
+        .map(str::to_string)
+        .collect();
+    missing.sort();
+    missing.dedup();
+    missing
+}
+
+/// Put back dependency lists saved by [`apply_unknown_dependency_policy`].
+///
+/// Tasks no longer in `tasks` are skipped.
+pub(crate) fn restore_dropped_dependencies(
+    tasks: &mut FxHashMap<String, Task>,
+    dropped: &mut Vec<(String, Vec<Dependency>)>,
+) {
+    for (task_id, dependencies) in dropped.drain(..) {
+        if let Some(task) = tasks.get_mut(&task_id) {
+            task.dependencies = dependencies;
+        }
+    }
+}
+
+/// Apply an `UnknownDependencyPolicy` to a scheduler's task map.
+///
+/// Returns `Err` with the missing IDs under `Error`, or `Ok` with the IDs whose
+/// dependencies were dropped under `Ignore` (empty under `Block`). The
+/// original dependency lists of edited tasks are saved in `dropped`, and
+/// those saved by the previous call are restored first, so every run applies
+/// the policy to the tasks as given.
+pub(crate) fn apply_unknown_dependency_policy(
+    tasks: &mut FxHashMap<String, Task>,
+    completed_task_ids: &FxHashSet<String>,
+    policy: UnknownDependencyPolicy,
+    dropped: &mut Vec<(String, Vec<Dependency>)>,
+) -> Result<Vec<String>, Vec<String>> {
+    restore_dropped_dependencies(tasks, dropped);
+    if policy == UnknownDependencyPolicy::Block {
+        return Ok(Vec::new());
+    }
+    let missing = unknown_dependency_ids(tasks.values(), completed_task_ids);
+    if missing.is_empty() {
+        return Ok(missing);
+    }
+    match policy {
+        UnknownDependencyPolicy::Error => Err(missing),
+        _ => {
+            let missing_set: FxHashSet<&str> = missing.iter().map(|s| s.as_str()).collect();
+            for task in tasks.values_mut() {
+                if task
+                    .dependencies
+                    .iter()
+                    .any(|dep| missing_set.contains(dep.entity_id.as_str()))
+                {
+                    dropped.push((task.id.clone(), task.dependencies.clone()));
+                    task.dependencies
+                        .retain(|dep| !missing_set.contains(dep.entity_id.as_str()));
+                }
+            }
+            Ok(missing)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::Dependency;
+
+    fn task(id: &str, deps: &[&str]) -> Task {
+        Task {
+            id: id.to_string(),
+            duration_days: 1.0,
+            dependencies: deps
+                .iter()
+                .map(|dep| Dependency {
+                    entity_id: dep.to_string(),
+                    ..Default::default()
+                })
+                .collect(),
+            ..Default::default()
+        }
+    }
+
+    fn codes(diagnostics: &[Diagnostic]) -> Vec<&str> {
+        diagnostics.iter().map(|d| d.code.as_str()).collect()
+    }
+
+    #[test]
+    fn test_valid_tasks_have_no_diagnostics() {
+        let tasks = vec![task("a", &[]), task("b", &["a"])];
+        assert!(validate_tasks(&tasks, None).is_empty());
+    }
+
+    #[test]
+    fn test_reports_all_problems() {
+        let d = |day| NaiveDate::from_ymd_opt(2025, 1, day);
+        let mut bad = task("bad", &["missing"]);
+        bad.duration_days = -1.0;
+        bad.resources = vec![("mallory".to_string(), 1.0)];
+        bad.resource_spec = Some("alice".to_string());
+        bad.start_on = d(10);
+        bad.end_on = d(5);
+        let tasks = vec![task("a", &[]), task("a", &[]), bad];
+
+        let config = ResourceConfig {
+            resource_order: vec!["alice".to_string()],
+            ..Default::default()
+        };
+        let diagnostics = validate_tasks(&tasks, Some(&config));
+        assert_eq!(
+            codes(&diagnostics),
+            vec![
+                "duplicate_id",
+                "dangling_dependency",
+                "negative_duration",
+                "unknown_resource",
+                "spec_and_resources",
+                "conflicting_fixed_dates",
+            ]
+        );
+        assert_eq!(diagnostics[1].task_id.as_deref(), Some("bad"));
+    }
+
+    #[test]
+    fn test_unknown_dependency_policy() {
+        let mut tasks: FxHashMap<String, Task> =
+            [task("a", &["x", "done"]), task("b", &["a", "y"])]
+                .into_iter()
+                .map(|t| (t.id.clone(), t))
+                .collect();
+        let completed: FxHashSet<String> = ["done".to_string()].into_iter().collect();
+        let mut dropped = Vec::new();
+        let mut apply = |tasks: &mut FxHashMap<String, Task>, policy| {
+            apply_unknown_dependency_policy(tasks, &completed, policy, &mut dropped)
+        };
+
+        assert_eq!(
+            apply(&mut tasks, UnknownDependencyPolicy::Block),
+            Ok(vec![])
+        );
+        assert_eq!(
+            apply(&mut tasks, UnknownDependencyPolicy::Error),
+            Err(vec!["x".to_string(), "y".to_string()])
+        );
+        // Repeated runs report the same dropped dependencies
+        for _ in 0..2 {
+            assert_eq!(
+                apply(&mut tasks, UnknownDependencyPolicy::Ignore),
+                Ok(vec!["x".to_string(), "y".to_string()])
+            );
+            assert_eq!(tasks["a"].dependencies.len(), 1);
+            assert_eq!(tasks["b"].dependencies[0].entity_id, "a");
+        }
+        assert_eq!(
+            apply(&mut tasks, UnknownDependencyPolicy::Block),
+            Ok(vec![])
+        );
+        assert_eq!(tasks["a"].dependencies.len(), 2);
+    }
+
+    #[test]
+    fn test_reports_cycle() {
+        let tasks = vec![task("a", &["b"]), task("b", &["a"])];
+        let diagnostics = validate_tasks(&tasks, None);
+        assert_eq!(codes(&diagnostics), vec!["circular_dependency"]);
+        assert_eq!(diagnostics[0].task_id.as_deref(), Some("a"));
+    }
+
+    #[test]
+    fn test_reports_out_of_range_dates() {
+        let near_min = NaiveDate::MIN.checked_add_days(Days::new(5)).unwrap();
+        let mut due = task("due", &["a"]);
+        due.end_before = Some(near_min);
+        let mut work = task("a", &[]);
+
+        // Deadlines propagate backwards past the first representable date
+        let diagnostics = validate_tasks(&[work.clone(), due.clone()], None);
+        assert_eq!(codes(&diagnostics), vec!["date_out_of_range"]);
+        assert!(diagnostics[0].message.contains("before 0001-01-01"));
+
+        for duration in [f64::INFINITY, 1e18] {
+            work.duration_days = duration;
+            let diagnostics = validate_tasks(&[work.clone(), due.clone()], None);
+            assert!(codes(&diagnostics).contains(&"date_out_of_range"));
+        }
+
+        // Cycles are still found without running the backward pass
+        work.dependencies = due.dependencies.clone();
+        work.dependencies[0].entity_id = "due".to_string();
+        let diagnostics = validate_tasks(&[work, due], None);
+        assert!(codes(&diagnostics).contains(&"circular_dependency"));
+    }
+
+    #[test]
+    fn test_offset_date() {
+        let d = |day| NaiveDate::from_ymd_opt(2025, 1, day).unwrap();
+        assert_eq!(offset_date(d(10), 1.5), Ok(d(12)));
+        assert_eq!(offset_date(d(10), -1.5), Ok(d(8)));
+        assert_eq!(offset_date(d(10), -0.0), Ok(d(10)));
+        for days in [f64::INFINITY, f64::NEG_INFINITY, f64::NAN, 1e18, -1e18] {
+            assert!(offset_date(d(10), days).is_err());
+        }
+        assert!(offset_date(NaiveDate::MIN, -1.0).is_err());
+        assert!(offset_date(NaiveDate::MAX, 1.0).is_err());
+    }
+
+    #[test]
+    fn test_check_feasibility_reports_overloads() {
+        let d = |day| NaiveDate::from_ymd_opt(2025, 1, day).unwrap();
+        let assigned = |id: &str, days: f64, resource: &str| Task {
+            id: id.to_string(),
+            duration_days: days,
+            resources: vec![(resource.to_string(), 1.0)],
+            ..Default::default()
+        };
+        let pooled = |id: &str, days: f64| Task {
+            id: id.to_string(),
+            duration_days: days,
+            resource_spec: Some("alice|bob".to_string()),
+            ..Default::default()
+        };
+        let tasks = vec![
+            assigned("a", 8.0, "alice"),
+            assigned("b", 4.0, "alice"),
+            assigned("c", 3.0, "bob"),
+            pooled("d", 6.0),
+        ];
+        let config = ResourceConfig {
+            resource_order: vec!["alice".to_string(), "bob".to_string()],
+            dns_periods: [("alice".to_string(), vec![(d(3), d(4))])]
+                .into_iter()
+                .collect(),
+            capacity: [("bob".to_string(), 0.5)].into_iter().collect(),
+            ..Default::default()
+        };
+
+        // Ten days: alice loses two to DNS, bob works half time
+        let overloads = check_feasibility(&tasks, &config, (d(1), d(11)), &[]);
+        let summary: Vec<(&str, f64, f64)> = overloads
+            .iter()
+            .map(|o| (o.resource.as_str(), o.required_days, o.available_days))
+            .collect();
+        assert_eq!(
+            summary,
+            vec![("alice|bob", 21.0, 13.0), ("alice", 12.0, 8.0)]
+        );
+        assert_eq!(overloads[0].overload_days, 8.0);
+
+        assert!(check_feasibility(&tasks, &config, (d(1), d(31)), &[]).is_empty());
+    }
+
+    #[test]
+    fn test_classify_unschedulable() {
+        let d = |y, m, day| NaiveDate::from_ymd_opt(y, m, day).unwrap();
+        let mut away = task("away", &[]);
+        away.resources = vec![("bob".to_string(), 1.0)];
+        let mut nobody = task("nobody", &[]);
+        nobody.resource_spec = Some("eve".to_string());
+        let tasks: FxHashMap<String, Task> = [
+            task("a", &["ghost"]),
+            task("b", &["a"]),
+            away,
+            nobody,
+            task("stuck", &[]),
+        ]
+        .into_iter()
+        .map(|t| (t.id.clone(), t))
+        .collect();
+        let config = ResourceConfig {
+            resource_order: vec!["bob".to_string()],
+            dns_periods: [("bob".to_string(), vec![(d(2025, 1, 1), d(9999, 12, 31))])]
+                .into_iter()
+                .collect(),
+            ..Default::default()
+        };
+
+        let leftover = leftover_task_ids(&tasks, &FxHashSet::default(), &FxHashSet::default());
+        let reasons: Vec<(String, String)> =
+            classify_unschedulable(&leftover, &tasks, &FxHashSet::default(), Some(&config), &[])
+                .into_iter()
+                .map(|u| (u.task_id, u.reason))
+                .collect();
+        let expected = [
+            ("a", "unsatisfiable_dependency"),
+            ("away", "dns_forever"),
+            ("b", "unsatisfiable_dependency"),
+            ("nobody", "no_resource"),
+            ("stuck", "blocked"),
+        ];
+        assert_eq!(
+            reasons,
+            expected
+                .iter()
+                .map(|(id, reason)| (id.to_string(), reason.to_string()))
+                .collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn test_resolve_fixed_conflicts() {
+        let d = |day: u32| NaiveDate::from_ymd_opt(2025, 1, day).unwrap();
+        let fixed = || {
+            let placed = |id: &str, start: u32, end: u32, resource: &str| ScheduledTask {
+                task_id: id.to_string(),
+                start_date: d(start),
+                end_date: d(end),
+                resources: vec![resource.to_string()],
+                ..Default::default()
+            };
+            vec![
+                placed("b", 3, 5, "alice"),
+                placed("a", 1, 3, "alice"),
+                placed("c", 2, 4, "bob"),
+            ]
+        };
+
+        let mut tasks = fixed();
+        let warnings = resolve_fixed_conflicts(&mut tasks, FixedConflictPolicy::Warn).unwrap();
+        assert_eq!(warnings.len(), 1);
+        assert_eq!(warnings[0].task_ids, vec!["b", "a"]);
+        assert_eq!(tasks, fixed());
+
+        let err = resolve_fixed_conflicts(&mut fixed(), FixedConflictPolicy::Error).unwrap_err();
+        assert_eq!(
+            err,
+            vec!["Fixed tasks 'b' and 'a' both use alice from 2025-01-03 to 2025-01-03"]
+        );
+
+        let mut tasks = fixed();
+        let warnings = resolve_fixed_conflicts(&mut tasks, FixedConflictPolicy::Shift).unwrap();
+        assert_eq!(warnings[0].code, "fixed_conflict_shifted");
+        assert_eq!((tasks[0].start_date, tasks[0].end_date), (d(4), d(6)));
+        assert_eq!(tasks[2].start_date, d(2));
+    }
+
+    #[test]
+    fn test_task_warnings() {
+        let d = |day: u32| NaiveDate::from_ymd_opt(2025, 1, day).unwrap();
+        let mut both = task("both", &[]);
+        both.resource_spec = Some("*".to_string());
+        both.resources = vec![("alice".to_string(), 1.0)];
+        let mut late = task("late", &[]);
+        late.end_before = Some(d(5));
+        let mut milestone = task("milestone", &[]);
+        milestone.duration_days = 0.0;
+        milestone.resources = vec![("bob".to_string(), 1.0)];
+        let mut done = late.clone();
+        done.id = "done".to_string();
+        let tasks = [milestone, late, done, both, task("fine", &[])];
+        let completed: FxHashSet<String> = ["done".to_string()].into_iter().collect();
+
+        let warnings = task_warnings(&tasks, d(10), &completed);
+        let found: Vec<(&str, &str)> = warnings
+            .iter()
+            .map(|w| (w.task_ids[0].as_str(), w.code.as_str()))
+            .collect();
+        assert_eq!(
+            found,
+            vec![
+                ("both", "spec_and_resources"),
+                ("late", "past_deadline"),
+                ("milestone", "milestone_with_resources"),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_input_warnings() {
+        let d = |day: u32| NaiveDate::from_ymd_opt(2025, 1, day).unwrap();
+        let codes = |tasks: &[Task], today: NaiveDate, completed: &[&str]| {
+            let completed: FxHashSet<String> = completed.iter().map(|s| s.to_string()).collect();
+            input_warnings(tasks, today, &completed)
+                .into_iter()
+                .map(|w| w.code)
+                .collect::<Vec<_>>()
+        };
+        let mut dated = task("dated", &[]);
+        dated.end_before = Some(d(5));
+        let tasks = [dated, task("free", &[])];
+
+        assert_eq!(codes(&[], d(1), &[]), vec!["no_tasks"]);
+        assert_eq!(
+            codes(&tasks, d(1), &["dated", "free"]),
+            vec!["all_completed"]
+        );
+        assert_eq!(
+            codes(&tasks, d(6), &[]),
+            vec!["current_date_after_all_dates"]
+        );
+        assert!(codes(&tasks, d(5), &[]).is_empty());
+        // Only incomplete tasks' dates count, and undated inputs never warn
+        assert!(codes(&tasks, d(6), &["dated"]).is_empty());
+    }
+
+    #[test]
+    fn test_date_range_errors() {
+        let far = NaiveDate::from_ymd_opt(9000, 1, 1).unwrap();
+        let mut lagged = task("lagged", &["a"]);
+        lagged.dependencies[0].lag_days = 1000.0;
+        assert!(date_range_errors(&[task("a", &[]), lagged.clone()], far, &[]).is_empty());
+
+        lagged.dependencies[0].lag_days = f64::INFINITY;
+        let mut huge = task("huge", &[]);
+        huge.duration_days = f64::NAN;
+        assert_eq!(
+            date_range_errors(&[huge.clone(), lagged], far, &[]),
+            vec![
+                "Task 'huge' has duration_days NaN",
+                "Task 'lagged' has lag_days inf"
+            ]
+        );
+
+        huge.duration_days = 400_000.0;
+        let errors = date_range_errors(&[huge], far, &[]);
+        assert_eq!(errors.len(), 1);
+        assert!(errors[0].contains("past 9999-12-31"));
+        // A calendar period past the last representable date fails on its own
+        let period = (far, NaiveDate::from_ymd_opt(10_000, 1, 1).unwrap());
+        assert_eq!(date_range_errors(&[], far, &[period]).len(), 1);
+    }
+}
@@ -0,0 +1,288 @@
+//! Invariant checks for finished schedules.
+//!
+//! `verify_schedule` re-checks a schedule against its tasks without running a
+//! scheduler, so schedules edited by hand or produced elsewhere can be
+//! validated the same way as the schedulers' own output.
+
+use chrono::{Datelike, NaiveDate};
+use rustc_hash::FxHashMap;
+
+use crate::models::{first_unblocked, AlgorithmResult, ScheduleWarning, ScheduledTask, Task};
+use crate::scheduler::ResourceConfig;
+
+/// Tolerance when comparing fractional-day offsets.
+const OFFSET_EPSILON: f64 = 1e-9;
+
+/// Time a task holds its resources, in days since 0001-01-01.
+///
+/// Work runs from the start date up to the end date, which is the first day
+/// after the work; in sub-day mode both ends are refined by their offsets.
+fn occupancy(task: &ScheduledTask, sub_day: bool) -> (f64, f64) {
+    let day = |date: NaiveDate| date.num_days_from_ce() as f64;
+    if sub_day {
+        (
+            day(task.start_date) + task.start_offset,
+            day(task.end_date) + task.end_offset,
+        )
+    } else {
+        (day(task.start_date), day(task.end_date))
+    }
+}
+
+/// Check a schedule for broken invariants.
+///
+/// Reports tasks whose spans on a shared resource overlap (`double_booking`)
+/// unless one runs entirely inside the other while it pauses, tasks starting
+/// before a dependency plus its lag allows (`dependency_violation`), tasks
+/// starting during a DNS or blocked period of the task or its resources
+/// (`dns_violation`), and tasks moved off their `start_on` or `end_on` date
+/// (`fixed_date_violation`). Fixed and in-progress tasks are exempt from the
+/// DNS check, and in-progress tasks from the dependency check. `sub_day` must
+/// match the configuration the schedule was built with.
+pub fn verify_schedule(
+    result: &AlgorithmResult,
+    tasks: &[Task],
+    resource_config: Option<&ResourceConfig>,
+    global_dns_periods: &[(NaiveDate, NaiveDate)],
+    sub_day: bool,
+) -> Vec<ScheduleWarning> {
+    let by_id: FxHashMap<&str, &Task> = tasks.iter().map(|t| (t.id.as_str(), t)).collect();
+    let scheduled: FxHashMap<&str, &ScheduledTask> = result
+        .scheduled_tasks
+        .iter()
+        .map(|st| (st.task_id.as_str(), st))
+        .collect();
+    let mut violations = Vec::new();
+
+    let mut bookings: FxHashMap<&str, Vec<(f64, f64, &str)>> = FxHashMap::default();
+    for st in &result.scheduled_tasks {
+        let (start, end) = occupancy(st, sub_day);
+        if end - start <= OFFSET_EPSILON {
+            continue;
+        }
+        for resource in &st.resources {
+            bookings
+                .entry(resource.as_str())
+                .or_default()
+                .push((start, end, st.task_id.as_str()));
+        }
+    }
+    for (resource, mut booked) in bookings {
+        booked.sort_by(|a, b| a.0.total_cmp(&b.0).then_with(|| a.2.cmp(b.2)));
+        for (i, &(outer_start, outer_end, first)) in booked.iter().enumerate() {
+            for &(start, end, second) in &booked[i + 1..] {
+                if start >= outer_end - OFFSET_EPSILON {
+                    break;
+                }
+                // Work pauses around tasks booked earlier, so spans may nest
+                if outer_start < start - OFFSET_EPSILON && end < outer_end - OFFSET_EPSILON {
+                    continue;
+                }
+                violations.push(ScheduleWarning {
+                    code: "double_booking".to_string(),
+                    task_ids: vec![first.to_string(), second.to_string()],
+                    message: format!(
+                        "Tasks '{}' and '{}' both use '{}' at the same time",
+                        first, second, resource
+                    ),
+                });
+            }
+        }
+    }
+
+    for st in &result.scheduled_tasks {
+        let Some(task) = by_id.get(st.task_id.as_str()) else {
+            continue;
+        };
+
+        if !st.in_progress {
+            for dep in &task.dependencies {
+                let Some(pred) = scheduled.get(dep.entity_id.as_str()) else {
+                    continue;
+                };
+                let allowed = dep.ready_date(pred.end_date, global_dns_periods);
+                if st.start_date < allowed {
+                    violations.push(ScheduleWarning {
+                        code: "dependency_violation".to_string(),
+                        task_ids: vec![st.task_id.clone(), dep.entity_id.clone()],
+                        message: format!(
+                            "Task '{}' starts {}, before dependency '{}' allows ({})",
+                            st.task_id, st.start_date, dep.entity_id, allowed
+                        ),
+                    });
+                }
+            }
+        }
+
+        let fixed = task.start_on.is_some() || task.end_on.is_some();
+        if !fixed && !st.in_progress {
+            let mut periods = task.blocked_periods.clone();
+            for resource in &st.resources {
+                periods.extend(match resource_config {
+                    Some(rc) => rc.get_dns_periods(resource, global_dns_periods),
+                    None => global_dns_periods.to_vec(),
+                });
+            }
+            if first_unblocked(&periods, st.start_date) != st.start_date {
+                violations.push(ScheduleWarning {
+                    code: "dns_violation".to_string(),
+                    task_ids: vec![st.task_id.clone()],
+                    message: format!(
+                        "Task '{}' starts {} during a DNS or blocked period",
+                        st.task_id, st.start_date
+                    ),
+                });
+            }
+        }
+
+        let moved = [
+            ("start_on", task.start_on, st.start_date),
+            ("end_on", task.end_on, st.end_date),
+        ];
+        for (field, fixed_date, actual) in moved {
+            if fixed_date.is_some_and(|date| date != actual) {
+                violations.push(ScheduleWarning {
+                    code: "fixed_date_violation".to_string(),
+                    task_ids: vec![st.task_id.clone()],
+                    message: format!(
+                        "Task '{}' has {} {} but is scheduled for {}",
+                        st.task_id,
+                        field,
+                        fixed_date.unwrap_or(actual),
+                        actual
+                    ),
+                });
+            }
+        }
+    }
+
+    violations.sort_by(|a, b| (&a.code, &a.task_ids).cmp(&(&b.code, &b.task_ids)));
+    violations
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{Dependency, LagUnit};
+
+    fn d(day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(2025, 1, day).unwrap()
+    }
+
+    fn placed(id: &str, start: u32, end: u32, resource: &str) -> ScheduledTask {
+        ScheduledTask {
+            task_id: id.to_string(),
+            start_date: d(start),
+            end_date: d(end),
+            resources: vec![resource.to_string()],
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_verify_schedule_reports_each_invariant() {
+        let tasks = vec![
+            Task {
+                id: "a".to_string(),
+                start_on: Some(d(1)),
+                ..Default::default()
+            },
+            Task {
+                id: "b".to_string(),
+                dependencies: vec![Dependency {
+                    entity_id: "a".to_string(),
+                    lag_days: 1.0,
+                    ..Default::default()
+                }],
+                ..Default::default()
+            },
+            Task {
+                id: "c".to_string(),
+                ..Default::default()
+            },
+        ];
+        let valid = AlgorithmResult {
+            scheduled_tasks: vec![
+                placed("a", 1, 3, "alice"),
+                placed("b", 5, 6, "alice"),
+                placed("c", 1, 2, "bob"),
+            ],
+            ..Default::default()
+        };
+        assert!(verify_schedule(&valid, &tasks, None, &[], false).is_empty());
+
+        let broken = AlgorithmResult {
+            scheduled_tasks: vec![
+                placed("a", 2, 4, "alice"),
+                placed("b", 4, 6, "alice"),
+                placed("c", 3, 5, "alice"),
+            ],
+            ..Default::default()
+        };
+        let violations = verify_schedule(&broken, &tasks, None, &[(d(3), d(3))], false);
+        let codes: Vec<(&str, Vec<&str>)> = violations
+            .iter()
+            .map(|v| {
+                (
+                    v.code.as_str(),
+                    v.task_ids.iter().map(String::as_str).collect(),
+                )
+            })
+            .collect();
+        assert_eq!(
+            codes,
+            vec![
+                ("dependency_violation", vec!["b", "a"]),
+                ("dns_violation", vec!["c"]),
+                ("double_booking", vec!["a", "c"]),
+                ("double_booking", vec!["c", "b"]),
+                ("fixed_date_violation", vec!["a"]),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_verify_schedule_working_lag_skips_global_dns() {
+        let tasks = |lag_unit| {
+            vec![Task {
+                id: "b".to_string(),
+                dependencies: vec![Dependency {
+                    entity_id: "a".to_string(),
+                    lag_days: 1.0,
+                    lag_unit,
+                }],
+                ..Default::default()
+            }]
+        };
+        let result = AlgorithmResult {
+            scheduled_tasks: vec![placed("a", 1, 3, "alice"), placed("b", 6, 7, "alice")],
+            ..Default::default()
+        };
+        let dns = [(d(4), d(5))];
+
+        assert!(verify_schedule(&result, &tasks(LagUnit::Calendar), None, &dns, false).is_empty());
+        let warnings = verify_schedule(&result, &tasks(LagUnit::Working), None, &dns, false);
+        assert_eq!(warnings.len(), 1);
+        assert_eq!(warnings[0].code, "dependency_violation");
+    }
+
+    #[test]
+    fn test_verify_schedule_sub_day_shares_days() {
+        let task = |id: &str, start_offset: f64, end_offset: f64| ScheduledTask {
+            start_offset,
+            end_offset,
+            ..placed(id, 1, 1, "alice")
+        };
+        let result = AlgorithmResult {
+            scheduled_tasks: vec![task("a", 0.0, 0.5), task("b", 0.5, 0.75)],
+            ..Default::default()
+        };
+        assert!(verify_schedule(&result, &[], None, &[], true).is_empty());
+
+        let overlapping = AlgorithmResult {
+            scheduled_tasks: vec![task("a", 0.0, 0.5), task("b", 0.25, 0.75)],
+            ..Default::default()
+        };
+        assert_eq!(verify_schedule(&overlapping, &[], None, &[], true).len(), 1);
+    }
+}
@@ -0,0 +1,194 @@
+//! Property tests: both schedulers produce schedules that pass `verify_schedule`
+//! on randomly generated instances.
+
+use std::collections::HashMap;
+
+use chrono::{Days, NaiveDate};
+use mouc_rust::{
+    verify_schedule, CriticalPathConfig, CriticalPathScheduler, Dependency, ParallelScheduler,
+    ResourceConfig, SchedulingConfig, Task,
+};
+use proptest::prelude::*;
+use rustc_hash::FxHashSet;
+
+const RESOURCES: [&str; 3] = ["alice", "bob", "carol"];
+
+fn day(offset: u64) -> NaiveDate {
+    NaiveDate::from_ymd_opt(2025, 1, 1)
+        .unwrap()
+        .checked_add_days(Days::new(offset))
+        .unwrap()
+}
+
+#[derive(Clone, Debug)]
+struct Instance {
+    tasks: Vec<Task>,
+    resource_config: ResourceConfig,
+    global_dns_periods: Vec<(NaiveDate, NaiveDate)>,
+}
+
+/// (duration in half days, resource index or auto-assigned, dependencies as (earlier index, lag), start_after, start_on)
+type TaskSpec = (
+    u32,
+    Option<usize>,
+    Vec<(usize, u32)>,
+    Option<u64>,
+    Option<u64>,
+);
+
+fn task_spec() -> impl Strategy<Value = TaskSpec> {
+    (
+        0u32..10,
+        proptest::option::of(0..RESOURCES.len()),
+        proptest::collection::vec((0usize..16, 0u32..3), 0..3),
+        proptest::option::weighted(0.2, 0u64..20),
+        proptest::option::weighted(0.15, 0u64..20),
+    )
+}
+
+fn period() -> impl Strategy<Value = (NaiveDate, NaiveDate)> {
+    (0u64..30, 0u64..4).prop_map(|(start, len)| (day(start), day(start + len)))
+}
+
+fn instance() -> impl Strategy<Value = Instance> {
+    (
+        proptest::collection::vec(task_spec(), 1..16),
+        proptest::collection::vec(proptest::collection::vec(period(), 0..3), RESOURCES.len()),
+        proptest::collection::vec(period(), 0..2),
+    )
+        .prop_map(|(specs, dns, global_dns_periods)| {
+            let mut fixed_resources = FxHashSet::default();
+            let tasks = specs
+                .into_iter()
+                .enumerate()
+                .map(|(i, (half_days, resource, deps, start_after, start_on))| {
+                    let resource = resource.map(|r| RESOURCES[r].to_string());
+                    // Fixed tasks have no dependencies and at most one per resource
+                    let start_on = start_on.filter(|_| {
+                        deps.is_empty()
+                            && resource
+                                .as_ref()
+                                .is_some_and(|r| fixed_resources.insert(r.clone()))
+                    });
+                    Task {
+                        id: format!("t{i}"),
+                        duration_days: half_days as f64 / 2.0,
+                        resource_spec: resource.is_none().then(|| "*".to_string()),
+                        resources: resource.into_iter().map(|r| (r, 1.0)).collect(),
+                        dependencies: deps
+                            .into_iter()
+                            .filter(|&(dep, _)| dep < i && start_on.is_none())
+                            .map(|(dep, lag)| Dependency {
+                                entity_id: format!("t{dep}"),
+                                lag_days: lag as f64,
+                                ..Default::default()
+                            })
+                            .collect(),
+                        start_after: start_after.map(day),
+                        start_on: start_on.map(day),
+                        ..Default::default()
+                    }
+                })
+                .collect();
+            let resource_config = ResourceConfig {
+                resource_order: RESOURCES.iter().map(|r| r.to_string()).collect(),
+                dns_periods: RESOURCES
+                    .iter()
+                    .map(|r| r.to_string())
+                    .zip(dns)
+                    .collect::<HashMap<_, _>>(),
+                ..Default::default()
+            };
+            Instance {
+                tasks,
+                resource_config,
+                global_dns_periods,
+            }
+        })
+}
+
+proptest! {
+    #![proptest_config(ProptestConfig::with_cases(128))]
+
+    #[test]
+    fn parallel_schedules_satisfy_invariants(instance in instance()) {
+        let result = ParallelScheduler::new(
+            instance.tasks.clone(),
+            day(0),
+            FxHashSet::default(),
+            SchedulingConfig::default(),
+            None,
+            Some(instance.resource_config.clone()),
+            instance.global_dns_periods.clone(),
+            None,
+            None,
+        )
+        .unwrap()
+        .schedule()
+        .unwrap();
+        prop_assert_eq!(result.scheduled_tasks.len(), instance.tasks.len());
+        let violations = verify_schedule(
+            &result,
+            &instance.tasks,
+            Some(&instance.resource_config),
+            &instance.global_dns_periods,
+            false,
+        );
+        prop_assert!(violations.is_empty(), "{:?}\n{:?}", violations, result.scheduled_tasks);
+    }
+
+    #[test]
+    fn rolling_wave_schedules_satisfy_invariants(instance in instance()) {
+        let result = ParallelScheduler::new(
+            instance.tasks.clone(),
+            day(0),
+            FxHashSet::default(),
+            SchedulingConfig {
+                rolling_wave_horizon_days: Some(10),
+                ..Default::default()
+            },
+            None,
+            Some(instance.resource_config.clone()),
+            instance.global_dns_periods.clone(),
+            None,
+            None,
+        )
+        .unwrap()
+        .schedule()
+        .unwrap();
+        prop_assert_eq!(result.scheduled_tasks.len(), instance.tasks.len());
+        let violations = verify_schedule(
+            &result,
+            &instance.tasks,
+            Some(&instance.resource_config),
+            &instance.global_dns_periods,
+            false,
+        );
+        prop_assert!(violations.is_empty(), "{:?}\n{:?}", violations, result.scheduled_tasks);
+    }
+
+    #[test]
+    fn critical_path_schedules_satisfy_invariants(instance in instance()) {
+        let result = CriticalPathScheduler::new(
+            instance.tasks.clone(),
+            day(0),
+            FxHashSet::default(),
+            50,
+            CriticalPathConfig::default(),
+            Some(instance.resource_config.clone()),
+            instance.global_dns_periods.clone(),
+        )
+        .unwrap()
+        .schedule()
+        .unwrap();
+        prop_assert_eq!(result.scheduled_tasks.len(), instance.tasks.len());
+        let violations = verify_schedule(
+            &result,
+            &instance.tasks,
+            Some(&instance.resource_config),
+            &instance.global_dns_periods,
+            false,
+        );
+        prop_assert!(violations.is_empty(), "{:?}\n{:?}", violations, result.scheduled_tasks);
+    }
+}
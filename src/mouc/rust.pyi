@@ -1,12 +1,49 @@
 """Type stubs for mouc.rust (Rust extension module)."""
 
+from collections.abc import Callable, Iterator, Sequence
 from datetime import date
+from typing import Any
+
+class MoucSchedulerError(ValueError):
+    """Base class for scheduler failures."""
+
+class CircularDependencyError(MoucSchedulerError):
+    cycle: list[str]  # each task depends on the next
+
+class UnschedulableError(MoucSchedulerError):
+    task_ids: list[str]
+
+class InvalidConfigError(MoucSchedulerError):
+    """A configuration value or option name is invalid."""
+
+class ScheduleCancelledError(MoucSchedulerError):
+    partial: list[ScheduledTask]  # tasks placed before the run stopped
 
 class Dependency:
     entity_id: str
     lag_days: float
+    lag_unit_str: str  # "calendar" or "working"
+
+    def __init__(self, entity_id: str, lag_days: float = 0.0, lag_unit: str = "calendar") -> None: ...
+    def __repr__(self) -> str: ...
 
-    def __init__(self, entity_id: str, lag_days: float = 0.0) -> None: ...
+class Recurrence:
+    interval_days: int
+    until: date
+    chained: bool
+
+    def __init__(self, interval_days: int, until: date, chained: bool = True) -> None:
+        """Raises InvalidConfigError if interval_days is 0."""
+        ...
+    def __repr__(self) -> str: ...
+
+class CrashSpec:
+    min_duration_days: float
+    cost_per_day: float  # cost of each day saved
+
+    def __init__(self, min_duration_days: float, cost_per_day: float) -> None:
+        """Raises InvalidConfigError if either value is negative or not finite."""
+        ...
     def __repr__(self) -> str: ...
 
 class Task:
@@ -20,6 +57,18 @@ class Task:
     end_on: date | None
     resource_spec: str | None
     priority: int | None
+    required_skills: list[str]
+    external_date: date | None
+    recurrence: Recurrence | None
+    remaining_days: float | None
+    actual_start: date | None
+    pinned_resource: str | None  # sole auto-assignment candidate
+    exclusive_groups: list[str]  # tasks sharing a group never overlap
+    category: str | None  # for changeover gaps on a resource
+    crashable: CrashSpec | None  # for suggest_crashes
+    blocked_periods: list[tuple[date, date]]  # inclusive; no work on these days
+    rank: int | None  # backlog order; breaks ties between equal tasks, lowest first
+    deadline_targets: list[tuple[str, date]]  # named deadlines; the tightest applies
 
     def __init__(
         self,
@@ -33,6 +82,18 @@ class Task:
         end_on: date | None = None,
         resource_spec: str | None = None,
         priority: int | None = None,
+        required_skills: list[str] | None = None,
+        external_date: date | None = None,
+        recurrence: Recurrence | None = None,
+        remaining_days: float | None = None,
+        actual_start: date | None = None,
+        pinned_resource: str | None = None,
+        exclusive_groups: list[str] | None = None,
+        category: str | None = None,
+        crashable: CrashSpec | None = None,
+        blocked_periods: list[tuple[date, date]] | None = None,
+        rank: int | None = None,
+        deadline_targets: list[tuple[str, date]] | None = None,
     ) -> None: ...
     def __repr__(self) -> str: ...
 
@@ -42,6 +103,10 @@ class ScheduledTask:
     end_date: date
     duration_days: float
     resources: list[str]
+    start_offset: float  # fraction of start day (sub-day mode)
+    end_offset: float  # fraction of end day (sub-day mode)
+    in_progress: bool
+    effective_duration_days: float  # work days reserved; whole days unless sub-day
 
     def __init__(
         self,
@@ -50,23 +115,122 @@ class ScheduledTask:
         end_date: date,
         duration_days: float,
         resources: list[str],
+        start_offset: float = 0.0,
+        end_offset: float = 0.0,
+        in_progress: bool = False,
+        effective_duration_days: float | None = None,  # defaults to duration_days
     ) -> None: ...
     def __repr__(self) -> str: ...
 
+class UnschedulableTask:
+    task_id: str
+    reason: str  # "no_resource", "unsatisfiable_dependency", "dns_forever", or "blocked"
+    detail: str
+
+    def __repr__(self) -> str: ...
+
+class ScheduleWarning:
+    # "fixed_conflict", "fixed_conflict_shifted", "dependency_violation",
+    # "spec_and_resources", "past_deadline", "milestone_with_resources",
+    # "conflicting_fixed_dates", "empty_recurrence", "no_tasks", "all_completed",
+    # or "current_date_after_all_dates"
+    code: str
+    task_ids: list[str]  # the task the warning is about first; empty for input-level codes
+    message: str
+
+    def __repr__(self) -> str: ...
+
+class TaskEligibility:
+    task_id: str
+    eligible: bool
+    waiting_on: list[str]  # dependencies not completed yet
+    reasons: list[str]  # one message per blocker; empty when eligible
+
+    def __repr__(self) -> str: ...
+
+class ScheduleSummary:
+    makespan_days: float
+    total_weighted_tardiness: float  # days past end_before, weighted by priority / 100
+    late_targets: int
+    average_flow_time_days: float
+    peak_resource_load: int  # most resources busy on one day
+
+    def __repr__(self) -> str: ...
+
+class PhaseTimings:
+    backward_pass_ms: float  # parallel scheduler only
+    cache_build_ms: float  # critical path scheduler only
+    main_loop_ms: float  # includes cache work and rollouts
+    rollout_ms: float
+    cache_invalidation_ms: float  # critical path scheduler only
+
+    def __repr__(self) -> str: ...
+
+class AlgorithmMetadata:
+    algorithm: str
+    strategy: str | None  # None for the critical path scheduler
+    rollout_decision_count: int
+    iterations: int  # main scheduling loop iterations
+    wall_time_ms: float
+    metrics: ScheduleSummary
+    timings: PhaseTimings | None  # set when the config enables profile
+    deadlines: list[DeadlineStatus]  # every end_before and deadline target
+
+    def __repr__(self) -> str: ...
+
+class DeadlineStatus:
+    task_id: str
+    name: str  # deadline target name, or "end_before"
+    deadline: date
+    end_date: date
+    met: bool
+    days_late: int  # 0 when met
+
+    def __repr__(self) -> str: ...
+
 class AlgorithmResult:
     scheduled_tasks: list[ScheduledTask]
-    algorithm_metadata: dict[str, str]
+    algorithm_metadata: dict[str, str]  # free-form extras; see metadata for typed fields
+    unschedulable: list[UnschedulableTask]  # filled when on_unschedulable is not "error"
+    warnings: list[ScheduleWarning]
+    metadata: AlgorithmMetadata | None  # set by the schedulers
 
     def __init__(
         self,
         scheduled_tasks: list[ScheduledTask],
         algorithm_metadata: dict[str, str] | None = None,
+        unschedulable: list[UnschedulableTask] | None = None,
+        warnings: list[ScheduleWarning] | None = None,
+        metadata: AlgorithmMetadata | None = None,
     ) -> None: ...
+    def iter_scheduled_tasks(self, batch_size: int = 1000) -> ScheduledTaskBatches:
+        """Yield scheduled tasks in lists of at most batch_size, converting lazily."""
+        ...
+    def fingerprint(self) -> int:
+        """Stable 64-bit hash of task/start/end/resources, for change detection."""
+        ...
+    def __repr__(self) -> str: ...
+
+class ScheduledTaskBatches(Iterator[list[ScheduledTask]]):
+    def __iter__(self) -> ScheduledTaskBatches: ...
+    def __next__(self) -> list[ScheduledTask]: ...
+
+class Provenance:
+    """Where a computed deadline and priority were inherited from."""
+
+    deadline_path: list[str]  # direct dependent first, origin last; empty if own
+    priority_path: list[str]
+    @property
+    def deadline_source(self) -> str | None: ...
+    @property
+    def priority_source(self) -> str | None: ...
     def __repr__(self) -> str: ...
 
 class PreProcessResult:
     computed_deadlines: dict[str, date]
     computed_priorities: dict[str, int]
+    provenance: dict[str, Provenance]  # only tasks with an inherited value
+    computed_earliest_starts: dict[str, date]  # only tasks reached by a start constraint
 
     def __init__(
         self,
@@ -75,6 +239,249 @@ class PreProcessResult:
     ) -> None: ...
     def __repr__(self) -> str: ...
 
+class Diagnostic:
+    code: str  # e.g. "dangling_dependency", "duplicate_id", "circular_dependency"
+    task_id: str | None
+    message: str
+
+    def __repr__(self) -> str: ...
+
+class ResourceOverload:
+    resource: str  # resource name, or resource spec for pooled auto-assigned work
+    required_days: float
+    available_days: float
+    overload_days: float
+
+    def __repr__(self) -> str: ...
+
+class Buffer:
+    id: str  # e.g. "launch__project_buffer"
+    kind: str  # "project" or "feeding"
+    chain: list[str]  # protected chain, in execution order
+    size_days: float
+    start_date: date
+    end_date: date
+    baseline_end: date  # end of the chain's last task when computed
+
+    @property
+    def protected_task(self) -> str | None: ...
+    def __repr__(self) -> str: ...
+
+class BufferStatus:
+    buffer_id: str
+    size_days: float
+    consumed_days: float
+    consumed_fraction: float  # above 1.0 means the buffer is exhausted
+
+    def __repr__(self) -> str: ...
+
+class EarnedValueSeries:
+    dates: list[date]
+    planned_value: list[float]  # PV
+    earned_value: list[float]  # EV
+    schedule_variance: list[float]  # SV = EV - PV
+    schedule_performance_index: list[float]  # SPI = EV / PV (1.0 while PV is zero)
+    budget_at_completion: float  # BAC
+
+    def __repr__(self) -> str: ...
+
+class ScheduleMetrics:
+    """Metric vector of a schedule; every metric is lower-is-better."""
+
+    makespan_days: float
+    weighted_tardiness: float  # days past end_before, weighted by priority / 100
+    weighted_flow_time: float  # days to completion, weighted by priority / 100
+    idle_days: float  # resource-days idle before the last completion
+    total_cost: float
+
+    def dominates(self, other: ScheduleMetrics) -> bool: ...
+    def __repr__(self) -> str: ...
+
+class ParetoSchedule:
+    objective: ScheduleObjective
+    metrics: ScheduleMetrics
+    result: AlgorithmResult
+
+    def __repr__(self) -> str: ...
+
+class TuningGrid:
+    """Values tried for each tuned parameter; an empty list keeps the base value."""
+
+    k: list[float]
+    urgency_floor: list[float]
+    work_exponent: list[float]
+    no_deadline_urgency_multiplier: list[float]
+
+    def __init__(
+        self,
+        k: list[float] | None = None,
+        urgency_floor: list[float] | None = None,
+        work_exponent: list[float] | None = None,
+        no_deadline_urgency_multiplier: list[float] | None = None,
+    ) -> None: ...
+    def __repr__(self) -> str: ...
+
+class TuningTrial:
+    k: float
+    urgency_floor: float
+    work_exponent: float
+    no_deadline_urgency_multiplier: float
+    score: float  # objective cost summed over instances; lower is better
+
+    def __repr__(self) -> str: ...
+
+class TuningResult:
+    best_config: CriticalPathConfig
+    best_score: float
+    trials: list[TuningTrial]  # best first
+
+    def __repr__(self) -> str: ...
+
+class KickoffSuggestion:
+    """Latest feasible kickoff for one deadline target."""
+
+    target_id: str
+    deadline: date
+    kickoff_date: date
+    dependency_kickoff_date: date
+    critical_task_id: str
+    constraining_resources: list[str]
+
+    def __repr__(self) -> str: ...
+
+class LatestStart:
+    """Latest responsible start of one task."""
+
+    task_id: str
+    latest_start: date
+    latest_finish: date
+    dependency_latest_start: date  # unlimited resources
+    deadline_task_id: str  # task whose deadline (or fixed start) binds
+    deadline: date
+    runway_days: int  # negative when already late
+    resources: list[str]
+
+    def __repr__(self) -> str: ...
+
+class CrashAction:
+    task_id: str
+    days_saved: float
+    cost: float
+
+    def __repr__(self) -> str: ...
+
+class CrashPlan:
+    """Cheapest crash set found for one late target."""
+
+    target_id: str
+    deadline: date
+    scheduled_end: date
+    late_days: float
+    crashes: list[CrashAction]  # cheapest per day first
+    total_cost: float
+    recovered_days: float
+
+    def is_sufficient(self) -> bool:
+        """Whether the crashes bring the target back before its deadline."""
+        ...
+    def __repr__(self) -> str: ...
+
+class TaskTiming:
+    """Earliest/latest start and finish of a task on a target's critical path, in days."""
+
+    earliest_start: float
+    earliest_finish: float
+    latest_start: float
+    latest_finish: float
+    slack: float
+    is_critical: bool
+
+    def __repr__(self) -> str: ...
+
+class CriticalPathResult:
+    """Critical path of one target."""
+
+    task_timings: dict[str, TaskTiming]
+    critical_path_tasks: list[str]
+    critical_path_length: float
+    total_work: float
+
+    def __repr__(self) -> str: ...
+
+class Blocker:
+    """An incomplete task standing between a task and its start."""
+
+    task_id: str
+    blocks: list[str]  # tasks on the chain depending on this one directly
+    expected_end: date | None  # from the given schedule; None when unscheduled
+    known: bool  # False for dependencies on unknown, incomplete tasks
+
+    def __repr__(self) -> str: ...
+
+class TargetImpact:
+    """A target whose earliest finish moves when a task slips."""
+
+    target_id: str
+    delay_days: float
+
+    def __repr__(self) -> str: ...
+
+class SlipAnalysis:
+    """Effect of a task slipping on an existing schedule."""
+
+    affected_tasks: list[ScheduledTask]
+    missed_deadlines: list[str]
+
+    def __repr__(self) -> str: ...
+
+class HeldReservation:
+    """A resource held by a rollout reservation for an upcoming task."""
+
+    resource: str
+    target_id: str
+    task_id: str
+    target_score: float
+    reserved_from: date
+    release_on: date
+
+    def __repr__(self) -> str: ...
+
+class SchedulerSnapshot:
+    """Scheduler state at a pause point.
+
+    Placements are the whole state; edit `scheduled_tasks` to pin or drop
+    decisions before passing the snapshot to a scheduler's `restore()`.
+    """
+
+    current_time: date
+    scheduled_tasks: list[ScheduledTask]
+    reservations: list[HeldReservation]
+
+    def __init__(
+        self,
+        current_time: date,
+        scheduled_tasks: list[ScheduledTask],
+        reservations: list[HeldReservation] | None = None,
+    ) -> None: ...
+    def to_json(self) -> str: ...
+    @staticmethod
+    def from_json(text: str) -> SchedulerSnapshot:
+        """Parse a snapshot written by `to_json`; raises ValueError on bad input."""
+        ...
+    def __repr__(self) -> str: ...
+
+class ScheduleStep:
+    """One decision of a single-stepped scheduling run."""
+
+    kind: str  # "placed", "advanced", or "finished"
+    time: date
+    placed: ScheduledTask | None
+    eligible_task_ids: list[str]
+    ranked_target_ids: list[str]  # critical path scheduler only
+    snapshot: SchedulerSnapshot
+
+    def __repr__(self) -> str: ...
+
 class SchedulingConfig:
     strategy: str
     cr_weight: float
@@ -86,6 +493,22 @@ class SchedulingConfig:
     atc_default_urgency_multiplier: float
     atc_default_urgency_floor: float
     verbosity: int
+    timeout_secs: float | None
+    sub_day: bool
+    duration_rounding_str: str  # "up" or "nearest"; ignored when sub_day is set
+    priority_decay: float
+    contention_aware_deadlines: bool
+    calendar_aware_deadlines: bool  # count durations in working days when propagating deadlines
+    rolling_wave_horizon_days: int | None  # place later work in coarse buckets (parallel only)
+    rolling_wave_bucket_days: int  # calendar days per rolling-wave bucket
+    unknown_dependencies_str: str  # "block", "error", or "ignore"
+    alap: bool
+    on_unschedulable_str: str  # "error", "defer", or "best_effort"
+    max_iterations: int | None  # time advances before failing; None = 100 per task
+    allow_fixed_in_global_dns: bool  # False moves milestones/start_on out of global DNS
+    on_fixed_conflict_str: str  # "ignore", "warn", "error", or "shift"
+    profile: bool  # record PhaseTimings in result metadata
+    algorithm_str: str  # "parallel_sgs", "bounded_rollout", "critical_path", or "hybrid"
 
     def __init__(
         self,
@@ -99,6 +522,51 @@ class SchedulingConfig:
         atc_default_urgency_multiplier: float | None = None,
         atc_default_urgency_floor: float | None = None,
         verbosity: int | None = None,
+        timeout_secs: float | None = None,
+        sub_day: bool | None = None,
+        duration_rounding: str | None = None,
+        priority_decay: float | None = None,
+        contention_aware_deadlines: bool | None = None,
+        calendar_aware_deadlines: bool | None = None,
+        rolling_wave_horizon_days: int | None = None,
+        rolling_wave_bucket_days: int | None = None,
+        unknown_dependencies: str | None = None,
+        alap: bool | None = None,
+        on_unschedulable: str | None = None,
+        max_iterations: int | None = None,
+        allow_fixed_in_global_dns: bool | None = None,
+        on_fixed_conflict: str | None = None,
+        profile: bool | None = None,
+        algorithm: str | None = None,
+    ) -> None: ...
+    def __repr__(self) -> str: ...
+    @staticmethod
+    def preset(name: str) -> SchedulingConfig: ...  # "deadline_focused", "throughput", "stable_plan", "fast"
+    @staticmethod
+    def from_profile(text: str) -> SchedulingConfig: ...  # JSON or TOML overrides; optional "preset" key
+    def validate(self) -> list[str]: ...  # every range problem; empty if valid
+
+class ScheduleObjective:
+    """Weights for scoring a (partial) schedule during rollout; lower is better.
+
+    Per-task terms are scaled by `priority / priority_scale`.
+    """
+
+    flow_time_weight: float
+    tardiness_weight: float
+    makespan_weight: float
+    idle_time_weight: float
+    priority_scale: float
+    reassignment_weight: float  # per task moved off ResourceConfig.prior_assignments
+
+    def __init__(
+        self,
+        flow_time_weight: float = 1.0,
+        tardiness_weight: float = 1000.0,
+        makespan_weight: float = 0.0,
+        idle_time_weight: float = 0.0,
+        priority_scale: float = 100.0,
+        reassignment_weight: float = 0.0,
     ) -> None: ...
     def __repr__(self) -> str: ...
 
@@ -108,6 +576,10 @@ class RolloutConfig:
     cr_relaxed_threshold: float
     min_cr_urgency_gap: float
     max_horizon_days: int | None
+    rollout_fidelity_str: str  # "full", "no_auto_assignment", or "critical_tasks_only"
+    objective: ScheduleObjective
+    trace_rollouts: bool  # keep the last decision's simulations for get_last_rollout_trace()
+    reservation_max_idle_days: int  # days a skip holds an idle resource for the competing task
 
     def __init__(
         self,
@@ -116,35 +588,105 @@ class RolloutConfig:
         cr_relaxed_threshold: float | None = None,
         min_cr_urgency_gap: float | None = None,
         max_horizon_days: int | None = 30,
+        rollout_fidelity: str = "full",
+        objective: ScheduleObjective | None = None,
+        trace_rollouts: bool = False,
+        reservation_max_idle_days: int = 0,
+    ) -> None: ...
+    def __repr__(self) -> str: ...
+
+class TaskColumns:
+    """Tasks built in Rust from columnar arrays (lists or numpy arrays).
+
+    Pass in place of a task list to the scheduler constructors to skip building
+    one Python `Task` object per task. `dependency_edges` is a
+    `(dependents, dependencies)` pair of index arrays into `ids`.
+    """
+
+    def __init__(
+        self,
+        ids: Sequence[str],
+        durations: Sequence[float],
+        priorities: Sequence[int | None] | None = None,
+        dependency_edges: tuple[Sequence[int], Sequence[int]] | None = None,
+        lags: Sequence[float] | None = None,
+        resources: Sequence[str | None] | None = None,
+        resource_specs: Sequence[str | None] | None = None,
+        start_afters: Sequence[date | None] | None = None,
+        end_befores: Sequence[date | None] | None = None,
     ) -> None: ...
+    def to_tasks(self) -> list[Task]: ...
+    def __len__(self) -> int: ...
     def __repr__(self) -> str: ...
 
 class TaskSortInfo:
     duration_days: float
     deadline: date | None
     priority: int
+    rank: int | None
 
     def __init__(
         self,
         duration_days: float,
         priority: int,
         deadline: date | None = None,
+        rank: int | None = None,
     ) -> None: ...
     def __repr__(self) -> str: ...
 
 class ResourceConfig:
     resource_order: list[str]
     dns_periods: dict[str, list[tuple[date, date]]]
-    spec_expansion: dict[str, list[str]]
+    spec_expansion: dict[str, list[str]]  # group -> resources or nested groups
+    cost_rates: dict[str, float]  # cost per working day
+    cost_weight: float  # weight of total cost in rollout scoring
+    skills: dict[str, list[str]]
+    efficiency: dict[str, float]  # speed multiplier: duration / efficiency
+    skill_efficiency: dict[str, dict[str, float]]
+    capacity: dict[str, float]  # daily working fraction (FTE)
+    prior_assignments: dict[str, str]  # task_id -> resource from a previous plan
+    switch_penalty_days: float  # days another resource must save to take a task
+    changeover_days: dict[str, dict[str, float]]  # from category -> to category -> days
+    capacity_segments: dict[str, list[tuple[date, float]]]  # capacity from each date on
+    attributes: dict[str, dict[str, str]]  # metadata for specs like "skill:rust&level>=3"
 
     def __init__(
         self,
         resource_order: list[str] | None = None,
         dns_periods: dict[str, list[tuple[date, date]]] | None = None,
         spec_expansion: dict[str, list[str]] | None = None,
+        cost_rates: dict[str, float] | None = None,
+        cost_weight: float = 0.0,
+        skills: dict[str, list[str]] | None = None,
+        efficiency: dict[str, float] | None = None,
+        skill_efficiency: dict[str, dict[str, float]] | None = None,
+        capacity: dict[str, float] | None = None,
+        prior_assignments: dict[str, str] | None = None,
+        switch_penalty_days: float = 0.0,
+        changeover_days: dict[str, dict[str, float]] | None = None,
+        capacity_segments: dict[str, list[tuple[date, float]]] | None = None,
+        attributes: dict[str, dict[str, str]] | None = None,
     ) -> None: ...
     def __repr__(self) -> str: ...
 
+class CancellationToken:
+    def __init__(self) -> None: ...
+    def cancel(self) -> None:
+        """Request cancellation of the schedule() call using this token."""
+        ...
+    def is_cancelled(self) -> bool: ...
+    def __repr__(self) -> str: ...
+
+class ScheduleHandle:
+    def is_done(self) -> bool:
+        """Check whether the background run has finished (non-blocking)."""
+        ...
+    def cancel(self) -> None: ...
+    def join(self) -> AlgorithmResult:
+        """Wait for the run to finish and return its result."""
+        ...
+    def __repr__(self) -> str: ...
+
 class RolloutDecision:
     task_id: str
     task_priority: int
@@ -159,10 +701,25 @@ class RolloutDecision:
 
     def __repr__(self) -> str: ...
 
+class RolloutTrace:
+    """Both simulated schedules behind a rollout decision."""
+
+    decision_date: date
+    task_id: str
+    decision: str  # "schedule" or "skip"
+    schedule_score: float
+    skip_score: float
+    schedule_tasks: list[ScheduledTask]  # placements if the task starts now
+    skip_tasks: list[ScheduledTask]  # placements if it is skipped
+    divergence_date: date | None  # earliest start that differs between the two
+    reservations: list[HeldReservation]  # held at the decision (critical path only)
+
+    def __repr__(self) -> str: ...
+
 class ParallelScheduler:
     def __init__(
         self,
-        tasks: list[Task],
+        tasks: list[Task] | TaskColumns,
         current_date: date,
         completed_task_ids: set[str] | None = None,
         config: SchedulingConfig | None = None,
@@ -170,19 +727,60 @@ class ParallelScheduler:
         resource_config: ResourceConfig | None = None,
         global_dns_periods: list[tuple[date, date]] | None = None,
         preprocess_result: PreProcessResult | None = None,
-    ) -> None: ...
-    def schedule(self) -> AlgorithmResult:
+        external_completions: dict[str, date] | None = None,
+    ) -> None:
+        """Create the scheduler; raises ValueError if task IDs are duplicated.
+
+        Dependencies on IDs in `external_completions` that are not tasks wait
+        for that event's date instead of a task.
+        """
+        ...
+    def schedule(
+        self,
+        cancel_token: CancellationToken | None = None,
+        progress_callback: Callable[[int, int, date], None] | None = None,
+        progress_interval: int = 1,
+    ) -> AlgorithmResult:
         """Run the scheduling algorithm."""
         ...
+    def schedule_background(
+        self,
+        cancel_token: CancellationToken | None = None,
+        progress_callback: Callable[[int, int, date], None] | None = None,
+        progress_interval: int = 1,
+    ) -> ScheduleHandle:
+        """Start scheduling on a background thread (GIL released)."""
+        ...
+    def schedule_until(self, pause_at: date) -> SchedulerSnapshot:
+        """Schedule decisions up to and including `pause_at` and return the state."""
+        ...
+    def schedule_step(self, dry_run: bool = False) -> ScheduleStep:
+        """Make exactly one decision and return it; later calls continue from it.
+
+        With ``dry_run`` the decision is only reported and the scheduler stays put.
+        """
+        ...
+    def restore(self, snapshot: SchedulerSnapshot | None = None) -> None:
+        """Make later runs resume from `snapshot`; None schedules from scratch again."""
+        ...
     def get_computed_deadlines(self) -> dict[str, date]:
         """Get computed deadlines."""
         ...
     def get_computed_priorities(self) -> dict[str, int]:
         """Get computed priorities."""
         ...
+    def get_eligible_tasks(self, as_of_date: date) -> list[TaskEligibility]:
+        """Tasks that could start on `as_of_date`, with the blockers of the rest."""
+        ...
     def get_rollout_decisions(self) -> list[RolloutDecision]:
         """Get rollout decisions (only populated if rollout was enabled)."""
         ...
+    def get_last_rollout_trace(self) -> RolloutTrace | None:
+        """Simulated schedules behind the last rollout decision (needs `trace_rollouts`)."""
+        ...
+    def get_reservations(self) -> list[ReservationRecord]:
+        """Get resource reservations made by rollout skips during the last run."""
+        ...
     def __repr__(self) -> str: ...
 
 class CriticalPathConfig:
@@ -193,8 +791,26 @@ class CriticalPathConfig:
     rollout_enabled: bool
     rollout_score_ratio_threshold: float
     rollout_max_horizon_days: int | None
+    rollout_beam_k: int
+    rollout_beam_width: int
+    rollout_cache_size: int
+    rollout_fidelity_str: str  # "full", "no_auto_assignment", or "critical_tasks_only"
+    objective: ScheduleObjective
+    reservation_max_idle_days: int
     work_transform_str: str  # "power", "log", or "log10"
     work_exponent: float
+    prefer_fungible_resources: bool
+    urgency_denominator_str: str  # "global_avg", "target_work", or "critical_path"
+    timeout_secs: float | None
+    unknown_dependencies_str: str  # "block", "error", or "ignore"
+    on_unschedulable_str: str  # "error", "defer", or "best_effort"
+    max_iterations: int | None  # time advances before failing; None = 100 per task
+    trace_rollouts: bool  # keep the last decision's simulations for get_last_rollout_trace()
+    allow_fixed_in_global_dns: bool  # False moves milestones/start_on out of global DNS
+    on_fixed_conflict_str: str  # "ignore", "warn", "error", or "shift"
+    profile: bool  # record PhaseTimings in result metadata
+    max_targets_per_iteration: int | None  # rank only the best K targets; None = all
+    duration_rounding_str: str  # "up" or "nearest"
 
     def __init__(
         self,
@@ -207,23 +823,169 @@ class CriticalPathConfig:
         rollout_max_horizon_days: int | None = 30,
         work_transform: str = "power",
         work_exponent: float = 1.0,
+        prefer_fungible_resources: bool = True,
+        urgency_denominator: str = "global_avg",
+        timeout_secs: float | None = None,
+        unknown_dependencies: str = "block",
+        rollout_beam_k: int = 1,
+        rollout_beam_width: int = 0,
+        rollout_cache_size: int = 0,
+        rollout_fidelity: str = "full",
+        objective: ScheduleObjective | None = None,
+        reservation_max_idle_days: int = 0,
+        on_unschedulable: str = "error",
+        max_iterations: int | None = None,
+        trace_rollouts: bool = False,
+        allow_fixed_in_global_dns: bool = True,
+        on_fixed_conflict: str = "warn",
+        profile: bool = False,
+        max_targets_per_iteration: int | None = None,
+        duration_rounding: str = "up",
     ) -> None: ...
     def __repr__(self) -> str: ...
+    @staticmethod
+    def preset(name: str) -> CriticalPathConfig: ...  # "deadline_focused", "throughput", "stable_plan", "fast"
+    @staticmethod
+    def from_profile(text: str) -> CriticalPathConfig: ...  # JSON or TOML overrides; optional "preset" key
+    def validate(self) -> list[str]: ...  # every range problem; empty if valid
+
+class CriticalPathRolloutDecision:
+    decision_date: date
+    task_id: str
+    task_score: float
+    competing_target_id: str
+    competing_target_score: float
+    competing_task_id: str
+    competing_eligible_date: date
+    schedule_score: float
+    skip_score: float
+    decision: str
+    reserved_resource: str | None
+
+    def __repr__(self) -> str: ...
+
+class ReservationRecord:
+    resource: str
+    target_id: str
+    task_id: str
+    reserved_from: date
+    released_on: date
+    held_days: int
+    outcome: str  # "fulfilled", "expired", "replaced", or "open"
+
+    def __repr__(self) -> str: ...
 
 class CriticalPathScheduler:
     def __init__(
         self,
-        tasks: list[Task],
+        tasks: list[Task] | TaskColumns,
         current_date: date,
         completed_task_ids: set[str] | None = None,
         default_priority: int | None = None,
         config: CriticalPathConfig | None = None,
         resource_config: ResourceConfig | None = None,
         global_dns_periods: list[tuple[date, date]] | None = None,
-    ) -> None: ...
-    def schedule(self) -> AlgorithmResult:
+        external_completions: dict[str, date] | None = None,
+    ) -> None:
+        """Create the scheduler; raises ValueError if task IDs are duplicated.
+
+        Dependencies on IDs in `external_completions` that are not tasks wait
+        for that event's date instead of a task, including in tasks added
+        later by `update_tasks`.
+        """
+        ...
+    def schedule(
+        self,
+        cancel_token: CancellationToken | None = None,
+        progress_callback: Callable[[int, int, date], None] | None = None,
+        progress_interval: int = 1,
+    ) -> AlgorithmResult:
         """Run the critical path scheduling algorithm."""
         ...
+    def schedule_background(
+        self,
+        cancel_token: CancellationToken | None = None,
+        progress_callback: Callable[[int, int, date], None] | None = None,
+        progress_interval: int = 1,
+    ) -> ScheduleHandle:
+        """Start scheduling on a background thread (GIL released)."""
+        ...
+    def schedule_until(self, pause_at: date) -> SchedulerSnapshot:
+        """Schedule decisions up to and including `pause_at` and return the state."""
+        ...
+    def schedule_step(self, dry_run: bool = False) -> ScheduleStep:
+        """Make exactly one decision and return it; later calls continue from it.
+
+        With ``dry_run`` the decision is only reported and the scheduler stays put.
+        """
+        ...
+    def restore(self, snapshot: SchedulerSnapshot | None = None) -> None:
+        """Make later runs resume from `snapshot`; None schedules from scratch again."""
+        ...
+    def update_tasks(
+        self,
+        upserts: list[Task] | None = None,
+        removed: list[str] | None = None,
+        completed: list[str] | None = None,
+    ) -> None:
+        """Edit the tasks for a replan; completions keep the interned task data."""
+        ...
+    def set_current_date(self, current_date: date) -> None:
+        """Move the scheduling date, re-resolving and re-validating the tasks."""
+        ...
+    def schedule_for_targets(self, target_ids: list[str]) -> AlgorithmResult:
+        """Schedule only the targets and their transitive dependencies.
+
+        Other tasks are left unscheduled and listed in the `deferred_tasks` metadata.
+
+        Raises:
+            ValueError: If a target ID is unknown or scheduling fails
+        """
+        ...
+    def get_eligible_tasks(self, as_of_date: date) -> list[TaskEligibility]:
+        """Tasks that could start on `as_of_date`, with the blockers of the rest."""
+        ...
+    def get_rollout_decisions(self) -> list[CriticalPathRolloutDecision]:
+        """Get rollout decisions from the last run (only populated if rollout was enabled)."""
+        ...
+    def get_last_rollout_trace(self) -> RolloutTrace | None:
+        """Simulated schedules behind the last rollout decision (needs `trace_rollouts`)."""
+        ...
+    def get_reservations(self) -> list[ReservationRecord]:
+        """Get resource reservations held during the last run (only populated if rollout was enabled)."""
+        ...
+    def __repr__(self) -> str: ...
+
+class Scheduler:
+    """Runs the algorithm selected by `SchedulingConfig.algorithm`."""
+
+    def __init__(
+        self,
+        tasks: list[Task] | TaskColumns,
+        current_date: date,
+        completed_task_ids: set[str] | None = None,
+        config: SchedulingConfig | None = None,
+        rollout_config: RolloutConfig | None = None,
+        critical_path_config: CriticalPathConfig | None = None,
+        resource_config: ResourceConfig | None = None,
+        global_dns_periods: list[tuple[date, date]] | None = None,
+        external_completions: dict[str, date] | None = None,
+    ) -> None: ...
+    def schedule(
+        self,
+        cancel_token: CancellationToken | None = None,
+        progress_callback: Callable[[int, int, date], None] | None = None,
+        progress_interval: int = 1,
+    ) -> AlgorithmResult: ...
+    def schedule_background(
+        self,
+        cancel_token: CancellationToken | None = None,
+        progress_callback: Callable[[int, int, date], None] | None = None,
+        progress_interval: int = 1,
+    ) -> ScheduleHandle: ...
+    def get_metadata(self) -> AlgorithmMetadata | None:
+        """Metadata of the last completed run, or None before the first."""
+        ...
     def __repr__(self) -> str: ...
 
 # Functions
@@ -232,6 +994,10 @@ def run_backward_pass(
     tasks: list[Task],
     completed_task_ids: set[str],
     default_priority: int,
+    priority_decay: float = 1.0,
+    contention_aware: bool = False,
+    dns_periods: dict[str, list[tuple[date, date]]] | None = None,
+    global_dns_periods: list[tuple[date, date]] | None = None,
 ) -> PreProcessResult:
     """Run the backward pass algorithm to compute deadlines and priorities.
 
@@ -239,12 +1005,29 @@ def run_backward_pass(
         tasks: List of tasks to process
         completed_task_ids: Set of task IDs already completed (excluded from propagation)
         default_priority: Default priority for tasks without explicit priority (0-100)
+        priority_decay: Multiplier applied to inherited priority per dependency level
+        contention_aware: Tighten deadlines of tasks that compete for the same explicit resource
+        dns_periods: DNS periods per resource; passing this or global_dns_periods
+            makes deadline propagation count durations in working days
+        global_dns_periods: DNS periods applying to everyone
 
     Returns:
-        PreProcessResult with computed deadlines and priorities
+        PreProcessResult with computed deadlines, priorities and earliest starts
 
     Raises:
-        ValueError: If circular dependency is detected
+        CircularDependencyError: If circular dependency is detected; the exception's
+            ``cycle`` attribute lists the task IDs on the cycle, each
+            depending on the next
+        MoucSchedulerError: If a propagated date falls outside the supported range
+    """
+    ...
+
+def enable_python_logging(enabled: bool = True) -> None:
+    """Route scheduler logs to Python ``logging`` instead of stderr.
+
+    Loggers are named per module (``mouc.rust.critical_path``, ``mouc.rust.scheduler``)
+    and their levels replace ``verbosity``: INFO for changes, DEBUG for checks, 5 for
+    internals. Levels are read on first use; call again after changing them.
     """
     ...
 
@@ -275,3 +1058,413 @@ def py_sort_tasks(
         ValueError: If unknown strategy, missing ATC params, or task not found
     """
     ...
+
+def validate_tasks(
+    tasks: list[Task],
+    resource_config: ResourceConfig | None = None,
+) -> list[Diagnostic]:
+    """Validate tasks before scheduling and return every problem found.
+
+    Args:
+        tasks: List of tasks to check
+        resource_config: Optional resource configuration; enables resource name checks
+
+    Returns:
+        List of diagnostics (empty if the tasks are valid)
+    """
+    ...
+
+def check_feasibility(
+    tasks: list[Task] | TaskColumns,
+    resource_config: ResourceConfig,
+    horizon: tuple[date, date],
+    global_dns_periods: list[tuple[date, date]] | None = None,
+) -> list[ResourceOverload]:
+    """Compare required work per resource with available capacity over a horizon.
+
+    Explicitly assigned work counts toward each resource; resource-spec work is
+    checked against the spec's candidates as a pool. Capacity excludes DNS days.
+
+    Args:
+        tasks: List of tasks to check
+        resource_config: Resource configuration
+        horizon: (start, end); days from start up to, not including, end count
+        global_dns_periods: DNS periods applying to every resource
+
+    Returns:
+        Overloaded resources, largest overload first (empty if none)
+    """
+    ...
+
+def verify_schedule(
+    result: AlgorithmResult,
+    tasks: list[Task] | TaskColumns,
+    resource_config: ResourceConfig | None = None,
+    global_dns_periods: list[tuple[date, date]] | None = None,
+    sub_day: bool = False,
+) -> list[ScheduleWarning]:
+    """Check a schedule, e.g. one edited by hand, for broken invariants.
+
+    Codes: "double_booking" (overlapping spans on a resource, unless one task
+    pauses while the other runs inside it), "dependency_violation" (starts
+    before a dependency plus lag allows), "dns_violation" (starts during a DNS
+    or blocked period) and "fixed_date_violation" (moved off start_on/end_on).
+
+    Args:
+        result: Schedule to check
+        tasks: Tasks the schedule was built from
+        resource_config: Resource configuration (per-resource DNS periods)
+        global_dns_periods: DNS periods applying to every resource
+        sub_day: Whether the schedule was built with sub_day packing
+
+    Returns:
+        One ScheduleWarning per violation (empty if the schedule is valid)
+    """
+    ...
+
+def get_eligible_tasks(
+    tasks: list[Task] | TaskColumns,
+    as_of_date: date,
+    completed_task_ids: set[str] | None = None,
+) -> list[TaskEligibility]:
+    """Which tasks could start on `as_of_date`, without scheduling.
+
+    Eligible tasks have every dependency completed and a start_after, start_on and
+    blocked periods that allow the date; resources are not checked.
+
+    Returns:
+        One TaskEligibility per task not completed, sorted by task ID
+    """
+    ...
+
+def compute_buffers(
+    result: AlgorithmResult,
+    tasks: list[Task],
+    target_id: str,
+    buffer_ratio: float = 0.5,
+) -> list[Buffer]:
+    """Compute CCPM project and feeding buffers for a scheduled target.
+
+    Args:
+        result: Schedule to analyze
+        tasks: Tasks the schedule was built from (for dependencies)
+        target_id: Task whose critical chain gets the project buffer
+        buffer_ratio: Buffer size as a fraction of chain duration
+
+    Returns:
+        List of buffers, project buffer first (empty if target is not scheduled)
+
+    Raises:
+        ValueError: If buffer_ratio is negative or not finite, or a buffer falls
+            outside the supported date range
+    """
+    ...
+
+def insert_buffer_tasks(result: AlgorithmResult, buffers: list[Buffer]) -> AlgorithmResult:
+    """Return a copy of `result` with a pseudo-task appended for each buffer."""
+    ...
+
+def buffer_consumption(buffers: list[Buffer], result: AlgorithmResult) -> list[BufferStatus]:
+    """Report how much of each buffer a rescheduled plan consumes."""
+    ...
+
+def earned_value(
+    baseline: AlgorithmResult,
+    actual_completions: dict[str, date],
+    current_date: date,
+) -> EarnedValueSeries:
+    """Compute daily earned value metrics (PV/EV/SV/SPI) against a baseline.
+
+    Args:
+        baseline: Baseline schedule; each task's budget is its duration
+        actual_completions: Map of task ID to actual completion date
+        current_date: Last date of the series
+
+    Returns:
+        Series from the baseline's first start through `current_date`
+    """
+    ...
+
+def pareto_frontier(
+    tasks: list[Task] | TaskColumns,
+    current_date: date,
+    objectives: list[ScheduleObjective],
+    completed_task_ids: set[str] | None = None,
+    default_priority: int | None = None,
+    config: CriticalPathConfig | None = None,
+    resource_config: ResourceConfig | None = None,
+    global_dns_periods: list[tuple[date, date]] | None = None,
+) -> list[ParetoSchedule]:
+    """Schedule once per objective and return the Pareto-optimal schedules.
+
+    Runs the critical path scheduler with each objective and keeps schedules
+    no other schedule beats on every `ScheduleMetrics` metric. Objectives act
+    through rollout, so `config` should have rollout enabled.
+
+    Raises:
+        ValueError: If scheduling fails
+    """
+    ...
+
+def tune_critical_path(
+    tasks: list[Task] | TaskColumns,
+    current_date: date,
+    completed_task_ids: set[str] | None = None,
+    default_priority: int | None = None,
+    config: CriticalPathConfig | None = None,
+    resource_config: ResourceConfig | None = None,
+    global_dns_periods: list[tuple[date, date]] | None = None,
+    grid: TuningGrid | None = None,
+    samples: int | None = None,
+    seed: int = 0,
+) -> TuningResult:
+    """Search k, urgency_floor, work_exponent and no_deadline_urgency_multiplier.
+
+    Schedules once per grid combination (or `samples` random ones) plus
+    `config` itself and returns the lowest objective cost under
+    `config.objective`, with every trial ranked.
+
+    Raises:
+        ValueError: If scheduling fails
+    """
+    ...
+
+def suggest_crashes(
+    tasks: list[Task] | TaskColumns,
+    result: AlgorithmResult,
+    target_ids: list[str] | None = None,
+    global_dns_periods: list[tuple[date, date]] | None = None,
+) -> list[CrashPlan]:
+    """Suggest the cheapest set of tasks to crash for each late target.
+
+    Follows each target's chain of binding predecessors in `result` and takes
+    days from its `crashable` tasks, cheapest per day saved first. Without
+    `target_ids`, every task past its `end_before` is a target. The schedule
+    is not re-run, so plans are estimates.
+
+    Raises:
+        ValueError: If a target is not in the schedule
+        InvalidConfigError: If a crash spec is negative, non-finite, or longer than its task
+    """
+    ...
+
+def suggest_kickoffs(
+    tasks: list[Task] | TaskColumns,
+    target_ids: list[str],
+    completed_task_ids: set[str] | None = None,
+    default_priority: int | None = None,
+    global_dns_periods: list[tuple[date, date]] | None = None,
+) -> list[KickoffSuggestion]:
+    """Suggest the latest kickoff date for each deadline target.
+
+    Targets' dependency subgraphs share explicitly assigned resources; when
+    that loading pulls a kickoff earlier than dependency chains alone, the
+    responsible resources are listed. Targets without a deadline are omitted.
+
+    Raises:
+        ValueError: If a target is unknown, the graph has a cycle, or a date falls
+            outside the supported range
+    """
+    ...
+
+def latest_starts(
+    tasks: list[Task] | TaskColumns,
+    current_date: date,
+    completed_task_ids: set[str] | None = None,
+    resource_config: ResourceConfig | None = None,
+    global_dns_periods: list[tuple[date, date]] | None = None,
+) -> list[LatestStart]:
+    """Latest responsible start for each task, scheduling backwards from deadlines.
+
+    Explicitly assigned resources take one task at a time, so work sharing a
+    person with later work starts earlier. Tasks with no deadline downstream
+    are omitted.
+
+    Raises:
+        ValueError: If the graph has a cycle or a date falls outside the supported range
+    """
+    ...
+
+def calculate_critical_path(
+    target_id: str,
+    tasks: list[Task] | TaskColumns,
+    scheduled: dict[str, float] | None = None,
+    completed_task_ids: set[str] | None = None,
+) -> CriticalPathResult:
+    """Compute ES/EF/LS/LF and slack for the work leading to a target.
+
+    Times are days from the start of the target's unscheduled dependency
+    subgraph. `scheduled` maps already scheduled task IDs to their end offset.
+
+    Raises:
+        ValueError: If the target is unknown or the graph has a cycle
+    """
+    ...
+
+def transitive_dependents(tasks: list[Task] | TaskColumns, task_id: str) -> list[str]:
+    """All tasks that depend on `task_id`, directly or transitively, sorted.
+
+    Raises:
+        ValueError: If the task is unknown
+    """
+    ...
+
+def transitive_dependencies(tasks: list[Task] | TaskColumns, task_id: str) -> list[str]:
+    """All known tasks `task_id` depends on, directly or transitively, sorted.
+
+    Raises:
+        ValueError: If the task is unknown
+    """
+    ...
+
+def topological_order(tasks: list[Task] | TaskColumns) -> list[str]:
+    """Task IDs ordered so each task follows its dependencies, ties broken by ID.
+
+    Raises:
+        ValueError: If the graph has a cycle; its `cycle` attribute lists the task IDs
+    """
+    ...
+
+def slip_impact(
+    tasks: list[Task] | TaskColumns, task_id: str, delay_days: float
+) -> list[TargetImpact]:
+    """Which targets (tasks nothing depends on) move if `task_id` slips by `delay_days`.
+
+    Uses dependency chains alone, assuming unlimited resources, so slack
+    absorbs part or all of the slip.
+
+    Raises:
+        ValueError: If the task is unknown or the graph has a cycle
+    """
+    ...
+
+def analyze_slip(
+    task_id: str,
+    delay_days: float,
+    result: AlgorithmResult,
+    tasks: list[Task] | TaskColumns,
+    respect_resources: bool = False,
+    global_dns_periods: list[tuple[date, date]] | None = None,
+) -> SlipAnalysis:
+    """Estimate how a schedule shifts if `task_id` finishes `delay_days` late.
+
+    Pushes dependents (and, with `respect_resources`, later tasks on shared
+    resources) without rescheduling. DNS periods only matter for working-day lags.
+    `missed_deadlines` lists tasks that now finish after their `end_before`.
+
+    Raises:
+        ValueError: If the task is not in the schedule
+    """
+    ...
+
+def compute_blockers(
+    tasks: list[Task] | TaskColumns,
+    task_id: str,
+    result: AlgorithmResult | None = None,
+    completed_task_ids: set[str] | None = None,
+) -> list[Blocker]:
+    """Incomplete tasks `task_id` waits on, directly or transitively.
+
+    The walk stops at completed tasks. Expected end dates come from `result`,
+    e.g. a partial schedule. Each blocker comes before the tasks it blocks.
+
+    Raises:
+        ValueError: If the task is unknown or the graph has a cycle
+    """
+    ...
+
+def export_mermaid(result: AlgorithmResult, tasks: list[Task]) -> str:
+    """Render a schedule as a Mermaid gantt chart, one section per primary resource."""
+    ...
+
+def export_csv(result: AlgorithmResult, tasks: list[Task]) -> str:
+    """Render a schedule as CSV, one row per scheduled task ordered by start date.
+
+    Columns: task_id, start_date, end_date, duration_days, resources,
+    dependencies, priority, end_before, in_progress. List columns are
+    `;`-separated; missing values are empty.
+    """
+    ...
+
+def export_json(result: AlgorithmResult, tasks: list[Task]) -> str:
+    """Render a schedule as JSON.
+
+    Schema (dates are ISO `YYYY-MM-DD`)::
+
+        {
+          "metadata": {str: str},
+          "tasks": [
+            {
+              "id": str,
+              "start_date": str,
+              "end_date": str,
+              "duration_days": float,
+              "resources": [str],
+              "dependencies": [str],
+              "priority": int | null,
+              "end_before": str | null,
+              "in_progress": bool
+            }
+          ]
+        }
+    """
+    ...
+
+def export_ics(
+    result: AlgorithmResult,
+    tasks: list[Task],
+    resource_config: ResourceConfig | None = None,
+    global_dns_periods: list[tuple[date, date]] | None = None,
+) -> dict[str, str]:
+    """Render per-resource iCalendar (.ics) calendars for a schedule.
+
+    Each scheduled task becomes an all-day event on its resources' calendars;
+    DNS periods become opaque "Unavailable" events.
+
+    Args:
+        result: Schedule to export
+        tasks: Tasks the schedule was built from (for descriptions)
+        resource_config: Optional resource configuration; adds its resources and DNS periods
+        global_dns_periods: Unavailable periods applied to every resource
+
+    Returns:
+        Dict mapping resource name to calendar text
+
+    Raises:
+        ValueError: If an event ends past the last representable date
+    """
+    ...
+
+def export_arrow(result: AlgorithmResult) -> Any:
+    """Convert a schedule to a `pyarrow.RecordBatch`, one row per (task, resource).
+
+    Columns: task_id (str), start (date32), end (date32), resource (str, null if
+    unassigned), duration (float64). Only available when the extension is built
+    with the `arrow` feature (`maturin build --features arrow`).
+    """
+    ...
+
+def tasks_from_json(text: str) -> list[Task]:
+    """Parse tasks from JSON text without constructing them in Python.
+
+    Accepts a list of task objects or an object with a "tasks" list. Fields use
+    the `Task` names; dates are "YYYY-MM-DD"; `resources` is a name list,
+    `[name, allocation]` pairs, or a `{name: allocation}` object; dependencies
+    are IDs or `{"id": ..., "lag_days": ...}` objects.
+
+    Raises:
+        ValueError: Naming the offending task, field, or line
+    """
+    ...
+
+def tasks_from_csv(text: str) -> list[Task]:
+    """Parse tasks from CSV text (with a header row) without constructing them in Python.
+
+    Requires `id` and `duration_days` columns. List columns are `;`-separated:
+    resources as `name` or `name:allocation`, dependencies as `id` or
+    `id:lag_days`. Empty cells are unset.
+
+    Raises:
+        ValueError: Naming the offending line and field
+    """
+    ...
@@ -30,6 +30,7 @@ from .config import (
     RolloutConfig,
     SchedulingConfig,
     TimeframeConstraintMode,
+    UnschedulablePolicy,
 )
 from .core import (
     AlgorithmResult,
@@ -75,6 +76,7 @@ __all__ = [
     "PreProcessorType",
     "RolloutConfig",
     "TimeframeConstraintMode",
+    "UnschedulablePolicy",
     # Protocols
     "PreProcessor",
     "SchedulingAlgorithm",
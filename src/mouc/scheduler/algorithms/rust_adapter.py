@@ -142,6 +142,7 @@ class RustSchedulerAdapter:
             atc_default_urgency_multiplier=config.atc_default_urgency_multiplier,
             atc_default_urgency_floor=config.atc_default_urgency_floor,
             verbosity=_get_verbosity(),
+            on_unschedulable=config.on_unschedulable.value,
         )
 
     def _convert_resource_config(
@@ -205,6 +206,7 @@ class RustSchedulerAdapter:
             work_exponent=config.critical_path.work_exponent,
             prefer_fungible_resources=config.critical_path.prefer_fungible_resources,  # pyright: ignore[reportCallIssue]
             urgency_denominator=config.critical_path.urgency_denominator,  # pyright: ignore[reportCallIssue]
+            on_unschedulable=config.on_unschedulable.value,
         )
 
     def schedule(self) -> AlgorithmResult:
@@ -227,9 +229,13 @@ class RustSchedulerAdapter:
             for st in rust_result.scheduled_tasks
         ]
 
+        metadata: dict[str, Any] = dict(rust_result.algorithm_metadata)
+        if rust_result.unschedulable:
+            metadata["unschedulable"] = {u.task_id: u.detail for u in rust_result.unschedulable}
+
         return AlgorithmResult(
             scheduled_tasks=scheduled_tasks,
-            algorithm_metadata=dict(rust_result.algorithm_metadata),
+            algorithm_metadata=metadata,
         )
 
     def get_computed_deadlines(self) -> dict[str, date]:
@@ -38,6 +38,14 @@ class ImplementationType(str, Enum):
     RUST = "rust"
 
 
+class UnschedulablePolicy(str, Enum):
+    """What the Rust schedulers do with tasks they cannot place."""
+
+    ERROR = "error"  # Fail the whole schedule
+    DEFER = "defer"  # Schedule the rest and report the leftover tasks
+    BEST_EFFORT = "best_effort"  # Drop unsatisfiable dependencies, then defer
+
+
 class AlgorithmConfig(BaseModel):
     """Configuration for algorithm selection."""
 
@@ -155,3 +163,6 @@ class SchedulingConfig(BaseModel):
 
     # Implementation language (python or rust)
     implementation: ImplementationType = ImplementationType.PYTHON
+
+    # Handling of tasks that cannot be scheduled (Rust implementation only)
+    on_unschedulable: UnschedulablePolicy = UnschedulablePolicy.ERROR
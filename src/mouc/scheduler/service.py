@@ -108,6 +108,9 @@ class SchedulingService:
         try:
             algorithm_result = algorithm.schedule()
             scheduled_tasks = algorithm_result.scheduled_tasks
+            unschedulable: dict[str, str] = algorithm_result.algorithm_metadata.get(
+                "unschedulable", {}
+            )
 
             # Get computed values from algorithm (may be from preprocess)
             computed_deadlines = algorithm.get_computed_deadlines()
@@ -177,6 +180,9 @@ class SchedulingService:
                 f"Task '{entity_id}' marked done without dates - excluded from schedule"
             )
 
+        for entity_id, detail in sorted(unschedulable.items()):
+            warnings.append(f"Task '{entity_id}' could not be scheduled: {detail}")
+
         for entity_id, annot in annotations.items():
             if annot.deadline_violated and annot.computed_deadline and annot.estimated_end:
                 days_late = (annot.estimated_end - annot.computed_deadline).days